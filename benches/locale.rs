@@ -0,0 +1,95 @@
+//! Benchmarks of the parsing and the display of `Locale`, without dependencies:
+//! `cargo bench --bench locale`.
+
+use pure_rust_locales::blob::Blob;
+use pure_rust_locales::Locale;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// How long each benchmark runs.
+const DURATION: Duration = Duration::from_secs(2);
+
+/// Run `f` repeatedly and print the time of a call. `f` returns a number that is printed too,
+/// so that its work isn't optimized away.
+fn bench(name: &str, mut f: impl FnMut() -> usize) {
+    let mut iterations = 0_u64;
+    let mut sum = 0_usize;
+    let start = Instant::now();
+
+    while start.elapsed() < DURATION {
+        for _ in 0..1000 {
+            sum = sum.wrapping_add(f());
+        }
+        iterations += 1000;
+    }
+
+    println!(
+        "{:<40} {:>8.1} ns/iter (checksum {})",
+        name,
+        start.elapsed().as_nanos() as f64 / iterations as f64,
+        sum,
+    );
+}
+
+/// A writer that only counts the bytes written.
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+fn main() {
+    let names = Blob::builtin()
+        .locales()
+        .filter_map(|x| x.name())
+        .collect::<Vec<_>>();
+    let locales = names
+        .iter()
+        .map(|x| x.parse::<Locale>().unwrap())
+        .collect::<Vec<_>>();
+    let lowercase = names.iter().map(|x| x.to_lowercase()).collect::<Vec<_>>();
+    let hyphenated = names
+        .iter()
+        .map(|x| x.replace('_', "-"))
+        .collect::<Vec<_>>();
+    let (len, mut i) = (names.len(), 0);
+    let mut next = move || {
+        i = (i + 1) % len;
+        i
+    };
+
+    bench("parse every locale", || {
+        let i = next();
+        names[i].parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("parse en_US", || {
+        "en_US".parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("parse zu_ZA", || {
+        "zu_ZA".parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("parse an unknown locale", || {
+        "xx_XX".parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("parse every locale in lowercase", || {
+        let i = next();
+        lowercase[i].parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("parse every locale with `-`", || {
+        let i = next();
+        hyphenated[i].parse::<Locale>().map_or(0, |x| x as usize)
+    });
+    bench("name of every locale", || {
+        let i = next();
+        locales[i].name().len()
+    });
+    bench("display every locale", || {
+        let i = next();
+        let mut counter = Counter(0);
+        write!(counter, "{}", locales[i]).unwrap();
+        counter.0
+    });
+}
@@ -0,0 +1,4 @@
+/* This file just defines the current version number of libc.  */
+
+#define RELEASE "stable"
+#define VERSION "2.39"
@@ -0,0 +1,260 @@
+//! The binary blob of the locale data, read by `pure_rust_locales::blob`.
+//!
+//! The blob is little-endian:
+//!
+//! ```text
+//! magic         b"PRLB"
+//! version       u32
+//! word count    u32
+//! words         [u32; word count]
+//! strings       UTF-8, up to the end
+//! ```
+//!
+//! A string is two words, its offset and its length in `strings`. The words start with the
+//! number of categories and the number of locales, then an entry per category (its name, the
+//! number of its items and the index of its item table) and an entry per locale sorted by name
+//! (its name and the index of the record of each category). An item table has the name and the
+//! `Kind` of each item of the category, sorted by name, and a record of a category has the index
+//! of the value of each item, or `NONE`. The strings, the values and the records are shared by
+//! the locales that have the same.
+
+use std::collections::{BTreeMap, HashMap};
+
+/// The first bytes of a blob.
+pub const MAGIC: &[u8; 4] = b"PRLB";
+
+/// The version of the format of the blob. It changes whenever a blob could be read differently.
+pub const VERSION: u32 = 1;
+
+/// The value of an item the locale doesn't define.
+const NONE: u32 = u32::MAX;
+
+/// The type of the values of an item, the same in every locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A string: its offset and its length.
+    String = 0,
+    /// An `i64`: its low and its high word.
+    Integer = 1,
+    /// A list of strings: its length then the strings.
+    Strings = 2,
+    /// A list of `i64`: its length then the integers.
+    Integers = 3,
+    /// A list of lists of strings: its length then the index of each list.
+    Table = 4,
+}
+
+/// The value of an item.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Strings(Vec<String>),
+    Integers(Vec<i64>),
+    Table(Vec<Vec<String>>),
+}
+
+impl Value {
+    fn kind(&self) -> Kind {
+        match self {
+            Value::String(_) => Kind::String,
+            Value::Integer(_) => Kind::Integer,
+            Value::Strings(_) => Kind::Strings,
+            Value::Integers(_) => Kind::Integers,
+            Value::Table(_) => Kind::Table,
+        }
+    }
+}
+
+/// The items of each category, by name, with their kind.
+pub type Schema = BTreeMap<String, BTreeMap<String, Kind>>;
+
+/// The items of a category of a locale, by name. The items of the schema missing are not
+/// defined by the locale.
+pub type Items = BTreeMap<String, Value>;
+
+/// Pack the locales, by name, with their categories, in a blob.
+///
+/// # Panics
+///
+/// If a value doesn't have the kind of its item in the schema.
+pub fn pack(schema: &Schema, locales: &BTreeMap<String, BTreeMap<String, Items>>) -> Vec<u8> {
+    let mut packer = Packer::default();
+    packer.words.push(schema.len() as u32);
+    packer.words.push(locales.len() as u32);
+
+    let categories_at = packer.words.len();
+    packer.words.resize(categories_at + 4 * schema.len(), 0);
+    let locales_at = packer.words.len();
+    packer
+        .words
+        .resize(locales_at + (2 + schema.len()) * locales.len(), 0);
+
+    for (i, (category, items)) in schema.iter().enumerate() {
+        let mut table = Vec::new();
+        for (name, kind) in items.iter() {
+            table.extend(packer.string(name));
+            table.push(*kind as u32);
+        }
+        let name = packer.string(category);
+        let table = packer.record(table);
+        let at = categories_at + 4 * i;
+        packer.words[at..at + 4].copy_from_slice(&[name[0], name[1], items.len() as u32, table]);
+    }
+
+    for (i, (lang, categories)) in locales.iter().enumerate() {
+        let mut entry = packer.string(lang).to_vec();
+        for (category, items) in schema.iter() {
+            let values = match categories.get(category) {
+                Some(values) => values,
+                None => {
+                    entry.push(NONE);
+                    continue;
+                }
+            };
+            let mut record = Vec::new();
+            for (name, kind) in items.iter() {
+                record.push(match values.get(name) {
+                    Some(value) => {
+                        assert_eq!(value.kind(), *kind, "{} {} {}", lang, category, name);
+                        packer.value(value)
+                    }
+                    None => NONE,
+                });
+            }
+            entry.push(packer.record(record));
+        }
+        let at = locales_at + (2 + schema.len()) * i;
+        packer.words[at..at + entry.len()].copy_from_slice(&entry);
+    }
+
+    let mut blob = MAGIC.to_vec();
+    blob.extend(VERSION.to_le_bytes());
+    blob.extend((packer.words.len() as u32).to_le_bytes());
+    for word in packer.words.iter() {
+        blob.extend(word.to_le_bytes());
+    }
+    blob.extend(packer.strings.as_bytes());
+
+    blob
+}
+
+#[derive(Default)]
+struct Packer {
+    words: Vec<u32>,
+    strings: String,
+    /// The offset of each string of `strings`.
+    string_offsets: HashMap<String, u32>,
+    /// The index of each record of `words`.
+    records: HashMap<Vec<u32>, u32>,
+}
+
+impl Packer {
+    fn string(&mut self, string: &str) -> [u32; 2] {
+        let offset = match self.string_offsets.get(string) {
+            Some(offset) => *offset,
+            None => {
+                let offset = self.strings.len() as u32;
+                self.strings.push_str(string);
+                self.string_offsets.insert(string.to_string(), offset);
+                offset
+            }
+        };
+        [offset, string.len() as u32]
+    }
+
+    /// Add the words of a record, unless the same words were already added, and return their
+    /// index.
+    fn record(&mut self, record: Vec<u32>) -> u32 {
+        if let Some(at) = self.records.get(&record) {
+            return *at;
+        }
+        let at = self.words.len() as u32;
+        self.words.extend(record.iter());
+        self.records.insert(record, at);
+        at
+    }
+
+    fn value(&mut self, value: &Value) -> u32 {
+        let mut record = Vec::new();
+        match value {
+            Value::String(x) => record.extend(self.string(x)),
+            Value::Integer(x) => record.extend([*x as u32, (*x >> 32) as u32]),
+            Value::Strings(x) => {
+                record.push(x.len() as u32);
+                for x in x.iter() {
+                    record.extend(self.string(x));
+                }
+            }
+            Value::Integers(x) => {
+                record.push(x.len() as u32);
+                for x in x.iter() {
+                    record.extend([*x as u32, (*x >> 32) as u32]);
+                }
+            }
+            Value::Table(x) => {
+                record.push(x.len() as u32);
+                for x in x.iter() {
+                    let strings = self.value(&Value::Strings(x.clone()));
+                    record.push(strings);
+                }
+            }
+        }
+        self.record(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    fn word(blob: &[u8], index: usize) -> u32 {
+        let at = 12 + 4 * index;
+        u32::from_le_bytes(blob[at..at + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn pack() {
+        let mut schema = Schema::new();
+        schema.insert(
+            "LC_TIME".to_string(),
+            [
+                ("DAY".to_string(), Kind::Strings),
+                ("D_FMT".to_string(), Kind::String),
+                ("FIRST_WEEKDAY".to_string(), Kind::Integer),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        );
+        let time = |d_fmt: &str| {
+            let mut items = Items::new();
+            items.insert(
+                "DAY".to_string(),
+                Value::Strings(vec!["dimanche".to_string(), "lundi".to_string()]),
+            );
+            items.insert("D_FMT".to_string(), Value::String(d_fmt.to_string()));
+            let mut categories = BTreeMap::new();
+            categories.insert("LC_TIME".to_string(), items);
+            categories
+        };
+        let mut locales = BTreeMap::new();
+        locales.insert("fr_BE".to_string(), time("%d/%m/%y"));
+        locales.insert("fr_FR".to_string(), time("%d/%m/%Y"));
+        locales.insert("fr_LU".to_string(), time("%d/%m/%Y"));
+
+        let blob = super::pack(&schema, &locales);
+        assert_eq!(&blob[..4], MAGIC);
+        assert_eq!(&blob[4..8], &VERSION.to_le_bytes());
+        let (categories, langs) = (word(&blob, 0), word(&blob, 1));
+        assert_eq!((categories, langs), (1, 3));
+        // The entries of the locales: name, record of LC_TIME.
+        let record = |i: usize| word(&blob, 2 + 4 + 3 * i + 2) as usize;
+        assert_ne!(record(0), record(1));
+        assert_eq!(record(1), record(2));
+        // DAY is shared, FIRST_WEEKDAY isn't defined.
+        assert_eq!(word(&blob, record(0)), word(&blob, record(1)));
+        assert_eq!(word(&blob, record(0) + 2), NONE);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::fixup::{self, Changes, Fixup};
+use crate::generator::{CodeGenerator, SUPPORT_MODULES};
+use crate::ids::Registry;
+use crate::inheritance::Inheritance;
+use crate::overlay;
+use crate::parser::{self, Object};
+use anyhow::{bail, Context, Result};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The locale that is always generated: it is the default `Locale` and the last fallback of the
+/// items.
+const DEFAULT_LOCALE: &str = "POSIX";
+
+/// The data of the locales, ready to be generated.
+pub struct Data {
+    /// The locales with their copies resolved and the fixups applied.
+    pub locales: HashMap<String, Vec<Object>>,
+    /// The same locales before the fixups.
+    pub raw: HashMap<String, Vec<Object>>,
+    /// The items changed by the fixups, by locale.
+    pub changes: HashMap<String, Changes>,
+    /// The copies of the locales before they were resolved.
+    pub inheritance: Inheritance,
+}
+
+/// Generates the code of a locale crate from locale source files.
+///
+/// It can be used from the build script of a crate that only needs a few locales:
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR")?);
+/// generate_api::Builder::new()
+///     .locales_dir("localedata/locales")
+///     .locales(["fr_BE", "nl_BE"])
+///     .categories(["LC_NUMERIC", "LC_TIME"])
+///     .feature_gating(false)
+///     .include(true)
+///     .output(out_dir.join("locales.rs"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// and the crate includes the code at its root:
+///
+/// ```ignore
+/// #![no_std]
+/// include!(concat!(env!("OUT_DIR"), "/locales.rs"));
+/// ```
+///
+/// The examples of the modules that are not generated use `pure_rust_locales`: the crate sets
+/// `doctest = false`.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    locales_dirs: Vec<PathBuf>,
+    overlay_dirs: Vec<PathBuf>,
+    locales: Option<BTreeSet<String>>,
+    categories: Option<BTreeSet<String>>,
+    fixups: BTreeSet<Fixup>,
+    feature_gating: bool,
+    include: bool,
+    glibc_version: Option<String>,
+    ids: Option<Registry>,
+    output: Option<PathBuf>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            locales_dirs: Vec::new(),
+            overlay_dirs: Vec::new(),
+            locales: None,
+            categories: None,
+            fixups: Fixup::ALL.iter().copied().collect(),
+            feature_gating: true,
+            include: false,
+            glibc_version: None,
+            ids: None,
+            output: None,
+        }
+    }
+}
+
+impl Builder {
+    /// A builder with every fixup and feature gating, without input directories.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a directory of locale source files. A file replaces a locale with the same name from
+    /// a previous directory and the files copied by the locales are looked up in the last
+    /// directory first.
+    pub fn locales_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.locales_dirs.push(dir.into());
+        self
+    }
+
+    /// Add a directory of overlay files: files named after a locale that redefine some of its
+    /// categories or keywords.
+    pub fn overlay_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.overlay_dirs.push(dir.into());
+        self
+    }
+
+    /// Generate only these locales, named like their file (`fr_BE`, `aa_ER@saaho`), and
+    /// `POSIX`. The categories they copy from the other locales are inlined.
+    pub fn locales<I, S>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut locales = locales.into_iter().map(Into::into).collect::<BTreeSet<_>>();
+        locales.insert(DEFAULT_LOCALE.to_string());
+        self.locales = Some(locales);
+        self
+    }
+
+    /// Generate only these categories (`LC_TIME`). The `alloc` and `std` features of the
+    /// generated code need every category.
+    pub fn categories<I, S>(mut self, categories: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.categories = Some(categories.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// The fixups applied to the data, all of them by default.
+    pub fn fixups(mut self, fixups: impl IntoIterator<Item = Fixup>) -> Self {
+        self.fixups = fixups.into_iter().collect();
+        self
+    }
+
+    /// Whether the locales are gated behind Cargo features (`lang-fr`, `region-be`), which the
+    /// crate must then declare. Without it every locale generated is compiled in.
+    pub fn feature_gating(mut self, enabled: bool) -> Self {
+        self.feature_gating = enabled;
+        self
+    }
+
+    /// Whether the code is included with `include!` at the root of another crate instead of
+    /// being its `lib.rs`. The modules that are not generated are then written next to the
+    /// output and declared with their path.
+    pub fn include(mut self, include: bool) -> Self {
+        self.include = include;
+        self
+    }
+
+    /// The version of `glibc` the locales come from, for `GLIBC_DATA_VERSION`.
+    pub fn glibc_version(mut self, version: impl Into<String>) -> Self {
+        self.glibc_version = Some(version.into());
+        self
+    }
+
+    /// The IDs of the locales, `Locale::to_u16`, from a registry like `localedata/ids.txt`. The
+    /// locales it doesn't have get the next IDs. By default the locales are numbered by name.
+    pub fn ids(mut self, ids: Registry) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    /// The file where the root of the code is written by [`Builder::build`]. The modules of the
+    /// locales and their blob are written in the `generated` directory next to it.
+    pub fn output(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = Some(path.into());
+        self
+    }
+
+    /// Read the locale source files, with the overlays applied.
+    pub fn read(&self) -> Result<HashMap<String, Vec<Object>>> {
+        if self.locales_dirs.is_empty() {
+            bail!("no directory of locale source files");
+        }
+
+        let mut locales = HashMap::new();
+
+        for dir in self.locales_dirs.iter() {
+            locales.extend(read_locales(dir)?);
+        }
+
+        for dir in self.overlay_dirs.iter() {
+            for (lang, overlay) in read_locales(dir)? {
+                match locales.get_mut(&lang) {
+                    Some(objects) => overlay::apply(objects, overlay),
+                    None => bail!("overlay for unknown locale {} in {}", lang, dir.display()),
+                }
+            }
+        }
+
+        Ok(locales)
+    }
+
+    /// Read the locales and prepare them: resolve their copies, keep the locales and the
+    /// categories selected and apply the fixups.
+    pub fn load(&self) -> Result<Data> {
+        let mut locales = self.read()?;
+        let mut inheritance = Inheritance::new(&locales, &self.locales_dirs)?;
+        overlay::resolve_copies(&mut locales)?;
+
+        if let Some(selected) = self.locales.as_ref() {
+            if let Some(lang) = selected
+                .iter()
+                .find(|x| *x != DEFAULT_LOCALE && !locales.contains_key(*x))
+            {
+                bail!("unknown locale {}", lang);
+            }
+            overlay::select(&mut locales, |x| selected.contains(x));
+            inheritance.retain_locales(|x| selected.contains(x));
+        }
+        if let Some(categories) = self.categories.as_ref() {
+            for objects in locales.values_mut() {
+                objects.retain(|x| categories.contains(&x.name));
+            }
+        }
+
+        let raw = locales.clone();
+        let mut changes = HashMap::new();
+        for (lang, objects) in locales.iter_mut() {
+            changes.insert(lang.clone(), fixup::validate_and_fix(objects, &self.fixups));
+        }
+
+        Ok(Data {
+            locales,
+            raw,
+            changes,
+            inheritance,
+        })
+    }
+
+    /// The code generator of the data.
+    pub fn code_generator(&self, data: Data) -> CodeGenerator {
+        let mut generator = CodeGenerator::new(
+            data.locales,
+            &data.raw,
+            &data.changes,
+            data.inheritance,
+            self.glibc_version.clone(),
+        );
+        if let Some(ids) = self.ids.as_ref() {
+            generator = generator.with_ids(ids.clone());
+        }
+        if !self.feature_gating {
+            generator = generator.without_feature_gating();
+        }
+        if let Some(dir) = self.support_dir() {
+            generator = generator.included(&dir);
+        }
+        generator
+    }
+
+    /// Generate the code and write it to the output, with the modules that are not generated
+    /// when it is included in another crate. Returns the generator, for its Cargo features.
+    pub fn build(&self) -> Result<CodeGenerator> {
+        let output = self.output.as_ref().context("no output file")?;
+        let generator = self.code_generator(self.load()?);
+
+        generator
+            .code()
+            .write(output)
+            .with_context(|| format!("could not write {}", output.display()))?;
+
+        if let Some(dir) = self.support_dir() {
+            for (name, source) in SUPPORT_MODULES {
+                let path = dir.join(format!("{}.rs", name));
+                if fs::read_to_string(&path).ok().as_deref() != Some(*source) {
+                    fs::write(&path, source)?;
+                }
+            }
+        }
+
+        Ok(generator)
+    }
+
+    /// Pack the locales in a blob, read by `pure_rust_locales::blob::Blob::new`, instead of
+    /// generating their code: a build script can embed it with `include_bytes!`.
+    pub fn blob(&self) -> Result<Vec<u8>> {
+        Ok(self.code_generator(self.load()?).blob())
+    }
+
+    /// The directory of the output, where the modules that are not generated are written. It is
+    /// absolute: the paths of the modules would be relative to the file including the code.
+    fn support_dir(&self) -> Option<PathBuf> {
+        if !self.include {
+            return None;
+        }
+        let dir = match self.output.as_ref()?.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        Some(dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf()))
+    }
+}
+
+/// Parse the locale source files of a directory.
+pub fn read_locales(dir: &Path) -> Result<HashMap<String, Vec<Object>>> {
+    let mut locales = HashMap::new();
+
+    for entry in fs::read_dir(dir).with_context(|| format!("could not read {}", dir.display()))? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        let lang = match file_name.to_str() {
+            Some(lang) => lang,
+            None => continue,
+        };
+
+        if parser::parse_lang(lang).is_err() {
+            // parse only files for which the name matches a language
+            // example: wa_BE@euro
+            continue;
+        }
+
+        let path = entry.path();
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            eprintln!("{}", path.display());
+            let objects = parser::parse(&input).map_err(|err| err.with_path(&path))?;
+            locales.insert(lang.to_string(), objects);
+        }
+    }
+
+    Ok(locales)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{BLOB_FILE, GENERATED_DIR};
+    use tempfile::TempDir;
+
+    #[test]
+    fn build() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+
+        let builder = Builder::new()
+            .locales_dir(fixtures.join("glibc/localedata/locales"))
+            .locales(["fr_BE"])
+            .categories(["LC_TIME"])
+            .feature_gating(false)
+            .include(true)
+            .output(dir.join("locales.rs"));
+        let generator = builder.build().unwrap();
+        let code = fs::read_to_string(dir.join("locales.rs")).unwrap();
+
+        let generated = dir.canonicalize().unwrap().join(GENERATED_DIR);
+        assert!(code.contains(&format!(
+            "#[path = {:?}]\npub mod fr_BE;",
+            generated.join("fr_BE.rs")
+        )));
+        assert!(generated.join("fr_BE.rs").exists());
+        assert!(!generated.join("fr_FR.rs").exists());
+        assert_eq!(
+            fs::read(generated.join(BLOB_FILE)).unwrap(),
+            builder.blob().unwrap()
+        );
+        assert!(!code.contains("#![no_std]"));
+        assert!(!code.contains("feature = \"lang-fr\""));
+        assert!(generator.cargo_features().is_empty());
+        for (name, source) in SUPPORT_MODULES {
+            let path = dir.canonicalize().unwrap().join(format!("{}.rs", name));
+            assert!(code.contains(&format!("#[path = {:?}]", path)));
+            assert_eq!(fs::read_to_string(path).unwrap(), *source);
+        }
+
+        assert!(builder.locales(["xx_XX"]).load().is_err());
+    }
+
+    #[test]
+    fn ids() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let builder = Builder::new()
+            .locales_dir(fixtures.join("glibc/localedata/locales"))
+            .locales(["fr_BE", "fr_FR"])
+            .categories(["LC_TIME"]);
+        let ids = Registry::parse("0 POSIX\n1 fr_XX\n2 fr_FR").unwrap();
+
+        let generator = builder
+            .clone()
+            .ids(ids)
+            .code_generator(builder.load().unwrap());
+        let code = generator.code();
+        assert_eq!(generator.ids().get("fr_BE"), Some(3));
+        assert_eq!(generator.ids().get("fr_XX"), Some(1));
+        assert_eq!(code.ids().get("fr_BE"), Some(3));
+        assert_eq!(code.ids().get("fr_FR"), Some(2));
+        assert_eq!(code.ids().get("fr_XX"), None);
+
+        let generator = builder.code_generator(builder.load().unwrap());
+        assert_eq!(generator.code().ids(), *generator.ids());
+        assert_eq!(generator.ids().get("fr_BE"), Some(0));
+        assert_eq!(generator.ids().get("fr_FR"), Some(1));
+    }
+}
@@ -0,0 +1,438 @@
+use crate::generator::Code;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The items of a category: the constants of its module, by name, with their value. The
+/// constants of the `raw` and `provenance` submodules are named after their module, like
+/// `raw::D_T_FMT`.
+pub type Items = BTreeMap<String, String>;
+
+/// The constants of the generated code, by locale and category. The categories a locale takes
+/// from another locale have the items of that locale.
+#[derive(Debug, Default, PartialEq)]
+pub struct Constants {
+    locales: BTreeMap<String, BTreeMap<String, Items>>,
+}
+
+impl Constants {
+    /// Read the constants of the modules of the locales from the generated code.
+    ///
+    /// The value of a constant is its expression. The references to the values shared by several
+    /// locales, in the module `__data`, are replaced by the values themselves.
+    pub fn parse(code: &Code) -> Self {
+        let mut items = BTreeMap::<(String, String), Items>::new();
+        let mut links = BTreeMap::<(String, String), (String, String)>::new();
+        let mut shared = BTreeMap::<String, String>::new();
+
+        scan(&code.root, None, &mut items, &mut links, &mut shared);
+        for (name, module) in code.modules.iter() {
+            scan(module, Some(name), &mut items, &mut links, &mut shared);
+        }
+        for value in items.values_mut().flat_map(|x| x.values_mut()) {
+            *value = resolve_shared(value, &shared);
+        }
+
+        let mut constants = Self::default();
+        for ((lang, category), category_items) in items.iter() {
+            constants
+                .locales
+                .entry(lang.clone())
+                .or_default()
+                .insert(category.clone(), category_items.clone());
+        }
+        for (link, target) in links.iter() {
+            let mut target = target;
+            let mut seen = BTreeSet::new();
+            while let Some(next) = links.get(target) {
+                if !seen.insert(target) {
+                    break;
+                }
+                target = next;
+            }
+            let category_items = items.get(target).cloned().unwrap_or_default();
+            constants
+                .locales
+                .entry(link.0.clone())
+                .or_default()
+                .insert(link.1.clone(), category_items);
+        }
+
+        constants
+    }
+
+    /// The locales found.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.locales.keys().map(String::as_str)
+    }
+
+    /// The items of a category of a locale.
+    pub fn items(&self, lang: &str, category: &str) -> Option<&Items> {
+        self.locales.get(lang)?.get(category)
+    }
+}
+
+/// Collect the constants of the categories of the locales, the categories that are links to
+/// another locale and the shared values of `__data`, by path, from the code of a file: the root
+/// of the crate or the file of `module`.
+fn scan<'a>(
+    code: &'a str,
+    module: Option<&'a str>,
+    items: &mut BTreeMap<(String, String), Items>,
+    links: &mut BTreeMap<(String, String), (String, String)>,
+    shared: &mut BTreeMap<String, String>,
+) {
+    // The module of the file is never closed.
+    let mut modules = module
+        .map(|x| (usize::MAX, x))
+        .into_iter()
+        .collect::<Vec<_>>();
+    let mut lines = code.lines();
+
+    while let Some(line) = lines.next() {
+        let indent = line.len() - line.trim_start().len();
+        let line = line.trim();
+
+        if let Some(name) = line
+            .strip_prefix("pub mod ")
+            .and_then(|x| x.strip_suffix(" {"))
+        {
+            modules.push((indent, name));
+        } else if line == "}" {
+            if matches!(modules.last(), Some((x, _)) if *x == indent) {
+                modules.pop();
+            }
+        } else if let Some(path) = line
+            .strip_prefix("pub use super::")
+            .and_then(|x| x.strip_suffix(';'))
+        {
+            if let ([lang], Some((source, category))) = (modules.as_slice(), path.split_once("::"))
+            {
+                if category.starts_with("LC_") {
+                    links.insert(
+                        (lang.1.to_string(), category.to_string()),
+                        (source.to_string(), category.to_string()),
+                    );
+                }
+            }
+        } else if let Some(definition) = line.strip_prefix("pub const ") {
+            let mut definition = definition.to_string();
+            while !definition.ends_with(';') {
+                match lines.next() {
+                    Some(line) => {
+                        definition.push(' ');
+                        definition.push_str(line.trim());
+                    }
+                    None => break,
+                }
+            }
+            let name = definition.split(':').next().unwrap_or_default();
+            // The values written on several lines end their lists with a comma.
+            let value = definition
+                .split_once(" = ")
+                .map_or("", |x| x.1)
+                .trim_end_matches(';')
+                .replace("[ ", "[")
+                .replace(", ]", "]");
+
+            match modules.as_slice() {
+                [lang, category] if lang.1 == "__data" => {
+                    shared.insert(format!("crate::__data::{}::{}", category.1, name), value);
+                }
+                [lang, category, submodules @ ..]
+                    if category.1.starts_with("LC_") && !lang.1.starts_with("__") =>
+                {
+                    let name = submodules
+                        .iter()
+                        .map(|x| x.1)
+                        .chain(std::iter::once(name))
+                        .collect::<Vec<_>>()
+                        .join("::");
+                    items
+                        .entry((lang.1.to_string(), category.1.to_string()))
+                        .or_default()
+                        .insert(name, value);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Replace the paths of the shared values of `__data` in the value of a constant by the values.
+fn resolve_shared(value: &str, shared: &BTreeMap<String, String>) -> String {
+    const PREFIX: &str = "crate::__data::";
+    let mut resolved = String::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find(PREFIX) {
+        let len = rest[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(rest.len() - start);
+        let path = &rest[start..start + len];
+        resolved.push_str(&rest[..start]);
+        resolved.push_str(shared.get(path).map_or(path, String::as_str));
+        rest = &rest[start + len..];
+    }
+    resolved.push_str(rest);
+
+    resolved
+}
+
+/// A difference between the constants of two versions of the generated code.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// A locale only in the new version.
+    AddedLocale(String),
+    /// A locale only in the old version.
+    RemovedLocale(String),
+    /// A constant of a locale that differs. The value is `None` on the side that doesn't have
+    /// the constant.
+    Item {
+        locale: String,
+        category: String,
+        item: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::AddedLocale(lang) => write!(f, "added locale {}", lang),
+            Change::RemovedLocale(lang) => write!(f, "removed locale {}", lang),
+            Change::Item {
+                locale,
+                category,
+                item,
+                old,
+                new,
+            } => {
+                let value = |x: &Option<String>| match x {
+                    Some(x) => format!("`{}`", x),
+                    None => "(none)".to_string(),
+                };
+                write!(
+                    f,
+                    "{} {} {}: {} -> {}",
+                    locale,
+                    category,
+                    item,
+                    value(old),
+                    value(new)
+                )
+            }
+        }
+    }
+}
+
+/// Compare the constants of two versions of the generated code, by locale, category and item.
+/// The locales added or removed are reported as a whole, without their items.
+pub fn diff(old: &Constants, new: &Constants) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let langs = old
+        .locales
+        .keys()
+        .chain(new.locales.keys())
+        .collect::<BTreeSet<_>>();
+    let empty = BTreeMap::new();
+
+    for lang in langs {
+        let (old_categories, new_categories) = match (old.locales.get(lang), new.locales.get(lang))
+        {
+            (Some(old), Some(new)) => (old, new),
+            (Some(_), None) => {
+                changes.push(Change::RemovedLocale(lang.clone()));
+                continue;
+            }
+            _ => {
+                changes.push(Change::AddedLocale(lang.clone()));
+                continue;
+            }
+        };
+
+        let categories = old_categories
+            .keys()
+            .chain(new_categories.keys())
+            .collect::<BTreeSet<_>>();
+        for category in categories {
+            let old_items = old_categories.get(category).unwrap_or(&empty);
+            let new_items = new_categories.get(category).unwrap_or(&empty);
+            let items = old_items
+                .keys()
+                .chain(new_items.keys())
+                .collect::<BTreeSet<_>>();
+            for item in items {
+                let (old, new) = (old_items.get(item), new_items.get(item));
+                if old != new {
+                    changes.push(Change::Item {
+                        locale: lang.clone(),
+                        category: category.clone(),
+                        item: item.clone(),
+                        old: old.cloned(),
+                        new: new.cloned(),
+                    });
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = r#"
+pub mod __data {
+    pub mod LC_TIME {
+        pub const WEEK_0: &[i64] = &[7, 19971130, 4];
+    }
+}
+
+pub mod fr_FR {
+    pub mod LC_NUMERIC {
+        /// `","`
+        pub const DECIMAL_POINT: &str = ",";
+    }
+    pub mod LC_TIME {
+        /// `&[7, 19971130, 4]`
+        pub const WEEK: &[i64] = crate::__data::LC_TIME::WEEK_0;
+        /// ```ignore
+        /// &[
+        ///     &["a", "b"],
+        /// ]
+        /// ```
+        pub const ERA: &[&[&str]] = &[&["a", "b"]];
+
+        pub mod raw {
+            pub use super::*;
+            /// `"%d.%m.%Y"`
+            pub const D_FMT: &str = "%d.%m.%Y";
+        }
+
+        pub mod provenance {
+            pub use crate::__provenance::LC_TIME::*;
+            pub const D_FMT: crate::Provenance = crate::Provenance::Inlined;
+        }
+    }
+}
+
+pub mod fr_BE {
+    pub use super::fr_FR::LC_NUMERIC;
+}
+
+impl Locale {
+    pub fn name(self) -> &'static str {
+        ""
+    }
+}
+"#;
+
+    fn parse_code(root: &str) -> Constants {
+        let mut code = Code {
+            root: root.to_string(),
+            ..Code::default()
+        };
+        code.modules.insert(
+            "fr_CH".to_string(),
+            "pub use super::fr_BE::LC_NUMERIC;\npub mod LC_TIME {\n    /// `7`\n    pub const FIRST_WEEKDAY: i64 = 7;\n}\n".to_string(),
+        );
+        Constants::parse(&code)
+    }
+
+    #[test]
+    fn parse() {
+        let constants = parse_code(CODE);
+
+        assert_eq!(
+            constants.locales().collect::<Vec<_>>(),
+            ["fr_BE", "fr_CH", "fr_FR"]
+        );
+        assert_eq!(
+            constants.items("fr_FR", "LC_TIME").unwrap(),
+            &[
+                ("ERA", r#"&[&["a", "b"]]"#),
+                ("WEEK", "&[7, 19971130, 4]"),
+                ("provenance::D_FMT", "crate::Provenance::Inlined"),
+                ("raw::D_FMT", r#""%d.%m.%Y""#),
+            ]
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<Items>(),
+        );
+        assert_eq!(
+            constants.items("fr_BE", "LC_NUMERIC"),
+            constants.items("fr_FR", "LC_NUMERIC"),
+        );
+        assert_eq!(constants.items("fr_BE", "LC_TIME"), None);
+        assert_eq!(
+            constants.items("fr_CH", "LC_NUMERIC"),
+            constants.items("fr_FR", "LC_NUMERIC"),
+        );
+        assert_eq!(
+            constants.items("fr_CH", "LC_TIME").unwrap()["FIRST_WEEKDAY"],
+            "7"
+        );
+    }
+
+    #[test]
+    fn changes() {
+        let old = parse_code(CODE);
+        let new = parse_code(
+            &CODE
+                .replace("pub mod fr_BE {", "pub mod fr_CA {")
+                .replace(r#"`","`"#, r#"`"."`"#)
+                .replace(
+                    "pub const DECIMAL_POINT: &str = \",\";",
+                    "pub const DECIMAL_POINT: &str = \".\";",
+                )
+                .replace(
+                    "pub const D_FMT: crate::Provenance",
+                    "pub const T_FMT: crate::Provenance",
+                ),
+        );
+        let changes = diff(&old, &new);
+
+        assert_eq!(
+            changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "removed locale fr_BE",
+                "added locale fr_CA",
+                "fr_CH LC_NUMERIC DECIMAL_POINT: `\",\"` -> (none)",
+                "fr_FR LC_NUMERIC DECIMAL_POINT: `\",\"` -> `\".\"`",
+                "fr_FR LC_TIME provenance::D_FMT: `crate::Provenance::Inlined` -> (none)",
+                "fr_FR LC_TIME provenance::T_FMT: (none) -> `crate::Provenance::Inlined`",
+            ],
+        );
+        assert!(diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn values_not_documentation() {
+        let old = parse_code(CODE);
+        let new = parse_code(
+            &CODE
+                .replace(
+                    "pub const DECIMAL_POINT: &str = \",\";",
+                    "pub const DECIMAL_POINT: &str = \".\";",
+                )
+                .replace(
+                    "WEEK_0: &[i64] = &[7, 19971130, 4]",
+                    "WEEK_0: &[i64] = &[7, 19971130, 1]",
+                ),
+        );
+        let changes = diff(&old, &new);
+
+        assert_eq!(
+            changes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "fr_BE LC_NUMERIC DECIMAL_POINT: `\",\"` -> `\".\"`",
+                "fr_CH LC_NUMERIC DECIMAL_POINT: `\",\"` -> `\".\"`",
+                "fr_FR LC_NUMERIC DECIMAL_POINT: `\",\"` -> `\".\"`",
+                "fr_FR LC_TIME WEEK: `&[7, 19971130, 4]` -> `&[7, 19971130, 1]`",
+            ],
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use crate::parser::{Entry, Object, Value};
+use std::fmt::{self, Write};
+
+const COMMENT_CHAR: char = '%';
+const ESCAPE_CHAR: char = '/';
+
+/// The column after which the values of a keyword are wrapped, one per line.
+const MAX_WIDTH: usize = 79;
+
+/// Write the categories of a locale in the syntax of the locale source files of `glibc`, the
+/// input of `localedef`. Parsing the output gives the same categories.
+///
+/// The categories are written as they are, so a category that is copied from another file is a
+/// `copy`. The characters of the strings that are not printable ASCII are written as `<Uxxxx>`.
+pub fn emit(objects: &[Object]) -> String {
+    let mut output = String::new();
+    write_locale(&mut output, objects).unwrap();
+    output
+}
+
+fn write_locale(f: &mut String, objects: &[Object]) -> fmt::Result {
+    writeln!(f, "comment_char {}", COMMENT_CHAR)?;
+    writeln!(f, "escape_char {}", ESCAPE_CHAR)?;
+
+    for object in objects {
+        writeln!(f)?;
+        writeln!(f, "{}", object.name)?;
+        write_entries(f, &object.entries, &object.name, false)?;
+        writeln!(f, "END {}", object.name)?;
+    }
+
+    Ok(())
+}
+
+/// Write the entries of a category. The keys of the entries of a reordering or of an
+/// `order_start` section are all symbols, even the ones that look like keywords.
+fn write_entries(
+    f: &mut String,
+    entries: &[Entry],
+    category: &str,
+    mut symbols: bool,
+) -> fmt::Result {
+    for (i, entry) in entries.iter().enumerate() {
+        let next = entries.get(i + 1);
+        match entry {
+            Entry::Copy(file) => writeln!(f, "copy {}", string(file))?,
+            Entry::Include {
+                file,
+                repertoire: None,
+            } => writeln!(f, "include {};\"\"", string(file))?,
+            Entry::Include {
+                file,
+                repertoire: Some(repertoire),
+            } => writeln!(f, "include {};{}", string(file), string(repertoire))?,
+            Entry::Keyword(key, values) if key == "order_start" => {
+                write_keyword(f, category, key, values, false)?;
+                symbols = true;
+            }
+            Entry::Keyword(key, values) if key == "order_end" => {
+                write_keyword(f, category, key, values, false)?;
+                symbols = false;
+            }
+            Entry::Keyword(key, values) => write_keyword(f, category, key, values, symbols)?,
+            Entry::Translit(entries) => {
+                writeln!(f, "translit_start")?;
+                write_entries(f, entries, category, false)?;
+                writeln!(f, "translit_end")?;
+            }
+            Entry::ReorderAfter { symbol, entries } => {
+                writeln!(f, "reorder-after {}", raw(symbol))?;
+                write_entries(f, entries, category, true)?;
+                // The reorderings that follow each other end together: `localedef` does not
+                // accept a `reorder-after` after a `reorder-end`.
+                if !matches!(next, Some(Entry::ReorderAfter { .. })) {
+                    writeln!(f, "reorder-end")?;
+                }
+            }
+            Entry::ReorderSectionsAfter { section, entries } => {
+                writeln!(f, "reorder-sections-after {}", raw(section))?;
+                write_entries(f, entries, category, true)?;
+                writeln!(f, "reorder-sections-end")?;
+            }
+            Entry::Script(name) => writeln!(f, "script <{}>", name)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a keyword and its values on a line, or one value per line if it is too long.
+fn write_keyword(
+    f: &mut String,
+    category: &str,
+    key: &str,
+    values: &[Value],
+    symbol: bool,
+) -> fmt::Result {
+    let key = match key {
+        ".." | "UNDEFINED" => key.to_string(),
+        _ if !symbol
+            && key
+                .chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-')) =>
+        {
+            key.to_string()
+        }
+        _ => format!("<{}>", key),
+    };
+    // The characters of the strings of `LC_COLLATE` must all be symbols.
+    let escape_all = category == "LC_COLLATE";
+    let values = values
+        .iter()
+        .map(|x| value(x, escape_all))
+        .collect::<Vec<_>>();
+    // `collating-element <name> from "<string>"` is the only keyword of which the values are
+    // separated by spaces.
+    if key == "collating-element" {
+        return writeln!(f, "{} {}", key, values.join(" "));
+    }
+    let line = values.join(";");
+
+    if values.is_empty() {
+        writeln!(f, "{}", key)?;
+    } else if key.len() + 1 + line.len() <= MAX_WIDTH || values.len() == 1 {
+        writeln!(f, "{} {}", key, line)?;
+    } else {
+        let separator = format!(";{}\n{:width$}", ESCAPE_CHAR, "", width = key.len() + 1);
+        writeln!(f, "{} {}", key, values.join(&separator))?;
+    }
+    // `localedef` reads the end of the line after fewer than 100 `alt_digits` and takes the next
+    // line as part of them.
+    if key == "alt_digits" && values.len() < 100 {
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn value(value: &Value, escape_all: bool) -> String {
+    match value {
+        Value::String(x) => escape_string(x, escape_all),
+        Value::Integer(x) => x.to_string(),
+        _ => raw(&value.to_string()),
+    }
+}
+
+/// A string between quotes, with `<Uxxxx>` for the characters that can't be written as is.
+fn string(string: &str) -> String {
+    escape_string(string, false)
+}
+
+/// A string between quotes, with all its characters written as `<Uxxxx>` if `escape_all` is
+/// set.
+///
+/// The symbolic names between angle brackets, like the weights `"<BASE>"` of `LC_COLLATE`, are
+/// kept as they are: the parser leaves them in the strings. This makes the characters `<` and `>`
+/// around a name, which the parser reads the same way, a symbol too.
+fn escape_string(string: &str, escape_all: bool) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    let mut chars = string.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if let Some(symbol) = symbol(&string[i..]) {
+            quoted.push_str(symbol);
+            chars.nth(symbol.len() - 2);
+            continue;
+        }
+        match c {
+            '"' | '<' | '>' | ESCAPE_CHAR => write!(quoted, "<U{:04X}>", c as u32).unwrap(),
+            ' '..='~' if !escape_all => quoted.push(c),
+            _ if (c as u32) <= 0xffff => write!(quoted, "<U{:04X}>", c as u32).unwrap(),
+            _ => write!(quoted, "<U{:08X}>", c as u32).unwrap(),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The symbolic name at the start of a string, with its angle brackets, if there is one that is
+/// not a `<Uxxxx>` escape.
+fn symbol(string: &str) -> Option<&str> {
+    let end = string.strip_prefix('<')?.find('>')? + 1;
+    let name = &string[1..end];
+    let is_escape =
+        name.len() > 1 && name.starts_with('U') && name[1..].chars().all(|c| c.is_ascii_hexdigit());
+
+    if name.is_empty()
+        || is_escape
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_graphic() && !matches!(c, '<' | '"' | ESCAPE_CHAR))
+    {
+        return None;
+    }
+    Some(&string[..=end])
+}
+
+/// A token other than a string, with the characters that would end it escaped.
+fn raw(token: &str) -> String {
+    let mut escaped = String::with_capacity(token.len());
+    for c in token.chars() {
+        if matches!(
+            c,
+            ' ' | '\t' | '\r' | '\n' | ';' | COMMENT_CHAR | ESCAPE_CHAR
+        ) {
+            escaped.push(ESCAPE_CHAR);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+    use std::path::Path;
+
+    #[test]
+    fn emit_time() {
+        let objects = parser::parse(
+            "comment_char %\nescape_char /\nLC_TIME\nabday \"dim\";\"lun\"\nd_fmt \"<U0025>d.%m\"\nalt_digits \"0\";\"1\"\nweek 7;19971130;4\nEND LC_TIME\n\nLC_NUMERIC\ncopy \"fr_FR\"\nEND LC_NUMERIC\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            emit(&objects),
+            "comment_char %\nescape_char /\n\nLC_TIME\nabday \"dim\";\"lun\"\nd_fmt \"%d.%m\"\nalt_digits \"0\";\"1\"\n\nweek 7;19971130;4\nEND LC_TIME\n\nLC_NUMERIC\ncopy \"fr_FR\"\nEND LC_NUMERIC\n",
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            string("déc. \"1/2\" <😀>"),
+            "\"d<U00E9>c. <U0022>1<U002F>2<U0022> <U003C><U0001F600><U003E>\""
+        );
+        assert_eq!(string("<BASE><U0041>"), "\"<BASE><U003C>U0041<U003E>\"");
+        assert_eq!(raw("<U0041>;%/"), "<U0041>/;/%//");
+    }
+
+    #[test]
+    fn collate() {
+        let input = "LC_COLLATE\ncopy \"iso14651_t1\"\ncollating-element <ae> from \"<U0061><U0065>\"\nreorder-after <RES-1>\n<space>\nreorder-after <AFTER-N>\n<n-tilde>\n<U00F1> <n-tilde>;\"<BASE><BASE>\";\"AE\";IGNORE\nreorder-end\nEND LC_COLLATE\n";
+        let objects = parser::parse(input).unwrap();
+        let output = emit(&objects);
+
+        assert_eq!(
+            output,
+            "comment_char %\nescape_char /\n\nLC_COLLATE\ncopy \"iso14651_t1\"\ncollating-element <ae> from \"<U0061><U0065>\"\nreorder-after <RES-1>\n<space>\nreorder-after <AFTER-N>\n<n-tilde>\n<U00F1> <n-tilde>;\"<BASE><BASE>\";\"<U0041><U0045>\";IGNORE\nreorder-end\nEND LC_COLLATE\n",
+        );
+        assert_eq!(parser::parse(&output).unwrap(), objects);
+    }
+
+    #[test]
+    fn wrap() {
+        let objects = parser::parse(&format!(
+            "LC_TIME\nmon {}\nEND LC_TIME\n",
+            ["\"janvier\""; 12].join(";")
+        ))
+        .unwrap();
+        let output = emit(&objects);
+
+        assert!(output.contains("mon \"janvier\";/\n    \"janvier\";/\n"));
+        assert_eq!(parser::parse(&output).unwrap(), objects);
+    }
+
+    #[test]
+    fn round_trip_localedata() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../localedata/locales");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let objects = match std::fs::read_to_string(&path) {
+                Ok(input) => parser::parse(&input).unwrap(),
+                Err(_) => continue,
+            };
+            let output = emit(&objects);
+
+            match parser::parse(&output) {
+                Ok(parsed) => assert!(parsed == objects, "{}", path.display()),
+                Err(err) => panic!("{}: {}", path.display(), err),
+            }
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use crate::fixup::{Changes, Provenance};
+use crate::inheritance::Inheritance;
+use crate::parser::{self, Object, Value};
+use serde_json::{json, Map, Value as Json};
+use std::collections::{BTreeMap, HashMap};
+
+/// The version of the schema of the exported files. It changes whenever a file could be read
+/// differently, not when items or locales are added.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The name of the file listing the exported locales.
+pub const INDEX_FILE: &str = "index.json";
+
+/// The categories that have no items but character classes and collation rules: only their
+/// copies are exported.
+const CATEGORIES_WITHOUT_ITEMS: &[&str] = &["LC_COLLATE", "LC_CTYPE"];
+
+/// How the values of an item are written, the same for every locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Shape {
+    /// A single value.
+    Value,
+    /// The values of the keyword.
+    Array,
+    /// The values of each occurrence of the keyword.
+    Array2d,
+}
+
+/// The shape and the type of an item: a number if all its values are integers in every locale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meta {
+    shape: Shape,
+    number: bool,
+}
+
+/// The data of the locales, with their copies resolved and the fixups applied, as JSON.
+pub struct Exporter<'a> {
+    locales: &'a HashMap<String, Vec<Object>>,
+    changes: &'a HashMap<String, Changes>,
+    inheritance: &'a Inheritance,
+    metadata: BTreeMap<(String, String), Meta>,
+}
+
+impl<'a> Exporter<'a> {
+    /// Create an exporter for the locales resolved by `overlay::resolve_copies`, with the
+    /// `changes` of the fixups and the `inheritance` of the locales before they were resolved.
+    pub fn new(
+        locales: &'a HashMap<String, Vec<Object>>,
+        changes: &'a HashMap<String, Changes>,
+        inheritance: &'a Inheritance,
+    ) -> Self {
+        let mut exporter = Self {
+            locales,
+            changes,
+            inheritance,
+            metadata: BTreeMap::new(),
+        };
+
+        for (lang, objects) in locales.iter() {
+            for object in objects {
+                if CATEGORIES_WITHOUT_ITEMS.contains(&object.name.as_str()) {
+                    continue;
+                }
+                for (key, lines) in exporter.category_items(lang, &object.name) {
+                    let meta = Meta {
+                        shape: match lines.as_slice() {
+                            [values] if values.len() == 1 => Shape::Value,
+                            [_] => Shape::Array,
+                            _ => Shape::Array2d,
+                        },
+                        number: lines
+                            .iter()
+                            .flat_map(|x| x.iter())
+                            .all(|x| matches!(x, Value::Integer(_))),
+                    };
+                    exporter
+                        .metadata
+                        .entry((object.name.clone(), key.to_string()))
+                        .and_modify(|x| {
+                            x.shape = x.shape.max(meta.shape);
+                            x.number &= meta.number;
+                        })
+                        .or_insert(meta);
+                }
+            }
+        }
+
+        exporter
+    }
+
+    /// The file of a locale: its categories with the files they copy, their items and the
+    /// items changed by a fixup.
+    pub fn locale(&self, lang: &str) -> Json {
+        let mut categories = Map::new();
+
+        for object in self.locales[lang].iter() {
+            let source = self.inheritance.source_of(lang, &object.name);
+            let mut category = Map::new();
+            category.insert(
+                "copies".to_string(),
+                json!(self.inheritance.chain(lang, &object.name)),
+            );
+            category.insert("source".to_string(), json!(source));
+
+            if !CATEGORIES_WITHOUT_ITEMS.contains(&object.name.as_str()) {
+                category.insert("items".to_string(), self.items(lang, &object.name));
+
+                let provenance = self
+                    .changes
+                    .get(source)
+                    .into_iter()
+                    .flatten()
+                    .filter(|((name, _), _)| *name == object.name)
+                    .map(|((_, key), provenance)| {
+                        (key.clone(), json!(provenance_name(*provenance)))
+                    })
+                    .collect::<Map<_, _>>();
+                if !provenance.is_empty() {
+                    category.insert("provenance".to_string(), Json::Object(provenance));
+                }
+            }
+
+            categories.insert(object.name.clone(), Json::Object(category));
+        }
+
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "locale": lang,
+            "categories": categories,
+        })
+    }
+
+    /// The list of the locales, with the name of their file and the parts of their name.
+    pub fn index(&self) -> Json {
+        let locales = self
+            .inheritance
+            .locales()
+            .iter()
+            .map(|lang| {
+                let (language, territory, modifier) = parser::parse_lang(lang).unwrap();
+                let entry = json!({
+                    "file": file_name(lang),
+                    "language": language,
+                    "territory": territory,
+                    "modifier": modifier,
+                });
+                (lang.clone(), entry)
+            })
+            .collect::<Map<_, _>>();
+
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "locales": locales,
+        })
+    }
+
+    fn items(&self, lang: &str, category: &str) -> Json {
+        let mut items = Map::new();
+
+        for (key, lines) in self.category_items(lang, category) {
+            let meta = self.metadata[&(category.to_string(), key.to_string())];
+            let convert = |values: &[Value]| {
+                values
+                    .iter()
+                    .map(|x| match x {
+                        Value::Integer(x) if meta.number => json!(x),
+                        _ => json!(x.to_string()),
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let value = match meta.shape {
+                Shape::Value => convert(lines[0]).remove(0),
+                Shape::Array => json!(convert(lines[0])),
+                Shape::Array2d => json!(lines.iter().map(|x| convert(x)).collect::<Vec<_>>()),
+            };
+            items.insert(key.to_string(), value);
+        }
+
+        Json::Object(items)
+    }
+
+    /// The values of each occurrence of the keywords of a category of a file, following its
+    /// copy: the keywords of the file replace the ones of the file copied.
+    fn category_items(&self, name: &str, category: &str) -> BTreeMap<&'a str, Vec<&'a [Value]>> {
+        let mut items = BTreeMap::<&str, Vec<&[Value]>>::new();
+        let mut chain = vec![name];
+        let mut name = name;
+
+        // The categories of `chain` from the one that copies no other to the one of `name`.
+        let mut objects = Vec::new();
+        while let Some(object) = self.object(name, category) {
+            objects.push(object);
+            match object.copied_from() {
+                Some(source) if !chain.contains(&source) => {
+                    chain.push(source);
+                    name = source;
+                }
+                _ => break,
+            }
+        }
+
+        for object in objects.into_iter().rev() {
+            let mut redefined = BTreeMap::<&str, Vec<&[Value]>>::new();
+            for (key, values) in object.keywords().filter(|(_, values)| !values.is_empty()) {
+                redefined.entry(key).or_default().push(values);
+            }
+            items.extend(redefined);
+        }
+
+        items
+    }
+
+    /// A category of a locale or of a file copied.
+    fn object(&self, name: &str, category: &str) -> Option<&'a Object> {
+        let objects = match self.locales.get(name) {
+            Some(objects) => objects.as_slice(),
+            None => self.inheritance.file(name)?,
+        };
+        objects.iter().find(|x| x.name == category)
+    }
+}
+
+/// The name of the file of a locale.
+pub fn file_name(lang: &str) -> String {
+    format!("{}.json", lang)
+}
+
+fn provenance_name(provenance: Provenance) -> &'static str {
+    match provenance {
+        Provenance::Inlined => "inlined",
+        Provenance::Synthesized => "synthesized",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlay;
+
+    fn locale(body: &str) -> Vec<Object> {
+        parser::parse(&format!("comment_char %\nescape_char /\n{}", body)).unwrap()
+    }
+
+    #[test]
+    fn export() {
+        let mut locales = HashMap::new();
+        locales.insert(
+            "fr_FR".to_string(),
+            locale("LC_TIME\nabday \"dim.\";\"lun.\"\nd_fmt \"%d.%m.%Y\"\nweek 7;19971130;4\nEND LC_TIME\nLC_CTYPE\ncopy \"i18n\"\nEND LC_CTYPE\n"),
+        );
+        locales.insert(
+            "fr_BE".to_string(),
+            locale(
+                "LC_TIME\ncopy \"fr_FR\"\nEND LC_TIME\nLC_CTYPE\ncopy \"fr_FR\"\nEND LC_CTYPE\n",
+            ),
+        );
+        locales.insert(
+            "fr_CA".to_string(),
+            locale("LC_TIME\ncopy \"fr_FR\"\nd_fmt \"%Y-%m-%d\"\nweek \"x\"\nEND LC_TIME\n"),
+        );
+        let inheritance = Inheritance::new(&locales, &[]).unwrap();
+        overlay::resolve_copies(&mut locales).unwrap();
+        let mut changes = HashMap::new();
+        changes.insert(
+            "fr_FR".to_string(),
+            Changes::from([(
+                ("LC_TIME".to_string(), "d_fmt".to_string()),
+                Provenance::Inlined,
+            )]),
+        );
+        let exporter = Exporter::new(&locales, &changes, &inheritance);
+
+        assert_eq!(
+            exporter.locale("fr_BE"),
+            json!({
+                "schema_version": 1,
+                "locale": "fr_BE",
+                "categories": {
+                    "LC_CTYPE": {"copies": ["fr_FR", "i18n"], "source": "fr_FR"},
+                    "LC_TIME": {
+                        "copies": ["fr_FR"],
+                        "source": "fr_FR",
+                        "items": {
+                            "abday": ["dim.", "lun."],
+                            "d_fmt": "%d.%m.%Y",
+                            "week": ["7", "19971130", "4"],
+                        },
+                        "provenance": {"d_fmt": "inlined"},
+                    },
+                },
+            }),
+        );
+        assert_eq!(
+            exporter.locale("fr_CA")["categories"]["LC_TIME"],
+            json!({
+                "copies": ["fr_FR"],
+                "source": "fr_CA",
+                "items": {
+                    "abday": ["dim.", "lun."],
+                    "d_fmt": "%Y-%m-%d",
+                    "week": ["x"],
+                },
+            }),
+        );
+        assert_eq!(
+            exporter.index()["locales"]["fr_BE"],
+            json!({"file": "fr_BE.json", "language": "fr", "territory": "BE", "modifier": null}),
+        );
+    }
+}
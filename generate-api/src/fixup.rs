@@ -0,0 +1,214 @@
+use crate::parser::{Entry, Object, Value};
+use anyhow::{bail, Result};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A pass that changes the data of `glibc` to make it easier to use.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fixup {
+    /// See [`validate_and_fix_t_fmt_ampm`].
+    TFmtAmpm,
+    /// See [`validate_and_fix_d_t_fmt`].
+    DTFmt,
+}
+
+impl Fixup {
+    /// All the fixups, in the order they are applied.
+    pub const ALL: &'static [Fixup] = &[Fixup::TFmtAmpm, Fixup::DTFmt];
+
+    /// The name of the fixup on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Fixup::TFmtAmpm => "t_fmt_ampm",
+            Fixup::DTFmt => "d_t_fmt",
+        }
+    }
+}
+
+impl std::str::FromStr for Fixup {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match Fixup::ALL.iter().find(|fixup| fixup.name() == s) {
+            Some(fixup) => Ok(*fixup),
+            None => bail!(
+                "unknown fixup `{}`, expected one of: {}",
+                s,
+                Fixup::ALL
+                    .iter()
+                    .map(|x| x.name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Where the value of an item comes from, when it is not the upstream value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Provenance {
+    /// The upstream value refers to other items and they have been inlined.
+    Inlined,
+    /// The item is missing or empty upstream and its value has been made up.
+    Synthesized,
+}
+
+/// The items changed by the fixups of a locale, by category and keyword.
+pub type Changes = BTreeMap<(String, String), Provenance>;
+
+/// Apply the selected fixups to the categories of a locale and return the items they changed.
+pub fn validate_and_fix(objects: &mut [Object], fixups: &BTreeSet<Fixup>) -> Changes {
+    let mut changes = Changes::new();
+
+    if fixups.contains(&Fixup::TFmtAmpm) {
+        validate_and_fix_t_fmt_ampm(objects, &mut changes);
+    }
+    if fixups.contains(&Fixup::DTFmt) {
+        validate_and_fix_d_t_fmt(objects, &mut changes);
+    }
+
+    changes
+}
+
+/// Add a `T_FMT_AMPM` item if it is missing or empty.
+///
+/// If the locale has non-empty values for `AM_PM` we assume the correct string to be the same as
+/// for POSIX: `%l:%M:%S %p`.
+/// If the locale has empty values for `AM_PM` we set `T_FMT_AMPM` to an empty value, similar to
+/// other locales that don't have a 12-hour clock format.
+fn validate_and_fix_t_fmt_ampm(objects: &mut [Object], changes: &mut Changes) {
+    for object in objects.iter_mut() {
+        if object.name != "LC_TIME" {
+            continue;
+        }
+        let mut found_t_fmt_ampm = object.copied_from().is_some();
+        let mut am_pm_empty = false;
+        for (key, value) in object.keywords() {
+            match (key, value) {
+                ("t_fmt_ampm" | "insert", _) => found_t_fmt_ampm = true,
+                ("am_pm", &[Value::String(ref am), Value::String(ref pm)]) => {
+                    am_pm_empty = am.is_empty() && pm.is_empty()
+                }
+                _ => {}
+            }
+        }
+        if !found_t_fmt_ampm {
+            // Insert `T_FMT_AMPM` if it is missing
+            let value = match am_pm_empty {
+                true => vec![Value::String(String::new())],
+                false => vec![Value::String("%l:%M:%S %p".to_string())],
+            };
+            object
+                .entries
+                .push(Entry::Keyword("t_fmt_ampm".to_string(), value));
+            changes.insert(
+                (object.name.clone(), "t_fmt_ampm".to_string()),
+                Provenance::Synthesized,
+            );
+        } else if !am_pm_empty {
+            // Update `T_FMT_AMPM` if it is empty and we do have a value for `AM_PM`.
+            for (key, value) in object.keywords_mut() {
+                match (key, value.as_slice()) {
+                    ("t_fmt_ampm", &[Value::String(ref t_fmt_ampm)]) if t_fmt_ampm.is_empty() => {
+                        *value = vec![Value::String("%l:%M:%S %p".to_string())];
+                        changes.insert(
+                            ("LC_TIME".to_string(), key.to_string()),
+                            Provenance::Synthesized,
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// In some locales `D_T_FMT` refers to other items:
+/// to `D_FMT` with `%x`, `T_FMT` with `%X`, and/or `T_FMT_AMPM` with `%r`.
+/// In the same way `T_FMT` can refer `T_FMT_AMPM` with `%r`.
+/// Inlining these strings simplifies the implementation of the strftime parser in chrono.
+fn validate_and_fix_d_t_fmt(objects: &mut [Object], changes: &mut Changes) {
+    for object in objects.iter_mut() {
+        if object.name != "LC_TIME" {
+            continue;
+        }
+        let mut d_fmt = String::new();
+        let mut t_fmt = String::new();
+        let mut t_fmt_ampm = String::new();
+        for (key, value) in object.keywords() {
+            match (key, value) {
+                ("d_fmt", &[Value::String(ref value)]) => d_fmt = value.clone(),
+                ("t_fmt", &[Value::String(ref value)]) => t_fmt = value.clone(),
+                ("t_fmt_ampm", &[Value::String(ref value)]) => t_fmt_ampm = value.clone(),
+                _ => {}
+            }
+        }
+        if t_fmt_ampm.is_empty() {
+            t_fmt_ampm = "%T".to_string();
+        }
+        for (key, vec) in object.keywords_mut() {
+            if key == "d_t_fmt" || key == "t_fmt" {
+                if let Value::String(ref val) = vec[0] {
+                    let fmt = val
+                        .replace("%x", &d_fmt)
+                        .replace("%X", &t_fmt)
+                        .replace("%r", &t_fmt_ampm);
+                    if &fmt != val {
+                        changes.insert(
+                            ("LC_TIME".to_string(), key.to_string()),
+                            Provenance::Inlined,
+                        );
+                    }
+                    vec[0] = Value::String(fmt);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    fn time(body: &str) -> Vec<Object> {
+        parser::parse(&format!(
+            "comment_char %\nescape_char /\nLC_TIME\n{}END LC_TIME\n",
+            body
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn provenance() {
+        let mut objects = time("am_pm \"AM\";\"PM\"\nd_fmt \"%d.%m\"\nd_t_fmt \"%x %r\"\n");
+        let changes = validate_and_fix(&mut objects, &Fixup::ALL.iter().copied().collect());
+
+        assert_eq!(
+            changes.into_iter().collect::<Vec<_>>(),
+            vec![
+                (
+                    ("LC_TIME".to_string(), "d_t_fmt".to_string()),
+                    Provenance::Inlined,
+                ),
+                (
+                    ("LC_TIME".to_string(), "t_fmt_ampm".to_string()),
+                    Provenance::Synthesized,
+                ),
+            ],
+        );
+        assert!(objects[0].keywords().any(|(key, value)| key == "d_t_fmt"
+            && value == [Value::String("%d.%m %l:%M:%S %p".to_string())]));
+    }
+
+    #[test]
+    fn select() {
+        let mut objects = time("am_pm \"AM\";\"PM\"\nd_fmt \"%d.%m\"\nd_t_fmt \"%x %r\"\n");
+        let changes = validate_and_fix(&mut objects, &[Fixup::DTFmt].iter().copied().collect());
+
+        assert_eq!(changes.len(), 1);
+        assert!(objects[0].keywords().all(|(key, _)| key != "t_fmt_ampm"));
+        assert!(objects[0].keywords().any(
+            |(key, value)| key == "d_t_fmt" && value == [Value::String("%d.%m %T".to_string())]
+        ));
+    }
+}
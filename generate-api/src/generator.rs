@@ -1,10 +1,17 @@
 use std::borrow::Cow;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Formatter, Write};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 
 use indenter::CodeFormatter;
 use itertools::Itertools;
 
+use crate::blob;
+use crate::fixup::{Changes, Provenance};
+use crate::ids::Registry;
+use crate::inheritance::Inheritance;
 use crate::parser;
 
 type Key = String;
@@ -15,14 +22,83 @@ pub struct CodeGenerator {
     by_language: BTreeMap<Lang, BTreeMap<Key, Category>>,
     field_metadata: BTreeMap<Key, BTreeMap<Field, Meta>>,
     normalized_langs: BTreeMap<Lang, String>,
+    features: BTreeMap<Lang, BTreeSet<String>>,
+    module_features: BTreeMap<Lang, BTreeSet<String>>,
+    /// Upstream values of the fields changed by a fixup in at least one locale.
+    raw_fields: BTreeMap<Lang, BTreeMap<Key, BTreeMap<Field, Value>>>,
+    raw_metadata: BTreeMap<Key, BTreeMap<Field, Meta>>,
+    /// Fields whose value is not the upstream value.
+    provenance: BTreeMap<Lang, BTreeMap<Key, BTreeMap<Field, Provenance>>>,
+    inheritance: Inheritance,
+    /// The keyword of the source files of each field.
+    keywords: BTreeMap<Key, BTreeMap<Field, String>>,
+    /// The version of `glibc` the locales were imported from.
+    glibc_version: Option<String>,
+    /// The discriminant of each variant of `Locale`.
+    ids: Registry,
+    /// The directory of the modules that are not generated, when the code is included in
+    /// another crate.
+    support_dir: Option<PathBuf>,
 }
 
+/// The directory of the generated modules, next to the root of the crate.
+pub const GENERATED_DIR: &str = "generated";
+
+/// The module of the values shared by several locales.
+const DATA_MODULE: &str = "__data";
+
+/// The file of `Locale` and its implementations, included in the root of the crate.
+const LOCALE_MODULE: &str = "locale";
+
+/// The file of the blob of the locale data in `GENERATED_DIR`, read by the `blob` module.
+pub const BLOB_FILE: &str = "locales.blob";
+
+/// The categories of which the items are generated. Each one has a module and a trait even if no
+/// locale defines it, for the modules that are not generated, like `LocaleProvider`.
+const CATEGORIES: &[&str] = &[
+    "LC_ADDRESS",
+    "LC_IDENTIFICATION",
+    "LC_MESSAGES",
+    "LC_MONETARY",
+    "LC_NUMERIC",
+    "LC_TELEPHONE",
+    "LC_TIME",
+];
+
+/// The modules of the crate that are not generated, with their source.
+pub const SUPPORT_MODULES: &[(&str, &str)] = &[
+    ("blob", include_str!("../../src/blob.rs")),
+    ("compiled", include_str!("../../src/compiled.rs")),
+    ("error", include_str!("../../src/error.rs")),
+    ("inheritance", include_str!("../../src/inheritance.rs")),
+    ("list", include_str!("../../src/list.rs")),
+    ("locale_set", include_str!("../../src/locale_set.rs")),
+    ("localedef", include_str!("../../src/localedef.rs")),
+    ("provenance", include_str!("../../src/provenance.rs")),
+    ("provider", include_str!("../../src/provider.rs")),
+    ("resolve", include_str!("../../src/resolve.rs")),
+];
+
+/// Cargo feature enabling every locale.
+pub const ALL_LOCALES_FEATURE: &str = "all-locales";
+
+/// Cargo feature enabling the locales of `MINIMAL_LOCALES` only.
+pub const MINIMAL_FEATURE: &str = "minimal";
+
+const MINIMAL_LOCALES: &[&str] = &["en_US"];
+
+/// The length of the longest locale name `Locale` can parse: the names are compared as `u128`.
+const LOCALE_KEY_LEN: usize = 16;
+
+/// Locale that is always compiled in because it is the default `Locale`.
+const DEFAULT_LOCALE: &str = "POSIX";
+
 enum Category {
     Link(String, String),
     Fields(BTreeMap<Field, Value>),
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 enum Value {
     Empty,
     Literal(String),
@@ -63,6 +139,50 @@ impl<'a> std::fmt::Display for TypeFormatter<'a> {
     }
 }
 
+/// The type of an item returned by the `Dyn*Info` traits: the lists are [`Strs`], [`Ints`] and
+/// [`Table`] so that they can borrow owned data.
+struct DynTypeFormatter<'a> {
+    meta: &'a Meta,
+}
+
+impl<'a> std::fmt::Display for DynTypeFormatter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ty = match (self.meta.container_ty, self.meta.ty) {
+            (ContainerType::Singleton, Some(ty)) => ty.to_string(),
+            (ContainerType::Array, Some(Type::String)) => "Strs<'_>".to_string(),
+            (ContainerType::Array, Some(Type::Integer)) => "Ints<'_>".to_string(),
+            (ContainerType::Array2D, Some(Type::String)) => "Table<'_>".to_string(),
+            (ContainerType::Array2D, Some(Type::Integer)) => unreachable!("no table of integers"),
+            (_, None) => unreachable!(),
+        };
+
+        match self.meta.optional {
+            true => write!(f, "Option<{}>", ty),
+            false => write!(f, "{}", ty),
+        }
+    }
+}
+
+/// Converts the value of an item, given by `expr` as a constant or a field, to its type in the
+/// `Dyn*Info` traits.
+fn to_dyn(meta: &Meta, expr: &str, owned: bool) -> String {
+    match (meta.container_ty, meta.optional, owned) {
+        (ContainerType::Singleton, _, false) => expr.to_string(),
+        (ContainerType::Singleton, false, true) if meta.ty == Some(Type::Integer) => {
+            expr.to_string()
+        }
+        (ContainerType::Singleton, false, true) => format!("&{}", expr),
+        (ContainerType::Singleton, true, true) if meta.ty == Some(Type::Integer) => {
+            expr.to_string()
+        }
+        (ContainerType::Singleton, true, true) => format!("{}.as_deref()", expr),
+        (_, false, false) => format!("{}.into()", expr),
+        (_, false, true) => format!("{}.as_slice().into()", expr),
+        (_, true, false) => format!("{}.map(Into::into)", expr),
+        (_, true, true) => format!("{}.as_deref().map(Into::into)", expr),
+    }
+}
+
 struct ValueFormatter<'a> {
     value: &'a Value,
     meta: &'a Meta,
@@ -137,6 +257,33 @@ impl<'a> std::fmt::Display for ValueFormatter<'a> {
     }
 }
 
+/// Writes a value without the `Option` wrapper, as stored in the shared data module.
+struct BareValueFormatter<'a> {
+    value: &'a Value,
+    ty: &'a Type,
+}
+
+impl<'a> std::fmt::Display for BareValueFormatter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        ValueFormatter::format(f, self.value, self.ty)
+    }
+}
+
+/// Writes the type of a value without the `Option` wrapper.
+struct BareTypeFormatter<'a> {
+    meta: &'a Meta,
+}
+
+impl<'a> std::fmt::Display for BareTypeFormatter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        TypeFormatter::new(&Meta {
+            optional: false,
+            ..*self.meta
+        })
+        .fmt(f)
+    }
+}
+
 struct LiteralFormatter<'a> {
     value: &'a str,
     ty: &'a Type,
@@ -157,6 +304,34 @@ impl<'a> std::fmt::Display for LiteralFormatter<'a> {
     }
 }
 
+/// Writes the `cfg` predicate selecting a locale from its Cargo features.
+struct CfgFormatter<'a> {
+    features: &'a BTreeSet<String>,
+}
+
+impl<'a> CfgFormatter<'a> {
+    fn new(features: &'a BTreeSet<String>) -> Self {
+        Self { features }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+}
+
+impl<'a> std::fmt::Display for CfgFormatter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "any({})",
+            self.features
+                .iter()
+                .map(|x| format!("feature = {:?}", x))
+                .join(", "),
+        )
+    }
+}
+
 impl Value {
     fn with_fixed_type<'a>(&'a self, meta: &Meta) -> Cow<'a, Self> {
         match meta.container_ty {
@@ -180,10 +355,16 @@ impl Value {
         }
     }
 
+    /// Returns `true` if the value can be shared between locales.
+    fn is_array(&self) -> bool {
+        matches!(self, Value::Array(_) | Value::Array2d(_))
+    }
+
     fn generate<W: Write>(
         &self,
         field_name: &str,
         meta: &Meta,
+        shared: Option<&str>,
         f: &mut CodeFormatter<W>,
     ) -> std::fmt::Result {
         let ty = meta.ty.as_ref().unwrap();
@@ -232,25 +413,59 @@ impl Value {
             }
         }
 
-        write!(
-            f,
-            r#"
-            pub const {key}: {ty} = {val};
-            "#,
-            key = field_name,
-            ty = type_formatter,
-            val = value_formatter,
-        )?;
+        match shared {
+            Some(path) if meta.optional => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = Some({path});
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                path = path,
+            )?,
+            Some(path) => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = {path};
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                path = path,
+            )?,
+            None => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = {val};
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                val = value_formatter,
+            )?,
+        }
 
         Ok(())
     }
 }
 
 impl CodeGenerator {
-    pub fn new(objects: HashMap<String, Vec<parser::Object>>) -> Self {
+    /// Create a generator for the locales with their fixups applied. `raw` has the same locales
+    /// before the fixups, `changes` the items the fixups changed and `inheritance` the copies of
+    /// the locales before they were resolved. `glibc_version` is the version of `glibc` the
+    /// locales were imported from, if known.
+    pub fn new(
+        objects: HashMap<String, Vec<parser::Object>>,
+        raw: &HashMap<String, Vec<parser::Object>>,
+        changes: &HashMap<String, Changes>,
+        inheritance: Inheritance,
+        glibc_version: Option<String>,
+    ) -> Self {
         let mut by_language = BTreeMap::<Lang, BTreeMap<Key, Category>>::new();
         let mut field_metadata = BTreeMap::<Key, BTreeMap<Field, Meta>>::new();
         let mut normalized_langs = BTreeMap::<Lang, String>::new();
+        let mut keywords = BTreeMap::<Key, BTreeMap<Field, String>>::new();
+        for category_name in CATEGORIES {
+            field_metadata.insert(category_name.to_string(), BTreeMap::new());
+        }
 
         for (lang, objects) in objects.iter() {
             normalized_langs.insert(lang.to_string(), lang.replace('@', "_"));
@@ -265,103 +480,19 @@ impl CodeGenerator {
                     || object.name == "LC_NAME"
                 {
                     continue;
-                } else if object.values.len() == 1 {
-                    let (key, value) = &object.values[0];
-                    #[allow(clippy::single_match)]
-                    match key.as_str() {
-                        "copy" => {
-                            assert_eq!(value.len(), 1);
-
-                            match &value[0] {
-                                parser::Value::String(x) => {
-                                    lang_categories.insert(
-                                        object.name.clone(),
-                                        Category::Link(x.replace('@', "_"), object.name.clone()),
-                                    );
-                                }
-                                x => panic!("unexpected value for key {}: {:?}", key, x),
-                            }
-                        }
-                        _ => {}
-                    }
+                } else if let [parser::Entry::Copy(source)] = object.entries.as_slice() {
+                    lang_categories.insert(
+                        object.name.clone(),
+                        Category::Link(source.replace('@', "_"), object.name.clone()),
+                    );
                     continue;
                 }
 
-                let mut fields = BTreeMap::<Field, Value>::new();
-
                 let cat_field_meta = field_metadata.entry(object.name.clone()).or_default();
-
-                for (key, group) in &object
-                    .values
-                    .iter()
-                    .filter(|x| !x.1.is_empty())
-                    .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
-                    .group_by(|x| x.0.clone())
-                {
-                    let key = key
-                        .replace(['\'', '\"'], "")
-                        .replace('-', "_")
-                        .replace('=', "eq")
-                        .replace('<', "lt")
-                        .replace("..", "dotdot")
-                        .replace('2', "two")
-                        .to_uppercase();
-                    let group: Vec<_> = group.map(|x| &x.1).collect();
-
-                    let meta = cat_field_meta.entry(key.clone()).or_insert(Meta::new());
-
-                    if group.len() == 1 && group[0].is_empty() {
-                        meta.make_optional();
-                        fields.insert(key, Value::Empty);
-                    } else if group.len() == 1 && group[0].len() == 1 {
-                        let singleton = &group[0][0];
-
-                        match singleton {
-                            parser::Value::Raw(_) | parser::Value::String(_) => meta.mark_str(),
-                            parser::Value::Integer(_) => meta.mark_int(),
-                        }
-
-                        fields.insert(key, Value::Literal(singleton.to_string()));
-                    } else if group.len() == 1 && group[0].iter().map(u8::from).all_equal() {
-                        let values = &group[0];
-                        let vec = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
-
-                        meta.mark_array();
-
-                        match &values[0] {
-                            parser::Value::Raw(_) | parser::Value::String(_) => meta.mark_str(),
-                            parser::Value::Integer(_) => meta.mark_int(),
-                        }
-
-                        fields.insert(key, Value::Array(vec));
-                    } else if group
-                        .iter()
-                        .flat_map(|x| x.iter().map(u8::from))
-                        .all_equal()
-                    {
-                        meta.mark_array_2d();
-
-                        let mut vec = Vec::with_capacity(group.len());
-
-                        for a in group.iter() {
-                            for value in a.iter() {
-                                match value {
-                                    parser::Value::Raw(_) | parser::Value::String(_) => {
-                                        meta.mark_str()
-                                    }
-                                    parser::Value::Integer(_) => meta.mark_int(),
-                                }
-                            }
-
-                            let inner_vec = a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
-
-                            vec.push(inner_vec);
-                        }
-
-                        fields.insert(key, Value::Array2d(vec));
-                    } else {
-                        unimplemented!()
-                    }
+                let fields = convert_fields(object, cat_field_meta);
+                let cat_keywords = keywords.entry(object.name.clone()).or_default();
+                for (key, _) in object.keywords() {
+                    cat_keywords.insert(field_name(key), key.to_string());
                 }
 
                 lang_categories.insert(object.name.clone(), Category::Fields(fields));
@@ -388,39 +519,311 @@ impl CodeGenerator {
             }
         }
 
+        let mut provenance = BTreeMap::<Lang, BTreeMap<Key, BTreeMap<Field, Provenance>>>::new();
+        let mut raw_metadata = BTreeMap::<Key, BTreeMap<Field, Meta>>::new();
+        for (lang, changes) in changes.iter() {
+            for ((category_name, key), value) in changes.iter() {
+                provenance
+                    .entry(lang.clone())
+                    .or_default()
+                    .entry(category_name.clone())
+                    .or_default()
+                    .insert(field_name(key), *value);
+                raw_metadata
+                    .entry(category_name.clone())
+                    .or_default()
+                    .insert(field_name(key), Meta::new());
+            }
+        }
+
+        let mut raw_fields = BTreeMap::<Lang, BTreeMap<Key, BTreeMap<Field, Value>>>::new();
+        let mut all_raw_metadata = BTreeMap::<Key, BTreeMap<Field, Meta>>::new();
+        for (lang, categories) in by_language.iter() {
+            for (category_name, raw_meta) in raw_metadata.iter_mut() {
+                if let Some(Category::Link(_, _)) = categories.get(category_name) {
+                    continue;
+                }
+
+                let cat_field_meta = all_raw_metadata.entry(category_name.clone()).or_default();
+                let mut fields = raw[lang]
+                    .iter()
+                    .find(|object| &object.name == category_name)
+                    .map(|object| convert_fields(object, cat_field_meta))
+                    .unwrap_or_default();
+                fields.retain(|field, _| raw_meta.contains_key(field));
+                for (field, meta) in raw_meta.iter_mut() {
+                    if !fields.contains_key(field) {
+                        fields.insert(field.clone(), Value::Empty);
+                        meta.make_optional();
+                    }
+                }
+                raw_fields
+                    .entry(lang.clone())
+                    .or_default()
+                    .insert(category_name.clone(), fields);
+            }
+        }
+        for (category_name, raw_meta) in raw_metadata.iter_mut() {
+            for (field, meta) in raw_meta.iter_mut() {
+                // A field that no locale defines upstream, like `T_FMT_AMPM` synthesized in every
+                // locale, has the type of its value after the fixups.
+                let all = match all_raw_metadata
+                    .get(category_name)
+                    .and_then(|x| x.get(field))
+                {
+                    Some(all) => all,
+                    None => &field_metadata[category_name][field],
+                };
+                meta.container_ty = all.container_ty;
+                meta.ty = all.ty;
+                meta.optional |= all.optional;
+            }
+        }
+
+        let features = normalized_langs
+            .keys()
+            .map(|lang| (lang.clone(), Self::locale_features(lang)))
+            .collect::<BTreeMap<_, _>>();
+
+        // A module must be compiled in whenever a locale linking to one of its categories is
+        // selected.
+        let langs_by_norm = normalized_langs
+            .iter()
+            .map(|(lang, norm)| (norm.clone(), lang.clone()))
+            .collect::<HashMap<_, _>>();
+        let mut module_features = features.clone();
+        loop {
+            let mut changed = false;
+            for (lang, categories) in by_language.iter() {
+                for category in categories.values() {
+                    if let Category::Link(target, _) = category {
+                        let target = &langs_by_norm[target];
+                        if target == lang {
+                            continue;
+                        }
+                        let required = module_features[lang].clone();
+                        let target_features = module_features.get_mut(target).unwrap();
+                        let len = target_features.len();
+                        target_features.extend(required);
+                        changed |= len != target_features.len();
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut ids = Registry::default();
+        ids.assign(normalized_langs.keys());
+
         Self {
             by_language,
             field_metadata,
             normalized_langs,
+            features,
+            module_features,
+            raw_fields,
+            raw_metadata,
+            provenance,
+            inheritance,
+            keywords,
+            glibc_version,
+            ids,
+            support_dir: None,
+        }
+    }
+
+    /// The code of the crate.
+    pub fn code(&self) -> Code {
+        self.generate().expect("the code is written to strings")
+    }
+
+    /// Compile every locale in, instead of gating them behind Cargo features.
+    pub fn without_feature_gating(mut self) -> Self {
+        self.features.values_mut().for_each(BTreeSet::clear);
+        self.module_features.values_mut().for_each(BTreeSet::clear);
+        self
+    }
+
+    /// Give the locales the IDs of a registry, `Locale::to_u16`, instead of numbering them by
+    /// name. The locales it doesn't have get the next IDs.
+    pub fn with_ids(mut self, mut ids: Registry) -> Self {
+        ids.assign(self.normalized_langs.keys());
+        self.ids = ids;
+        self
+    }
+
+    /// The IDs of the locales, with the ones of the locales removed when they come from a
+    /// registry.
+    pub fn ids(&self) -> &Registry {
+        &self.ids
+    }
+
+    /// Generate code that is included with `include!` at the root of another crate, with the
+    /// modules of `SUPPORT_MODULES` in `dir`. The crate declares `#![no_std]` itself.
+    pub fn included(mut self, dir: &Path) -> Self {
+        self.support_dir = Some(dir.to_path_buf());
+        self
+    }
+
+    /// Cargo features selecting a locale: its language, its territory and the features that
+    /// select every locale. It is empty for the default locale which is always compiled in.
+    fn locale_features(lang: &str) -> BTreeSet<String> {
+        let mut features = BTreeSet::new();
+
+        if lang == DEFAULT_LOCALE {
+            return features;
+        }
+
+        let name = lang.split('@').next().unwrap();
+        let mut parts = name.splitn(2, '_');
+        features.insert(ALL_LOCALES_FEATURE.to_string());
+        features.insert(format!("lang-{}", parts.next().unwrap().to_lowercase()));
+        if let Some(region) = parts.next() {
+            features.insert(format!("region-{}", region.to_lowercase()));
+        }
+        if MINIMAL_LOCALES.contains(&lang) {
+            features.insert(MINIMAL_FEATURE.to_string());
+        }
+
+        features
+    }
+
+    /// All the Cargo features used to select locales, none without feature gating.
+    pub fn cargo_features(&self) -> BTreeSet<String> {
+        if self.module_features.values().all(BTreeSet::is_empty) {
+            return BTreeSet::new();
         }
+        let mut features = self
+            .features
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        features.insert(MINIMAL_FEATURE.to_string());
+        features
     }
 
-    fn generate<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+    /// Writes the crate: its root, with the modules that are not generated and the traits, and
+    /// the files of `GENERATED_DIR`: a module per locale, the values they share and `Locale`.
+    fn generate(&self) -> Result<Code, std::fmt::Error> {
+        let mut root = String::new();
+        let mut modules = BTreeMap::new();
+        let f = &mut CodeFormatter::new(&mut root, "    ");
+        let path = |name: &str| match &self.support_dir {
+            Some(dir) => format!("#[path = {:?}] ", dir.join(format!("{}.rs", name))),
+            None => String::new(),
+        };
+        let generated_dir = match &self.support_dir {
+            Some(dir) => dir.join(GENERATED_DIR),
+            None => PathBuf::from(GENERATED_DIR),
+        };
+        let generated = |name: &str| {
+            generated_dir
+                .join(format!("{}.rs", name))
+                .display()
+                .to_string()
+        };
+
         write!(
             f,
             r#"
-            #![no_std]
-
-            #[derive(Debug)]
-            #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-            pub struct UnknownLocale;
+            {no_std}
+
+            {error}mod error;
+            {inheritance}mod inheritance;
+            {list}mod list;
+            {locale_set}mod locale_set;
+            {provenance}mod provenance;
+            {provider}mod provider;
+            {resolve}mod resolve;
+
+            #[cfg(feature = "alloc")]
+            extern crate alloc;
+            #[cfg(feature = "std")]
+            extern crate std;
+
+            {blob}pub mod blob;
+            #[cfg(feature = "std")]
+            {compiled}pub mod compiled;
+            #[cfg(feature = "alloc")]
+            {localedef}pub mod localedef;
+
+            pub use error::{{UnknownLocale, UnknownLocaleKind}};
+            pub use inheritance::Source;
+            pub use list::{{Ints, Strs, Table}};
+            pub use locale_set::{{Category, LocaleSet}};
+            pub use provenance::Provenance;
+            pub use provider::{{Builtin, LocaleProvider}};
+            pub use resolve::{{Origin, Resolved}};
+
+            #[doc(hidden)]
+            pub use resolve::__private as __resolve;
+
+            /// The version of `glibc` the locale data was imported from with
+            /// `generate-api import`, `None` if the data was copied by hand.
+            pub const GLIBC_DATA_VERSION: Option<&str> = {glibc_version:?};
 
             "#,
+            no_std = if self.support_dir.is_some() {
+                ""
+            } else {
+                "#![no_std]"
+            },
+            blob = path("blob"),
+            error = path("error"),
+            inheritance = path("inheritance"),
+            list = path("list"),
+            locale_set = path("locale_set"),
+            provenance = path("provenance"),
+            provider = path("provider"),
+            resolve = path("resolve"),
+            compiled = path("compiled"),
+            localedef = path("localedef"),
+            glibc_version = self.glibc_version,
         )?;
 
-        for (lang, categories) in self.by_language.iter() {
-            let lang = &self.normalized_langs[lang];
+        let shared = self.shared_values();
+        write!(
+            f,
+            r#"
 
+            /// Values shared by several locales.
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            #[path = {:?}]
+            pub mod __data;
+            "#,
+            generated(DATA_MODULE),
+        )?;
+        let mut data = String::new();
+        self.generate_shared_values(&mut CodeFormatter::new(&mut data, "    "), &shared)?;
+        modules.insert(DATA_MODULE.to_string(), data);
+        self.generate_default_provenance(f)?;
+        self.generate_inheritance(f)?;
+
+        for (lang_name, categories) in self.by_language.iter() {
+            let cfg = CfgFormatter::new(&self.module_features[lang_name]);
+            let lang = &self.normalized_langs[lang_name];
+
+            writeln!(f)?;
+            if !cfg.is_empty() {
+                write!(f, "\n#[cfg({})]", cfg)?;
+            }
             write!(
                 f,
                 r#"
-
                 #[allow(non_snake_case,non_camel_case_types,dead_code,unused_imports)]
-                pub mod {} {{
+                #[path = {:?}]
+                pub mod {};
                 "#,
+                generated(lang),
                 lang,
             )?;
-            f.indent(1);
+
+            let mut module = String::new();
+            let f = &mut CodeFormatter::new(&mut module, "    ");
 
             for (category_name, category) in categories.iter() {
                 let category_metadata = self.field_metadata.get(category_name).unwrap();
@@ -447,13 +850,18 @@ impl CodeGenerator {
                         f.indent(1);
 
                         for (field_name, meta) in category_metadata.iter() {
-                            fields
-                                .get(field_name)
-                                .unwrap()
-                                .with_fixed_type(meta)
-                                .generate(field_name, meta, f)?;
+                            let value = fields.get(field_name).unwrap().with_fixed_type(meta);
+                            let shared = shared
+                                .get(&SharedKey::new(category_name, field_name, &value, meta))
+                                .map(|shared| {
+                                    format!("crate::__data::{}::{}", category_name, shared.name)
+                                });
+
+                            value.generate(field_name, meta, shared.as_deref(), f)?;
                         }
 
+                        self.generate_raw(f, lang_name, category_name, fields)?;
+
                         f.dedent(1);
 
                         write!(
@@ -466,18 +874,24 @@ impl CodeGenerator {
                 }
             }
 
-            f.dedent(1);
-
-            write!(
-                f,
-                r#"
-                }}
-                "#,
-            )?
+            modules.insert(lang.clone(), module);
         }
 
-        self.generate_variants(f)?;
+        self.generate_traits(f)?;
+        self.generate_markers(f)?;
+        self.generate_owned(f)?;
+        write!(
+            f,
+            r#"
+
+            include!({:?});
+            "#,
+            generated(LOCALE_MODULE),
+        )?;
 
+        let mut locale = String::new();
+        let f = &mut CodeFormatter::new(&mut locale, "    ");
+        self.generate_variants(f)?;
         write!(
             f,
             r#"
@@ -489,53 +903,105 @@ impl CodeGenerator {
             }}
             "#,
         )?;
-        Ok(())
+        modules.insert(LOCALE_MODULE.to_string(), locale);
+
+        Ok(Code {
+            root,
+            modules,
+            blob: self.blob(),
+        })
     }
 
-    fn generate_variants<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+    /// The values of the locales packed in a blob, with the categories they link to resolved.
+    pub fn blob(&self) -> Vec<u8> {
+        let schema = self
+            .field_metadata
+            .iter()
+            .map(|(category_name, fields)| {
+                let kinds = fields
+                    .iter()
+                    .map(|(field, meta)| (field.clone(), blob_kind(meta)))
+                    .collect();
+                (category_name.clone(), kinds)
+            })
+            .collect::<blob::Schema>();
+        let langs_by_norm = self
+            .normalized_langs
+            .iter()
+            .map(|(lang, norm)| (norm.as_str(), lang.as_str()))
+            .collect::<HashMap<_, _>>();
+
+        let mut locales = BTreeMap::new();
+        for (lang, categories) in self.by_language.iter() {
+            let mut items = BTreeMap::new();
+            for (category_name, mut category) in categories.iter() {
+                let mut seen = BTreeSet::new();
+                while let Category::Link(target, _) = category {
+                    let target = langs_by_norm[target.as_str()];
+                    assert!(
+                        seen.insert(target),
+                        "{} {} links to itself",
+                        lang,
+                        category_name
+                    );
+                    category = &self.by_language[target][category_name];
+                }
+                let fields = match category {
+                    Category::Fields(fields) => fields,
+                    Category::Link(_, _) => unreachable!(),
+                };
+                let metadata = &self.field_metadata[category_name];
+                let values = fields
+                    .iter()
+                    .filter_map(|(field, value)| {
+                        Some((field.clone(), blob_value(value, &metadata[field])?))
+                    })
+                    .collect();
+                items.insert(category_name.clone(), values);
+            }
+            locales.insert(lang.clone(), items);
+        }
+
+        blob::pack(&schema, &locales)
+    }
+
+    /// Writes the `raw` and `provenance` modules of a category changed by a fixup.
+    ///
+    /// `raw` re-exports the fields of the category and shadows the ones that differ upstream,
+    /// either in value or in type. `provenance` re-exports `Provenance::Upstream` for every field
+    /// and shadows the ones changed in this locale.
+    fn generate_raw<W: Write>(
+        &self,
+        f: &mut CodeFormatter<W>,
+        lang: &str,
+        category_name: &str,
+        fields: &BTreeMap<Field, Value>,
+    ) -> std::fmt::Result {
+        let raw_fields = match self.raw_fields.get(lang).and_then(|x| x.get(category_name)) {
+            Some(raw_fields) => raw_fields,
+            None => return Ok(()),
+        };
+
         write!(
             f,
             r#"
 
-            /// Locales matching the locales in `glibc`.
-            ///
-            /// Most locale names follow the syntax `language[_territory][@modifier]`.
-            /// The `@` is replaced with `_` in the `enum` variant names.
-            ///
-            /// The default locale is `POSIX`.
-            ///
-            /// License note: The Free Software Foundation does not claim any copyright interest in the locale
-            /// data of the GNU C Library; they believe it is not copyrightable.
-            #[allow(non_camel_case_types,dead_code)]
-            #[derive(Copy, Clone, PartialEq, Eq, Hash)]
-            pub enum Locale {{
+            /// The values of `glibc`, without the fixups applied by this crate.
+            pub mod raw {{
+                pub use super::*;
             "#,
         )?;
         f.indent(1);
 
-        for (lang, norm) in self.normalized_langs.iter() {
-            let desc = match self
-                .by_language
-                .get(lang)
-                .and_then(|l| l.get("LC_IDENTIFICATION"))
-            {
-                Some(Category::Fields(fields)) => match fields.get("TITLE") {
-                    Some(Value::Literal(title)) => {
-                        let mut title = title.clone();
-                        if !title.ends_with('.') {
-                            title.push('.');
-                        }
-                        title
-                    }
-                    _ => match lang == "POSIX" {
-                        true => "POSIX Standard Locale.".to_string(),
-                        false => "".to_string(),
-                    },
-                },
-                _ => "".to_string(),
-            };
-            write!(f, "\n/// `{}`: {}\n", lang, desc)?;
-            writeln!(f, "\n{},\n", norm)?;
+        let category_metadata = &self.field_metadata[category_name];
+        for (field_name, meta) in self.raw_metadata[category_name].iter() {
+            let value = raw_fields[field_name].with_fixed_type(meta);
+            let fixed_meta = &category_metadata[field_name];
+            if meta == fixed_meta && value == fields[field_name].with_fixed_type(fixed_meta) {
+                continue;
+            }
+
+            value.generate(field_name, meta, None, f)?;
         }
 
         f.dedent(1);
@@ -544,70 +1010,938 @@ impl CodeGenerator {
             r#"
             }}
 
-            impl core::fmt::Display for Locale {{
-                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                    f.write_str(match self {{
+            /// Where the values of this category come from.
+            pub mod provenance {{
+                pub use crate::__provenance::{}::*;
             "#,
+            category_name,
         )?;
-        f.indent(3);
+        f.indent(1);
 
-        for (lang, norm) in self.normalized_langs.iter() {
-            write!(
-                f,
-                r#"
-                Locale::{norm} => {lang:?},
-                "#,
-                lang = lang,
-                norm = norm,
-            )?;
+        if let Some(provenance) = self.provenance.get(lang).and_then(|x| x.get(category_name)) {
+            for (field_name, provenance) in provenance.iter() {
+                write!(
+                    f,
+                    r#"
+                    pub const {}: crate::Provenance = crate::Provenance::{:?};
+                    "#,
+                    field_name, provenance,
+                )?;
+            }
         }
 
-        f.dedent(3);
+        f.dedent(1);
         write!(
             f,
             r#"
-                    }})
-                }}
             }}
+            "#,
+        )
+    }
 
-            impl core::fmt::Debug for Locale {{
-                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                    core::fmt::Display::fmt(self, f)
-                }}
-            }}
+    /// Writes the modules with `Provenance::Upstream` for every field of the categories changed
+    /// by a fixup.
+    fn generate_default_provenance<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
 
-            #[cfg(feature = "defmt")]
-            impl defmt::Format for Locale {{
-                fn format(&self, f: defmt::Formatter) {{
-                    defmt::write!(f, "{{:?}}", self);
-                }}
-            }}
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            pub mod __provenance {{
+            "#,
+        )?;
+        f.indent(1);
 
-            impl core::str::FromStr for Locale {{
-                type Err = UnknownLocale;
+        for category_name in self.raw_metadata.keys() {
+            write!(
+                f,
+                r#"
+                pub mod {} {{
+                "#,
+                category_name,
+            )?;
+            f.indent(1);
+
+            for field_name in self.field_metadata[category_name].keys() {
+                write!(
+                    f,
+                    r#"
+                    pub const {}: crate::Provenance = crate::Provenance::Upstream;
+                    "#,
+                    field_name,
+                )?;
+            }
+
+            f.dedent(1);
+            write!(
+                f,
+                r#"
+                }}
+                "#,
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+            "#,
+        )
+    }
+
+    /// Writes the tables of `Locale::inheritance`, `Locale::source_of`, `Locale::shared_with`
+    /// and `Locale::main_territory`.
+    fn generate_inheritance<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        let locales = self.inheritance.locales();
+        let mut chains = vec![Vec::new()];
+        let mut rows = Vec::with_capacity(locales.len());
+
+        for lang in locales {
+            let mut row = Vec::with_capacity(parser::CATEGORIES.len());
+
+            for category in parser::CATEGORIES {
+                let chain = self.inheritance.chain(lang, category);
+                let chain_index = match chains.iter().position(|x| *x == chain) {
+                    Some(index) => index,
+                    None => {
+                        chains.push(chain);
+                        chains.len() - 1
+                    }
+                };
+                let source = self.inheritance.source_of(lang, category);
+                let source_index = locales
+                    .binary_search_by(|x| x.as_str().cmp(source))
+                    .unwrap();
+                row.push((chain_index, source_index));
+            }
+
+            rows.push(row);
+        }
+
+        write!(
+            f,
+            r#"
+
+            #[doc(hidden)]
+            pub mod __inheritance {{
+                use crate::Source::{{File, Locale}};
+
+                pub static CHAINS: &[&[crate::Source]] = &[
+            "#,
+        )?;
+        f.indent(2);
+
+        for chain in chains.iter() {
+            let chain = chain
+                .iter()
+                .map(|name| match self.inheritance.is_locale(name) {
+                    true => format!("Locale({:?})", name),
+                    false => format!("File({:?})", name),
+                })
+                .join(", ");
+            write!(f, "\n&[{}],", chain)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// Sorted by name.
+            pub static LOCALES: &[&str] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        for lang in locales {
+            write!(f, "\n{:?},", lang)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// The index in `LOCALES` of the main territory of the language of every locale of
+            /// `LOCALES`.
+            pub static MAIN_TERRITORIES: &[Option<u16>] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        for lang in locales {
+            match self.inheritance.main_territory(lang) {
+                Some(main) => write!(
+                    f,
+                    "\nSome({}),",
+                    locales.binary_search_by(|x| x.as_str().cmp(main)).unwrap(),
+                )?,
+                None => write!(f, "\nNone,")?,
+            }
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// For every locale of `LOCALES` and every category: the index of its chain in
+            /// `CHAINS` and the index of its source in `LOCALES`.
+            pub static CATEGORIES: &[[(u16, u16); 12]] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        for row in rows.iter() {
+            let row = row
+                .iter()
+                .map(|(chain, source)| format!("({}, {})", chain, source))
+                .join(", ");
+            write!(f, "\n[{}],", row)?;
+        }
+
+        f.dedent(2);
+        write!(
+            f,
+            r#"
+                ];
+            }}
+            "#,
+        )
+    }
+
+    /// Writes a trait with the items of each category as associated constants, its object-safe
+    /// version and the macro implementing them for a locale.
+    fn generate_traits<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            let (trait_name, _) = category_trait(category_name);
+
+            write!(
+                f,
+                r#"
+
+                /// The items of `{category}` of a locale, as associated constants.
+                pub trait {name} {{
+                "#,
+                category = category_name,
+                name = trait_name,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(f, "\nconst {}: {};", field_name, static_type(meta))?;
+            }
+            f.dedent(1);
+
+            write!(
+                f,
+                r#"
+                }}
+
+                /// Object-safe version of [`{name}`]. The items borrow from the value so that it
+                /// can be implemented by data loaded at runtime: see [`LocaleProvider`].
+                pub trait Dyn{name} {{
+                "#,
+                name = trait_name,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(
+                    f,
+                    "\nfn {}(&self) -> {};",
+                    field_name.to_lowercase(),
+                    DynTypeFormatter { meta },
+                )?;
+            }
+            f.dedent(1);
+
+            write!(
+                f,
+                r#"
+                }}
+
+                impl<T: {name}> Dyn{name} for T {{
+                "#,
+                name = trait_name,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(
+                    f,
+                    r#"
+                    fn {method}(&self) -> {ty} {{
+                        {value}
+                    }}
+                    "#,
+                    method = field_name.to_lowercase(),
+                    ty = DynTypeFormatter { meta },
+                    value = to_dyn(meta, &format!("T::{}", field_name), false),
+                )?;
+            }
+            f.dedent(1);
+            write!(
+                f,
+                r#"
+                }}
+                "#,
+            )?;
+        }
+
+        let supertraits = self
+            .field_metadata
+            .keys()
+            .map(|x| category_trait(x).0)
+            .join(" + ");
+        write!(
+            f,
+            r#"
+
+            /// The items of every category of a locale, as associated constants. It is implemented
+            /// by the types of the module [`locales`] for static dispatch.
+            pub trait LocaleInfo: {supertraits} {{
+                const LOCALE: Locale;
+            }}
+
+            /// Object-safe version of [`LocaleInfo`], returned by [`Locale::info`] and by the
+            /// implementations of [`LocaleProvider`]. It is implemented by the types of the module
+            /// [`locales`] and by the data loaded at runtime, like `LocaleData`.
+            pub trait DynLocaleInfo {{
+                /// The name of the locale, like `fr_BE` or `ca_ES@valencia`.
+                fn name(&self) -> &str;
+
+                /// The locale compiled in with the name of this one, if there is one.
+                fn locale(&self) -> Option<Locale> {{
+                    core::convert::TryFrom::<&str>::try_from(self.name()).ok()
+                }}
+            "#,
+            supertraits = supertraits,
+        )?;
+        f.indent(1);
+        for category_name in self.field_metadata.keys() {
+            let (trait_name, method) = category_trait(category_name);
+            write!(f, "\nfn {}(&self) -> &dyn Dyn{};", method, trait_name)?;
+        }
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            impl<T: LocaleInfo> DynLocaleInfo for T {{
+                fn name(&self) -> &str {{
+                    T::LOCALE.name()
+                }}
+            "#,
+        )?;
+        f.indent(1);
+        for category_name in self.field_metadata.keys() {
+            let (trait_name, method) = category_trait(category_name);
+            write!(
+                f,
+                r#"
+
+                fn {}(&self) -> &dyn Dyn{} {{
+                    self
+                }}
+                "#,
+                method, trait_name,
+            )?;
+        }
+        f.dedent(1);
+
+        write!(
+            f,
+            r#"
+            }}
+
+            macro_rules! __locale_info {{
+                ($marker:ident, $module:ident, $name:literal) => {{
+                    #[doc = concat!("`", $name, "`")]
+                    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+                    pub struct $marker;
+
+                    impl crate::LocaleInfo for $marker {{
+                        const LOCALE: crate::Locale = crate::Locale::$module;
+                    }}
+            "#,
+        )?;
+        f.indent(2);
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            write!(
+                f,
+                r#"
+
+                impl crate::{} for $marker {{
+                "#,
+                category_trait(category_name).0,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(
+                    f,
+                    "\nconst {field}: {ty} = crate::$module::{category}::{field};",
+                    field = field_name,
+                    ty = static_type(meta),
+                    category = category_name,
+                )?;
+            }
+            f.dedent(1);
+            write!(
+                f,
+                r#"
+                }}
+                "#,
+            )?;
+        }
+        f.dedent(2);
+        write!(
+            f,
+            r#"
+                }};
+            }}
+
+            /// The items of the `Dyn*Info` traits as functions, used by the macros with a
+            /// provider.
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            pub mod __dyn {{
+            "#,
+        )?;
+        f.indent(1);
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            write!(
+                f,
+                r#"
+
+                pub mod {} {{
+                    #[allow(unused_imports)]
+                    use crate::{{DynLocaleInfo, Ints, Strs, Table}};
+                "#,
+                category_name,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(
+                    f,
+                    r#"
+
+                    pub fn {field}(locale: &dyn DynLocaleInfo) -> {ty} {{
+                        locale.{category}().{method}()
+                    }}
+                    "#,
+                    field = field_name,
+                    ty = DynTypeFormatter { meta },
+                    category = category_trait(category_name).1,
+                    method = field_name.to_lowercase(),
+                )?;
+            }
+            f.dedent(1);
+            write!(f, "\n}}")?;
+        }
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+            "#,
+        )
+    }
+
+    /// Writes the marker types of the locales and `Locale::info`.
+    fn generate_markers<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+
+            /// A zero-sized type per locale implementing [`LocaleInfo`].
+            ///
+            /// ```
+            /// use pure_rust_locales::{{locales, TimeInfo}};
+            ///
+            /// fn d_fmt<L: TimeInfo>() -> &'static str {{
+            ///     L::D_FMT
+            /// }}
+            ///
+            /// assert_eq!(d_fmt::<locales::fr_BE>(), "%d/%m/%y");
+            /// ```
+            #[allow(non_camel_case_types)]
+            pub mod locales {{
+            "#,
+        )?;
+        f.indent(1);
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_cfg(f, lang)?;
+            write!(
+                f,
+                "\n__locale_info!({norm}, {norm}, {lang:?});",
+                norm = norm,
+                lang = lang
+            )?;
+        }
+        f.dedent(1);
+
+        write!(
+            f,
+            r#"
+            }}
+
+            impl Locale {{
+                /// The items of the locale, with dynamic dispatch. They are read from
+                /// [`Blob::builtin`](blob::Blob::builtin), which is smaller than the constants of
+                /// every locale in a program that chooses its locale at runtime.
+                ///
+                /// ```
+                /// use pure_rust_locales::Locale;
+                ///
+                /// assert_eq!(Locale::fr_BE.info().time().d_fmt(), "%d/%m/%y");
+                /// ```
+                pub fn info(self) -> &'static dyn DynLocaleInfo {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_cfg(f, lang)?;
+            write!(
+                f,
+                "\nLocale::{norm} => &crate::blob::BuiltinInfo(Locale::{norm}),",
+                norm = norm
+            )?;
+        }
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+            }}
+            "#,
+        )?;
+
+        self.generate_builtin_info(f)
+    }
+
+    /// Writes the implementations of the `Dyn*Info` traits reading the values of `Locale::info`
+    /// from the blob of the crate.
+    fn generate_builtin_info<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            write!(
+                f,
+                r#"
+
+                impl Dyn{} for crate::blob::BuiltinInfo {{
+                "#,
+                category_trait(category_name).0,
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                let read = match (meta.container_ty, meta.ty) {
+                    (ContainerType::Singleton, Some(Type::Integer)) => "int",
+                    (ContainerType::Singleton, _) => "str",
+                    (ContainerType::Array, Some(Type::Integer)) => "ints",
+                    (ContainerType::Array, _) => "strs",
+                    (ContainerType::Array2D, _) => "table",
+                };
+                write!(
+                    f,
+                    r#"
+                    fn {method}(&self) -> {ty} {{
+                        self.category(Category::{category}).and_then(|x| x.{read}("{field}")){default}
+                    }}
+                    "#,
+                    method = field_name.to_lowercase(),
+                    ty = DynTypeFormatter { meta },
+                    category = category_name,
+                    read = read,
+                    field = field_name,
+                    default = if meta.optional {
+                        ""
+                    } else {
+                        ".unwrap_or_default()"
+                    },
+                )?;
+            }
+            f.dedent(1);
+            write!(f, "\n}}")?;
+        }
+
+        write!(
+            f,
+            r#"
+
+            impl DynLocaleInfo for crate::blob::BuiltinInfo {{
+                fn name(&self) -> &str {{
+                    self.0.name()
+                }}
+
+                fn locale(&self) -> Option<Locale> {{
+                    Some(self.0)
+                }}
+            "#,
+        )?;
+        f.indent(1);
+        for category_name in self.field_metadata.keys() {
+            let (trait_name, method) = category_trait(category_name);
+            write!(
+                f,
+                r#"
+
+                fn {method}(&self) -> &dyn Dyn{name} {{
+                    self
+                }}
+                "#,
+                method = method,
+                name = trait_name,
+            )?;
+        }
+        f.dedent(1);
+        write!(f, "\n}}")
+    }
+
+    /// Writes the owned version of the data of a locale, behind the feature `alloc`.
+    fn generate_owned<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+
+            /// The data of the locales as owned values, to load locales at runtime: see
+            /// [`localedef`].
+            #[cfg(feature = "alloc")]
+            pub mod owned {{
+                use crate::localedef::{{Error, Keyword, __private::*}};
+                use crate::{{Category, Ints, Locale, Strs, Table}};
+                use alloc::string::{{String, ToString}};
+                use alloc::vec::Vec;
+            "#,
+        )?;
+        f.indent(1);
+
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            write!(
+                f,
+                r#"
+
+                /// The items of `{category}`.
+                #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+                pub struct {name} {{
+                "#,
+                category = category_name,
+                name = category_struct(category_name),
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                write!(
+                    f,
+                    "\npub {}: {},",
+                    field_name.to_lowercase(),
+                    OwnedTypeFormatter { meta },
+                )?;
+            }
+            f.dedent(1);
+            write!(f, "\n}}")?;
+        }
+
+        write!(
+            f,
+            r#"
+
+            /// The data of a locale.
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct LocaleData {{
+                /// The name of the locale, like `fr_BE` or `ca_ES@valencia`.
+                pub name: String,
+            "#,
+        )?;
+        f.indent(1);
+        for category_name in self.field_metadata.keys() {
+            write!(
+                f,
+                "\npub {}: {},",
+                category_trait(category_name).1,
+                category_struct(category_name),
+            )?;
+        }
+        f.dedent(1);
+        write!(f, "\n}}")?;
+
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            write!(
+                f,
+                r#"
+
+                impl crate::Dyn{} for {} {{
+                "#,
+                category_trait(category_name).0,
+                category_struct(category_name),
+            )?;
+            f.indent(1);
+            for (field_name, meta) in category_metadata.iter() {
+                let field = field_name.to_lowercase();
+                write!(
+                    f,
+                    r#"
+                    fn {field}(&self) -> {ty} {{
+                        {value}
+                    }}
+                    "#,
+                    field = field,
+                    ty = DynTypeFormatter { meta },
+                    value = to_dyn(meta, &format!("self.{}", field), true),
+                )?;
+            }
+            f.dedent(1);
+            write!(f, "\n}}")?;
+        }
+
+        write!(
+            f,
+            r#"
+
+            impl crate::DynLocaleInfo for LocaleData {{
+                fn name(&self) -> &str {{
+                    &self.name
+                }}
+            "#,
+        )?;
+        f.indent(1);
+        for category_name in self.field_metadata.keys() {
+            let (trait_name, method) = category_trait(category_name);
+            write!(
+                f,
+                r#"
+
+                fn {method}(&self) -> &dyn crate::Dyn{name} {{
+                    &self.{method}
+                }}
+                "#,
+                method = method,
+                name = trait_name,
+            )?;
+        }
+        f.dedent(1);
+
+        write!(
+            f,
+            r#"
+            }}
+
+            impl LocaleData {{
+                /// Build the data from the keywords of each category. The items missing use the
+                /// values of `POSIX`.
+                pub(crate) fn from_keywords(
+                    name: &str,
+                    keywords: &mut dyn FnMut(Category) -> Result<Vec<Keyword>, Error>,
+                ) -> Result<Self, Error> {{
+                    let posix = Self::from(Locale::POSIX);
+
+                    Ok(Self {{
+                        name: name.to_string(),
+            "#,
+        )?;
+        f.indent(3);
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            let (_, method) = category_trait(category_name);
+            write!(
+                f,
+                r#"
+                {method}: {{
+                    let keywords = keywords(Category::{category})?;
+                    {name} {{
+                "#,
+                method = method,
+                category = category_name,
+                name = category_struct(category_name),
+            )?;
+            f.indent(2);
+            for (field_name, meta) in category_metadata.iter() {
+                let field = field_name.to_lowercase();
+                let keyword = &self.keywords[category_name][field_name];
+                if meta.optional {
+                    write!(
+                        f,
+                        "\n{}: {}(&keywords, {:?}),",
+                        field,
+                        parse_function(meta),
+                        keyword,
+                    )?;
+                } else {
+                    write!(
+                        f,
+                        "\n{field}: {function}(&keywords, {keyword:?}).unwrap_or(posix.{method}.{field}),",
+                        field = field,
+                        method = method,
+                        function = parse_function(meta),
+                        keyword = keyword,
+                    )?;
+                }
+            }
+            f.dedent(2);
+            write!(
+                f,
+                r#"
+                    }}
+                }},
+                "#,
+            )?;
+        }
+        f.dedent(3);
+
+        write!(
+            f,
+            r#"
+                    }})
+                }}
+            }}
+
+            impl From<Locale> for LocaleData {{
+                fn from(locale: Locale) -> Self {{
+                    let info = locale.info();
+
+                    Self {{
+                        name: locale.name().to_string(),
+            "#,
+        )?;
+        f.indent(3);
+        for (category_name, category_metadata) in self.field_metadata.iter() {
+            let (_, method) = category_trait(category_name);
+            write!(
+                f,
+                r#"
+                {method}: {{
+                    let info = info.{method}();
+                    {name} {{
+                "#,
+                method = method,
+                name = category_struct(category_name),
+            )?;
+            f.indent(2);
+            for (field_name, meta) in category_metadata.iter() {
+                let field = field_name.to_lowercase();
+                write!(
+                    f,
+                    "\n{}: {},",
+                    field,
+                    to_owned(meta, &format!("info.{}()", field)),
+                )?;
+            }
+            f.dedent(2);
+            write!(
+                f,
+                r#"
+                    }}
+                }},
+                "#,
+            )?;
+        }
+        f.dedent(3);
+
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+            }}
+            "#,
+        )?;
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            #[cfg(feature = "alloc")]
+            pub use owned::LocaleData;
+            "#,
+        )
+    }
+
+    fn generate_variants<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+
+            /// Locales matching the locales in `glibc`.
+            ///
+            /// Most locale names follow the syntax `language[_territory][@modifier]`.
+            /// The `@` is replaced with `_` in the `enum` variant names.
+            ///
+            /// The default locale is `POSIX`.
+            ///
+            /// License note: The Free Software Foundation does not claim any copyright interest in the locale
+            /// data of the GNU C Library; they believe it is not copyrightable.
+            ///
+            /// The discriminant of a locale is its ID: see [`Locale::to_u16`].
+            #[allow(non_camel_case_types,dead_code)]
+            #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+            #[repr(u16)]
+            pub enum Locale {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            let desc = match self
+                .by_language
+                .get(lang)
+                .and_then(|l| l.get("LC_IDENTIFICATION"))
+            {
+                Some(Category::Fields(fields)) => match fields.get("TITLE") {
+                    Some(Value::Literal(title)) => {
+                        let mut title = title.clone();
+                        if !title.ends_with('.') {
+                            title.push('.');
+                        }
+                        title
+                    }
+                    _ => match lang == "POSIX" {
+                        true => "POSIX Standard Locale.".to_string(),
+                        false => "".to_string(),
+                    },
+                },
+                _ => "".to_string(),
+            };
+            write!(f, "\n/// `{}`: {}\n", lang, desc)?;
+            self.write_cfg(f, lang)?;
+            writeln!(f, "\n{} = {},\n", norm, self.ids.get(lang).unwrap())?;
+        }
 
-                fn from_str(s: &str) -> Result<Self, Self::Err> {{
-                    core::convert::TryFrom::<&str>::try_from(s)
-                }}
+        f.dedent(1);
+        write!(
+            f,
+            r#"
             }}
 
-            impl core::convert::TryFrom<&str> for Locale {{
-                type Error = UnknownLocale;
+            impl Locale {{
+                /// The name of the locale in `glibc`, like `de_DE@euro`.
+                pub fn name(self) -> &'static str {{
+                    LOCALE_NAMES[self as usize]
+                }}
 
-                fn try_from(i: &str) -> Result<Self, Self::Error> {{
-                    match i {{
+                /// The ID of the locale. It stays the same in the next versions of the crate,
+                /// unlike the order of the variants, and it isn't given to another locale if
+                /// this one is removed: it can be stored or sent instead of the name.
+                pub const fn to_u16(self) -> u16 {{
+                    self as u16
+                }}
+
+                /// The locale with an ID, if it is compiled in.
+                pub const fn from_u16(id: u16) -> Option<Self> {{
+                    match id {{
             "#,
         )?;
         f.indent(3);
 
         for (lang, norm) in self.normalized_langs.iter() {
+            self.write_cfg(f, lang)?;
             write!(
                 f,
-                r#"
-                {lang:?} => Ok(Locale::{norm}),
-                "#,
-                lang = lang,
-                norm = norm,
+                "\n{} => Some(Locale::{}),",
+                self.ids.get(lang).unwrap(),
+                norm
             )?;
         }
 
@@ -615,15 +1949,211 @@ impl CodeGenerator {
         write!(
             f,
             r#"
-                        _ => Err(UnknownLocale),
+                        _ => None,
+                    }}
+                }}
+            }}
+
+            /// The names of the locales, by ID. The IDs of the locales removed have an empty
+            /// name.
+            const LOCALE_NAMES: &[&str] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        let mut names = vec![
+            "";
+            self.ids
+                .iter()
+                .map(|(_, id)| id as usize + 1)
+                .max()
+                .unwrap_or(0)
+        ];
+        for (lang, id) in self.ids.iter() {
+            if self.normalized_langs.contains_key(lang) {
+                names[id as usize] = lang;
+            }
+        }
+        for name in names {
+            write!(f, "\n{:?},", name)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// The locales by the key of their name, sorted by key.
+            const LOCALES_BY_NAME: &[(u128, Locale)] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        let by_name = self
+            .normalized_langs
+            .iter()
+            .map(|(lang, norm)| (lang.to_lowercase(), lang, norm))
+            .sorted();
+        for (_, lang, norm) in by_name {
+            assert!(lang.len() <= LOCALE_KEY_LEN, "{} is too long", lang);
+            self.write_cfg(f, lang)?;
+            write!(f, "\n(locale_key(b{:?}), Locale::{}),", lang, norm)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// The length of the longest name that has a key.
+            const LOCALE_KEY_LEN: usize = {};
+
+            /// The key of the name of a locale, compared in one step: its bytes, in lowercase
+            /// and with `_` for `-`, padded with zeros. The keys are sorted like the names.
+            const fn locale_key(name: &[u8]) -> u128 {{
+                let mut key = 0;
+                let mut i = 0;
+                while i < LOCALE_KEY_LEN {{
+                    key <<= 8;
+                    if i < name.len() {{
+                        key |= match name[i] {{
+                            b'-' => b'_',
+                            x => x.to_ascii_lowercase(),
+                        }} as u128;
+                    }}
+                    i += 1;
+                }}
+                key
+            }}
+            "#,
+            LOCALE_KEY_LEN,
+        )?;
+        write!(
+            f,
+            r#"
+
+            impl core::fmt::Display for Locale {{
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                    f.write_str(self.name())
+                }}
+            }}
+
+            impl core::fmt::Debug for Locale {{
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                    core::fmt::Display::fmt(self, f)
+                }}
+            }}
+
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for Locale {{
+                fn format(&self, f: defmt::Formatter) {{
+                    defmt::write!(f, "{{:?}}", self);
+                }}
+            }}
+
+            impl core::str::FromStr for Locale {{
+                type Err = UnknownLocale;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                    core::convert::TryFrom::<&str>::try_from(s)
+                }}
+            }}
+
+            /// Parses the name of a locale, ignoring the case of the ASCII letters and with `-`
+            /// or `_` between its parts: `fr_BE`, `fr-be` and `FR_BE` are `Locale::fr_BE`.
+            impl core::convert::TryFrom<&str> for Locale {{
+                type Error = UnknownLocale;
+
+                fn try_from(i: &str) -> Result<Self, Self::Error> {{
+                    if i.len() > LOCALE_KEY_LEN {{
+                        return Err(UnknownLocale::new(i));
                     }}
+                    let key = locale_key(i.as_bytes());
+                    // The key is padded with zeros: the length tells `fr_BE` from `fr_BE\0`.
+                    LOCALES_BY_NAME
+                        .binary_search_by(|(x, _)| x.cmp(&key))
+                        .ok()
+                        .map(|index| LOCALES_BY_NAME[index].1)
+                        .filter(|locale| locale.name().len() == i.len())
+                        .ok_or_else(|| UnknownLocale::new(i))
                 }}
             }}
 
+            /// Constants and modules used by `locale_match!`.
+            ///
+            /// `#[cfg]` attributes can't be used in the expansion of the macro as they would be
+            /// evaluated in the crate calling it. Locales that are not selected are aliased to
+            /// `POSIX` here instead; their arms are unreachable.
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals,unused_imports)]
+            pub mod __locale_match {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            let cfg = CfgFormatter::new(&self.features[lang]);
+
+            if cfg.is_empty() {
+                write!(
+                    f,
+                    r#"
+                    pub use super::{norm};
+                    pub const {norm}: super::Locale = super::Locale::{norm};
+                    "#,
+                    norm = norm,
+                )?;
+            } else {
+                write!(
+                    f,
+                    r#"
+                    #[cfg({cfg})]
+                    pub use super::{norm};
+                    #[cfg({cfg})]
+                    pub const {norm}: super::Locale = super::Locale::{norm};
+                    #[cfg(not({cfg}))]
+                    pub use super::{default} as {norm};
+                    #[cfg(not({cfg}))]
+                    pub const {norm}: super::Locale = super::Locale::{default};
+                    "#,
+                    cfg = cfg,
+                    norm = norm,
+                    default = DEFAULT_LOCALE,
+                )?;
+            }
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            /// Look up an item of a locale: `locale_match!(locale => LC_TIME::D_FMT)` is the
+            /// constant of the module of the locale, `LC_TIME::D_FMT` here.
+            ///
+            /// With `in provider, name`, the item is looked up in the locale named `name` of a
+            /// [`LocaleProvider`] instead, with the method of the `Dyn*Info` trait of the
+            /// category. It returns `None` if the provider doesn't have the locale:
+            ///
+            /// ```
+            /// use pure_rust_locales::{{locale_match, Builtin, Locale}};
+            ///
+            /// assert_eq!(locale_match!(Locale::fr_BE => LC_TIME::D_FMT), "%d/%m/%y");
+            /// assert_eq!(locale_match!(in Builtin, "fr_BE" => LC_TIME::D_FMT), Some("%d/%m/%y"));
+            /// assert_eq!(locale_match!(in Builtin, "xx_XX" => LC_TIME::D_FMT), None);
+            /// ```
             #[macro_export]
             macro_rules! locale_match {{
+                (in $provider:expr, $name:expr => $category:ident :: $item:ident) => {{
+                    $crate::LocaleProvider::locale(&$provider, $name)
+                        .map($crate::__dyn::$category::$item)
+                }};
                 ($locale:expr => $($item:ident)::+) => {{{{
-                    match $locale {{
+                    #[allow(unreachable_patterns)]
+                    let value = match $locale {{
             "#,
         )?;
         f.indent(3);
@@ -632,7 +2162,7 @@ impl CodeGenerator {
             write!(
                 f,
                 r#"
-                $crate::Locale::{norm} => $crate::{norm}::$($item)::+,
+                $crate::__locale_match::{norm} => $crate::__locale_match::{norm}::$($item)::+,
                 "#,
                 norm = norm,
             )?;
@@ -642,22 +2172,456 @@ impl CodeGenerator {
         write!(
             f,
             r#"
-                    }}
+                        _ => ::core::unreachable!(),
+                    }};
+                    value
                 }}}}
             }}
 
+            /// Like [`locale_match!`] but for a [`LocaleSet`]: the first segment of the path is the
+            /// category and the locale of that category is used, from a provider with
+            /// `in provider, set`.
+            #[macro_export]
+            macro_rules! locale_set_match {{
+                (in $provider:expr, $set:expr => $category:ident :: $item:ident) => {{
+                    $crate::locale_match!(
+                        in $provider,
+                        $crate::LocaleSet::get(&$set, $crate::Category::$category).name()
+                            => $category::$item
+                    )
+                }};
+                ($set:expr => $category:ident $(::$item:ident)+) => {{
+                    $crate::locale_match!(
+                        $crate::LocaleSet::get(&$set, $crate::Category::$category)
+                            => $category $(::$item)+
+                    )
+                }};
+            }}
+
             "#,
         )
     }
 }
 
-impl std::fmt::Display for CodeGenerator {
+/// The name of the trait of a category, like `TimeInfo` for `LC_TIME`, and of the method of
+/// `DynLocaleInfo` returning it.
+fn category_trait(category_name: &str) -> (String, String) {
+    let method = category_name.trim_start_matches("LC_").to_lowercase();
+    let name = format!("{}{}Info", method[..1].to_uppercase(), &method[1..]);
+    (name, method)
+}
+
+/// The name of the owned struct of a category, like `TimeData` for `LC_TIME`.
+fn category_struct(category_name: &str) -> String {
+    category_trait(category_name).0.replace("Info", "Data")
+}
+
+/// The function of `localedef::__private` reading the keyword of a field.
+fn parse_function(meta: &Meta) -> &'static str {
+    match (meta.container_ty, meta.ty) {
+        (ContainerType::Singleton, Some(Type::Integer)) => "integer",
+        (ContainerType::Singleton, _) => "string",
+        (ContainerType::Array, Some(Type::Integer)) => "integers",
+        (ContainerType::Array, _) => "strings",
+        (ContainerType::Array2D, _) => "table",
+    }
+}
+
+/// Converts the value of a field, given by `expr`, to its owned type.
+fn to_owned(meta: &Meta, expr: &str) -> String {
+    let convert = |x: &str| match (meta.container_ty, meta.ty) {
+        (ContainerType::Singleton, Some(Type::Integer)) => x.to_string(),
+        (ContainerType::Singleton, _) => format!("{}.to_string()", x),
+        (ContainerType::Array, Some(Type::Integer)) => format!("{}.iter().collect()", x),
+        (ContainerType::Array, _) => format!("{}.iter().map(|x| x.to_string()).collect()", x),
+        (ContainerType::Array2D, _) => format!(
+            "{}.iter().map(|x| x.iter().map(|x| x.to_string()).collect()).collect()",
+            x
+        ),
+    };
+
+    match meta.optional {
+        true if meta.container_ty == ContainerType::Singleton && meta.ty == Some(Type::Integer) => {
+            expr.to_string()
+        }
+        true => format!("{}.map(|x| {})", expr, convert("x")),
+        false => convert(expr),
+    }
+}
+
+/// The owned type of a field, like `Vec<String>` for `&[&str]`.
+struct OwnedTypeFormatter<'a> {
+    meta: &'a Meta,
+}
+
+impl<'a> std::fmt::Display for OwnedTypeFormatter<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut f = CodeFormatter::new(f, "    ");
-        self.generate(&mut f)
+        let ty = match self.meta.ty {
+            Some(Type::Integer) => "i64",
+            _ => "String",
+        };
+        let ty = match self.meta.container_ty {
+            ContainerType::Singleton => ty.to_string(),
+            ContainerType::Array => format!("Vec<{}>", ty),
+            ContainerType::Array2D => format!("Vec<Vec<{}>>", ty),
+        };
+
+        match self.meta.optional {
+            true => write!(f, "Option<{}>", ty),
+            false => write!(f, "{}", ty),
+        }
+    }
+}
+
+/// The type of a field with explicit `'static` lifetimes, as required by associated constants.
+fn blob_kind(meta: &Meta) -> blob::Kind {
+    match (meta.container_ty, meta.ty) {
+        (ContainerType::Singleton, Some(Type::String)) => blob::Kind::String,
+        (ContainerType::Singleton, Some(Type::Integer)) => blob::Kind::Integer,
+        (ContainerType::Array, Some(Type::String)) => blob::Kind::Strings,
+        (ContainerType::Array, Some(Type::Integer)) => blob::Kind::Integers,
+        (ContainerType::Array2D, Some(Type::String)) => blob::Kind::Table,
+        (ContainerType::Array2D, Some(Type::Integer)) => unreachable!("no table of integers"),
+        (_, None) => unreachable!(),
+    }
+}
+
+/// The value of a field in the blob, `None` if the locale doesn't define it.
+fn blob_value(value: &Value, meta: &Meta) -> Option<blob::Value> {
+    let integer = |x: &String| x.parse::<i64>().expect("the value is an integer");
+
+    Some(match (&*value.with_fixed_type(meta), blob_kind(meta)) {
+        (Value::Empty, _) => return None,
+        (Value::Literal(x), blob::Kind::Integer) => blob::Value::Integer(integer(x)),
+        (Value::Literal(x), _) => blob::Value::String(x.clone()),
+        (Value::Array(x), blob::Kind::Integers) => {
+            blob::Value::Integers(x.iter().map(integer).collect())
+        }
+        (Value::Array(x), _) => blob::Value::Strings(x.clone()),
+        (Value::Array2d(x), _) => blob::Value::Table(x.clone()),
+    })
+}
+
+fn static_type(meta: &Meta) -> String {
+    TypeFormatter::new(meta)
+        .to_string()
+        .replace('&', "&'static ")
+}
+
+/// The name of the constant of a keyword.
+fn field_name(key: &str) -> Field {
+    key.replace(['\'', '\"'], "")
+        .replace('-', "_")
+        .replace('=', "eq")
+        .replace('<', "lt")
+        .replace("..", "dotdot")
+        .replace('2', "two")
+        .to_uppercase()
+}
+
+/// Convert the keywords of a category to fields, updating the metadata of the fields of the
+/// category.
+fn convert_fields(
+    object: &parser::Object,
+    cat_field_meta: &mut BTreeMap<Field, Meta>,
+) -> BTreeMap<Field, Value> {
+    let mut fields = BTreeMap::<Field, Value>::new();
+
+    for (key, group) in &object
+        .keywords()
+        .filter(|x| !x.1.is_empty())
+        .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
+        .group_by(|x| x.0)
+    {
+        let key = field_name(key);
+        let group: Vec<_> = group.map(|x| x.1).collect();
+
+        let meta = cat_field_meta.entry(key.clone()).or_insert(Meta::new());
+
+        if group.len() == 1 && group[0].is_empty() {
+            meta.make_optional();
+            fields.insert(key, Value::Empty);
+        } else if group.len() == 1 && group[0].len() == 1 {
+            let singleton = &group[0][0];
+
+            meta.mark_value(singleton);
+
+            fields.insert(key, Value::Literal(singleton.to_string()));
+        } else if group.len() == 1 {
+            // values of different types are all rendered as strings
+            let values = group[0];
+            let vec = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+            meta.mark_array();
+
+            for value in values.iter() {
+                meta.mark_value(value);
+            }
+
+            fields.insert(key, Value::Array(vec));
+        } else {
+            meta.mark_array_2d();
+
+            let mut vec = Vec::with_capacity(group.len());
+
+            for a in group.iter() {
+                for value in a.iter() {
+                    meta.mark_value(value);
+                }
+
+                let inner_vec = a.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+                vec.push(inner_vec);
+            }
+
+            fields.insert(key, Value::Array2d(vec));
+        }
+    }
+
+    fields
+}
+
+/// Identifies an array value of a field, used to share identical values between locales.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct SharedKey {
+    category: Key,
+    field: Field,
+    ty: String,
+    value: String,
+}
+
+impl SharedKey {
+    fn new(category: &str, field: &str, value: &Value, meta: &Meta) -> Self {
+        let value = match (value, meta.ty.as_ref()) {
+            (Value::Array(_) | Value::Array2d(_), Some(ty)) => {
+                BareValueFormatter { value, ty }.to_string()
+            }
+            _ => String::new(),
+        };
+
+        Self {
+            category: category.to_string(),
+            field: field.to_string(),
+            ty: BareTypeFormatter { meta }.to_string(),
+            value,
+        }
+    }
+}
+
+struct SharedValue {
+    name: String,
+    value: Value,
+    ty: Type,
+}
+
+impl CodeGenerator {
+    /// Arrays used by more than one locale are only written once, in the `__data` module, and
+    /// the constants of the locales refer to them. This returns the name of each of them.
+    fn shared_values(&self) -> BTreeMap<SharedKey, SharedValue> {
+        let mut counts = BTreeMap::<SharedKey, (usize, Value, Type)>::new();
+
+        for categories in self.by_language.values() {
+            for (category_name, category) in categories.iter() {
+                if let Category::Fields(fields) = category {
+                    for (field_name, meta) in self.field_metadata[category_name].iter() {
+                        let value = fields[field_name].with_fixed_type(meta);
+                        if value.is_array() {
+                            counts
+                                .entry(SharedKey::new(category_name, field_name, &value, meta))
+                                .or_insert_with(|| (0, value.into_owned(), meta.ty.unwrap()))
+                                .0 += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut indexes = BTreeMap::<(Key, Field), usize>::new();
+        counts
+            .into_iter()
+            .filter(|(_, (count, _, _))| *count > 1)
+            .map(|(key, (_, value, ty))| {
+                let index = indexes
+                    .entry((key.category.clone(), key.field.clone()))
+                    .or_default();
+                let name = format!("{}_{}", key.field, index);
+                *index += 1;
+                (key, SharedValue { name, value, ty })
+            })
+            .collect()
+    }
+
+    fn generate_shared_values<W: Write>(
+        &self,
+        f: &mut CodeFormatter<W>,
+        shared: &BTreeMap<SharedKey, SharedValue>,
+    ) -> std::fmt::Result {
+        for (category_name, values) in &shared.iter().group_by(|(key, _)| key.category.clone()) {
+            write!(
+                f,
+                r#"
+                pub mod {} {{
+                "#,
+                category_name,
+            )?;
+            f.indent(1);
+
+            for (key, shared) in values {
+                write!(
+                    f,
+                    r#"
+            pub const {name}: {ty} = {val};
+            "#,
+                    name = shared.name,
+                    ty = key.ty,
+                    val = BareValueFormatter {
+                        value: &shared.value,
+                        ty: &shared.ty,
+                    },
+                )?;
+            }
+
+            f.dedent(1);
+            write!(
+                f,
+                r#"
+                }}
+                "#,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the `#[cfg]` attribute selecting a locale, if it is not always compiled in.
+    fn write_cfg<W: Write>(&self, f: &mut CodeFormatter<W>, lang: &str) -> std::fmt::Result {
+        let cfg = CfgFormatter::new(&self.features[lang]);
+
+        if cfg.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "\n#[cfg({})]", cfg)
+    }
+}
+
+/// The code of a crate generated by a `CodeGenerator`: its root, the modules of
+/// `GENERATED_DIR`, by name, and the blob of `BLOB_FILE`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Code {
+    pub root: String,
+    pub modules: BTreeMap<String, String>,
+    pub blob: Vec<u8>,
+}
+
+impl Code {
+    /// Read the code generated in a crate from the file of its root.
+    pub fn read(root: &Path) -> io::Result<Self> {
+        let mut code = Self {
+            root: fs::read_to_string(root)?,
+            modules: BTreeMap::new(),
+            blob: Vec::new(),
+        };
+
+        let dir = generated_dir(root);
+        if dir.exists() {
+            code.blob = fs::read(dir.join(BLOB_FILE)).unwrap_or_default();
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                match (path.file_stem().and_then(|x| x.to_str()), path.extension()) {
+                    (Some(name), Some(extension)) if extension == "rs" => {
+                        code.modules
+                            .insert(name.to_string(), fs::read_to_string(&path)?);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(code)
+    }
+
+    /// Write the code of a crate from the file of its root. Only the files that changed are
+    /// written and the modules that are not generated anymore are removed.
+    pub fn write(&self, root: &Path) -> io::Result<()> {
+        let old = Self::read(root).unwrap_or_default();
+        let dir = generated_dir(root);
+        fs::create_dir_all(&dir)?;
+
+        for (name, module) in self.modules.iter() {
+            if old.modules.get(name) != Some(module) {
+                fs::write(dir.join(format!("{}.rs", name)), module)?;
+            }
+        }
+        for name in old.modules.keys() {
+            if !self.modules.contains_key(name) {
+                fs::remove_file(dir.join(format!("{}.rs", name)))?;
+            }
+        }
+        if old.blob != self.blob {
+            fs::write(dir.join(BLOB_FILE), &self.blob)?;
+        }
+        if old.root != self.root {
+            fs::write(root, &self.root)?;
+        }
+
+        Ok(())
+    }
+
+    /// The files that differ from the code `other`, relative to the directory of the root.
+    pub fn changed_files(&self, other: &Code, root_name: &str) -> Vec<String> {
+        let mut files = Vec::new();
+
+        if self.root != other.root {
+            files.push(root_name.to_string());
+        }
+        let names = self
+            .modules
+            .keys()
+            .chain(other.modules.keys())
+            .collect::<BTreeSet<_>>();
+        for name in names {
+            if self.modules.get(name) != other.modules.get(name) {
+                files.push(format!("{}/{}.rs", GENERATED_DIR, name));
+            }
+        }
+        if self.blob != other.blob {
+            files.push(format!("{}/{}", GENERATED_DIR, BLOB_FILE));
+        }
+
+        files
+    }
+
+    /// The IDs of the locales of the code, read from the names of `Locale` by ID.
+    pub fn ids(&self) -> Registry {
+        let module = self.modules.get(LOCALE_MODULE).map_or("", String::as_str);
+        let names = module
+            .split("const LOCALE_NAMES: &[&str] = &[\n")
+            .nth(1)
+            .and_then(|x| x.split("\n];").next())
+            .unwrap_or("")
+            .lines()
+            .map(str::trim)
+            .filter(|x| x.starts_with('"'))
+            .map(|x| x.trim_end_matches(',').trim_matches('"'));
+        let mut lines = String::new();
+        for (id, name) in names.enumerate() {
+            if !name.is_empty() {
+                lines.push_str(&format!("{} {}\n", id, name));
+            }
+        }
+        Registry::parse(&lines).unwrap_or_default()
     }
 }
 
+/// The directory of the generated modules of a crate, from the file of its root.
+fn generated_dir(root: &Path) -> PathBuf {
+    root.parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(GENERATED_DIR)
+}
+
+#[derive(PartialEq)]
 struct Meta {
     optional: bool,
     container_ty: ContainerType,
@@ -689,6 +2653,14 @@ impl Meta {
         }
     }
 
+    /// Mark the type of a value: a field with values of different types is a string.
+    fn mark_value(&mut self, value: &parser::Value) {
+        match value {
+            parser::Value::Integer(_) => self.mark_int(),
+            _ => self.mark_str(),
+        }
+    }
+
     fn make_optional(&mut self) {
         self.optional = true;
     }
@@ -702,13 +2674,14 @@ impl Meta {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub enum ContainerType {
     Singleton,
     Array,
     Array2D,
 }
 
+#[derive(Copy, Clone, PartialEq)]
 pub enum Type {
     String,
     Integer,
@@ -0,0 +1,191 @@
+//! The registry of the IDs of the locales, the discriminants of `Locale` returned by
+//! `Locale::to_u16`.
+//!
+//! It is checked in as `localedata/ids.txt`, a line per locale with its ID and its name, sorted
+//! by ID:
+//!
+//! ```text
+//! # comment
+//! 0 POSIX
+//! 1 aa_DJ
+//! ```
+//!
+//! The ID of a locale never changes: the locales added get the next IDs and the IDs of the
+//! locales removed stay in the registry, so that they are not given to another locale.
+
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// The file of the `localedata` directory with the IDs of the locales.
+pub const IDS_FILE: &str = "ids.txt";
+
+/// The IDs of the locales, by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Registry {
+    ids: BTreeMap<String, u16>,
+}
+
+impl Registry {
+    /// Read a registry from a file. It is empty if the file doesn't exist.
+    pub fn read(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(input) => Self::parse(&input).with_context(|| format!("in {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Parse the lines of a registry, ignoring the empty lines and the comments.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut registry = Self::default();
+
+        for (number, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (id, lang) = match line.split_once(char::is_whitespace) {
+                Some((id, lang)) => (id, lang.trim()),
+                None => bail!("line {}: expected an ID and a locale", number + 1),
+            };
+            let id = id
+                .parse::<u16>()
+                .with_context(|| format!("line {}: invalid ID {}", number + 1, id))?;
+            if let Some(other) = registry.lang(id) {
+                bail!("line {}: {} already has the ID {}", number + 1, other, id);
+            }
+            if registry.ids.insert(lang.to_string(), id).is_some() {
+                bail!("line {}: {} has several IDs", number + 1, lang);
+            }
+        }
+
+        Ok(registry)
+    }
+
+    /// The ID of a locale, if it has one.
+    pub fn get(&self, lang: &str) -> Option<u16> {
+        self.ids.get(lang).copied()
+    }
+
+    /// The locale that has an ID.
+    pub fn lang(&self, id: u16) -> Option<&str> {
+        self.ids
+            .iter()
+            .find(|(_, x)| **x == id)
+            .map(|(lang, _)| lang.as_str())
+    }
+
+    /// The locales with their ID, by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u16)> {
+        self.ids.iter().map(|(lang, id)| (lang.as_str(), *id))
+    }
+
+    /// Give the next IDs to the locales that don't have one, in their order. Returns the
+    /// locales added.
+    ///
+    /// # Panics
+    ///
+    /// If there are more locales than IDs.
+    pub fn assign<I, S>(&mut self, langs: I) -> Vec<String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut next = self.ids.values().max().map_or(0, |x| u32::from(*x) + 1);
+        let mut added = Vec::new();
+
+        for lang in langs {
+            let lang = lang.as_ref();
+            if self.ids.contains_key(lang) {
+                continue;
+            }
+            let id = u16::try_from(next).expect("no ID left for the locales");
+            self.ids.insert(lang.to_string(), id);
+            added.push(lang.to_string());
+            next += 1;
+        }
+
+        added
+    }
+
+    /// The locales of this registry that have another ID in `new`, with their IDs in both.
+    /// The locales `new` doesn't have are not changed.
+    pub fn changes<'a>(&'a self, new: &'a Registry) -> Vec<(&'a str, u16, u16)> {
+        self.iter()
+            .filter_map(|(lang, id)| match new.get(lang) {
+                Some(new_id) if new_id != id => Some((lang, id, new_id)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Writes the registry as its file, sorted by ID.
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# The IDs of the locales, `Locale::to_u16`, updated by `generate-api`."
+        )?;
+        writeln!(
+            f,
+            "# An ID never changes and the IDs of the locales removed are not reused."
+        )?;
+        let mut ids = self.iter().collect::<Vec<_>>();
+        ids.sort_by_key(|(_, id)| *id);
+        for (lang, id) in ids {
+            writeln!(f, "{} {}", id, lang)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let registry = Registry::parse("# comment\n0 POSIX\n\n2 fr_BE\n1 aa_ER@saaho\n").unwrap();
+        assert_eq!(registry.get("POSIX"), Some(0));
+        assert_eq!(registry.get("aa_ER@saaho"), Some(1));
+        assert_eq!(registry.lang(2), Some("fr_BE"));
+        assert_eq!(registry.get("fr_FR"), None);
+        assert_eq!(
+            registry.to_string().lines().skip(2).collect::<Vec<_>>(),
+            ["0 POSIX", "1 aa_ER@saaho", "2 fr_BE"],
+        );
+        assert_eq!(Registry::parse(&registry.to_string()).unwrap(), registry);
+
+        assert!(Registry::parse("0").is_err());
+        assert!(Registry::parse("x fr_BE").is_err());
+        assert!(Registry::parse("0 fr_BE\n0 fr_FR").is_err());
+        assert!(Registry::parse("0 fr_BE\n1 fr_BE").is_err());
+    }
+
+    #[test]
+    fn assign() {
+        let mut registry = Registry::default();
+        assert_eq!(
+            registry.assign(["POSIX", "fr_BE", "fr_FR"]),
+            ["POSIX", "fr_BE", "fr_FR"]
+        );
+        assert_eq!(registry.get("fr_FR"), Some(2));
+
+        // A locale was removed: its ID isn't reused.
+        let old = registry.clone();
+        let mut registry = Registry::parse(&registry.to_string()).unwrap();
+        assert_eq!(registry.assign(["POSIX", "fr_CA", "fr_FR"]), ["fr_CA"]);
+        assert_eq!(registry.get("fr_BE"), Some(1));
+        assert_eq!(registry.get("fr_CA"), Some(3));
+        assert_eq!(registry.get("fr_FR"), Some(2));
+        assert!(old.changes(&registry).is_empty());
+
+        let edited = Registry::parse("0 POSIX\n1 fr_FR\n2 fr_BE").unwrap();
+        assert_eq!(old.changes(&edited), [("fr_BE", 1, 2), ("fr_FR", 2, 1)]);
+    }
+}
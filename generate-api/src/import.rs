@@ -0,0 +1,188 @@
+use crate::parser::{self, Object};
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// The file of the `localedata` directory with the version of `glibc` the locales come from.
+pub const VERSION_FILE: &str = "VERSION";
+
+/// The locales added, removed and renamed by an import.
+#[derive(Debug, Default, PartialEq)]
+pub struct Report {
+    /// The version of `glibc` imported.
+    pub version: String,
+    pub added: BTreeSet<String>,
+    pub removed: BTreeSet<String>,
+    /// The locales removed that have the same data as a locale added, with its name.
+    pub renamed: BTreeMap<String, String>,
+}
+
+/// Replace the locale source files of `localedata/locales` with the ones of the source tree of
+/// `glibc` and write its version to `localedata/VERSION`.
+///
+/// Every file of the directory is copied, the files copied by the locales (like `i18n`)
+/// included, and the files that `glibc` doesn't have anymore are removed.
+pub fn import(glibc: &Path, localedata: &Path) -> Result<Report> {
+    let version = version(glibc)?;
+    let source = glibc.join("localedata").join("locales");
+    let destination = localedata.join("locales");
+
+    let old = files(&destination)?;
+    let new = files(&source)?;
+    let old_locales = old.iter().filter(|x| is_locale(x)).collect::<BTreeSet<_>>();
+    let new_locales = new.iter().filter(|x| is_locale(x)).collect::<BTreeSet<_>>();
+
+    let mut report = Report {
+        version,
+        added: new_locales
+            .difference(&old_locales)
+            .map(|x| x.to_string())
+            .collect(),
+        removed: old_locales
+            .difference(&new_locales)
+            .map(|x| x.to_string())
+            .collect(),
+        renamed: BTreeMap::new(),
+    };
+
+    for removed in report.removed.iter() {
+        let data = read_data(&destination.join(removed))?;
+        for added in report.added.iter() {
+            if report.renamed.values().any(|x| x == added) {
+                continue;
+            }
+            if read_data(&source.join(added))? == data {
+                report.renamed.insert(removed.clone(), added.clone());
+                break;
+            }
+        }
+    }
+    for (removed, added) in report.renamed.iter() {
+        report.removed.remove(removed);
+        report.added.remove(added);
+    }
+
+    for name in old.difference(&new) {
+        fs::remove_file(destination.join(name))?;
+    }
+    for name in new.iter() {
+        fs::copy(source.join(name), destination.join(name))
+            .with_context(|| format!("could not copy {}", name))?;
+    }
+    fs::write(
+        localedata.join(VERSION_FILE),
+        format!("{}\n", report.version),
+    )?;
+
+    Ok(report)
+}
+
+/// The version of `glibc` imported in `localedata`, if the locales were imported.
+pub fn imported_version(localedata: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(localedata.join(VERSION_FILE)) {
+        Ok(version) => Ok(Some(version.trim().to_string())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// The version of a source tree of `glibc`, defined in `version.h`.
+fn version(glibc: &Path) -> Result<String> {
+    let path = glibc.join("version.h");
+    let header =
+        fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))?;
+
+    header
+        .lines()
+        .find_map(|line| {
+            let value = line.strip_prefix("#define VERSION")?.trim();
+            Some(value.trim_matches('"').to_string())
+        })
+        .with_context(|| format!("no VERSION in {}", path.display()))
+}
+
+/// The names of the files of a directory.
+fn files(dir: &Path) -> Result<BTreeSet<String>> {
+    let mut files = BTreeSet::new();
+
+    for entry in fs::read_dir(dir).with_context(|| format!("could not read {}", dir.display()))? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            if let Some(name) = entry.file_name().to_str() {
+                files.insert(name.to_string());
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+fn is_locale(name: &str) -> bool {
+    parser::parse_lang(name).is_ok()
+}
+
+/// The categories of a locale, without `LC_IDENTIFICATION` which names the locale: the data
+/// that stays the same when it is renamed.
+fn read_data(path: &Path) -> Result<Vec<Object>> {
+    let input = fs::read_to_string(path)?;
+    let mut objects = parser::parse(&input).map_err(|err| err.with_path(path))?;
+    objects.retain(|x| x.name != "LC_IDENTIFICATION");
+    Ok(objects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn copy_dir(source: &Path, destination: &Path) {
+        fs::create_dir_all(destination).unwrap();
+        for entry in fs::read_dir(source).unwrap() {
+            let entry = entry.unwrap();
+            let path = destination.join(entry.file_name());
+            if entry.file_type().unwrap().is_dir() {
+                copy_dir(&entry.path(), &path);
+            } else {
+                fs::copy(entry.path(), path).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn import() {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/import");
+        let temp_dir = TempDir::new().unwrap();
+        let localedata = temp_dir.path();
+        copy_dir(&fixtures.join("localedata"), localedata);
+
+        assert_eq!(imported_version(localedata).unwrap(), None);
+        let report = super::import(&fixtures.join("glibc"), localedata).unwrap();
+
+        assert_eq!(
+            report,
+            Report {
+                version: "2.39".to_string(),
+                added: ["fr_CA".to_string()].iter().cloned().collect(),
+                removed: ["wa_BE".to_string()].iter().cloned().collect(),
+                renamed: [("fr_XX".to_string(), "fr_BE".to_string())]
+                    .iter()
+                    .cloned()
+                    .collect(),
+            },
+        );
+        assert_eq!(
+            files(&localedata.join("locales")).unwrap(),
+            files(&fixtures.join("glibc/localedata/locales")).unwrap(),
+        );
+        assert_eq!(
+            fs::read_to_string(localedata.join("locales/fr_FR")).unwrap(),
+            fs::read_to_string(fixtures.join("glibc/localedata/locales/fr_FR")).unwrap(),
+        );
+        assert_eq!(
+            imported_version(localedata).unwrap(),
+            Some("2.39".to_string())
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::parser::{self, Object};
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+/// The main territory of the languages spoken in several territories, when it isn't the
+/// territory named like the language (`de_DE`). Follows the likely subtags of CLDR.
+const MAIN_TERRITORIES: &[(&str, &str)] = &[
+    ("aa", "aa_ET"),
+    ("ar", "ar_EG"),
+    ("bho", "bho_IN"),
+    ("bn", "bn_BD"),
+    ("bo", "bo_CN"),
+    ("ca", "ca_ES"),
+    ("el", "el_GR"),
+    ("en", "en_US"),
+    ("fy", "fy_NL"),
+    ("gez", "gez_ET"),
+    ("li", "li_NL"),
+    ("mai", "mai_IN"),
+    ("nds", "nds_DE"),
+    ("niu", "niu_NU"),
+    ("om", "om_ET"),
+    ("pa", "pa_IN"),
+    ("pap", "pap_AW"),
+    ("sq", "sq_AL"),
+    ("sr", "sr_RS"),
+    ("sv", "sv_SE"),
+    ("sw", "sw_TZ"),
+    ("ta", "ta_IN"),
+    ("ti", "ti_ET"),
+    ("ur", "ur_PK"),
+    ("zh", "zh_CN"),
+];
+
+/// The `copy` of a category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryCopy {
+    /// The file copied.
+    pub source: String,
+    /// Whether the category consists only of the `copy`: it uses the data of the source as is.
+    pub whole: bool,
+}
+
+/// Which file each category of the locales, and of the files they copy, is copied from.
+#[derive(Debug)]
+pub struct Inheritance {
+    copies: BTreeMap<String, BTreeMap<String, CategoryCopy>>,
+    locales: Vec<String>,
+    /// The files copied that are not locales.
+    files: BTreeMap<String, Vec<Object>>,
+}
+
+impl Inheritance {
+    /// Collect the copies of the locales, before they are resolved. The files copied that are
+    /// not locales, like `i18n`, are looked up in `dirs`, the last directory first. A file that
+    /// can't be found ends its chains.
+    pub fn new(locales: &HashMap<String, Vec<Object>>, dirs: &[PathBuf]) -> Result<Self> {
+        let mut inheritance = Self {
+            copies: BTreeMap::new(),
+            locales: locales.keys().cloned().collect(),
+            files: BTreeMap::new(),
+        };
+        inheritance.locales.sort();
+
+        let mut pending = Vec::new();
+        for (lang, objects) in locales.iter() {
+            pending.extend(inheritance.insert(lang, objects));
+        }
+
+        while let Some(name) = pending.pop() {
+            if inheritance.copies.contains_key(&name) || locales.contains_key(&name) {
+                continue;
+            }
+            let path = match dirs
+                .iter()
+                .rev()
+                .map(|dir| dir.join(&name))
+                .find(|x| x.exists())
+            {
+                Some(path) => path,
+                None => continue,
+            };
+            let input = fs::read_to_string(&path)
+                .with_context(|| format!("could not read {}", path.display()))?;
+            let objects = parser::parse(&input).map_err(|err| err.with_path(&path))?;
+            pending.extend(inheritance.insert(&name, &objects));
+            inheritance.files.insert(name, objects);
+        }
+
+        Ok(inheritance)
+    }
+
+    /// Record the copies of a file and return the names of the files copied.
+    fn insert(&mut self, name: &str, objects: &[Object]) -> Vec<String> {
+        let copies = self.copies.entry(name.to_string()).or_default();
+
+        for object in objects {
+            if let Some(source) = object.copied_from() {
+                copies.insert(
+                    object.name.clone(),
+                    CategoryCopy {
+                        source: source.to_string(),
+                        whole: object.entries.len() == 1,
+                    },
+                );
+            }
+        }
+
+        copies.values().map(|x| x.source.clone()).collect()
+    }
+
+    /// Keep only the locales for which `keep` returns `true`: the other ones are treated like
+    /// the files copied that are not locales.
+    pub fn retain_locales(&mut self, keep: impl Fn(&str) -> bool) {
+        self.locales.retain(|x| keep(x));
+    }
+
+    /// The locales, sorted by name.
+    pub fn locales(&self) -> &[String] {
+        &self.locales
+    }
+
+    /// The categories of a file copied that is not a locale.
+    pub fn file(&self, name: &str) -> Option<&[Object]> {
+        self.files.get(name).map(Vec::as_slice)
+    }
+
+    /// Whether the file is a locale.
+    pub fn is_locale(&self, name: &str) -> bool {
+        self.locales
+            .binary_search_by(|x| x.as_str().cmp(name))
+            .is_ok()
+    }
+
+    /// The files the category of a file copies, nearest first.
+    pub fn chain(&self, name: &str, category: &str) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut name = name;
+
+        while let Some(copy) = self.copies.get(name).and_then(|x| x.get(category)) {
+            if chain.contains(&copy.source) {
+                break;
+            }
+            chain.push(copy.source.clone());
+            name = &copy.source;
+        }
+
+        chain
+    }
+
+    /// The locale whose data the category of a locale uses: the last locale reached by copies of
+    /// whole categories.
+    pub fn source_of<'a>(&'a self, lang: &'a str, category: &str) -> &'a str {
+        let mut source = lang;
+
+        for copy in self.chain(lang, category) {
+            let whole = self.copies[source][category].whole;
+            if !whole || !self.is_locale(&copy) {
+                break;
+            }
+            source = self.locales[self.locales.binary_search(&copy).unwrap()].as_str();
+        }
+
+        source
+    }
+
+    /// The locale of the main territory of the language of a locale: the one listed in
+    /// `MAIN_TERRITORIES`, the only territory of the language or the territory named like the
+    /// language. Locales with a modifier are not candidates.
+    pub fn main_territory(&self, lang: &str) -> Option<&str> {
+        let language = lang.split(['_', '@']).next().unwrap();
+        let territories = self
+            .locales
+            .iter()
+            .filter(|x| {
+                !x.contains('@') && x.split('_').next() == Some(language) && x.contains('_')
+            })
+            .collect::<Vec<_>>();
+
+        let main = match MAIN_TERRITORIES.iter().find(|(x, _)| *x == language) {
+            Some((_, main)) => main.to_string(),
+            None if territories.len() == 1 => territories[0].clone(),
+            None => format!("{}_{}", language, language.to_uppercase()),
+        };
+
+        territories
+            .into_iter()
+            .find(|x| **x == main)
+            .map(String::as_str)
+    }
+}
@@ -0,0 +1,20 @@
+//! Generator of the code of `pure-rust-locales` from the locale source files of `glibc`.
+//!
+//! The command `generate-api` generates the `lib.rs` of the crate; [`Builder`] generates a crate
+//! with a subset of the locales, e.g. from a build script.
+
+pub mod blob;
+pub mod builder;
+pub mod diff;
+pub mod emitter;
+pub mod export;
+pub mod fixup;
+pub mod generator;
+pub mod ids;
+pub mod import;
+pub mod inheritance;
+pub mod lint;
+pub mod overlay;
+pub mod parser;
+
+pub use builder::{Builder, Data};
@@ -0,0 +1,396 @@
+use crate::parser::{Object, Value};
+use std::fmt;
+
+/// How bad a problem found in the data is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The data is unusual but can be used.
+    Warning,
+    /// The data is wrong: programs using it would misbehave.
+    Error,
+}
+
+/// A problem found in an item of a locale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub category: String,
+    pub keyword: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(
+            f,
+            "{}: {} {}: {}",
+            severity, self.category, self.keyword, self.message
+        )
+    }
+}
+
+/// The number of values of the items that are lists of names.
+const COUNTS: &[(&str, usize)] = &[
+    ("abday", 7),
+    ("day", 7),
+    ("abmon", 12),
+    ("mon", 12),
+    ("ab_alt_mon", 12),
+    ("alt_mon", 12),
+    ("am_pm", 2),
+];
+
+/// The items of `LC_TIME` that are formats of `strftime`.
+const TIME_FORMATS: &[&str] = &[
+    "d_t_fmt",
+    "d_fmt",
+    "t_fmt",
+    "t_fmt_ampm",
+    "date_fmt",
+    "era_d_t_fmt",
+    "era_d_fmt",
+    "era_t_fmt",
+];
+
+/// The conversions of `strftime` in `glibc`, and the ones that accept the modifiers `E` and `O`.
+const CONVERSIONS: &str = "aAbBcCdDeFgGhHIjklmMnpPrRsStTuUVwWxXyYzZ%+";
+const E_CONVERSIONS: &str = "cCnpPrRstTuxXyYzZ";
+const O_CONVERSIONS: &str = "bBCdegGhHIjklmMnpPrRsStTuUVwWyzZ";
+
+/// Check the items of the categories of a locale, with its copies resolved.
+pub fn lint(objects: &[Object]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for object in objects {
+        for (key, values) in object.keywords() {
+            let mut report = |severity, message: String| {
+                diagnostics.push(Diagnostic {
+                    severity,
+                    category: object.name.clone(),
+                    keyword: key.to_string(),
+                    message,
+                })
+            };
+
+            match (object.name.as_str(), key) {
+                ("LC_TIME", _) if COUNTS.iter().any(|(x, _)| *x == key) => {
+                    let expected = COUNTS.iter().find(|(x, _)| *x == key).unwrap().1;
+                    if values.len() != expected {
+                        report(
+                            Severity::Error,
+                            format!("expected {} values, found {}", expected, values.len()),
+                        );
+                    }
+                }
+                ("LC_TIME", _) if TIME_FORMATS.contains(&key) => {
+                    if let Some(Err(message)) = values.first().and_then(Value::as_str).map(format) {
+                        report(Severity::Error, message);
+                    }
+                }
+                ("LC_TIME", "era") => {
+                    for era in values.iter().filter_map(Value::as_str) {
+                        if let Err(message) = self::era(era) {
+                            report(Severity::Error, format!("`{}`: {}", era, message));
+                        }
+                    }
+                }
+                ("LC_NUMERIC", "grouping") | ("LC_MONETARY", "mon_grouping") => {
+                    if let Err((severity, message)) = grouping(values) {
+                        report(severity, message);
+                    }
+                }
+                ("LC_MONETARY", "int_curr_symbol") => {
+                    if let Some(Err(message)) = values.first().and_then(Value::as_str).map(currency)
+                    {
+                        report(Severity::Error, message);
+                    }
+                }
+                ("LC_MESSAGES", "yesexpr" | "noexpr") => {
+                    if let Some(Err(message)) = values.first().and_then(Value::as_str).map(regex) {
+                        report(Severity::Error, message);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Check that a format only has conversions of `strftime`, with their flags, width and
+/// modifier.
+fn format(format: &str) -> Result<(), String> {
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+        while let Some('_' | '-' | '0' | '^' | '#') = chars.peek() {
+            chars.next();
+        }
+        while let Some('0'..='9') = chars.peek() {
+            chars.next();
+        }
+        let (modifier, conversion) = match chars.next() {
+            Some(modifier @ ('E' | 'O')) => (Some(modifier), chars.next()),
+            conversion => (None, conversion),
+        };
+        let valid = match (modifier, conversion) {
+            (None, Some(x)) => CONVERSIONS.contains(x),
+            (Some('E'), Some(x)) => E_CONVERSIONS.contains(x),
+            (Some(_), Some(x)) => O_CONVERSIONS.contains(x),
+            (_, None) => return Err("the format ends with `%`".to_string()),
+        };
+        if !valid {
+            return Err(format!(
+                "unknown conversion `%{}{}`",
+                modifier.map(String::from).unwrap_or_default(),
+                conversion.unwrap()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check an era: `direction:offset:start_date:end_date:era_name:era_format`.
+fn era(era: &str) -> Result<(), String> {
+    let fields = era.splitn(6, ':').collect::<Vec<_>>();
+    let (direction, offset, start, end, format) = match fields.as_slice() {
+        [direction, offset, start, end, _, format] => (direction, offset, start, end, format),
+        _ => return Err("expected 6 fields separated by `:`".to_string()),
+    };
+
+    if !matches!(*direction, "+" | "-") {
+        return Err(format!("invalid direction `{}`", direction));
+    }
+    if offset.parse::<i32>().is_err() {
+        return Err(format!("invalid offset `{}`", offset));
+    }
+    for date in [start, end] {
+        if !matches!(*date, "-*" | "+*") && !is_date(date) {
+            return Err(format!("invalid date `{}`", date));
+        }
+    }
+    if *start == "-*" || *start == "+*" {
+        return Err("the start date must be a date".to_string());
+    }
+    self::format(format)
+}
+
+/// Whether the string is a date `yyyy/mm/dd`, the year possibly negative.
+fn is_date(date: &str) -> bool {
+    let fields = date.strip_prefix('-').unwrap_or(date).split('/');
+    let fields = fields.map(str::parse::<u32>).collect::<Vec<_>>();
+    matches!(fields.as_slice(), [Ok(_), Ok(1..=12), Ok(1..=31)])
+}
+
+/// Check a grouping: sizes from 1 to 127, the last one possibly `-1` to stop grouping. A `0`
+/// first or a single `-1` means no grouping.
+fn grouping(values: &[Value]) -> Result<(), (Severity, String)> {
+    let sizes = values
+        .iter()
+        .map(|x| match x {
+            Value::Integer(x) => Ok(*x),
+            _ => Err((Severity::Error, format!("`{}` is not an integer", x))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match sizes.as_slice() {
+        [] => Err((Severity::Error, "no value".to_string())),
+        [0] | [-1] => Ok(()),
+        [0, ..] => Err((
+            Severity::Warning,
+            "the sizes after 0 are ignored: there is no grouping".to_string(),
+        )),
+        [rest @ .., last] => {
+            if let Some(x) = rest.iter().find(|x| !(1..=127).contains(*x)) {
+                Err((Severity::Error, format!("invalid group size {}", x)))
+            } else if !(1..=127).contains(last) && *last != -1 {
+                Err((Severity::Error, format!("invalid group size {}", last)))
+            } else if sizes.len() > 2 && rest.iter().all(|x| x == last) {
+                Err((
+                    Severity::Warning,
+                    "the last size is repeated: it is not necessary".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Check an international currency symbol: an ISO 4217 code followed by a separator.
+fn currency(symbol: &str) -> Result<(), String> {
+    let chars = symbol.chars().collect::<Vec<_>>();
+
+    match chars.as_slice() {
+        [] => Ok(()),
+        [a, b, c, _] if [a, b, c].iter().all(|x| x.is_ascii_uppercase()) => Ok(()),
+        _ => Err(format!(
+            "`{}` is not a 3-letter code followed by a separator",
+            symbol
+        )),
+    }
+}
+
+/// Check that an extended regular expression of POSIX compiles: its brackets and parentheses are
+/// balanced, its character classes are known and its repetitions follow something.
+fn regex(regex: &str) -> Result<(), String> {
+    const CLASSES: &[&str] = &[
+        "alnum", "alpha", "blank", "cntrl", "digit", "graph", "lower", "print", "punct", "space",
+        "upper", "xdigit",
+    ];
+    let mut chars = regex.chars().peekable();
+    let mut depth = 0_usize;
+    // Whether a repetition can follow: there is an expression before it.
+    let mut repeatable = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if chars.next().is_none() {
+                    return Err("the expression ends with `\\`".to_string());
+                }
+                repeatable = true;
+            }
+            '[' => {
+                chars.next_if_eq(&'^');
+                chars.next_if_eq(&']');
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some('[') if chars.next_if_eq(&':').is_some() => {
+                            let mut name = String::new();
+                            loop {
+                                match chars.next() {
+                                    Some(':') if chars.next_if_eq(&']').is_some() => break,
+                                    Some(c) => name.push(c),
+                                    None => return Err("unterminated `[:`".to_string()),
+                                }
+                            }
+                            if !CLASSES.contains(&name.as_str()) {
+                                return Err(format!("unknown character class `{}`", name));
+                            }
+                        }
+                        Some(_) => {}
+                        None => return Err("unterminated `[`".to_string()),
+                    }
+                }
+                repeatable = true;
+            }
+            '(' => {
+                depth += 1;
+                repeatable = false;
+            }
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| "unmatched `)`".to_string())?;
+                repeatable = true;
+            }
+            '|' | '^' => repeatable = false,
+            '*' | '+' | '?' | '{' if !repeatable => {
+                return Err(format!("`{}` does not follow an expression", c));
+            }
+            '{' => {
+                let mut bound = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => bound.push(c),
+                        None => return Err("unterminated `{`".to_string()),
+                    }
+                }
+                let valid = match bound.split_once(',') {
+                    Some((min, max)) => {
+                        min.parse::<u32>().is_ok() && (max.is_empty() || max.parse::<u32>().is_ok())
+                    }
+                    None => bound.parse::<u32>().is_ok(),
+                };
+                if !valid {
+                    return Err(format!("invalid repetition `{{{}}}`", bound));
+                }
+            }
+            _ => repeatable = true,
+        }
+    }
+
+    if depth > 0 {
+        return Err("unmatched `(`".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    fn lint_locale(body: &str) -> Vec<String> {
+        let objects = parser::parse(&format!("comment_char %\nescape_char /\n{}", body)).unwrap();
+        lint(&objects).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn time() {
+        assert_eq!(
+            lint_locale(
+                "LC_TIME\nabday \"a\";\"b\"\nam_pm \"AM\";\"PM\"\nd_t_fmt \"%a %Ey %Od %-d %Q\"\nt_fmt \"%H:%M%\"\nera \"+:1:2019//05//01:+*:R:%EC%Ey\";\"+:1:2019//13//01:+*:R:%EC\";\"x\"\nEND LC_TIME\n"
+            ),
+            [
+                "error: LC_TIME abday: expected 7 values, found 2",
+                "error: LC_TIME d_t_fmt: unknown conversion `%Q`",
+                "error: LC_TIME t_fmt: the format ends with `%`",
+                "error: LC_TIME era: `+:1:2019/13/01:+*:R:%EC`: invalid date `2019/13/01`",
+                "error: LC_TIME era: `x`: expected 6 fields separated by `:`",
+            ],
+        );
+        assert_eq!(format("%Od %EY %OB %OC %_5H %%"), Ok(()));
+        assert!(format("%Ed").is_err());
+    }
+
+    #[test]
+    fn numeric_and_monetary() {
+        assert_eq!(
+            lint_locale(
+                "LC_NUMERIC\ngrouping 3;3;3\nEND LC_NUMERIC\nLC_MONETARY\nint_curr_symbol \"eur\"\nmon_grouping 3;-1;2\nEND LC_MONETARY\n"
+            ),
+            [
+                "warning: LC_NUMERIC grouping: the last size is repeated: it is not necessary",
+                "error: LC_MONETARY int_curr_symbol: `eur` is not a 3-letter code followed by a separator",
+                "error: LC_MONETARY mon_grouping: invalid group size -1",
+            ],
+        );
+        assert_eq!(grouping(&[Value::Integer(3), Value::Integer(-1)]), Ok(()));
+        assert_eq!(grouping(&[Value::Integer(0)]), Ok(()));
+        assert_eq!(
+            grouping(&[Value::Integer(0), Value::Integer(0)])
+                .unwrap_err()
+                .0,
+            Severity::Warning,
+        );
+        assert_eq!(currency("EUR "), Ok(()));
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(regex("^[+1yYsS]"), Ok(()));
+        assert_eq!(regex("^(([yY]([eE][sS])?)|([jJ]a?))"), Ok(()));
+        assert_eq!(regex("^[[:alpha:]]{1,3}$"), Ok(()));
+        assert!(regex("^[yY").is_err());
+        assert!(regex("^(yes").is_err());
+        assert!(regex("*y").is_err());
+        assert!(regex("^[[:letter:]]").is_err());
+        assert_eq!(
+            lint_locale("LC_MESSAGES\nyesexpr \"^[yY\"\nEND LC_MESSAGES\n"),
+            ["error: LC_MESSAGES yesexpr: unterminated `[`"],
+        );
+    }
+}
@@ -1,155 +1,371 @@
-pub mod generator;
-pub mod parser;
-
-use crate::parser::{Object, Value};
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use cargo_metadata::MetadataCommand;
-use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use generate_api::fixup::Fixup;
+use generate_api::generator::Code;
+use generate_api::ids::{Registry, IDS_FILE};
+use generate_api::parser::Object;
+use generate_api::{diff, emitter, export, import, lint, Builder};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap};
 use std::env;
 use std::fs;
-use std::io;
-use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Command line arguments.
+struct Args {
+    check: bool,
+    /// Directories with more locale source files, read after `localedata/locales`. A file
+    /// replaces a locale with the same name from a previous directory.
+    locales_dirs: Vec<PathBuf>,
+    /// Directories with overlay files: files named after a locale that redefine some of its
+    /// categories or keywords.
+    overlay_dirs: Vec<PathBuf>,
+    /// Fixups applied to the data. The upstream values are kept in the `raw` modules.
+    fixups: BTreeSet<Fixup>,
+    /// Directory where to write the locales, with the overlays applied, as locale source files
+    /// instead of generating the code.
+    emit_dir: Option<PathBuf>,
+    /// Directory where to write the data of the locales as JSON instead of generating the code.
+    json_dir: Option<PathBuf>,
+    /// Only check the data, without generating the code.
+    lint: bool,
+    /// Fail if the check of the data finds errors.
+    strict: bool,
+    /// Two directories of locale source files to compare instead of generating the code: they
+    /// replace `localedata/locales` in turn.
+    diff_dirs: Option<(PathBuf, PathBuf)>,
+    /// Source tree of `glibc` from which to import the locales before generating the code.
+    glibc_dir: Option<PathBuf>,
+}
+
+impl Args {
+    fn parse() -> Result<Self> {
+        let mut args = Self {
+            check: env::var("CHECK").is_ok(),
+            locales_dirs: Vec::new(),
+            overlay_dirs: Vec::new(),
+            fixups: Fixup::ALL.iter().copied().collect(),
+            emit_dir: None,
+            json_dir: None,
+            lint: false,
+            strict: false,
+            diff_dirs: None,
+            glibc_dir: None,
+        };
+        let mut iter = env::args().skip(1);
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--check" => args.check = true,
+                "--lint" => args.lint = true,
+                "--strict" => args.strict = true,
+                "--locales" => args.locales_dirs.push(
+                    iter.next()
+                        .context("missing directory after --locales")?
+                        .into(),
+                ),
+                "--overlay" => args.overlay_dirs.push(
+                    iter.next()
+                        .context("missing directory after --overlay")?
+                        .into(),
+                ),
+                "--emit" => {
+                    args.emit_dir = Some(
+                        iter.next()
+                            .context("missing directory after --emit")?
+                            .into(),
+                    )
+                }
+                "--json" => {
+                    args.json_dir = Some(
+                        iter.next()
+                            .context("missing directory after --json")?
+                            .into(),
+                    )
+                }
+                "import" => {
+                    args.glibc_dir = Some(
+                        iter.next()
+                            .context("missing glibc source tree after import")?
+                            .into(),
+                    )
+                }
+                "--diff" => {
+                    let old = iter.next().context("missing directories after --diff")?;
+                    let new = iter.next().context("missing directory after --diff")?;
+                    args.diff_dirs = Some((old.into(), new.into()));
+                }
+                "--fixups" => {
+                    args.fixups = iter
+                        .next()
+                        .context("missing list of fixups after --fixups")?
+                        .split(',')
+                        .filter(|x| !x.is_empty())
+                        .map(str::parse)
+                        .collect::<Result<_>>()?
+                }
+                _ => bail!("unexpected argument: {}", arg),
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// A builder for the locales of `locales_dir` instead of `localedata/locales`, with the
+    /// other directories, overlays and fixups of the arguments.
+    fn builder(&self, locales_dir: &Path) -> Builder {
+        let mut builder = Builder::new()
+            .locales_dir(locales_dir)
+            .fixups(self.fixups.iter().copied());
+        for dir in self.locales_dirs.iter() {
+            builder = builder.locales_dir(dir);
+        }
+        for dir in self.overlay_dirs.iter() {
+            builder = builder.overlay_dir(dir);
+        }
+        builder
+    }
+}
 
 fn main() -> Result<()> {
+    let args = Args::parse()?;
     let metadata = MetadataCommand::new().exec()?;
 
-    eprintln!("Reading data...");
+    if let Some((old, new)) = args.diff_dirs.as_ref() {
+        let old = constants(&args.builder(old))?;
+        let new = constants(&args.builder(new))?;
+        for change in diff::diff(&old, &new) {
+            println!("{}", change);
+        }
+        return Ok(());
+    }
 
-    let locales_path = metadata.workspace_root.join("localedata").join("locales");
-    let mut locales = HashMap::new();
+    let localedata = metadata
+        .workspace_root
+        .join("localedata")
+        .into_std_path_buf();
+    let locales_path = localedata.join("locales");
 
-    for entry in fs::read_dir(locales_path)? {
-        let entry = entry?;
-        let file_name = entry.file_name();
-        let lang = file_name.to_str().unwrap();
+    if let Some(glibc) = args.glibc_dir.as_ref() {
+        let old_version = import::imported_version(&localedata)?;
+        let old = constants(&args.builder(&locales_path))?;
+        eprintln!("Importing locales from `{}`...", glibc.display());
+        let report = import::import(glibc, &localedata)?;
+        let new = constants(&args.builder(&locales_path))?;
+        print_changelog(old_version.as_deref(), &report, &diff::diff(&old, &new));
+    }
 
-        if parser::parse_lang(lang).is_err() {
-            // parse only files for which the name matches a language
-            // example: wa_BE@euro
-            continue;
+    let ids_file = localedata.join(IDS_FILE);
+    let ids = Registry::read(&ids_file)?;
+    let mut builder = args.builder(&locales_path).ids(ids.clone());
+    if let Some(version) = import::imported_version(&localedata)? {
+        builder = builder.glibc_version(version);
+    }
+
+    eprintln!("Reading data...");
+
+    if let Some(dir) = args.emit_dir.as_ref() {
+        fs::create_dir_all(dir)?;
+        for (lang, objects) in builder.read()?.iter() {
+            let path = dir.join(lang);
+            eprintln!("Writing to file `{}`...", path.display());
+            fs::write(&path, emitter::emit(objects))?;
         }
+        return Ok(());
+    }
+
+    let data = builder.load()?;
+
+    let errors = report_lints(&data.raw);
+    if args.strict && errors > 0 {
+        bail!("the data has {} errors", errors);
+    }
+    if args.lint {
+        return Ok(());
+    }
 
-        let path = entry.path();
-        if let Ok(input) = std::fs::read_to_string(&path) {
-            eprintln!("{}", path.display());
-            let mut objects = parser::parse(&input)?;
-            validate_and_fix(&mut objects);
-            locales.insert(lang.to_string(), objects);
+    if let Some(dir) = args.json_dir.as_ref() {
+        let exporter = export::Exporter::new(&data.locales, &data.changes, &data.inheritance);
+        fs::create_dir_all(dir)?;
+        for lang in data.inheritance.locales() {
+            write_json(&dir.join(export::file_name(lang)), &exporter.locale(lang))?;
         }
+        write_json(&dir.join(export::INDEX_FILE), &exporter.index())?;
+        return Ok(());
     }
 
     let lib_file = metadata.workspace_root.join("src").join("lib.rs");
+    let manifest_file = metadata.workspace_root.join("Cargo.toml");
+    let code_generator = builder.code_generator(data);
+    let manifest = update_manifest(
+        &fs::read_to_string(&manifest_file)?,
+        &code_generator.cargo_features(),
+    )?;
 
-    if env::var("CHECK").is_ok() {
-        eprintln!("Calculating checksum...");
-        let mut f = Sha256::default();
+    if args.check {
+        if manifest != fs::read_to_string(&manifest_file)? {
+            bail!(
+                "Cargo.toml features are outdated! Please run `cargo run -p generate-api --release`",
+            );
+        }
 
-        write!(f, "{}", generator::CodeGenerator::new(locales))?;
+        let code = code_generator.code();
+        let lib = Code::read(lib_file.as_std_path())?;
 
-        let expected = f.finalize();
-        eprintln!("expected: {:x}", expected);
+        let old_ids = lib.ids();
+        let changed_ids = old_ids.changes(code_generator.ids());
+        if !changed_ids.is_empty() {
+            eprintln!("IDs that differ (lib.rs -> {}):", IDS_FILE);
+            for (lang, old, new) in changed_ids.iter() {
+                eprintln!("    {}: {} -> {}", lang, old, new);
+            }
+            bail!(
+                "the IDs of existing locales would change! Please restore `localedata/{}`",
+                IDS_FILE,
+            );
+        }
+        if *code_generator.ids() != ids {
+            bail!(
+                "`localedata/{}` is outdated! Please run `cargo run -p generate-api --release`",
+                IDS_FILE,
+            );
+        }
 
-        let mut hasher = Sha256::default();
-        io::copy(&mut fs::File::open(&lib_file)?, &mut hasher)?;
-        let got = hasher.finalize();
-        eprintln!("got: {:x}", got);
+        let changed_files = lib.changed_files(&code, "lib.rs");
 
-        if expected != got {
+        if !changed_files.is_empty() {
+            eprintln!("Files that differ: {}", changed_files.join(", "));
+            let changes = diff::diff(
+                &diff::Constants::parse(&lib),
+                &diff::Constants::parse(&code),
+            );
+            if changes.is_empty() {
+                eprintln!("No constant of the locales differs: the rest of the code does.");
+            } else {
+                eprintln!("Constants that differ (lib.rs -> data):");
+            }
+            for change in changes.iter() {
+                eprintln!("    {}", change);
+            }
             bail!(
-                "lib.rs file has been modified! Please run `cargo run -p generate-api --release`",
+                "the generated code has been modified! Please run `cargo run -p generate-api --release`",
             );
         }
     } else {
-        eprintln!("Writing to file `{lib_file}`...");
-        let mut f = BufWriter::new(fs::File::create(&lib_file)?);
-        write!(f, "{}", generator::CodeGenerator::new(locales))?;
+        eprintln!("Writing to file `{lib_file}` and its generated modules...");
+        code_generator.code().write(lib_file.as_std_path())?;
+
+        eprintln!("Writing to file `{manifest_file}`...");
+        fs::write(&manifest_file, manifest)?;
+
+        if *code_generator.ids() != ids {
+            eprintln!("Writing to file `{}`...", ids_file.display());
+            fs::write(&ids_file, code_generator.ids().to_string())?;
+        }
     }
 
     Ok(())
 }
 
-fn validate_and_fix(objects: &mut [Object]) {
-    validate_and_fix_t_fmt_ampm(objects);
-    validate_and_fix_d_t_fmt(objects);
+const FEATURES_BEGIN: &str = "# BEGIN generated locale features";
+const FEATURES_END: &str = "# END generated locale features";
+
+/// Replace the generated locale features of the `[features]` section of the manifest.
+fn update_manifest(manifest: &str, features: &BTreeSet<String>) -> Result<String> {
+    let (begin, end) = match (manifest.find(FEATURES_BEGIN), manifest.find(FEATURES_END)) {
+        (Some(begin), Some(end)) if begin < end => (begin, end),
+        _ => bail!(
+            "could not find `{}` and `{}` in Cargo.toml",
+            FEATURES_BEGIN,
+            FEATURES_END
+        ),
+    };
+
+    let mut updated = manifest[..begin].to_string();
+    updated.push_str(FEATURES_BEGIN);
+    updated.push('\n');
+    for feature in features {
+        updated.push_str(&format!("{} = []\n", feature));
+    }
+    updated.push_str(&manifest[end..]);
+
+    Ok(updated)
 }
 
-/// Add a `T_FMT_AMPM` item if it is missing or empty.
-///
-/// If the locale has non-empty values for `AM_PM` we assume the correct string to be the same as
-/// for POSIX: `%l:%M:%S %p`.
-/// If the locale has empty values for `AM_PM` we set `T_FMT_AMPM` to an empty value, similar to
-/// other locales that don't have a 12-hour clock format.
-fn validate_and_fix_t_fmt_ampm(objects: &mut [Object]) {
-    for object in objects.iter_mut() {
-        if object.name != "LC_TIME" {
+/// Check the data of the locales and print the problems found, by locale. Returns the number of
+/// errors.
+fn report_lints(locales: &HashMap<String, Vec<Object>>) -> usize {
+    let mut errors = 0;
+
+    for (lang, objects) in locales.iter().sorted_by(|a, b| a.0.cmp(b.0)) {
+        let diagnostics = lint::lint(objects);
+        if diagnostics.is_empty() {
             continue;
         }
-        let mut found_t_fmt_ampm = false;
-        let mut am_pm_empty = false;
-        for (key, value) in object.values.iter() {
-            match (key.as_str(), value.as_slice()) {
-                ("t_fmt_ampm" | "copy" | "insert", _) => found_t_fmt_ampm = true,
-                ("am_pm", &[Value::String(ref am), Value::String(ref pm)]) => {
-                    am_pm_empty = am.is_empty() && pm.is_empty()
-                }
-                _ => {}
-            }
-        }
-        if !found_t_fmt_ampm {
-            // Insert `T_FMT_AMPM` if it is missing
-            let value = match am_pm_empty {
-                true => vec![Value::String(String::new())],
-                false => vec![Value::String("%l:%M:%S %p".to_string())],
-            };
-            object.values.push(("t_fmt_ampm".to_string(), value));
-        } else if !am_pm_empty {
-            // Update `T_FMT_AMPM` if it is empty and we do have a value for `AM_PM`.
-            for (key, value) in object.values.iter_mut() {
-                match (key.as_str(), value.as_slice()) {
-                    ("t_fmt_ampm", &[Value::String(ref t_fmt_ampm)]) if t_fmt_ampm.is_empty() => {
-                        *value = vec![Value::String("%l:%M:%S %p".to_string())];
-                    }
-                    _ => {}
-                }
-            }
+        eprintln!("{}:", lang);
+        for diagnostic in diagnostics.iter() {
+            eprintln!("    {}", diagnostic);
         }
+        errors += diagnostics
+            .iter()
+            .filter(|x| x.severity == lint::Severity::Error)
+            .count();
     }
+
+    errors
 }
 
-/// In some locales `D_T_FMT` refers to other items:
-/// to `D_FMT` with `%x`, `T_FMT` with `%X`, and/or `T_FMT_AMPM` with `%r`.
-/// In the same way `T_FMT` can refer `T_FMT_AMPM` with `%r`.
-/// Inlining these strings simplifies the implementation of the strftime parser in chrono.
-fn validate_and_fix_d_t_fmt(objects: &mut [Object]) {
-    for object in objects.iter_mut() {
-        if object.name != "LC_TIME" {
-            continue;
+/// Write a JSON file, indented.
+fn write_json(path: &Path, value: &serde_json::Value) -> Result<()> {
+    eprintln!("Writing to file `{}`...", path.display());
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// The constants of the code generated by a builder.
+fn constants(builder: &Builder) -> Result<diff::Constants> {
+    let code = builder.code_generator(builder.load()?).code();
+    Ok(diff::Constants::parse(&code))
+}
+
+/// Print the changes of an import: the version of `glibc`, the `Locale` variants added, removed
+/// and renamed, and the items that changed in the other locales.
+fn print_changelog(old_version: Option<&str>, report: &import::Report, changes: &[diff::Change]) {
+    let variant = |lang: &str| format!("Locale::{}", lang.replace('@', "_"));
+
+    println!(
+        "glibc {} (previously {})",
+        report.version,
+        old_version.unwrap_or("unknown"),
+    );
+    if !report.added.is_empty() {
+        println!("\nAdded locales:");
+        for lang in report.added.iter() {
+            println!("    {}", variant(lang));
         }
-        let mut d_fmt = String::new();
-        let mut t_fmt = String::new();
-        let mut t_fmt_ampm = String::new();
-        for (key, value) in object.values.iter() {
-            match (key.as_str(), value.as_slice()) {
-                ("d_fmt", &[Value::String(ref value)]) => d_fmt = value.clone(),
-                ("t_fmt", &[Value::String(ref value)]) => t_fmt = value.clone(),
-                ("t_fmt_ampm", &[Value::String(ref value)]) => t_fmt_ampm = value.clone(),
-                _ => {}
-            }
+    }
+    if !report.removed.is_empty() {
+        println!("\nRemoved locales:");
+        for lang in report.removed.iter() {
+            println!("    {}", variant(lang));
         }
-        if t_fmt_ampm.is_empty() {
-            t_fmt_ampm = "%T".to_string();
-        }
-        for (key, ref mut value) in object.values.iter_mut() {
-            if let ("d_t_fmt", vec) | ("t_fmt", vec) = (key.as_str(), value) {
-                if let Value::String(ref val) = vec[0] {
-                    let fmt = val
-                        .replace("%x", &d_fmt)
-                        .replace("%X", &t_fmt)
-                        .replace("%r", &t_fmt_ampm);
-                    vec[0] = Value::String(fmt);
-                }
-            }
+    }
+    if !report.renamed.is_empty() {
+        println!("\nRenamed locales:");
+        for (old, new) in report.renamed.iter() {
+            println!("    {} -> {}", variant(old), variant(new));
+        }
+    }
+    let items = changes
+        .iter()
+        .filter(|x| matches!(x, diff::Change::Item { .. }))
+        .collect::<Vec<_>>();
+    if !items.is_empty() {
+        println!("\nChanged items:");
+        for change in items {
+            println!("    {}", change);
         }
     }
 }
@@ -0,0 +1,268 @@
+use crate::parser::{Entry, Object};
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+
+/// Merge an overlay file into the objects of a locale.
+///
+/// A category of the overlay that starts with `copy` replaces the category of the locale, like
+/// it would in a locale source file. Otherwise the keywords it defines replace the same keywords
+/// of the locale and the other keywords are kept. Categories the locale doesn't have are added.
+pub fn apply(objects: &mut Vec<Object>, overlay: Vec<Object>) {
+    for overlay_object in overlay {
+        let object = match objects.iter_mut().find(|x| x.name == overlay_object.name) {
+            Some(object) => object,
+            None => {
+                objects.push(overlay_object);
+                continue;
+            }
+        };
+
+        if overlay_object.copied_from().is_some() {
+            *object = overlay_object;
+            continue;
+        }
+
+        redefine(&mut object.entries, overlay_object.entries);
+    }
+}
+
+/// Resolve the categories that `copy` another locale and redefine some of its keywords.
+///
+/// The keywords of the copied category are inlined and the redefinitions replace them, following
+/// the semantics of `localedef`. Categories that consist only of a `copy` are kept as they are:
+/// they are links to the other locale. Copies of files that are not locales (like `i18n`) are
+/// left untouched.
+pub fn resolve_copies(locales: &mut HashMap<String, Vec<Object>>) -> Result<()> {
+    let mut to_resolve = Vec::new();
+
+    for (lang, objects) in locales.iter() {
+        for object in objects.iter() {
+            if object.entries.len() > 1 && object.copied_from().is_some() {
+                to_resolve.push((lang.clone(), object.name.clone()));
+            }
+        }
+    }
+    to_resolve.sort();
+
+    for (lang, name) in to_resolve {
+        let mut chain = vec![lang.clone()];
+        if let Some(entries) = resolve(locales, &lang, &name, &mut chain)? {
+            let object = locales
+                .get_mut(&lang)
+                .unwrap()
+                .iter_mut()
+                .find(|x| x.name == name)
+                .unwrap();
+            object.entries = entries;
+        }
+    }
+
+    Ok(())
+}
+
+/// Keep only the locales for which `keep` returns `true`, after `resolve_copies`.
+///
+/// The categories that consist only of a `copy` of a locale that is removed get the data of that
+/// locale: they can't be links to it anymore. They are removed if that locale doesn't define
+/// the category.
+pub fn select(locales: &mut HashMap<String, Vec<Object>>, keep: impl Fn(&str) -> bool) {
+    let mut langs = locales.keys().cloned().collect::<Vec<_>>();
+    langs.sort();
+
+    for lang in langs.iter().filter(|x| keep(x)) {
+        let mut objects = locales[lang].clone();
+        objects.retain_mut(|object| {
+            let mut chain = vec![lang.clone()];
+            while let Some(source) = object.copied_from() {
+                if object.entries.len() > 1
+                    || keep(source)
+                    || !locales.contains_key(source)
+                    || chain.iter().any(|x| x == source)
+                {
+                    return true;
+                }
+                chain.push(source.to_string());
+                match locales[source].iter().find(|x| x.name == object.name) {
+                    Some(copied) => *object = copied.clone(),
+                    None => return false,
+                }
+            }
+            true
+        });
+        locales.insert(lang.clone(), objects);
+    }
+
+    locales.retain(|lang, _| keep(lang));
+}
+
+/// The entries of a category of a locale with its copy resolved. Returns `None` if the copied
+/// file is not a known locale.
+fn resolve(
+    locales: &HashMap<String, Vec<Object>>,
+    lang: &str,
+    name: &str,
+    chain: &mut Vec<String>,
+) -> Result<Option<Vec<Entry>>> {
+    let object = match locales[lang].iter().find(|x| x.name == name) {
+        Some(object) => object,
+        None => bail!("{} is copied but it is not defined in {}", name, lang),
+    };
+
+    let source = match object.copied_from() {
+        Some(source) => source,
+        None => return Ok(Some(object.entries.clone())),
+    };
+
+    if !locales.contains_key(source) {
+        return Ok(None);
+    }
+    if chain.iter().any(|x| x == source) {
+        bail!(
+            "circular copy of {}: {} -> {}",
+            name,
+            chain.join(" -> "),
+            source
+        );
+    }
+    chain.push(source.to_string());
+
+    let mut entries = match resolve(locales, source, name, chain)? {
+        Some(entries) => entries,
+        None => return Ok(None),
+    };
+    redefine(&mut entries, object.entries[1..].to_vec());
+
+    Ok(Some(entries))
+}
+
+/// Add `redefinitions` to `entries`, replacing the keywords they define again.
+fn redefine(entries: &mut Vec<Entry>, redefinitions: Vec<Entry>) {
+    for key in redefinitions.iter().filter_map(Entry::keyword) {
+        entries.retain(|x| x.keyword() != Some(key));
+    }
+    entries.extend(redefinitions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{self, Value};
+
+    fn locale(body: &str) -> Vec<Object> {
+        parser::parse(&format!("comment_char %\nescape_char /\n{}", body)).unwrap()
+    }
+
+    fn time(objects: &[Object]) -> Vec<Entry> {
+        objects
+            .iter()
+            .find(|x| x.name == "LC_TIME")
+            .unwrap()
+            .entries
+            .clone()
+    }
+
+    fn string(key: &str, value: &str) -> Entry {
+        Entry::Keyword(key.to_string(), vec![Value::String(value.to_string())])
+    }
+
+    #[test]
+    fn copy_and_redefine() {
+        let mut locales = HashMap::new();
+        locales.insert(
+            "en_US".to_string(),
+            locale("LC_TIME\nd_fmt \"%m/%d/%Y\"\nt_fmt \"%r\"\nEND LC_TIME\n"),
+        );
+        locales.insert(
+            "en_DE".to_string(),
+            locale("LC_TIME\ncopy \"en_US\"\nd_fmt \"%d.%m.%Y\"\nEND LC_TIME\n"),
+        );
+        locales.insert(
+            "en_AT".to_string(),
+            locale("LC_TIME\ncopy \"en_DE\"\nt_fmt \"%T\"\nEND LC_TIME\n"),
+        );
+
+        resolve_copies(&mut locales).unwrap();
+
+        assert_eq!(
+            time(&locales["en_DE"]),
+            vec![string("t_fmt", "%r"), string("d_fmt", "%d.%m.%Y")],
+        );
+        assert_eq!(
+            time(&locales["en_AT"]),
+            vec![string("d_fmt", "%d.%m.%Y"), string("t_fmt", "%T")],
+        );
+    }
+
+    #[test]
+    fn circular_copy() {
+        let mut locales = HashMap::new();
+        locales.insert(
+            "aa_AA".to_string(),
+            locale("LC_TIME\ncopy \"bb_BB\"\nd_fmt \"\"\nEND LC_TIME\n"),
+        );
+        locales.insert(
+            "bb_BB".to_string(),
+            locale("LC_TIME\ncopy \"aa_AA\"\nd_fmt \"\"\nEND LC_TIME\n"),
+        );
+
+        assert!(resolve_copies(&mut locales).is_err());
+    }
+
+    #[test]
+    fn select_locales() {
+        let mut locales = HashMap::new();
+        locales.insert(
+            "en_US".to_string(),
+            locale("LC_TIME\nd_fmt \"%m-%d-%Y\"\nEND LC_TIME\n"),
+        );
+        locales.insert(
+            "en_CA".to_string(),
+            locale("LC_TIME\ncopy \"en_US\"\nEND LC_TIME\n"),
+        );
+        locales.insert(
+            "en_BE".to_string(),
+            locale("LC_TIME\ncopy \"en_CA\"\nEND LC_TIME\nLC_NAME\ncopy \"en_CA\"\nEND LC_NAME\n"),
+        );
+        locales.insert(
+            "en_DE".to_string(),
+            locale("LC_TIME\ncopy \"en_BE\"\nEND LC_TIME\n"),
+        );
+
+        select(&mut locales, |x| x == "en_BE" || x == "en_DE");
+
+        let mut langs = locales.keys().collect::<Vec<_>>();
+        langs.sort();
+        assert_eq!(langs, ["en_BE", "en_DE"]);
+        assert_eq!(locales["en_BE"].len(), 1);
+        assert_eq!(time(&locales["en_BE"]), vec![string("d_fmt", "%m-%d-%Y")]);
+        assert_eq!(
+            time(&locales["en_DE"]),
+            vec![Entry::Copy("en_BE".to_string())]
+        );
+    }
+
+    #[test]
+    fn overlay_keywords() {
+        let mut objects = locale("LC_TIME\nd_fmt \"%m/%d/%Y\"\nt_fmt \"%r\"\nEND LC_TIME\n");
+        apply(
+            &mut objects,
+            locale("LC_TIME\nd_fmt \"%Y-%m-%d\"\nEND LC_TIME\n"),
+        );
+
+        assert_eq!(
+            time(&objects),
+            vec![string("t_fmt", "%r"), string("d_fmt", "%Y-%m-%d")],
+        );
+    }
+
+    #[test]
+    fn overlay_copy() {
+        let mut objects = locale("LC_TIME\nd_fmt \"%m/%d/%Y\"\nt_fmt \"%r\"\nEND LC_TIME\n");
+        apply(
+            &mut objects,
+            locale("LC_TIME\ncopy \"en_GB\"\nEND LC_TIME\n"),
+        );
+
+        assert_eq!(time(&objects), vec![Entry::Copy("en_GB".to_string())]);
+    }
+}
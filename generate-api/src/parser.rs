@@ -6,25 +6,264 @@ use nom::{
         alpha1, anychar, char, hex_digit1, multispace0, multispace1, not_line_ending, one_of,
         space1,
     },
-    combinator::{all_consuming, cut, map, map_opt, map_parser, map_res, opt, verify},
-    error::{context, ErrorKind},
+    combinator::{all_consuming, map, map_parser, map_res, opt, recognize, verify},
+    error::{ContextError, FromExternalError},
     multi::{fold_many0, fold_many1, many0, many1, separated_list0},
     sequence::{preceded, separated_pair, terminated},
     IResult, Parser,
 };
+use std::fmt;
+use std::path::{Path, PathBuf};
 
+/// The categories a locale source file can define.
+pub const CATEGORIES: &[&str] = &[
+    "LC_CTYPE",
+    "LC_NUMERIC",
+    "LC_TIME",
+    "LC_COLLATE",
+    "LC_MONETARY",
+    "LC_MESSAGES",
+    "LC_PAPER",
+    "LC_NAME",
+    "LC_ADDRESS",
+    "LC_TELEPHONE",
+    "LC_MEASUREMENT",
+    "LC_IDENTIFICATION",
+];
+
+/// Why a locale source file could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    UnterminatedString,
+    UnknownCategory(String),
+    MismatchedEnd { expected: String, found: String },
+    MissingEnd(String),
+    BadUnicodeEscape(String),
+    UnterminatedBlock { start: String, end: String },
+    InvalidArguments(String),
+    Syntax(nom::error::ErrorKind),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            ErrorKind::UnknownCategory(name) => write!(f, "unknown category `{}`", name),
+            ErrorKind::MismatchedEnd { expected, found } => {
+                write!(f, "expected `END {}`, found `END {}`", expected, found)
+            }
+            ErrorKind::MissingEnd(name) => {
+                write!(f, "expected a keyword or `END {}`", name)
+            }
+            ErrorKind::BadUnicodeEscape(escape) => {
+                write!(f, "invalid unicode escape `{}`", escape)
+            }
+            ErrorKind::UnterminatedBlock { start, end } => {
+                write!(f, "`{}` without `{}`", start, end)
+            }
+            ErrorKind::InvalidArguments(keyword) => {
+                write!(f, "invalid arguments for `{}`", keyword)
+            }
+            ErrorKind::Syntax(kind) => write!(f, "syntax error ({})", kind.description()),
+        }
+    }
+}
+
+/// Error of the nom parsers: the remaining input where it happened and why.
 #[derive(Debug, PartialEq)]
+pub struct Error<'a> {
+    input: &'a str,
+    kind: ErrorKind,
+}
+
+impl<'a> Error<'a> {
+    fn failure(input: &'a str, kind: ErrorKind) -> nom::Err<Self> {
+        nom::Err::Failure(Self { input, kind })
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, kind: nom::error::ErrorKind) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::Syntax(kind),
+        }
+    }
+
+    fn append(_input: &'a str, _kind: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: nom::error::ErrorKind, _e: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {}
+
+/// A parse error located in its source: file path, line, column and the offending line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    pub path: Option<PathBuf>,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number in characters, starting at 1.
+    pub column: usize,
+    pub line_text: String,
+}
+
+impl ParseError {
+    fn new(source: &str, error: Error<'_>) -> Self {
+        // the error input is always a sub-slice of the source
+        let offset = error.input.as_ptr() as usize - source.as_ptr() as usize;
+        let line_start = source[..offset].rfind('\n').map(|x| x + 1).unwrap_or(0);
+        let line_end = source[offset..]
+            .find('\n')
+            .map(|x| offset + x)
+            .unwrap_or_else(|| source.len());
+
+        Self {
+            kind: error.kind,
+            path: None,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            line_text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Set the path of the file the error comes from.
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = match &self.path {
+            Some(path) => path.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        writeln!(f, "{}:{}:{}: {}", path, self.line, self.column, self.kind)?;
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", number, self.line_text)?;
+        write!(f, "{} | {}^", margin, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A value of a keyword.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
+    /// A token that is not one of the other values, like `IGNORE` or `<U0041><U0042>`.
     Raw(String),
     String(String),
     Integer(i64),
+    /// A symbolic name: `<U0041>` or `<LATIN>`. The angle brackets are not included.
+    Symbol(String),
+    /// A range of symbols: `<U0041>..<U005A>`.
+    Range {
+        start: String,
+        end: String,
+        ellipsis: Ellipsis,
+    },
+    /// A pair of values, used by the character maps: `(<U0061>,<U0041>)`.
+    Pair(Box<Value>, Box<Value>),
+}
+
+/// The ellipsis of a [`Value::Range`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ellipsis {
+    /// `..`: the symbols end with a hexadecimal number.
+    Hexadecimal,
+    /// `....`: the symbols end with a decimal number.
+    Decimal,
+    /// `...`: the range covers every character between the two.
+    Absolute,
+    /// `..(n)..`: like `..` but only every `n`-th character.
+    Step(u32),
+}
+
+impl Value {
+    /// Classify a bare token.
+    fn from_token(token: String) -> Self {
+        if let Some(pair) = token.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+            if let Some((first, second)) = pair.split_once(',') {
+                return Value::Pair(
+                    Box::new(Value::from_token(first.to_string())),
+                    Box::new(Value::from_token(second.to_string())),
+                );
+            }
+        }
+
+        if let Some((start, rest)) = split_symbol(&token) {
+            if rest.is_empty() {
+                return Value::Symbol(start.to_string());
+            }
+
+            let (ellipsis, rest) = if let Some(rest) = rest.strip_prefix("....") {
+                (Ellipsis::Decimal, rest)
+            } else if let Some(rest) = rest.strip_prefix("...") {
+                (Ellipsis::Absolute, rest)
+            } else if let Some((step, rest)) = rest
+                .strip_prefix("..(")
+                .and_then(|x| x.split_once(").."))
+                .and_then(|(step, rest)| Some((step.parse().ok()?, rest)))
+            {
+                (Ellipsis::Step(step), rest)
+            } else if let Some(rest) = rest.strip_prefix("..") {
+                (Ellipsis::Hexadecimal, rest)
+            } else {
+                return Value::Raw(token);
+            };
+
+            if let Some((end, "")) = split_symbol(rest) {
+                return Value::Range {
+                    start: start.to_string(),
+                    end: end.to_string(),
+                    ellipsis,
+                };
+            }
+        }
+
+        Value::Raw(token)
+    }
+
+    /// The content of a string value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+/// Split the symbol at the start of a token: `<U0041>..<U005A>` gives `U0041` and
+/// `..<U005A>`.
+fn split_symbol(token: &str) -> Option<(&str, &str)> {
+    let rest = token.strip_prefix('<')?;
+    let end = rest.find('>')?;
+    let name = &rest[..end];
+    if name.is_empty() || name.contains('<') {
+        return None;
+    }
+    Some((name, &rest[end + 1..]))
 }
 
 impl From<&Value> for u8 {
     fn from(x: &Value) -> u8 {
         match x {
-            Value::Raw(_) | Value::String(_) => 0,
             Value::Integer(_) => 1,
+            _ => 0,
         }
     }
 }
@@ -34,15 +273,29 @@ impl std::fmt::Display for Value {
         match self {
             Value::Raw(x) | Value::String(x) => write!(f, "{}", x), // FIXME
             Value::Integer(x) => write!(f, "{:?}", x),
+            Value::Symbol(x) => write!(f, "<{}>", x),
+            Value::Range {
+                start,
+                end,
+                ellipsis,
+            } => write!(f, "<{}>{}<{}>", start, ellipsis, end),
+            Value::Pair(first, second) => write!(f, "({},{})", first, second),
         }
     }
 }
 
-fn sp(
-    i: &str,
-    escape_char: char,
-    comment_char: char,
-) -> IResult<&str, Vec<&str>, (&str, ErrorKind)> {
+impl std::fmt::Display for Ellipsis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ellipsis::Hexadecimal => write!(f, ".."),
+            Ellipsis::Decimal => write!(f, "...."),
+            Ellipsis::Absolute => write!(f, "..."),
+            Ellipsis::Step(step) => write!(f, "..({})..", step),
+        }
+    }
+}
+
+fn sp(i: &str, escape_char: char, comment_char: char) -> IResult<&str, Vec<&str>, Error<'_>> {
     let chars = "\n\r";
 
     many0(alt((
@@ -56,33 +309,33 @@ fn sp(
     .parse(i)
 }
 
-fn integer(i: &str) -> IResult<&str, &str, (&str, ErrorKind)> {
+fn integer(i: &str) -> IResult<&str, &str, Error<'_>> {
     let chars = "-0123456789";
 
     take_while1(move |c| chars.contains(c))(i)
 }
 
-fn parse_key(i: &str) -> IResult<&str, String, (&str, ErrorKind)> {
+fn parse_key(i: &str) -> IResult<&str, String, Error<'_>> {
     let chars = "abcdefghijklmnopqrstuvwxyz0123456789_-";
 
     alt((
         map(take_while1(move |c| chars.contains(c)), |x: &str| {
             x.to_string()
         }),
+        // A sequence of symbols, like `<U0417><U0433>` in a transliteration, is a single key.
         map(
-            preceded(char('<'), terminated(take_while1(|c| c != '>'), char('>'))),
-            |x: &str| x.to_string(),
+            recognize(many1(preceded(
+                char('<'),
+                terminated(take_while1(|c| c != '>'), char('>')),
+            ))),
+            |x: &str| x[1..x.len() - 1].to_string(),
         ),
         map(alt((tag(".."), tag("UNDEFINED"))), |x: &str| x.to_string()),
     ))
     .parse(i)
 }
 
-fn parse_raw(
-    i: &str,
-    escape_char: char,
-    comment_char: char,
-) -> IResult<&str, String, (&str, ErrorKind)> {
+fn parse_raw(i: &str, escape_char: char, comment_char: char) -> IResult<&str, String, Error<'_>> {
     let chars = " \t\r\n;";
 
     fold_many1(
@@ -99,11 +352,11 @@ fn parse_raw(
     .parse(i)
 }
 
-fn parse_str(i: &str, escape_char: char) -> IResult<&str, String, (&str, ErrorKind)> {
+fn parse_str(i: &str, escape_char: char) -> IResult<&str, String, Error<'_>> {
     fold_many0(
         map_parser(
             alt((
-                take_while1(|c| c != escape_char && c != '"'),
+                take_while1(|c| c != escape_char && c != '"' && c != '\n'),
                 map(preceded(char(escape_char), char('\n')), |_| ""),
                 preceded(char(escape_char), take(1_usize)),
             )),
@@ -118,48 +371,78 @@ fn parse_str(i: &str, escape_char: char) -> IResult<&str, String, (&str, ErrorKi
     .parse(i)
 }
 
-fn string(i: &str, escape_char: char) -> IResult<&str, String, (&str, ErrorKind)> {
-    context(
-        "string",
-        alt((
-            map(tag("\"\""), |_| String::new()),
-            preceded(
-                char('\"'),
-                cut(terminated(|x| parse_str(x, escape_char), char('\"'))),
-            ),
-        )),
-    )
-    .parse(i)
+fn string(i: &str, escape_char: char) -> IResult<&str, String, Error<'_>> {
+    let (rest, _) = char('"').parse(i)?;
+
+    match terminated(|x| parse_str(x, escape_char), char('"')).parse(rest) {
+        Err(nom::Err::Error(_)) => Err(Error::failure(i, ErrorKind::UnterminatedString)),
+        result => result,
+    }
 }
 
-fn unescape_unicode(i: &str) -> IResult<&str, String, (&str, ErrorKind)> {
-    map(
-        many0(alt((
-            map(take_while1(|c| c != '<'), |x: &str| x.to_string()),
-            map_opt(
-                map_res(
-                    preceded(tag("<U"), terminated(hex_digit1, char('>'))),
-                    |x: &str| u32::from_str_radix(x, 16),
-                ),
-                |x: u32| std::char::from_u32(x).map(|x| x.to_string()),
-            ),
-        ))),
-        |x: Vec<String>| x.join(""),
+fn unicode_escape(i: &str) -> IResult<&str, char, Error<'_>> {
+    map_res(
+        preceded(tag("<U"), terminated(hex_digit1, char('>'))),
+        |x: &str| match u32::from_str_radix(x, 16)
+            .ok()
+            .and_then(std::char::from_u32)
+        {
+            Some(c) => Ok(c),
+            None => Err(()),
+        },
     )
     .parse(i)
 }
 
-fn parse_special_chars(mut i: &str) -> IResult<&str, (char, char), (&str, ErrorKind)> {
-    let mut comment_char = '%';
-    let mut escape_char = '/';
+/// Replace the `<Uxxxx>` escapes of a string with the characters they stand for.
+fn unescape_unicode(i: &str) -> IResult<&str, String, Error<'_>> {
+    let mut unescaped = String::new();
+    let mut rest = i;
+
+    while let Some(pos) = rest.find("<U") {
+        unescaped.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        match unicode_escape(rest) {
+            Ok((remaining, c)) => {
+                unescaped.push(c);
+                rest = remaining;
+            }
+            Err(_) => {
+                let escape = match rest.find('>') {
+                    Some(end) if !rest[..end].contains(char::is_whitespace) => &rest[..=end],
+                    _ => rest.split(char::is_whitespace).next().unwrap(),
+                };
+                return Err(Error::failure(
+                    rest,
+                    ErrorKind::BadUnicodeEscape(escape.to_string()),
+                ));
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    Ok(("", unescaped))
+}
+
+/// The `comment_char` and `escape_char` declared at the start of a file, `#` and `\` by default
+/// like in `localedef`.
+fn parse_special_chars(mut i: &str) -> IResult<&str, (char, char), Error<'_>> {
+    let mut comment_char = '#';
+    let mut escape_char = '\\';
 
     for _ in 0..2 {
-        let (rest, (k, c)) = separated_pair(
+        let (rest, (k, c)) = match separated_pair(
             preceded(multispace0, alt((tag("comment_char"), tag("escape_char")))),
             space1,
             anychar,
         )
-        .parse(i)?;
+        .parse(i)
+        {
+            Ok(x) => x,
+            Err(nom::Err::Error(_)) => break,
+            Err(err) => return Err(err),
+        };
         i = rest;
 
         match k {
@@ -177,7 +460,7 @@ fn key_value(
     i: &str,
     escape_char: char,
     comment_char: char,
-) -> IResult<&str, (String, Vec<Option<Value>>), (&str, ErrorKind)> {
+) -> IResult<&str, (String, Vec<Option<Value>>), Error<'_>> {
     alt((
         separated_pair(
             preceded(|x| sp_comment(x, comment_char), parse_key),
@@ -191,35 +474,181 @@ fn key_value(
     .parse(i)
 }
 
-fn value(
-    i: &str,
-    escape_char: char,
-    comment_char: char,
-) -> IResult<&str, Value, (&str, ErrorKind)> {
+fn value(i: &str, escape_char: char, comment_char: char) -> IResult<&str, Value, Error<'_>> {
     preceded(
         |x| sp(x, escape_char, comment_char),
         alt((
             map_res(integer, |s| s.parse().map(Value::Integer)),
             map(|x| string(x, escape_char), Value::String),
-            map(|x| parse_raw(x, escape_char, comment_char), Value::Raw),
+            map(
+                |x| parse_raw(x, escape_char, comment_char),
+                Value::from_token,
+            ),
         )),
     )
     .parse(i)
 }
 
-#[derive(Debug, PartialEq)]
+/// A category of a locale source file: `LC_TIME` ... `END LC_TIME`.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Object {
     pub name: String,
-    pub values: Vec<(String, Vec<Value>)>,
+    pub entries: Vec<Entry>,
 }
 
-fn parse_object_head(i: &str) -> IResult<&str, &str, (&str, ErrorKind)> {
-    let chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZ_";
+impl Object {
+    /// The keywords defined at the top level of the category, in order.
+    pub fn keywords(&self) -> impl Iterator<Item = (&str, &[Value])> {
+        self.entries.iter().filter_map(|entry| match entry {
+            Entry::Keyword(key, values) => Some((key.as_str(), values.as_slice())),
+            _ => None,
+        })
+    }
 
-    take_while1(move |c| chars.contains(c))(i)
+    /// Mutable version of [`Object::keywords`].
+    pub fn keywords_mut(&mut self) -> impl Iterator<Item = (&str, &mut Vec<Value>)> {
+        self.entries.iter_mut().filter_map(|entry| match entry {
+            Entry::Keyword(key, values) => Some((key.as_str(), values)),
+            _ => None,
+        })
+    }
+
+    /// The file the category copies, if it starts with `copy`.
+    pub fn copied_from(&self) -> Option<&str> {
+        match self.entries.first() {
+            Some(Entry::Copy(source)) => Some(source),
+            _ => None,
+        }
+    }
 }
 
-fn sp_comment(i: &str, comment_char: char) -> IResult<&str, Vec<&str>, (&str, ErrorKind)> {
+/// A statement of a category.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entry {
+    /// `copy "file"`: use the category of another file. Following entries redefine it.
+    Copy(String),
+    /// `include "file";"repertoire"`: include the definitions of another file.
+    Include {
+        file: String,
+        repertoire: Option<String>,
+    },
+    /// Any other keyword with its values, like `d_fmt "%d/%m/%y"` or `<U0041> <a>;<BAS>`.
+    Keyword(String, Vec<Value>),
+    /// `translit_start` ... `translit_end`.
+    Translit(Vec<Entry>),
+    /// `reorder-after <symbol>` followed by the rules to insert after it.
+    ReorderAfter { symbol: String, entries: Vec<Entry> },
+    /// `reorder-sections-after <section>` ... `reorder-sections-end`.
+    ReorderSectionsAfter {
+        section: String,
+        entries: Vec<Entry>,
+    },
+    /// `script <name>`: declare a collation script.
+    Script(String),
+}
+
+impl Entry {
+    /// The name of the keyword, if the entry is one.
+    pub fn keyword(&self) -> Option<&str> {
+        match self {
+            Entry::Keyword(key, _) => Some(key),
+            _ => None,
+        }
+    }
+}
+
+/// A keyword line with the input it starts at, before it is structured into entries.
+struct Line<'a> {
+    input: &'a str,
+    key: String,
+    values: Vec<Value>,
+}
+
+/// Structure keyword lines into entries until `end` (consumed) or, for `reorder-after` blocks,
+/// until the next `reorder-after` (not consumed).
+fn parse_entries<'a>(
+    lines: &mut std::iter::Peekable<std::vec::IntoIter<Line<'a>>>,
+    start: Option<&Line<'a>>,
+    end: Option<&str>,
+) -> std::result::Result<Vec<Entry>, nom::Err<Error<'a>>> {
+    let mut entries = Vec::new();
+
+    loop {
+        let line = match lines.peek() {
+            Some(line) if start.map(|x| x.key.as_str()) == Some("reorder-after") => {
+                if line.key == "reorder-after" {
+                    return Ok(entries);
+                }
+                lines.next().unwrap()
+            }
+            Some(_) => lines.next().unwrap(),
+            None => match (start, end) {
+                (Some(start), Some(end)) => {
+                    return Err(Error::failure(
+                        start.input,
+                        ErrorKind::UnterminatedBlock {
+                            start: start.key.clone(),
+                            end: end.to_string(),
+                        },
+                    ))
+                }
+                _ => return Ok(entries),
+            },
+        };
+
+        if Some(line.key.as_str()) == end {
+            return Ok(entries);
+        }
+
+        let invalid = || Error::failure(line.input, ErrorKind::InvalidArguments(line.key.clone()));
+        let entry = match line.key.as_str() {
+            "copy" => match line.values.as_slice() {
+                [Value::String(file)] => Entry::Copy(file.clone()),
+                _ => return Err(invalid()),
+            },
+            "include" => match line.values.as_slice() {
+                [Value::String(file)] => Entry::Include {
+                    file: file.clone(),
+                    repertoire: None,
+                },
+                [Value::String(file), Value::String(repertoire)] => Entry::Include {
+                    file: file.clone(),
+                    repertoire: Some(repertoire.clone()).filter(|x| !x.is_empty()),
+                },
+                _ => return Err(invalid()),
+            },
+            "script" => match line.values.as_slice() {
+                [Value::Symbol(name)] => Entry::Script(name.clone()),
+                _ => return Err(invalid()),
+            },
+            "translit_start" => {
+                Entry::Translit(parse_entries(lines, Some(&line), Some("translit_end"))?)
+            }
+            "reorder-after" => match line.values.as_slice() {
+                [symbol] => Entry::ReorderAfter {
+                    symbol: symbol.to_string(),
+                    entries: parse_entries(lines, Some(&line), Some("reorder-end"))?,
+                },
+                _ => return Err(invalid()),
+            },
+            "reorder-sections-after" => match line.values.as_slice() {
+                [section] => Entry::ReorderSectionsAfter {
+                    section: section.to_string(),
+                    entries: parse_entries(lines, Some(&line), Some("reorder-sections-end"))?,
+                },
+                _ => return Err(invalid()),
+            },
+            _ => Entry::Keyword(line.key, line.values),
+        };
+        entries.push(entry);
+    }
+}
+
+fn parse_object_head(i: &str) -> IResult<&str, &str, Error<'_>> {
+    take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_')(i)
+}
+
+fn sp_comment(i: &str, comment_char: char) -> IResult<&str, Vec<&str>, Error<'_>> {
     many0(alt((
         preceded(char(comment_char), not_line_ending),
         multispace1,
@@ -227,75 +656,88 @@ fn sp_comment(i: &str, comment_char: char) -> IResult<&str, Vec<&str>, (&str, Er
     .parse(i)
 }
 
-fn object(
-    i: &str,
-    escape_char: char,
-    comment_char: char,
-) -> IResult<&str, Object, (&str, ErrorKind)> {
-    let (i, name) = preceded(|x| sp_comment(x, comment_char), parse_object_head).parse(i)?;
-    let (i, values) = preceded(
-        multispace0,
-        many0(|x| key_value(x, escape_char, comment_char)),
-    )
-    .parse(i)?;
-    let (i, _) = preceded(
-        |x| sp_comment(x, comment_char),
-        terminated(tag(format!("END {}", name).as_str()), multispace0),
-    )
-    .parse(i)?;
+fn object(i: &str, escape_char: char, comment_char: char) -> IResult<&str, Object, Error<'_>> {
+    let (i, _) = sp_comment(i, comment_char)?;
+    let (rest, name) = parse_object_head(i)?;
+    if !CATEGORIES.contains(&name) {
+        return Err(Error::failure(
+            i,
+            ErrorKind::UnknownCategory(name.to_string()),
+        ));
+    }
+    let (mut i, _) = multispace0(rest)?;
+    let mut lines = Vec::new();
+    loop {
+        let (start, _) = sp_comment(i, comment_char)?;
+        match key_value(start, escape_char, comment_char) {
+            Ok((rest, (key, values))) => {
+                lines.push(Line {
+                    input: start,
+                    key,
+                    values: values.into_iter().flatten().collect(),
+                });
+                i = rest;
+            }
+            Err(nom::Err::Error(_)) => break,
+            Err(err) => return Err(err),
+        }
+    }
+    let (i, _) = sp_comment(i, comment_char)?;
+    let i = match preceded(terminated(tag("END"), space1), parse_object_head).parse(i) {
+        Ok((rest, found)) if found == name => rest,
+        Ok((rest, found)) => {
+            return Err(Error::failure(
+                &i[i.len() - rest.len() - found.len()..],
+                ErrorKind::MismatchedEnd {
+                    expected: name.to_string(),
+                    found: found.to_string(),
+                },
+            ))
+        }
+        Err(_) => return Err(Error::failure(i, ErrorKind::MissingEnd(name.to_string()))),
+    };
+    let (i, _) = multispace0(i)?;
 
     Ok((
         i,
         Object {
             name: name.to_string(),
-            values: values
-                .into_iter()
-                .map(|(k, v)| (k, v.into_iter().flatten().collect()))
-                .collect(),
+            entries: parse_entries(&mut lines.into_iter().peekable(), None, None)?,
         },
     ))
 }
 
-fn parse_locale(mut i: &str) -> IResult<&str, Vec<Object>, (&str, ErrorKind)> {
+fn parse_locale(mut i: &str) -> IResult<&str, Vec<Object>, Error<'_>> {
     let mut objects = Vec::new();
-    // NOTE: the default comment_char is # because it's used in iso14651_t1_pinyin
-    // NOTE: I don't know the default escape_char
-    let (rest, special_chars) = opt(parse_special_chars).parse(i)?;
+    let (rest, (comment_char, escape_char)) = parse_special_chars(i)?;
     i = rest;
-    let (comment_char, escape_char) = special_chars.unwrap_or(('#', '\0'));
 
-    while !i.is_empty() {
-        match object(i, escape_char, comment_char) {
-            Ok(x) => {
-                let (rest, o) = x;
-                i = rest;
-                objects.push(o);
-            }
-            _ => {
-                let (rest, _) = all_consuming(|x| sp_comment(x, comment_char)).parse(i)?;
-                i = rest;
-                if i.is_empty() {
-                    break;
-                }
-            }
+    loop {
+        let (rest, _) = sp_comment(i, comment_char)?;
+        if rest.is_empty() {
+            break;
         }
+        let (rest, o) = object(rest, escape_char, comment_char)?;
+        i = rest;
+        objects.push(o);
     }
 
-    Ok((i, objects))
+    Ok(("", objects))
 }
 
-pub fn parse(input: &str) -> Result<Vec<Object>> {
+/// Parse a locale source file. Use [`ParseError::with_path`] to tell where the input comes
+/// from.
+pub fn parse(input: &str) -> std::result::Result<Vec<Object>, ParseError> {
     match parse_locale(input) {
         Ok((_, objects)) => Ok(objects),
-        Err(err) => bail!("could not parse input: {}", err),
+        Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => Err(ParseError::new(input, err)),
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
     }
 }
 
 pub fn parse_lang(input: &str) -> Result<(&str, Option<&str>, Option<&str>)> {
     #[allow(clippy::type_complexity)]
-    fn inner_parser(
-        i: &str,
-    ) -> IResult<&str, (&str, Option<&str>, Option<&str>), (&str, ErrorKind)> {
+    fn inner_parser(i: &str) -> IResult<&str, (&str, Option<&str>, Option<&str>), Error<'_>> {
         let (i, lang) = verify(alpha1, |x: &str| x != "translit").parse(i)?;
         let (i, country) = opt(preceded(char('_'), alpha1)).parse(i)?;
         let (i, variant) = all_consuming(opt(preceded(char('@'), alpha1))).parse(i)?;
@@ -307,3 +749,340 @@ pub fn parse_lang(input: &str) -> Result<(&str, Option<&str>, Option<&str>)> {
         Err(err) => bail!("could not parse lang: {}", err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "comment_char %\nescape_char /\n";
+
+    fn parse_err(body: &str) -> ParseError {
+        parse(&format!("{}{}", HEADER, body)).unwrap_err()
+    }
+
+    #[test]
+    fn parse_ok() {
+        let objects = parse(&format!(
+            "{}LC_TIME\nd_fmt \"<U0025>d/%m\" % comment\nweek 7;19971130;4\nEND LC_TIME\n",
+            HEADER,
+        ))
+        .unwrap();
+
+        assert_eq!(
+            objects,
+            vec![Object {
+                name: "LC_TIME".to_string(),
+                entries: vec![
+                    Entry::Keyword("d_fmt".to_string(), vec![Value::String("%d%m".to_string())]),
+                    Entry::Keyword(
+                        "week".to_string(),
+                        vec![
+                            Value::Integer(7),
+                            Value::Integer(19971130),
+                            Value::Integer(4),
+                        ],
+                    ),
+                ],
+            }],
+        );
+    }
+
+    fn entries(body: &str) -> Vec<Entry> {
+        parse(&format!("{}{}", HEADER, body))
+            .unwrap()
+            .remove(0)
+            .entries
+    }
+
+    fn symbol(name: &str) -> Value {
+        Value::Symbol(name.to_string())
+    }
+
+    #[test]
+    fn copy_include_script() {
+        assert_eq!(
+            entries("LC_CTYPE\ncopy \"i18n\"\ninclude \"translit_combining\";\"\"\nEND LC_CTYPE\n"),
+            vec![
+                Entry::Copy("i18n".to_string()),
+                Entry::Include {
+                    file: "translit_combining".to_string(),
+                    repertoire: None,
+                },
+            ],
+        );
+        assert_eq!(
+            entries("LC_COLLATE\nscript <LATIN>\nEND LC_COLLATE\n"),
+            vec![Entry::Script("LATIN".to_string())],
+        );
+    }
+
+    #[test]
+    fn translit() {
+        assert_eq!(
+            entries(
+                "LC_CTYPE\ntranslit_start\ninclude \"translit_neutral\";\"\"\n\
+                 <U00C4> \"<U0041><U0308>\";\"AE\"\n<U201C> <U0022>\ntranslit_end\n\
+                 END LC_CTYPE\n"
+            ),
+            vec![Entry::Translit(vec![
+                Entry::Include {
+                    file: "translit_neutral".to_string(),
+                    repertoire: None,
+                },
+                Entry::Keyword(
+                    "U00C4".to_string(),
+                    vec![
+                        Value::String("A\u{308}".to_string()),
+                        Value::String("AE".to_string()),
+                    ],
+                ),
+                Entry::Keyword("U201C".to_string(), vec![symbol("U0022")]),
+            ])],
+        );
+
+        let err = parse_err("LC_CTYPE\ntranslit_start\n<U00C4> \"A\"\nEND LC_CTYPE\n");
+        assert_eq!(
+            err.kind,
+            ErrorKind::UnterminatedBlock {
+                start: "translit_start".to_string(),
+                end: "translit_end".to_string(),
+            },
+        );
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+
+    #[test]
+    fn reorder_after() {
+        assert_eq!(
+            entries(
+                "LC_COLLATE\ncopy \"iso14651_t1\"\nreorder-after <U0041>\n<U00C4> <a>;<DIA>\n\
+                 reorder-after <U004F>\n<U00D6> <o>;<DIA>\nreorder-end\nEND LC_COLLATE\n"
+            ),
+            vec![
+                Entry::Copy("iso14651_t1".to_string()),
+                Entry::ReorderAfter {
+                    symbol: "<U0041>".to_string(),
+                    entries: vec![Entry::Keyword(
+                        "U00C4".to_string(),
+                        vec![symbol("a"), symbol("DIA")],
+                    )],
+                },
+                Entry::ReorderAfter {
+                    symbol: "<U004F>".to_string(),
+                    entries: vec![Entry::Keyword(
+                        "U00D6".to_string(),
+                        vec![symbol("o"), symbol("DIA")],
+                    )],
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn ranges_and_continuation() {
+        assert_eq!(
+            entries(
+                "LC_CTYPE\nupper <U0041>..<U005A>;/\n   <U00C0>;/\n   <U0100>..(2)..<U0136>\n\
+                 toupper (<U0061>,<U0041>);(<U0062>,<U0042>)\nEND LC_CTYPE\n"
+            ),
+            vec![
+                Entry::Keyword(
+                    "upper".to_string(),
+                    vec![
+                        Value::Range {
+                            start: "U0041".to_string(),
+                            end: "U005A".to_string(),
+                            ellipsis: Ellipsis::Hexadecimal,
+                        },
+                        symbol("U00C0"),
+                        Value::Range {
+                            start: "U0100".to_string(),
+                            end: "U0136".to_string(),
+                            ellipsis: Ellipsis::Step(2),
+                        },
+                    ],
+                ),
+                Entry::Keyword(
+                    "toupper".to_string(),
+                    vec![
+                        Value::Pair(Box::new(symbol("U0061")), Box::new(symbol("U0041"))),
+                        Value::Pair(Box::new(symbol("U0062")), Box::new(symbol("U0042"))),
+                    ],
+                ),
+            ],
+        );
+        assert_eq!(
+            Value::from_token("<U0041>..(2)..<U005A>".to_string()).to_string(),
+            "<U0041>..(2)..<U005A>",
+        );
+        assert_eq!(
+            Value::from_token("<U0041><U0042>".to_string()),
+            Value::Raw("<U0041><U0042>".to_string()),
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let err = parse_err("LC_TIME\ncopy en_US\nEND LC_TIME\n");
+        assert_eq!(err.kind, ErrorKind::InvalidArguments("copy".to_string()));
+    }
+
+    #[test]
+    fn all_localedata() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../localedata/locales");
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read_to_string(&path).unwrap();
+            if let Err(err) = parse(&input) {
+                panic!("{}", err.with_path(&path));
+            }
+        }
+    }
+
+    #[test]
+    fn unterminated_string() {
+        let err = parse_err("LC_TIME\nd_fmt \"%d/%m\nEND LC_TIME\n");
+        assert_eq!(err.kind, ErrorKind::UnterminatedString);
+        assert_eq!((err.line, err.column), (4, 7));
+        assert_eq!(err.line_text, "d_fmt \"%d/%m");
+    }
+
+    #[test]
+    fn unknown_category() {
+        let err = parse_err("LC_FOO\nEND LC_FOO\n");
+        assert_eq!(err.kind, ErrorKind::UnknownCategory("LC_FOO".to_string()));
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn mismatched_end() {
+        let err = parse_err("LC_TIME\nd_fmt \"%d\"\nEND LC_NUMERIC\n");
+        assert_eq!(
+            err.kind,
+            ErrorKind::MismatchedEnd {
+                expected: "LC_TIME".to_string(),
+                found: "LC_NUMERIC".to_string(),
+            },
+        );
+        assert_eq!((err.line, err.column), (5, 5));
+    }
+
+    #[test]
+    fn missing_end() {
+        let err = parse_err("LC_TIME\nd_fmt \"%d\"\n");
+        assert_eq!(err.kind, ErrorKind::MissingEnd("LC_TIME".to_string()));
+    }
+
+    #[test]
+    fn bad_unicode_escape() {
+        let err = parse_err("LC_TIME\nd_fmt \"%d<U00ZZ>\"\nEND LC_TIME\n");
+        assert_eq!(err.kind, ErrorKind::BadUnicodeEscape("<U00ZZ>".to_string()));
+        assert_eq!((err.line, err.column), (4, 10));
+
+        let err = parse_err("LC_TIME\nd_fmt \"<UD800>\"\nEND LC_TIME\n");
+        assert_eq!(err.kind, ErrorKind::BadUnicodeEscape("<UD800>".to_string()));
+    }
+
+    #[test]
+    fn display() {
+        let err = parse_err("LC_TIME\nd_fmt \"%d\nEND LC_TIME\n").with_path("locales/xx_XX");
+        assert_eq!(
+            err.to_string(),
+            "locales/xx_XX:4:7: unterminated string\n  |\n4 | d_fmt \"%d\n  |       ^",
+        );
+    }
+
+    #[test]
+    fn default_special_chars() {
+        let objects = parse("LC_TIME\nd_fmt \"\\\"%d\" # comment\nEND LC_TIME\n").unwrap();
+        assert_eq!(
+            objects[0].entries,
+            vec![Entry::Keyword(
+                "d_fmt".to_string(),
+                vec![Value::String("\"%d".to_string())],
+            )],
+        );
+
+        let objects = parse("comment_char %\nLC_TIME\nd_fmt \"\\\"%d\"\nEND LC_TIME\n").unwrap();
+        assert_eq!(objects[0].keywords().count(), 1);
+    }
+
+    /// The keywords of the categories of a file, in the terms of the runtime parser of the
+    /// crate. `LC_CTYPE` and `LC_COLLATE` are left out: the runtime parser doesn't structure them.
+    fn keywords(
+        objects: &[Object],
+    ) -> Vec<(String, String, Vec<pure_rust_locales::localedef::Value>)> {
+        use pure_rust_locales::localedef::Value as Runtime;
+
+        let value = |value: &Value| match value {
+            Value::Raw(x) => Runtime::Raw(x.clone()),
+            Value::String(x) => Runtime::String(x.clone()),
+            Value::Integer(x) => Runtime::Integer(*x),
+            Value::Symbol(x) => Runtime::Raw(format!("<{}>", x)),
+            other => panic!("unexpected value {:?}", other),
+        };
+        let mut keywords = Vec::new();
+
+        for object in objects
+            .iter()
+            .filter(|x| x.name != "LC_CTYPE" && x.name != "LC_COLLATE")
+        {
+            for entry in object.entries.iter() {
+                let (key, values) = match entry {
+                    Entry::Copy(file) => ("copy".to_string(), vec![Runtime::String(file.clone())]),
+                    Entry::Include { file, .. } => {
+                        ("include".to_string(), vec![Runtime::String(file.clone())])
+                    }
+                    Entry::Keyword(key, values) => {
+                        (key.clone(), values.iter().map(value).collect())
+                    }
+                    other => panic!("unexpected entry {:?}", other),
+                };
+                keywords.push((object.name.clone(), key, values));
+            }
+        }
+
+        keywords
+    }
+
+    #[test]
+    fn same_as_runtime_parser() {
+        use pure_rust_locales::localedef::{SourceFile, Value as Runtime};
+
+        let localedata = Path::new(env!("CARGO_MANIFEST_DIR")).join("../localedata/locales");
+        let mut files = 0;
+
+        for entry in std::fs::read_dir(localedata).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read_to_string(&path).unwrap();
+            let objects = parse(&input).unwrap();
+            let file = SourceFile::parse(&input).unwrap();
+
+            let runtime = file
+                .definitions
+                .iter()
+                .filter(|x| !matches!(x.category.name(), "LC_CTYPE" | "LC_COLLATE"))
+                .flat_map(|definition| {
+                    definition.keywords.iter().map(move |keyword| {
+                        let values = match keyword.name.as_str() {
+                            // the runtime parser keeps the repertoire as a value
+                            "include" => keyword.values.iter().take(1).cloned().collect(),
+                            _ => keyword.values.clone(),
+                        };
+                        (
+                            definition.category.name().to_string(),
+                            keyword.name.clone(),
+                            values,
+                        )
+                    })
+                })
+                .collect::<Vec<(String, String, Vec<Runtime>)>>();
+
+            assert_eq!(keywords(&objects), runtime, "{}", path.display());
+            files += 1;
+        }
+
+        assert!(files > 300);
+    }
+}
@@ -0,0 +1,369 @@
+//! Reader of the locale data packed in a binary blob by `generate-api`, without allocation.
+//!
+//! A blob holds the same values as the modules of the locales but it is embedded with
+//! `include_bytes!` instead of being compiled from tens of thousands of constants. [`Blob::builtin`]
+//! has every locale of the crate, whatever the Cargo features: a crate can disable the default
+//! features, to compile the constants of `POSIX` only, and read the other locales from the blob.
+//!
+//! The values are read from the blob when they are asked for, the strings and the lists borrow
+//! it:
+//!
+//! ```
+//! use pure_rust_locales::blob::{Blob, Value};
+//! use pure_rust_locales::Category;
+//!
+//! let locale = Blob::builtin().locale("fr_BE").unwrap();
+//! let time = locale.category(Category::LC_TIME).unwrap();
+//!
+//! assert_eq!(time.str("D_FMT"), Some("%d/%m/%y"));
+//! assert_eq!(time.strs("DAY").unwrap().get(1), Some("lundi"));
+//! assert_eq!(time.ints("WEEK").unwrap().iter().last(), Some(4));
+//! assert!(matches!(time.get("FIRST_WEEKDAY"), Some(Value::Int(2))));
+//! assert_eq!(time.get("ERA"), None);
+//! ```
+//!
+//! The items are named like the constants of the modules of the locales. An item the locale
+//! doesn't define, one that is `None` in its module, is missing from the blob. The `raw` and
+//! `provenance` modules are not in the blob.
+//!
+//! `generate_api::Builder::blob` packs the locales selected by a build script, which are read
+//! with [`Blob::new`].
+
+use crate::{Category, Locale};
+use core::convert::TryInto;
+use core::fmt;
+
+pub use crate::list::{Ints, Strs, Table};
+
+/// The first bytes of a blob.
+const MAGIC: &[u8; 4] = b"PRLB";
+
+/// The version of the format of the blob read.
+const VERSION: u32 = 1;
+
+/// The length of the header: the magic number, the version and the number of words.
+const HEADER_LEN: usize = 12;
+
+/// The value of an item the locale doesn't define.
+const NONE: u32 = u32::MAX;
+
+/// Why a blob could not be read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BlobError {
+    /// The data doesn't start with the magic number of a blob.
+    BadMagic,
+    /// The blob was packed in another version of the format.
+    UnsupportedVersion(u32),
+    /// The blob is shorter than its header says.
+    Truncated,
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlobError::BadMagic => write!(f, "not a blob of locale data"),
+            BlobError::UnsupportedVersion(version) => {
+                write!(
+                    f,
+                    "unsupported blob version {}, expected {}",
+                    version, VERSION
+                )
+            }
+            BlobError::Truncated => write!(f, "truncated blob"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlobError {}
+
+/// The locales of a blob.
+#[derive(Copy, Clone)]
+pub struct Blob<'a> {
+    words: &'a [u8],
+    strings: &'a [u8],
+}
+
+impl<'a> Blob<'a> {
+    /// Read the header of a blob. The rest is read when the values are asked for: the values
+    /// that are not in the blob, because it is corrupted, are `None`.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, BlobError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(BlobError::BadMagic);
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != VERSION {
+            return Err(BlobError::UnsupportedVersion(version));
+        }
+        let len = u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize;
+        let end = len
+            .checked_mul(4)
+            .and_then(|x| x.checked_add(HEADER_LEN))
+            .filter(|x| *x <= bytes.len())
+            .ok_or(BlobError::Truncated)?;
+
+        Ok(Self {
+            words: &bytes[HEADER_LEN..end],
+            strings: &bytes[end..],
+        })
+    }
+
+    /// The number of locales.
+    pub fn len(&self) -> usize {
+        self.word(1).unwrap_or(0) as usize
+    }
+
+    /// Whether the blob has no locale.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The locales, sorted by name.
+    pub fn locales(self) -> impl Iterator<Item = BlobLocale<'a>> {
+        (0..self.len()).filter_map(move |i| self.locale_at(i))
+    }
+
+    /// The locale named `name`, like `fr_BE` or `aa_ER@saaho`.
+    pub fn locale(&self, name: &str) -> Option<BlobLocale<'a>> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            let locale = self.locale_at(middle)?;
+            match locale.name()?.cmp(name) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => return Some(locale),
+            }
+        }
+        None
+    }
+
+    fn locale_at(self, index: usize) -> Option<BlobLocale<'a>> {
+        Some(BlobLocale {
+            blob: self,
+            at: self.locale_index(index)?,
+        })
+    }
+
+    /// The index of the entry of a locale, after the entries of the categories. The indexes are
+    /// computed from the words of the blob, which may be corrupted: they are checked for overflow.
+    fn locale_index(&self, index: usize) -> Option<usize> {
+        let categories = self.categories();
+        let entry = categories.checked_add(2)?.checked_mul(index)?;
+        self.category_index(categories, 0)?.checked_add(entry)
+    }
+
+    /// The index of a field of the entry of a category.
+    fn category_index(&self, category: usize, field: usize) -> Option<usize> {
+        category.checked_mul(4)?.checked_add(2 + field)
+    }
+
+    /// The number of categories, bounded by the words of the blob when it is corrupted.
+    fn categories(&self) -> usize {
+        (self.word(0).unwrap_or(0) as usize).min(self.words.len() / 16)
+    }
+
+    pub(crate) fn word(&self, index: usize) -> Option<u32> {
+        let at = index.checked_mul(4)?;
+        let bytes = self.words.get(at..at.checked_add(4)?)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub(crate) fn str_at(&self, index: usize) -> Option<&'a str> {
+        let offset = self.word(index)? as usize;
+        let len = self.word(index.checked_add(1)?)? as usize;
+        core::str::from_utf8(self.strings.get(offset..offset.checked_add(len)?)?).ok()
+    }
+
+    /// The length of the list at `index`, with elements of `width` words. It is bounded by the
+    /// words of the blob when it is corrupted.
+    pub(crate) fn list_len(&self, index: usize, width: usize) -> usize {
+        let available = (self.words.len() / 4).saturating_sub(index.saturating_add(1)) / width;
+        (self.word(index).unwrap_or(0) as usize).min(available)
+    }
+
+    pub(crate) fn int_at(&self, index: usize) -> Option<i64> {
+        let low = self.word(index)? as u64;
+        let high = self.word(index.checked_add(1)?)? as u64;
+        Some(((high << 32) | low) as i64)
+    }
+}
+
+impl<'a> fmt::Debug for Blob<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Blob")
+            .field("locales", &self.len())
+            .finish()
+    }
+}
+
+impl Blob<'static> {
+    /// The locales of the crate, all of them whatever the Cargo features.
+    pub fn builtin() -> Self {
+        Self::new(include_bytes!("generated/locales.blob")).expect("the blob of the crate is valid")
+    }
+}
+
+/// A locale of the crate read from [`Blob::builtin`], returned by [`Locale::info`]: the values
+/// are read from the blob instead of the constants of the modules of the locales, so that a
+/// program choosing its locale at runtime doesn't link the constants of every locale.
+pub(crate) struct BuiltinInfo(pub(crate) Locale);
+
+impl BuiltinInfo {
+    /// The items of a category of the locale.
+    pub(crate) fn category(&self, category: Category) -> Option<BlobCategory<'static>> {
+        Blob::builtin().locale(self.0.name())?.category(category)
+    }
+}
+
+/// A locale of a [`Blob`].
+#[derive(Debug, Copy, Clone)]
+pub struct BlobLocale<'a> {
+    blob: Blob<'a>,
+    /// The index of the entry of the locale.
+    at: usize,
+}
+
+impl<'a> BlobLocale<'a> {
+    /// The name of the locale, like `fr_BE` or `aa_ER@saaho`.
+    pub fn name(&self) -> Option<&'a str> {
+        self.blob.str_at(self.at)
+    }
+
+    /// The items of a category, `None` for the categories that have no items in the crate, like
+    /// `LC_CTYPE`.
+    pub fn category(&self, category: Category) -> Option<BlobCategory<'a>> {
+        let blob = self.blob;
+        let index = (0..blob.categories()).find(|&i| {
+            blob.category_index(i, 0).and_then(|x| blob.str_at(x)) == Some(category.name())
+        })?;
+        let record = blob.word(self.at.checked_add(2)?.checked_add(index)?)?;
+        if record == NONE {
+            return None;
+        }
+
+        Some(BlobCategory {
+            blob,
+            items: blob.word(blob.category_index(index, 3)?)? as usize,
+            len: blob.word(blob.category_index(index, 2)?)? as usize,
+            record: record as usize,
+        })
+    }
+}
+
+/// The items of a category of a locale of a [`Blob`].
+#[derive(Debug, Copy, Clone)]
+pub struct BlobCategory<'a> {
+    blob: Blob<'a>,
+    /// The index of the table of the names and kinds of the items.
+    items: usize,
+    len: usize,
+    /// The index of the values of the items.
+    record: usize,
+}
+
+impl<'a> BlobCategory<'a> {
+    /// The value of the item named `name`, like `D_FMT`, if the locale defines it.
+    pub fn get(&self, name: &str) -> Option<Value<'a>> {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.blob.str_at(self.item_index(middle, 0)?)?.cmp(name) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => return self.value(middle),
+            }
+        }
+        None
+    }
+
+    /// The items the locale defines, with their name, sorted by name.
+    pub fn items(self) -> impl Iterator<Item = (&'a str, Value<'a>)> {
+        (0..self.len).filter_map(move |i| {
+            let name = self.blob.str_at(self.item_index(i, 0)?)?;
+            Some((name, self.value(i)?))
+        })
+    }
+
+    /// The value of an item that is a string.
+    pub fn str(&self, name: &str) -> Option<&'a str> {
+        match self.get(name)? {
+            Value::Str(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The value of an item that is an integer.
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.get(name)? {
+            Value::Int(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The value of an item that is a list of strings.
+    pub fn strs(&self, name: &str) -> Option<Strs<'a>> {
+        match self.get(name)? {
+            Value::Strs(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The value of an item that is a list of integers.
+    pub fn ints(&self, name: &str) -> Option<Ints<'a>> {
+        match self.get(name)? {
+            Value::Ints(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The value of an item that is a list of lists of strings.
+    pub fn table(&self, name: &str) -> Option<Table<'a>> {
+        match self.get(name)? {
+            Value::Table(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// The index of a field of the entry of an item in the table of the items.
+    fn item_index(&self, index: usize, field: usize) -> Option<usize> {
+        index
+            .checked_mul(3)?
+            .checked_add(field)?
+            .checked_add(self.items)
+    }
+
+    fn value(&self, index: usize) -> Option<Value<'a>> {
+        let kind = self.blob.word(self.item_index(index, 2)?)?;
+        let at = self.blob.word(self.record.checked_add(index)?)?;
+        if at == NONE {
+            return None;
+        }
+        let (blob, at) = (self.blob, at as usize);
+
+        match kind {
+            0 => blob.str_at(at).map(Value::Str),
+            1 => blob.int_at(at).map(Value::Int),
+            2 => Some(Value::Strs(Strs::blob(blob, at))),
+            3 => Some(Value::Ints(Ints::blob(blob, at))),
+            4 => Some(Value::Table(Table::blob(blob, at))),
+            _ => None,
+        }
+    }
+}
+
+/// The value of an item, with the type of its constant.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// `&str`
+    Str(&'a str),
+    /// `i64`
+    Int(i64),
+    /// `&[&str]`
+    Strs(Strs<'a>),
+    /// `&[i64]`
+    Ints(Ints<'a>),
+    /// `&[&[&str]]`
+    Table(Table<'a>),
+}
@@ -0,0 +1,1092 @@
+//! Reader and writer of the locales compiled by `localedef`, the binary files `glibc` loads at
+//! runtime.
+//!
+//! A compiled locale is a directory with a file per category, like
+//! `/usr/lib/locale/fr_BE.utf8/LC_TIME`, read by
+//! [`LocaleData::load_compiled`](crate::LocaleData::load_compiled), or an entry of the
+//! `locale-archive` file, read by [`Archive`]. The categories can also be read one by one, like
+//! with [`TimeData::from_compiled`](crate::owned::TimeData::from_compiled).
+//!
+//! [`LocaleData::write_compiled`](crate::LocaleData::write_compiled) writes the categories of a
+//! locale in the same format, byte for byte, as `localedef`.
+//!
+//! Only the format of `glibc` 2.27 and later, in the byte order of the machine, is supported.
+//! The strings must be UTF-8: the locales compiled with another charmap than `UTF-8` or ASCII
+//! can't be read.
+
+use crate::owned::{
+    AddressData, IdentificationData, LocaleData, MessagesData, MonetaryData, NumericData,
+    TelephoneData, TimeData,
+};
+use crate::Category;
+use core::fmt;
+use std::borrow::Cow;
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+/// The codeset of the files written.
+const CODESET: &str = "UTF-8";
+
+/// The magic number of a `locale-archive` file.
+const ARCHIVE_MAGIC: u32 = 0xde02_0109;
+
+/// Why a compiled locale could not be read or written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The file doesn't start with the magic number of the category or of an archive: it is
+    /// something else or it was compiled by a version of `glibc` with another format.
+    BadMagic { expected: u32, found: u32 },
+    /// The file has fewer items than the category has in the supported format: it was compiled
+    /// by an older version of `glibc`.
+    MissingItems { expected: usize, found: usize },
+    /// An item or a table is past the end of the file.
+    Truncated,
+    /// A string is not UTF-8.
+    InvalidUtf8,
+    /// The archive has no locale with this name.
+    NotFound(String),
+    /// The archive has no data for a category of the locale.
+    MissingCategory(Category),
+    /// An entry of `ERA` can't be written: it is not `direction:offset:start:end:name:format`.
+    InvalidEra(String),
+    /// A file could not be read or written.
+    Io(std::io::ErrorKind),
+}
+
+/// An error of the reading or the writing of a compiled locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// The file the error is in, if it is not the data read directly.
+    pub file: Option<String>,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    fn in_file(mut self, file: &str) -> Self {
+        self.file.get_or_insert_with(|| file.to_string());
+        self
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self { file: None, kind }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::BadMagic { expected, found } => write!(
+                f,
+                "bad magic number {:#010x}, expected {:#010x}",
+                found, expected
+            ),
+            ErrorKind::MissingItems { expected, found } => {
+                write!(f, "{} items, expected at least {}", found, expected)
+            }
+            ErrorKind::Truncated => write!(f, "truncated file"),
+            ErrorKind::InvalidUtf8 => write!(f, "string is not UTF-8"),
+            ErrorKind::NotFound(name) => write!(f, "locale `{}` not found", name),
+            ErrorKind::MissingCategory(category) => {
+                write!(f, "no data for `{}`", category.name())
+            }
+            ErrorKind::InvalidEra(era) => write!(f, "invalid era `{}`", era),
+            ErrorKind::Io(kind) => write!(f, "could not access the file ({:?})", kind),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}: ", file)?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The number of the category in `glibc`, which has `LC_ALL` between `LC_MESSAGES` and
+/// `LC_PAPER`.
+pub(crate) fn glibc_number(category: Category) -> usize {
+    let index = category as usize;
+    index + (index >= Category::LC_PAPER as usize) as usize
+}
+
+/// The magic number of the file of a category.
+pub(crate) fn magic(category: Category) -> u32 {
+    match category {
+        Category::LC_CTYPE => 0x2009_0720,
+        Category::LC_COLLATE => 0x2005_1014,
+        _ => 0x2003_1115 ^ glibc_number(category) as u32,
+    }
+}
+
+/// The number of items of the file of a category, for the categories of the crate.
+pub(crate) fn item_count(category: Category) -> usize {
+    match category {
+        Category::LC_NUMERIC => 6,
+        Category::LC_TIME => 159,
+        Category::LC_MONETARY => 46,
+        Category::LC_MESSAGES => 5,
+        Category::LC_ADDRESS => 13,
+        Category::LC_TELEPHONE => 5,
+        Category::LC_IDENTIFICATION => 16,
+        _ => unreachable!("unsupported category {}", category.name()),
+    }
+}
+
+/// The path of the file of a category in the directory of a compiled locale.
+pub fn file_name(category: Category) -> &'static str {
+    match category {
+        Category::LC_MESSAGES => "LC_MESSAGES/SYS_LC_MESSAGES",
+        _ => category.name(),
+    }
+}
+
+fn word(data: &[u8], offset: usize) -> Result<u32, ErrorKind> {
+    let bytes = data.get(offset..offset + 4).ok_or(ErrorKind::Truncated)?;
+    Ok(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// The string at an offset and the offset after its NUL.
+fn c_string(data: &[u8], offset: usize) -> Result<(&str, usize), ErrorKind> {
+    let bytes = data.get(offset..).ok_or(ErrorKind::Truncated)?;
+    let len = bytes
+        .iter()
+        .position(|x| *x == 0)
+        .ok_or(ErrorKind::Truncated)?;
+    let string = core::str::from_utf8(&bytes[..len]).map_err(|_| ErrorKind::InvalidUtf8)?;
+    Ok((string, offset + len + 1))
+}
+
+/// The items of the file of a category: a header with the magic number, the number of items and
+/// their offsets, then their values.
+struct Items<'a> {
+    data: &'a [u8],
+    count: usize,
+}
+
+impl<'a> Items<'a> {
+    fn new(data: &'a [u8], category: Category) -> Result<Self, ErrorKind> {
+        let found = word(data, 0)?;
+        if found != magic(category) {
+            return Err(ErrorKind::BadMagic {
+                expected: magic(category),
+                found,
+            });
+        }
+        let count = word(data, 4)? as usize;
+        if count < item_count(category) {
+            return Err(ErrorKind::MissingItems {
+                expected: item_count(category),
+                found: count,
+            });
+        }
+        if data.len() < 8 + 4 * count {
+            return Err(ErrorKind::Truncated);
+        }
+        Ok(Self { data, count })
+    }
+
+    fn offset(&self, item: usize) -> Result<usize, ErrorKind> {
+        debug_assert!(item < self.count);
+        Ok(word(self.data, 8 + 4 * item)? as usize)
+    }
+
+    fn string(&self, item: usize) -> Result<String, ErrorKind> {
+        Ok(c_string(self.data, self.offset(item)?)?.0.to_string())
+    }
+
+    /// A string that is empty when it is not defined.
+    fn optional(&self, item: usize) -> Result<Option<String>, ErrorKind> {
+        Ok(Some(self.string(item)?).filter(|x| !x.is_empty()))
+    }
+
+    /// Strings that are items of their own, like `DAY_1` ... `DAY_7`.
+    fn strings(&self, first: usize, count: usize) -> Result<Vec<String>, ErrorKind> {
+        (first..first + count).map(|x| self.string(x)).collect()
+    }
+
+    /// Strings that are stored one after the other in a single item, like `ERA`.
+    fn string_list(&self, item: usize, count: usize) -> Result<Vec<String>, ErrorKind> {
+        let mut offset = self.offset(item)?;
+        let mut list = Vec::new();
+        for _ in 0..count {
+            let (string, next) = c_string(self.data, offset)?;
+            list.push(string.to_string());
+            offset = next;
+        }
+        Ok(list)
+    }
+
+    /// A signed byte, like `FRAC_DIGITS`.
+    fn byte(&self, item: usize) -> Result<i64, ErrorKind> {
+        let offset = self.offset(item)?;
+        let byte = self.data.get(offset).ok_or(ErrorKind::Truncated)?;
+        Ok(*byte as i8 as i64)
+    }
+
+    fn word(&self, item: usize) -> Result<u32, ErrorKind> {
+        word(self.data, self.offset(item)?)
+    }
+
+    /// A grouping: bytes up to a NUL, where `CHAR_MAX` stands for `-1`. No grouping at all is
+    /// stored as an empty string.
+    fn grouping(&self, item: usize) -> Result<Vec<i64>, ErrorKind> {
+        let offset = self.offset(item)?;
+        let bytes = self.data.get(offset..).ok_or(ErrorKind::Truncated)?;
+        let len = bytes
+            .iter()
+            .position(|x| *x == 0)
+            .ok_or(ErrorKind::Truncated)?;
+        let grouping = bytes[..len]
+            .iter()
+            .map(|x| match *x {
+                0x7f => -1,
+                x => x as i8 as i64,
+            })
+            .collect::<Vec<_>>();
+        match grouping.is_empty() {
+            true => Ok(std::vec![-1]),
+            false => Ok(grouping),
+        }
+    }
+}
+
+/// The file of a category being written in the same way as `localedef`: the items are appended
+/// in order and the offset of each is recorded for the header.
+struct Writer {
+    category: Category,
+    offsets: Vec<u32>,
+    data: Vec<u8>,
+    /// Whether the items are parts of a single item, like the strings of `ALT_DIGITS`, and the
+    /// first part has been written.
+    structure: Option<bool>,
+}
+
+impl Writer {
+    fn new(category: Category) -> Self {
+        Self {
+            category,
+            offsets: Vec::with_capacity(item_count(category)),
+            data: Vec::new(),
+            structure: None,
+        }
+    }
+
+    /// Record the offset of the next item unless it is a part of a structure other than the
+    /// first. The numbers and the wide strings are aligned to 4 bytes.
+    fn begin(&mut self, align: bool) {
+        if align {
+            self.data.resize((self.data.len() + 3) & !3, 0);
+        }
+        match self.structure {
+            Some(true) => {}
+            _ => self.offsets.push(self.data.len() as u32),
+        }
+        if self.structure.is_some() {
+            self.structure = Some(true);
+        }
+    }
+
+    fn start_structure(&mut self) {
+        self.structure = Some(false);
+    }
+
+    fn end_structure(&mut self) {
+        if self.structure == Some(false) {
+            self.offsets.push(self.data.len() as u32);
+        }
+        self.structure = None;
+    }
+
+    fn string(&mut self, string: &str) {
+        self.begin(false);
+        self.data.extend_from_slice(string.as_bytes());
+        self.data.push(0);
+    }
+
+    /// A string in UCS-4, the `wchar_t` of `glibc`.
+    fn wide_string(&mut self, string: &str) {
+        self.begin(true);
+        for c in string.chars().chain(Some('\0')) {
+            self.data.extend_from_slice(&(c as u32).to_ne_bytes());
+        }
+    }
+
+    fn byte(&mut self, byte: i64) {
+        self.begin(false);
+        self.data.push(byte as u8);
+    }
+
+    fn word(&mut self, word: u32) {
+        self.begin(true);
+        self.data.extend_from_slice(&word.to_ne_bytes());
+    }
+
+    fn grouping(&mut self, grouping: &[i64]) {
+        self.begin(false);
+        if grouping != [-1] {
+            self.data.extend(
+                grouping
+                    .iter()
+                    .map(|x| if *x == -1 { 0x7f } else { *x as u8 }),
+            );
+        }
+        self.data.push(0);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        debug_assert_eq!(self.offsets.len(), item_count(self.category));
+        let header = 8 + 4 * self.offsets.len() as u32;
+        let mut file = Vec::with_capacity(header as usize + self.data.len());
+        file.extend_from_slice(&magic(self.category).to_ne_bytes());
+        file.extend_from_slice(&(self.offsets.len() as u32).to_ne_bytes());
+        for offset in self.offsets {
+            file.extend_from_slice(&(header + offset).to_ne_bytes());
+        }
+        file.extend_from_slice(&self.data);
+        file
+    }
+}
+
+/// The code point of the first character of a string, or 0.
+fn wide_char(string: &str) -> u32 {
+    string.chars().next().map_or(0, |x| x as u32)
+}
+
+/// Parse a date of an `ERA` entry in the way `localedef` stores it: the years are from 1900,
+/// with no year 0, and the months from 0.
+fn era_date(date: &str) -> Option<[i32; 3]> {
+    match date {
+        "-*" => return Some([i32::MIN; 3]),
+        "+*" => return Some([i32::MAX; 3]),
+        _ => {}
+    }
+    let mut parts = date.split('/').map(|x| x.parse::<i32>().ok());
+    let (year, month, day) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Some(year)), Some(Some(month)), Some(Some(day)), None) => (year, month, day),
+        _ => return None,
+    };
+    let year = match year < 0 {
+        true => year + 1 - 1900,
+        false => year - 1900,
+    };
+    Some([year, month - 1, day])
+}
+
+/// Read the file of a category with `read`.
+fn read<T>(
+    data: &[u8],
+    category: Category,
+    read: impl FnOnce(&Items) -> Result<T, ErrorKind>,
+) -> Result<T, Error> {
+    let items = Items::new(data, category)?;
+    Ok(read(&items)?)
+}
+
+impl AddressData {
+    /// Read the `LC_ADDRESS` file of a compiled locale.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_ADDRESS, |items| {
+            Ok(Self {
+                postal_fmt: items.optional(0)?,
+                country_name: items.optional(1)?,
+                country_post: items.optional(2)?,
+                country_abtwo: items.optional(3)?,
+                country_ab3: items.optional(4)?,
+                country_car: items.optional(5)?,
+                country_num: Some(items.word(6)? as i64).filter(|x| *x != 0),
+                country_isbn: items.optional(7)?,
+                lang_name: items.optional(8)?,
+                lang_ab: items.optional(9)?,
+                lang_term: items.optional(10)?,
+                lang_lib: items.optional(11)?,
+            })
+        })
+    }
+
+    /// Write the `LC_ADDRESS` file of a compiled locale.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_ADDRESS);
+        let string = |x: &Option<String>| x.clone().unwrap_or_default();
+        file.string(&string(&self.postal_fmt));
+        file.string(&string(&self.country_name));
+        file.string(&string(&self.country_post));
+        file.string(&string(&self.country_abtwo));
+        file.string(&string(&self.country_ab3));
+        file.string(&string(&self.country_car));
+        file.word(self.country_num.unwrap_or(0) as u32);
+        file.string(&string(&self.country_isbn));
+        file.string(&string(&self.lang_name));
+        file.string(&string(&self.lang_ab));
+        file.string(&string(&self.lang_term));
+        file.string(&string(&self.lang_lib));
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl IdentificationData {
+    /// Read the `LC_IDENTIFICATION` file of a compiled locale.
+    ///
+    /// The rows of `category` are in the order of [`Category::ALL`].
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_IDENTIFICATION, |items| {
+            let category = items
+                .string_list(14, Category::ALL.len())?
+                .into_iter()
+                .zip(Category::ALL.iter())
+                .filter(|(version, _)| !version.is_empty())
+                .map(|(version, category)| std::vec![version, category.name().to_string()])
+                .collect::<Vec<_>>();
+
+            Ok(Self {
+                title: items.optional(0)?,
+                source: items.optional(1)?,
+                address: items.optional(2)?,
+                contact: items.optional(3)?,
+                email: items.optional(4)?,
+                tel: items.optional(5)?,
+                fax: items.optional(6)?,
+                language: items.optional(7)?,
+                territory: items.optional(8)?,
+                abbreviation: items.optional(11)?,
+                revision: items.optional(12)?,
+                date: items.optional(13)?,
+                category: Some(category).filter(|x| !x.is_empty()),
+            })
+        })
+    }
+
+    /// Write the `LC_IDENTIFICATION` file of a compiled locale.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_IDENTIFICATION);
+        let string = |x: &Option<String>| x.clone().unwrap_or_default();
+        file.string(&string(&self.title));
+        file.string(&string(&self.source));
+        file.string(&string(&self.address));
+        file.string(&string(&self.contact));
+        file.string(&string(&self.email));
+        file.string(&string(&self.tel));
+        file.string(&string(&self.fax));
+        file.string(&string(&self.language));
+        file.string(&string(&self.territory));
+        // The audience and the application.
+        file.string("");
+        file.string("");
+        file.string(&string(&self.abbreviation));
+        file.string(&string(&self.revision));
+        file.string(&string(&self.date));
+        file.start_structure();
+        for category in Category::ALL.iter() {
+            let version = self
+                .category
+                .iter()
+                .flatten()
+                .find(|row| row.get(1).map(String::as_str) == Some(category.name()))
+                .map_or("", |row| row[0].as_str());
+            file.string(version);
+        }
+        file.end_structure();
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl MessagesData {
+    /// Read the `LC_MESSAGES/SYS_LC_MESSAGES` file of a compiled locale.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_MESSAGES, |items| {
+            Ok(Self {
+                yesexpr: items.string(0)?,
+                noexpr: items.string(1)?,
+                yesstr: items.optional(2)?,
+                nostr: items.optional(3)?,
+            })
+        })
+    }
+
+    /// Write the `LC_MESSAGES/SYS_LC_MESSAGES` file of a compiled locale.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_MESSAGES);
+        file.string(&self.yesexpr);
+        file.string(&self.noexpr);
+        file.string(self.yesstr.as_deref().unwrap_or(""));
+        file.string(self.nostr.as_deref().unwrap_or(""));
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl MonetaryData {
+    /// Read the `LC_MONETARY` file of a compiled locale.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_MONETARY, |items| {
+            Ok(Self {
+                int_curr_symbol: items.string(0)?,
+                currency_symbol: items.string(1)?,
+                mon_decimal_point: items.string(2)?,
+                mon_thousands_sep: items.string(3)?,
+                mon_grouping: items.grouping(4)?,
+                positive_sign: items.string(5)?,
+                negative_sign: items.string(6)?,
+                int_frac_digits: items.byte(7)?,
+                frac_digits: items.byte(8)?,
+                p_cs_precedes: items.byte(9)?,
+                p_sep_by_space: items.byte(10)?,
+                n_cs_precedes: items.byte(11)?,
+                n_sep_by_space: items.byte(12)?,
+                p_sign_posn: items.byte(13)?,
+                n_sign_posn: items.byte(14)?,
+                int_p_cs_precedes: Some(items.byte(16)?),
+                int_p_sep_by_space: Some(items.byte(17)?),
+                int_n_cs_precedes: Some(items.byte(18)?),
+                int_n_sep_by_space: Some(items.byte(19)?),
+                int_p_sign_posn: Some(items.byte(20)?),
+                int_n_sign_posn: Some(items.byte(21)?),
+            })
+        })
+    }
+
+    /// Write the `LC_MONETARY` file of a compiled locale.
+    ///
+    /// The international items that are not defined have the value of the national ones, and the
+    /// items of the second currency the values of the first, like in `localedef`.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_MONETARY);
+        let int_p_cs_precedes = self.int_p_cs_precedes.unwrap_or(self.p_cs_precedes);
+        let int_p_sep_by_space = self.int_p_sep_by_space.unwrap_or(self.p_sep_by_space);
+        let int_n_cs_precedes = self.int_n_cs_precedes.unwrap_or(self.n_cs_precedes);
+        let int_n_sep_by_space = self.int_n_sep_by_space.unwrap_or(self.n_sep_by_space);
+        let int_p_sign_posn = self.int_p_sign_posn.unwrap_or(self.p_sign_posn);
+        let int_n_sign_posn = self.int_n_sign_posn.unwrap_or(self.n_sign_posn);
+
+        file.string(&self.int_curr_symbol);
+        file.string(&self.currency_symbol);
+        file.string(&self.mon_decimal_point);
+        file.string(&self.mon_thousands_sep);
+        file.grouping(&self.mon_grouping);
+        file.string(&self.positive_sign);
+        file.string(&self.negative_sign);
+        file.byte(self.int_frac_digits);
+        file.byte(self.frac_digits);
+        file.byte(self.p_cs_precedes);
+        file.byte(self.p_sep_by_space);
+        file.byte(self.n_cs_precedes);
+        file.byte(self.n_sep_by_space);
+        file.byte(self.p_sign_posn);
+        file.byte(self.n_sign_posn);
+        // `CRNCYSTR`: the symbol after `-` if it precedes the value or `+` if it follows it.
+        let position = if self.p_cs_precedes != 0 { '-' } else { '+' };
+        file.string(&std::format!("{}{}", position, self.currency_symbol));
+        file.byte(int_p_cs_precedes);
+        file.byte(int_p_sep_by_space);
+        file.byte(int_n_cs_precedes);
+        file.byte(int_n_sep_by_space);
+        file.byte(int_p_sign_posn);
+        file.byte(int_n_sign_posn);
+        // The second currency.
+        file.string(&self.int_curr_symbol);
+        file.string(&self.currency_symbol);
+        file.byte(self.int_frac_digits);
+        file.byte(self.frac_digits);
+        file.byte(self.p_cs_precedes);
+        file.byte(self.p_sep_by_space);
+        file.byte(self.n_cs_precedes);
+        file.byte(self.n_sep_by_space);
+        file.byte(int_p_cs_precedes);
+        file.byte(int_p_sep_by_space);
+        file.byte(int_n_cs_precedes);
+        file.byte(int_n_sep_by_space);
+        file.byte(self.p_sign_posn);
+        file.byte(self.n_sign_posn);
+        file.byte(int_p_sign_posn);
+        file.byte(int_n_sign_posn);
+        // The dates the currencies are valid from and to, and the conversion rate.
+        file.word(10101);
+        file.word(99991231);
+        file.word(10101);
+        file.word(99991231);
+        file.start_structure();
+        file.word(1);
+        file.word(1);
+        file.end_structure();
+        file.word(wide_char(&self.mon_decimal_point));
+        file.word(wide_char(&self.mon_thousands_sep));
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl NumericData {
+    /// Read the `LC_NUMERIC` file of a compiled locale.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_NUMERIC, |items| {
+            Ok(Self {
+                decimal_point: items.string(0)?,
+                thousands_sep: items.string(1)?,
+                grouping: items.grouping(2)?,
+            })
+        })
+    }
+
+    /// Write the `LC_NUMERIC` file of a compiled locale.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_NUMERIC);
+        file.string(&self.decimal_point);
+        file.string(&self.thousands_sep);
+        file.grouping(&self.grouping);
+        file.word(wide_char(&self.decimal_point));
+        file.word(wide_char(&self.thousands_sep));
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl TelephoneData {
+    /// Read the `LC_TELEPHONE` file of a compiled locale.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_TELEPHONE, |items| {
+            Ok(Self {
+                tel_int_fmt: items.optional(0)?,
+                tel_dom_fmt: items.optional(1)?,
+                int_select: items.optional(2)?,
+                int_prefix: items.optional(3)?,
+            })
+        })
+    }
+
+    /// Write the `LC_TELEPHONE` file of a compiled locale.
+    pub fn to_compiled(&self) -> Vec<u8> {
+        let mut file = Writer::new(Category::LC_TELEPHONE);
+        let string = |x: &Option<String>| x.clone().unwrap_or_default();
+        file.string(&string(&self.tel_int_fmt));
+        file.string(&string(&self.tel_dom_fmt));
+        file.string(&string(&self.int_select));
+        file.string(&string(&self.int_prefix));
+        file.string(CODESET);
+        file.finish()
+    }
+}
+
+impl TimeData {
+    /// Read the `LC_TIME` file of a compiled locale.
+    ///
+    /// `localedef` stores `MON` and `ABMON` in place of `ALT_MON` and `AB_ALT_MON` when they
+    /// are not defined, so they are read as not defined when they are the same. `WEEK` and the
+    /// items that have a default value in `localedef` are always defined.
+    pub fn from_compiled(data: &[u8]) -> Result<Self, Error> {
+        read(data, Category::LC_TIME, |items| {
+            let abmon = items.strings(14, 12)?;
+            let mon = items.strings(26, 12)?;
+            let alt_mon = items.strings(111, 12)?;
+            let ab_alt_mon = items.strings(135, 12)?;
+            let era = items.string_list(44, items.word(50)? as usize)?;
+            let mut alt_digits = items.string_list(47, 100)?;
+            while alt_digits.last().map_or(false, |x| x.is_empty()) {
+                alt_digits.pop();
+            }
+
+            Ok(Self {
+                abday: items.strings(0, 7)?,
+                day: items.strings(7, 7)?,
+                am_pm: items.strings(38, 2)?,
+                d_t_fmt: items.string(40)?,
+                d_fmt: items.string(41)?,
+                t_fmt: items.string(42)?,
+                t_fmt_ampm: items.string(43)?,
+                era: Some(era).filter(|x| !x.is_empty()),
+                era_d_fmt: items.optional(46)?,
+                alt_digits: Some(alt_digits).filter(|x| !x.is_empty()),
+                era_d_t_fmt: items.optional(48)?,
+                era_t_fmt: items.optional(49)?,
+                week: Some(std::vec![
+                    items.byte(101)?,
+                    items.word(102)? as i64,
+                    items.byte(103)?,
+                ]),
+                first_weekday: Some(items.byte(104)?),
+                first_workday: Some(items.byte(105)?),
+                cal_direction: Some(items.byte(106)?),
+                date_fmt: items.optional(108)?,
+                alt_mon: Some(alt_mon).filter(|x| *x != mon),
+                ab_alt_mon: Some(ab_alt_mon).filter(|x| *x != abmon),
+                abmon,
+                mon,
+            })
+        })
+    }
+
+    /// Write the `LC_TIME` file of a compiled locale.
+    ///
+    /// The items that are not defined have the default values of `localedef`, like `MON` for
+    /// `ALT_MON`. The entries of `ERA` must be valid.
+    pub fn to_compiled(&self) -> Result<Vec<u8>, Error> {
+        let mut file = Writer::new(Category::LC_TIME);
+        let era = self.era.as_deref().unwrap_or(&[]);
+        let era_d_fmt = self.era_d_fmt.as_deref().unwrap_or("");
+        let era_d_t_fmt = self.era_d_t_fmt.as_deref().unwrap_or("");
+        let era_t_fmt = self.era_t_fmt.as_deref().unwrap_or("");
+        let alt_digits = self.alt_digits.as_deref().unwrap_or(&[]);
+        let alt_digits = || {
+            alt_digits
+                .iter()
+                .map(String::as_str)
+                .chain(core::iter::repeat(""))
+        };
+        let date_fmt = self
+            .date_fmt
+            .as_deref()
+            .unwrap_or("%a %b %e %H:%M:%S %Z %Y");
+        let alt_mon = self.alt_mon.as_ref().unwrap_or(&self.mon);
+        let ab_alt_mon = self.ab_alt_mon.as_ref().unwrap_or(&self.abmon);
+        let week = self.week.as_deref().unwrap_or(&[]);
+        let strings = [&self.abday, &self.day, &self.abmon, &self.mon, &self.am_pm];
+        let formats = [&self.d_t_fmt, &self.d_fmt, &self.t_fmt, &self.t_fmt_ampm];
+
+        for string in strings
+            .iter()
+            .copied()
+            .flatten()
+            .chain(formats.iter().copied())
+        {
+            file.string(string);
+        }
+        file.start_structure();
+        for entry in era.iter() {
+            file.string(entry);
+        }
+        file.end_structure();
+        // `ERA_YEAR`, which is not used.
+        file.string("");
+        file.string(era_d_fmt);
+        file.start_structure();
+        for digit in alt_digits().take(100) {
+            file.string(digit);
+        }
+        file.end_structure();
+        file.string(era_d_t_fmt);
+        file.string(era_t_fmt);
+        file.word(era.len() as u32);
+        file.start_structure();
+        for entry in era.iter() {
+            let invalid = || Error::from(ErrorKind::InvalidEra(entry.clone()));
+            let fields = entry.splitn(6, ':').collect::<Vec<_>>();
+            let (direction, offset, start, end, name, format) = match fields.as_slice() {
+                [direction @ ("+" | "-"), offset, start, end, name, format] => {
+                    (direction, offset, start, end, name, format)
+                }
+                _ => return Err(invalid()),
+            };
+            file.word(direction.as_bytes()[0] as u32);
+            file.word(offset.parse::<i32>().map_err(|_| invalid())? as u32);
+            for date in [start, end].iter() {
+                for x in era_date(date).ok_or_else(invalid)?.iter() {
+                    file.word(*x as u32);
+                }
+            }
+            file.string(name);
+            file.string(format);
+            file.wide_string(name);
+            file.wide_string(format);
+        }
+        file.end_structure();
+
+        for string in strings
+            .iter()
+            .copied()
+            .flatten()
+            .chain(formats.iter().copied())
+        {
+            file.wide_string(string);
+        }
+        file.wide_string("");
+        file.wide_string(era_d_fmt);
+        file.start_structure();
+        for digit in alt_digits().take(100) {
+            file.wide_string(digit);
+        }
+        file.end_structure();
+        file.wide_string(era_d_t_fmt);
+        file.wide_string(era_t_fmt);
+
+        file.byte(week.first().copied().unwrap_or(7));
+        file.word(week.get(1).copied().unwrap_or(19971130) as u32);
+        file.byte(week.get(2).copied().unwrap_or(7));
+        file.byte(self.first_weekday.unwrap_or(1));
+        file.byte(self.first_workday.unwrap_or(2));
+        file.byte(self.cal_direction.unwrap_or(1));
+        // `TIMEZONE`, which is not used.
+        file.string("");
+        file.string(date_fmt);
+        file.wide_string(date_fmt);
+        file.string(CODESET);
+        for month in alt_mon.iter() {
+            file.string(month);
+        }
+        for month in alt_mon.iter() {
+            file.wide_string(month);
+        }
+        for month in ab_alt_mon.iter() {
+            file.string(month);
+        }
+        for month in ab_alt_mon.iter() {
+            file.wide_string(month);
+        }
+        Ok(file.finish())
+    }
+}
+
+impl LocaleData {
+    /// Read a locale compiled by `localedef --no-archive`: a directory like
+    /// `/usr/lib/locale/fr_BE.utf8` with a file per category. The name of the locale is the name
+    /// of the directory.
+    ///
+    /// ```no_run
+    /// use pure_rust_locales::LocaleData;
+    ///
+    /// let data = LocaleData::load_compiled("/usr/lib/locale/C.utf8").unwrap();
+    /// println!("{}", data.time.d_fmt);
+    /// ```
+    pub fn load_compiled<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let name = path
+            .file_name()
+            .map(|x| x.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self::read_compiled(&name, &mut |category| {
+            std::fs::read(path.join(file_name(category)))
+                .map(Cow::Owned)
+                .map_err(|err| Error::from(ErrorKind::Io(err.kind())))
+        })
+    }
+
+    /// Write the categories of the locale in a directory, like `/usr/lib/locale/fr_BE.utf8`, in
+    /// the same format as `localedef --no-archive`. The directory is created if needed.
+    ///
+    /// `glibc` loads these categories one by one, with `setlocale(LC_TIME, "fr_BE.utf8")`.
+    /// `setlocale(LC_ALL, ...)` also needs the categories the crate doesn't have, like
+    /// `LC_CTYPE`, which can be copied from another compiled locale.
+    ///
+    /// ```no_run
+    /// use pure_rust_locales::{Locale, LocaleData};
+    ///
+    /// LocaleData::from(Locale::fr_BE)
+    ///     .write_compiled("/usr/lib/locale/fr_BE.utf8")
+    ///     .unwrap();
+    /// ```
+    pub fn write_compiled<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let files = [
+            (Category::LC_ADDRESS, self.address.to_compiled()),
+            (
+                Category::LC_IDENTIFICATION,
+                self.identification.to_compiled(),
+            ),
+            (Category::LC_MESSAGES, self.messages.to_compiled()),
+            (Category::LC_MONETARY, self.monetary.to_compiled()),
+            (Category::LC_NUMERIC, self.numeric.to_compiled()),
+            (Category::LC_TELEPHONE, self.telephone.to_compiled()),
+            (
+                Category::LC_TIME,
+                self.time
+                    .to_compiled()
+                    .map_err(|err| err.in_file(file_name(Category::LC_TIME)))?,
+            ),
+        ];
+
+        for (category, data) in files.iter() {
+            let io = |err: std::io::Error| {
+                Error::from(ErrorKind::Io(err.kind())).in_file(file_name(*category))
+            };
+            let file = path.join(file_name(*category));
+            std::fs::create_dir_all(file.parent().unwrap_or(path)).map_err(io)?;
+            std::fs::write(file, data).map_err(io)?;
+        }
+        Ok(())
+    }
+
+    fn read_compiled<'a>(
+        name: &str,
+        file: &mut dyn FnMut(Category) -> Result<Cow<'a, [u8]>, Error>,
+    ) -> Result<Self, Error> {
+        let mut read =
+            |category: Category| file(category).map_err(|err| err.in_file(file_name(category)));
+        let in_file = |category: Category| move |err: Error| err.in_file(file_name(category));
+
+        Ok(Self {
+            name: name.to_string(),
+            address: AddressData::from_compiled(&read(Category::LC_ADDRESS)?)
+                .map_err(in_file(Category::LC_ADDRESS))?,
+            identification: IdentificationData::from_compiled(&read(Category::LC_IDENTIFICATION)?)
+                .map_err(in_file(Category::LC_IDENTIFICATION))?,
+            messages: MessagesData::from_compiled(&read(Category::LC_MESSAGES)?)
+                .map_err(in_file(Category::LC_MESSAGES))?,
+            monetary: MonetaryData::from_compiled(&read(Category::LC_MONETARY)?)
+                .map_err(in_file(Category::LC_MONETARY))?,
+            numeric: NumericData::from_compiled(&read(Category::LC_NUMERIC)?)
+                .map_err(in_file(Category::LC_NUMERIC))?,
+            telephone: TelephoneData::from_compiled(&read(Category::LC_TELEPHONE)?)
+                .map_err(in_file(Category::LC_TELEPHONE))?,
+            time: TimeData::from_compiled(&read(Category::LC_TIME)?)
+                .map_err(in_file(Category::LC_TIME))?,
+        })
+    }
+}
+
+/// Normalize the codeset of a locale name in the same way as `glibc`: `fr_BE.UTF-8@euro` is
+/// `fr_BE.utf8@euro`.
+fn normalize_codeset(name: &str) -> String {
+    let (base, rest) = match name.find('.') {
+        Some(dot) => name.split_at(dot),
+        None => return name.to_string(),
+    };
+    let (codeset, modifier) = match rest.find('@') {
+        Some(at) => rest[1..].split_at(at - 1),
+        None => (&rest[1..], ""),
+    };
+    let codeset = codeset
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|x| x.to_ascii_lowercase())
+        .collect::<String>();
+    let prefix = match codeset.chars().all(|x| x.is_ascii_digit()) {
+        true => "iso",
+        false => "",
+    };
+    std::format!("{}.{}{}{}", base, prefix, codeset, modifier)
+}
+
+/// The hash of a name in the hash table of an archive, `compute_hashval` of `localedef`.
+fn archive_hashval(name: &[u8]) -> u32 {
+    let hashval = name.iter().fold(name.len() as u32, |hashval, &byte| {
+        hashval.rotate_left(9).wrapping_add(byte as u32)
+    });
+    match hashval {
+        0 => u32::MAX,
+        hashval => hashval,
+    }
+}
+
+/// A `locale-archive` file, where `localedef` adds the locales it compiles by default, like
+/// `/usr/lib/locale/locale-archive`.
+///
+/// ```no_run
+/// use pure_rust_locales::compiled::Archive;
+///
+/// let archive = Archive::open("/usr/lib/locale/locale-archive").unwrap();
+/// for name in archive.names() {
+///     println!("{}", name);
+/// }
+/// let data = archive.locale("fr_BE.UTF-8").unwrap();
+/// println!("{}", data.time.d_fmt);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Archive {
+    data: Vec<u8>,
+    /// The offset and the number of entries of the hash table of the names.
+    namehash: (usize, usize),
+    /// The name and the offset of the record of each locale.
+    locales: Vec<(String, usize)>,
+}
+
+impl Archive {
+    /// Read an archive file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let data = std::fs::read(path).map_err(|err| Error::from(ErrorKind::Io(err.kind())))?;
+        Self::from_bytes(data)
+    }
+
+    /// Read an archive from its content.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, Error> {
+        let found = word(&data, 0)?;
+        if found != ARCHIVE_MAGIC {
+            return Err(ErrorKind::BadMagic {
+                expected: ARCHIVE_MAGIC,
+                found,
+            }
+            .into());
+        }
+
+        let namehash_offset = word(&data, 8)? as usize;
+        let namehash_size = word(&data, 16)? as usize;
+        let mut locales = Vec::new();
+        for i in 0..namehash_size {
+            let entry = namehash_offset + 12 * i;
+            let name_offset = word(&data, entry + 4)? as usize;
+            if name_offset == 0 {
+                continue;
+            }
+            let locrec_offset = word(&data, entry + 8)? as usize;
+            let name = c_string(&data, name_offset)?.0.to_string();
+            locales.push((name, locrec_offset));
+        }
+        locales.sort();
+
+        Ok(Self {
+            data,
+            namehash: (namehash_offset, namehash_size),
+            locales,
+        })
+    }
+
+    /// Find the record of a locale in the hash table of the names, like `glibc` does: the table is
+    /// probed by double hashing until the name or an empty entry is found.
+    fn lookup(&self, name: &str) -> Option<(&str, usize)> {
+        let (namehash_offset, size) = self.namehash;
+        let hashval = archive_hashval(name.as_bytes());
+        let mut index = hashval as usize % size.max(1);
+        let increment = 1 + hashval as usize % size.saturating_sub(2).max(1);
+        for _ in 0..size {
+            let entry = namehash_offset + 12 * index;
+            let name_offset = word(&self.data, entry + 4).ok()? as usize;
+            if name_offset == 0 {
+                return None;
+            }
+            if word(&self.data, entry).ok()? == hashval {
+                let (found, _) = c_string(&self.data, name_offset).ok()?;
+                if found == name {
+                    return Some((found, word(&self.data, entry + 8).ok()? as usize));
+                }
+            }
+            index = (index + increment) % size;
+        }
+        None
+    }
+
+    /// The names of the locales, like `fr_BE.utf8`, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.locales.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Read a locale. The codeset of the name is normalized like `glibc` does, so
+    /// `fr_BE.UTF-8` finds `fr_BE.utf8`. The name of the locale is the name in the archive.
+    pub fn locale(&self, name: &str) -> Result<LocaleData, Error> {
+        let normalized = normalize_codeset(name);
+        let (name, locrec_offset) = self
+            .lookup(name)
+            .or_else(|| self.lookup(&normalized))
+            .ok_or_else(|| ErrorKind::NotFound(name.to_string()))?;
+
+        LocaleData::read_compiled(name, &mut |category| {
+            // The record has a reference count, then the offset and the length of the data of
+            // each category, `LC_ALL` included.
+            let record = locrec_offset + 4 + 8 * glibc_number(category);
+            let offset = word(&self.data, record)? as usize;
+            let len = word(&self.data, record + 4)? as usize;
+            if len == 0 {
+                return Err(ErrorKind::MissingCategory(category).into());
+            }
+            self.data
+                .get(offset..offset + len)
+                .map(Cow::Borrowed)
+                .ok_or_else(|| ErrorKind::Truncated.into())
+        })
+    }
+}
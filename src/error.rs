@@ -0,0 +1,283 @@
+use crate::{locale_key, Locale, LOCALES_BY_NAME, LOCALE_KEY_LEN};
+use core::fmt;
+
+/// The length of the name kept by an `UnknownLocale`, in bytes.
+const INPUT_LEN: usize = 32;
+
+/// The largest edit distance of a suggestion that doesn't have the language of the name. It is
+/// at most half the length of the name.
+const MAX_DISTANCE: usize = 2;
+
+/// Why a name is not the name of a `Locale`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UnknownLocaleKind {
+    /// The name isn't `language[_territory][@modifier]`, with a language of 2 or 3 letters, a
+    /// territory of 2 letters or 3 digits and a modifier of letters and digits.
+    Syntax,
+    /// No locale has the language.
+    Language,
+    /// The language is known, but not with the territory, or without a territory.
+    Territory,
+    /// The locale of the language and the territory doesn't have the modifier.
+    Modifier,
+    /// The name isn't the name of a category, in the composite form of a `LocaleSet`. There
+    /// are no suggestions.
+    Category,
+    /// The category is given twice in the composite form of a `LocaleSet`. There are no
+    /// suggestions.
+    RepeatedCategory,
+}
+
+impl fmt::Display for UnknownLocaleKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            UnknownLocaleKind::Syntax => "expected `language[_territory][@modifier]`",
+            UnknownLocaleKind::Language => "unknown language",
+            UnknownLocaleKind::Territory => "unknown territory for the language",
+            UnknownLocaleKind::Modifier => "unknown modifier for the locale",
+            UnknownLocaleKind::Category => "not a category",
+            UnknownLocaleKind::RepeatedCategory => "category given twice",
+        })
+    }
+}
+
+/// The error of parsing a name that isn't the name of a `Locale` compiled in: the name, up to
+/// 32 bytes, and why it is unknown.
+///
+/// ```
+/// use pure_rust_locales::{Locale, UnknownLocaleKind};
+///
+/// let error = "frBE".parse::<Locale>().unwrap_err();
+/// assert_eq!(error.input(), "frBE");
+/// assert_eq!(error.kind(), UnknownLocaleKind::Syntax);
+/// assert_eq!(error.suggestions().next(), Some(Locale::fr_BE));
+/// assert_eq!(
+///     error.to_string(),
+///     "unknown locale `frBE`: expected `language[_territory][@modifier]`, did you mean `fr_BE`?",
+/// );
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnknownLocale {
+    input: [u8; INPUT_LEN],
+    len: u8,
+    kind: UnknownLocaleKind,
+}
+
+impl UnknownLocale {
+    /// The largest number of suggestions of [`UnknownLocale::suggestions`].
+    pub const MAX_SUGGESTIONS: usize = 3;
+
+    /// The error of parsing `input` as the name of a `Locale`.
+    pub(crate) fn new(input: &str) -> Self {
+        Self::with_kind(input, classify(input))
+    }
+
+    pub(crate) fn with_kind(input: &str, kind: UnknownLocaleKind) -> Self {
+        let mut len = input.len().min(INPUT_LEN);
+        while !input.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut error = Self {
+            input: [0; INPUT_LEN],
+            len: len as u8,
+            kind,
+        };
+        error.input[..len].copy_from_slice(&input.as_bytes()[..len]);
+        error
+    }
+
+    /// The name that was parsed, truncated to 32 bytes.
+    pub fn input(&self) -> &str {
+        core::str::from_utf8(&self.input[..self.len as usize]).unwrap_or_default()
+    }
+
+    /// Why the name is unknown.
+    pub fn kind(&self) -> UnknownLocaleKind {
+        self.kind
+    }
+
+    /// The locales compiled in with the closest names, closest first: the ones of the same
+    /// language when it is known, then the ones a letter or two away from the name. They are
+    /// looked for on each call.
+    pub fn suggestions(&self) -> impl Iterator<Item = Locale> {
+        let mut suggestions = [None::<((bool, usize), Locale)>; Self::MAX_SUGGESTIONS];
+        let locales = match self.kind {
+            UnknownLocaleKind::Category | UnknownLocaleKind::RepeatedCategory => &[],
+            _ => LOCALES_BY_NAME,
+        };
+        let input = self.input().as_bytes();
+        let input_language = language(input);
+        let known_language = matches!(
+            self.kind,
+            UnknownLocaleKind::Territory | UnknownLocaleKind::Modifier
+        );
+        let max_distance = MAX_DISTANCE.min(input.len() / 2);
+
+        for (_, locale) in locales.iter() {
+            let name = locale.name().as_bytes();
+            let other_language = !(known_language && eq(input_language, language(name)));
+            let rank = (other_language, distance(input, name));
+            if other_language && rank.1 > max_distance {
+                continue;
+            }
+            let at = suggestions
+                .iter()
+                .position(|x| x.map_or(true, |(other, _)| rank < other));
+            if let Some(at) = at {
+                suggestions[at..].rotate_right(1);
+                suggestions[at] = Some((rank, *locale));
+            }
+        }
+
+        IntoIterator::into_iter(suggestions)
+            .flatten()
+            .map(|(_, locale)| locale)
+    }
+}
+
+impl fmt::Display for UnknownLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            UnknownLocaleKind::Category => {
+                return write!(f, "unknown category `{}`", self.input());
+            }
+            UnknownLocaleKind::RepeatedCategory => {
+                return write!(f, "category `{}` given twice", self.input());
+            }
+            _ => {}
+        }
+        write!(f, "unknown locale `{}`: {}", self.input(), self.kind)?;
+        let mut suggestions = self.suggestions().peekable();
+        let mut first = true;
+        while let Some(locale) = suggestions.next() {
+            let separator = match (first, suggestions.peek()) {
+                (true, _) => ", did you mean",
+                (false, Some(_)) => ",",
+                (false, None) => " or",
+            };
+            write!(f, "{} `{}`", separator, locale)?;
+            first = false;
+        }
+        if !first {
+            f.write_str("?")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UnknownLocale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UnknownLocale")
+            .field("input", &self.input())
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownLocale {}
+
+/// Why `name` isn't the name of a locale compiled in.
+fn classify(name: &str) -> UnknownLocaleKind {
+    let (base, modifier) = match name.find('@') {
+        Some(pos) => (&name[..pos], Some(&name[pos + 1..])),
+        None => (name, None),
+    };
+    let language = language(base.as_bytes());
+    let territory = base.get(language.len() + 1..);
+
+    let valid = (2..=3).contains(&language.len())
+        && language.iter().all(u8::is_ascii_alphabetic)
+        && territory.map_or(true, |x| {
+            x.len() == 2 && x.bytes().all(|x| x.is_ascii_alphabetic())
+                || x.len() == 3 && x.bytes().all(|x| x.is_ascii_digit())
+        })
+        && modifier.map_or(true, |x| {
+            !x.is_empty() && x.bytes().all(|x| x.is_ascii_alphanumeric())
+        });
+
+    if !valid {
+        UnknownLocaleKind::Syntax
+    } else if !has_locale(language, b"_@") {
+        UnknownLocaleKind::Language
+    } else if !has_locale(base.as_bytes(), b"@") {
+        UnknownLocaleKind::Territory
+    } else {
+        UnknownLocaleKind::Modifier
+    }
+}
+
+/// The language of a name: up to its first `_`, `-` or `@`.
+fn language(name: &[u8]) -> &[u8] {
+    match name.iter().position(|x| matches!(x, b'_' | b'-' | b'@')) {
+        Some(pos) => &name[..pos],
+        None => name,
+    }
+}
+
+/// Whether a locale compiled in is named `prefix`, or a name starting with `prefix` followed by
+/// one of `separators`. The names starting with `prefix` follow its key in `LOCALES_BY_NAME`.
+fn has_locale(prefix: &[u8], separators: &[u8]) -> bool {
+    if prefix.len() > LOCALE_KEY_LEN {
+        return false;
+    }
+    let key = locale_key(prefix);
+    let start = match LOCALES_BY_NAME.binary_search_by(|(x, _)| x.cmp(&key)) {
+        // The key is padded with zeros: the length tells `fr_BE` from `fr_BE\0`.
+        Ok(index) => return LOCALES_BY_NAME[index].1.name().len() == prefix.len(),
+        Err(start) => start,
+    };
+
+    LOCALES_BY_NAME[start..]
+        .iter()
+        .map(|(_, locale)| locale.name().as_bytes())
+        .take_while(|name| name.len() > prefix.len() && eq(&name[..prefix.len()], prefix))
+        .any(|name| separators.contains(&name[prefix.len()]))
+}
+
+/// The byte as in the key of a name: in lowercase, and `_` for `-`.
+fn fold(x: u8) -> u8 {
+    match x {
+        b'-' => b'_',
+        x => x.to_ascii_lowercase(),
+    }
+}
+
+fn eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| fold(*a) == fold(*b))
+}
+
+/// The number of bytes to insert, remove, replace or swap with the next one to turn `a` into
+/// `b`, ignoring the case and with `_` for `-`. `b` is the name of a locale.
+fn distance(a: &[u8], b: &[u8]) -> usize {
+    let b = &b[..b.len().min(LOCALE_KEY_LEN)];
+    // The row of the distances from the previous prefix but one of `a`, the previous and the
+    // current one to the prefixes of `b`.
+    let mut rows = [[0; LOCALE_KEY_LEN + 1]; 3];
+    for (j, x) in rows[1].iter_mut().enumerate() {
+        *x = j;
+    }
+
+    for i in 1..=a.len() {
+        rows[2][0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(fold(a[i - 1]) != fold(b[j - 1]));
+            let mut distance = (rows[1][j] + 1)
+                .min(rows[2][j - 1] + 1)
+                .min(rows[1][j - 1] + cost);
+            if i > 1
+                && j > 1
+                && fold(a[i - 1]) == fold(b[j - 2])
+                && fold(a[i - 2]) == fold(b[j - 1])
+            {
+                distance = distance.min(rows[0][j - 2] + 1);
+            }
+            rows[2][j] = distance;
+        }
+        rows.rotate_left(1);
+    }
+
+    rows[1][b.len()]
+}
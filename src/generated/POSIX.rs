@@ -0,0 +1,182 @@
+pub mod LC_ADDRESS {
+    /// `None`
+    pub const COUNTRY_AB3: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_ABTWO: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_CAR: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NAME: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NUM: Option<i64> = None;
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `None`
+    pub const LANG_NAME: Option<&str> = None;
+    /// `None`
+    pub const LANG_TERM: Option<&str> = None;
+    /// `None`
+    pub const POSTAL_FMT: Option<&str> = None;
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// `None`
+    pub const CATEGORY: Option<&[&[&str]]> = None;
+    /// `None`
+    pub const CONTACT: Option<&str> = None;
+    /// `None`
+    pub const DATE: Option<&str> = None;
+    /// `None`
+    pub const EMAIL: Option<&str> = None;
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `None`
+    pub const LANGUAGE: Option<&str> = None;
+    /// `None`
+    pub const REVISION: Option<&str> = None;
+    /// `None`
+    pub const SOURCE: Option<&str> = None;
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `None`
+    pub const TERRITORY: Option<&str> = None;
+    /// `None`
+    pub const TITLE: Option<&str> = None;
+}
+pub mod LC_MESSAGES {
+    /// `"^[nN]"`
+    pub const NOEXPR: &str = "^[nN]";
+    /// `Some("No")`
+    pub const NOSTR: Option<&str> = Some("No");
+    /// `"^[yY]"`
+    pub const YESEXPR: &str = "^[yY]";
+    /// `Some("Yes")`
+    pub const YESSTR: Option<&str> = Some("Yes");
+}
+pub mod LC_MONETARY {
+    /// `""`
+    pub const CURRENCY_SYMBOL: &str = "";
+    /// `-1`
+    pub const FRAC_DIGITS: i64 = -1;
+    /// `""`
+    pub const INT_CURR_SYMBOL: &str = "";
+    /// `-1`
+    pub const INT_FRAC_DIGITS: i64 = -1;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[-1]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_0;
+    /// `""`
+    pub const MON_THOUSANDS_SEP: &str = "";
+    /// `""`
+    pub const NEGATIVE_SIGN: &str = "";
+    /// `-1`
+    pub const N_CS_PRECEDES: i64 = -1;
+    /// `-1`
+    pub const N_SEP_BY_SPACE: i64 = -1;
+    /// `-1`
+    pub const N_SIGN_POSN: i64 = -1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `-1`
+    pub const P_CS_PRECEDES: i64 = -1;
+    /// `-1`
+    pub const P_SEP_BY_SPACE: i64 = -1;
+    /// `-1`
+    pub const P_SIGN_POSN: i64 = -1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[-1]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_0;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `None`
+    pub const INT_PREFIX: Option<&str> = None;
+    /// `None`
+    pub const INT_SELECT: Option<&str> = None;
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `None`
+    pub const TEL_INT_FMT: Option<&str> = None;
+}
+pub mod LC_TIME {
+    /// `&["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_6;
+    /// `&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_3;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %b %e %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %b %e %H:%M:%S %Z %Y");
+    /// `&["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_7;
+    /// `"%m/%d/%y"`
+    pub const D_FMT: &str = "%m/%d/%y";
+    /// `"%a %b %e %H:%M:%S %Y"`
+    pub const D_T_FMT: &str = "%a %b %e %H:%M:%S %Y";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_4;
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,396 @@
+pub mod LC_IDENTIFICATION {
+    pub const CATEGORY_0: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_TIME"],
+    ];
+    pub const CATEGORY_1: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TIME"],
+    ];
+    pub const CATEGORY_2: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_TIME"],
+    ];
+    pub const CATEGORY_3: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_NAME"],
+    ];
+    pub const CATEGORY_4: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_ADDRESS"],
+    ];
+    pub const CATEGORY_5: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_ADDRESS"],
+    ];
+    pub const CATEGORY_6: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+    ];
+    pub const CATEGORY_7: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+    ];
+    pub const CATEGORY_8: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ];
+    pub const CATEGORY_9: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ];
+    pub const CATEGORY_10: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ];
+    pub const CATEGORY_11: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+    ];
+    pub const CATEGORY_12: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ];
+    pub const CATEGORY_13: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ];
+    pub const CATEGORY_14: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+    ];
+    pub const CATEGORY_15: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_NAME"],
+    ];
+    pub const CATEGORY_16: &[&[&str]] = &[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+    ];
+}
+pub mod LC_MONETARY {
+    pub const MON_GROUPING_0: &[i64] = &[-1];
+    pub const MON_GROUPING_1: &[i64] = &[3, 2];
+    pub const MON_GROUPING_2: &[i64] = &[3, 3];
+    pub const MON_GROUPING_3: &[i64] = &[3];
+    pub const MON_GROUPING_4: &[i64] = &[4];
+}
+pub mod LC_NUMERIC {
+    pub const GROUPING_0: &[i64] = &[-1];
+    pub const GROUPING_1: &[i64] = &[0, 0];
+    pub const GROUPING_2: &[i64] = &[3, 2];
+    pub const GROUPING_3: &[i64] = &[3, 3];
+    pub const GROUPING_4: &[i64] = &[3];
+    pub const GROUPING_5: &[i64] = &[4];
+}
+pub mod LC_TIME {
+    pub const ABDAY_0: &[&str] = &["Aca", "Etl", "Tal", "Arb", "Kam", "Gum", "Sab"];
+    pub const ABDAY_1: &[&str] = &["Axd", "Isn", "Sal", "Arb", "Kha", "Jim", "Sab"];
+    pub const ABDAY_2: &[&str] = &["J2", "J3", "J4", "J5", "Alh", "Ij", "J1"];
+    pub const ABDAY_3: &[&str] = &["Lin", "Lun", "Mar", "Miy", "Huw", "Biy", "Sab"];
+    pub const ABDAY_4: &[&str] = &["Sdg", "Mdg", "Dsg", "Mwk", "Ddg", "Fdg", "Swd"];
+    pub const ABDAY_5: &[&str] = &["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"];
+    pub const ABDAY_6: &[&str] = &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    pub const ABDAY_7: &[&str] = &["baz", "bir", "iki", "üçü", "dör", "beş", "alt"];
+    pub const ABDAY_8: &[&str] = &["dim", "lun", "mar", "mer", "jeu", "ven", "sam"];
+    pub const ABDAY_9: &[&str] = &["do", "lu", "ma", "we", "ra", "bi", "sa"];
+    pub const ABDAY_10: &[&str] = &["dom", "lun", "mar", "mer", "gio", "ven", "sab"];
+    pub const ABDAY_11: &[&str] = &["dom", "lun", "mar", "mié", "jue", "vie", "sáb"];
+    pub const ABDAY_12: &[&str] = &["dom", "seg", "ter", "qua", "qui", "sex", "sáb"];
+    pub const ABDAY_13: &[&str] = &["sön", "mån", "tis", "ons", "tor", "fre", "lör"];
+    pub const ABDAY_14: &[&str] = &["zo", "ma", "di", "wo", "do", "vr", "za"];
+    pub const ABDAY_15: &[&str] = &["Κυρ", "Δευ", "Τρι", "Τετ", "Πεμ", "Παρ", "Σαβ"];
+    pub const ABDAY_16: &[&str] = &["нд", "пн", "вт", "ср", "чт", "пт", "сб"];
+    pub const ABDAY_17: &[&str] = &["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"];
+    pub const ABDAY_18: &[&str] = &["ح", "ن", "ث", "ر", "خ", "ج", "س"];
+    pub const ABDAY_19: &[&str] = &["रवि", "सोम", "म\u{902}गल", "ब\u{941}ध", "ग\u{941}र\u{941}", "श\u{941}क\u{94d}र", "शनि"];
+    pub const ABDAY_20: &[&str] = &["रवि", "सोम", "म\u{902}गल", "ब\u{941}ध", "ब\u{943}हस\u{94d}पति", "श\u{941}क\u{94d}र", "शनि"];
+    pub const ABDAY_21: &[&str] = &["ஞ\u{bbe}", "தி", "செ", "பு", "வி", "வெ", "ச"];
+    pub const ABDAY_22: &[&str] = &["ሰንበ", "ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም"];
+    pub const ABDAY_23: &[&str] = &["እኁድ", "ሰኑይ", "ሠሉስ", "ራብዕ", "ሐሙስ", "ዓርበ", "ቀዳሚ"];
+    pub const ABDAY_24: &[&str] = &["日", "一", "二", "三", "四", "五", "六"];
+    pub const ABMON_0: &[&str] = &[" 1月", " 2月", " 3月", " 4月", " 5月", " 6月", " 7月", " 8月", " 9月", "10月", "11月", "12月"];
+    pub const ABMON_1: &[&str] = &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"];
+    pub const ABMON_2: &[&str] = &["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"];
+    pub const ABMON_3: &[&str] = &["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+    pub const ABMON_4: &[&str] = &["Jan", "Feb", "Moz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Now", "Dez"];
+    pub const ABMON_5: &[&str] = &["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"];
+    pub const ABMON_6: &[&str] = &["Jän", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"];
+    pub const ABMON_7: &[&str] = &["Kob", "Lab", "Sad", "Afr", "Sha", "Lix", "Tod", "Sid", "Sag", "Tob", "KIT", "LIT"];
+    pub const ABMON_8: &[&str] = &["Qun", "Nah", "Cig", "Agd", "Cax", "Qas", "Qad", "Leq", "Way", "Dit", "Xim", "Kax"];
+    pub const ABMON_9: &[&str] = &["Yan", "Feb", "Mar", "Apr", "Mei", "Yün", "Yül", "Oug", "Sèp", "Okt", "Nov", "Des"];
+    pub const ABMON_10: &[&str] = &["Yan", "Fev", "Mar", "Apr", "May", "İyn", "İyl", "Avq", "Sen", "Okt", "Noy", "Dek"];
+    pub const ABMON_11: &[&str] = &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"];
+    pub const ABMON_12: &[&str] = &["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "set", "oct", "nov", "dic"];
+    pub const ABMON_13: &[&str] = &["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"];
+    pub const ABMON_14: &[&str] = &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"];
+    pub const ABMON_15: &[&str] = &["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"];
+    pub const ABMON_16: &[&str] = &["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"];
+    pub const ABMON_17: &[&str] = &["jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov", "dez"];
+    pub const ABMON_18: &[&str] = &["jan", "fév", "mar", "avr", "mai", "jun", "jui", "aoû", "sep", "oct", "nov", "déc"];
+    pub const ABMON_19: &[&str] = &["jan.", "feb.", "mars", "april", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."];
+    pub const ABMON_20: &[&str] = &["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αυγ", "Σεπ", "Οκτ", "Νοε", "Δεκ"];
+    pub const ABMON_21: &[&str] = &["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"];
+    pub const ABMON_22: &[&str] = &["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"];
+    pub const ABMON_23: &[&str] = &["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"];
+    pub const ABMON_24: &[&str] = &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"];
+    pub const ABMON_25: &[&str] = &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"];
+    pub const ABMON_26: &[&str] = &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"];
+    pub const ABMON_27: &[&str] = &["ينا", "فبر", "مار", "أبر", "ماي", "يون", "يول", "أغس", "سبت", "أكت", "نوف", "ديس"];
+    pub const ABMON_28: &[&str] = &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"];
+    pub const ABMON_29: &[&str] = &["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"];
+    pub const ABMON_30: &[&str] = &["ཟ\u{fb3}་༡", "ཟ\u{fb3}་༢", "ཟ\u{fb3}་༣", "ཟ\u{fb3}་༤", "ཟ\u{fb3}་༥", "ཟ\u{fb3}་༦", "ཟ\u{fb3}་༧", "ཟ\u{fb3}་༨", "ཟ\u{fb3}་༩", "ཟ\u{fb3}་༡༠", "ཟ\u{fb3}་༡༡", "ཟ\u{fb3}་༡༢"];
+    pub const ABMON_31: &[&str] = &["ጃንዩ", "ፌብሩ", "ማርች", "ኤፕረ", "ሜይ ", "ጁን ", "ጁላይ", "ኦገስ", "ሴፕቴ", "ኦክተ", "ኖቬም", "ዲሴም"];
+    pub const ABMON_32: &[&str] = &["ጥሪ ", "ለካቲ", "መጋቢ", "ሚያዝ", "ግንቦ", "ሰነ ", "ሓምለ", "ነሓሰ", "መስከ", "ጥቅም", "ሕዳር", "ታሕሳ"];
+    pub const AB_ALT_MON_0: &[&str] = &["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"];
+    pub const ALT_DIGITS_0: &[&str] = &["۰۰", "۰۱", "۰۲", "۰۳", "۰۴", "۰۵", "۰۶", "۰۷", "۰۸", "۰۹", "۱۰", "۱۱", "۱۲", "۱۳", "۱۴", "۱۵", "۱۶", "۱۷", "۱۸", "۱۹", "۲۰", "۲۱", "۲۲", "۲۳", "۲۴", "۲۵", "۲۶", "۲۷", "۲۸", "۲۹", "۳۰", "۳۱", "۳۲", "۳۳", "۳۴", "۳۵", "۳۶", "۳۷", "۳۸", "۳۹", "۴۰", "۴۱", "۴۲", "۴۳", "۴۴", "۴۵", "۴۶", "۴۷", "۴۸", "۴۹", "۵۰", "۵۱", "۵۲", "۵۳", "۵۴", "۵۵", "۵۶", "۵۷", "۵۸", "۵۹", "۶۰", "۶۱", "۶۲", "۶۳", "۶۴", "۶۵", "۶۶", "۶۷", "۶۸", "۶۹", "۷۰", "۷۱", "۷۲", "۷۳", "۷۴", "۷۵", "۷۶", "۷۷", "۷۸", "۷۹", "۸۰", "۸۱", "۸۲", "۸۳", "۸۴", "۸۵", "۸۶", "۸۷", "۸۸", "۸۹", "۹۰", "۹۱", "۹۲", "۹۳", "۹۴", "۹۵", "۹۶", "۹۷", "۹۸", "۹۹"];
+    pub const ALT_DIGITS_1: &[&str] = &["၀၀", "၀၁", "၀၂", "၀၃", "၀၄", "၀၅", "၀၆", "၀၇", "၀၈", "၀၉", "၁၀", "၁၁", "၁၂", "၁၃", "၁၄", "၁၅", "၁၆", "၁၇", "၁၈", "၁၉", "၂၀", "၂၁", "၂၂", "၂၃", "၂၄", "၂၅", "၂၆", "၂၇", "၂၈", "၂၉", "၃၀", "၃၁", "၃၂", "၃၃", "၃၄", "၃၅", "၃၆", "၃၇", "၃၈", "၃၉", "၄၀", "၄၁", "၄၂", "၄၃", "၄၄", "၄၅", "၄၆", "၄၇", "၄၈", "၄၉", "၅၀", "၅၁", "၅၂", "၅၃", "၅၄", "၅၅", "၅၆", "၅၇", "၅၈", "၅၉", "၆၀", "၆၁", "၆၂", "၆၃", "၆၄", "၆၅", "၆၆", "၆၇", "၆၈", "၆၉", "၇၀", "၇၁", "၇၂", "၇၃", "၇၄", "၇၅", "၇၆", "၇၇", "၇၈", "၇၉", "၈၀", "၈၁", "၈၂", "၈၃", "၈၄", "၈၅", "၈၆", "၈၇", "၈၈", "၈၉", "၉၀", "၉၁", "၉၂", "၉၃", "၉၄", "၉၅", "၉၆", "၉၇", "၉၈", "၉၉"];
+    pub const ALT_MON_0: &[&str] = &["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αύγουστος", "Σεπτέμβριος", "Οκτώβριος", "Νοέμβριος", "Δεκέμβριος"];
+    pub const AM_PM_0: &[&str] = &["", ""];
+    pub const AM_PM_1: &[&str] = &["AM", "PM"];
+    pub const AM_PM_2: &[&str] = &["VM", "NM"];
+    pub const AM_PM_3: &[&str] = &["am", "pm"];
+    pub const AM_PM_4: &[&str] = &["asubuhi", "alasiri"];
+    pub const AM_PM_5: &[&str] = &["saaku", "carra"];
+    pub const AM_PM_6: &[&str] = &["subaxnimo", "galabnimo"];
+    pub const AM_PM_7: &[&str] = &["πμ", "μμ"];
+    pub const AM_PM_8: &[&str] = &["ص", "ش"];
+    pub const AM_PM_9: &[&str] = &["ص", "م"];
+    pub const AM_PM_10: &[&str] = &["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"];
+    pub const AM_PM_11: &[&str] = &["म.प\u{942}.", "म.न\u{902}."];
+    pub const AM_PM_12: &[&str] = &["প\u{9c2}র\u{9cd}ব\u{9be}হ\u{9cd}ণ", "অপর\u{9be}হ\u{9cd}ণ"];
+    pub const AM_PM_13: &[&str] = &["க\u{bbe}லை", "ம\u{bbe}லை"];
+    pub const AM_PM_14: &[&str] = &["ངས་ཆ", "ཕ\u{fb1}\u{f72}་ཆ"];
+    pub const AM_PM_15: &[&str] = &["ንጉሆ ሰዓተ", "ድሕር ሰዓት"];
+    pub const AM_PM_16: &[&str] = &["ጽባሕ", "ምሴት"];
+    pub const AM_PM_17: &[&str] = &["上午", "下午"];
+    pub const DAY_0: &[&str] = &["Acaada", "Etleeni", "Talaata", "Arbaqa", "Kamiisi", "Gumqata", "Sabti"];
+    pub const DAY_1: &[&str] = &["Axad", "Isniin", "Salaaso", "Arbaco", "Khamiis", "Jimco", "Sabti"];
+    pub const DAY_2: &[&str] = &["Djadomingo", "Djaluna", "Djamars", "Djawebs", "Djarason", "Djabierne", "Djasabra"];
+    pub const DAY_3: &[&str] = &["Jumapili", "Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi"];
+    pub const DAY_4: &[&str] = &["Linggo", "Lunes", "Martes", "Miyerkoles", "Huwebes", "Biyernes", "Sabado"];
+    pub const DAY_5: &[&str] = &["Sinndag", "Mondag", "Dingsdag", "Meddwäakj", "Donnadag", "Friedag", "Sinnowend"];
+    pub const DAY_6: &[&str] = &["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"];
+    pub const DAY_7: &[&str] = &["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+    pub const DAY_8: &[&str] = &["bazar günü", "birinci gün", "ikinci gün", "üçüncü gün", "dördüncü gün", "beşinci gün", "altıncı gün"];
+    pub const DAY_9: &[&str] = &["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"];
+    pub const DAY_10: &[&str] = &["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"];
+    pub const DAY_11: &[&str] = &["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"];
+    pub const DAY_12: &[&str] = &["domingo", "segunda", "terça", "quarta", "quinta", "sexta", "sábado"];
+    pub const DAY_13: &[&str] = &["söndag", "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag"];
+    pub const DAY_14: &[&str] = &["søndag", "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag"];
+    pub const DAY_15: &[&str] = &["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"];
+    pub const DAY_16: &[&str] = &["Κυριακή", "Δευτέρα", "Τρίτη", "Τετάρτη", "Πέμπτη", "Παρασκευή", "Σάββατο"];
+    pub const DAY_17: &[&str] = &["Воскресенье", "Понедельник", "Вторник", "Среда", "Четверг", "Пятница", "Суббота"];
+    pub const DAY_18: &[&str] = &["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"];
+    pub const DAY_19: &[&str] = &["الأحد", "الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت"];
+    pub const DAY_20: &[&str] = &["रविवार", "सोमवार", "म\u{902}गलवार", "ब\u{941}धवार", "ग\u{941}र\u{941}वार", "श\u{941}क\u{94d}रवार", "शनिवार"];
+    pub const DAY_21: &[&str] = &["রবিব\u{9be}র", "সোমব\u{9be}র", "মঙ\u{9cd}গলব\u{9be}র", "ব\u{9c1}ধব\u{9be}র", "ব\u{9c3}হস\u{9cd}পতিব\u{9be}র", "শ\u{9c1}ক\u{9cd}রব\u{9be}র", "শনিব\u{9be}র"];
+    pub const DAY_22: &[&str] = &["ஞ\u{bbe}யிறு", "திங\u{bcd}கள\u{bcd}", "செவ\u{bcd}வ\u{bbe}ய\u{bcd}", "புதன\u{bcd}", "விய\u{bbe}ழன\u{bcd}", "வெள\u{bcd}ளி", "சனி"];
+    pub const DAY_23: &[&str] = &["ሰንበት", "ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም"];
+    pub const DAY_24: &[&str] = &["እኁድ", "ሰኑይ", "ሠሉስ", "ራብዕ", "ሐሙስ", "ዓርበ", "ቀዳሚት"];
+    pub const DAY_25: &[&str] = &["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"];
+    pub const DAY_26: &[&str] = &["禮拜日", "禮拜一", "禮拜二", "禮拜三", "禮拜四", "禮拜五", "禮拜六"];
+    pub const DAY_27: &[&str] = &["週日", "週一", "週二", "週三", "週四", "週五", "週六"];
+    pub const ERA_0: &[&str] = &["+:2:1913/01/01:+*:民國:%EC%Ey年", "+:1:1912/01/01:1912/12/31:民國:%EC元年", "+:1:1911/12/31:-*:民前:%EC%Ey年"];
+    pub const MON_0: &[&str] = &["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"];
+    pub const MON_1: &[&str] = &["Bisha Koobaad", "Bisha Labaad", "Bisha Saddexaad", "Bisha Afraad", "Bisha Shanaad", "Bisha Lixaad", "Bisha Todobaad", "Bisha Sideedaad", "Bisha Sagaalaad", "Bisha Tobnaad", "Bisha Kow iyo Tobnaad", "Bisha Laba iyo Tobnaad"];
+    pub const MON_2: &[&str] = &["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"];
+    pub const MON_3: &[&str] = &["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"];
+    pub const MON_4: &[&str] = &["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"];
+    pub const MON_5: &[&str] = &["Jaunuwoa", "Februwoa", "Moaz", "Aprell", "Mai", "Juni", "Juli", "August", "Septamba", "Oktoba", "Nowamba", "Dezamba"];
+    pub const MON_6: &[&str] = &["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"];
+    pub const MON_7: &[&str] = &["Qunxa Garablu", "Naharsi Kudo", "Ciggilta Kudo", "Agda Baxis", "Caxah Alsa", "Qasa Dirri", "Qado Dirri", "Liiqen", "Waysu", "Diteli", "Ximoli", "Kaxxa Garablu"];
+    pub const MON_8: &[&str] = &["Yanüari", "Febrüari", "Mart", "Aprel", "Mei", "Yüni", "Yüli", "Ougùstùs", "Sèptèmber", "Oktober", "Novèmber", "Desèmber"];
+    pub const MON_9: &[&str] = &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"];
+    pub const MON_10: &[&str] = &["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "setiembre", "octubre", "noviembre", "diciembre"];
+    pub const MON_11: &[&str] = &["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"];
+    pub const MON_12: &[&str] = &["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"];
+    pub const MON_13: &[&str] = &["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"];
+    pub const MON_14: &[&str] = &["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"];
+    pub const MON_15: &[&str] = &["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"];
+    pub const MON_16: &[&str] = &["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"];
+    pub const MON_17: &[&str] = &["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"];
+    pub const MON_18: &[&str] = &["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"];
+    pub const MON_19: &[&str] = &["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"];
+    pub const MON_20: &[&str] = &["جانفي", "فيفري", "مارس", "أفريل", "ماي", "جوان", "جويلية", "أوت", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"];
+    pub const MON_21: &[&str] = &["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"];
+    pub const MON_22: &[&str] = &["كانون الثاني", "شباط", "آذار", "نيسان", "أيار", "حزيران", "تموز", "آب", "أيلول", "تشرين الأول", "تشرين الثاني", "كانون الأول"];
+    pub const MON_23: &[&str] = &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"];
+    pub const MON_24: &[&str] = &["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"];
+    pub const MON_25: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"];
+    pub const MON_26: &[&str] = &["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"];
+    pub const MON_27: &[&str] = &["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"];
+    pub const MON_28: &[&str] = &["ཟ\u{fb3}་བ་དང་པ་", "ཟ\u{fb3}་བ་གཉ\u{f72}ས་པ་", "ཟ\u{fb3}་བ་གས\u{f74}མ་པ་", "ཟ\u{fb3}་བ་བཞ\u{f72}་པ་", "ཟ\u{fb3}་བ་ལ\u{f94}་ཕ་", "ཟ\u{fb3}་བ་ད\u{fb2}\u{f74}ག་པ་", "ཟ\u{fb3}་བ་བད\u{f74}ནཔ་", "ཟ\u{fb3}་བ་བར\u{f92}\u{fb1}ད་པ་", "ཟ\u{fb3}་བ་དག\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཅ\u{f72}ག་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཉ\u{f72}ས་པ་"];
+    pub const MON_29: &[&str] = &["ጃንዩወሪ", "ፌብሩወሪ", "ማርች", "ኤፕረል", "ሜይ", "ጁን", "ጁላይ", "ኦገስት", "ሴፕቴምበር", "ኦክተውበር", "ኖቬምበር", "ዲሴምበር"];
+    pub const MON_30: &[&str] = &["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"];
+    pub const MON_31: &[&str] = &["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"];
+    pub const WEEK_0: &[i64] = &[7, 19971130, 1];
+    pub const WEEK_1: &[i64] = &[7, 19971130, 4];
+    pub const WEEK_2: &[i64] = &[7, 19971130, 7];
+}
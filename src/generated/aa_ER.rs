@@ -0,0 +1,132 @@
+pub mod LC_ADDRESS {
+    /// `Some("ERI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ERI");
+    /// `Some("ER")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ER");
+    /// `Some("ER")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ER");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Eretria")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Eretria");
+    /// `Some(232)`
+    pub const COUNTRY_NUM: Option<i64> = Some(232);
+    /// `Some("ERI")`
+    pub const COUNTRY_POST: Option<&str> = Some("ERI");
+    /// `Some("aa")`
+    pub const LANG_AB: Option<&str> = Some("aa");
+    /// `Some("aar")`
+    pub const LANG_LIB: Option<&str> = Some("aar");
+    /// `Some("Qafar")`
+    pub const LANG_NAME: Option<&str> = Some("Qafar");
+    /// `Some("aar")`
+    pub const LANG_TERM: Option<&str> = Some("aar");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_0);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Afar")`
+    pub const LANGUAGE: Option<&str> = Some("Afar");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Eritrea")`
+    pub const TERRITORY: Option<&str> = Some("Eritrea");
+    /// `Some("Afar language locale for Eritrea (Cadu/Laaqo Dialects).")`
+    pub const TITLE: Option<&str> = Some("Afar language locale for Eritrea (Cadu/Laaqo Dialects).");
+}
+pub use super::aa_ET::LC_MESSAGES;
+pub use super::ti_ER::LC_MONETARY;
+pub use super::ti_ER::LC_NUMERIC;
+pub use super::ti_ER::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Aca", "Etl", "Tal", "Arb", "Kam", "Gum", "Sab"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_0;
+    /// `&["Qun", "Nah", "Cig", "Agd", "Cax", "Qas", "Qad", "Leq", "Way", "Dit", "Xim", "Kax"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_8;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["saaku", "carra"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_5;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A, %B %e, %r %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %B %e, %r %Z %Y");
+    /// `&["Acaada", "Etleeni", "Talaata", "Arbaqa", "Kamiisi", "Gumqata", "Sabti"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_0;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A, %B %e, %Y %l:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A, %B %e, %Y %l:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Qunxa Garablu", "Naharsi Kudo", "Ciggilta Kudo", "Agda Baxis", "Caxah Alsa", "Qasa Dirri", "Qado Dirri", "Liiqen", "Waysu", "Diteli", "Ximoli", "Kaxxa Garablu"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_7;
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%A, %B %e, %Y %r %Z"`
+        pub const D_T_FMT: &str = "%A, %B %e, %Y %r %Z";
+        /// `Some("%l:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
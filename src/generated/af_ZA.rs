@@ -0,0 +1,139 @@
+pub mod LC_ADDRESS {
+    /// `Some("ZAF")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ZAF");
+    /// `Some("ZA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ZA");
+    /// `Some("ZA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ZA");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("Suid-Afrika")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Suid-Afrika");
+    /// `Some(710)`
+    pub const COUNTRY_NUM: Option<i64> = Some(710);
+    /// `Some("ZA")`
+    pub const COUNTRY_POST: Option<&str> = Some("ZA");
+    /// `Some("af")`
+    pub const LANG_AB: Option<&str> = Some("af");
+    /// `Some("afr")`
+    pub const LANG_LIB: Option<&str> = Some("afr");
+    /// `Some("Afrikaans")`
+    pub const LANG_NAME: Option<&str> = Some("Afrikaans");
+    /// `Some("afr")`
+    pub const LANG_TERM: Option<&str> = Some("afr");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Box 28364, Sunnyside, 0132, South Africa")`
+    pub const ADDRESS: Option<&str> = Some("Box 28364, Sunnyside, 0132, South Africa");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Dwayne Bailey")`
+    pub const CONTACT: Option<&str> = Some("Dwayne Bailey");
+    /// `Some("2005-10-13")`
+    pub const DATE: Option<&str> = Some("2005-10-13");
+    /// `Some("dwayne@translate.org.za")`
+    pub const EMAIL: Option<&str> = Some("dwayne@translate.org.za");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Afrikaans")`
+    pub const LANGUAGE: Option<&str> = Some("Afrikaans");
+    /// `Some("1.2.1")`
+    pub const REVISION: Option<&str> = Some("1.2.1");
+    /// `Some("Zuza Software Foundation (Translate.org.za)")`
+    pub const SOURCE: Option<&str> = Some("Zuza Software Foundation (Translate.org.za)");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("South Africa")`
+    pub const TERRITORY: Option<&str> = Some("South Africa");
+    /// `Some("Afrikaans locale for South Africa")`
+    pub const TITLE: Option<&str> = Some("Afrikaans locale for South Africa");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nee")`
+    pub const NOSTR: Option<&str> = Some("nee");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub use super::en_ZA::LC_MONETARY;
+pub use super::en_ZA::LC_NUMERIC;
+pub use super::en_ZA::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["So", "Ma", "Di", "Wo", "Do", "Vr", "Sa"]`
+    pub const ABDAY: &[&str] = &["So", "Ma", "Di", "Wo", "Do", "Vr", "Sa"];
+    /// `&["Jan", "Feb", "Mrt", "Apr", "Mei", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mrt", "Apr", "Mei", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["VM", "NM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_2;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sondag", "Maandag", "Dinsdag", "Woensdag", "Donderdag", "Vrydag", "Saterdag"]`
+    pub const DAY: &[&str] = &["Sondag", "Maandag", "Dinsdag", "Woensdag", "Donderdag", "Vrydag", "Saterdag"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"]`
+    pub const MON: &[&str] = &["Januarie", "Februarie", "Maart", "April", "Mei", "Junie", "Julie", "Augustus", "September", "Oktober", "November", "Desember"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
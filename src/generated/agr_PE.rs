@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("PER")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PER");
+    /// `Some("PE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PE");
+    /// `Some("PE")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PE");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Perú")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Perú");
+    /// `Some(604)`
+    pub const COUNTRY_NUM: Option<i64> = Some(604);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("agr")`
+    pub const LANG_LIB: Option<&str> = Some("agr");
+    /// `Some("Awajún")`
+    pub const LANG_NAME: Option<&str> = Some("Awajún");
+    /// `Some("")`
+    pub const LANG_TERM: Option<&str> = Some("");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2016-08-20")`
+    pub const DATE: Option<&str> = Some("2016-08-20");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Aguaruna")`
+    pub const LANGUAGE: Option<&str> = Some("Aguaruna");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("somosazucar.org")`
+    pub const SOURCE: Option<&str> = Some("somosazucar.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Peru")`
+    pub const TERRITORY: Option<&str> = Some("Peru");
+    /// `Some("Awajún (agr) locale for Peru")`
+    pub const TITLE: Option<&str> = Some("Awajún (agr) locale for Peru");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0aAnN]"`
+    pub const NOEXPR: &str = "^[-0aAnN]";
+    /// `Some("atsa")`
+    pub const NOSTR: Option<&str> = Some("atsa");
+    /// `"^[+1eEsSyY]"`
+    pub const YESEXPR: &str = "^[+1eEsSyY]";
+    /// `Some("ehe")`
+    pub const YESSTR: Option<&str> = Some("ehe");
+}
+pub use super::es_PE::LC_MONETARY;
+pub use super::es_PE::LC_NUMERIC;
+pub use super::es_PE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Tun", "Ach", "Kug", "Sak", "Shim", "Im", "Bat"]`
+    pub const ABDAY: &[&str] = &["Tun", "Ach", "Kug", "Sak", "Shim", "Im", "Bat"];
+    /// `&["Pet", "Kup", "Uya", "Tay", "Keg", "Teg", "Kun", "Yag", "Dait", "Ipam", "Shin", "Sak"]`
+    pub const ABMON: &[&str] = &["Pet", "Kup", "Uya", "Tay", "Keg", "Teg", "Kun", "Yag", "Dait", "Ipam", "Shin", "Sak"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["VM", "NM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_2;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %b %e %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %b %e %H:%M:%S %Z %Y");
+    /// `&["Tuntuamtin", "Achutin", "Kugkuktin", "Saketin", "Shimpitin", "Imaptin", "Bataetin"]`
+    pub const DAY: &[&str] = &["Tuntuamtin", "Achutin", "Kugkuktin", "Saketin", "Shimpitin", "Imaptin", "Bataetin"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(1)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(1);
+    /// `Some(2)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(2);
+    /// `&["Petsatin", "Kupitin", "Uyaitin", "Tayutin", "Kegketin", "Tegmatin", "Kuntutin", "Yagkujutin", "Daiktatin", "Ipamtatin", "Shinutin", "Sakamtin"]`
+    pub const MON: &[&str] = &["Petsatin", "Kupitin", "Uyaitin", "Tayutin", "Kegketin", "Tegmatin", "Kuntutin", "Yagkujutin", "Daiktatin", "Ipamtatin", "Shinutin", "Sakamtin"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 7])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_2);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("GHA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("GHA");
+    /// `Some("GH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("GH");
+    /// `Some("GH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("GH");
+    /// `Some("9964")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("9964");
+    /// `Some("Gaana")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Gaana");
+    /// `Some(288)`
+    pub const COUNTRY_NUM: Option<i64> = Some(288);
+    /// `Some("GH")`
+    pub const COUNTRY_POST: Option<&str> = Some("GH");
+    /// `Some("ak")`
+    pub const LANG_AB: Option<&str> = Some("ak");
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("Akan")`
+    pub const LANG_NAME: Option<&str> = Some("Akan");
+    /// `Some("aka")`
+    pub const LANG_TERM: Option<&str> = Some("aka");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("sugarlabs.org")`
+    pub const CONTACT: Option<&str> = Some("sugarlabs.org");
+    /// `Some("2013-08-24")`
+    pub const DATE: Option<&str> = Some("2013-08-24");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Akan")`
+    pub const LANGUAGE: Option<&str> = Some("Akan");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Sugar Labs / OLPC")`
+    pub const SOURCE: Option<&str> = Some("Sugar Labs / OLPC");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Ghana")`
+    pub const TERRITORY: Option<&str> = Some("Ghana");
+    /// `Some("Akan locale for Ghana")`
+    pub const TITLE: Option<&str> = Some("Akan locale for Ghana");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0dDnN]"`
+    pub const NOEXPR: &str = "^[-0dDnN]";
+    /// `Some("Daabi")`
+    pub const NOSTR: Option<&str> = Some("Daabi");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("Yiw")`
+    pub const YESSTR: Option<&str> = Some("Yiw");
+}
+pub mod LC_MONETARY {
+    /// `"GH₵"`
+    pub const CURRENCY_SYMBOL: &str = "GH₵";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"GHS "`
+    pub const INT_CURR_SYMBOL: &str = "GHS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("233")`
+    pub const INT_PREFIX: Option<&str> = Some("233");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Kwe", "Dwo", "Ben", "Wuk", "Yaw", "Fia", "Mem"]`
+    pub const ABDAY: &[&str] = &["Kwe", "Dwo", "Ben", "Wuk", "Yaw", "Fia", "Mem"];
+    /// `&["S-Ɔ", "K-Ɔ", "E-Ɔ", "E-O", "E-K", "O-A", "A-K", "D-Ɔ", "F-Ɛ", "Ɔ-A", "Ɔ-O", "M-Ɔ"]`
+    pub const ABMON: &[&str] = &["S-Ɔ", "K-Ɔ", "E-Ɔ", "E-O", "E-K", "O-A", "A-K", "D-Ɔ", "F-Ɛ", "Ɔ-A", "Ɔ-O", "M-Ɔ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AN", "EW"]`
+    pub const AM_PM: &[&str] = &["AN", "EW"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Kwesida", "Dwowda", "Benada", "Wukuda", "Yawda", "Fida", "Memeneda"]`
+    pub const DAY: &[&str] = &["Kwesida", "Dwowda", "Benada", "Wukuda", "Yawda", "Fida", "Memeneda"];
+    /// `"%Y/%m/%d"`
+    pub const D_FMT: &str = "%Y/%m/%d";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Sanda-Ɔpɛpɔn", "Kwakwar-Ɔgyefuo", "Ebɔw-Ɔbenem", "Ebɔbira-Oforisuo", "Esusow Aketseaba-Kɔtɔnimba", "Obirade-Ayɛwohomumu", "Ayɛwoho-Kitawonsa", "Difuu-Ɔsandaa", "Fankwa-Ɛbɔ", "Ɔbɛsɛ-Ahinime", "Ɔberɛfɛw-Obubuo", "Mumu-Ɔpɛnimba"]`
+    pub const MON: &[&str] = &["Sanda-Ɔpɛpɔn", "Kwakwar-Ɔgyefuo", "Ebɔw-Ɔbenem", "Ebɔbira-Oforisuo", "Esusow Aketseaba-Kɔtɔnimba", "Obirade-Ayɛwohomumu", "Ayɛwoho-Kitawonsa", "Difuu-Ɔsandaa", "Fankwa-Ɛbɔ", "Ɔbɛsɛ-Ahinime", "Ɔberɛfɛw-Obubuo", "Mumu-Ɔpɛnimba"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
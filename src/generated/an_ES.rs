@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("ESP")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ESP");
+    /// `Some("ES")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ES");
+    /// `Some("E")`
+    pub const COUNTRY_CAR: Option<&str> = Some("E");
+    /// `Some("978-84")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-84");
+    /// `Some("Espanya")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Espanya");
+    /// `Some(724)`
+    pub const COUNTRY_NUM: Option<i64> = Some(724);
+    /// `Some("E")`
+    pub const COUNTRY_POST: Option<&str> = Some("E");
+    /// `Some("an")`
+    pub const LANG_AB: Option<&str> = Some("an");
+    /// `Some("arg")`
+    pub const LANG_LIB: Option<&str> = Some("arg");
+    /// `Some("aragonés")`
+    pub const LANG_NAME: Option<&str> = Some("aragonés");
+    /// `Some("arg")`
+    pub const LANG_TERM: Option<&str> = Some("arg");
+    /// `Some("%a%N%f%N%d%N%b%N%s %h %e %r%N%z %T, %S%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%s %h %e %r%N%z %T, %S%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Jordi Mallach Pérez, Juan Pablo Martínez")`
+    pub const CONTACT: Option<&str> = Some("Jordi Mallach Pérez, Juan Pablo Martínez");
+    /// `Some("2018-02-05")`
+    pub const DATE: Option<&str> = Some("2018-02-05");
+    /// `Some("bug-glibc-locales@gnu.org, softaragones@softaragones.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org, softaragones@softaragones.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Aragonese")`
+    pub const LANGUAGE: Option<&str> = Some("Aragonese");
+    /// `Some("1.2")`
+    pub const REVISION: Option<&str> = Some("1.2");
+    /// `Some("Softaragones")`
+    pub const SOURCE: Option<&str> = Some("Softaragones");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Spain")`
+    pub const TERRITORY: Option<&str> = Some("Spain");
+    /// `Some("Aragonese locale for Spain")`
+    pub const TITLE: Option<&str> = Some("Aragonese locale for Spain");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("no")`
+    pub const NOSTR: Option<&str> = Some("no");
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `Some("sí")`
+    pub const YESSTR: Option<&str> = Some("sí");
+}
+pub use super::es_ES::LC_MONETARY;
+pub use super::es_ES::LC_NUMERIC;
+pub use super::es_ES::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["dom", "lun", "mar", "mie", "chu", "vie", "sab"]`
+    pub const ABDAY: &[&str] = &["dom", "lun", "mar", "mie", "chu", "vie", "sab"];
+    /// `&["chi", "feb", "mar", "abr", "may", "chn", "chl", "ago", "set", "oct", "nov", "avi"]`
+    pub const ABMON: &[&str] = &["chi", "feb", "mar", "abr", "may", "chn", "chl", "ago", "set", "oct", "nov", "avi"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["domingo", "luns", "martes", "mierques", "chueves", "viernes", "sabado"]`
+    pub const DAY: &[&str] = &["domingo", "luns", "martes", "mierques", "chueves", "viernes", "sabado"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["chinero", "febrero", "marzo", "abril", "mayo", "chunyo", "chuliol", "agosto", "setiembre", "octubre", "noviembre", "aviento"]`
+    pub const MON: &[&str] = &["chinero", "febrero", "marzo", "abril", "mayo", "chunyo", "chuliol", "agosto", "setiembre", "octubre", "noviembre", "aviento"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
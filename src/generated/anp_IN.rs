@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("anp")`
+    pub const LANG_LIB: Option<&str> = Some("anp");
+    /// `Some("अ\u{902}गिका")`
+    pub const LANG_NAME: Option<&str> = Some("अ\u{902}गिका");
+    /// `Some("anp")`
+    pub const LANG_TERM: Option<&str> = Some("anp");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2013-10-24")`
+    pub const DATE: Option<&str> = Some("2013-10-24");
+    /// `Some("bhashaghar@googlegroups.com")`
+    pub const EMAIL: Option<&str> = Some("bhashaghar@googlegroups.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Angika")`
+    pub const LANGUAGE: Option<&str> = Some("Angika");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Angika language locale for India")`
+    pub const TITLE: Option<&str> = Some("Angika language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0नइnN]"`
+    pub const NOEXPR: &str = "^[-0नइnN]";
+    /// `Some("नही\u{902}")`
+    pub const NOSTR: Option<&str> = Some("नही\u{902}");
+    /// `"^[+1हवyY]"`
+    pub const YESEXPR: &str = "^[+1हवyY]";
+    /// `Some("हा\u{901}")`
+    pub const YESSTR: Option<&str> = Some("हा\u{901}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["रवि", "सोम", "म\u{902}गल", "ब\u{941}ध", "ब\u{943}हस\u{94d}पति", "श\u{941}क\u{94d}र", "शनि"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_20;
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"]`
+    pub const ABMON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["रविवार", "सोमवार", "म\u{902}गलवार", "ब\u{941}धवार", "ब\u{943}हस\u{94d}पतिवार", "श\u{941}क\u{94d}रवार", "शनिवार"]`
+    pub const DAY: &[&str] = &["रविवार", "सोमवार", "म\u{902}गलवार", "ब\u{941}धवार", "ब\u{943}हस\u{94d}पतिवार", "श\u{941}क\u{94d}रवार", "शनिवार"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{948}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}ट\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_25;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
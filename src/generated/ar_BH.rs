@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("BHR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BHR");
+    /// `Some("BH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BH");
+    /// `Some("BRN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BRN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("البحرين")`
+    pub const COUNTRY_NAME: Option<&str> = Some("البحرين");
+    /// `Some(48)`
+    pub const COUNTRY_NUM: Option<i64> = Some(48);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ar")`
+    pub const LANG_AB: Option<&str> = Some("ar");
+    /// `Some("ara")`
+    pub const LANG_LIB: Option<&str> = Some("ara");
+    /// `Some("العربية")`
+    pub const LANG_NAME: Option<&str> = Some("العربية");
+    /// `Some("ara")`
+    pub const LANG_TERM: Option<&str> = Some("ara");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-20")`
+    pub const DATE: Option<&str> = Some("2000-07-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Arabic")`
+    pub const LANGUAGE: Option<&str> = Some("Arabic");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Bahrain")`
+    pub const TERRITORY: Option<&str> = Some("Bahrain");
+    /// `Some("Arabic language locale for Bahrain")`
+    pub const TITLE: Option<&str> = Some("Arabic language locale for Bahrain");
+}
+pub use super::ar_EG::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"د.ب."`
+    pub const CURRENCY_SYMBOL: &str = "د.ب.";
+    /// `3`
+    pub const FRAC_DIGITS: i64 = 3;
+    /// `"BHD "`
+    pub const INT_CURR_SYMBOL: &str = "BHD ";
+    /// `3`
+    pub const INT_FRAC_DIGITS: i64 = 3;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const N_SIGN_POSN: i64 = 2;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("973")`
+    pub const INT_PREFIX: Option<&str> = Some("973");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["ح", "ن", "ث", "ر", "خ", "ج", "س"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_18;
+    /// `&["ينا", "فبر", "مار", "أبر", "ماي", "يون", "يول", "أغس", "سبت", "أكت", "نوف", "ديس"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_27;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ص", "م"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_9;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["الأحد", "الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_19;
+    /// `"%d %b, %Y"`
+    pub const D_FMT: &str = "%d %b, %Y";
+    /// `"%d %b, %Y %Z %I:%M:%S %p"`
+    pub const D_T_FMT: &str = "%d %b, %Y %Z %I:%M:%S %p";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(1)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(1);
+    /// `&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_23;
+    /// `"%Z %I:%M:%S %p"`
+    pub const T_FMT: &str = "%Z %I:%M:%S %p";
+    /// `"%Z %I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%Z %I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%Z %I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%Z %I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
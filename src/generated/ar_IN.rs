@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("الهند")`
+    pub const COUNTRY_NAME: Option<&str> = Some("الهند");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ar")`
+    pub const LANG_AB: Option<&str> = Some("ar");
+    /// `Some("ara")`
+    pub const LANG_LIB: Option<&str> = Some("ara");
+    /// `Some("العربية")`
+    pub const LANG_NAME: Option<&str> = Some("العربية");
+    /// `Some("ara")`
+    pub const LANG_TERM: Option<&str> = Some("ara");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000,October,27 (XML source:2000,July,20)")`
+    pub const DATE: Option<&str> = Some("2000,October,27 (XML source:2000,July,20)");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Arabic")`
+    pub const LANGUAGE: Option<&str> = Some("Arabic");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Arabic language locale for India")`
+    pub const TITLE: Option<&str> = Some("Arabic language locale for India");
+}
+pub use super::ar_EG::LC_MESSAGES;
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ح", "ن", "ث", "ر", "خ", "ج", "س"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_18;
+    /// `&["ينا", "فبر", "مار", "أبر", "ماي", "يون", "يول", "أغس", "سبت", "أكت", "نوف", "ديس"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_27;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ص", "م"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_9;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["الأحد", "الاثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعة", "السبت"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_19;
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %B %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_23;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("SAU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SAU");
+    /// `Some("SA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("SA");
+    /// `Some("KSA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("KSA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("المملكة العربية السعودية")`
+    pub const COUNTRY_NAME: Option<&str> = Some("المملكة العربية السعودية");
+    /// `Some(682)`
+    pub const COUNTRY_NUM: Option<i64> = Some(682);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ar")`
+    pub const LANG_AB: Option<&str> = Some("ar");
+    /// `Some("ara")`
+    pub const LANG_LIB: Option<&str> = Some("ara");
+    /// `Some("العربية")`
+    pub const LANG_NAME: Option<&str> = Some("العربية");
+    /// `Some("ara")`
+    pub const LANG_TERM: Option<&str> = Some("ara");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Arabic")`
+    pub const LANGUAGE: Option<&str> = Some("Arabic");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Saudi Arabia")`
+    pub const TERRITORY: Option<&str> = Some("Saudi Arabia");
+    /// `Some("Arabic locale for Saudi Arabia")`
+    pub const TITLE: Option<&str> = Some("Arabic locale for Saudi Arabia");
+}
+pub use super::ar_EG::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"ر.س"`
+    pub const CURRENCY_SYMBOL: &str = "ر.س";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"SAR "`
+    pub const INT_CURR_SYMBOL: &str = "SAR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[-1]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_0;
+    /// `""`
+    pub const MON_THOUSANDS_SEP: &str = "";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[-1]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_0;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("966")`
+    pub const INT_PREFIX: Option<&str> = Some("966");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ح", "ن", "ث", "ر", "خ", "ج", "س"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_18;
+    /// `&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"]`
+    pub const ABMON: &[&str] = &["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["الأحد", "الإثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعـة", "السبت"]`
+    pub const DAY: &[&str] = &["الأحد", "الإثنين", "الثلاثاء", "الأربعاء", "الخميس", "الجمعـة", "السبت"];
+    /// `"%A %e %B %Y"`
+    pub const D_FMT: &str = "%A %e %B %Y";
+    /// `"%A %e %B %Y  %k:%M:%S"`
+    pub const D_T_FMT: &str = "%A %e %B %Y  %k:%M:%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `Some("")`
+    pub const ERA_D_FMT: Option<&str> = Some("");
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `Some(1)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(1);
+    /// `&["يناير", "فبراير", "مارس", "أبريل", "مايو", "يونيو", "يوليو", "أغسطس", "سبتمبر", "أكتوبر", "نوفمبر", "ديسمبر"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_23;
+    /// `"%k:%M:%S"`
+    pub const T_FMT: &str = "%k:%M:%S";
+    /// `"%k:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%k:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%k:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%k:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `None`
+    pub const COUNTRY_AB3: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_ABTWO: Option<&str> = None;
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ভ\u{9be}ৰত")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ভ\u{9be}ৰত");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("as")`
+    pub const LANG_AB: Option<&str> = Some("as");
+    /// `Some("asm")`
+    pub const LANG_LIB: Option<&str> = Some("asm");
+    /// `Some("অসমীয\u{9bc}\u{9be}")`
+    pub const LANG_NAME: Option<&str> = Some("অসমীয\u{9bc}\u{9be}");
+    /// `Some("asm")`
+    pub const LANG_TERM: Option<&str> = Some("asm");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-05-25")`
+    pub const DATE: Option<&str> = Some("2006-05-25");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Assamese")`
+    pub const LANGUAGE: Option<&str> = Some("Assamese");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Amitakhya Phukan, Red Hat")`
+    pub const SOURCE: Option<&str> = Some("Amitakhya Phukan, Red Hat");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Assamese language locale for India")`
+    pub const TITLE: Option<&str> = Some("Assamese language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNন]"`
+    pub const NOEXPR: &str = "^[-0nNন]";
+    /// `Some("নহয়")`
+    pub const NOSTR: Option<&str> = Some("নহয়");
+    /// `"^[+1yYহ]"`
+    pub const YESEXPR: &str = "^[+1yYহ]";
+    /// `Some("হয়")`
+    pub const YESSTR: Option<&str> = Some("হয়");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::en_IN::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("91")`
+    pub const INT_PREFIX: Option<&str> = Some("91");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["দেও", "সোম", "মঙ\u{9cd}গল", "ব\u{9c1}ধ", "ব\u{9c3}হষ\u{9cd}পতি", "শ\u{9c1}ক\u{9cd}ৰ", "শনি"]`
+    pub const ABDAY: &[&str] = &["দেও", "সোম", "মঙ\u{9cd}গল", "ব\u{9c1}ধ", "ব\u{9c3}হষ\u{9cd}পতি", "শ\u{9c1}ক\u{9cd}ৰ", "শনি"];
+    /// `&["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "সেপ\u{9cd}ট", "অক\u{9cd}টো", "নভে", "ডিসে"]`
+    pub const ABMON: &[&str] = &["জ\u{9be}ন\u{9c1}", "ফেব\u{9cd}ৰ\u{9c1}", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগ", "সেপ\u{9cd}ট", "অক\u{9cd}টো", "নভে", "ডিসে"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["প\u{9c2}ৰ\u{9cd}ব\u{9cd}ব\u{9be}হ\u{9cd}ন", "অপৰ\u{9be}হ\u{9cd}ন"]`
+    pub const AM_PM: &[&str] = &["প\u{9c2}ৰ\u{9cd}ব\u{9cd}ব\u{9be}হ\u{9cd}ন", "অপৰ\u{9be}হ\u{9cd}ন"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["দেওব\u{9be}ৰ", "সোমব\u{9be}ৰ", "মঙ\u{9cd}গলব\u{9be}ৰ", "ব\u{9c1}ধব\u{9be}ৰ", "ব\u{9c3}হষ\u{9cd}পতিব\u{9be}ৰ", "শ\u{9c1}ক\u{9cd}ৰব\u{9be}ৰ", "শনিব\u{9be}ৰ"]`
+    pub const DAY: &[&str] = &["দেওব\u{9be}ৰ", "সোমব\u{9be}ৰ", "মঙ\u{9cd}গলব\u{9be}ৰ", "ব\u{9c1}ধব\u{9be}ৰ", "ব\u{9c3}হষ\u{9cd}পতিব\u{9be}ৰ", "শ\u{9c1}ক\u{9cd}ৰব\u{9be}ৰ", "শনিব\u{9be}ৰ"];
+    /// `"%e-%m-%Y"`
+    pub const D_FMT: &str = "%e-%m-%Y";
+    /// `"%e %B, %Y %I.%M.%S %p %Z"`
+    pub const D_T_FMT: &str = "%e %B, %Y %I.%M.%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"]`
+    pub const MON: &[&str] = &["জ\u{9be}ন\u{9c1}ৱ\u{9be}ৰী", "ফেব\u{9cd}ৰ\u{9c1}ৱ\u{9be}ৰী", "ম\u{9be}ৰ\u{9cd}চ", "এপ\u{9cd}ৰিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "ছেপ\u{9cd}তেম\u{9cd}বৰ", "অক\u{9cd}টোবৰ", "নৱেম\u{9cd}বৰ", "ডিচেম\u{9cd}বৰ"];
+    /// `"%I.%M.%S %p"`
+    pub const T_FMT: &str = "%I.%M.%S %p";
+    /// `"%I.%M.%S %p"`
+    pub const T_FMT_AMPM: &str = "%I.%M.%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I.%M.%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I.%M.%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
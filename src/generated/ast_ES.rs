@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("ESP")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ESP");
+    /// `Some("ES")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ES");
+    /// `Some("E")`
+    pub const COUNTRY_CAR: Option<&str> = Some("E");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("España")`
+    pub const COUNTRY_NAME: Option<&str> = Some("España");
+    /// `Some(724)`
+    pub const COUNTRY_NUM: Option<i64> = Some(724);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("ast")`
+    pub const LANG_LIB: Option<&str> = Some("ast");
+    /// `Some("asturianu")`
+    pub const LANG_NAME: Option<&str> = Some("asturianu");
+    /// `Some("ast")`
+    pub const LANG_TERM: Option<&str> = Some("ast");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Jordi Mallach")`
+    pub const CONTACT: Option<&str> = Some("Jordi Mallach");
+    /// `Some("2005-08-26")`
+    pub const DATE: Option<&str> = Some("2005-08-26");
+    /// `Some("jordi@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("jordi@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Asturian")`
+    pub const LANGUAGE: Option<&str> = Some("Asturian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Spain")`
+    pub const TERRITORY: Option<&str> = Some("Spain");
+    /// `Some("Asturian locale for Spain")`
+    pub const TITLE: Option<&str> = Some("Asturian locale for Spain");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("non")`
+    pub const NOSTR: Option<&str> = Some("non");
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `Some("sí")`
+    pub const YESSTR: Option<&str> = Some("sí");
+}
+pub use super::es_ES::LC_MONETARY;
+pub use super::es_ES::LC_NUMERIC;
+pub use super::es_ES::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["dom", "llu", "mar", "mié", "xue", "vie", "sáb"]`
+    pub const ABDAY: &[&str] = &["dom", "llu", "mar", "mié", "xue", "vie", "sáb"];
+    /// `&["xin", "feb", "mar", "abr", "may", "xun", "xnt", "ago", "set", "och", "pay", "avi"]`
+    pub const ABMON: &[&str] = &["xin", "feb", "mar", "abr", "may", "xun", "xnt", "ago", "set", "och", "pay", "avi"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["xineru", "febreru", "marzu", "abril", "mayu", "xunu", "xunetu", "agostu", "setiembre", "ochobre", "payares", "avientu"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["xineru", "febreru", "marzu", "abril", "mayu", "xunu", "xunetu", "agostu", "setiembre", "ochobre", "payares", "avientu"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["domingu", "llunes", "martes", "miércoles", "xueves", "vienres", "sábadu"]`
+    pub const DAY: &[&str] = &["domingu", "llunes", "martes", "miércoles", "xueves", "vienres", "sábadu"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["de xineru", "de febreru", "de marzu", "d’abril", "de mayu", "de xunu", "de xunetu", "d’agostu", "de setiembre", "d’ochobre", "de payares", "d’avientu"]`
+    pub const MON: &[&str] = &["de xineru", "de febreru", "de marzu", "d’abril", "de mayu", "de xunu", "de xunetu", "d’agostu", "de setiembre", "d’ochobre", "de payares", "d’avientu"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("PER")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PER");
+    /// `Some("PE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PE");
+    /// `Some("PE")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PE");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Piruw")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Piruw");
+    /// `Some(604)`
+    pub const COUNTRY_NUM: Option<i64> = Some(604);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ay")`
+    pub const LANG_AB: Option<&str> = Some("ay");
+    /// `Some("ayc")`
+    pub const LANG_LIB: Option<&str> = Some("ayc");
+    /// `Some("Aymar aru")`
+    pub const LANG_NAME: Option<&str> = Some("Aymar aru");
+    /// `Some("ayc")`
+    pub const LANG_TERM: Option<&str> = Some("ayc");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2011-11-13")`
+    pub const DATE: Option<&str> = Some("2011-11-13");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Aymara")`
+    pub const LANGUAGE: Option<&str> = Some("Aymara");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("runasimipi.org")`
+    pub const SOURCE: Option<&str> = Some("runasimipi.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Peru")`
+    pub const TERRITORY: Option<&str> = Some("Peru");
+    /// `Some("Aymara (ayc) locale for Peru")`
+    pub const TITLE: Option<&str> = Some("Aymara (ayc) locale for Peru");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0jJnN]"`
+    pub const NOEXPR: &str = "^[-0jJnN]";
+    /// `Some("Janiwa")`
+    pub const NOSTR: Option<&str> = Some("Janiwa");
+    /// `"^[+1uUsSyY]"`
+    pub const YESEXPR: &str = "^[+1uUsSyY]";
+    /// `Some("Ukhamawa")`
+    pub const YESSTR: Option<&str> = Some("Ukhamawa");
+}
+pub use super::es_PE::LC_MONETARY;
+pub use super::es_PE::LC_NUMERIC;
+pub use super::es_PE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["tum", "lun", "mar", "mir", "juy", "wir", "saw"]`
+    pub const ABDAY: &[&str] = &["tum", "lun", "mar", "mir", "juy", "wir", "saw"];
+    /// `&["ini", "phi", "mar", "awr", "may", "jun", "jul", "awu", "sit", "ukt", "nuw", "ris"]`
+    pub const ABMON: &[&str] = &["ini", "phi", "mar", "awr", "may", "jun", "jul", "awu", "sit", "ukt", "nuw", "ris"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["VM", "NM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_2;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["tuminku", "lunisa", "martisa", "mirkulisa", "juywisa", "wirnisa", "sawäru"]`
+    pub const DAY: &[&str] = &["tuminku", "lunisa", "martisa", "mirkulisa", "juywisa", "wirnisa", "sawäru"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["inïru", "phiwriru", "marsu", "awrila", "mayu", "junyu", "julyu", "awustu", "sitimri", "uktuwri", "nuwimri", "risimri"]`
+    pub const MON: &[&str] = &["inïru", "phiwriru", "marsu", "awrila", "mayu", "junyu", "julyu", "awustu", "sitimri", "uktuwri", "nuwimri", "risimri"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,196 @@
+pub mod LC_ADDRESS {
+    /// `Some("AZE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("AZE");
+    /// `Some("AZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AZ");
+    /// `Some("AZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Azərbaycan")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Azərbaycan");
+    /// `Some(31)`
+    pub const COUNTRY_NUM: Option<i64> = Some(31);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("az")`
+    pub const LANG_AB: Option<&str> = Some("az");
+    /// `Some("aze")`
+    pub const LANG_LIB: Option<&str> = Some("aze");
+    /// `Some("azərbaycan")`
+    pub const LANG_NAME: Option<&str> = Some("azərbaycan");
+    /// `Some("aze")`
+    pub const LANG_TERM: Option<&str> = Some("aze");
+    /// `Some("???")`
+    pub const POSTAL_FMT: Option<&str> = Some("???");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2001-01-26")`
+    pub const DATE: Option<&str> = Some("2001-01-26");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Azerbaijani")`
+    pub const LANGUAGE: Option<&str> = Some("Azerbaijani");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Azerbaijan")`
+    pub const TERRITORY: Option<&str> = Some("Azerbaijan");
+    /// `Some("Azeri language locale for Azerbaijan (latin)")`
+    pub const TITLE: Option<&str> = Some("Azeri language locale for Azerbaijan (latin)");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0YyNn]"`
+    pub const NOEXPR: &str = "^[-0YyNn]";
+    /// `Some("yox")`
+    pub const NOSTR: Option<&str> = Some("yox");
+    /// `"^[+1bBhH]"`
+    pub const YESEXPR: &str = "^[+1bBhH]";
+    /// `Some("hə")`
+    pub const YESSTR: Option<&str> = Some("hə");
+}
+pub mod LC_MONETARY {
+    /// `"₼"`
+    pub const CURRENCY_SYMBOL: &str = "₼";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"AZN "`
+    pub const INT_CURR_SYMBOL: &str = "AZN ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("994")`
+    pub const INT_PREFIX: Option<&str> = Some("994");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["baz", "ber", "çax", "çər", "cax", "cüm", "şnb"]`
+    pub const ABDAY: &[&str] = &["baz", "ber", "çax", "çər", "cax", "cüm", "şnb"];
+    /// `&["Yan", "Fev", "Mar", "Apr", "May", "İyn", "İyl", "Avq", "Sen", "Okt", "Noy", "Dek"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_10;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["bazar günü", "bazar ertəsi", "çərşənbə axşamı", "çərşənbə", "cümə axşamı", "cümə", "şənbə"]`
+    pub const DAY: &[&str] = &["bazar günü", "bazar ertəsi", "çərşənbə axşamı", "çərşənbə", "cümə axşamı", "cümə", "şənbə"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%A, %d %B %Y %T"`
+    pub const D_T_FMT: &str = "%A, %d %B %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_17;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
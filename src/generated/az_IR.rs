@@ -0,0 +1,137 @@
+pub mod LC_ADDRESS {
+    /// `Some("IRN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IRN");
+    /// `Some("IR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IR");
+    /// `Some("IR")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IR");
+    /// `Some("964")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("964");
+    /// `Some("ایران")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ایران");
+    /// `Some(364)`
+    pub const COUNTRY_NUM: Option<i64> = Some(364);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("az")`
+    pub const LANG_AB: Option<&str> = Some("az");
+    /// `Some("aze")`
+    pub const LANG_LIB: Option<&str> = Some("aze");
+    /// `Some("تۆرکجه")`
+    pub const LANG_NAME: Option<&str> = Some("تۆرکجه");
+    /// `Some("azb")`
+    pub const LANG_TERM: Option<&str> = Some("azb");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Mousa Moradi")`
+    pub const CONTACT: Option<&str> = Some("Mousa Moradi");
+    /// `Some("2014-05-01")`
+    pub const DATE: Option<&str> = Some("2014-05-01");
+    /// `Some("mousamk@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("mousamk@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("South Azerbaijani")`
+    pub const LANGUAGE: Option<&str> = Some("South Azerbaijani");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("(+98) 914 492 1058")`
+    pub const TEL: Option<&str> = Some("(+98) 914 492 1058");
+    /// `Some("Iran")`
+    pub const TERRITORY: Option<&str> = Some("Iran");
+    /// `Some("South Azerbaijani language locale for Iran")`
+    pub const TITLE: Option<&str> = Some("South Azerbaijani language locale for Iran");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNیخ]"`
+    pub const NOEXPR: &str = "^[-0nNیخ]";
+    /// `Some("یو\u{652}خ")`
+    pub const NOSTR: Option<&str> = Some("یو\u{652}خ");
+    /// `"^[+1yYهب]"`
+    pub const YESEXPR: &str = "^[+1yYهب]";
+    /// `Some("هن")`
+    pub const YESSTR: Option<&str> = Some("هن");
+}
+pub use super::fa_IR::LC_MONETARY;
+pub use super::fa_IR::LC_NUMERIC;
+pub use super::fa_IR::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "جۆمعه آخشامی", "جۆمعه", "شنبه"]`
+    pub const ABDAY: &[&str] = &["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "جۆمعه آخشامی", "جۆمعه", "شنبه"];
+    /// `&["ژانویه", "فوریه", "مارس", "آوریل", "مئی", "ژوئن", "جولای", "آقۇست", "سپتامبر", "او\u{652}کتو\u{652}بر", "نو\u{652}وامبر", "دسامبر"]`
+    pub const ABMON: &[&str] = &["ژانویه", "فوریه", "مارس", "آوریل", "مئی", "ژوئن", "جولای", "آقۇست", "سپتامبر", "او\u{652}کتو\u{652}بر", "نو\u{652}وامبر", "دسامبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["۰۰", "۰۱", "۰۲", "۰۳", "۰۴", "۰۵", "۰۶", "۰۷", "۰۸", "۰۹", "۱۰", "۱۱", "۱۲", "۱۳", "۱۴", "۱۵", "۱۶", "۱۷", "۱۸", "۱۹", "۲۰", "۲۱", "۲۲", "۲۳", "۲۴", "۲۵", "۲۶", "۲۷", "۲۸", "۲۹", "۳۰", "۳۱", "۳۲", "۳۳", "۳۴", "۳۵", "۳۶", "۳۷", "۳۸", "۳۹", "۴۰", "۴۱", "۴۲", "۴۳", "۴۴", "۴۵", "۴۶", "۴۷", "۴۸", "۴۹", "۵۰", "۵۱", "۵۲", "۵۳", "۵۴", "۵۵", "۵۶", "۵۷", "۵۸", "۵۹", "۶۰", "۶۱", "۶۲", "۶۳", "۶۴", "۶۵", "۶۶", "۶۷", "۶۸", "۶۹", "۷۰", "۷۱", "۷۲", "۷۳", "۷۴", "۷۵", "۷۶", "۷۷", "۷۸", "۷۹", "۸۰", "۸۱", "۸۲", "۸۳", "۸۴", "۸۵", "۸۶", "۸۷", "۸۸", "۸۹", "۹۰", "۹۱", "۹۲", "۹۳", "۹۴", "۹۵", "۹۶", "۹۷", "۹۸", "۹۹"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(crate::__data::LC_TIME::ALT_DIGITS_0);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `Some("\u{202b}%A %Oe %B %Oy، ساعات %OH:%OM:%OS (%Z)\u{202c}")`
+    pub const DATE_FMT: Option<&str> = Some("\u{202b}%A %Oe %B %Oy، ساعات %OH:%OM:%OS (%Z)\u{202c}");
+    /// `&["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "جۆمعه آخشامی", "جۆمعه", "شنبه"]`
+    pub const DAY: &[&str] = &["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "جۆمعه آخشامی", "جۆمعه", "شنبه"];
+    /// `"%Oy/%Om/%Od"`
+    pub const D_FMT: &str = "%Oy/%Om/%Od";
+    /// `"\u{202b}%A %Oe %B %Oy، %OH:%OM:%OS\u{202c}"`
+    pub const D_T_FMT: &str = "\u{202b}%A %Oe %B %Oy، %OH:%OM:%OS\u{202c}";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(7)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(7);
+    /// `&["ژانویه", "فوریه", "مارس", "آوریل", "مئی", "ژوئن", "جولای", "آقۇست", "سپتامبر", "او\u{652}کتو\u{652}بر", "نو\u{652}وامبر", "دسامبر"]`
+    pub const MON: &[&str] = &["ژانویه", "فوریه", "مارس", "آوریل", "مئی", "ژوئن", "جولای", "آقۇست", "سپتامبر", "او\u{652}کتو\u{652}بر", "نو\u{652}وامبر", "دسامبر"];
+    /// `"%OH:%OM:%OS"`
+    pub const T_FMT: &str = "%OH:%OM:%OS";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("BLR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BLR");
+    /// `Some("BY")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BY");
+    /// `Some("BY")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BY");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Беларусь")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Беларусь");
+    /// `Some(112)`
+    pub const COUNTRY_NUM: Option<i64> = Some(112);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("be")`
+    pub const LANG_AB: Option<&str> = Some("be");
+    /// `Some("bel")`
+    pub const LANG_LIB: Option<&str> = Some("bel");
+    /// `Some("беларуская")`
+    pub const LANG_NAME: Option<&str> = Some("беларуская");
+    /// `Some("bel")`
+    pub const LANG_TERM: Option<&str> = Some("bel");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Belarusian")`
+    pub const LANGUAGE: Option<&str> = Some("Belarusian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Belarus")`
+    pub const TERRITORY: Option<&str> = Some("Belarus");
+    /// `Some("Belarusian locale for Belarus")`
+    pub const TITLE: Option<&str> = Some("Belarusian locale for Belarus");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNНн]"`
+    pub const NOEXPR: &str = "^[-0nNНн]";
+    /// `Some("не")`
+    pub const NOSTR: Option<&str> = Some("не");
+    /// `"^[+1yYТт]"`
+    pub const YESEXPR: &str = "^[+1yYТт]";
+    /// `Some("так")`
+    pub const YESSTR: Option<&str> = Some("так");
+}
+pub mod LC_MONETARY {
+    /// `"руб"`
+    pub const CURRENCY_SYMBOL: &str = "руб";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"BYR "`
+    pub const INT_CURR_SYMBOL: &str = "BYR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("375")`
+    pub const INT_PREFIX: Option<&str> = Some("375");
+    /// `Some("8~10")`
+    pub const INT_SELECT: Option<&str> = Some("8~10");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Няд", "Пан", "Аўт", "Срд", "Чцв", "Пят", "Суб"]`
+    pub const ABDAY: &[&str] = &["Няд", "Пан", "Аўт", "Срд", "Чцв", "Пят", "Суб"];
+    /// `&["сту", "лют", "сак", "кра", "мая", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"]`
+    pub const ABMON: &[&str] = &["сту", "лют", "сак", "кра", "мая", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"];
+    /// `Some(&["сту", "лют", "сак", "кра", "май", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"])`
+    pub const AB_ALT_MON: Option<&[&str]> = Some(&["сту", "лют", "сак", "кра", "май", "чэр", "ліп", "жні", "вер", "кас", "ліс", "сне"]);
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["студзень", "люты", "сакавік", "красавік", "май", "чэрвень", "ліпень", "жнівень", "верасень", "кастрычнік", "лістапад", "снежань"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["студзень", "люты", "сакавік", "красавік", "май", "чэрвень", "ліпень", "жнівень", "верасень", "кастрычнік", "лістапад", "снежань"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Нядзеля", "Панядзелак", "Аўторак", "Серада", "Чацвер", "Пятніца", "Субота"]`
+    pub const DAY: &[&str] = &["Нядзеля", "Панядзелак", "Аўторак", "Серада", "Чацвер", "Пятніца", "Субота"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["студзеня", "лютага", "сакавіка", "красавіка", "мая", "чэрвеня", "ліпеня", "жніўня", "верасня", "кастрычніка", "лістапада", "снежня"]`
+    pub const MON: &[&str] = &["студзеня", "лютага", "сакавіка", "красавіка", "мая", "чэрвеня", "ліпеня", "жніўня", "верасня", "кастрычніка", "лістапада", "снежня"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("ZMB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ZMB");
+    /// `Some("ZM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ZM");
+    /// `Some("Z")`
+    pub const COUNTRY_CAR: Option<&str> = Some("Z");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Zambia")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Zambia");
+    /// `Some(894)`
+    pub const COUNTRY_NUM: Option<i64> = Some(894);
+    /// `Some("ZM")`
+    pub const COUNTRY_POST: Option<&str> = Some("ZM");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("bem")`
+    pub const LANG_LIB: Option<&str> = Some("bem");
+    /// `Some("Ichibemba")`
+    pub const LANG_NAME: Option<&str> = Some("Ichibemba");
+    /// `Some("bem")`
+    pub const LANG_TERM: Option<&str> = Some("bem");
+    /// `Some("%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Martin Benjamin")`
+    pub const CONTACT: Option<&str> = Some("Martin Benjamin");
+    /// `Some("2011-04-18")`
+    pub const DATE: Option<&str> = Some("2011-04-18");
+    /// `Some("locales@africanlocalization.net")`
+    pub const EMAIL: Option<&str> = Some("locales@africanlocalization.net");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Bemba")`
+    pub const LANGUAGE: Option<&str> = Some("Bemba");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("ANLoc")`
+    pub const SOURCE: Option<&str> = Some("ANLoc");
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Zambia")`
+    pub const TERRITORY: Option<&str> = Some("Zambia");
+    /// `Some("Bemba locale for Zambia")`
+    pub const TITLE: Option<&str> = Some("Bemba locale for Zambia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNaA]"`
+    pub const NOEXPR: &str = "^[-0nNaA]";
+    /// `Some("Awe")`
+    pub const NOSTR: Option<&str> = Some("Awe");
+    /// `"^[+1yYeE]"`
+    pub const YESEXPR: &str = "^[+1yYeE]";
+    /// `Some("Ee")`
+    pub const YESSTR: Option<&str> = Some("Ee");
+}
+pub mod LC_MONETARY {
+    /// `"K"`
+    pub const CURRENCY_SYMBOL: &str = "K";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"ZMW "`
+    pub const INT_CURR_SYMBOL: &str = "ZMW ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("260")`
+    pub const INT_PREFIX: Option<&str> = Some("260");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %A %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %A %l");
+}
+pub mod LC_TIME {
+    /// `&["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_6;
+    /// `&["Jan", "Feb", "Mac", "Epr", "Mei", "Jun", "Jul", "Oga", "Sep", "Okt", "Nov", "Dis"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mac", "Epr", "Mei", "Jun", "Jul", "Oga", "Sep", "Okt", "Nov", "Dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["uluchelo", "akasuba"]`
+    pub const AM_PM: &[&str] = &["uluchelo", "akasuba"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Pa Mulungu", "Palichimo", "Palichibuli", "Palichitatu", "Palichine", "Palichisano", "Pachibelushi"]`
+    pub const DAY: &[&str] = &["Pa Mulungu", "Palichimo", "Palichibuli", "Palichitatu", "Palichine", "Palichisano", "Pachibelushi"];
+    /// `"%m/%d/%Y"`
+    pub const D_FMT: &str = "%m/%d/%Y";
+    /// `"%a %d %b %Y %R %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %R %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januari", "Februari", "Machi", "Epreo", "Mei", "Juni", "Julai", "Ogasti", "Septemba", "Oktoba", "Novemba", "Disemba"]`
+    pub const MON: &[&str] = &["Januari", "Februari", "Machi", "Epreo", "Mei", "Juni", "Julai", "Ogasti", "Septemba", "Oktoba", "Novemba", "Disemba"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,187 @@
+pub mod LC_ADDRESS {
+    /// `Some("DZA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DZA");
+    /// `Some("DZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DZ");
+    /// `Some("DZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("DZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Lezzayer")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Lezzayer");
+    /// `Some(12)`
+    pub const COUNTRY_NUM: Option<i64> = Some(12);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("ber")`
+    pub const LANG_LIB: Option<&str> = Some("ber");
+    /// `Some("Tamaziɣt")`
+    pub const LANG_NAME: Option<&str> = Some("Tamaziɣt");
+    /// `Some("ber")`
+    pub const LANG_TERM: Option<&str> = Some("ber");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2002-04-16")`
+    pub const DATE: Option<&str> = Some("2002-04-16");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Berber")`
+    pub const LANGUAGE: Option<&str> = Some("Berber");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Algeria")`
+    pub const TERRITORY: Option<&str> = Some("Algeria");
+    /// `Some("Berber language locale for Algeria (latin)")`
+    pub const TITLE: Option<&str> = Some("Berber language locale for Algeria (latin)");
+}
+pub use super::kab_DZ::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"د.ج."`
+    pub const CURRENCY_SYMBOL: &str = "د.ج.";
+    /// `3`
+    pub const FRAC_DIGITS: i64 = 3;
+    /// `"DZD "`
+    pub const INT_CURR_SYMBOL: &str = "DZD ";
+    /// `3`
+    pub const INT_FRAC_DIGITS: i64 = 3;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const N_SIGN_POSN: i64 = 2;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("213")`
+    pub const INT_PREFIX: Option<&str> = Some("213");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["baz", "bir", "iki", "üçü", "dör", "beş", "alt"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_7;
+    /// `&["Yan", "Fev", "Mar", "Apr", "May", "İyn", "İyl", "Avq", "Sen", "Okt", "Noy", "Dek"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_10;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["bazar günü", "birinci gün", "ikinci gün", "üçüncü gün", "dördüncü gün", "beşinci gün", "altıncı gün"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_8;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%A, %d %B %Y %T"`
+    pub const D_T_FMT: &str = "%A, %d %B %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(1)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(1);
+    /// `&["yanvar", "fevral", "mart", "aprel", "may", "iyun", "iyul", "avqust", "sentyabr", "oktyabr", "noyabr", "dekabr"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_17;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
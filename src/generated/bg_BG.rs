@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("BGR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BGR");
+    /// `Some("BG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BG");
+    /// `Some("BG")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BG");
+    /// `Some("954")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("954");
+    /// `Some("България")`
+    pub const COUNTRY_NAME: Option<&str> = Some("България");
+    /// `Some(100)`
+    pub const COUNTRY_NUM: Option<i64> = Some(100);
+    /// `Some("BG")`
+    pub const COUNTRY_POST: Option<&str> = Some("BG");
+    /// `Some("bg")`
+    pub const LANG_AB: Option<&str> = Some("bg");
+    /// `Some("bul")`
+    pub const LANG_LIB: Option<&str> = Some("bul");
+    /// `Some("български")`
+    pub const LANG_NAME: Option<&str> = Some("български");
+    /// `Some("bul")`
+    pub const LANG_TERM: Option<&str> = Some("bul");
+    /// `Some("%f%N%a%N%d%N%b%N%sN%h, %e, %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%sN%h, %e, %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("develop@linux.zonebg.com")`
+    pub const ADDRESS: Option<&str> = Some("develop@linux.zonebg.com");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Delyan Toshev")`
+    pub const CONTACT: Option<&str> = Some("Delyan Toshev");
+    /// `Some("2002-09-10")`
+    pub const DATE: Option<&str> = Some("2002-09-10");
+    /// `Some("delyant@yahoo.com")`
+    pub const EMAIL: Option<&str> = Some("delyant@yahoo.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bulgarian")`
+    pub const LANGUAGE: Option<&str> = Some("Bulgarian");
+    /// `Some("2.0.1")`
+    pub const REVISION: Option<&str> = Some("2.0.1");
+    /// `Some("Linux Society Bulgaria")`
+    pub const SOURCE: Option<&str> = Some("Linux Society Bulgaria");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Bulgaria")`
+    pub const TERRITORY: Option<&str> = Some("Bulgaria");
+    /// `Some("Bulgarian locale for Bulgaria")`
+    pub const TITLE: Option<&str> = Some("Bulgarian locale for Bulgaria");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNkKНн]"`
+    pub const NOEXPR: &str = "^[-0nNkKНн]";
+    /// `Some("не")`
+    pub const NOSTR: Option<&str> = Some("не");
+    /// `"^[+1yYdDoOДд]"`
+    pub const YESEXPR: &str = "^[+1yYdDoOДд]";
+    /// `Some("да")`
+    pub const YESSTR: Option<&str> = Some("да");
+}
+pub mod LC_MONETARY {
+    /// `"лв."`
+    pub const CURRENCY_SYMBOL: &str = "лв.";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"BGN "`
+    pub const INT_CURR_SYMBOL: &str = "BGN ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("359")`
+    pub const INT_PREFIX: Option<&str> = Some("359");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("(0%a) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(0%a) %l");
+    /// `Some("(+%c %a) %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("(+%c %a) %l");
+}
+pub mod LC_TIME {
+    /// `&["нд", "пн", "вт", "ср", "чт", "пт", "сб"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_16;
+    /// `&["яну", "фев", "мар", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"]`
+    pub const ABMON: &[&str] = &["яну", "фев", "мар", "апр", "май", "юни", "юли", "авг", "сеп", "окт", "ное", "дек"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["неделя", "понеделник", "вторник", "сряда", "четвъртък", "петък", "събота"]`
+    pub const DAY: &[&str] = &["неделя", "понеделник", "вторник", "сряда", "четвъртък", "петък", "събота"];
+    /// `"%e.%m.%Y"`
+    pub const D_FMT: &str = "%e.%m.%Y";
+    /// `"%e.%m.%Y (%a) %k:%M:%S %Z"`
+    pub const D_T_FMT: &str = "%e.%m.%Y (%a) %k:%M:%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"]`
+    pub const MON: &[&str] = &["януари", "февруари", "март", "април", "май", "юни", "юли", "август", "септември", "октомври", "ноември", "декември"];
+    /// `"%k:%M:%S"`
+    pub const T_FMT: &str = "%k:%M:%S";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%x (%a) %X %Z"`
+        pub const D_T_FMT: &str = "%x (%a) %X %Z";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
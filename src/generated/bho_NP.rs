@@ -0,0 +1,74 @@
+pub mod LC_ADDRESS {
+    /// `Some("NPL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NPL");
+    /// `Some("NP")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NP");
+    /// `Some("NEP")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NEP");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("न\u{947}पाल")`
+    pub const COUNTRY_NAME: Option<&str> = Some("न\u{947}पाल");
+    /// `Some(524)`
+    pub const COUNTRY_NUM: Option<i64> = Some(524);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("bho")`
+    pub const LANG_LIB: Option<&str> = Some("bho");
+    /// `Some("भोजप\u{941}री")`
+    pub const LANG_NAME: Option<&str> = Some("भोजप\u{941}री");
+    /// `Some("bho")`
+    pub const LANG_TERM: Option<&str> = Some("bho");
+    /// `Some("%f%N%h%s%N%T")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%h%s%N%T");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-07-24")`
+    pub const DATE: Option<&str> = Some("2017-07-24");
+    /// `Some("akhilesh.k@samusng.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samusng.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bhojpuri")`
+    pub const LANGUAGE: Option<&str> = Some("Bhojpuri");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nepal")`
+    pub const TERRITORY: Option<&str> = Some("Nepal");
+    /// `Some("Bhojpuri language locale for Nepal")`
+    pub const TITLE: Option<&str> = Some("Bhojpuri language locale for Nepal");
+}
+pub use super::bho_IN::LC_MESSAGES;
+pub use super::ne_NP::LC_MONETARY;
+pub use super::ne_NP::LC_NUMERIC;
+pub use super::ne_NP::LC_TELEPHONE;
+pub use super::bho_IN::LC_TIME;
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("VUT")`
+    pub const COUNTRY_AB3: Option<&str> = Some("VUT");
+    /// `Some("VU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("VU");
+    /// `Some("VU")`
+    pub const COUNTRY_CAR: Option<&str> = Some("VU");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Vanuatu")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Vanuatu");
+    /// `Some(548)`
+    pub const COUNTRY_NUM: Option<i64> = Some(548);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("bi")`
+    pub const LANG_AB: Option<&str> = Some("bi");
+    /// `Some("bis")`
+    pub const LANG_LIB: Option<&str> = Some("bis");
+    /// `Some("Bislama")`
+    pub const LANG_NAME: Option<&str> = Some("Bislama");
+    /// `Some("bis")`
+    pub const LANG_TERM: Option<&str> = Some("bis");
+    /// `Some("%a%b%s%c")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%b%s%c");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-07-13")`
+    pub const DATE: Option<&str> = Some("2017-07-13");
+    /// `Some("akhilesh.k@samsung.com & maninder1.s@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com & maninder1.s@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bislama")`
+    pub const LANGUAGE: Option<&str> = Some("Bislama");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Vanuatu")`
+    pub const TERRITORY: Option<&str> = Some("Vanuatu");
+    /// `Some("Bislama language locale for Vanuatu")`
+    pub const TITLE: Option<&str> = Some("Bislama language locale for Vanuatu");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("No")`
+    pub const NOSTR: Option<&str> = Some("No");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("Yes")`
+    pub const YESSTR: Option<&str> = Some("Yes");
+}
+pub mod LC_MONETARY {
+    /// `"VT"`
+    pub const CURRENCY_SYMBOL: &str = "VT";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"VUV "`
+    pub const INT_CURR_SYMBOL: &str = "VUV ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("678")`
+    pub const INT_PREFIX: Option<&str> = Some("678");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["San", "Man", "Tus", "Wen", "Tas", "Fra", "Sar"]`
+    pub const ABDAY: &[&str] = &["San", "Man", "Tus", "Wen", "Tas", "Fra", "Sar"];
+    /// `&["Jan", "Feb", "Maj", "Epr", "Mei", "Jun", "Jul", "Ogs", "Sep", "Okt", "Nov", "Dis"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Maj", "Epr", "Mei", "Jun", "Jul", "Ogs", "Sep", "Okt", "Nov", "Dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sande", "Mande", "Tusde", "Wenesde", "Tosde", "Fraede", "Sarere"]`
+    pub const DAY: &[&str] = &["Sande", "Mande", "Tusde", "Wenesde", "Tosde", "Fraede", "Sarere"];
+    /// `"%A %d %b %Y"`
+    pub const D_FMT: &str = "%A %d %b %Y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jenuware", "Febwari", "Maj", "Epril", "Mei", "Jun", "Julae", "Ogis", "Septemba", "Oktoba", "Novemba", "Disemba"]`
+    pub const MON: &[&str] = &["Jenuware", "Febwari", "Maj", "Epril", "Mei", "Jun", "Julae", "Ogis", "Septemba", "Oktoba", "Novemba", "Disemba"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("BGD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BGD");
+    /// `Some("BD")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BD");
+    /// `Some("BD")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BD");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ব\u{9be}ংল\u{9be}দেশ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ব\u{9be}ংল\u{9be}দেশ");
+    /// `Some(50)`
+    pub const COUNTRY_NUM: Option<i64> = Some(50);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("bn")`
+    pub const LANG_AB: Option<&str> = Some("bn");
+    /// `Some("ben")`
+    pub const LANG_LIB: Option<&str> = Some("ben");
+    /// `Some("ব\u{9be}ংল\u{9be}")`
+    pub const LANG_NAME: Option<&str> = Some("ব\u{9be}ংল\u{9be}");
+    /// `Some("ben")`
+    pub const LANG_TERM: Option<&str> = Some("ben");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Dhaka, Bangladesh")`
+    pub const ADDRESS: Option<&str> = Some("Dhaka, Bangladesh");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_NAME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_3);
+    /// `Some("Taneem Ahmed, Jamil Ahmed")`
+    pub const CONTACT: Option<&str> = Some("Taneem Ahmed, Jamil Ahmed");
+    /// `Some("2007-01-10")`
+    pub const DATE: Option<&str> = Some("2007-01-10");
+    /// `Some("taneem@bengalinux.org, jamil@bengalinux.org")`
+    pub const EMAIL: Option<&str> = Some("taneem@bengalinux.org, jamil@bengalinux.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bangla")`
+    pub const LANGUAGE: Option<&str> = Some("Bangla");
+    /// `Some("0.5")`
+    pub const REVISION: Option<&str> = Some("0.5");
+    /// `Some("Ankur Group, http://www.ankurbangla.org, http://www.bengalinux.org")`
+    pub const SOURCE: Option<&str> = Some("Ankur Group, http://www.ankurbangla.org, http://www.bengalinux.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Bangladesh")`
+    pub const TERRITORY: Option<&str> = Some("Bangladesh");
+    /// `Some("Bangla language locale for Bangladesh")`
+    pub const TITLE: Option<&str> = Some("Bangla language locale for Bangladesh");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNন]"`
+    pub const NOEXPR: &str = "^[-0nNন]";
+    /// `Some("ন\u{9be}")`
+    pub const NOSTR: Option<&str> = Some("ন\u{9be}");
+    /// `"^[+1yYহ]"`
+    pub const YESEXPR: &str = "^[+1yYহ]";
+    /// `Some("হ\u{9cd}য\u{9be}\u{981}")`
+    pub const YESSTR: Option<&str> = Some("হ\u{9cd}য\u{9be}\u{981}");
+}
+pub mod LC_MONETARY {
+    /// `"৳"`
+    pub const CURRENCY_SYMBOL: &str = "৳";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"BDT "`
+    pub const INT_CURR_SYMBOL: &str = "BDT ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_1;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("880")`
+    pub const INT_PREFIX: Option<&str> = Some("880");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["রবি", "সোম", "মঙ\u{9cd}গল", "ব\u{9c1}ধ", "ব\u{9c3}হঃ", "শ\u{9c1}ক\u{9cd}র", "শনি"]`
+    pub const ABDAY: &[&str] = &["রবি", "সোম", "মঙ\u{9cd}গল", "ব\u{9c1}ধ", "ব\u{9c3}হঃ", "শ\u{9c1}ক\u{9cd}র", "শনি"];
+    /// `&["জ\u{9be}ন\u{9c1}", "ফেব", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_29;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["প\u{9c2}র\u{9cd}ব\u{9be}হ\u{9cd}ণ", "অপর\u{9be}হ\u{9cd}ণ"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_12;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["রবিব\u{9be}র", "সোমব\u{9be}র", "মঙ\u{9cd}গলব\u{9be}র", "ব\u{9c1}ধব\u{9be}র", "ব\u{9c3}হস\u{9cd}পতিব\u{9be}র", "শ\u{9c1}ক\u{9cd}রব\u{9be}র", "শনিব\u{9be}র"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_21;
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(6)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(6);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["জ\u{9be}ন\u{9c1}য\u{9bc}\u{9be}রী", "ফেব\u{9cd}র\u{9c1}য\u{9bc}\u{9be}রী", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগস\u{9cd}ট", "সেপ\u{9cd}টেম\u{9cd}বর", "অক\u{9cd}টোবর", "নভেম\u{9cd}বর", "ডিসেম\u{9cd}বর"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_26;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
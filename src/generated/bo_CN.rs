@@ -0,0 +1,137 @@
+pub mod LC_ADDRESS {
+    /// `Some("CHN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CHN");
+    /// `Some("CN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CN");
+    /// `Some("CHN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CHN");
+    /// `Some("7")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("7");
+    /// `Some("ར\u{f92}\u{fb1}་ནག")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ར\u{f92}\u{fb1}་ནག");
+    /// `Some(156)`
+    pub const COUNTRY_NUM: Option<i64> = Some(156);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("bo")`
+    pub const LANG_AB: Option<&str> = Some("bo");
+    /// `Some("tib")`
+    pub const LANG_LIB: Option<&str> = Some("tib");
+    /// `Some("བ\u{f7c}ད་ས\u{f90}ད་")`
+    pub const LANG_NAME: Option<&str> = Some("བ\u{f7c}ད་ས\u{f90}ད་");
+    /// `Some("bod")`
+    pub const LANG_TERM: Option<&str> = Some("bod");
+    /// `Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_7);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2007-11-06")`
+    pub const DATE: Option<&str> = Some("2007-11-06");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tibetan")`
+    pub const LANGUAGE: Option<&str> = Some("Tibetan");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("China")`
+    pub const TERRITORY: Option<&str> = Some("China");
+    /// `Some("Tibetan language locale for P.R. of China")`
+    pub const TITLE: Option<&str> = Some("Tibetan language locale for P.R. of China");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNམ]"`
+    pub const NOEXPR: &str = "^[-0nNམ]";
+    /// `Some("མ\u{f72}ན།")`
+    pub const NOSTR: Option<&str> = Some("མ\u{f72}ན།");
+    /// `"^[+1yYཨ]"`
+    pub const YESEXPR: &str = "^[+1yYཨ]";
+    /// `Some("ཡ\u{f72}ན།")`
+    pub const YESSTR: Option<&str> = Some("ཡ\u{f72}ན།");
+}
+pub use super::zh_CN::LC_MONETARY;
+pub use super::zh_CN::LC_NUMERIC;
+pub use super::zh_CN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ཉ\u{f72}་", "ཟ\u{fb3}་", "མ\u{f72}ར་", "ལ\u{fb7}ག་", "པ\u{f74}ར་", "སངས་", "ས\u{fa4}\u{f7a}ན་"]`
+    pub const ABDAY: &[&str] = &["ཉ\u{f72}་", "ཟ\u{fb3}་", "མ\u{f72}ར་", "ལ\u{fb7}ག་", "པ\u{f74}ར་", "སངས་", "ས\u{fa4}\u{f7a}ན་"];
+    /// `&["ཟ\u{fb3}་༡", "ཟ\u{fb3}་༢", "ཟ\u{fb3}་༣", "ཟ\u{fb3}་༤", "ཟ\u{fb3}་༥", "ཟ\u{fb3}་༦", "ཟ\u{fb3}་༧", "ཟ\u{fb3}་༨", "ཟ\u{fb3}་༩", "ཟ\u{fb3}་༡༠", "ཟ\u{fb3}་༡༡", "ཟ\u{fb3}་༡༢"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_30;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ངས་ཆ", "ཕ\u{fb1}\u{f72}་ཆ"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_14;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["གཟའ་ཉ\u{f72}་མ་", "གཟའ་ཟ\u{fb3}་བ་", "གཟའ་མ\u{f72}ག་དམར་", "གཟའ་ལ\u{fb7}ག་ཕ་", "གཟའ་པ\u{f74}ར་བ\u{f74}་", "གཟའ་པ་སངས་", "གཟའ་ས\u{fa4}\u{f7a}ན་ཕ་"]`
+    pub const DAY: &[&str] = &["གཟའ་ཉ\u{f72}་མ་", "གཟའ་ཟ\u{fb3}་བ་", "གཟའ་མ\u{f72}ག་དམར་", "གཟའ་ལ\u{fb7}ག་ཕ་", "གཟའ་པ\u{f74}ར་བ\u{f74}་", "གཟའ་པ་སངས་", "གཟའ་ས\u{fa4}\u{f7a}ན་ཕ་"];
+    /// `"པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%d"`
+    pub const D_FMT: &str = "པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%d";
+    /// `"པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%dཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S"`
+    pub const D_T_FMT: &str = "པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%dཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ཟ\u{fb3}་བ་དང་པ་", "ཟ\u{fb3}་བ་གཉ\u{f72}ས་པ་", "ཟ\u{fb3}་བ་གས\u{f74}མ་པ་", "ཟ\u{fb3}་བ་བཞ\u{f72}་པ་", "ཟ\u{fb3}་བ་ལ\u{f94}་ཕ་", "ཟ\u{fb3}་བ་ད\u{fb2}\u{f74}ག་པ་", "ཟ\u{fb3}་བ་བད\u{f74}ནཔ་", "ཟ\u{fb3}་བ་བར\u{f92}\u{fb1}ད་པ་", "ཟ\u{fb3}་བ་དག\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཅ\u{f72}ག་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཉ\u{f72}ས་པ་"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_28;
+    /// `"ཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S"`
+    pub const T_FMT: &str = "ཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S";
+    /// `"ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p"`
+    pub const T_FMT_AMPM: &str = "ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
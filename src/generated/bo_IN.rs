@@ -0,0 +1,73 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ར\u{f92}\u{fb1}་གར་")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ར\u{f92}\u{fb1}་གར་");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("bo")`
+    pub const LANG_AB: Option<&str> = Some("bo");
+    /// `Some("tib")`
+    pub const LANG_LIB: Option<&str> = Some("tib");
+    /// `Some("བ\u{f7c}ད་ས\u{f90}ད་")`
+    pub const LANG_NAME: Option<&str> = Some("བ\u{f7c}ད་ས\u{f90}ད་");
+    /// `Some("bod")`
+    pub const LANG_TERM: Option<&str> = Some("bod");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_7);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2007-11-06")`
+    pub const DATE: Option<&str> = Some("2007-11-06");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tibetan")`
+    pub const LANGUAGE: Option<&str> = Some("Tibetan");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Tibetan language locale for India")`
+    pub const TITLE: Option<&str> = Some("Tibetan language locale for India");
+}
+pub use super::bo_CN::LC_MESSAGES;
+pub use super::hi_IN::LC_MONETARY;
+pub use super::bo_CN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub use super::bo_CN::LC_TIME;
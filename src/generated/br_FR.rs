@@ -0,0 +1,137 @@
+pub mod LC_ADDRESS {
+    /// `Some("FRA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FRA");
+    /// `Some("FR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FR");
+    /// `Some("F")`
+    pub const COUNTRY_CAR: Option<&str> = Some("F");
+    /// `Some("979-10")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("979-10");
+    /// `Some("Frañs")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Frañs");
+    /// `Some(250)`
+    pub const COUNTRY_NUM: Option<i64> = Some(250);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("br")`
+    pub const LANG_AB: Option<&str> = Some("br");
+    /// `Some("bre")`
+    pub const LANG_LIB: Option<&str> = Some("bre");
+    /// `Some("brezhoneg")`
+    pub const LANG_NAME: Option<&str> = Some("brezhoneg");
+    /// `Some("bre")`
+    pub const LANG_TERM: Option<&str> = Some("bre");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Thierry Vignaud")`
+    pub const CONTACT: Option<&str> = Some("Thierry Vignaud");
+    /// `Some("2001-01-28")`
+    pub const DATE: Option<&str> = Some("2001-01-28");
+    /// `Some("thierry.vignaud@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("thierry.vignaud@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Breton")`
+    pub const LANGUAGE: Option<&str> = Some("Breton");
+    /// `Some("0.54")`
+    pub const REVISION: Option<&str> = Some("0.54");
+    /// `Some("Denise.Peden@enst-bretagne.fr (Denise Derrien-Peden)")`
+    pub const SOURCE: Option<&str> = Some("Denise.Peden@enst-bretagne.fr (Denise Derrien-Peden)");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("France")`
+    pub const TERRITORY: Option<&str> = Some("France");
+    /// `Some("Breton language locale for France")`
+    pub const TITLE: Option<&str> = Some("Breton language locale for France");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0kKnN]"`
+    pub const NOEXPR: &str = "^[-0kKnN]";
+    /// `Some("ket")`
+    pub const NOSTR: Option<&str> = Some("ket");
+    /// `"^[+1oOyY]"`
+    pub const YESEXPR: &str = "^[+1oOyY]";
+    /// `Some("ya")`
+    pub const YESSTR: Option<&str> = Some("ya");
+}
+pub use super::fr_FR::LC_MONETARY;
+pub use super::fr_FR::LC_NUMERIC;
+pub use super::fr_FR::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["sul", "lun", "meu", "mer", "yao", "gwe", "sad"]`
+    pub const ABDAY: &[&str] = &["sul", "lun", "meu", "mer", "yao", "gwe", "sad"];
+    /// `&["Gen ", "Cʼhw", "Meu ", "Ebr ", "Mae ", "Mezh", "Gou ", "Eos ", "Gwe ", "Her ", "Du  ", "Ker "]`
+    pub const ABMON: &[&str] = &["Gen ", "Cʼhw", "Meu ", "Ebr ", "Mae ", "Mezh", "Gou ", "Eos ", "Gwe ", "Her ", "Du  ", "Ker "];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&[" ", " "]`
+    pub const AM_PM: &[&str] = &[" ", " "];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sul", "lun", "meurzh", "mercʼher", "yaou", "gwener", "sadorn"]`
+    pub const DAY: &[&str] = &["sul", "lun", "meurzh", "mercʼher", "yaou", "gwener", "sadorn"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"Dʼar %A %d a viz %B %Y"`
+    pub const D_T_FMT: &str = "Dʼar %A %d a viz %B %Y";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"]`
+    pub const MON: &[&str] = &["Genver", "Cʼhwevrer", "Meurzh", "Ebrel", "Mae", "Mezheven", "Gouere", "Eost", "Gwengolo", "Here", "Du", "Kerzu"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%Ie%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%Ie%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%Ie%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%Ie%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,49 @@
+pub use super::br_FR::LC_ADDRESS;
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `None`
+    pub const CONTACT: Option<&str> = None;
+    /// `Some("2002-02-28")`
+    pub const DATE: Option<&str> = Some("2002-02-28");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Breton")`
+    pub const LANGUAGE: Option<&str> = Some("Breton");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("France")`
+    pub const TERRITORY: Option<&str> = Some("France");
+    /// `Some("Breton locale for France with Euro")`
+    pub const TITLE: Option<&str> = Some("Breton locale for France with Euro");
+}
+pub use super::br_FR::LC_MESSAGES;
+pub use super::br_FR::LC_MONETARY;
+pub use super::br_FR::LC_NUMERIC;
+pub use super::br_FR::LC_TELEPHONE;
+pub use super::br_FR::LC_TIME;
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("brx")`
+    pub const LANG_LIB: Option<&str> = Some("brx");
+    /// `Some("बड\u{93c}ो")`
+    pub const LANG_NAME: Option<&str> = Some("बड\u{93c}ो");
+    /// `Some("brx")`
+    pub const LANG_TERM: Option<&str> = Some("brx");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ")`
+    pub const ADDRESS: Option<&str> = Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2011-10-17")`
+    pub const DATE: Option<&str> = Some("2011-10-17");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bodo")`
+    pub const LANGUAGE: Option<&str> = Some("Bodo");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Bodo language locale for India")`
+    pub const TITLE: Option<&str> = Some("Bodo language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("नही\u{902}")`
+    pub const NOSTR: Option<&str> = Some("नही\u{902}");
+    /// `"^[+1yYह]"`
+    pub const YESEXPR: &str = "^[+1yYह]";
+    /// `Some("हा\u{901}")`
+    pub const YESSTR: Option<&str> = Some("हा\u{901}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["रबि", "सम", "म\u{902}गल", "ब\u{941}द", "बिसथि", "स\u{941}ख\u{941}र", "स\u{941}नि"]`
+    pub const ABDAY: &[&str] = &["रबि", "सम", "म\u{902}गल", "ब\u{941}द", "बिसथि", "स\u{941}ख\u{941}र", "स\u{941}नि"];
+    /// `&["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}स", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाइ", "आगस\u{94d}थ", "स\u{947}बथ\u{947}ज\u{94d}ब\u{93c}र", "अखथबर", "नब\u{947}ज\u{94d}ब\u{93c}र", "दिस\u{947}ज\u{94d}ब\u{93c}र"]`
+    pub const ABMON: &[&str] = &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}स", "एप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाइ", "आगस\u{94d}थ", "स\u{947}बथ\u{947}ज\u{94d}ब\u{93c}र", "अखथबर", "नब\u{947}ज\u{94d}ब\u{93c}र", "दिस\u{947}ज\u{94d}ब\u{93c}र"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["फ\u{941}\u{902}.", "ब\u{947}लास\u{947}."]`
+    pub const AM_PM: &[&str] = &["फ\u{941}\u{902}.", "ब\u{947}लास\u{947}."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["रबिबार", "सोबार", "म\u{902}गलबार", "ब\u{941}दबार", "बिसथिबार", "स\u{941}ख\u{941}रबार", "स\u{941}निबार"]`
+    pub const DAY: &[&str] = &["रबिबार", "सोबार", "म\u{902}गलबार", "ब\u{941}दबार", "बिसथिबार", "स\u{941}ख\u{941}रबार", "स\u{941}निबार"];
+    /// `"%-m/%-d/%y"`
+    pub const D_FMT: &str = "%-m/%-d/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}स", "एफ\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाइ", "आगस\u{94d}थ", "स\u{947}बथ\u{947}ज\u{94d}ब\u{93c}र", "अखथबर", "नब\u{947}ज\u{94d}ब\u{93c}र", "दिस\u{947}ज\u{94d}ब\u{93c}र"]`
+    pub const MON: &[&str] = &["जान\u{941}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}स", "एफ\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाइ", "आगस\u{94d}थ", "स\u{947}बथ\u{947}ज\u{94d}ब\u{93c}र", "अखथबर", "नब\u{947}ज\u{94d}ब\u{93c}र", "दिस\u{947}ज\u{94d}ब\u{93c}र"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
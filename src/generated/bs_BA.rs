@@ -0,0 +1,196 @@
+pub mod LC_ADDRESS {
+    /// `Some("BIH")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BIH");
+    /// `Some("BA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BA");
+    /// `Some("BIH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BIH");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Bosna i Hercegovina")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Bosna i Hercegovina");
+    /// `Some(70)`
+    pub const COUNTRY_NUM: Option<i64> = Some(70);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("bs")`
+    pub const LANG_AB: Option<&str> = Some("bs");
+    /// `Some("bos")`
+    pub const LANG_LIB: Option<&str> = Some("bos");
+    /// `Some("bosanski")`
+    pub const LANG_NAME: Option<&str> = Some("bosanski");
+    /// `Some("bos")`
+    pub const LANG_TERM: Option<&str> = Some("bos");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Tomislav Vujec")`
+    pub const CONTACT: Option<&str> = Some("Tomislav Vujec");
+    /// `Some("2004-01-09")`
+    pub const DATE: Option<&str> = Some("2004-01-09");
+    /// `Some("tvujec@carnet.hr")`
+    pub const EMAIL: Option<&str> = Some("tvujec@carnet.hr");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Bosnian")`
+    pub const LANGUAGE: Option<&str> = Some("Bosnian");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("adapted from Croatian locale")`
+    pub const SOURCE: Option<&str> = Some("adapted from Croatian locale");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Bosnia & Herzegovina")`
+    pub const TERRITORY: Option<&str> = Some("Bosnia & Herzegovina");
+    /// `Some("Bosnian language locale for Bosnia and Herzegowina")`
+    pub const TITLE: Option<&str> = Some("Bosnian language locale for Bosnia and Herzegowina");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ne")`
+    pub const NOSTR: Option<&str> = Some("ne");
+    /// `"^[+1dDyY]"`
+    pub const YESEXPR: &str = "^[+1dDyY]";
+    /// `Some("da")`
+    pub const YESSTR: Option<&str> = Some("da");
+}
+pub mod LC_MONETARY {
+    /// `"KM"`
+    pub const CURRENCY_SYMBOL: &str = "KM";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"BAM "`
+    pub const INT_CURR_SYMBOL: &str = "BAM ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("387")`
+    pub const INT_PREFIX: Option<&str> = Some("387");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Ned", "Pon", "Uto", "Sri", "Čet", "Pet", "Sub"]`
+    pub const ABDAY: &[&str] = &["Ned", "Pon", "Uto", "Sri", "Čet", "Pet", "Sub"];
+    /// `&["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dec"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mar", "Apr", "Maj", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dec"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Nedjelja", "Ponedjeljak", "Utorak", "Srijeda", "Četvrtak", "Petak", "Subota"]`
+    pub const DAY: &[&str] = &["Nedjelja", "Ponedjeljak", "Utorak", "Srijeda", "Četvrtak", "Petak", "Subota"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januar", "Februar", "Mart", "April", "Maj", "Juni", "Juli", "August", "Septembar", "Oktobar", "Novembar", "Decembar"]`
+    pub const MON: &[&str] = &["Januar", "Februar", "Mart", "April", "Maj", "Juni", "Juli", "August", "Septembar", "Oktobar", "Novembar", "Decembar"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,198 @@
+pub mod LC_ADDRESS {
+    /// `Some("ESP")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ESP");
+    /// `Some("ES")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ES");
+    /// `Some("E")`
+    pub const COUNTRY_CAR: Option<&str> = Some("E");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Espanya")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Espanya");
+    /// `Some(724)`
+    pub const COUNTRY_NUM: Option<i64> = Some(724);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ca")`
+    pub const LANG_AB: Option<&str> = Some("ca");
+    /// `Some("cat")`
+    pub const LANG_LIB: Option<&str> = Some("cat");
+    /// `Some("català")`
+    pub const LANG_NAME: Option<&str> = Some("català");
+    /// `Some("cat")`
+    pub const LANG_TERM: Option<&str> = Some("cat");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Catalan")`
+    pub const LANGUAGE: Option<&str> = Some("Catalan");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Spain")`
+    pub const TERRITORY: Option<&str> = Some("Spain");
+    /// `Some("Catalan locale for Spain")`
+    pub const TITLE: Option<&str> = Some("Catalan locale for Spain");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("no")`
+    pub const NOSTR: Option<&str> = Some("no");
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `Some("sí")`
+    pub const YESSTR: Option<&str> = Some("sí");
+}
+pub mod LC_MONETARY {
+    /// `"€"`
+    pub const CURRENCY_SYMBOL: &str = "€";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"EUR "`
+    pub const INT_CURR_SYMBOL: &str = "EUR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("34")`
+    pub const INT_PREFIX: Option<&str> = Some("34");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dg.", "dl.", "dt.", "dc.", "dj.", "dv.", "ds."]`
+    pub const ABDAY: &[&str] = &["dg.", "dl.", "dt.", "dc.", "dj.", "dv.", "ds."];
+    /// `&["de gen.", "de febr.", "de març", "d’abr.", "de maig", "de juny", "de jul.", "d’ag.", "de set.", "d’oct.", "de nov.", "de des."]`
+    pub const ABMON: &[&str] = &["de gen.", "de febr.", "de març", "d’abr.", "de maig", "de juny", "de jul.", "d’ag.", "de set.", "d’oct.", "de nov.", "de des."];
+    /// `Some(&["gen.", "febr.", "març", "abr.", "maig", "juny", "jul.", "ag.", "set.", "oct.", "nov.", "des."])`
+    pub const AB_ALT_MON: Option<&[&str]> = Some(&["gen.", "febr.", "març", "abr.", "maig", "juny", "jul.", "ag.", "set.", "oct.", "nov.", "des."]);
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["gener", "febrer", "març", "abril", "maig", "juny", "juliol", "agost", "setembre", "octubre", "novembre", "desembre"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["gener", "febrer", "març", "abril", "maig", "juny", "juliol", "agost", "setembre", "octubre", "novembre", "desembre"]);
+    /// `&["a. m.", "p. m."]`
+    pub const AM_PM: &[&str] = &["a. m.", "p. m."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["diumenge", "dilluns", "dimarts", "dimecres", "dijous", "divendres", "dissabte"]`
+    pub const DAY: &[&str] = &["diumenge", "dilluns", "dimarts", "dimecres", "dijous", "divendres", "dissabte"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A, %-d %B de %Y, %T %Z"`
+    pub const D_T_FMT: &str = "%A, %-d %B de %Y, %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["de gener", "de febrer", "de març", "d’abril", "de maig", "de juny", "de juliol", "d’agost", "de setembre", "d’octubre", "de novembre", "de desembre"]`
+    pub const MON: &[&str] = &["de gener", "de febrer", "de març", "d’abril", "de maig", "de juny", "de juliol", "d’agost", "de setembre", "d’octubre", "de novembre", "de desembre"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,49 @@
+pub use super::ca_ES::LC_ADDRESS;
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("Jordi Mallach")`
+    pub const CONTACT: Option<&str> = Some("Jordi Mallach");
+    /// `Some("2006-04-06")`
+    pub const DATE: Option<&str> = Some("2006-04-06");
+    /// `Some("jordi@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("jordi@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Catalan")`
+    pub const LANGUAGE: Option<&str> = Some("Catalan");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Spain")`
+    pub const TERRITORY: Option<&str> = Some("Spain");
+    /// `Some("Valencian (southern Catalan) locale for Spain with Euro")`
+    pub const TITLE: Option<&str> = Some("Valencian (southern Catalan) locale for Spain with Euro");
+}
+pub use super::ca_ES::LC_MESSAGES;
+pub use super::ca_ES::LC_MONETARY;
+pub use super::ca_ES::LC_NUMERIC;
+pub use super::ca_ES::LC_TELEPHONE;
+pub use super::ca_ES::LC_TIME;
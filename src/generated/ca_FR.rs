@@ -0,0 +1,74 @@
+pub mod LC_ADDRESS {
+    /// `Some("FRA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FRA");
+    /// `Some("FR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FR");
+    /// `Some("F")`
+    pub const COUNTRY_CAR: Option<&str> = Some("F");
+    /// `Some("979-10")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("979-10");
+    /// `Some("França")`
+    pub const COUNTRY_NAME: Option<&str> = Some("França");
+    /// `Some(250)`
+    pub const COUNTRY_NUM: Option<i64> = Some(250);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ca")`
+    pub const LANG_AB: Option<&str> = Some("ca");
+    /// `Some("cat")`
+    pub const LANG_LIB: Option<&str> = Some("cat");
+    /// `Some("català")`
+    pub const LANG_NAME: Option<&str> = Some("català");
+    /// `Some("cat")`
+    pub const LANG_TERM: Option<&str> = Some("cat");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-01-16")`
+    pub const DATE: Option<&str> = Some("2006-01-16");
+    /// `Some("")`
+    pub const EMAIL: Option<&str> = Some("");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Catalan")`
+    pub const LANGUAGE: Option<&str> = Some("Catalan");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Robert Millan")`
+    pub const SOURCE: Option<&str> = Some("Robert Millan");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("France")`
+    pub const TERRITORY: Option<&str> = Some("France");
+    /// `Some("Catalan locale for France ")`
+    pub const TITLE: Option<&str> = Some("Catalan locale for France ");
+}
+pub use super::ca_ES::LC_MESSAGES;
+pub use super::ca_ES::LC_MONETARY;
+pub use super::ca_ES::LC_NUMERIC;
+pub use super::fr_FR::LC_TELEPHONE;
+pub use super::ca_ES::LC_TIME;
@@ -0,0 +1,190 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Росси")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Росси");
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ce")`
+    pub const LANG_AB: Option<&str> = Some("ce");
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("нохчийн")`
+    pub const LANG_NAME: Option<&str> = Some("нохчийн");
+    /// `Some("che")`
+    pub const LANG_TERM: Option<&str> = Some("che");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("364024, ChR, Grozny, pr-kt of M. Esambayev, 13")`
+    pub const ADDRESS: Option<&str> = Some("364024, ChR, Grozny, pr-kt of M. Esambayev, 13");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `None`
+    pub const CONTACT: Option<&str> = None;
+    /// `Some("2014-08-25")`
+    pub const DATE: Option<&str> = Some("2014-08-25");
+    /// `None`
+    pub const EMAIL: Option<&str> = None;
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Chechen")`
+    pub const LANGUAGE: Option<&str> = Some("Chechen");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("ANCHR")`
+    pub const SOURCE: Option<&str> = Some("ANCHR");
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Russia")`
+    pub const TERRITORY: Option<&str> = Some("Russia");
+    /// `Some("Chechen locale for RUSSIAN FEDERATION")`
+    pub const TITLE: Option<&str> = Some("Chechen locale for RUSSIAN FEDERATION");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNМм]"`
+    pub const NOEXPR: &str = "^[-0nNМм]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yYДд]"`
+    pub const YESEXPR: &str = "^[+1yYДд]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub mod LC_MONETARY {
+    /// `"₽"`
+    pub const CURRENCY_SYMBOL: &str = "₽";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"RUB "`
+    pub const INT_CURR_SYMBOL: &str = "RUB ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::ru_RU::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("7")`
+    pub const INT_PREFIX: Option<&str> = Some("7");
+    /// `Some("0~10")`
+    pub const INT_SELECT: Option<&str> = Some("0~10");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["КӀ", "Ор", "Ши", "Кх", "Еа", "ПӀ", "Шо"]`
+    pub const ABDAY: &[&str] = &["КӀ", "Ор", "Ши", "Кх", "Еа", "ПӀ", "Шо"];
+    /// `&["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_22;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["КӀиранан де", "Оршотан де", "Шинарин де", "Кхаарин де", "Еарин де", "ПӀераскан де", "Шот де"]`
+    pub const DAY: &[&str] = &["КӀиранан де", "Оршотан де", "Шинарин де", "Кхаарин де", "Еарин де", "ПӀераскан де", "Шот де"];
+    /// `"%Y.%d.%m"`
+    pub const D_FMT: &str = "%Y.%d.%m";
+    /// `"%Y %d %b %a %T"`
+    pub const D_T_FMT: &str = "%Y %d %b %a %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Январь", "Февраль", "Март", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"]`
+    pub const MON: &[&str] = &["Январь", "Февраль", "Март", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,144 @@
+pub mod LC_ADDRESS {
+    /// `Some("USA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("USA");
+    /// `Some("US")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("US");
+    /// `Some("USA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("USA");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("ᏌᏊ ᎢᏳᎾᎵᏍᏔᏅ ᏍᎦᏚᎩ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ᏌᏊ ᎢᏳᎾᎵᏍᏔᏅ ᏍᎦᏚᎩ");
+    /// `Some(840)`
+    pub const COUNTRY_NUM: Option<i64> = Some(840);
+    /// `Some("USA")`
+    pub const COUNTRY_POST: Option<&str> = Some("USA");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("chr")`
+    pub const LANG_LIB: Option<&str> = Some("chr");
+    /// `Some("ᏣᎳᎩ")`
+    pub const LANG_NAME: Option<&str> = Some("ᏣᎳᎩ");
+    /// `Some("chr")`
+    pub const LANG_TERM: Option<&str> = Some("chr");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("http://cldr.unicode.org/index/process")`
+    pub const ADDRESS: Option<&str> = Some("http://cldr.unicode.org/index/process");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Joseph Erb")`
+    pub const CONTACT: Option<&str> = Some("Joseph Erb");
+    /// `Some("2010-10-20")`
+    pub const DATE: Option<&str> = Some("2010-10-20");
+    /// `Some("josepherb7@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("josepherb7@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Cherokee")`
+    pub const LANGUAGE: Option<&str> = Some("Cherokee");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Cherokee Nation")`
+    pub const SOURCE: Option<&str> = Some("Cherokee Nation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("United States")`
+    pub const TERRITORY: Option<&str> = Some("United States");
+    /// `Some("Cherokee language locale for United States")`
+    pub const TITLE: Option<&str> = Some("Cherokee language locale for United States");
+}
+pub mod LC_MESSAGES {
+    /// `"^([-0nN]|ᎥᏝ)"`
+    pub const NOEXPR: &str = "^([-0nN]|ᎥᏝ)";
+    /// `Some("ᎥᏝ")`
+    pub const NOSTR: Option<&str> = Some("ᎥᏝ");
+    /// `"^([+1yY]|ᎥᎥ)"`
+    pub const YESEXPR: &str = "^([+1yY]|ᎥᎥ)";
+    /// `Some("ᎥᎥ")`
+    pub const YESSTR: Option<&str> = Some("ᎥᎥ");
+}
+pub use super::en_US::LC_MONETARY;
+pub use super::en_US::LC_NUMERIC;
+pub use super::en_US::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ᏆᏍᎬ", "ᏉᏅᎯ", "ᏔᎵᏁ", "ᏦᎢᏁ", "ᏅᎩᏁ", "ᏧᎾᎩ", "ᏈᏕᎾ"]`
+    pub const ABDAY: &[&str] = &["ᏆᏍᎬ", "ᏉᏅᎯ", "ᏔᎵᏁ", "ᏦᎢᏁ", "ᏅᎩᏁ", "ᏧᎾᎩ", "ᏈᏕᎾ"];
+    /// `&["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"]`
+    pub const ABMON: &[&str] = &["ᎤᏃ", "ᎧᎦ", "ᎠᏅ", "ᎧᏬ", "ᎠᏂ", "ᏕᎭ", "ᎫᏰ", "ᎦᎶ", "ᏚᎵ", "ᏚᏂ", "ᏅᏓ", "ᎥᏍ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ᏌᎾᎴ", "ᏒᎯᏱᎢᏗᏢ"]`
+    pub const AM_PM: &[&str] = &["ᏌᎾᎴ", "ᏒᎯᏱᎢᏗᏢ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ᎤᎾᏙᏓᏆᏍᎬ", "ᎤᎾᏙᏓᏉᏅᎯ", "ᏔᎵᏁᎢᎦ", "ᏦᎢᏁᎢᎦ", "ᏅᎩᏁᎢᎦ", "ᏧᎾᎩᎶᏍᏗ", "ᎤᎾᏙᏓᏈᏕᎾ"]`
+    pub const DAY: &[&str] = &["ᎤᎾᏙᏓᏆᏍᎬ", "ᎤᎾᏙᏓᏉᏅᎯ", "ᏔᎵᏁᎢᎦ", "ᏦᎢᏁᎢᎦ", "ᏅᎩᏁᎢᎦ", "ᏧᎾᎩᎶᏍᏗ", "ᎤᎾᏙᏓᏈᏕᎾ"];
+    /// `"%m/%d/%Y"`
+    pub const D_FMT: &str = "%m/%d/%Y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"]`
+    pub const MON: &[&str] = &["ᎤᏃᎸᏔᏅ", "ᎧᎦᎵ", "ᎠᏅᏱ", "ᎧᏬᏂ", "ᎠᏂᏍᎬᏘ", "ᏕᎭᎷᏱ", "ᎫᏰᏉᏂ", "ᎦᎶᏂ", "ᏚᎵᏍᏗ", "ᏚᏂᏅᏗ", "ᏅᏓᏕᏆ", "ᎥᏍᎩᏱ"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
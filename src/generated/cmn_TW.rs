@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("TWN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TWN");
+    /// `Some("TW")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TW");
+    /// `Some("RC")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RC");
+    /// `Some("957")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("957");
+    /// `Some("中華民國")`
+    pub const COUNTRY_NAME: Option<&str> = Some("中華民國");
+    /// `Some(158)`
+    pub const COUNTRY_NUM: Option<i64> = Some(158);
+    /// `Some("TW")`
+    pub const COUNTRY_POST: Option<&str> = Some("TW");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("cmn")`
+    pub const LANG_LIB: Option<&str> = Some("cmn");
+    /// `Some("漢語官話")`
+    pub const LANG_NAME: Option<&str> = Some("漢語官話");
+    /// `Some("cmn")`
+    pub const LANG_TERM: Option<&str> = Some("cmn");
+    /// `Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-07-20")`
+    pub const DATE: Option<&str> = Some("2017-07-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Mandarin Chinese")`
+    pub const LANGUAGE: Option<&str> = Some("Mandarin Chinese");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Taiwan")`
+    pub const TERRITORY: Option<&str> = Some("Taiwan");
+    /// `Some("Mandarin Chinese locale for the Republic of China")`
+    pub const TITLE: Option<&str> = Some("Mandarin Chinese locale for the Republic of China");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNｎＮ不否]"`
+    pub const NOEXPR: &str = "^[-0nNｎＮ不否]";
+    /// `Some("不是")`
+    pub const NOSTR: Option<&str> = Some("不是");
+    /// `"^[+1yYｙＹ是]"`
+    pub const YESEXPR: &str = "^[+1yYｙＹ是]";
+    /// `Some("是")`
+    pub const YESSTR: Option<&str> = Some("是");
+}
+pub mod LC_MONETARY {
+    /// `"NT$"`
+    pub const CURRENCY_SYMBOL: &str = "NT$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TWD "`
+    pub const INT_CURR_SYMBOL: &str = "TWD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(1);
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[4]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_4;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[4]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_5;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("886")`
+    pub const INT_PREFIX: Option<&str> = Some("886");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A-%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A-%l");
+    /// `Some("+%c-%a-%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c-%a-%l");
+}
+pub mod LC_TIME {
+    /// `&["日", "一", "二", "三", "四", "五", "六"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_24;
+    /// `&[" 1月", " 2月", " 3月", " 4月", " 5月", " 6月", " 7月", " 8月", " 9月", "10月", "11月", "12月"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_0;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["上午", "下午"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_17;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y年 %b %-d號 %A %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y年 %b %-d號 %A %H:%M:%S %Z");
+    /// `&["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_25;
+    /// `"%Y年%m月%d號"`
+    pub const D_FMT: &str = "%Y年%m月%d號";
+    /// `"%Y年%m月%d號 (%A) %H點%M分%S秒"`
+    pub const D_T_FMT: &str = "%Y年%m月%d號 (%A) %H點%M分%S秒";
+    /// `Some(&["+:2:1913/01/01:+*:民國:%EC%Ey年", "+:1:1912/01/01:1912/12/31:民國:%EC元年", "+:1:1911/12/31:-*:民前:%EC%Ey年"])`
+    pub const ERA: Option<&[&str]> = Some(crate::__data::LC_TIME::ERA_0);
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_31;
+    /// `"%H點%M分%S秒"`
+    pub const T_FMT: &str = "%H點%M分%S秒";
+    /// `"%p %I點%M分%S秒"`
+    pub const T_FMT_AMPM: &str = "%p %I點%M分%S秒";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p %I點%M分%S秒")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %I點%M分%S秒");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("CZE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CZE");
+    /// `Some("CZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CZ");
+    /// `Some("CZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Česká republika")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Česká republika");
+    /// `Some(203)`
+    pub const COUNTRY_NUM: Option<i64> = Some(203);
+    /// `Some("CZ")`
+    pub const COUNTRY_POST: Option<&str> = Some("CZ");
+    /// `Some("cs")`
+    pub const LANG_AB: Option<&str> = Some("cs");
+    /// `Some("cze")`
+    pub const LANG_LIB: Option<&str> = Some("cze");
+    /// `Some("čeština")`
+    pub const LANG_NAME: Option<&str> = Some("čeština");
+    /// `Some("ces")`
+    pub const LANG_TERM: Option<&str> = Some("ces");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-28")`
+    pub const DATE: Option<&str> = Some("2000-06-28");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Czech")`
+    pub const LANGUAGE: Option<&str> = Some("Czech");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Czech Republic")`
+    pub const TERRITORY: Option<&str> = Some("Czech Republic");
+    /// `Some("Czech locale for the Czech Republic")`
+    pub const TITLE: Option<&str> = Some("Czech locale for the Czech Republic");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ne")`
+    pub const NOSTR: Option<&str> = Some("ne");
+    /// `"^[+1aAyY]"`
+    pub const YESEXPR: &str = "^[+1aAyY]";
+    /// `Some("ano")`
+    pub const YESSTR: Option<&str> = Some("ano");
+}
+pub mod LC_MONETARY {
+    /// `"Kč"`
+    pub const CURRENCY_SYMBOL: &str = "Kč";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"CZK "`
+    pub const INT_CURR_SYMBOL: &str = "CZK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("420")`
+    pub const INT_PREFIX: Option<&str> = Some("420");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("(0%a) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(0%a) %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Ne", "Po", "Út", "St", "Čt", "Pá", "So"]`
+    pub const ABDAY: &[&str] = &["Ne", "Po", "Út", "St", "Čt", "Pá", "So"];
+    /// `&["led", "úno", "bře", "dub", "kvě", "čen", "čec", "srp", "zář", "říj", "lis", "pro"]`
+    pub const ABMON: &[&str] = &["led", "úno", "bře", "dub", "kvě", "čen", "čec", "srp", "zář", "říj", "lis", "pro"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["leden", "únor", "březen", "duben", "květen", "červen", "červenec", "srpen", "září", "říjen", "listopad", "prosinec"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["leden", "únor", "březen", "duben", "květen", "červen", "červenec", "srpen", "září", "říjen", "listopad", "prosinec"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Neděle", "Pondělí", "Úterý", "Středa", "Čtvrtek", "Pátek", "Sobota"]`
+    pub const DAY: &[&str] = &["Neděle", "Pondělí", "Úterý", "Středa", "Čtvrtek", "Pátek", "Sobota"];
+    /// `"%-d.%-m.%Y"`
+    pub const D_FMT: &str = "%-d.%-m.%Y";
+    /// `"%a\u{a0}%-d.\u{a0}%B\u{a0}%Y,\u{a0}%H:%M:%S\u{a0}%Z"`
+    pub const D_T_FMT: &str = "%a\u{a0}%-d.\u{a0}%B\u{a0}%Y,\u{a0}%H:%M:%S\u{a0}%Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ledna", "února", "března", "dubna", "května", "června", "července", "srpna", "září", "října", "listopadu", "prosince"]`
+    pub const MON: &[&str] = &["ledna", "února", "března", "dubna", "května", "června", "července", "srpna", "září", "října", "listopadu", "prosince"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("POL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("POL");
+    /// `Some("PL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PL");
+    /// `Some("PL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Pòlskô")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Pòlskô");
+    /// `Some(616)`
+    pub const COUNTRY_NUM: Option<i64> = Some(616);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("csb")`
+    pub const LANG_LIB: Option<&str> = Some("csb");
+    /// `Some("kaszëbsczi jãzëk")`
+    pub const LANG_NAME: Option<&str> = Some("kaszëbsczi jãzëk");
+    /// `Some("csb")`
+    pub const LANG_TERM: Option<&str> = Some("csb");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Michal Ostrowski")`
+    pub const CONTACT: Option<&str> = Some("Michal Ostrowski");
+    /// `Some("2006-07-25")`
+    pub const DATE: Option<&str> = Some("2006-07-25");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kashubian")`
+    pub const LANGUAGE: Option<&str> = Some("Kashubian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("csb_PL locale")`
+    pub const SOURCE: Option<&str> = Some("csb_PL locale");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Poland")`
+    pub const TERRITORY: Option<&str> = Some("Poland");
+    /// `Some("Kashubian locale for Poland")`
+    pub const TITLE: Option<&str> = Some("Kashubian locale for Poland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nié")`
+    pub const NOSTR: Option<&str> = Some("nié");
+    /// `"^[+1JjTtYy]"`
+    pub const YESEXPR: &str = "^[+1JjTtYy]";
+    /// `Some("jo")`
+    pub const YESSTR: Option<&str> = Some("jo");
+}
+pub use super::pl_PL::LC_MONETARY;
+pub use super::pl_PL::LC_NUMERIC;
+pub use super::pl_PL::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["nie", "pòn", "wtó", "str", "czw", "pią", "sob"]`
+    pub const ABDAY: &[&str] = &["nie", "pòn", "wtó", "str", "czw", "pią", "sob"];
+    /// `&["stë", "gro", "str", "łżë", "maj", "cze", "lëp", "zél", "séw", "ruj", "lës", "gòd"]`
+    pub const ABMON: &[&str] = &["stë", "gro", "str", "łżë", "maj", "cze", "lëp", "zél", "séw", "ruj", "lës", "gòd"];
+    /// `Some(&["stë", "gro", "str", "łżë", "môj", "cze", "lëp", "zél", "séw", "ruj", "lës", "gòd"])`
+    pub const AB_ALT_MON: Option<&[&str]> = Some(&["stë", "gro", "str", "łżë", "môj", "cze", "lëp", "zél", "séw", "ruj", "lës", "gòd"]);
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["stëcznik", "gromicznik", "strëmiannik", "łżëkwiat", "môj", "czerwińc", "lëpińc", "zélnik", "séwnik", "rujan", "lëstopadnik", "gòdnik"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["stëcznik", "gromicznik", "strëmiannik", "łżëkwiat", "môj", "czerwińc", "lëpińc", "zélnik", "séwnik", "rujan", "lëstopadnik", "gòdnik"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["niedzela", "pòniedzôłk", "wtórk", "strzoda", "czwiôrtk", "piątk", "sobòta"]`
+    pub const DAY: &[&str] = &["niedzela", "pòniedzôłk", "wtórk", "strzoda", "czwiôrtk", "piątk", "sobòta"];
+    /// `"%Y-%m-%d"`
+    pub const D_FMT: &str = "%Y-%m-%d";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["stëcznika", "gromicznika", "strëmiannika", "łżëkwiata", "maja", "czerwińca", "lëpińca", "zélnika", "séwnika", "rujana", "lëstopadnika", "gòdnika"]`
+    pub const MON: &[&str] = &["stëcznika", "gromicznika", "strëmiannika", "łżëkwiata", "maja", "czerwińca", "lëpińca", "zélnika", "séwnika", "rujana", "lëstopadnika", "gòdnika"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,137 @@
+pub mod LC_ADDRESS {
+    /// `Some("GBR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("GBR");
+    /// `Some("GB")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("GB");
+    /// `Some("GB")`
+    pub const COUNTRY_CAR: Option<&str> = Some("GB");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("Y Deyrnas Unedig")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Y Deyrnas Unedig");
+    /// `Some(826)`
+    pub const COUNTRY_NUM: Option<i64> = Some(826);
+    /// `Some("GB")`
+    pub const COUNTRY_POST: Option<&str> = Some("GB");
+    /// `Some("cy")`
+    pub const LANG_AB: Option<&str> = Some("cy");
+    /// `Some("wel")`
+    pub const LANG_LIB: Option<&str> = Some("wel");
+    /// `Some("Cymraeg")`
+    pub const LANG_NAME: Option<&str> = Some("Cymraeg");
+    /// `Some("cym")`
+    pub const LANG_TERM: Option<&str> = Some("cym");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_14);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2004-09-27")`
+    pub const DATE: Option<&str> = Some("2004-09-27");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Welsh")`
+    pub const LANGUAGE: Option<&str> = Some("Welsh");
+    /// `Some("0.9")`
+    pub const REVISION: Option<&str> = Some("0.9");
+    /// `Some("thanks to Dafydd Tomos (dafydd@imaginet.co.uk)")`
+    pub const SOURCE: Option<&str> = Some("thanks to Dafydd Tomos (dafydd@imaginet.co.uk)");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("United Kingdom")`
+    pub const TERRITORY: Option<&str> = Some("United Kingdom");
+    /// `Some("Welsh language locale for Great Britain")`
+    pub const TITLE: Option<&str> = Some("Welsh language locale for Great Britain");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("na")`
+    pub const NOSTR: Option<&str> = Some("na");
+    /// `"^[+1iItTyY]"`
+    pub const YESEXPR: &str = "^[+1iItTyY]";
+    /// `Some("ie")`
+    pub const YESSTR: Option<&str> = Some("ie");
+}
+pub use super::en_GB::LC_MONETARY;
+pub use super::en_GB::LC_NUMERIC;
+pub use super::en_GB::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sul", "Llu", "Maw", "Mer", "Iau", "Gwe", "Sad"]`
+    pub const ABDAY: &[&str] = &["Sul", "Llu", "Maw", "Mer", "Iau", "Gwe", "Sad"];
+    /// `&["Ion", "Chw", "Maw", "Ebr", "Mai", "Meh", "Gor", "Aws", "Med", "Hyd", "Tach", "Rha"]`
+    pub const ABMON: &[&str] = &["Ion", "Chw", "Maw", "Ebr", "Mai", "Meh", "Gor", "Aws", "Med", "Hyd", "Tach", "Rha"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["am", "pm"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_3;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %H:%M:%S %Z %Y");
+    /// `&["Sul", "Llun", "Mawrth", "Mercher", "Iau", "Gwener", "Sadwrn"]`
+    pub const DAY: &[&str] = &["Sul", "Llun", "Mawrth", "Mercher", "Iau", "Gwener", "Sadwrn"];
+    /// `"%d.%m.%y"`
+    pub const D_FMT: &str = "%d.%m.%y";
+    /// `"Dydd %A %d mis %B %Y %T %Z"`
+    pub const D_T_FMT: &str = "Dydd %A %d mis %B %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"]`
+    pub const MON: &[&str] = &["Ionawr", "Chwefror", "Mawrth", "Ebrill", "Mai", "Mehefin", "Gorffennaf", "Awst", "Medi", "Hydref", "Tachwedd", "Rhagfyr"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %P %Z"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %P %Z";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%l:%M:%S %P %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %P %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,49 @@
+pub use super::de_AT::LC_ADDRESS;
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Postfach 130, A-1021 Wien")`
+    pub const ADDRESS: Option<&str> = Some("Postfach 130, A-1021 Wien");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Gerhard Budin")`
+    pub const CONTACT: Option<&str> = Some("Gerhard Budin");
+    /// `Some("2000-08-20")`
+    pub const DATE: Option<&str> = Some("2000-08-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("German")`
+    pub const LANGUAGE: Option<&str> = Some("German");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("O:sterreiches Normungsinstitut")`
+    pub const SOURCE: Option<&str> = Some("O:sterreiches Normungsinstitut");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Austria")`
+    pub const TERRITORY: Option<&str> = Some("Austria");
+    /// `Some("German locale for Austria with Euro")`
+    pub const TITLE: Option<&str> = Some("German locale for Austria with Euro");
+}
+pub use super::de_AT::LC_MESSAGES;
+pub use super::de_AT::LC_MONETARY;
+pub use super::de_AT::LC_NUMERIC;
+pub use super::de_AT::LC_TELEPHONE;
+pub use super::de_AT::LC_TIME;
@@ -0,0 +1,49 @@
+pub use super::de_BE::LC_ADDRESS;
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-08-21")`
+    pub const DATE: Option<&str> = Some("2000-08-21");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("German")`
+    pub const LANGUAGE: Option<&str> = Some("German");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Belgium")`
+    pub const TERRITORY: Option<&str> = Some("Belgium");
+    /// `Some("German locale for Belgium with Euro")`
+    pub const TITLE: Option<&str> = Some("German locale for Belgium with Euro");
+}
+pub use super::de_BE::LC_MESSAGES;
+pub use super::de_BE::LC_MONETARY;
+pub use super::de_BE::LC_NUMERIC;
+pub use super::de_BE::LC_TELEPHONE;
+pub use super::de_BE::LC_TIME;
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("DEU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DEU");
+    /// `Some("DE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DE");
+    /// `Some("D")`
+    pub const COUNTRY_CAR: Option<&str> = Some("D");
+    /// `Some("3")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("3");
+    /// `Some("Deutschland")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Deutschland");
+    /// `Some(276)`
+    pub const COUNTRY_NUM: Option<i64> = Some(276);
+    /// `Some("D")`
+    pub const COUNTRY_POST: Option<&str> = Some("D");
+    /// `Some("de")`
+    pub const LANG_AB: Option<&str> = Some("de");
+    /// `Some("ger")`
+    pub const LANG_LIB: Option<&str> = Some("ger");
+    /// `Some("Deutsch")`
+    pub const LANG_NAME: Option<&str> = Some("Deutsch");
+    /// `Some("deu")`
+    pub const LANG_TERM: Option<&str> = Some("deu");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-24")`
+    pub const DATE: Option<&str> = Some("2000-06-24");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("German")`
+    pub const LANGUAGE: Option<&str> = Some("German");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Germany")`
+    pub const TERRITORY: Option<&str> = Some("Germany");
+    /// `Some("German locale for Germany")`
+    pub const TITLE: Option<&str> = Some("German locale for Germany");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nein")`
+    pub const NOSTR: Option<&str> = Some("nein");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub mod LC_MONETARY {
+    /// `"€"`
+    pub const CURRENCY_SYMBOL: &str = "€";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"EUR "`
+    pub const INT_CURR_SYMBOL: &str = "EUR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("49")`
+    pub const INT_PREFIX: Option<&str> = Some("49");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_5;
+    /// `&["Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_5;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d. %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d. %b %H:%M:%S %Z %Y");
+    /// `&["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_6;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_2;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
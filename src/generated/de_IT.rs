@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("ITA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ITA");
+    /// `Some("IT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IT");
+    /// `Some("I")`
+    pub const COUNTRY_CAR: Option<&str> = Some("I");
+    /// `Some("978-88,979-12")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-88,979-12");
+    /// `Some("Italien")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Italien");
+    /// `Some(380)`
+    pub const COUNTRY_NUM: Option<i64> = Some(380);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("de")`
+    pub const LANG_AB: Option<&str> = Some("de");
+    /// `Some("ger")`
+    pub const LANG_LIB: Option<&str> = Some("ger");
+    /// `Some("Deutsch")`
+    pub const LANG_NAME: Option<&str> = Some("Deutsch");
+    /// `Some("deu")`
+    pub const LANG_TERM: Option<&str> = Some("deu");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("I-39100 Bolzano/Bozen")`
+    pub const ADDRESS: Option<&str> = Some("I-39100 Bolzano/Bozen");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Matthias Dieter Wallno:fer")`
+    pub const CONTACT: Option<&str> = Some("Matthias Dieter Wallno:fer");
+    /// `Some("2000-06-28")`
+    pub const DATE: Option<&str> = Some("2000-06-28");
+    /// `Some("libc-locales@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-locales@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("German")`
+    pub const LANGUAGE: Option<&str> = Some("German");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Italy")`
+    pub const TERRITORY: Option<&str> = Some("Italy");
+    /// `Some("German language locale for Italy")`
+    pub const TITLE: Option<&str> = Some("German language locale for Italy");
+}
+pub use super::de_DE::LC_MESSAGES;
+pub use super::it_IT::LC_MONETARY;
+pub use super::de_DE::LC_NUMERIC;
+pub use super::it_IT::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_5;
+    /// `&["Jän", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_6;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d. %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d. %b %H:%M:%S %Z %Y");
+    /// `&["Sonntag", "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_6;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jänner", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_6;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,82 @@
+pub mod LC_ADDRESS {
+    /// `Some("LIE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LIE");
+    /// `Some("LI")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LI");
+    /// `Some("FL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("FL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Liechtenstein")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Liechtenstein");
+    /// `Some(438)`
+    pub const COUNTRY_NUM: Option<i64> = Some(438);
+    /// `Some("LI")`
+    pub const COUNTRY_POST: Option<&str> = Some("LI");
+    /// `Some("de")`
+    pub const LANG_AB: Option<&str> = Some("de");
+    /// `Some("ger")`
+    pub const LANG_LIB: Option<&str> = Some("ger");
+    /// `Some("Deutsch")`
+    pub const LANG_NAME: Option<&str> = Some("Deutsch");
+    /// `Some("deu")`
+    pub const LANG_TERM: Option<&str> = Some("deu");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_12);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2007-11-27")`
+    pub const DATE: Option<&str> = Some("2007-11-27");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("German")`
+    pub const LANGUAGE: Option<&str> = Some("German");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Liechtenstein")`
+    pub const TERRITORY: Option<&str> = Some("Liechtenstein");
+    /// `Some("German locale for Liechtenstein")`
+    pub const TITLE: Option<&str> = Some("German locale for Liechtenstein");
+}
+pub use super::de_CH::LC_MESSAGES;
+pub use super::de_CH::LC_MONETARY;
+pub use super::de_CH::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("423")`
+    pub const INT_PREFIX: Option<&str> = Some("423");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub use super::de_CH::LC_TIME;
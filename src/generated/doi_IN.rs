@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("doi")`
+    pub const LANG_LIB: Option<&str> = Some("doi");
+    /// `Some("डोगरी")`
+    pub const LANG_NAME: Option<&str> = Some("डोगरी");
+    /// `Some("doi")`
+    pub const LANG_TERM: Option<&str> = Some("doi");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ")`
+    pub const ADDRESS: Option<&str> = Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2012-02-16")`
+    pub const DATE: Option<&str> = Some("2012-02-16");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Dogri")`
+    pub const LANGUAGE: Option<&str> = Some("Dogri");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Dogri language locale for India")`
+    pub const TITLE: Option<&str> = Some("Dogri language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("ना")`
+    pub const NOSTR: Option<&str> = Some("ना");
+    /// `"^[+1yYऑ]"`
+    pub const YESEXPR: &str = "^[+1yYऑ]";
+    /// `Some("ऑह")`
+    pub const YESSTR: Option<&str> = Some("ऑह");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ऐत", "सोम", "म\u{902}गल", "ब\u{941}ध", "बीर", "श\u{941}क\u{94d}कर", "श\u{94d}नीचर"]`
+    pub const ABDAY: &[&str] = &["ऐत", "सोम", "म\u{902}गल", "ब\u{941}ध", "बीर", "श\u{941}क\u{94d}कर", "श\u{94d}नीचर"];
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "एप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{942}ल\u{948}", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"]`
+    pub const ABMON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "एप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{942}ल\u{948}", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["सञ\u{902}", "सब\u{947}र"]`
+    pub const AM_PM: &[&str] = &["सञ\u{902}", "सब\u{947}र"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ऐतबार", "सोमबार", "म\u{902}गलबर", "ब\u{941}धबार", "बीरबार", "श\u{941}क\u{94d}करबार", "श\u{94d}नीचरबार"]`
+    pub const DAY: &[&str] = &["ऐतबार", "सोमबार", "म\u{902}गलबर", "ब\u{941}धबार", "बीरबार", "श\u{941}क\u{94d}करबार", "श\u{94d}नीचरबार"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "एप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{942}ल\u{948}", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"]`
+    pub const MON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "एप\u{94d}र\u{948}ल", "म\u{947}ई", "ज\u{942}न", "ज\u{942}ल\u{948}", "अगस\u{94d}त", "सित\u{902}बर", "अक\u{94d}त\u{942}बर", "नव\u{902}बर", "दिस\u{902}बर"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("MDV")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MDV");
+    /// `Some("MV")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MV");
+    /// `Some("MV")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MV");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}ރ\u{7a7}އ\u{7b0}ޖ\u{7ac}")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}ރ\u{7a7}އ\u{7b0}ޖ\u{7ac}");
+    /// `Some(462)`
+    pub const COUNTRY_NUM: Option<i64> = Some(462);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("dv")`
+    pub const LANG_AB: Option<&str> = Some("dv");
+    /// `Some("div")`
+    pub const LANG_LIB: Option<&str> = Some("div");
+    /// `Some("ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}")`
+    pub const LANG_NAME: Option<&str> = Some("ދ\u{7a8}ވ\u{7ac}ހ\u{7a8}");
+    /// `Some("div")`
+    pub const LANG_TERM: Option<&str> = Some("div");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-05-13")`
+    pub const DATE: Option<&str> = Some("2006-05-13");
+    /// `Some("sofwath@hotmail.com")`
+    pub const EMAIL: Option<&str> = Some("sofwath@hotmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Divehi")`
+    pub const LANGUAGE: Option<&str> = Some("Divehi");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Maldives")`
+    pub const TERRITORY: Option<&str> = Some("Maldives");
+    /// `Some("Dhivehi Language Locale for Maldives")`
+    pub const TITLE: Option<&str> = Some("Dhivehi Language Locale for Maldives");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub mod LC_MONETARY {
+    /// `"ރ."`
+    pub const CURRENCY_SYMBOL: &str = "ރ.";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MVR "`
+    pub const INT_CURR_SYMBOL: &str = "MVR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const P_SIGN_POSN: i64 = 2;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("960")`
+    pub const INT_PREFIX: Option<&str> = Some("960");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["އ\u{7a7}ދ\u{7a9}އ\u{7b0}ތ\u{7a6}", "ހ\u{7af}މ\u{7a6}", "އ\u{7a6}ނ\u{7b0}ގ\u{7a7}ރ\u{7a6}", "ބ\u{7aa}ދ\u{7a6}", "ބ\u{7aa}ރ\u{7a7}ސ\u{7b0}ފ\u{7a6}ތ\u{7a8}", "ހ\u{7aa}ކ\u{7aa}ރ\u{7aa}", "ހ\u{7ae}ނ\u{7a8}ހ\u{7a8}ރ\u{7aa}"]`
+    pub const ABDAY: &[&str] = &["އ\u{7a7}ދ\u{7a9}އ\u{7b0}ތ\u{7a6}", "ހ\u{7af}މ\u{7a6}", "އ\u{7a6}ނ\u{7b0}ގ\u{7a7}ރ\u{7a6}", "ބ\u{7aa}ދ\u{7a6}", "ބ\u{7aa}ރ\u{7a7}ސ\u{7b0}ފ\u{7a6}ތ\u{7a8}", "ހ\u{7aa}ކ\u{7aa}ރ\u{7aa}", "ހ\u{7ae}ނ\u{7a8}ހ\u{7a8}ރ\u{7aa}"];
+    /// `&["ޖ\u{7ac}ނ\u{7aa}އ\u{7a6}ރ\u{7a9}", "ފ\u{7ac}ބ\u{7b0}ރ\u{7aa}އ\u{7a6}ރ\u{7a9}", "މ\u{7a7}ރޗ\u{7b0}", "އ\u{7ac}ޕ\u{7b0}ރ\u{7a9}ލ\u{7b0}", "މ\u{7ac}އ\u{7a8}", "ޖ\u{7ab}ނ\u{7b0}", "ޖ\u{7aa}ލ\u{7a6}އ\u{7a8}", "އ\u{7ae}ގ\u{7a6}ސ\u{7b0}ޓ\u{7b0}", "ސ\u{7ac}ޕ\u{7b0}ޓ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "އ\u{7ae}ކ\u{7b0}ޓ\u{7ab}ބ\u{7a6}ރ", "ނ\u{7ae}ވ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "ޑ\u{7a8}ސ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ"]`
+    pub const ABMON: &[&str] = &["ޖ\u{7ac}ނ\u{7aa}އ\u{7a6}ރ\u{7a9}", "ފ\u{7ac}ބ\u{7b0}ރ\u{7aa}އ\u{7a6}ރ\u{7a9}", "މ\u{7a7}ރޗ\u{7b0}", "އ\u{7ac}ޕ\u{7b0}ރ\u{7a9}ލ\u{7b0}", "މ\u{7ac}އ\u{7a8}", "ޖ\u{7ab}ނ\u{7b0}", "ޖ\u{7aa}ލ\u{7a6}އ\u{7a8}", "އ\u{7ae}ގ\u{7a6}ސ\u{7b0}ޓ\u{7b0}", "ސ\u{7ac}ޕ\u{7b0}ޓ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "އ\u{7ae}ކ\u{7b0}ޓ\u{7ab}ބ\u{7a6}ރ", "ނ\u{7ae}ވ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "ޑ\u{7a8}ސ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["މކ", "މފ"]`
+    pub const AM_PM: &[&str] = &["މކ", "މފ"];
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["އ\u{7a7}ދ\u{7a9}އ\u{7b0}ތ\u{7a6}", "ހ\u{7af}މ\u{7a6}", "އ\u{7a6}ނ\u{7b0}ގ\u{7a7}ރ\u{7a6}", "ބ\u{7aa}ދ\u{7a6}", "ބ\u{7aa}ރ\u{7a7}ސ\u{7b0}ފ\u{7a6}ތ\u{7a8}", "ހ\u{7aa}ކ\u{7aa}ރ\u{7aa}", "ހ\u{7ae}ނ\u{7a8}ހ\u{7a8}ރ\u{7aa}"]`
+    pub const DAY: &[&str] = &["އ\u{7a7}ދ\u{7a9}އ\u{7b0}ތ\u{7a6}", "ހ\u{7af}މ\u{7a6}", "އ\u{7a6}ނ\u{7b0}ގ\u{7a7}ރ\u{7a6}", "ބ\u{7aa}ދ\u{7a6}", "ބ\u{7aa}ރ\u{7a7}ސ\u{7b0}ފ\u{7a6}ތ\u{7a8}", "ހ\u{7aa}ކ\u{7aa}ރ\u{7aa}", "ހ\u{7ae}ނ\u{7a8}ހ\u{7a8}ރ\u{7aa}"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%Z %H:%M:%S %Y %b %d %a"`
+    pub const D_T_FMT: &str = "%Z %H:%M:%S %Y %b %d %a";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(6)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(6);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ޖ\u{7ac}ނ\u{7aa}އ\u{7a6}ރ\u{7a9}", "ފ\u{7ac}ބ\u{7b0}ރ\u{7aa}އ\u{7a6}ރ\u{7a9}", "މ\u{7a7}ރޗ\u{7b0}", "އ\u{7ac}ޕ\u{7b0}ރ\u{7a9}ލ\u{7b0}", "މ\u{7ac}އ\u{7a8}", "ޖ\u{7ab}ނ\u{7b0}", "ޖ\u{7aa}ލ\u{7a6}އ\u{7a8}", "އ\u{7ae}ގ\u{7a6}ސ\u{7b0}ޓ\u{7b0}", "ސ\u{7ac}ޕ\u{7b0}ޓ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "އ\u{7ae}ކ\u{7b0}ޓ\u{7ab}ބ\u{7a6}ރ", "ނ\u{7ae}ވ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "ޑ\u{7a8}ސ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ"]`
+    pub const MON: &[&str] = &["ޖ\u{7ac}ނ\u{7aa}އ\u{7a6}ރ\u{7a9}", "ފ\u{7ac}ބ\u{7b0}ރ\u{7aa}އ\u{7a6}ރ\u{7a9}", "މ\u{7a7}ރޗ\u{7b0}", "އ\u{7ac}ޕ\u{7b0}ރ\u{7a9}ލ\u{7b0}", "މ\u{7ac}އ\u{7a8}", "ޖ\u{7ab}ނ\u{7b0}", "ޖ\u{7aa}ލ\u{7a6}އ\u{7a8}", "އ\u{7ae}ގ\u{7a6}ސ\u{7b0}ޓ\u{7b0}", "ސ\u{7ac}ޕ\u{7b0}ޓ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "އ\u{7ae}ކ\u{7b0}ޓ\u{7ab}ބ\u{7a6}ރ", "ނ\u{7ae}ވ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ", "ޑ\u{7a8}ސ\u{7ac}ނ\u{7b0}ބ\u{7a6}ރ"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%P %I:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%P %I:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%P %I:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%P %I:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
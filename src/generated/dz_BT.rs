@@ -0,0 +1,210 @@
+pub mod LC_ADDRESS {
+    /// `Some("BTN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BTN");
+    /// `Some("BT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BT");
+    /// `Some("BHT")`
+    pub const COUNTRY_CAR: Option<&str> = Some("BHT");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("འབ\u{fb2}\u{f74}ག")`
+    pub const COUNTRY_NAME: Option<&str> = Some("འབ\u{fb2}\u{f74}ག");
+    /// `Some(64)`
+    pub const COUNTRY_NUM: Option<i64> = Some(64);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("dz")`
+    pub const LANG_AB: Option<&str> = Some("dz");
+    /// `Some("dzo")`
+    pub const LANG_LIB: Option<&str> = Some("dzo");
+    /// `Some("ར\u{fab}\u{f7c}ང་ཁ")`
+    pub const LANG_NAME: Option<&str> = Some("ར\u{fab}\u{f7c}ང་ཁ");
+    /// `Some("dzo")`
+    pub const LANG_TERM: Option<&str> = Some("dzo");
+    /// `Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Kanglung, Bhutan")`
+    pub const ADDRESS: Option<&str> = Some("Kanglung, Bhutan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+        pub const CATEGORY: Option<&[&[&str]]> = Some(&[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+    ]);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-09-03")`
+    pub const DATE: Option<&str> = Some("2004-09-03");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Dzongkha")`
+    pub const LANGUAGE: Option<&str> = Some("Dzongkha");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("Sherubtse College")`
+    pub const SOURCE: Option<&str> = Some("Sherubtse College");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Bhutan")`
+    pub const TERRITORY: Option<&str> = Some("Bhutan");
+    /// `Some("Dzongkha language locale for Bhutan")`
+    pub const TITLE: Option<&str> = Some("Dzongkha language locale for Bhutan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNམ]"`
+    pub const NOEXPR: &str = "^[-0nNམ]";
+    /// `Some("མ\u{f7a}ན་")`
+    pub const NOSTR: Option<&str> = Some("མ\u{f7a}ན་");
+    /// `"^[+1yYཨ]"`
+    pub const YESEXPR: &str = "^[+1yYཨ]";
+    /// `Some("ཨ\u{f72}ན་")`
+    pub const YESSTR: Option<&str> = Some("ཨ\u{f72}ན་");
+}
+pub mod LC_MONETARY {
+    /// `"Nu."`
+    pub const CURRENCY_SYMBOL: &str = "Nu.";
+    /// `3`
+    pub const FRAC_DIGITS: i64 = 3;
+    /// `"BTN "`
+    pub const INT_CURR_SYMBOL: &str = "BTN ";
+    /// `3`
+    pub const INT_FRAC_DIGITS: i64 = 3;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_1;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("975")`
+    pub const INT_PREFIX: Option<&str> = Some("975");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ཟ\u{fb3}་", "མ\u{f72}ར་", "ལ\u{fb7}ག་", "པ\u{f74}ར་", "སངས་", "ས\u{fa4}\u{f7a}ན་", "ཉ\u{f72}་"]`
+    pub const ABDAY: &[&str] = &["ཟ\u{fb3}་", "མ\u{f72}ར་", "ལ\u{fb7}ག་", "པ\u{f74}ར་", "སངས་", "ས\u{fa4}\u{f7a}ན་", "ཉ\u{f72}་"];
+    /// `&["ཟ\u{fb3}་༡", "ཟ\u{fb3}་༢", "ཟ\u{fb3}་༣", "ཟ\u{fb3}་༤", "ཟ\u{fb3}་༥", "ཟ\u{fb3}་༦", "ཟ\u{fb3}་༧", "ཟ\u{fb3}་༨", "ཟ\u{fb3}་༩", "ཟ\u{fb3}་༡༠", "ཟ\u{fb3}་༡༡", "ཟ\u{fb3}་༡༢"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_30;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ངས་ཆ", "ཕ\u{fb1}\u{f72}་ཆ"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_14;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["གཟའ་ཟ\u{fb3}་བ་", "གཟའ་མ\u{f72}ག་དམར་", "གཟའ་ལ\u{fb7}ག་ཕ་", "གཟའ་པ\u{f74}ར་བ\u{f74}་", "གཟའ་པ་སངས་", "གཟའ་ས\u{fa4}\u{f7a}ན་ཕ་", "གཟའ་ཉ\u{f72}་མ་"]`
+    pub const DAY: &[&str] = &["གཟའ་ཟ\u{fb3}་བ་", "གཟའ་མ\u{f72}ག་དམར་", "གཟའ་ལ\u{fb7}ག་ཕ་", "གཟའ་པ\u{f74}ར་བ\u{f74}་", "གཟའ་པ་སངས་", "གཟའ་ས\u{fa4}\u{f7a}ན་ཕ་", "གཟའ་ཉ\u{f72}་མ་"];
+    /// `"པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%d"`
+    pub const D_FMT: &str = "པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%d";
+    /// `"པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%dཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S"`
+    pub const D_T_FMT: &str = "པས\u{fb1}\u{f72}་ལ\u{f7c}%yཟལ%mཚ\u{f7a}ས%dཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ཟ\u{fb3}་བ་དང་པ་", "ཟ\u{fb3}་བ་གཉ\u{f72}ས་པ་", "ཟ\u{fb3}་བ་གས\u{f74}མ་པ་", "ཟ\u{fb3}་བ་བཞ\u{f72}་པ་", "ཟ\u{fb3}་བ་ལ\u{f94}་ཕ་", "ཟ\u{fb3}་བ་ད\u{fb2}\u{f74}ག་པ་", "ཟ\u{fb3}་བ་བད\u{f74}ནཔ་", "ཟ\u{fb3}་བ་བར\u{f92}\u{fb1}ད་པ་", "ཟ\u{fb3}་བ་དག\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཅ\u{f72}ག་པ་", "ཟ\u{fb3}་བ་བཅ\u{f74}་གཉ\u{f72}ས་པ་"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_28;
+    /// `"ཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S"`
+    pub const T_FMT: &str = "ཆ\u{f74}་ཚ\u{f7c}ད%Hཀསར་མ%Mཀསར་ཆ%S";
+    /// `"ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p"`
+    pub const T_FMT_AMPM: &str = "ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("ཆ\u{f74}་ཚ\u{f7c}ད%Iཀསར་མ%Mཀསར་ཆ%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
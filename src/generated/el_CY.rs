@@ -0,0 +1,151 @@
+pub mod LC_ADDRESS {
+    /// `Some("CYP")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CYP");
+    /// `Some("CY")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CY");
+    /// `Some("CY")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CY");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Κύπρος")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Κύπρος");
+    /// `Some(196)`
+    pub const COUNTRY_NUM: Option<i64> = Some(196);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("el")`
+    pub const LANG_AB: Option<&str> = Some("el");
+    /// `Some("gre")`
+    pub const LANG_LIB: Option<&str> = Some("gre");
+    /// `Some("Ελληνικά")`
+    pub const LANG_NAME: Option<&str> = Some("Ελληνικά");
+    /// `Some("ell")`
+    pub const LANG_TERM: Option<&str> = Some("ell");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Konstantinos Margaritis, M. Asias 50, Nafplion 21100, Greece")`
+    pub const ADDRESS: Option<&str> = Some("Konstantinos Margaritis, M. Asias 50, Nafplion 21100, Greece");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-10-20")`
+    pub const DATE: Option<&str> = Some("2004-10-20");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Greek")`
+    pub const LANGUAGE: Option<&str> = Some("Greek");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Greek Debian Translation Team")`
+    pub const SOURCE: Option<&str> = Some("Greek Debian Translation Team");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Cyprus")`
+    pub const TERRITORY: Option<&str> = Some("Cyprus");
+    /// `Some("Greek locale for Cyprus")`
+    pub const TITLE: Option<&str> = Some("Greek locale for Cyprus");
+}
+pub use super::el_GR::LC_MESSAGES;
+pub use super::el_GR::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("357")`
+    pub const INT_PREFIX: Option<&str> = Some("357");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Κυρ", "Δευ", "Τρι", "Τετ", "Πεμ", "Παρ", "Σαβ"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_15;
+    /// `&["Ιαν", "Φεβ", "Μαρ", "Απρ", "Μαΐ", "Ιουν", "Ιουλ", "Αυγ", "Σεπ", "Οκτ", "Νοε", "Δεκ"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_20;
+    /// `Some(&["Ιαν", "Φεβ", "Μάρ", "Απρ", "Μάι", "Ιούν", "Ιούλ", "Αύγ", "Σεπ", "Οκτ", "Νοέ", "Δεκ"])`
+    pub const AB_ALT_MON: Option<&[&str]> = Some(crate::__data::LC_TIME::AB_ALT_MON_0);
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["Ιανουάριος", "Φεβρουάριος", "Μάρτιος", "Απρίλιος", "Μάιος", "Ιούνιος", "Ιούλιος", "Αύγουστος", "Σεπτέμβριος", "Οκτώβριος", "Νοέμβριος", "Δεκέμβριος"])`
+    pub const ALT_MON: Option<&[&str]> = Some(crate::__data::LC_TIME::ALT_MON_0);
+    /// `&["πμ", "μμ"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_7;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %d %b %Y %r %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %d %b %Y %r %Z");
+    /// `&["Κυριακή", "Δευτέρα", "Τρίτη", "Τετάρτη", "Πέμπτη", "Παρασκευή", "Σάββατο"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_16;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ιανουαρίου", "Φεβρουαρίου", "Μαρτίου", "Απριλίου", "Μαΐου", "Ιουνίου", "Ιουλίου", "Αυγούστου", "Σεπτεμβρίου", "Οκτωβρίου", "Νοεμβρίου", "Δεκεμβρίου"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_18;
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("ATG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ATG");
+    /// `Some("AG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AG");
+    /// `Some("AG")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AG");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Antigua & Barbuda")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Antigua & Barbuda");
+    /// `Some(28)`
+    pub const COUNTRY_NUM: Option<i64> = Some(28);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("en")`
+    pub const LANG_AB: Option<&str> = Some("en");
+    /// `Some("eng")`
+    pub const LANG_LIB: Option<&str> = Some("eng");
+    /// `Some("English")`
+    pub const LANG_NAME: Option<&str> = Some("English");
+    /// `Some("eng")`
+    pub const LANG_TERM: Option<&str> = Some("eng");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2008-09-16")`
+    pub const DATE: Option<&str> = Some("2008-09-16");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("English")`
+    pub const LANGUAGE: Option<&str> = Some("English");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Antigua & Barbuda")`
+    pub const TERRITORY: Option<&str> = Some("Antigua & Barbuda");
+    /// `Some("English language locale for Antigua and Barbuda")`
+    pub const TITLE: Option<&str> = Some("English language locale for Antigua and Barbuda");
+}
+pub use super::en_GB::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"$"`
+    pub const CURRENCY_SYMBOL: &str = "$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"XCD "`
+    pub const INT_CURR_SYMBOL: &str = "XCD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::en_GB::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("1")`
+    pub const INT_PREFIX: Option<&str> = Some("1");
+    /// `Some("011")`
+    pub const INT_SELECT: Option<&str> = Some("011");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_6;
+    /// `&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_3;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["am", "pm"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_3;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %H:%M:%S %Z %Y");
+    /// `&["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_7;
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_4;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %P %Z"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %P %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%l:%M:%S %P %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %P %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,126 @@
+pub mod LC_ADDRESS {
+    /// `Some("SYC")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SYC");
+    /// `Some("SC")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("SC");
+    /// `Some("SY")`
+    pub const COUNTRY_CAR: Option<&str> = Some("SY");
+    /// `Some("978-99931")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-99931");
+    /// `Some("Seychelles")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Seychelles");
+    /// `Some(690)`
+    pub const COUNTRY_NUM: Option<i64> = Some(690);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("en")`
+    pub const LANG_AB: Option<&str> = Some("en");
+    /// `Some("eng")`
+    pub const LANG_LIB: Option<&str> = Some("eng");
+    /// `Some("English")`
+    pub const LANG_NAME: Option<&str> = Some("English");
+    /// `Some("eng")`
+    pub const LANG_TERM: Option<&str> = Some("eng");
+    /// `Some("%f%N%h%s%N%T")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%h%s%N%T");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-07-24")`
+    pub const DATE: Option<&str> = Some("2017-07-24");
+    /// `Some("akhilesh.k@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("English")`
+    pub const LANGUAGE: Option<&str> = Some("English");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Seychelles")`
+    pub const TERRITORY: Option<&str> = Some("Seychelles");
+    /// `Some("English locale for the Seychelles")`
+    pub const TITLE: Option<&str> = Some("English locale for the Seychelles");
+}
+pub use super::en_GB::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"SR"`
+    pub const CURRENCY_SYMBOL: &str = "SR";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"SCR "`
+    pub const INT_CURR_SYMBOL: &str = "SCR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::en_GB::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("248")`
+    pub const INT_PREFIX: Option<&str> = Some("248");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub use super::en_GB::LC_TIME;
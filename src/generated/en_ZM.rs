@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("ZMB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ZMB");
+    /// `Some("ZM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ZM");
+    /// `Some("Z")`
+    pub const COUNTRY_CAR: Option<&str> = Some("Z");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Zambia")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Zambia");
+    /// `Some(894)`
+    pub const COUNTRY_NUM: Option<i64> = Some(894);
+    /// `Some("ZM")`
+    pub const COUNTRY_POST: Option<&str> = Some("ZM");
+    /// `Some("en")`
+    pub const LANG_AB: Option<&str> = Some("en");
+    /// `Some("eng")`
+    pub const LANG_LIB: Option<&str> = Some("eng");
+    /// `Some("English")`
+    pub const LANG_NAME: Option<&str> = Some("English");
+    /// `Some("eng")`
+    pub const LANG_TERM: Option<&str> = Some("eng");
+    /// `Some("%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%e %h%N%b %s%N%z%N%T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Martin Benjamin")`
+    pub const CONTACT: Option<&str> = Some("Martin Benjamin");
+    /// `Some("2011-04-18")`
+    pub const DATE: Option<&str> = Some("2011-04-18");
+    /// `Some("locales@africanlocalization.net")`
+    pub const EMAIL: Option<&str> = Some("locales@africanlocalization.net");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("English")`
+    pub const LANGUAGE: Option<&str> = Some("English");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("ANLoc")`
+    pub const SOURCE: Option<&str> = Some("ANLoc");
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Zambia")`
+    pub const TERRITORY: Option<&str> = Some("Zambia");
+    /// `Some("English locale for Zambia")`
+    pub const TITLE: Option<&str> = Some("English locale for Zambia");
+}
+pub use super::bem_ZM::LC_MESSAGES;
+pub use super::bem_ZM::LC_MONETARY;
+pub use super::en_GB::LC_NUMERIC;
+pub use super::bem_ZM::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_6;
+    /// `&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_3;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["am", "pm"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_3;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %H:%M:%S %Z %Y");
+    /// `&["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_7;
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_4;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %P %Z"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %P %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%l:%M:%S %P %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %P %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
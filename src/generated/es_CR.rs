@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("CRI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CRI");
+    /// `Some("CR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CR");
+    /// `Some("CR")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CR");
+    /// `Some("9930,9977,9968")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("9930,9977,9968");
+    /// `Some("Costa Rica")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Costa Rica");
+    /// `Some(188)`
+    pub const COUNTRY_NUM: Option<i64> = Some(188);
+    /// `Some("CR")`
+    pub const COUNTRY_POST: Option<&str> = Some("CR");
+    /// `Some("es")`
+    pub const LANG_AB: Option<&str> = Some("es");
+    /// `Some("spa")`
+    pub const LANG_LIB: Option<&str> = Some("spa");
+    /// `Some("español")`
+    pub const LANG_NAME: Option<&str> = Some("español");
+    /// `Some("spa")`
+    pub const LANG_TERM: Option<&str> = Some("spa");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2009-12-23")`
+    pub const DATE: Option<&str> = Some("2009-12-23");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Spanish")`
+    pub const LANGUAGE: Option<&str> = Some("Spanish");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Costa Rica")`
+    pub const TERRITORY: Option<&str> = Some("Costa Rica");
+    /// `Some("Spanish locale for Costa Rica")`
+    pub const TITLE: Option<&str> = Some("Spanish locale for Costa Rica");
+}
+pub use super::es_ES::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"₡"`
+    pub const CURRENCY_SYMBOL: &str = "₡";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"CRC "`
+    pub const INT_CURR_SYMBOL: &str = "CRC ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("506")`
+    pub const INT_PREFIX: Option<&str> = Some("506");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dom", "lun", "mar", "mié", "jue", "vie", "sáb"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_11;
+    /// `&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_11;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["a.m.", "p.m."]`
+    pub const AM_PM: &[&str] = &["a.m.", "p.m."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %H:%M:%S %Z %Y");
+    /// `&["domingo", "lunes", "martes", "miércoles", "jueves", "viernes", "sábado"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_11;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_9;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("IRN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IRN");
+    /// `Some("IR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IR");
+    /// `Some("IR")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IR");
+    /// `Some("964")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("964");
+    /// `Some("ایران")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ایران");
+    /// `Some(364)`
+    pub const COUNTRY_NUM: Option<i64> = Some(364);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("fa")`
+    pub const LANG_AB: Option<&str> = Some("fa");
+    /// `Some("per")`
+    pub const LANG_LIB: Option<&str> = Some("per");
+    /// `Some("فارسی")`
+    pub const LANG_NAME: Option<&str> = Some("فارسی");
+    /// `Some("fas")`
+    pub const LANG_TERM: Option<&str> = Some("fas");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("5, Shahid Ghasemi Habibollah, Azadi Ave, Tehran, Iran")`
+    pub const ADDRESS: Option<&str> = Some("5, Shahid Ghasemi Habibollah, Azadi Ave, Tehran, Iran");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Roozbeh Pournader")`
+    pub const CONTACT: Option<&str> = Some("Roozbeh Pournader");
+    /// `Some("2005-04-06")`
+    pub const DATE: Option<&str> = Some("2005-04-06");
+    /// `Some("roozbeh@farsiweb.info")`
+    pub const EMAIL: Option<&str> = Some("roozbeh@farsiweb.info");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Persian")`
+    pub const LANGUAGE: Option<&str> = Some("Persian");
+    /// `Some("3.0")`
+    pub const REVISION: Option<&str> = Some("3.0");
+    /// `Some("Sharif FarsiWeb, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Sharif FarsiWeb, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Iran")`
+    pub const TERRITORY: Option<&str> = Some("Iran");
+    /// `Some("Persian locale for Iran")`
+    pub const TITLE: Option<&str> = Some("Persian locale for Iran");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNخنok]"`
+    pub const NOEXPR: &str = "^[-0nNخنok]";
+    /// `Some("نه")`
+    pub const NOSTR: Option<&str> = Some("نه");
+    /// `"^[+1yYآبHf]"`
+    pub const YESEXPR: &str = "^[+1yYآبHf]";
+    /// `Some("بله")`
+    pub const YESSTR: Option<&str> = Some("بله");
+}
+pub mod LC_MONETARY {
+    /// `"ریال"`
+    pub const CURRENCY_SYMBOL: &str = "ریال";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"IRR "`
+    pub const INT_CURR_SYMBOL: &str = "IRR ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `Some(0)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(1);
+    /// `"٫"`
+    pub const MON_DECIMAL_POINT: &str = "٫";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"٬"`
+    pub const MON_THOUSANDS_SEP: &str = "٬";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("98")`
+    pub const INT_PREFIX: Option<&str> = Some("98");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("\u{202a}%A‒%l\u{202c}")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("\u{202a}%A‒%l\u{202c}");
+    /// `Some("\u{202a}+%c %a %l\u{202c}")`
+    pub const TEL_INT_FMT: Option<&str> = Some("\u{202a}+%c %a %l\u{202c}");
+}
+pub mod LC_TIME {
+    /// `&["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه"]`
+    pub const ABDAY: &[&str] = &["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه"];
+    /// `&["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اكتبر", "نوامبر", "دسامبر"]`
+    pub const ABMON: &[&str] = &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اكتبر", "نوامبر", "دسامبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["۰۰", "۰۱", "۰۲", "۰۳", "۰۴", "۰۵", "۰۶", "۰۷", "۰۸", "۰۹", "۱۰", "۱۱", "۱۲", "۱۳", "۱۴", "۱۵", "۱۶", "۱۷", "۱۸", "۱۹", "۲۰", "۲۱", "۲۲", "۲۳", "۲۴", "۲۵", "۲۶", "۲۷", "۲۸", "۲۹", "۳۰", "۳۱", "۳۲", "۳۳", "۳۴", "۳۵", "۳۶", "۳۷", "۳۸", "۳۹", "۴۰", "۴۱", "۴۲", "۴۳", "۴۴", "۴۵", "۴۶", "۴۷", "۴۸", "۴۹", "۵۰", "۵۱", "۵۲", "۵۳", "۵۴", "۵۵", "۵۶", "۵۷", "۵۸", "۵۹", "۶۰", "۶۱", "۶۲", "۶۳", "۶۴", "۶۵", "۶۶", "۶۷", "۶۸", "۶۹", "۷۰", "۷۱", "۷۲", "۷۳", "۷۴", "۷۵", "۷۶", "۷۷", "۷۸", "۷۹", "۸۰", "۸۱", "۸۲", "۸۳", "۸۴", "۸۵", "۸۶", "۸۷", "۸۸", "۸۹", "۹۰", "۹۱", "۹۲", "۹۳", "۹۴", "۹۵", "۹۶", "۹۷", "۹۸", "۹۹"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(crate::__data::LC_TIME::ALT_DIGITS_0);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `Some("\u{202b}%A %Oe %B %Oy، ساعت %OH:%OM:%OS (%Z)\u{202c}")`
+    pub const DATE_FMT: Option<&str> = Some("\u{202b}%A %Oe %B %Oy، ساعت %OH:%OM:%OS (%Z)\u{202c}");
+    /// `&["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه"]`
+    pub const DAY: &[&str] = &["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چهارشنبه", "پنجشنبه", "جمعه", "شنبه"];
+    /// `"%Oy/%Om/%Od"`
+    pub const D_FMT: &str = "%Oy/%Om/%Od";
+    /// `"\u{202b}%A %Oe %B %Oy، %OH:%OM:%OS\u{202c}"`
+    pub const D_T_FMT: &str = "\u{202b}%A %Oe %B %Oy، %OH:%OM:%OS\u{202c}";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(7)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(7);
+    /// `&["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اكتبر", "نوامبر", "دسامبر"]`
+    pub const MON: &[&str] = &["ژانویه", "فوریه", "مارس", "آوریل", "مه", "ژوئن", "ژوئیه", "اوت", "سپتامبر", "اكتبر", "نوامبر", "دسامبر"];
+    /// `"%OH:%OM:%OS"`
+    pub const T_FMT: &str = "%OH:%OM:%OS";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
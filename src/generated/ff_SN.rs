@@ -0,0 +1,198 @@
+pub mod LC_ADDRESS {
+    /// `Some("SEN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SEN");
+    /// `Some("SN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("SN");
+    /// `Some("SN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("SN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Senegaal")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Senegaal");
+    /// `Some(686)`
+    pub const COUNTRY_NUM: Option<i64> = Some(686);
+    /// `Some("SN")`
+    pub const COUNTRY_POST: Option<&str> = Some("SN");
+    /// `Some("ff")`
+    pub const LANG_AB: Option<&str> = Some("ff");
+    /// `Some("ful")`
+    pub const LANG_LIB: Option<&str> = Some("ful");
+    /// `Some("Pulaar")`
+    pub const LANG_NAME: Option<&str> = Some("Pulaar");
+    /// `Some("ful")`
+    pub const LANG_TERM: Option<&str> = Some("ful");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Ibrahima Sarr")`
+    pub const CONTACT: Option<&str> = Some("Ibrahima Sarr");
+    /// `Some("2011-03-28")`
+    pub const DATE: Option<&str> = Some("2011-03-28");
+    /// `Some("admin@pulaar-fulfulde.org")`
+    pub const EMAIL: Option<&str> = Some("admin@pulaar-fulfulde.org");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Fulah")`
+    pub const LANGUAGE: Option<&str> = Some("Fulah");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Pular-Fulfulde.org")`
+    pub const SOURCE: Option<&str> = Some("Pular-Fulfulde.org");
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Senegal")`
+    pub const TERRITORY: Option<&str> = Some("Senegal");
+    /// `Some("Fulah locale for Senegal")`
+    pub const TITLE: Option<&str> = Some("Fulah locale for Senegal");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNaA]"`
+    pub const NOEXPR: &str = "^[-0nNaA]";
+    /// `Some("Alaa")`
+    pub const NOSTR: Option<&str> = Some("Alaa");
+    /// `"^[+1yYeE]"`
+    pub const YESEXPR: &str = "^[+1yYeE]";
+    /// `Some("Eey")`
+    pub const YESSTR: Option<&str> = Some("Eey");
+}
+pub mod LC_MONETARY {
+    /// `"CFA"`
+    pub const CURRENCY_SYMBOL: &str = "CFA";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"XOF "`
+    pub const INT_CURR_SYMBOL: &str = "XOF ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("221")`
+    pub const INT_PREFIX: Option<&str> = Some("221");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dew", "aaɓ", "maw", "nje", "naa", "mwd", "hbi"]`
+    pub const ABDAY: &[&str] = &["dew", "aaɓ", "maw", "nje", "naa", "mwd", "hbi"];
+    /// `&["sii", "col", "mbo", "see", "duu", "kor", "mor", "juk", "slt", "yar", "jol", "bow"]`
+    pub const ABMON: &[&str] = &["sii", "col", "mbo", "see", "duu", "kor", "mor", "juk", "slt", "yar", "jol", "bow"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["subaka", "kikiiɗe"]`
+    pub const AM_PM: &[&str] = &["subaka", "kikiiɗe"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dewo", "aaɓnde", "mawbaare", "njeslaare", "naasaande", "mawnde", "hoore-biir"]`
+    pub const DAY: &[&str] = &["dewo", "aaɓnde", "mawbaare", "njeslaare", "naasaande", "mawnde", "hoore-biir"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %R %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %R %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["siilo", "colte", "mbooy", "seeɗto", "duujal", "korse", "morso", "juko", "siilto", "yarkomaa", "jolal", "bowte"]`
+    pub const MON: &[&str] = &["siilo", "colte", "mbooy", "seeɗto", "duujal", "korse", "morso", "juko", "siilto", "yarkomaa", "jolal", "bowte"];
+    /// `"%R"`
+    pub const T_FMT: &str = "%R";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `None`
+        pub const T_FMT_AMPM: Option<&str> = None;
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,186 @@
+pub mod LC_ADDRESS {
+    /// `Some("PHL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PHL");
+    /// `Some("PH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PH");
+    /// `Some("RP")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RP");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Pilipinas")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Pilipinas");
+    /// `Some(608)`
+    pub const COUNTRY_NUM: Option<i64> = Some(608);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("Filipino")`
+    pub const LANG_NAME: Option<&str> = Some("Filipino");
+    /// `Some("fil")`
+    pub const LANG_TERM: Option<&str> = Some("fil");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_14);
+    /// `Some("Rene Torres, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Rene Torres, Pablo Saratxaga");
+    /// `Some("2005-02-02")`
+    pub const DATE: Option<&str> = Some("2005-02-02");
+    /// `Some("rgtorre@rocketmail.com, pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("rgtorre@rocketmail.com, pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Filipino")`
+    pub const LANGUAGE: Option<&str> = Some("Filipino");
+    /// `Some("0.5")`
+    pub const REVISION: Option<&str> = Some("0.5");
+    /// `Some("Rene Torres")`
+    pub const SOURCE: Option<&str> = Some("Rene Torres");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Philippines")`
+    pub const TERRITORY: Option<&str> = Some("Philippines");
+    /// `Some("Filipino language locale for Philippines")`
+    pub const TITLE: Option<&str> = Some("Filipino language locale for Philippines");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0hHnN]"`
+    pub const NOEXPR: &str = "^[-0hHnN]";
+    /// `Some("hindi")`
+    pub const NOSTR: Option<&str> = Some("hindi");
+    /// `"^[+1oOyY]"`
+    pub const YESEXPR: &str = "^[+1oOyY]";
+    /// `Some("oo")`
+    pub const YESSTR: Option<&str> = Some("oo");
+}
+pub mod LC_MONETARY {
+    /// `"₱"`
+    pub const CURRENCY_SYMBOL: &str = "₱";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"PHP "`
+    pub const INT_CURR_SYMBOL: &str = "PHP ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::tl_PH::LC_NUMERIC;
+pub use super::en_PH::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Lin", "Lun", "Mar", "Miy", "Huw", "Biy", "Sab"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_3;
+    /// `&["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"]`
+    pub const ABMON: &[&str] = &["Ene", "Peb", "Mar", "Abr", "May", "Hun", "Hul", "Ago", "Set", "Okt", "Nob", "Dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["N.U.", "N.H."]`
+    pub const AM_PM: &[&str] = &["N.U.", "N.H."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Linggo", "Lunes", "Martes", "Miyerkoles", "Huwebes", "Biyernes", "Sabado"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_4;
+    /// `"%m/%d/%y"`
+    pub const D_FMT: &str = "%m/%d/%y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"]`
+    pub const MON: &[&str] = &["Enero", "Pebrero", "Marso", "Abril", "Mayo", "Hunyo", "Hulyo", "Agosto", "Setyembre", "Oktubre", "Nobyembre", "Disyembre"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("FRO")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FRO");
+    /// `Some("FO")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FO");
+    /// `Some("FO")`
+    pub const COUNTRY_CAR: Option<&str> = Some("FO");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Føroyar")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Føroyar");
+    /// `Some(234)`
+    pub const COUNTRY_NUM: Option<i64> = Some(234);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("fo")`
+    pub const LANG_AB: Option<&str> = Some("fo");
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("føroyskt")`
+    pub const LANG_NAME: Option<&str> = Some("føroyskt");
+    /// `Some("fao")`
+    pub const LANG_TERM: Option<&str> = Some("fao");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Kollegievej 6, DK-2920 Charlottenlund, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Kollegievej 6, DK-2920 Charlottenlund, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Faroese")`
+    pub const LANGUAGE: Option<&str> = Some("Faroese");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Danish Standards Association")`
+    pub const SOURCE: Option<&str> = Some("Danish Standards Association");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Faroe Islands")`
+    pub const TERRITORY: Option<&str> = Some("Faroe Islands");
+    /// `Some("Faroese locale for Faroe Islands")`
+    pub const TITLE: Option<&str> = Some("Faroese locale for Faroe Islands");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0Nn]"`
+    pub const NOEXPR: &str = "^[-0Nn]";
+    /// `Some("nei")`
+    pub const NOSTR: Option<&str> = Some("nei");
+    /// `"^[+1JjYy]"`
+    pub const YESEXPR: &str = "^[+1JjYy]";
+    /// `Some("já")`
+    pub const YESSTR: Option<&str> = Some("já");
+}
+pub use super::da_DK::LC_MONETARY;
+pub use super::da_DK::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("298")`
+    pub const INT_PREFIX: Option<&str> = Some("298");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["sun", "mán", "týs", "mik", "hós", "frí", "ley"]`
+    pub const ABDAY: &[&str] = &["sun", "mán", "týs", "mik", "hós", "frí", "ley"];
+    /// `&["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"]`
+    pub const ABMON: &[&str] = &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "okt", "nov", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sunnudagur", "mánadagur", "týsdagur", "mikudagur", "hósdagur", "fríggjadagur", "leygardagur"]`
+    pub const DAY: &[&str] = &["sunnudagur", "mánadagur", "týsdagur", "mikudagur", "hósdagur", "fríggjadagur", "leygardagur"];
+    /// `"%d/%m-%Y"`
+    pub const D_FMT: &str = "%d/%m-%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"]`
+    pub const MON: &[&str] = &["januar", "februar", "mars", "apríl", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("CHE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CHE");
+    /// `Some("CH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CH");
+    /// `Some("CH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CH");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Suisse")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Suisse");
+    /// `Some(756)`
+    pub const COUNTRY_NUM: Option<i64> = Some(756);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("fr")`
+    pub const LANG_AB: Option<&str> = Some("fr");
+    /// `Some("fre")`
+    pub const LANG_LIB: Option<&str> = Some("fre");
+    /// `Some("français")`
+    pub const LANG_NAME: Option<&str> = Some("français");
+    /// `Some("fra")`
+    pub const LANG_TERM: Option<&str> = Some("fra");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Swiss French")`
+    pub const LANGUAGE: Option<&str> = Some("Swiss French");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Switzerland")`
+    pub const TERRITORY: Option<&str> = Some("Switzerland");
+    /// `Some("French locale for Switzerland")`
+    pub const TITLE: Option<&str> = Some("French locale for Switzerland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("non")`
+    pub const NOSTR: Option<&str> = Some("non");
+    /// `"^[+1OojJsSyY]"`
+    pub const YESEXPR: &str = "^[+1OojJsSyY]";
+    /// `Some("oui")`
+    pub const YESSTR: Option<&str> = Some("oui");
+}
+pub use super::de_CH::LC_MONETARY;
+pub use super::de_CH::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("41")`
+    pub const INT_PREFIX: Option<&str> = Some("41");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dim", "lun", "mar", "mer", "jeu", "ven", "sam"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_8;
+    /// `&["jan", "fév", "mar", "avr", "mai", "jun", "jui", "aoû", "sep", "oct", "nov", "déc"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_18;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_9;
+    /// `"%d. %m. %y"`
+    pub const D_FMT: &str = "%d. %m. %y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_16;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("ITA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ITA");
+    /// `Some("IT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IT");
+    /// `Some("I")`
+    pub const COUNTRY_CAR: Option<&str> = Some("I");
+    /// `Some("978-88,979-12")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-88,979-12");
+    /// `Some("Italie")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Italie");
+    /// `Some(380)`
+    pub const COUNTRY_NUM: Option<i64> = Some(380);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("furlan")`
+    pub const LANG_NAME: Option<&str> = Some("furlan");
+    /// `Some("fur")`
+    pub const LANG_TERM: Option<&str> = Some("fur");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2004-04-26")`
+    pub const DATE: Option<&str> = Some("2004-04-26");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Friulian")`
+    pub const LANGUAGE: Option<&str> = Some("Friulian");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Italy")`
+    pub const TERRITORY: Option<&str> = Some("Italy");
+    /// `Some("Furlan locale for Italy")`
+    pub const TITLE: Option<&str> = Some("Furlan locale for Italy");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("no")`
+    pub const NOSTR: Option<&str> = Some("no");
+    /// `"^[+1sSjJoOyY]"`
+    pub const YESEXPR: &str = "^[+1sSjJoOyY]";
+    /// `Some("sì")`
+    pub const YESSTR: Option<&str> = Some("sì");
+}
+pub use super::it_IT::LC_MONETARY;
+pub use super::it_IT::LC_NUMERIC;
+pub use super::it_IT::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Dom", "Lun", "Mar", "Mie", "Joi", "Vin", "Sab"]`
+    pub const ABDAY: &[&str] = &["Dom", "Lun", "Mar", "Mie", "Joi", "Vin", "Sab"];
+    /// `&["Zen", "Fev", "Mar", "Avr", "Mai", "Jug", "Lui", "Avo", "Set", "Otu", "Nov", "Dic"]`
+    pub const ABMON: &[&str] = &["Zen", "Fev", "Mar", "Avr", "Mai", "Jug", "Lui", "Avo", "Set", "Otu", "Nov", "Dic"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Domenie", "Lunis", "Martars", "Miercus", "Joibe", "Vinars", "Sabide"]`
+    pub const DAY: &[&str] = &["Domenie", "Lunis", "Martars", "Miercus", "Joibe", "Vinars", "Sabide"];
+    /// `"%d. %m. %y"`
+    pub const D_FMT: &str = "%d. %m. %y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Zenâr", "Fevrâr", "Març", "Avrîl", "Mai", "Jugn", "Lui", "Avost", "Setembar", "Otubar", "Novembar", "Dicembar"]`
+    pub const MON: &[&str] = &["Zenâr", "Fevrâr", "Març", "Avrîl", "Mai", "Jugn", "Lui", "Avost", "Setembar", "Otubar", "Novembar", "Dicembar"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("DEU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DEU");
+    /// `Some("DE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DE");
+    /// `Some("D")`
+    pub const COUNTRY_CAR: Option<&str> = Some("D");
+    /// `Some("3")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("3");
+    /// `Some("Dútslân")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Dútslân");
+    /// `Some(276)`
+    pub const COUNTRY_NUM: Option<i64> = Some(276);
+    /// `Some("D")`
+    pub const COUNTRY_POST: Option<&str> = Some("D");
+    /// `Some("fy")`
+    pub const LANG_AB: Option<&str> = Some("fy");
+    /// `Some("fry")`
+    pub const LANG_LIB: Option<&str> = Some("fry");
+    /// `Some("Frysk")`
+    pub const LANG_NAME: Option<&str> = Some("Frysk");
+    /// `Some("fry")`
+    pub const LANG_TERM: Option<&str> = Some("fry");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_5);
+    /// `Some("Kenneth Christiansen, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Kenneth Christiansen, Pablo Saratxaga");
+    /// `Some("2003-11-30")`
+    pub const DATE: Option<&str> = Some("2003-11-30");
+    /// `Some("kenneth@gnu.org, pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("kenneth@gnu.org, pablo@mandriva.com");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Western Frisian")`
+    pub const LANGUAGE: Option<&str> = Some("Western Frisian");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("information from Kenneth Christiansen")`
+    pub const SOURCE: Option<&str> = Some("information from Kenneth Christiansen");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Germany")`
+    pub const TERRITORY: Option<&str> = Some("Germany");
+    /// `Some("Sater Frisian and North Frisian Locale for Germany")`
+    pub const TITLE: Option<&str> = Some("Sater Frisian and North Frisian Locale for Germany");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nee")`
+    pub const NOSTR: Option<&str> = Some("nee");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub use super::de_DE::LC_MONETARY;
+pub use super::de_DE::LC_NUMERIC;
+pub use super::de_DE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sdg", "Mdg", "Dsg", "Mwk", "Ddg", "Fdg", "Swd"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_4;
+    /// `&["Jan", "Feb", "Moz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Now", "Dez"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_4;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sinndag", "Mondag", "Dingsdag", "Meddwäakj", "Donnadag", "Friedag", "Sinnowend"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_5;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d. %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jaunuwoa", "Februwoa", "Moaz", "Aprell", "Mai", "Juni", "Juli", "August", "Septamba", "Oktoba", "Nowamba", "Dezamba"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_5;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("NLD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NLD");
+    /// `Some("NL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NL");
+    /// `Some("NL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Nederlân")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Nederlân");
+    /// `Some(528)`
+    pub const COUNTRY_NUM: Option<i64> = Some(528);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("fy")`
+    pub const LANG_AB: Option<&str> = Some("fy");
+    /// `Some("fry")`
+    pub const LANG_LIB: Option<&str> = Some("fry");
+    /// `Some("West-Frysk")`
+    pub const LANG_NAME: Option<&str> = Some("West-Frysk");
+    /// `Some("fry")`
+    pub const LANG_TERM: Option<&str> = Some("fry");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_8);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-08-13")`
+    pub const DATE: Option<&str> = Some("2006-08-13");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Western Frisian")`
+    pub const LANGUAGE: Option<&str> = Some("Western Frisian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Netherlands")`
+    pub const TERRITORY: Option<&str> = Some("Netherlands");
+    /// `Some("Frisian locale for the Netherlands")`
+    pub const TITLE: Option<&str> = Some("Frisian locale for the Netherlands");
+}
+pub use super::nl_NL::LC_MESSAGES;
+pub use super::nl_NL::LC_MONETARY;
+pub use super::nl_NL::LC_NUMERIC;
+pub use super::nl_NL::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sn", "Mo", "Ti", "Wo", "To", "Fr", "Sn"]`
+    pub const ABDAY: &[&str] = &["Sn", "Mo", "Ti", "Wo", "To", "Fr", "Sn"];
+    /// `&["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mrt", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Snein", "Moandei", "Tiisdei", "Woansdei", "Tongersdei", "Freed", "Sneon"]`
+    pub const DAY: &[&str] = &["Snein", "Moandei", "Tiisdei", "Woansdei", "Tongersdei", "Freed", "Sneon"];
+    /// `"%d-%m-%y"`
+    pub const D_FMT: &str = "%d-%m-%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"]`
+    pub const MON: &[&str] = &["Jannewaris", "Febrewaris", "Maart", "April", "Maaie", "Juny", "July", "Augustus", "Septimber", "Oktober", "Novimber", "Desimber"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
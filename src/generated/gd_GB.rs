@@ -0,0 +1,142 @@
+pub mod LC_ADDRESS {
+    /// `Some("GBR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("GBR");
+    /// `Some("GB")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("GB");
+    /// `Some("GB")`
+    pub const COUNTRY_CAR: Option<&str> = Some("GB");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("An Rìoghachd Aonaichte")`
+    pub const COUNTRY_NAME: Option<&str> = Some("An Rìoghachd Aonaichte");
+    /// `Some(826)`
+    pub const COUNTRY_NUM: Option<i64> = Some(826);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("gd")`
+    pub const LANG_AB: Option<&str> = Some("gd");
+    /// `Some("gla")`
+    pub const LANG_LIB: Option<&str> = Some("gla");
+    /// `Some("Gàidhlig")`
+    pub const LANG_NAME: Option<&str> = Some("Gàidhlig");
+    /// `Some("gla")`
+    pub const LANG_TERM: Option<&str> = Some("gla");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%S%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%S%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1/2, 47 Wilton Street, Glasgow G20 6RT, UK")`
+    pub const ADDRESS: Option<&str> = Some("1/2, 47 Wilton Street, Glasgow G20 6RT, UK");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Michael Bauer")`
+    pub const CONTACT: Option<&str> = Some("Michael Bauer");
+    /// `Some("2013-04-25")`
+    pub const DATE: Option<&str> = Some("2013-04-25");
+    /// `Some("fios@akerbeltz.org")`
+    pub const EMAIL: Option<&str> = Some("fios@akerbeltz.org");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Scottish Gaelic")`
+    pub const LANGUAGE: Option<&str> = Some("Scottish Gaelic");
+    /// `Some("1.2")`
+    pub const REVISION: Option<&str> = Some("1.2");
+    /// `Some("Akerbeltz")`
+    pub const SOURCE: Option<&str> = Some("Akerbeltz");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("United Kingdom")`
+    pub const TERRITORY: Option<&str> = Some("United Kingdom");
+    /// `Some("Scots Gaelic language locale for Great Britain")`
+    pub const TITLE: Option<&str> = Some("Scots Gaelic language locale for Great Britain");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0cCnN]"`
+    pub const NOEXPR: &str = "^[-0cCnN]";
+    /// `Some("chan eil")`
+    pub const NOSTR: Option<&str> = Some("chan eil");
+    /// `"^[+1tTyY]"`
+    pub const YESEXPR: &str = "^[+1tTyY]";
+    /// `Some("tha")`
+    pub const YESSTR: Option<&str> = Some("tha");
+}
+pub use super::en_GB::LC_MONETARY;
+pub use super::en_GB::LC_NUMERIC;
+pub use super::en_GB::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["DiD", "DiL", "DiM", "DiC", "Dia", "Dih", "DiS"]`
+    pub const ABDAY: &[&str] = &["DiD", "DiL", "DiM", "DiC", "Dia", "Dih", "DiS"];
+    /// `&["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"]`
+    pub const ABMON: &[&str] = &["Faoi", "Gearr", "Màrt", "Gibl", "Cèit", "Ògmh", "Iuch", "Lùna", "Sult", "Dàmh", "Samh", "Dùbh"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["Am Faoilleach", "An Gearran", "Am Màrt", "An Giblean", "An Cèitean", "An t-Ògmhios", "An t-Iuchar", "An Lùnastal", "An t-Sultain", "An Dàmhair", "An t-Samhain", "An Dùbhlachd"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["Am Faoilleach", "An Gearran", "Am Màrt", "An Giblean", "An Cèitean", "An t-Ògmhios", "An t-Iuchar", "An Lùnastal", "An t-Sultain", "An Dàmhair", "An t-Samhain", "An Dùbhlachd"]);
+    /// `&["m", "f"]`
+    pub const AM_PM: &[&str] = &["m", "f"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["DiDòmhnaich", "DiLuain", "DiMàirt", "DiCiadain", "DiarDaoin", "DihAoine", "DiSathairne"]`
+    pub const DAY: &[&str] = &["DiDòmhnaich", "DiLuain", "DiMàirt", "DiCiadain", "DiarDaoin", "DihAoine", "DiSathairne"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["dhen Fhaoilleach", "dhen Ghearran", "dhen Mhàrt", "dhen Ghiblean", "dhen Chèitean", "dhen Ògmhios", "dhen Iuchar", "dhen Lùnastal", "dhen t-Sultain", "dhen Dàmhair", "dhen t-Samhain", "dhen Dùbhlachd"]`
+    pub const MON: &[&str] = &["dhen Fhaoilleach", "dhen Ghearran", "dhen Mhàrt", "dhen Ghiblean", "dhen Chèitean", "dhen Ògmhios", "dhen Iuchar", "dhen Lùnastal", "dhen t-Sultain", "dhen Dàmhair", "dhen t-Samhain", "dhen Dùbhlachd"];
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ભારત")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ભારત");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("gu")`
+    pub const LANG_AB: Option<&str> = Some("gu");
+    /// `Some("guj")`
+    pub const LANG_LIB: Option<&str> = Some("guj");
+    /// `Some("ગ\u{ac1}જરાતી")`
+    pub const LANG_NAME: Option<&str> = Some("ગ\u{ac1}જરાતી");
+    /// `Some("guj")`
+    pub const LANG_TERM: Option<&str> = Some("guj");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-09-14")`
+    pub const DATE: Option<&str> = Some("2004-09-14");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Gujarati")`
+    pub const LANGUAGE: Option<&str> = Some("Gujarati");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("IndLinux.org")`
+    pub const SOURCE: Option<&str> = Some("IndLinux.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Gujarati Language Locale For India")`
+    pub const TITLE: Option<&str> = Some("Gujarati Language Locale For India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNન]"`
+    pub const NOEXPR: &str = "^[-0nNન]";
+    /// `Some("નહી\u{a82}")`
+    pub const NOSTR: Option<&str> = Some("નહી\u{a82}");
+    /// `"^[+1yYહ]"`
+    pub const YESEXPR: &str = "^[+1yYહ]";
+    /// `Some("હા")`
+    pub const YESSTR: Option<&str> = Some("હા");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["રવિ", "સોમ", "મ\u{a82}ગળ", "બ\u{ac1}ધ", "ગ\u{ac1}ર\u{ac1}", "શ\u{ac1}ક\u{acd}ર", "શનિ"]`
+    pub const ABDAY: &[&str] = &["રવિ", "સોમ", "મ\u{a82}ગળ", "બ\u{ac1}ધ", "ગ\u{ac1}ર\u{ac1}", "શ\u{ac1}ક\u{acd}ર", "શનિ"];
+    /// `&["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"]`
+    pub const ABMON: &[&str] = &["જાન\u{acd}ય\u{ac1}", "ફ\u{ac7}બ\u{acd}ર\u{ac1}", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}", "ઑક\u{acd}ટો", "નવ\u{ac7}", "ડિસ\u{ac7}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["સવાર\u{ac7}", "સા\u{a82}જ\u{ac7}"]`
+    pub const AM_PM: &[&str] = &["સવાર\u{ac7}", "સા\u{a82}જ\u{ac7}"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["રવિવાર", "સોમવાર", "મ\u{a82}ગળવાર", "બ\u{ac1}ધવાર", "ગ\u{ac1}ર\u{ac1}વાર", "શ\u{ac1}ક\u{acd}રવાર", "શનિવાર"]`
+    pub const DAY: &[&str] = &["રવિવાર", "સોમવાર", "મ\u{a82}ગળવાર", "બ\u{ac1}ધવાર", "ગ\u{ac1}ર\u{ac1}વાર", "શ\u{ac1}ક\u{acd}રવાર", "શનિવાર"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"]`
+    pub const MON: &[&str] = &["જાન\u{acd}ય\u{ac1}આરી", "ફ\u{ac7}બ\u{acd}ર\u{ac1}આરી", "માર\u{acd}ચ", "એપ\u{acd}રિલ", "મ\u{ac7}", "જ\u{ac2}ન", "જ\u{ac1}લાઈ", "ઑગસ\u{acd}ટ", "સપ\u{acd}ટ\u{ac7}મ\u{acd}બર", "ઑક\u{acd}ટોબર", "નવ\u{ac7}મ\u{acd}બર", "ડિસ\u{ac7}મ\u{acd}બર"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
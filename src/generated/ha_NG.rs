@@ -0,0 +1,144 @@
+pub mod LC_ADDRESS {
+    /// `Some("NGA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NGA");
+    /// `Some("NG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NG");
+    /// `Some("WAN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("WAN");
+    /// `Some("978")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978");
+    /// `Some("Najeriya")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Najeriya");
+    /// `Some(566)`
+    pub const COUNTRY_NUM: Option<i64> = Some(566);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ha")`
+    pub const LANG_AB: Option<&str> = Some("ha");
+    /// `Some("hau")`
+    pub const LANG_LIB: Option<&str> = Some("hau");
+    /// `Some("Hausa")`
+    pub const LANG_NAME: Option<&str> = Some("Hausa");
+    /// `Some("hau")`
+    pub const LANG_TERM: Option<&str> = Some("hau");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-02-01")`
+    pub const DATE: Option<&str> = Some("2006-02-01");
+    /// `Some("pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandriva.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Hausa")`
+    pub const LANGUAGE: Option<&str> = Some("Hausa");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nigeria")`
+    pub const TERRITORY: Option<&str> = Some("Nigeria");
+    /// `Some("Hausa locale for Nigeria")`
+    pub const TITLE: Option<&str> = Some("Hausa locale for Nigeria");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0bBaAnN]"`
+    pub const NOEXPR: &str = "^[-0bBaAnN]";
+    /// `Some("aʼa")`
+    pub const NOSTR: Option<&str> = Some("aʼa");
+    /// `"^[+1TtiIYy]"`
+    pub const YESEXPR: &str = "^[+1TtiIYy]";
+    /// `Some("i")`
+    pub const YESSTR: Option<&str> = Some("i");
+}
+pub use super::en_NG::LC_MONETARY;
+pub use super::en_NG::LC_NUMERIC;
+pub use super::en_NG::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Lah", "Lit", "Tal", "Lar", "Alh", "Jum", "Asa"]`
+    pub const ABDAY: &[&str] = &["Lah", "Lit", "Tal", "Lar", "Alh", "Jum", "Asa"];
+    /// `&["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"]`
+    pub const ABMON: &[&str] = &["Jan", "Fab", "Mar", "Afi", "May", "Yun", "Yul", "Agu", "Sat", "Okt", "Nuw", "Dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Lahadi", "Litini", "Talata", "Laraba", "Alhamis", "Juma'a", "Asabar"]`
+    pub const DAY: &[&str] = &["Lahadi", "Litini", "Talata", "Laraba", "Alhamis", "Juma'a", "Asabar"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"ranar %A, %d ga %B cikin %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "ranar %A, %d ga %B cikin %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"]`
+    pub const MON: &[&str] = &["Janairu", "Faburairu", "Maris", "Afirilu", "Mayu", "Yuni", "Yuli", "Agusta", "Satumba", "Oktoba", "Nuwamba", "Disamba"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"ranar %A, %d ga %B cikin %r %Z"`
+        pub const D_T_FMT: &str = "ranar %A, %d ga %B cikin %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
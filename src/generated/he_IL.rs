@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("ISR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ISR");
+    /// `Some("IL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IL");
+    /// `Some("IL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ישראל")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ישראל");
+    /// `Some(376)`
+    pub const COUNTRY_NUM: Option<i64> = Some(376);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("he")`
+    pub const LANG_AB: Option<&str> = Some("he");
+    /// `Some("heb")`
+    pub const LANG_LIB: Option<&str> = Some("heb");
+    /// `Some("עברית")`
+    pub const LANG_NAME: Option<&str> = Some("עברית");
+    /// `Some("heb")`
+    pub const LANG_TERM: Option<&str> = Some("heb");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sankt Jo/rgens Alle 8, DK-1615 Ko/benhavn V, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Sankt Jo/rgens Alle 8, DK-1615 Ko/benhavn V, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Hebrew")`
+    pub const LANGUAGE: Option<&str> = Some("Hebrew");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Israel")`
+    pub const TERRITORY: Option<&str> = Some("Israel");
+    /// `Some("Hebrew locale for Israel")`
+    pub const TITLE: Option<&str> = Some("Hebrew locale for Israel");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNל]"`
+    pub const NOEXPR: &str = "^[-0nNל]";
+    /// `Some("לא")`
+    pub const NOSTR: Option<&str> = Some("לא");
+    /// `"^[+1yYכ]"`
+    pub const YESEXPR: &str = "^[+1yYכ]";
+    /// `Some("כן")`
+    pub const YESSTR: Option<&str> = Some("כן");
+}
+pub mod LC_MONETARY {
+    /// `"₪"`
+    pub const CURRENCY_SYMBOL: &str = "₪";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"ILS "`
+    pub const INT_CURR_SYMBOL: &str = "ILS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const N_SIGN_POSN: i64 = 2;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const P_SIGN_POSN: i64 = 2;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("972")`
+    pub const INT_PREFIX: Option<&str> = Some("972");
+    /// `Some("00,012,013,014,018")`
+    pub const INT_SELECT: Option<&str> = Some("00,012,013,014,018");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["א'", "ב'", "ג'", "ד'", "ה'", "ו'", "ש'"]`
+    pub const ABDAY: &[&str] = &["א'", "ב'", "ג'", "ד'", "ה'", "ו'", "ש'"];
+    /// `&["ינו", "פבר", "מרץ", "אפר", "מאי", "יונ", "יול", "אוג", "ספט", "אוק", "נוב", "דצמ"]`
+    pub const ABMON: &[&str] = &["ינו", "פבר", "מרץ", "אפר", "מאי", "יונ", "יול", "אוג", "ספט", "אוק", "נוב", "דצמ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ראשון", "שני", "שלישי", "רביעי", "חמישי", "שישי", "שבת"]`
+    pub const DAY: &[&str] = &["ראשון", "שני", "שלישי", "רביעי", "חמישי", "שישי", "שבת"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%Z %H:%M:%S %Y %b %d %a"`
+    pub const D_T_FMT: &str = "%Z %H:%M:%S %Y %b %d %a";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `Some(1)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(1);
+    /// `&["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"]`
+    pub const MON: &[&str] = &["ינואר", "פברואר", "מרץ", "אפריל", "מאי", "יוני", "יולי", "אוגוסט", "ספטמבר", "אוקטובר", "נובמבר", "דצמבר"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %P"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %P";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %P")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %P");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,190 @@
+pub mod LC_ADDRESS {
+    /// `Some("FJI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FJI");
+    /// `Some("FJ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FJ");
+    /// `Some("FJI")`
+    pub const COUNTRY_CAR: Option<&str> = Some("FJI");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Fiji")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Fiji");
+    /// `Some(242)`
+    pub const COUNTRY_NUM: Option<i64> = Some(242);
+    /// `Some("")`
+    pub const COUNTRY_POST: Option<&str> = Some("");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("")`
+    pub const LANG_LIB: Option<&str> = Some("");
+    /// `Some("हिन\u{94d}दी")`
+    pub const LANG_NAME: Option<&str> = Some("हिन\u{94d}दी");
+    /// `Some("hif")`
+    pub const LANG_TERM: Option<&str> = Some("hif");
+    /// `Some("%a%N%h%t%s%N%N%T%c")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%h%t%s%N%N%T%c");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-03-21")`
+    pub const DATE: Option<&str> = Some("2017-03-21");
+    /// `Some("akhilesh.k@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Fiji Hindi")`
+    pub const LANGUAGE: Option<&str> = Some("Fiji Hindi");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Fiji")`
+    pub const TERRITORY: Option<&str> = Some("Fiji");
+    /// `Some("Fiji Hindi (Latin) language locale for Fiji")`
+    pub const TITLE: Option<&str> = Some("Fiji Hindi (Latin) language locale for Fiji");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Nahi")`
+    pub const NOSTR: Option<&str> = Some("Nahi");
+    /// `"^[+1hHyY]"`
+    pub const YESEXPR: &str = "^[+1hHyY]";
+    /// `Some("Haan")`
+    pub const YESSTR: Option<&str> = Some("Haan");
+}
+pub mod LC_MONETARY {
+    /// `"FJ$"`
+    pub const CURRENCY_SYMBOL: &str = "FJ$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"FJD "`
+    pub const INT_CURR_SYMBOL: &str = "FJD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::hi_IN::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("679")`
+    pub const INT_PREFIX: Option<&str> = Some("679");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["Ravi", "Som", "Mangal", "Budh", "Guru", "Shukra", "Shani"]`
+    pub const ABDAY: &[&str] = &["Ravi", "Som", "Mangal", "Budh", "Guru", "Shukra", "Shani"];
+    /// `&["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_3;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["Purvahan", "Aparaahna"]`
+    pub const AM_PM: &[&str] = &["Purvahan", "Aparaahna"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A %d %b %Y %I:%M:%S %p %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%A %d %b %Y %I:%M:%S %p %Z");
+    /// `&["Ravivar", "Somvar", "Mangalvar", "Budhvar", "Guruvar", "Shukravar", "Shanivar"]`
+    pub const DAY: &[&str] = &["Ravivar", "Somvar", "Mangalvar", "Budhvar", "Guruvar", "Shukravar", "Shanivar"];
+    /// `"%A %d %b %Y"`
+    pub const D_FMT: &str = "%A %d %b %Y";
+    /// `"%A %d %b %Y %I:%M:%S %p"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_4;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
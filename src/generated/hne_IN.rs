@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `None`
+    pub const LANG_LIB: Option<&str> = None;
+    /// `Some("छत\u{94d}तीसगढ\u{93c}ी")`
+    pub const LANG_NAME: Option<&str> = Some("छत\u{94d}तीसगढ\u{93c}ी");
+    /// `Some("hne")`
+    pub const LANG_TERM: Option<&str> = Some("hne");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Marisfot III, Marigold Premises, East-Wing, Kalyaninagar, Pune, India-411014")`
+    pub const ADDRESS: Option<&str> = Some("Marisfot III, Marigold Premises, East-Wing, Kalyaninagar, Pune, India-411014");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2008-12-03")`
+    pub const DATE: Option<&str> = Some("2008-12-03");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Chhattisgarhi")`
+    pub const LANGUAGE: Option<&str> = Some("Chhattisgarhi");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat, Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat, Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Chhattisgarhi language locale for India")`
+    pub const TITLE: Option<&str> = Some("Chhattisgarhi language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("नइ")`
+    pub const NOSTR: Option<&str> = Some("नइ");
+    /// `"^[+1yYह]"`
+    pub const YESEXPR: &str = "^[+1yYह]";
+    /// `Some("हव")`
+    pub const YESSTR: Option<&str> = Some("हव");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["इत", "सोम", "म\u{902}ग", "ब\u{941}ध", "बिर", "स\u{941}क", "सनि"]`
+    pub const ABDAY: &[&str] = &["इत", "सोम", "म\u{902}ग", "ब\u{941}ध", "बिर", "स\u{941}क", "सनि"];
+    /// `&["जन", "फर", "मार\u{94d}च", "अप", "मई", "ज\u{942}न", "ज\u{941}ला", "अग", "सित", "अकट\u{942}", "नव", "दिस"]`
+    pub const ABMON: &[&str] = &["जन", "फर", "मार\u{94d}च", "अप", "मई", "ज\u{942}न", "ज\u{941}ला", "अग", "सित", "अकट\u{942}", "नव", "दिस"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["बिहिनिया\u{901}", "म\u{902}झनिया\u{901}"]`
+    pub const AM_PM: &[&str] = &["बिहिनिया\u{901}", "म\u{902}झनिया\u{901}"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["इतवार", "सोमवार", "म\u{902}गलवार", "ब\u{941}धवार", "बिरसपत", "स\u{941}करवार", "सनिवार"]`
+    pub const DAY: &[&str] = &["इतवार", "सोमवार", "म\u{902}गलवार", "ब\u{941}धवार", "बिरसपत", "स\u{941}करवार", "सनिवार"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "अपर\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितमबर", "अकट\u{942}बर", "नवमबर", "दिसमबर"]`
+    pub const MON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "अपर\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितमबर", "अकट\u{942}बर", "नवमबर", "दिसमबर"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("HRV")`
+    pub const COUNTRY_AB3: Option<&str> = Some("HRV");
+    /// `Some("HR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("HR");
+    /// `Some("HR")`
+    pub const COUNTRY_CAR: Option<&str> = Some("HR");
+    /// `Some("978-953")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-953");
+    /// `Some("Hrvatska")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Hrvatska");
+    /// `Some(191)`
+    pub const COUNTRY_NUM: Option<i64> = Some(191);
+    /// `Some("HR")`
+    pub const COUNTRY_POST: Option<&str> = Some("HR");
+    /// `Some("hr")`
+    pub const LANG_AB: Option<&str> = Some("hr");
+    /// `Some("hrv")`
+    pub const LANG_LIB: Option<&str> = Some("hrv");
+    /// `Some("hrvatski")`
+    pub const LANG_NAME: Option<&str> = Some("hrvatski");
+    /// `Some("hrv")`
+    pub const LANG_TERM: Option<&str> = Some("hrv");
+    /// `Some("%f%N%d%N%a%N%s %h%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%d%N%a%N%s %h%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2016-04-16")`
+    pub const DATE: Option<&str> = Some("2016-04-16");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Croatian")`
+    pub const LANGUAGE: Option<&str> = Some("Croatian");
+    /// `Some("2.3")`
+    pub const REVISION: Option<&str> = Some("2.3");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Croatia")`
+    pub const TERRITORY: Option<&str> = Some("Croatia");
+    /// `Some("Croatian locale for Croatia")`
+    pub const TITLE: Option<&str> = Some("Croatian locale for Croatia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ne")`
+    pub const NOSTR: Option<&str> = Some("ne");
+    /// `"^[+1dDyY]"`
+    pub const YESEXPR: &str = "^[+1dDyY]";
+    /// `Some("da")`
+    pub const YESSTR: Option<&str> = Some("da");
+}
+pub mod LC_MONETARY {
+    /// `"kn"`
+    pub const CURRENCY_SYMBOL: &str = "kn";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"HRK "`
+    pub const INT_CURR_SYMBOL: &str = "HRK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("385")`
+    pub const INT_PREFIX: Option<&str> = Some("385");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ned", "pon", "uto", "sri", "čet", "pet", "sub"]`
+    pub const ABDAY: &[&str] = &["ned", "pon", "uto", "sri", "čet", "pet", "sub"];
+    /// `&["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"]`
+    pub const ABMON: &[&str] = &["sij", "velj", "ožu", "tra", "svi", "lip", "srp", "kol", "ruj", "lis", "stu", "pro"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["siječanj", "veljača", "ožujak", "travanj", "svibanj", "lipanj", "srpanj", "kolovoz", "rujan", "listopad", "studeni", "prosinac"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["siječanj", "veljača", "ožujak", "travanj", "svibanj", "lipanj", "srpanj", "kolovoz", "rujan", "listopad", "studeni", "prosinac"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %e.%m.%Y.  %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %e.%m.%Y.  %H:%M:%S %Z");
+    /// `&["nedjelja", "ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota"]`
+    pub const DAY: &[&str] = &["nedjelja", "ponedjeljak", "utorak", "srijeda", "četvrtak", "petak", "subota"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%A, %d. %B %Y. %T %Z"`
+    pub const D_T_FMT: &str = "%A, %d. %B %Y. %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `Some(2)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(2);
+    /// `&["siječnja", "veljače", "ožujka", "travnja", "svibnja", "lipnja", "srpnja", "kolovoza", "rujna", "listopada", "studenoga", "prosinca"]`
+    pub const MON: &[&str] = &["siječnja", "veljače", "ožujka", "travnja", "svibnja", "lipnja", "srpnja", "kolovoza", "rujna", "listopada", "studenoga", "prosinca"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
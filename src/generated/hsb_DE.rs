@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("DEU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DEU");
+    /// `Some("DE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DE");
+    /// `Some("D")`
+    pub const COUNTRY_CAR: Option<&str> = Some("D");
+    /// `Some("3")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("3");
+    /// `Some("Němska")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Němska");
+    /// `Some(276)`
+    pub const COUNTRY_NUM: Option<i64> = Some(276);
+    /// `Some("D")`
+    pub const COUNTRY_POST: Option<&str> = Some("D");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("hsb")`
+    pub const LANG_LIB: Option<&str> = Some("hsb");
+    /// `Some("hornjoserbšćina")`
+    pub const LANG_NAME: Option<&str> = Some("hornjoserbšćina");
+    /// `Some("hsb")`
+    pub const LANG_TERM: Option<&str> = Some("hsb");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Andrzej Krzysztofowicz")`
+    pub const CONTACT: Option<&str> = Some("Andrzej Krzysztofowicz");
+    /// `Some("2004-09-09")`
+    pub const DATE: Option<&str> = Some("2004-09-09");
+    /// `Some("ankry@mif.pg.gda.pl")`
+    pub const EMAIL: Option<&str> = Some("ankry@mif.pg.gda.pl");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Upper Sorbian")`
+    pub const LANGUAGE: Option<&str> = Some("Upper Sorbian");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Information from Michael Wolf")`
+    pub const SOURCE: Option<&str> = Some("Information from Michael Wolf");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Germany")`
+    pub const TERRITORY: Option<&str> = Some("Germany");
+    /// `Some("Upper Sorbian locale for Germany")`
+    pub const TITLE: Option<&str> = Some("Upper Sorbian locale for Germany");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ně")`
+    pub const NOSTR: Option<&str> = Some("ně");
+    /// `"^[+1hHyY]"`
+    pub const YESEXPR: &str = "^[+1hHyY]";
+    /// `Some("haj")`
+    pub const YESSTR: Option<&str> = Some("haj");
+}
+pub use super::de_DE::LC_MONETARY;
+pub use super::de_DE::LC_NUMERIC;
+pub use super::de_DE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Nj", "Pó", "Wu", "Sr", "Št", "Pj", "So"]`
+    pub const ABDAY: &[&str] = &["Nj", "Pó", "Wu", "Sr", "Št", "Pj", "So"];
+    /// `&["Jan", "Feb", "Měr", "Apr", "Mej", "Jun", "Jul", "Awg", "Sep", "Okt", "Now", "Dec"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Měr", "Apr", "Mej", "Jun", "Jul", "Awg", "Sep", "Okt", "Now", "Dec"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["Januar", "Februar", "Měrc", "Apryl", "Meja", "Junij", "Julij", "Awgust", "September", "Oktober", "Nowember", "December"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["Januar", "Februar", "Měrc", "Apryl", "Meja", "Junij", "Julij", "Awgust", "September", "Oktober", "Nowember", "December"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Njedźela", "Póndźela", "Wutora", "Srjeda", "Štvórtk", "Pjatk", "Sobota"]`
+    pub const DAY: &[&str] = &["Njedźela", "Póndźela", "Wutora", "Srjeda", "Štvórtk", "Pjatk", "Sobota"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januara", "februara", "měrca", "apryla", "meje", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"]`
+    pub const MON: &[&str] = &["januara", "februara", "měrca", "apryla", "meje", "junija", "julija", "awgusta", "septembra", "oktobra", "nowembra", "decembra"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
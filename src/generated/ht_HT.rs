@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("HTI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("HTI");
+    /// `Some("HT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("HT");
+    /// `Some("RH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RH");
+    /// `Some("99935")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("99935");
+    /// `Some("Ayiti")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Ayiti");
+    /// `Some(332)`
+    pub const COUNTRY_NUM: Option<i64> = Some(332);
+    /// `Some("HT")`
+    pub const COUNTRY_POST: Option<&str> = Some("HT");
+    /// `Some("ht")`
+    pub const LANG_AB: Option<&str> = Some("ht");
+    /// `Some("hat")`
+    pub const LANG_LIB: Option<&str> = Some("hat");
+    /// `Some("Kreyòl ayisyen")`
+    pub const LANG_NAME: Option<&str> = Some("Kreyòl ayisyen");
+    /// `Some("hat")`
+    pub const LANG_TERM: Option<&str> = Some("hat");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %R%N%%Z %t%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %R%N%%Z %t%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("sugarlabs.org")`
+    pub const CONTACT: Option<&str> = Some("sugarlabs.org");
+    /// `Some("2013-08-09")`
+    pub const DATE: Option<&str> = Some("2013-08-09");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Haitian Creole")`
+    pub const LANGUAGE: Option<&str> = Some("Haitian Creole");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Sugar Labs / OLPC")`
+    pub const SOURCE: Option<&str> = Some("Sugar Labs / OLPC");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Haiti")`
+    pub const TERRITORY: Option<&str> = Some("Haiti");
+    /// `Some("Kreyol locale for Haiti")`
+    pub const TITLE: Option<&str> = Some("Kreyol locale for Haiti");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Non")`
+    pub const NOSTR: Option<&str> = Some("Non");
+    /// `"^[+1wWoOyY]"`
+    pub const YESEXPR: &str = "^[+1wWoOyY]";
+    /// `Some("Wi")`
+    pub const YESSTR: Option<&str> = Some("Wi");
+}
+pub mod LC_MONETARY {
+    /// `"g"`
+    pub const CURRENCY_SYMBOL: &str = "g";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"HTG "`
+    pub const INT_CURR_SYMBOL: &str = "HTG ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("509")`
+    pub const INT_PREFIX: Option<&str> = Some("509");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dim", "len", "mad", "mèk", "jed", "van", "sam"]`
+    pub const ABDAY: &[&str] = &["dim", "len", "mad", "mèk", "jed", "van", "sam"];
+    /// `&["jan", "fev", "mas", "avr", "me", "jen", "jiy", "out", "sep", "okt", "nov", "des"]`
+    pub const ABMON: &[&str] = &["jan", "fev", "mas", "avr", "me", "jen", "jiy", "out", "sep", "okt", "nov", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dimanch", "lendi", "madi", "mèkredi", "jedi", "vandredi", "samdi"]`
+    pub const DAY: &[&str] = &["dimanch", "lendi", "madi", "mèkredi", "jedi", "vandredi", "samdi"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["janvye", "fevriye", "mas", "avril", "me", "jen", "jiyè", "out", "septanm", "oktòb", "novanm", "desanm"]`
+    pub const MON: &[&str] = &["janvye", "fevriye", "mas", "avril", "me", "jen", "jiyè", "out", "septanm", "oktòb", "novanm", "desanm"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
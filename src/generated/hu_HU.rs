@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("HUN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("HUN");
+    /// `Some("HU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("HU");
+    /// `Some("H")`
+    pub const COUNTRY_CAR: Option<&str> = Some("H");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Magyarország")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Magyarország");
+    /// `Some(348)`
+    pub const COUNTRY_NUM: Option<i64> = Some(348);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("hu")`
+    pub const LANG_AB: Option<&str> = Some("hu");
+    /// `Some("hun")`
+    pub const LANG_LIB: Option<&str> = Some("hun");
+    /// `Some("magyar")`
+    pub const LANG_NAME: Option<&str> = Some("magyar");
+    /// `Some("hun")`
+    pub const LANG_TERM: Option<&str> = Some("hun");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sankt Jorgens Alle 8, DK-1615 Kobenhavn V, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Sankt Jorgens Alle 8, DK-1615 Kobenhavn V, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2001-01-29")`
+    pub const DATE: Option<&str> = Some("2001-01-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Hungarian")`
+    pub const LANGUAGE: Option<&str> = Some("Hungarian");
+    /// `Some("4.7")`
+    pub const REVISION: Option<&str> = Some("4.7");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Hungary")`
+    pub const TERRITORY: Option<&str> = Some("Hungary");
+    /// `Some("Hungarian locale for Hungary")`
+    pub const TITLE: Option<&str> = Some("Hungarian locale for Hungary");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nem")`
+    pub const NOSTR: Option<&str> = Some("nem");
+    /// `"^[+1IiYy]"`
+    pub const YESEXPR: &str = "^[+1IiYy]";
+    /// `Some("igen")`
+    pub const YESSTR: Option<&str> = Some("igen");
+}
+pub mod LC_MONETARY {
+    /// `"Ft"`
+    pub const CURRENCY_SYMBOL: &str = "Ft";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"HUF "`
+    pub const INT_CURR_SYMBOL: &str = "HUF ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(1);
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("36")`
+    pub const INT_PREFIX: Option<&str> = Some("36");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["v", "h", "k", "sze", "cs", "p", "szo"]`
+    pub const ABDAY: &[&str] = &["v", "h", "k", "sze", "cs", "p", "szo"];
+    /// `&["jan", "febr", "márc", "ápr", "máj", "jún", "júl", "aug", "szept", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = &["jan", "febr", "márc", "ápr", "máj", "jún", "júl", "aug", "szept", "okt", "nov", "dec"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y. %b. %-e., %A, %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y. %b. %-e., %A, %H:%M:%S %Z");
+    /// `&["vasárnap", "hétfő", "kedd", "szerda", "csütörtök", "péntek", "szombat"]`
+    pub const DAY: &[&str] = &["vasárnap", "hétfő", "kedd", "szerda", "csütörtök", "péntek", "szombat"];
+    /// `"%Y-%m-%d"`
+    pub const D_FMT: &str = "%Y-%m-%d";
+    /// `"%Y. %b. %-e., %A, %H:%M:%S %Z"`
+    pub const D_T_FMT: &str = "%Y. %b. %-e., %A, %H:%M:%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"]`
+    pub const MON: &[&str] = &["január", "február", "március", "április", "május", "június", "július", "augusztus", "szeptember", "október", "november", "december"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%H:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%H:%M:%S";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%H:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%H:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,202 @@
+pub mod LC_ADDRESS {
+    /// `Some("ARM")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ARM");
+    /// `Some("AM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AM");
+    /// `Some("AM")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AM");
+    /// `Some("978-9939,978-99930,978-99941")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-9939,978-99930,978-99941");
+    /// `Some("Հայաստան")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Հայաստան");
+    /// `Some(51)`
+    pub const COUNTRY_NUM: Option<i64> = Some(51);
+    /// `Some("AM")`
+    pub const COUNTRY_POST: Option<&str> = Some("AM");
+    /// `Some("hy")`
+    pub const LANG_AB: Option<&str> = Some("hy");
+    /// `Some("arm")`
+    pub const LANG_LIB: Option<&str> = Some("arm");
+    /// `Some("հայերեն")`
+    pub const LANG_NAME: Option<&str> = Some("հայերեն");
+    /// `Some("hye")`
+    pub const LANG_TERM: Option<&str> = Some("hye");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_4);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2001-01-26")`
+    pub const DATE: Option<&str> = Some("2001-01-26");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Armenian")`
+    pub const LANGUAGE: Option<&str> = Some("Armenian");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("http://www.freenet.am/armscii")`
+    pub const SOURCE: Option<&str> = Some("http://www.freenet.am/armscii");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Armenia")`
+    pub const TERRITORY: Option<&str> = Some("Armenia");
+    /// `Some("Armenian language locale for Armenia")`
+    pub const TITLE: Option<&str> = Some("Armenian language locale for Armenia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNոՈ]"`
+    pub const NOEXPR: &str = "^[-0nNոՈ]";
+    /// `Some("ոչ")`
+    pub const NOSTR: Option<&str> = Some("ոչ");
+    /// `"^[+1yYsSաԱ]"`
+    pub const YESEXPR: &str = "^[+1yYsSաԱ]";
+    /// `Some("այո")`
+    pub const YESSTR: Option<&str> = Some("այո");
+}
+pub mod LC_MONETARY {
+    /// `"֏"`
+    pub const CURRENCY_SYMBOL: &str = "֏";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"AMD "`
+    pub const INT_CURR_SYMBOL: &str = "AMD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("374")`
+    pub const INT_PREFIX: Option<&str> = Some("374");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Կրկ", "Երկ", "Երք", "Չրք", "Հնգ", "Ուր", "Շբթ"]`
+    pub const ABDAY: &[&str] = &["Կրկ", "Երկ", "Երք", "Չրք", "Հնգ", "Ուր", "Շբթ"];
+    /// `&["Հնվ", "Փտր", "Մար", "Ապր", "Մայ", "Հնս", "Հլս", "Օգս", "Սեպ", "Հոկ", "Նմբ", "Դեկ"]`
+    pub const ABMON: &[&str] = &["Հնվ", "Փտր", "Մար", "Ապր", "Մայ", "Հնս", "Հլս", "Օգս", "Սեպ", "Հոկ", "Նմբ", "Դեկ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["հունվար", "փետրվար", "մարտ", "ապրիլ", "մայիս", "հունիս", "հուլիս", "օգոստոս", "սեպտեմբեր", "հոկտեմբեր", "նոյեմբեր", "դեկտեմբեր"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["հունվար", "փետրվար", "մարտ", "ապրիլ", "մայիս", "հունիս", "հուլիս", "օգոստոս", "սեպտեմբեր", "հոկտեմբեր", "նոյեմբեր", "դեկտեմբեր"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Կիրակի", "Երկուշաբթի", "Երեքշաբթի", "Չորեքշաբթի", "Հինգշաբթի", "Ուրբաթ", "Շաբաթ"]`
+    pub const DAY: &[&str] = &["Կիրակի", "Երկուշաբթի", "Երեքշաբթի", "Չորեքշաբթի", "Հինգշաբթի", "Ուրբաթ", "Շաբաթ"];
+    /// `"%m/%d/%y"`
+    pub const D_FMT: &str = "%m/%d/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["հունվարի", "փետրվարի", "մարտի", "ապրիլի", "մայիսի", "հունիսի", "հուլիսի", "օգոստոսի", "սեպտեմբերի", "հոկտեմբերի", "նոյեմբերի", "դեկտեմբերի"]`
+    pub const MON: &[&str] = &["հունվարի", "փետրվարի", "մարտի", "ապրիլի", "մայիսի", "հունիսի", "հուլիսի", "օգոստոսի", "սեպտեմբերի", "հոկտեմբերի", "նոյեմբերի", "դեկտեմբերի"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
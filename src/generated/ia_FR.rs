@@ -0,0 +1,139 @@
+pub mod LC_ADDRESS {
+    /// `Some("FRA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FRA");
+    /// `Some("FR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FR");
+    /// `Some("F")`
+    pub const COUNTRY_CAR: Option<&str> = Some("F");
+    /// `Some("979-10")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("979-10");
+    /// `Some("Francia")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Francia");
+    /// `Some(250)`
+    pub const COUNTRY_NUM: Option<i64> = Some(250);
+    /// `Some("F")`
+    pub const COUNTRY_POST: Option<&str> = Some("F");
+    /// `Some("ia")`
+    pub const LANG_AB: Option<&str> = Some("ia");
+    /// `Some("ina")`
+    pub const LANG_LIB: Option<&str> = Some("ina");
+    /// `Some("interlingua")`
+    pub const LANG_NAME: Option<&str> = Some("interlingua");
+    /// `Some("ina")`
+    pub const LANG_TERM: Option<&str> = Some("ina");
+    /// `Some("%f%N%a%N%d%N%b%N%s%t%h%t%e%t%r%N%z%t%T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s%t%h%t%e%t%r%N%z%t%T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_9);
+    /// `Some("Nik Kalach")`
+    pub const CONTACT: Option<&str> = Some("Nik Kalach");
+    /// `Some("2013-03-01")`
+    pub const DATE: Option<&str> = Some("2013-03-01");
+    /// `Some("nikka@fedoraproject.org")`
+    pub const EMAIL: Option<&str> = Some("nikka@fedoraproject.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Interlingua")`
+    pub const LANGUAGE: Option<&str> = Some("Interlingua");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Fedora Project")`
+    pub const SOURCE: Option<&str> = Some("Fedora Project");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("France")`
+    pub const TERRITORY: Option<&str> = Some("France");
+    /// `Some("Interlingua locale for France")`
+    pub const TITLE: Option<&str> = Some("Interlingua locale for France");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("No")`
+    pub const NOSTR: Option<&str> = Some("No");
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `Some("Si")`
+    pub const YESSTR: Option<&str> = Some("Si");
+}
+pub use super::fr_FR::LC_MONETARY;
+pub use super::fr_FR::LC_NUMERIC;
+pub use super::fr_FR::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["dom", "lun", "mar", "mer", "jov", "ven", "sab"]`
+    pub const ABDAY: &[&str] = &["dom", "lun", "mar", "mer", "jov", "ven", "sab"];
+    /// `&["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"]`
+    pub const ABMON: &[&str] = &["jan", "feb", "mar", "apr", "mai", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dominica", "lunedi", "martedi", "mercuridi", "jovedi", "venerdi", "sabbato"]`
+    pub const DAY: &[&str] = &["dominica", "lunedi", "martedi", "mercuridi", "jovedi", "venerdi", "sabbato"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"]`
+    pub const MON: &[&str] = &["januario", "februario", "martio", "april", "maio", "junio", "julio", "augusto", "septembre", "octobre", "novembre", "decembre"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,141 @@
+pub mod LC_ADDRESS {
+    /// `Some("NGA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NGA");
+    /// `Some("NG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NG");
+    /// `Some("WAN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("WAN");
+    /// `Some("978")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978");
+    /// `Some("Naịjịrịa")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Naịjịrịa");
+    /// `Some(566)`
+    pub const COUNTRY_NUM: Option<i64> = Some(566);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ig")`
+    pub const LANG_AB: Option<&str> = Some("ig");
+    /// `Some("ibo")`
+    pub const LANG_LIB: Option<&str> = Some("ibo");
+    /// `Some("Asụsụ Igbo")`
+    pub const LANG_NAME: Option<&str> = Some("Asụsụ Igbo");
+    /// `Some("ibo")`
+    pub const LANG_TERM: Option<&str> = Some("ibo");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2005-12-14")`
+    pub const DATE: Option<&str> = Some("2005-12-14");
+    /// `Some("pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandriva.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Igbo")`
+    pub const LANGUAGE: Option<&str> = Some("Igbo");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nigeria")`
+    pub const TERRITORY: Option<&str> = Some("Nigeria");
+    /// `Some("Igbo locale for Nigeria")`
+    pub const TITLE: Option<&str> = Some("Igbo locale for Nigeria");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0MmNn]"`
+    pub const NOEXPR: &str = "^[-0MmNn]";
+    /// `Some("Mba")`
+    pub const NOSTR: Option<&str> = Some("Mba");
+    /// `"^[+1EeIiYy]"`
+    pub const YESEXPR: &str = "^[+1EeIiYy]";
+    /// `Some("Eye")`
+    pub const YESSTR: Option<&str> = Some("Eye");
+}
+pub use super::en_NG::LC_MONETARY;
+pub use super::en_NG::LC_NUMERIC;
+pub use super::en_NG::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["sọn", "mọn", "tuz", "wen", "tọs", "fra", "sat"]`
+    pub const ABDAY: &[&str] = &["sọn", "mọn", "tuz", "wen", "tọs", "fra", "sat"];
+    /// `&["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"]`
+    pub const ABMON: &[&str] = &["Jen", "Feb", "Maa", "Epr", "Mee", "Juu", "Jul", "Ọgọ", "Sep", "Ọkt", "Nov", "Dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sọnde", "mọnde", "tuzde", "wenzde", "tọsde", "fraịde", "satọde"]`
+    pub const DAY: &[&str] = &["sọnde", "mọnde", "tuzde", "wenzde", "tọsde", "fraịde", "satọde"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%A, %d %B %Y %T %Z"`
+    pub const D_T_FMT: &str = "%A, %d %B %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jenụwarị", "Febrụwarị", "Maachị", "Eprel", "Mee", "Juun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"]`
+    pub const MON: &[&str] = &["Jenụwarị", "Febrụwarị", "Maachị", "Eprel", "Mee", "Juun", "Julaị", "Ọgọọst", "Septemba", "Ọktoba", "Novemba", "Disemba"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
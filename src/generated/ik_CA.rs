@@ -0,0 +1,144 @@
+pub mod LC_ADDRESS {
+    /// `Some("CAN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CAN");
+    /// `Some("CA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CA");
+    /// `Some("CDN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CDN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Kanada")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Kanada");
+    /// `Some(124)`
+    pub const COUNTRY_NUM: Option<i64> = Some(124);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ik")`
+    pub const LANG_AB: Option<&str> = Some("ik");
+    /// `Some("ipk")`
+    pub const LANG_LIB: Option<&str> = Some("ipk");
+    /// `Some("Iñupiatun")`
+    pub const LANG_NAME: Option<&str> = Some("Iñupiatun");
+    /// `Some("ipk")`
+    pub const LANG_TERM: Option<&str> = Some("ipk");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-08-01")`
+    pub const DATE: Option<&str> = Some("2004-08-01");
+    /// `Some("pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandriva.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Inupiaq")`
+    pub const LANGUAGE: Option<&str> = Some("Inupiaq");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Canada")`
+    pub const TERRITORY: Option<&str> = Some("Canada");
+    /// `Some("Inupiaq locale for Canada")`
+    pub const TITLE: Option<&str> = Some("Inupiaq locale for Canada");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNqQ]"`
+    pub const NOEXPR: &str = "^[-0nNqQ]";
+    /// `Some("qañaa")`
+    pub const NOSTR: Option<&str> = Some("qañaa");
+    /// `"^[+1yYiIaA]"`
+    pub const YESEXPR: &str = "^[+1yYiIaA]";
+    /// `Some("aa")`
+    pub const YESSTR: Option<&str> = Some("aa");
+}
+pub use super::en_CA::LC_MONETARY;
+pub use super::en_CA::LC_NUMERIC;
+pub use super::en_CA::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Min", "Sav", "Ila", "Qit", "Sis", "Tal", "Maq"]`
+    pub const ABDAY: &[&str] = &["Min", "Sav", "Ila", "Qit", "Sis", "Tal", "Maq"];
+    /// `&["Sñt", "Sñs", "Pan", "Qil", "Sup", "Iġñ", "Itc", "Tiñ", "Ami", "Sik", "Nip", "Siq"]`
+    pub const ABMON: &[&str] = &["Sñt", "Sñs", "Pan", "Qil", "Sup", "Iġñ", "Itc", "Tiñ", "Ami", "Sik", "Nip", "Siq"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Minġuiqsioiq", "Savałłiq", "Ilaqtchiioiq", "Qitchiioiq", "Sisamiioiq", "Tallimmiioiq", "Maqinġuoiq"]`
+    pub const DAY: &[&str] = &["Minġuiqsioiq", "Savałłiq", "Ilaqtchiioiq", "Qitchiioiq", "Sisamiioiq", "Tallimmiioiq", "Maqinġuoiq"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Siqiññaatchiaq", "Siqiññaasrugruk", "Paniqsiqsiivik", "Qilġich Tatqiat", "Suppivik", "Iġñivik", "Itchavik", "Tiññivik", "Amiġaiqsivik", "Sikkuvik", "Nippivik", "Siqiñġiḷaq"]`
+    pub const MON: &[&str] = &["Siqiññaatchiaq", "Siqiññaasrugruk", "Paniqsiqsiivik", "Qilġich Tatqiat", "Suppivik", "Iġñivik", "Itchavik", "Tiññivik", "Amiġaiqsivik", "Sikkuvik", "Nippivik", "Siqiñġiḷaq"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
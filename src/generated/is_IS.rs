@@ -0,0 +1,198 @@
+pub mod LC_ADDRESS {
+    /// `Some("ISL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ISL");
+    /// `Some("IS")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IS");
+    /// `Some("IS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Ísland")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Ísland");
+    /// `Some(352)`
+    pub const COUNTRY_NUM: Option<i64> = Some(352);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("is")`
+    pub const LANG_AB: Option<&str> = Some("is");
+    /// `Some("ice")`
+    pub const LANG_LIB: Option<&str> = Some("ice");
+    /// `Some("íslenska")`
+    pub const LANG_NAME: Option<&str> = Some("íslenska");
+    /// `Some("isl")`
+    pub const LANG_TERM: Option<&str> = Some("isl");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Keldnaholt-ITI', IS-112 Reykjavi'k, Iceland")`
+    pub const ADDRESS: Option<&str> = Some("Keldnaholt-ITI', IS-112 Reykjavi'k, Iceland");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Icelandic")`
+    pub const LANGUAGE: Option<&str> = Some("Icelandic");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Stadlarad I'slands")`
+    pub const SOURCE: Option<&str> = Some("Stadlarad I'slands");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Iceland")`
+    pub const TERRITORY: Option<&str> = Some("Iceland");
+    /// `Some("Icelandic locale for Iceland")`
+    pub const TITLE: Option<&str> = Some("Icelandic locale for Iceland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nei")`
+    pub const NOSTR: Option<&str> = Some("nei");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("já")`
+    pub const YESSTR: Option<&str> = Some("já");
+}
+pub mod LC_MONETARY {
+    /// `"kr"`
+    pub const CURRENCY_SYMBOL: &str = "kr";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"ISK "`
+    pub const INT_CURR_SYMBOL: &str = "ISK ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("354")`
+    pub const INT_PREFIX: Option<&str> = Some("354");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["sun", "mán", "þri", "mið", "fim", "fös", "lau"]`
+    pub const ABDAY: &[&str] = &["sun", "mán", "þri", "mið", "fim", "fös", "lau"];
+    /// `&["jan", "feb", "mar", "apr", "maí", "jún", "júl", "ágú", "sep", "okt", "nóv", "des"]`
+    pub const ABMON: &[&str] = &["jan", "feb", "mar", "apr", "maí", "jún", "júl", "ágú", "sep", "okt", "nóv", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["fh", "eh"]`
+    pub const AM_PM: &[&str] = &["fh", "eh"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sunnudagur", "mánudagur", "þriðjudagur", "miðvikudagur", "fimmtudagur", "föstudagur", "laugardagur"]`
+    pub const DAY: &[&str] = &["sunnudagur", "mánudagur", "þriðjudagur", "miðvikudagur", "fimmtudagur", "föstudagur", "laugardagur"];
+    /// `"%a %e.%b %Y"`
+    pub const D_FMT: &str = "%a %e.%b %Y";
+    /// `"%a %e.%b %Y, %T %Z"`
+    pub const D_T_FMT: &str = "%a %e.%b %Y, %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"]`
+    pub const MON: &[&str] = &["janúar", "febrúar", "mars", "apríl", "maí", "júní", "júlí", "ágúst", "september", "október", "nóvember", "desember"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("CHE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CHE");
+    /// `Some("CH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CH");
+    /// `Some("CH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CH");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Svizzera")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Svizzera");
+    /// `Some(756)`
+    pub const COUNTRY_NUM: Option<i64> = Some(756);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("it")`
+    pub const LANG_AB: Option<&str> = Some("it");
+    /// `Some("ita")`
+    pub const LANG_LIB: Option<&str> = Some("ita");
+    /// `Some("italiano")`
+    pub const LANG_NAME: Option<&str> = Some("italiano");
+    /// `Some("ita")`
+    pub const LANG_TERM: Option<&str> = Some("ita");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Italian")`
+    pub const LANGUAGE: Option<&str> = Some("Italian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Switzerland")`
+    pub const TERRITORY: Option<&str> = Some("Switzerland");
+    /// `Some("Italian locale for Switzerland")`
+    pub const TITLE: Option<&str> = Some("Italian locale for Switzerland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("no")`
+    pub const NOSTR: Option<&str> = Some("no");
+    /// `"^[+1sSjJoOyY]"`
+    pub const YESEXPR: &str = "^[+1sSjJoOyY]";
+    /// `Some("sì")`
+    pub const YESSTR: Option<&str> = Some("sì");
+}
+pub use super::de_CH::LC_MONETARY;
+pub use super::de_CH::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("41")`
+    pub const INT_PREFIX: Option<&str> = Some("41");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dom", "lun", "mar", "mer", "gio", "ven", "sab"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_10;
+    /// `&["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_13;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d %b %Y, %T, %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d %b %Y, %T, %Z");
+    /// `&["domenica", "lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_10;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %-d %b %Y, %T"`
+    pub const D_T_FMT: &str = "%a %-d %b %Y, %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_11;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
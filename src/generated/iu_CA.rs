@@ -0,0 +1,144 @@
+pub mod LC_ADDRESS {
+    /// `Some("CAN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CAN");
+    /// `Some("CA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CA");
+    /// `Some("CDN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CDN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ᑲᓇᑕ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ᑲᓇᑕ");
+    /// `Some(124)`
+    pub const COUNTRY_NUM: Option<i64> = Some(124);
+    /// `Some("CA")`
+    pub const COUNTRY_POST: Option<&str> = Some("CA");
+    /// `Some("iu")`
+    pub const LANG_AB: Option<&str> = Some("iu");
+    /// `Some("iku")`
+    pub const LANG_LIB: Option<&str> = Some("iku");
+    /// `Some("ᐃᓄᒃᑎᑐᑦ")`
+    pub const LANG_NAME: Option<&str> = Some("ᐃᓄᒃᑎᑐᑦ");
+    /// `Some("iku")`
+    pub const LANG_TERM: Option<&str> = Some("iku");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_5);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2001-05-04")`
+    pub const DATE: Option<&str> = Some("2001-05-04");
+    /// `Some("pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandriva.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Inuktitut")`
+    pub const LANGUAGE: Option<&str> = Some("Inuktitut");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Canada")`
+    pub const TERRITORY: Option<&str> = Some("Canada");
+    /// `Some("Inuktitut language locale for Nunavut, Canada")`
+    pub const TITLE: Option<&str> = Some("Inuktitut language locale for Nunavut, Canada");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yYsS]"`
+    pub const YESEXPR: &str = "^[+1yYsS]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::en_CA::LC_MONETARY;
+pub use super::en_CA::LC_NUMERIC;
+pub use super::en_CA::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ᓈ", "ᓇ", "ᓕ", "ᐱ", "ᕿ", "ᐅ", "ᓯ"]`
+    pub const ABDAY: &[&str] = &["ᓈ", "ᓇ", "ᓕ", "ᐱ", "ᕿ", "ᐅ", "ᓯ"];
+    /// `&["ᔮᓄ", "ᕕᕗ", "ᒪᔅ", "ᐃᐳ", "ᒪᐃ", "ᔪᓂ", "ᔪᓚ", "ᐊᒋ", "ᓯᑎ", "ᐊᑦ", "ᓄᕕ", "ᑎᓯ"]`
+    pub const ABMON: &[&str] = &["ᔮᓄ", "ᕕᕗ", "ᒪᔅ", "ᐃᐳ", "ᒪᐃ", "ᔪᓂ", "ᔪᓚ", "ᐊᒋ", "ᓯᑎ", "ᐊᑦ", "ᓄᕕ", "ᑎᓯ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ᓈᑦᑎᖑᔭᕐᕕᒃ", "ᓇᒡᒐᔾᔭᐅ", "ᓇᒡᒐᔾᔭᐅᓕᖅᑭᑦ", "ᐱᖓᓲᓕᖅᓯᐅᑦ", "ᕿᑎᖅᑰᑦ", "ᐅᓪᓗᕈᓘᑐᐃᓇᖅ", "ᓯᕙᑖᕕᒃ"]`
+    pub const DAY: &[&str] = &["ᓈᑦᑎᖑᔭᕐᕕᒃ", "ᓇᒡᒐᔾᔭᐅ", "ᓇᒡᒐᔾᔭᐅᓕᖅᑭᑦ", "ᐱᖓᓲᓕᖅᓯᐅᑦ", "ᕿᑎᖅᑰᑦ", "ᐅᓪᓗᕈᓘᑐᐃᓇᖅ", "ᓯᕙᑖᕕᒃ"];
+    /// `"%m/%d/%y"`
+    pub const D_FMT: &str = "%m/%d/%y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ᔮᓄᐊᓕ", "ᕕᕗᐊᓕ", "ᒪᔅᓯ", "ᐃᐳᓗ", "ᒪᐃ", "ᔪᓂ", "ᔪᓚᐃ", "ᐊᒋᓯ", "ᓯᑎᕙ", "ᐊᑦᑐᕙ", "ᓄᕕᕙ", "ᑎᓯᕝᕙ"]`
+    pub const MON: &[&str] = &["ᔮᓄᐊᓕ", "ᕕᕗᐊᓕ", "ᒪᔅᓯ", "ᐃᐳᓗ", "ᒪᐃ", "ᔪᓂ", "ᔪᓚᐃ", "ᐊᒋᓯ", "ᓯᑎᕙ", "ᐊᑦᑐᕙ", "ᓄᕕᕙ", "ᑎᓯᕝᕙ"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
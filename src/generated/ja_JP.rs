@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("JPN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("JPN");
+    /// `Some("JP")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("JP");
+    /// `Some("J")`
+    pub const COUNTRY_CAR: Option<&str> = Some("J");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("日本")`
+    pub const COUNTRY_NAME: Option<&str> = Some("日本");
+    /// `Some(392)`
+    pub const COUNTRY_NUM: Option<i64> = Some(392);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ja")`
+    pub const LANG_AB: Option<&str> = Some("ja");
+    /// `Some("jpn")`
+    pub const LANG_LIB: Option<&str> = Some("jpn");
+    /// `Some("日本語")`
+    pub const LANG_NAME: Option<&str> = Some("日本語");
+    /// `Some("jpn")`
+    pub const LANG_TERM: Option<&str> = Some("jpn");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-20")`
+    pub const DATE: Option<&str> = Some("2000-07-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Japanese")`
+    pub const LANGUAGE: Option<&str> = Some("Japanese");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("HANATAKA, Shinya, hanataka@abyss.rim.or.jp")`
+    pub const SOURCE: Option<&str> = Some("HANATAKA, Shinya, hanataka@abyss.rim.or.jp");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Japan")`
+    pub const TERRITORY: Option<&str> = Some("Japan");
+    /// `Some("Japanese language locale for Japan")`
+    pub const TITLE: Option<&str> = Some("Japanese language locale for Japan");
+}
+pub mod LC_MESSAGES {
+    /// `"^([-0nNｎＮ]|いいえ|イイエ)"`
+    pub const NOEXPR: &str = "^([-0nNｎＮ]|いいえ|イイエ)";
+    /// `Some("いいえ")`
+    pub const NOSTR: Option<&str> = Some("いいえ");
+    /// `"^([+1yYｙＹ]|はい|ハイ)"`
+    pub const YESEXPR: &str = "^([+1yYｙＹ]|はい|ハイ)";
+    /// `Some("はい")`
+    pub const YESSTR: Option<&str> = Some("はい");
+}
+pub mod LC_MONETARY {
+    /// `"￥"`
+    pub const CURRENCY_SYMBOL: &str = "￥";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"JPY "`
+    pub const INT_CURR_SYMBOL: &str = "JPY ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(2)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(2);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(2)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(2);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("81")`
+    pub const INT_PREFIX: Option<&str> = Some("81");
+    /// `Some("010")`
+    pub const INT_SELECT: Option<&str> = Some("010");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["日", "月", "火", "水", "木", "金", "土"]`
+    pub const ABDAY: &[&str] = &["日", "月", "火", "水", "木", "金", "土"];
+    /// `&[" 1月", " 2月", " 3月", " 4月", " 5月", " 6月", " 7月", " 8月", " 9月", "10月", "11月", "12月"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_0;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "二十", "二十一", "二十二", "二十三", "二十四", "二十五", "二十六", "二十七", "二十八", "二十九", "三十", "三十一", "三十二", "三十三", "三十四", "三十五", "三十六", "三十七", "三十八", "三十九", "四十", "四十一", "四十二", "四十三", "四十四", "四十五", "四十六", "四十七", "四十八", "四十九", "五十", "五十一", "五十二", "五十三", "五十四", "五十五", "五十六", "五十七", "五十八", "五十九", "六十", "六十一", "六十二", "六十三", "六十四", "六十五", "六十六", "六十七", "六十八", "六十九", "七十", "七十一", "七十二", "七十三", "七十四", "七十五", "七十六", "七十七", "七十八", "七十九", "八十", "八十一", "八十二", "八十三", "八十四", "八十五", "八十六", "八十七", "八十八", "八十九", "九十", "九十一", "九十二", "九十三", "九十四", "九十五", "九十六", "九十七", "九十八", "九十九"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(&["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "二十", "二十一", "二十二", "二十三", "二十四", "二十五", "二十六", "二十七", "二十八", "二十九", "三十", "三十一", "三十二", "三十三", "三十四", "三十五", "三十六", "三十七", "三十八", "三十九", "四十", "四十一", "四十二", "四十三", "四十四", "四十五", "四十六", "四十七", "四十八", "四十九", "五十", "五十一", "五十二", "五十三", "五十四", "五十五", "五十六", "五十七", "五十八", "五十九", "六十", "六十一", "六十二", "六十三", "六十四", "六十五", "六十六", "六十七", "六十八", "六十九", "七十", "七十一", "七十二", "七十三", "七十四", "七十五", "七十六", "七十七", "七十八", "七十九", "八十", "八十一", "八十二", "八十三", "八十四", "八十五", "八十六", "八十七", "八十八", "八十九", "九十", "九十一", "九十二", "九十三", "九十四", "九十五", "九十六", "九十七", "九十八", "九十九"]);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["午前", "午後"]`
+    pub const AM_PM: &[&str] = &["午前", "午後"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y年 %b %e日 %A %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y年 %b %e日 %A %H:%M:%S %Z");
+    /// `&["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"]`
+    pub const DAY: &[&str] = &["日曜日", "月曜日", "火曜日", "水曜日", "木曜日", "金曜日", "土曜日"];
+    /// `"%Y年%m月%d日"`
+    pub const D_FMT: &str = "%Y年%m月%d日";
+    /// `"%Y年%m月%d日 %H時%M分%S秒"`
+    pub const D_T_FMT: &str = "%Y年%m月%d日 %H時%M分%S秒";
+    /// `Some(&["+:2:2020/01/01:+*:令和:%EC%Ey年", "+:1:2019/05/01:2019/12/31:令和:%EC元年", "+:2:1990/01/01:2019/04/30:平成:%EC%Ey年", "+:1:1989/01/08:1989/12/31:平成:%EC元年", "+:2:1927/01/01:1989/01/07:昭和:%EC%Ey年", "+:1:1926/12/25:1926/12/31:昭和:%EC元年", "+:2:1913/01/01:1926/12/24:大正:%EC%Ey年", "+:1:1912/07/30:1912/12/31:大正:%EC元年", "+:6:1873/01/01:1912/07/29:明治:%EC%Ey年", "+:1:0001/01/01:1872/12/31:西暦:%EC%Ey年", "+:1:-0001/12/31:-*:紀元前:%EC%Ey年"])`
+    pub const ERA: Option<&[&str]> = Some(&["+:2:2020/01/01:+*:令和:%EC%Ey年", "+:1:2019/05/01:2019/12/31:令和:%EC元年", "+:2:1990/01/01:2019/04/30:平成:%EC%Ey年", "+:1:1989/01/08:1989/12/31:平成:%EC元年", "+:2:1927/01/01:1989/01/07:昭和:%EC%Ey年", "+:1:1926/12/25:1926/12/31:昭和:%EC元年", "+:2:1913/01/01:1926/12/24:大正:%EC%Ey年", "+:1:1912/07/30:1912/12/31:大正:%EC元年", "+:6:1873/01/01:1912/07/29:明治:%EC%Ey年", "+:1:0001/01/01:1872/12/31:西暦:%EC%Ey年", "+:1:-0001/12/31:-*:紀元前:%EC%Ey年"]);
+    /// `Some("%EY%m月%d日")`
+    pub const ERA_D_FMT: Option<&str> = Some("%EY%m月%d日");
+    /// `Some("%EY%m月%d日 %H時%M分%S秒")`
+    pub const ERA_D_T_FMT: Option<&str> = Some("%EY%m月%d日 %H時%M分%S秒");
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_0;
+    /// `"%H時%M分%S秒"`
+    pub const T_FMT: &str = "%H時%M分%S秒";
+    /// `"%p%I時%M分%S秒"`
+    pub const T_FMT_AMPM: &str = "%p%I時%M分%S秒";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p%I時%M分%S秒")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p%I時%M分%S秒");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,210 @@
+pub mod LC_ADDRESS {
+    /// `Some("GEO")`
+    pub const COUNTRY_AB3: Option<&str> = Some("GEO");
+    /// `Some("GE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("GE");
+    /// `Some("GE")`
+    pub const COUNTRY_CAR: Option<&str> = Some("GE");
+    /// `Some("99928")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("99928");
+    /// `Some("საქართველო")`
+    pub const COUNTRY_NAME: Option<&str> = Some("საქართველო");
+    /// `Some(268)`
+    pub const COUNTRY_NUM: Option<i64> = Some(268);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ka")`
+    pub const LANG_AB: Option<&str> = Some("ka");
+    /// `Some("geo")`
+    pub const LANG_LIB: Option<&str> = Some("geo");
+    /// `Some("ქართული")`
+    pub const LANG_NAME: Option<&str> = Some("ქართული");
+    /// `Some("kat")`
+    pub const LANG_TERM: Option<&str> = Some("kat");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    /// ]
+    /// ```
+        pub const CATEGORY: Option<&[&[&str]]> = Some(&[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_TELEPHONE"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+    ]);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2001-01-26")`
+    pub const DATE: Option<&str> = Some("2001-01-26");
+    /// `Some("srtxg@chanae.alphanet.ch")`
+    pub const EMAIL: Option<&str> = Some("srtxg@chanae.alphanet.ch");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Georgian")`
+    pub const LANGUAGE: Option<&str> = Some("Georgian");
+    /// `Some("0.6")`
+    pub const REVISION: Option<&str> = Some("0.6");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Georgia")`
+    pub const TERRITORY: Option<&str> = Some("Georgia");
+    /// `Some("Georgian language locale for Georgia")`
+    pub const TITLE: Option<&str> = Some("Georgian language locale for Georgia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNaAა]"`
+    pub const NOEXPR: &str = "^[-0nNaAა]";
+    /// `Some("არა")`
+    pub const NOSTR: Option<&str> = Some("არა");
+    /// `"^[+1yYkKxXკ]"`
+    pub const YESEXPR: &str = "^[+1yYkKxXკ]";
+    /// `Some("კი")`
+    pub const YESSTR: Option<&str> = Some("კი");
+}
+pub mod LC_MONETARY {
+    /// `"₾"`
+    pub const CURRENCY_SYMBOL: &str = "₾";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"GEL "`
+    pub const INT_CURR_SYMBOL: &str = "GEL ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("995")`
+    pub const INT_PREFIX: Option<&str> = Some("995");
+    /// `Some("8~10")`
+    pub const INT_SELECT: Option<&str> = Some("8~10");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["კვი", "ორშ", "სამ", "ოთხ", "ხუთ", "პარ", "შაბ"]`
+    pub const ABDAY: &[&str] = &["კვი", "ორშ", "სამ", "ოთხ", "ხუთ", "პარ", "შაბ"];
+    /// `&["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"]`
+    pub const ABMON: &[&str] = &["იან", "თებ", "მარ", "აპრ", "მაი", "ივნ", "ივლ", "აგვ", "სექ", "ოქტ", "ნოე", "დეკ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["კვირა", "ორშაბათი", "სამშაბათი", "ოთხშაბათი", "ხუთშაბათი", "პარასკევი", "შაბათი"]`
+    pub const DAY: &[&str] = &["კვირა", "ორშაბათი", "სამშაბათი", "ოთხშაბათი", "ხუთშაბათი", "პარასკევი", "შაბათი"];
+    /// `"%m/%d/%Y"`
+    pub const D_FMT: &str = "%m/%d/%Y";
+    /// `"%Y წლის %d %B, %T %Z"`
+    pub const D_T_FMT: &str = "%Y წლის %d %B, %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"]`
+    pub const MON: &[&str] = &["იანვარი", "თებერვალი", "მარტი", "აპრილი", "მაისი", "ივნისი", "ივლისი", "აგვისტო", "სექტემბერი", "ოქტომბერი", "ნოემბერი", "დეკემბერი"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("DZA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DZA");
+    /// `Some("DZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DZ");
+    /// `Some("DZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("DZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Lezzayer")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Lezzayer");
+    /// `Some(12)`
+    pub const COUNTRY_NUM: Option<i64> = Some(12);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("kab")`
+    pub const LANG_LIB: Option<&str> = Some("kab");
+    /// `Some("Taqbaylit")`
+    pub const LANG_NAME: Option<&str> = Some("Taqbaylit");
+    /// `Some("kab")`
+    pub const LANG_TERM: Option<&str> = Some("kab");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2016-07-06")`
+    pub const DATE: Option<&str> = Some("2016-07-06");
+    /// `Some("belkacem77@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("belkacem77@gmail.com");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Kabyle")`
+    pub const LANGUAGE: Option<&str> = Some("Kabyle");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Algeria")`
+    pub const TERRITORY: Option<&str> = Some("Algeria");
+    /// `Some("Kabyle language locale for Algeria")`
+    pub const TITLE: Option<&str> = Some("Kabyle language locale for Algeria");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNuUaA]"`
+    pub const NOEXPR: &str = "^[-0nNuUaA]";
+    /// `Some("Uhu")`
+    pub const NOSTR: Option<&str> = Some("Uhu");
+    /// `"^[+1yYiI]"`
+    pub const YESEXPR: &str = "^[+1yYiI]";
+    /// `Some("Ih")`
+    pub const YESSTR: Option<&str> = Some("Ih");
+}
+pub mod LC_MONETARY {
+    /// `"DA"`
+    pub const CURRENCY_SYMBOL: &str = "DA";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"DZD "`
+    pub const INT_CURR_SYMBOL: &str = "DZD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `""`
+    pub const MON_THOUSANDS_SEP: &str = "";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub use super::ar_DZ::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Ace", "Ari", "Ara", "Aha", "Amh", "Sem", "Sed"]`
+    pub const ABDAY: &[&str] = &["Ace", "Ari", "Ara", "Aha", "Amh", "Sem", "Sed"];
+    /// `&["Yen", "Fur", "Meɣ", "Yeb", "May", "Yun", "Yul", "ɣuc", "Cte", "Tub", "Wam", "Duj"]`
+    pub const ABMON: &[&str] = &["Yen", "Fur", "Meɣ", "Yeb", "May", "Yun", "Yul", "ɣuc", "Cte", "Tub", "Wam", "Duj"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["FT", "MD"]`
+    pub const AM_PM: &[&str] = &["FT", "MD"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Acer", "Arim", "Aram", "Ahad", "Amhad", "Sem", "Sed"]`
+    pub const DAY: &[&str] = &["Acer", "Arim", "Aram", "Ahad", "Amhad", "Sem", "Sed"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(1)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(1);
+    /// `&["Yennayer", "Fuṛar", "Meɣres", "Yebrir", "Mayyu", "Yunyu", "Yulyu", "ɣuct", "Ctembeṛ", "Tubeṛ", "Wambeṛ", "Dujembeṛ"]`
+    pub const MON: &[&str] = &["Yennayer", "Fuṛar", "Meɣres", "Yebrir", "Mayyu", "Yunyu", "Yulyu", "ɣuct", "Ctembeṛ", "Tubeṛ", "Wambeṛ", "Dujembeṛ"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
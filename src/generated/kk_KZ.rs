@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("KAZ")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KAZ");
+    /// `Some("KZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KZ");
+    /// `Some("KZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("KZ");
+    /// `Some("978-601")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-601");
+    /// `Some("Қазақстан")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Қазақстан");
+    /// `Some(398)`
+    pub const COUNTRY_NUM: Option<i64> = Some(398);
+    /// `Some("KAZ")`
+    pub const COUNTRY_POST: Option<&str> = Some("KAZ");
+    /// `Some("kk")`
+    pub const LANG_AB: Option<&str> = Some("kk");
+    /// `Some("kaz")`
+    pub const LANG_LIB: Option<&str> = Some("kaz");
+    /// `Some("қазақ тілі")`
+    pub const LANG_NAME: Option<&str> = Some("қазақ тілі");
+    /// `Some("kaz")`
+    pub const LANG_TERM: Option<&str> = Some("kaz");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Timur Birsh")`
+    pub const CONTACT: Option<&str> = Some("Timur Birsh");
+    /// `Some("2016-03-22")`
+    pub const DATE: Option<&str> = Some("2016-03-22");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kazakh")`
+    pub const LANGUAGE: Option<&str> = Some("Kazakh");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("linukz.org")`
+    pub const SOURCE: Option<&str> = Some("linukz.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Kazakhstan")`
+    pub const TERRITORY: Option<&str> = Some("Kazakhstan");
+    /// `Some("Kazakh locale for Kazakhstan")`
+    pub const TITLE: Option<&str> = Some("Kazakh locale for Kazakhstan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNЖжN]"`
+    pub const NOEXPR: &str = "^[-0nNЖжN]";
+    /// `Some("жоқ")`
+    pub const NOSTR: Option<&str> = Some("жоқ");
+    /// `"^[+1yYИи]"`
+    pub const YESEXPR: &str = "^[+1yYИи]";
+    /// `Some("иә")`
+    pub const YESSTR: Option<&str> = Some("иә");
+}
+pub mod LC_MONETARY {
+    /// `"₸"`
+    pub const CURRENCY_SYMBOL: &str = "₸";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"KZT "`
+    pub const INT_CURR_SYMBOL: &str = "KZT ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(4)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(4);
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(2)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(2);
+    /// `Some(4)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(4);
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `2`
+    pub const P_SEP_BY_SPACE: i64 = 2;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("7")`
+    pub const INT_PREFIX: Option<&str> = Some("7");
+    /// `Some("8~10")`
+    pub const INT_SELECT: Option<&str> = Some("8~10");
+    /// `Some("(%A) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%A) %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Жк", "Дс", "Сс", "Ср", "Бс", "Жм", "Сб"]`
+    pub const ABDAY: &[&str] = &["Жк", "Дс", "Сс", "Ср", "Бс", "Жм", "Сб"];
+    /// `&["Қаң", "Ақп", "Нау", "Сәу", "Мам", "Мау", "Шіл", "Там", "Қыр", "Қаз", "Қар", "Жел"]`
+    pub const ABMON: &[&str] = &["Қаң", "Ақп", "Нау", "Сәу", "Мам", "Мау", "Шіл", "Там", "Қыр", "Қаз", "Қар", "Жел"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Жексенбі", "Дүйсенбі", "Сейсенбі", "Сәрсенбі", "Бейсенбі", "Жұма", "Сенбі"]`
+    pub const DAY: &[&str] = &["Жексенбі", "Дүйсенбі", "Сейсенбі", "Сәрсенбі", "Бейсенбі", "Жұма", "Сенбі"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Қаңтар", "Ақпан", "Наурыз", "Сәуір", "Мамыр", "Маусым", "Шілде", "Тамыз", "Қыркүйек", "Қазан", "Қараша", "Желтоқсан"]`
+    pub const MON: &[&str] = &["Қаңтар", "Ақпан", "Наурыз", "Сәуір", "Мамыр", "Маусым", "Шілде", "Тамыз", "Қыркүйек", "Қазан", "Қараша", "Желтоқсан"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("GRL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("GRL");
+    /// `Some("GL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("GL");
+    /// `Some("KN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("KN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Kalaallit Nunaat")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Kalaallit Nunaat");
+    /// `Some(304)`
+    pub const COUNTRY_NUM: Option<i64> = Some(304);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("kl")`
+    pub const LANG_AB: Option<&str> = Some("kl");
+    /// `Some("kal")`
+    pub const LANG_LIB: Option<&str> = Some("kal");
+    /// `Some("kalaallisut")`
+    pub const LANG_NAME: Option<&str> = Some("kalaallisut");
+    /// `Some("kal")`
+    pub const LANG_TERM: Option<&str> = Some("kal");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Kollegievej 6, DK-2920 Charlottenlund, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Kollegievej 6, DK-2920 Charlottenlund, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kalaallisut")`
+    pub const LANGUAGE: Option<&str> = Some("Kalaallisut");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Danish Standards Association")`
+    pub const SOURCE: Option<&str> = Some("Danish Standards Association");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Greenland")`
+    pub const TERRITORY: Option<&str> = Some("Greenland");
+    /// `Some("Greenlandic locale for Greenland")`
+    pub const TITLE: Option<&str> = Some("Greenlandic locale for Greenland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0Nn]"`
+    pub const NOEXPR: &str = "^[-0Nn]";
+    /// `Some("naagga")`
+    pub const NOSTR: Option<&str> = Some("naagga");
+    /// `"^[+1JjYyAa]"`
+    pub const YESEXPR: &str = "^[+1JjYyAa]";
+    /// `Some("aap")`
+    pub const YESSTR: Option<&str> = Some("aap");
+}
+pub use super::da_DK::LC_MONETARY;
+pub use super::da_DK::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("299")`
+    pub const INT_PREFIX: Option<&str> = Some("299");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["sap", "ata", "mar", "pin", "sis", "tal", "arf"]`
+    pub const ABDAY: &[&str] = &["sap", "ata", "mar", "pin", "sis", "tal", "arf"];
+    /// `&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_14;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["januaari", "februaari", "marsi", "apriili", "maaji", "juuni", "juuli", "aggusti", "septembari", "oktobari", "novembari", "decembari"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["januaari", "februaari", "marsi", "apriili", "maaji", "juuni", "juuli", "aggusti", "septembari", "oktobari", "novembari", "decembari"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sapaat", "ataasinngorneq", "marlunngorneq", "pingasunngorneq", "sisamanngorneq", "tallimanngorneq", "arfininngorneq"]`
+    pub const DAY: &[&str] = &["sapaat", "ataasinngorneq", "marlunngorneq", "pingasunngorneq", "sisamanngorneq", "tallimanngorneq", "arfininngorneq"];
+    /// `"%b %d %Y"`
+    pub const D_FMT: &str = "%b %d %Y";
+    /// `"%a %b %d %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %b %d %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januaarip", "februaarip", "marsip", "apriilip", "maajip", "juunip", "juulip", "aggustip", "septembarip", "oktobarip", "novembarip", "decembarip"]`
+    pub const MON: &[&str] = &["januaarip", "februaarip", "marsip", "apriilip", "maajip", "juunip", "juulip", "aggustip", "septembarip", "oktobarip", "novembarip", "decembarip"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
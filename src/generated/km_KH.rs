@@ -0,0 +1,198 @@
+pub mod LC_ADDRESS {
+    /// `Some("KHM")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KHM");
+    /// `Some("KH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KH");
+    /// `Some("KH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("KH");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("កម\u{17d2}ព\u{17bb}ជា")`
+    pub const COUNTRY_NAME: Option<&str> = Some("កម\u{17d2}ព\u{17bb}ជា");
+    /// `Some(116)`
+    pub const COUNTRY_NUM: Option<i64> = Some(116);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("km")`
+    pub const LANG_AB: Option<&str> = Some("km");
+    /// `Some("khm")`
+    pub const LANG_LIB: Option<&str> = Some("khm");
+    /// `Some("ខ\u{17d2}មែរ")`
+    pub const LANG_NAME: Option<&str> = Some("ខ\u{17d2}មែរ");
+    /// `Some("khm")`
+    pub const LANG_TERM: Option<&str> = Some("khm");
+    /// `Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Jens Herden at: jens@khmeros.info")`
+    pub const CONTACT: Option<&str> = Some("Jens Herden at: jens@khmeros.info");
+    /// `Some("2005-3-15")`
+    pub const DATE: Option<&str> = Some("2005-3-15");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Khmer")`
+    pub const LANGUAGE: Option<&str> = Some("Khmer");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Cambodia")`
+    pub const TERRITORY: Option<&str> = Some("Cambodia");
+    /// `Some("Khmer locale for Cambodia")`
+    pub const TITLE: Option<&str> = Some("Khmer locale for Cambodia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNទ]"`
+    pub const NOEXPR: &str = "^[-0nNទ]";
+    /// `Some("ទេ")`
+    pub const NOSTR: Option<&str> = Some("ទេ");
+    /// `"^[+1yYប]"`
+    pub const YESEXPR: &str = "^[+1yYប]";
+    /// `Some("បាទ/ចាស")`
+    pub const YESSTR: Option<&str> = Some("បាទ/ចាស");
+}
+pub mod LC_MONETARY {
+    /// `"៛"`
+    pub const CURRENCY_SYMBOL: &str = "៛";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"KHR "`
+    pub const INT_CURR_SYMBOL: &str = "KHR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(0)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(0);
+    /// `Some(0)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(0);
+    /// `Some(0)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(1);
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("855")`
+    pub const INT_PREFIX: Option<&str> = Some("855");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `Some("(%A)%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%A)%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["អា", "ច", "អ", "ព\u{17bb}", "ព\u{17d2}រ", "ស\u{17bb}", "ស"]`
+    pub const ABDAY: &[&str] = &["អា", "ច", "អ", "ព\u{17bb}", "ព\u{17d2}រ", "ស\u{17bb}", "ស"];
+    /// `&["១", "២", "៣", "៤", "៥", "៦", "៧", "៨", "៩", "១០", "១១", "១២"]`
+    pub const ABMON: &[&str] = &["១", "២", "៣", "៤", "៥", "៦", "៧", "៨", "៩", "១០", "១១", "១២"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ព\u{17d2}រ\u{17b9}ក", "ល\u{17d2}ងាច"]`
+    pub const AM_PM: &[&str] = &["ព\u{17d2}រ\u{17b9}ក", "ល\u{17d2}ងាច"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ថ\u{17d2}ងៃ\u{200b}អាទ\u{17b7}ត\u{17d2}យ", "ថ\u{17d2}ងៃ\u{200b}ច\u{17d0}ន\u{17d2}ទ", "ថ\u{17d2}ងៃ\u{200b}អង\u{17d2}គារ", "ថ\u{17d2}ងៃ\u{200b}ព\u{17bb}ធ", "ថ\u{17d2}ងៃ\u{200b}ព\u{17d2}រហស\u{17d2}បត\u{17b7}\u{17cd}", "ថ\u{17d2}ងៃ\u{200b}ស\u{17bb}ក\u{17d2}រ", "ថ\u{17d2}ងៃ\u{200b}សៅរ\u{17cd}"]`
+    pub const DAY: &[&str] = &["ថ\u{17d2}ងៃ\u{200b}អាទ\u{17b7}ត\u{17d2}យ", "ថ\u{17d2}ងៃ\u{200b}ច\u{17d0}ន\u{17d2}ទ", "ថ\u{17d2}ងៃ\u{200b}អង\u{17d2}គារ", "ថ\u{17d2}ងៃ\u{200b}ព\u{17bb}ធ", "ថ\u{17d2}ងៃ\u{200b}ព\u{17d2}រហស\u{17d2}បត\u{17b7}\u{17cd}", "ថ\u{17d2}ងៃ\u{200b}ស\u{17bb}ក\u{17d2}រ", "ថ\u{17d2}ងៃ\u{200b}សៅរ\u{17cd}"];
+    /// `"%e %B %Y"`
+    pub const D_FMT: &str = "%e %B %Y";
+    /// `"%A ថ\u{17d2}ងៃ %e ខែ %B ឆ\u{17d2}នា\u{17c6} %Y, %H ម\u{17c9}ោង m នាទ\u{17b8} %S វ\u{17b7}នាទ\u{17b8}\u{200b}"`
+    pub const D_T_FMT: &str = "%A ថ\u{17d2}ងៃ %e ខែ %B ឆ\u{17d2}នា\u{17c6} %Y, %H ម\u{17c9}ោង m នាទ\u{17b8} %S វ\u{17b7}នាទ\u{17b8}\u{200b}";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(1)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(1);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"]`
+    pub const MON: &[&str] = &["មករា", "ក\u{17bb}ម\u{17d2}ភៈ", "ម\u{17b8}នា", "មេសា", "ឧសភា", "ម\u{17b7}ថ\u{17bb}នា", "កក\u{17d2}កដា", "ស\u{17b8}ហា", "កញ\u{17d2}ញា", "ត\u{17bb}លា", "វ\u{17b7}ច\u{17d2}ឆ\u{17b7}កា", "ធ\u{17d2}ន\u{17bc}"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `None`
+        pub const T_FMT_AMPM: Option<&str> = None;
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ಭಾರತ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ಭಾರತ");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("kn")`
+    pub const LANG_AB: Option<&str> = Some("kn");
+    /// `Some("kan")`
+    pub const LANG_LIB: Option<&str> = Some("kan");
+    /// `Some("ಕನ\u{ccd}ನಡ")`
+    pub const LANG_NAME: Option<&str> = Some("ಕನ\u{ccd}ನಡ");
+    /// `Some("kan")`
+    pub const LANG_TERM: Option<&str> = Some("kan");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2002-11-28")`
+    pub const DATE: Option<&str> = Some("2002-11-28");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kannada")`
+    pub const LANGUAGE: Option<&str> = Some("Kannada");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("IndLinux.org")`
+    pub const SOURCE: Option<&str> = Some("IndLinux.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Kannada language locale for India")`
+    pub const TITLE: Option<&str> = Some("Kannada language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNಇ]"`
+    pub const NOEXPR: &str = "^[-0nNಇ]";
+    /// `Some("ಇಲ\u{ccd}ಲ")`
+    pub const NOSTR: Option<&str> = Some("ಇಲ\u{ccd}ಲ");
+    /// `"^[+1yYಹ]"`
+    pub const YESEXPR: &str = "^[+1yYಹ]";
+    /// `Some("ಹ\u{ccc}ದು")`
+    pub const YESSTR: Option<&str> = Some("ಹ\u{ccc}ದು");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ರ", "ಸ\u{ccb}", "ಮಂ", "ಬು", "ಗು", "ಶು", "ಶ"]`
+    pub const ABDAY: &[&str] = &["ರ", "ಸ\u{ccb}", "ಮಂ", "ಬು", "ಗು", "ಶು", "ಶ"];
+    /// `&["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"]`
+    pub const ABMON: &[&str] = &["ಜನ", "ಫ\u{cc6}ಬ\u{ccd}ರ", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆ", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂ", "ಅಕ\u{ccd}ಟ\u{ccb}", "ನವ\u{cc6}ಂ", "ಡ\u{cbf}ಸ\u{cc6}ಂ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ಪ\u{cc2}ರ\u{ccd}ವಾಹ\u{ccd}ನ", "ಅಪರಾಹ\u{ccd}ನ"]`
+    pub const AM_PM: &[&str] = &["ಪ\u{cc2}ರ\u{ccd}ವಾಹ\u{ccd}ನ", "ಅಪರಾಹ\u{ccd}ನ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ರವ\u{cbf}ವಾರ", "ಸ\u{ccb}ಮವಾರ", "ಮಂಗಳವಾರ", "ಬುಧವಾರ", "ಗುರುವಾರ", "ಶುಕ\u{ccd}ರವಾರ", "ಶನ\u{cbf}ವಾರ"]`
+    pub const DAY: &[&str] = &["ರವ\u{cbf}ವಾರ", "ಸ\u{ccb}ಮವಾರ", "ಮಂಗಳವಾರ", "ಬುಧವಾರ", "ಗುರುವಾರ", "ಶುಕ\u{ccd}ರವಾರ", "ಶನ\u{cbf}ವಾರ"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"]`
+    pub const MON: &[&str] = &["ಜನವರ\u{cbf}", "ಫ\u{cc6}ಬ\u{ccd}ರವರ\u{cbf}", "ಮಾರ\u{ccd}ಚ\u{ccd}", "ಏಪ\u{ccd}ರ\u{cbf}ಲ\u{ccd}", "ಮ\u{cc7}", "ಜ\u{cc2}ನ\u{ccd}", "ಜುಲ\u{cc8}", "ಆಗಸ\u{ccd}ಟ\u{ccd}", "ಸ\u{cc6}ಪ\u{ccd}ಟ\u{cc6}ಂಬರ\u{ccd}", "ಅಕ\u{ccd}ಟ\u{ccb}ಬರ\u{ccd}", "ನವ\u{cc6}ಂಬರ\u{ccd}", "ಡ\u{cbf}ಸ\u{cc6}ಂಬರ\u{ccd}"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
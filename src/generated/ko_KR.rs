@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("KOR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KOR");
+    /// `Some("KR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KR");
+    /// `Some("ROK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ROK");
+    /// `Some("979-11,978-89")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("979-11,978-89");
+    /// `Some("대한민국")`
+    pub const COUNTRY_NAME: Option<&str> = Some("대한민국");
+    /// `Some(410)`
+    pub const COUNTRY_NUM: Option<i64> = Some(410);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ko")`
+    pub const LANG_AB: Option<&str> = Some("ko");
+    /// `Some("kor")`
+    pub const LANG_LIB: Option<&str> = Some("kor");
+    /// `Some("한국어")`
+    pub const LANG_NAME: Option<&str> = Some("한국어");
+    /// `Some("kor")`
+    pub const LANG_TERM: Option<&str> = Some("kor");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-11-09")`
+    pub const DATE: Option<&str> = Some("2000-11-09");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Korean")`
+    pub const LANGUAGE: Option<&str> = Some("Korean");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("South Korea")`
+    pub const TERRITORY: Option<&str> = Some("South Korea");
+    /// `Some("Korean locale for Republic of Korea")`
+    pub const TITLE: Option<&str> = Some("Korean locale for Republic of Korea");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNｎＮ아]"`
+    pub const NOEXPR: &str = "^[-0nNｎＮ아]";
+    /// `Some("아니요")`
+    pub const NOSTR: Option<&str> = Some("아니요");
+    /// `"^[+1yYｙＹ예]"`
+    pub const YESEXPR: &str = "^[+1yYｙＹ예]";
+    /// `Some("예")`
+    pub const YESSTR: Option<&str> = Some("예");
+}
+pub mod LC_MONETARY {
+    /// `"₩"`
+    pub const CURRENCY_SYMBOL: &str = "₩";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"KRW "`
+    pub const INT_CURR_SYMBOL: &str = "KRW ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("82")`
+    pub const INT_PREFIX: Option<&str> = Some("82");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `Some("(%A) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%A) %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["일", "월", "화", "수", "목", "금", "토"]`
+    pub const ABDAY: &[&str] = &["일", "월", "화", "수", "목", "금", "토"];
+    /// `&[" 1월", " 2월", " 3월", " 4월", " 5월", " 6월", " 7월", " 8월", " 9월", "10월", "11월", "12월"]`
+    pub const ABMON: &[&str] = &[" 1월", " 2월", " 3월", " 4월", " 5월", " 6월", " 7월", " 8월", " 9월", "10월", "11월", "12월"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["오전", "오후"]`
+    pub const AM_PM: &[&str] = &["오전", "오후"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y. %m. %d. (%a) %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y. %m. %d. (%a) %H:%M:%S %Z");
+    /// `&["일요일", "월요일", "화요일", "수요일", "목요일", "금요일", "토요일"]`
+    pub const DAY: &[&str] = &["일요일", "월요일", "화요일", "수요일", "목요일", "금요일", "토요일"];
+    /// `"%Y년 %m월 %d일"`
+    pub const D_FMT: &str = "%Y년 %m월 %d일";
+    /// `"%Y년 %m월 %d일 (%a) %p %I시 %M분 %S초"`
+    pub const D_T_FMT: &str = "%Y년 %m월 %d일 (%a) %p %I시 %M분 %S초";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"]`
+    pub const MON: &[&str] = &["1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월"];
+    /// `"%H시 %M분 %S초"`
+    pub const T_FMT: &str = "%H시 %M분 %S초";
+    /// `"%p %I시 %M분 %S초"`
+    pub const T_FMT_AMPM: &str = "%p %I시 %M분 %S초";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%x (%a) %r"`
+        pub const D_T_FMT: &str = "%x (%a) %r";
+        /// `Some("%p %I시 %M분 %S초")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %I시 %M분 %S초");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
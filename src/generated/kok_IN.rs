@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("kok")`
+    pub const LANG_LIB: Option<&str> = Some("kok");
+    /// `Some("को\u{902}कणी")`
+    pub const LANG_NAME: Option<&str> = Some("को\u{902}कणी");
+    /// `Some("kok")`
+    pub const LANG_TERM: Option<&str> = Some("kok");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Level-1, Tower 10, Cyber City, Magarpatta City, Hadapsar, Pune, Maharashtra, India-411013")`
+    pub const ADDRESS: Option<&str> = Some("Level-1, Tower 10, Cyber City, Magarpatta City, Hadapsar, Pune, Maharashtra, India-411013");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2009,December,08")`
+    pub const DATE: Option<&str> = Some("2009,December,08");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Konkani")`
+    pub const LANGUAGE: Option<&str> = Some("Konkani");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat, Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat, Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Konkani language locale for India")`
+    pub const TITLE: Option<&str> = Some("Konkani language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("न\u{94d}ही")`
+    pub const NOSTR: Option<&str> = Some("न\u{94d}ही");
+    /// `"^[+1yYह]"`
+    pub const YESEXPR: &str = "^[+1yYह]";
+    /// `Some("हय")`
+    pub const YESSTR: Option<&str> = Some("हय");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["आयतार", "सोमार", "म\u{902}गळवार", "ब\u{941}धवार", "ब\u{947}र\u{947}सतार", "श\u{941}करार", "श\u{947}नवार"]`
+    pub const ABDAY: &[&str] = &["आयतार", "सोमार", "म\u{902}गळवार", "ब\u{941}धवार", "ब\u{947}र\u{947}सतार", "श\u{941}करार", "श\u{947}नवार"];
+    /// `&["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ओगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}\u{902}बर", "ओक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"]`
+    pub const ABMON: &[&str] = &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ओगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}\u{902}बर", "ओक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["म.प\u{942}.", "म.न\u{902}."]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_11;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["आयतार", "सोमार", "म\u{902}गळवार", "ब\u{941}धवार", "ब\u{947}र\u{947}सतार", "श\u{941}करार", "श\u{947}नवार"]`
+    pub const DAY: &[&str] = &["आयतार", "सोमार", "म\u{902}गळवार", "ब\u{941}धवार", "ब\u{947}र\u{947}सतार", "श\u{941}करार", "श\u{947}नवार"];
+    /// `"%-d-%-m-%y"`
+    pub const D_FMT: &str = "%-d-%-m-%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ओगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}\u{902}बर", "ओक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"]`
+    pub const MON: &[&str] = &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ओगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}\u{902}बर", "ओक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ہ\u{650}ندوستان")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ہ\u{650}ندوستان");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ks")`
+    pub const LANG_AB: Option<&str> = Some("ks");
+    /// `Some("kas")`
+    pub const LANG_LIB: Option<&str> = Some("kas");
+    /// `Some("کٲش\u{64f}ر")`
+    pub const LANG_NAME: Option<&str> = Some("کٲش\u{64f}ر");
+    /// `Some("kas")`
+    pub const LANG_TERM: Option<&str> = Some("kas");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Marisfot III, Marigold Premises, East-Wing, Kalyaninagar, Pune, India-411014")`
+    pub const ADDRESS: Option<&str> = Some("Marisfot III, Marigold Premises, East-Wing, Kalyaninagar, Pune, India-411014");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2009,April,06")`
+    pub const DATE: Option<&str> = Some("2009,April,06");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kashmiri")`
+    pub const LANGUAGE: Option<&str> = Some("Kashmiri");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat, Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat, Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Kashmiri language locale for India")`
+    pub const TITLE: Option<&str> = Some("Kashmiri language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNن]"`
+    pub const NOEXPR: &str = "^[-0nNن]";
+    /// `Some("نع")`
+    pub const NOSTR: Option<&str> = Some("نع");
+    /// `"^[+1yYآ]"`
+    pub const YESEXPR: &str = "^[+1yYآ]";
+    /// `Some("آ")`
+    pub const YESSTR: Option<&str> = Some("آ");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["آتهوار", "ژءنتروار", "بوءںوار", "بودهوار", "برىسوار", "جمع", "بٹوار"]`
+    pub const ABDAY: &[&str] = &["آتهوار", "ژءنتروار", "بوءںوار", "بودهوار", "برىسوار", "جمع", "بٹوار"];
+    /// `&["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "می\u{654}", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"]`
+    pub const ABMON: &[&str] = &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "می\u{654}", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["دوپھربرونھ", "دوپھرپتھ"]`
+    pub const AM_PM: &[&str] = &["دوپھربرونھ", "دوپھرپتھ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["آتهوار", "ژءندروار", "بوءںوار", "بودهوار", "برىسوار", "جمع", "بٹوار"]`
+    pub const DAY: &[&str] = &["آتهوار", "ژءندروار", "بوءںوار", "بودهوار", "برىسوار", "جمع", "بٹوار"];
+    /// `"%-m/%-d/%y"`
+    pub const D_FMT: &str = "%-m/%-d/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "می\u{654}", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"]`
+    pub const MON: &[&str] = &["جنؤری", "فرؤری", "مار\u{655}چ", "اپریل", "می\u{654}", "جو\u{657}ن", "جو\u{657}لایی", "اگست", "ستمبر", "اکتو\u{657}بر", "نومبر", "دسمبر"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
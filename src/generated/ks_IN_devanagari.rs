@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ks")`
+    pub const LANG_AB: Option<&str> = Some("ks");
+    /// `Some("kas")`
+    pub const LANG_LIB: Option<&str> = Some("kas");
+    /// `Some("कॉश\u{941}र")`
+    pub const LANG_NAME: Option<&str> = Some("कॉश\u{941}र");
+    /// `Some("kas")`
+    pub const LANG_TERM: Option<&str> = Some("kas");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2008-08-26")`
+    pub const DATE: Option<&str> = Some("2008-08-26");
+    /// `Some("ks-gnome-trans-commits@lists.code.indlinux.net")`
+    pub const EMAIL: Option<&str> = Some("ks-gnome-trans-commits@lists.code.indlinux.net");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kashmiri")`
+    pub const LANGUAGE: Option<&str> = Some("Kashmiri");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Kashmiri(devanagari) language locale for India")`
+    pub const TITLE: Option<&str> = Some("Kashmiri(devanagari) language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("न")`
+    pub const NOSTR: Option<&str> = Some("न");
+    /// `"^[+1yYइ]"`
+    pub const YESEXPR: &str = "^[+1yYइ]";
+    /// `Some("इ\u{902}न")`
+    pub const YESSTR: Option<&str> = Some("इ\u{902}न");
+}
+pub use super::ks_IN::LC_MONETARY;
+pub use super::ks_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["आथ ", "च\u{945}\u{93c}द\u{941}र", "बोम", "ब\u{94d}वद", "ब\u{94d}रस", "शोक\u{941}र", "बट\u{941}"]`
+    pub const ABDAY: &[&str] = &["आथ ", "च\u{945}\u{93c}द\u{941}र", "बोम", "ब\u{94d}वद", "ब\u{94d}रस", "शोक\u{941}र", "बट\u{941}"];
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_28;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["आथवार", "च\u{945}\u{93c}द\u{941}रवार", "बोमवार", "ब\u{94d}वदवार", "ब\u{94d}रसवार", "शोक\u{941}रवार", "बट\u{941}वार"]`
+    pub const DAY: &[&str] = &["आथवार", "च\u{945}\u{93c}द\u{941}रवार", "बोमवार", "ब\u{94d}वदवार", "ब\u{94d}रसवार", "शोक\u{941}रवार", "बट\u{941}वार"];
+    /// `"%-m/%-d/%y"`
+    pub const D_FMT: &str = "%-m/%-d/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_24;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
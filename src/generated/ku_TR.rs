@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("TUR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TUR");
+    /// `Some("TR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TR");
+    /// `Some("TR")`
+    pub const COUNTRY_CAR: Option<&str> = Some("TR");
+    /// `Some("975")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("975");
+    /// `Some("Tirkiye")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Tirkiye");
+    /// `Some(792)`
+    pub const COUNTRY_NUM: Option<i64> = Some(792);
+    /// `Some("TR")`
+    pub const COUNTRY_POST: Option<&str> = Some("TR");
+    /// `Some("ku")`
+    pub const LANG_AB: Option<&str> = Some("ku");
+    /// `Some("kur")`
+    pub const LANG_LIB: Option<&str> = Some("kur");
+    /// `Some("kurdî")`
+    pub const LANG_NAME: Option<&str> = Some("kurdî");
+    /// `Some("kur")`
+    pub const LANG_TERM: Option<&str> = Some("kur");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Kader DILSIZ, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Kader DILSIZ, Pablo Saratxaga");
+    /// `Some("2003-07-27")`
+    pub const DATE: Option<&str> = Some("2003-07-27");
+    /// `Some("kader@ikader.com, pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("kader@ikader.com, pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kurdish")`
+    pub const LANGUAGE: Option<&str> = Some("Kurdish");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Kader DILSIZ")`
+    pub const SOURCE: Option<&str> = Some("Kader DILSIZ");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Turkey")`
+    pub const TERRITORY: Option<&str> = Some("Turkey");
+    /// `Some("Kurdish (latin) locale for Turkey")`
+    pub const TITLE: Option<&str> = Some("Kurdish (latin) locale for Turkey");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nâ")`
+    pub const NOSTR: Option<&str> = Some("nâ");
+    /// `"^[+1eEdDyY]"`
+    pub const YESEXPR: &str = "^[+1eEdDyY]";
+    /// `Some("erê")`
+    pub const YESSTR: Option<&str> = Some("erê");
+}
+pub use super::tr_TR::LC_MONETARY;
+pub use super::tr_TR::LC_NUMERIC;
+pub use super::tr_TR::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["yêk", "dus", "sês", "çar", "pên", "înî", "sep"]`
+    pub const ABDAY: &[&str] = &["yêk", "dus", "sês", "çar", "pên", "înî", "sep"];
+    /// `&["Çil", "Sib", "Ada", "Nîs", "Gul", "Hez", "Tîr", "Teb", "Îlo", "Cot", "Mij", "Kan"]`
+    pub const ABMON: &[&str] = &["Çil", "Sib", "Ada", "Nîs", "Gul", "Hez", "Tîr", "Teb", "Îlo", "Cot", "Mij", "Kan"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A %d %B %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%A %d %B %Y");
+    /// `&["yêksêm", "dusêm", "sêsêm", "çarsêm", "pêncsêm", "înî", "sept"]`
+    pub const DAY: &[&str] = &["yêksêm", "dusêm", "sêsêm", "çarsêm", "pêncsêm", "înî", "sept"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A %d %B %Y %T %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Çile", "Sibat", "Adar", "Nîsan", "Gulan", "Hezîran", "Tîrmeh", "Tebax", "Îlon", "Cotmeh", "Mijdar", "Kanûn"]`
+    pub const MON: &[&str] = &["Çile", "Sibat", "Adar", "Nîsan", "Gulan", "Hezîran", "Tîrmeh", "Tebax", "Îlon", "Cotmeh", "Mijdar", "Kanûn"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
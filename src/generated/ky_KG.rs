@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("KGZ")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KGZ");
+    /// `Some("KG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KG");
+    /// `Some("KS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("KS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Кыргызстан")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Кыргызстан");
+    /// `Some(417)`
+    pub const COUNTRY_NUM: Option<i64> = Some(417);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ky")`
+    pub const LANG_AB: Option<&str> = Some("ky");
+    /// `Some("kir")`
+    pub const LANG_LIB: Option<&str> = Some("kir");
+    /// `Some("кыргызча")`
+    pub const LANG_NAME: Option<&str> = Some("кыргызча");
+    /// `Some("kir")`
+    pub const LANG_TERM: Option<&str> = Some("kir");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Pablo Saratxaga, Timur Jamakeev")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga, Timur Jamakeev");
+    /// `Some("2004-10-14")`
+    pub const DATE: Option<&str> = Some("2004-10-14");
+    /// `Some("srtxg@mandrakesoft.com, ztimur@mail.ru")`
+    pub const EMAIL: Option<&str> = Some("srtxg@mandrakesoft.com, ztimur@mail.ru");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Kyrgyz")`
+    pub const LANGUAGE: Option<&str> = Some("Kyrgyz");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("Timur Jamakeev")`
+    pub const SOURCE: Option<&str> = Some("Timur Jamakeev");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Kyrgyzstan")`
+    pub const TERRITORY: Option<&str> = Some("Kyrgyzstan");
+    /// `Some("Kyrgyz Language Locale for Kyrgyzstan")`
+    pub const TITLE: Option<&str> = Some("Kyrgyz Language Locale for Kyrgyzstan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNЖж]"`
+    pub const NOEXPR: &str = "^[-0nNЖж]";
+    /// `Some("жок")`
+    pub const NOSTR: Option<&str> = Some("жок");
+    /// `"^[+1yYОо]"`
+    pub const YESEXPR: &str = "^[+1yYОо]";
+    /// `Some("ооба")`
+    pub const YESSTR: Option<&str> = Some("ооба");
+}
+pub mod LC_MONETARY {
+    /// `"сом"`
+    pub const CURRENCY_SYMBOL: &str = "сом";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"KGS "`
+    pub const INT_CURR_SYMBOL: &str = "KGS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("996")`
+    pub const INT_PREFIX: Option<&str> = Some("996");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["жк", "дш", "ше", "ша", "бш", "жм", "иш"]`
+    pub const ABDAY: &[&str] = &["жк", "дш", "ше", "ша", "бш", "жм", "иш"];
+    /// `&["янв", "фев", "мар", "апр", "май", "июн", "июл", "авг", "сен", "окт", "ноя", "дек"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_22;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %e-%b %Y ж, %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %e-%b %Y ж, %H:%M:%S %Z");
+    /// `&["жекшемби", "дүйшөмбү", "шейшемби", "шаршемби", "бейшемби", "жума", "ишемби"]`
+    pub const DAY: &[&str] = &["жекшемби", "дүйшөмбү", "шейшемби", "шаршемби", "бейшемби", "жума", "ишемби"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"]`
+    pub const MON: &[&str] = &["январь", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("LUX")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LUX");
+    /// `Some("LU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LU");
+    /// `Some("L")`
+    pub const COUNTRY_CAR: Option<&str> = Some("L");
+    /// `Some("2")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("2");
+    /// `Some("Lëtzebuerg")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Lëtzebuerg");
+    /// `Some(442)`
+    pub const COUNTRY_NUM: Option<i64> = Some(442);
+    /// `Some("L")`
+    pub const COUNTRY_POST: Option<&str> = Some("L");
+    /// `Some("lb")`
+    pub const LANG_AB: Option<&str> = Some("lb");
+    /// `Some("ltz")`
+    pub const LANG_LIB: Option<&str> = Some("ltz");
+    /// `Some("Lëtzebuergesch")`
+    pub const LANG_NAME: Option<&str> = Some("Lëtzebuergesch");
+    /// `Some("ltz")`
+    pub const LANG_TERM: Option<&str> = Some("ltz");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("Pit Wenkin")`
+    pub const CONTACT: Option<&str> = Some("Pit Wenkin");
+    /// `Some("2011-01-28")`
+    pub const DATE: Option<&str> = Some("2011-01-28");
+    /// `Some("pit@wenkin.lu")`
+    pub const EMAIL: Option<&str> = Some("pit@wenkin.lu");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Luxembourgish")`
+    pub const LANGUAGE: Option<&str> = Some("Luxembourgish");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Luxembourg")`
+    pub const TERRITORY: Option<&str> = Some("Luxembourg");
+    /// `Some("Luxembourgish locale for Luxembourg")`
+    pub const TITLE: Option<&str> = Some("Luxembourgish locale for Luxembourg");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nee")`
+    pub const NOSTR: Option<&str> = Some("nee");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("jo")`
+    pub const YESSTR: Option<&str> = Some("jo");
+}
+pub use super::de_DE::LC_MONETARY;
+pub use super::de_DE::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("352")`
+    pub const INT_PREFIX: Option<&str> = Some("352");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["So", "Mé", "Dë", "Më", "Do", "Fr", "Sa"]`
+    pub const ABDAY: &[&str] = &["So", "Mé", "Dë", "Më", "Do", "Fr", "Sa"];
+    /// `&["Jan", "Feb", "Mäe", "Abr", "Mee", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mäe", "Abr", "Mee", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d. %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d. %b %H:%M:%S %Z %Y");
+    /// `&["Sonndeg", "Méindeg", "Dënschdeg", "Mëttwoch", "Donneschdeg", "Freideg", "Samschdeg"]`
+    pub const DAY: &[&str] = &["Sonndeg", "Méindeg", "Dënschdeg", "Mëttwoch", "Donneschdeg", "Freideg", "Samschdeg"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d. %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"]`
+    pub const MON: &[&str] = &["Januar", "Februar", "Mäerz", "Abrëll", "Mee", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
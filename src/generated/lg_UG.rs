@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("UGA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("UGA");
+    /// `Some("UG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("UG");
+    /// `Some("EAU")`
+    pub const COUNTRY_CAR: Option<&str> = Some("EAU");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Yuganda")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Yuganda");
+    /// `Some(800)`
+    pub const COUNTRY_NUM: Option<i64> = Some(800);
+    /// `Some("UG")`
+    pub const COUNTRY_POST: Option<&str> = Some("UG");
+    /// `Some("lg")`
+    pub const LANG_AB: Option<&str> = Some("lg");
+    /// `Some("lug")`
+    pub const LANG_LIB: Option<&str> = Some("lug");
+    /// `Some("Luganda")`
+    pub const LANG_NAME: Option<&str> = Some("Luganda");
+    /// `Some("lug")`
+    pub const LANG_TERM: Option<&str> = Some("lug");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("c/o P.O. Box 5190 Kampala, Uganda")`
+    pub const ADDRESS: Option<&str> = Some("c/o P.O. Box 5190 Kampala, Uganda");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Kizito Birabwa")`
+    pub const CONTACT: Option<&str> = Some("Kizito Birabwa");
+    /// `Some("2001-11-04")`
+    pub const DATE: Option<&str> = Some("2001-11-04");
+    /// `Some("kompyuta@kizito.uklinux.net")`
+    pub const EMAIL: Option<&str> = Some("kompyuta@kizito.uklinux.net");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Ganda")`
+    pub const LANGUAGE: Option<&str> = Some("Ganda");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Akademe ya Luganda")`
+    pub const SOURCE: Option<&str> = Some("Akademe ya Luganda");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Uganda")`
+    pub const TERRITORY: Option<&str> = Some("Uganda");
+    /// `Some("Luganda locale for Uganda")`
+    pub const TITLE: Option<&str> = Some("Luganda locale for Uganda");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Nedda")`
+    pub const NOSTR: Option<&str> = Some("Nedda");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("Ye")`
+    pub const YESSTR: Option<&str> = Some("Ye");
+}
+pub mod LC_MONETARY {
+    /// `"USh"`
+    pub const CURRENCY_SYMBOL: &str = "USh";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"UGX "`
+    pub const INT_CURR_SYMBOL: &str = "UGX ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("256")`
+    pub const INT_PREFIX: Option<&str> = Some("256");
+    /// `Some("000")`
+    pub const INT_SELECT: Option<&str> = Some("000");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Sab", "Bal", "Lw2", "Lw3", "Lw4", "Lw5", "Lw6"]`
+    pub const ABDAY: &[&str] = &["Sab", "Bal", "Lw2", "Lw3", "Lw4", "Lw5", "Lw6"];
+    /// `&["Jan", "Feb", "Mar", "Apu", "Maa", "Juu", "Jul", "Agu", "Seb", "Oki", "Nov", "Des"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mar", "Apu", "Maa", "Juu", "Jul", "Agu", "Seb", "Oki", "Nov", "Des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sabiiti", "Balaza", "Lwakubiri", "Lwakusatu", "Lwakuna", "Lwakutaano", "Lwamukaaga"]`
+    pub const DAY: &[&str] = &["Sabiiti", "Balaza", "Lwakubiri", "Lwakusatu", "Lwakuna", "Lwakutaano", "Lwamukaaga"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Janwaliyo", "Febwaliyo", "Marisi", "Apuli", "Maayi", "Juuni", "Julaayi", "Agusito", "Sebuttemba", "Okitobba", "Novemba", "Desemba"]`
+    pub const MON: &[&str] = &["Janwaliyo", "Febwaliyo", "Marisi", "Apuli", "Maayi", "Juuni", "Julaayi", "Agusito", "Sebuttemba", "Okitobba", "Novemba", "Desemba"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
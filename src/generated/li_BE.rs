@@ -0,0 +1,74 @@
+pub mod LC_ADDRESS {
+    /// `Some("BEL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BEL");
+    /// `Some("BE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BE");
+    /// `Some("B")`
+    pub const COUNTRY_CAR: Option<&str> = Some("B");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Bèlsj")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Bèlsj");
+    /// `Some(56)`
+    pub const COUNTRY_NUM: Option<i64> = Some(56);
+    /// `Some("B")`
+    pub const COUNTRY_POST: Option<&str> = Some("B");
+    /// `Some("li")`
+    pub const LANG_AB: Option<&str> = Some("li");
+    /// `Some("lim")`
+    pub const LANG_LIB: Option<&str> = Some("lim");
+    /// `Some("Lèmbörgs")`
+    pub const LANG_NAME: Option<&str> = Some("Lèmbörgs");
+    /// `Some("lim")`
+    pub const LANG_TERM: Option<&str> = Some("lim");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_5);
+    /// `Some("Kenneth Christiansen, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Kenneth Christiansen, Pablo Saratxaga");
+    /// `Some("2003-11-30")`
+    pub const DATE: Option<&str> = Some("2003-11-30");
+    /// `Some("kenneth@gnu.org, pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("kenneth@gnu.org, pablo@mandriva.com");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Limburgish")`
+    pub const LANGUAGE: Option<&str> = Some("Limburgish");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("information from Kenneth Christiansen")`
+    pub const SOURCE: Option<&str> = Some("information from Kenneth Christiansen");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Belgium")`
+    pub const TERRITORY: Option<&str> = Some("Belgium");
+    /// `Some("Limburgish Language Locale for Belgium")`
+    pub const TITLE: Option<&str> = Some("Limburgish Language Locale for Belgium");
+}
+pub use super::nl_BE::LC_MESSAGES;
+pub use super::nl_BE::LC_MONETARY;
+pub use super::nl_BE::LC_NUMERIC;
+pub use super::nl_BE::LC_TELEPHONE;
+pub use super::li_NL::LC_TIME;
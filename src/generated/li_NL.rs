@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("NLD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NLD");
+    /// `Some("NL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NL");
+    /// `Some("NL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Nederland")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Nederland");
+    /// `Some(528)`
+    pub const COUNTRY_NUM: Option<i64> = Some(528);
+    /// `Some("NL")`
+    pub const COUNTRY_POST: Option<&str> = Some("NL");
+    /// `Some("li")`
+    pub const LANG_AB: Option<&str> = Some("li");
+    /// `Some("lim")`
+    pub const LANG_LIB: Option<&str> = Some("lim");
+    /// `Some("Lèmbörgs")`
+    pub const LANG_NAME: Option<&str> = Some("Lèmbörgs");
+    /// `Some("lim")`
+    pub const LANG_TERM: Option<&str> = Some("lim");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_5);
+    /// `Some("Kenneth Christiansen, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Kenneth Christiansen, Pablo Saratxaga");
+    /// `Some("2003-11-30")`
+    pub const DATE: Option<&str> = Some("2003-11-30");
+    /// `Some("kenneth@gnu.org, pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("kenneth@gnu.org, pablo@mandriva.com");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Limburgish")`
+    pub const LANGUAGE: Option<&str> = Some("Limburgish");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("information from Kenneth Christiansen")`
+    pub const SOURCE: Option<&str> = Some("information from Kenneth Christiansen");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Netherlands")`
+    pub const TERRITORY: Option<&str> = Some("Netherlands");
+    /// `Some("Limburgish Language Locale for the Netherlands")`
+    pub const TITLE: Option<&str> = Some("Limburgish Language Locale for the Netherlands");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::nl_NL::LC_MONETARY;
+pub use super::nl_NL::LC_NUMERIC;
+pub use super::nl_NL::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["zón", "mao", "dae", "goo", "dón", "vri", "zao"]`
+    pub const ABDAY: &[&str] = &["zón", "mao", "dae", "goo", "dón", "vri", "zao"];
+    /// `&["jan", "fib", "mie", "epr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "des"]`
+    pub const ABMON: &[&str] = &["jan", "fib", "mie", "epr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["zóndig", "maondig", "daensdig", "goonsdig", "dónderdig", "vriedig", "zaoterdig"]`
+    pub const DAY: &[&str] = &["zóndig", "maondig", "daensdig", "goonsdig", "dónderdig", "vriedig", "zaoterdig"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d. %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["jannewarie", "fibberwarie", "miert", "eprèl", "meij", "junie", "julie", "augustus", "september", "oktober", "november", "desember"]`
+    pub const MON: &[&str] = &["jannewarie", "fibberwarie", "miert", "eprèl", "meij", "junie", "julie", "augustus", "september", "oktober", "november", "desember"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("ITA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ITA");
+    /// `Some("IT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IT");
+    /// `Some("I")`
+    pub const COUNTRY_CAR: Option<&str> = Some("I");
+    /// `Some("978-88,979-12")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-88,979-12");
+    /// `Some("Italia")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Italia");
+    /// `Some(380)`
+    pub const COUNTRY_NUM: Option<i64> = Some(380);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("lij")`
+    pub const LANG_LIB: Option<&str> = Some("lij");
+    /// `Some("Lìgure")`
+    pub const LANG_NAME: Option<&str> = Some("Lìgure");
+    /// `Some("lij")`
+    pub const LANG_TERM: Option<&str> = Some("lij");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2010-10-22")`
+    pub const DATE: Option<&str> = Some("2010-10-22");
+    /// `Some("alessio.gastaldi@libero.it")`
+    pub const EMAIL: Option<&str> = Some("alessio.gastaldi@libero.it");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Ligurian")`
+    pub const LANGUAGE: Option<&str> = Some("Ligurian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Gastaldi")`
+    pub const SOURCE: Option<&str> = Some("Gastaldi");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Italy")`
+    pub const TERRITORY: Option<&str> = Some("Italy");
+    /// `Some("Ligurian locale for Italy")`
+    pub const TITLE: Option<&str> = Some("Ligurian locale for Italy");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::it_IT::LC_MONETARY;
+pub use super::it_IT::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("39")`
+    pub const INT_PREFIX: Option<&str> = Some("39");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dom", "lûn", "mar", "mer", "zêu", "ven", "sab"]`
+    pub const ABDAY: &[&str] = &["dom", "lûn", "mar", "mer", "zêu", "ven", "sab"];
+    /// `&["zen", "fev", "mar", "arv", "maz", "zûg", "lûg", "ago", "set", "ött", "nov", "dix"]`
+    pub const ABMON: &[&str] = &["zen", "fev", "mar", "arv", "maz", "zûg", "lûg", "ago", "set", "ött", "nov", "dix"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %Y, %H.%M.%S, %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %Y, %H.%M.%S, %Z");
+    /// `&["domenega", "lûnedì", "martedì", "mercUrdì", "zêggia", "venardì", "sabbo"]`
+    pub const DAY: &[&str] = &["domenega", "lûnedì", "martedì", "mercUrdì", "zêggia", "venardì", "sabbo"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["zenâ", "fevrâ", "marzo", "avrî", "mazzo", "zûgno", "lûggio", "agosto", "settembre", "ottobre", "novembre", "dixembre"]`
+    pub const MON: &[&str] = &["zenâ", "fevrâ", "marzo", "avrî", "mazzo", "zûgno", "lûggio", "agosto", "settembre", "ottobre", "novembre", "dixembre"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
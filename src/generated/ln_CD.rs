@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("COD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("COD");
+    /// `Some("CD")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CD");
+    /// `Some("CGO")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CGO");
+    /// `Some("2")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("2");
+    /// `Some("Repibiki demokratiki ya Kongó")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Repibiki demokratiki ya Kongó");
+    /// `Some(180)`
+    pub const COUNTRY_NUM: Option<i64> = Some(180);
+    /// `Some("CD")`
+    pub const COUNTRY_POST: Option<&str> = Some("CD");
+    /// `Some("ln")`
+    pub const LANG_AB: Option<&str> = Some("ln");
+    /// `Some("lin")`
+    pub const LANG_LIB: Option<&str> = Some("lin");
+    /// `Some("lingála")`
+    pub const LANG_NAME: Option<&str> = Some("lingála");
+    /// `Some("lin")`
+    pub const LANG_TERM: Option<&str> = Some("lin");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Ubuntu DR Congo Team")`
+    pub const ADDRESS: Option<&str> = Some("Ubuntu DR Congo Team");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("René Manassé GALEKWA")`
+    pub const CONTACT: Option<&str> = Some("René Manassé GALEKWA");
+    /// `Some("2016-03-03")`
+    pub const DATE: Option<&str> = Some("2016-03-03");
+    /// `Some("renemanasse@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("renemanasse@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Lingala")`
+    pub const LANGUAGE: Option<&str> = Some("Lingala");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Ubuntu")`
+    pub const SOURCE: Option<&str> = Some("Ubuntu");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Democratic Republic of the Congo")`
+    pub const TERRITORY: Option<&str> = Some("Democratic Republic of the Congo");
+    /// `Some("Lingala locale for Democratic Republic of the Congo")`
+    pub const TITLE: Option<&str> = Some("Lingala locale for Democratic Republic of the Congo");
+}
+pub mod LC_MESSAGES {
+    /// `"^[nNtT]"`
+    pub const NOEXPR: &str = "^[nNtT]";
+    /// `Some("Tɛ\u{302}")`
+    pub const NOSTR: Option<&str> = Some("Tɛ\u{302}");
+    /// `"^[yYiIíÍ]"`
+    pub const YESEXPR: &str = "^[yYiIíÍ]";
+    /// `Some("Íyo")`
+    pub const YESSTR: Option<&str> = Some("Íyo");
+}
+pub mod LC_MONETARY {
+    /// `"FC"`
+    pub const CURRENCY_SYMBOL: &str = "FC";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"CDF "`
+    pub const INT_CURR_SYMBOL: &str = "CDF ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("243")`
+    pub const INT_PREFIX: Option<&str> = Some("243");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a%t%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a%t%l");
+}
+pub mod LC_TIME {
+    /// `&["m1.", "m2.", "m3.", "m4.", "m5.", "m6.", "m7."]`
+    pub const ABDAY: &[&str] = &["m1.", "m2.", "m3.", "m4.", "m5.", "m6.", "m7."];
+    /// `&["yan", "fbl", "msi", "apl", "mai", "yun", "yul", "agt", "stb", "ɔtb", "nvb", "dsb"]`
+    pub const ABMON: &[&str] = &["yan", "fbl", "msi", "apl", "mai", "yun", "yul", "agt", "stb", "ɔtb", "nvb", "dsb"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A %-e %B %Y, %H:%M:%S (UTC%z)")`
+    pub const DATE_FMT: Option<&str> = Some("%A %-e %B %Y, %H:%M:%S (UTC%z)");
+    /// `&["Lomíngo", "Mosálá mɔ\u{30c}kɔ\u{301}", "Misálá míbalé", "Misálá mísáto", "Misálá mínei", "Misálá mítáno", "Mpɔ\u{301}sɔ"]`
+    pub const DAY: &[&str] = &["Lomíngo", "Mosálá mɔ\u{30c}kɔ\u{301}", "Misálá míbalé", "Misálá mísáto", "Misálá mínei", "Misálá mítáno", "Mpɔ\u{301}sɔ"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["sánzá ya yambo", "sánzá ya míbalé", "sánzá ya mísáto", "sánzá ya mínei", "sánzá ya mítáno", "sánzá ya motóbá", "sánzá ya nsambo", "sánzá ya mwambe", "sánzá ya libwa", "sánzá ya zómi", "sánzá ya zómi na mɔ\u{30c}kɔ\u{301}", "sánzá ya zómi na míbalé"]`
+    pub const MON: &[&str] = &["sánzá ya yambo", "sánzá ya míbalé", "sánzá ya mísáto", "sánzá ya mínei", "sánzá ya mítáno", "sánzá ya motóbá", "sánzá ya nsambo", "sánzá ya mwambe", "sánzá ya libwa", "sánzá ya zómi", "sánzá ya zómi na mɔ\u{30c}kɔ\u{301}", "sánzá ya zómi na míbalé"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("LAO")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LAO");
+    /// `Some("LA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LA");
+    /// `Some("LAO")`
+    pub const COUNTRY_CAR: Option<&str> = Some("LAO");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ລາວ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ລາວ");
+    /// `Some(418)`
+    pub const COUNTRY_NUM: Option<i64> = Some(418);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("lo")`
+    pub const LANG_AB: Option<&str> = Some("lo");
+    /// `Some("lao")`
+    pub const LANG_LIB: Option<&str> = Some("lao");
+    /// `Some("ລາວ")`
+    pub const LANG_NAME: Option<&str> = Some("ລາວ");
+    /// `Some("lao")`
+    pub const LANG_TERM: Option<&str> = Some("lao");
+    /// `Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Anousak Souphavanh at: anousak@muanglao.com")`
+    pub const CONTACT: Option<&str> = Some("Anousak Souphavanh at: anousak@muanglao.com");
+    /// `Some("2003-4-1")`
+    pub const DATE: Option<&str> = Some("2003-4-1");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Lao")`
+    pub const LANGUAGE: Option<&str> = Some("Lao");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Laos")`
+    pub const TERRITORY: Option<&str> = Some("Laos");
+    /// `Some("Lao locale for Laos")`
+    pub const TITLE: Option<&str> = Some("Lao locale for Laos");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNບ]"`
+    pub const NOEXPR: &str = "^[-0nNບ]";
+    /// `Some("ບ\u{ecd}\u{ec8}ແມ\u{ec8}ນ")`
+    pub const NOSTR: Option<&str> = Some("ບ\u{ecd}\u{ec8}ແມ\u{ec8}ນ");
+    /// `"^[+1yYມ]"`
+    pub const YESEXPR: &str = "^[+1yYມ]";
+    /// `Some("ແມ\u{ec8}ນ")`
+    pub const YESSTR: Option<&str> = Some("ແມ\u{ec8}ນ");
+}
+pub mod LC_MONETARY {
+    /// `"₭"`
+    pub const CURRENCY_SYMBOL: &str = "₭";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"LAK "`
+    pub const INT_CURR_SYMBOL: &str = "LAK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `2`
+    pub const N_SEP_BY_SPACE: i64 = 2;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `2`
+    pub const P_SEP_BY_SPACE: i64 = 2;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("856")`
+    pub const INT_PREFIX: Option<&str> = Some("856");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `Some("(%A)%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%A)%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ອາ.", "ຈ.", "ຄ.", "ພ.", "ພຫ.", "ສ.", "ສ."]`
+    pub const ABDAY: &[&str] = &["ອາ.", "ຈ.", "ຄ.", "ພ.", "ພຫ.", "ສ.", "ສ."];
+    /// `&["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."]`
+    pub const ABMON: &[&str] = &["ມ.ກ.", "ກ.ພ.", "ມ.ນ.", "ມ.ສ.", "ພ.ພ.", "ມ\u{eb4}.ຖ.", "ກ.ລ.", "ສ.ຫ.", "ກ.ຍ.", "ຕ.ລ.", "ພ.ຈ.", "ທ.ວ."];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %Ey %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %Ey %H:%M:%S %Z");
+    /// `&["ອາທ\u{eb4}ດ", "ຈ\u{eb1}ນ", "ອ\u{eb1}ງຄານ", "ພ\u{eb8}ດ", "ພະຫ\u{eb1}ດ", "ສ\u{eb8}ກ", "ເສ\u{ebb}າ"]`
+    pub const DAY: &[&str] = &["ອາທ\u{eb4}ດ", "ຈ\u{eb1}ນ", "ອ\u{eb1}ງຄານ", "ພ\u{eb8}ດ", "ພະຫ\u{eb1}ດ", "ສ\u{eb8}ກ", "ເສ\u{ebb}າ"];
+    /// `"%d/%m/%Ey"`
+    pub const D_FMT: &str = "%d/%m/%Ey";
+    /// `"%a %e %b %Ey, %H:%M:%S"`
+    pub const D_T_FMT: &str = "%a %e %b %Ey, %H:%M:%S";
+    /// `Some(&["+:1:-543/01/01:+*:ພ.ສ.:%EC %Ey"])`
+    pub const ERA: Option<&[&str]> = Some(&["+:1:-543/01/01:+*:ພ.ສ.:%EC %Ey"]);
+    /// `Some("%e %b %Ey")`
+    pub const ERA_D_FMT: Option<&str> = Some("%e %b %Ey");
+    /// `Some("ວ\u{eb1}ນ%Aທ\u{eb5}\u{ec8} %e %B %EC %Ey, %H.%M.%S ນ.")`
+    pub const ERA_D_T_FMT: Option<&str> = Some("ວ\u{eb1}ນ%Aທ\u{eb5}\u{ec8} %e %B %EC %Ey, %H.%M.%S ນ.");
+    /// `Some("%H.%M.%S ນ.")`
+    pub const ERA_T_FMT: Option<&str> = Some("%H.%M.%S ນ.");
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"]`
+    pub const MON: &[&str] = &["ມ\u{eb1}ງກອນ", "ກ\u{eb8}ມພາ", "ມ\u{eb5}ນາ", "ເມສາ", "ພ\u{eb6}ດສະພາ", "ມ\u{eb4}ຖ\u{eb8}ນາ", "ກ\u{ecd}ລະກ\u{ebb}ດ", "ສ\u{eb4}ງຫາ", "ກ\u{eb1}ນຍາ", "ຕ\u{eb8}ລາ", "ພະຈ\u{eb4}ກ", "ທ\u{eb1}ນວາ"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,5883 @@
+
+/// Locales matching the locales in `glibc`.
+///
+/// Most locale names follow the syntax `language[_territory][@modifier]`.
+/// The `@` is replaced with `_` in the `enum` variant names.
+///
+/// The default locale is `POSIX`.
+///
+/// License note: The Free Software Foundation does not claim any copyright interest in the locale
+/// data of the GNU C Library; they believe it is not copyrightable.
+///
+/// The discriminant of a locale is its ID: see [`Locale::to_u16`].
+#[allow(non_camel_case_types,dead_code)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum Locale {
+    /// `POSIX`: POSIX Standard Locale.
+    POSIX = 0,
+    /// `aa_DJ`: Afar language locale for Djibouti (Cadu/Laaqo Dialects).
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj"))]
+    aa_DJ = 1,
+    /// `aa_ER`: Afar language locale for Eritrea (Cadu/Laaqo Dialects).
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    aa_ER = 2,
+    /// `aa_ER@saaho`: Afar language locale for Eritrea (Saaho Dialect).
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    aa_ER_saaho = 3,
+    /// `aa_ET`: Afar language locale for Ethiopia (Cadu/Carra Dialects).
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-et"))]
+    aa_ET = 4,
+    /// `af_ZA`: Afrikaans locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-af", feature = "region-za"))]
+    af_ZA = 5,
+    /// `agr_PE`: Awajún (agr) locale for Peru.
+    #[cfg(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe"))]
+    agr_PE = 6,
+    /// `ak_GH`: Akan locale for Ghana.
+    #[cfg(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh"))]
+    ak_GH = 7,
+    /// `am_ET`: Amharic language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-am", feature = "region-et"))]
+    am_ET = 8,
+    /// `an_ES`: Aragonese locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-an", feature = "region-es"))]
+    an_ES = 9,
+    /// `anp_IN`: Angika language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-anp", feature = "region-in"))]
+    anp_IN = 10,
+    /// `ar_AE`: Arabic language locale for United Arab Emirates.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae"))]
+    ar_AE = 11,
+    /// `ar_BH`: Arabic language locale for Bahrain.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh"))]
+    ar_BH = 12,
+    /// `ar_DZ`: Arabic language locale for Algeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz"))]
+    ar_DZ = 13,
+    /// `ar_EG`: Arabic language locale for Egypt.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg"))]
+    ar_EG = 14,
+    /// `ar_IN`: Arabic language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-in"))]
+    ar_IN = 15,
+    /// `ar_IQ`: Arabic language locale for Iraq.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq"))]
+    ar_IQ = 16,
+    /// `ar_JO`: Arabic language locale for Jordan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo"))]
+    ar_JO = 17,
+    /// `ar_KW`: Arabic language locale for Kuwait.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw"))]
+    ar_KW = 18,
+    /// `ar_LB`: Arabic language locale for Lebanon.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb"))]
+    ar_LB = 19,
+    /// `ar_LY`: Arabic language locale for Libyan Arab Jamahiriya.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly"))]
+    ar_LY = 20,
+    /// `ar_MA`: Arabic language locale for Morocco.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma"))]
+    ar_MA = 21,
+    /// `ar_OM`: Arabic language locale for Oman.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-om"))]
+    ar_OM = 22,
+    /// `ar_QA`: Arabic language locale for Qatar.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa"))]
+    ar_QA = 23,
+    /// `ar_SA`: Arabic locale for Saudi Arabia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa"))]
+    ar_SA = 24,
+    /// `ar_SD`: Arabic language locale for Sudan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd"))]
+    ar_SD = 25,
+    /// `ar_SS`: Arabic language locale for South Sudan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss"))]
+    ar_SS = 26,
+    /// `ar_SY`: Arabic language locale for Syrian Arab Republic.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy"))]
+    ar_SY = 27,
+    /// `ar_TN`: Arabic language locale for Tunisia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn"))]
+    ar_TN = 28,
+    /// `ar_YE`: Arabic language locale for Yemen.
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye"))]
+    ar_YE = 29,
+    /// `as_IN`: Assamese language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-as", feature = "region-in"))]
+    as_IN = 30,
+    /// `ast_ES`: Asturian locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-ast", feature = "region-es"))]
+    ast_ES = 31,
+    /// `ayc_PE`: Aymara (ayc) locale for Peru.
+    #[cfg(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe"))]
+    ayc_PE = 32,
+    /// `az_AZ`: Azeri language locale for Azerbaijan (latin).
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-az"))]
+    az_AZ = 33,
+    /// `az_IR`: South Azerbaijani language locale for Iran.
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-ir"))]
+    az_IR = 34,
+    /// `be_BY`: Belarusian locale for Belarus.
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    be_BY = 35,
+    /// `be_BY@latin`: Belarusian Latin-Script locale for Belarus.
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    be_BY_latin = 36,
+    /// `bem_ZM`: Bemba locale for Zambia.
+    #[cfg(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm"))]
+    bem_ZM = 37,
+    /// `ber_DZ`: Berber language locale for Algeria (latin).
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz"))]
+    ber_DZ = 38,
+    /// `ber_MA`: Berber language locale for Morocco (tifinagh).
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma"))]
+    ber_MA = 39,
+    /// `bg_BG`: Bulgarian locale for Bulgaria.
+    #[cfg(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg"))]
+    bg_BG = 40,
+    /// `bhb_IN`: Bhili(devanagari) language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in"))]
+    bhb_IN = 41,
+    /// `bho_IN`: Bhojpuri language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-in"))]
+    bho_IN = 42,
+    /// `bho_NP`: Bhojpuri language locale for Nepal.
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-np"))]
+    bho_NP = 43,
+    /// `bi_VU`: Bislama language locale for Vanuatu.
+    #[cfg(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu"))]
+    bi_VU = 44,
+    /// `bn_BD`: Bangla language locale for Bangladesh.
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd"))]
+    bn_BD = 45,
+    /// `bn_IN`: Bangla language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-in"))]
+    bn_IN = 46,
+    /// `bo_CN`: Tibetan language locale for P.R. of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn"))]
+    bo_CN = 47,
+    /// `bo_IN`: Tibetan language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-in"))]
+    bo_IN = 48,
+    /// `br_FR`: Breton language locale for France.
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    br_FR = 49,
+    /// `br_FR@euro`: Breton locale for France with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    br_FR_euro = 50,
+    /// `brx_IN`: Bodo language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-brx", feature = "region-in"))]
+    brx_IN = 51,
+    /// `bs_BA`: Bosnian language locale for Bosnia and Herzegowina.
+    #[cfg(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba"))]
+    bs_BA = 52,
+    /// `byn_ER`: Blin language locale for Eritrea.
+    #[cfg(any(feature = "all-locales", feature = "lang-byn", feature = "region-er"))]
+    byn_ER = 53,
+    /// `ca_AD`: Catalan locale for Andorra .
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad"))]
+    ca_AD = 54,
+    /// `ca_ES`: Catalan locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    ca_ES = 55,
+    /// `ca_ES@euro`: Catalan locale for Catalonia with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    ca_ES_euro = 56,
+    /// `ca_ES@valencia`: Valencian (southern Catalan) locale for Spain with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    ca_ES_valencia = 57,
+    /// `ca_FR`: Catalan locale for France .
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr"))]
+    ca_FR = 58,
+    /// `ca_IT`: Catalan locale for Italy (L'Alguer) .
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-it"))]
+    ca_IT = 59,
+    /// `ce_RU`: Chechen locale for RUSSIAN FEDERATION.
+    #[cfg(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru"))]
+    ce_RU = 60,
+    /// `chr_US`: Cherokee language locale for United States.
+    #[cfg(any(feature = "all-locales", feature = "lang-chr", feature = "region-us"))]
+    chr_US = 61,
+    /// `cmn_TW`: Mandarin Chinese locale for the Republic of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw"))]
+    cmn_TW = 62,
+    /// `crh_UA`: Crimean Tatar (Crimean Turkish) language locale for Ukraine.
+    #[cfg(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua"))]
+    crh_UA = 63,
+    /// `cs_CZ`: Czech locale for the Czech Republic.
+    #[cfg(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz"))]
+    cs_CZ = 64,
+    /// `csb_PL`: Kashubian locale for Poland.
+    #[cfg(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl"))]
+    csb_PL = 65,
+    /// `cv_RU`: Chuvash locale for Russia.
+    #[cfg(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru"))]
+    cv_RU = 66,
+    /// `cy_GB`: Welsh language locale for Great Britain.
+    #[cfg(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb"))]
+    cy_GB = 67,
+    /// `da_DK`: Danish locale for Denmark.
+    #[cfg(any(feature = "all-locales", feature = "lang-da", feature = "region-dk"))]
+    da_DK = 68,
+    /// `de_AT`: German locale for Austria.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    de_AT = 69,
+    /// `de_AT@euro`: German locale for Austria with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    de_AT_euro = 70,
+    /// `de_BE`: German locale for Belgium.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    de_BE = 71,
+    /// `de_BE@euro`: German locale for Belgium with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    de_BE_euro = 72,
+    /// `de_CH`: German locale for Switzerland.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-ch"))]
+    de_CH = 73,
+    /// `de_DE`: German locale for Germany.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    de_DE = 74,
+    /// `de_DE@euro`: German locale for Germany with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    de_DE_euro = 75,
+    /// `de_IT`: German language locale for Italy.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-it"))]
+    de_IT = 76,
+    /// `de_LI`: German locale for Liechtenstein.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-li"))]
+    de_LI = 77,
+    /// `de_LU`: German locale for Luxemburg.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    de_LU = 78,
+    /// `de_LU@euro`: German locale for Luxemburg with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    de_LU_euro = 79,
+    /// `doi_IN`: Dogri language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-doi", feature = "region-in"))]
+    doi_IN = 80,
+    /// `dsb_DE`: Lower Sorbian locale for Germany.
+    #[cfg(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de"))]
+    dsb_DE = 81,
+    /// `dv_MV`: Dhivehi Language Locale for Maldives.
+    #[cfg(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv"))]
+    dv_MV = 82,
+    /// `dz_BT`: Dzongkha language locale for Bhutan.
+    #[cfg(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt"))]
+    dz_BT = 83,
+    /// `el_CY`: Greek locale for Cyprus.
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-cy"))]
+    el_CY = 84,
+    /// `el_GR`: Greek locale for Greece.
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    el_GR = 85,
+    /// `el_GR@euro`: Greek locale for Greece with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    el_GR_euro = 86,
+    /// `en_AG`: English language locale for Antigua and Barbuda.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ag"))]
+    en_AG = 87,
+    /// `en_AU`: English locale for Australia.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-au"))]
+    en_AU = 88,
+    /// `en_BW`: English locale for Botswana.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-bw"))]
+    en_BW = 89,
+    /// `en_CA`: English locale for Canada.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ca"))]
+    en_CA = 90,
+    /// `en_DK`: English locale for Denmark.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-dk"))]
+    en_DK = 91,
+    /// `en_GB`: English locale for Britain.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-gb"))]
+    en_GB = 92,
+    /// `en_HK`: English locale for Hong Kong.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-hk"))]
+    en_HK = 93,
+    /// `en_IE`: English locale for Ireland.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    en_IE = 94,
+    /// `en_IE@euro`: English locale for Ireland with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    en_IE_euro = 95,
+    /// `en_IL`: English locale for Israel.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-il"))]
+    en_IL = 96,
+    /// `en_IN`: English language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-in"))]
+    en_IN = 97,
+    /// `en_NG`: English locale for Nigeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ng"))]
+    en_NG = 98,
+    /// `en_NZ`: English locale for New Zealand.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-nz"))]
+    en_NZ = 99,
+    /// `en_PH`: English language locale for Philippines.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ph"))]
+    en_PH = 100,
+    /// `en_SC`: English locale for the Seychelles.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sc"))]
+    en_SC = 101,
+    /// `en_SG`: English language locale for Singapore.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sg"))]
+    en_SG = 102,
+    /// `en_US`: English locale for the USA.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us"))]
+    en_US = 103,
+    /// `en_ZA`: English locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-za"))]
+    en_ZA = 104,
+    /// `en_ZM`: English locale for Zambia.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zm"))]
+    en_ZM = 105,
+    /// `en_ZW`: English locale for Zimbabwe.
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zw"))]
+    en_ZW = 106,
+    /// `eo`: Esperanto language locale.
+    #[cfg(any(feature = "all-locales", feature = "lang-eo"))]
+    eo = 107,
+    /// `es_AR`: Spanish locale for Argentina.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ar"))]
+    es_AR = 108,
+    /// `es_BO`: Spanish locale for Bolivia.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-bo"))]
+    es_BO = 109,
+    /// `es_CL`: Spanish locale for Chile.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cl"))]
+    es_CL = 110,
+    /// `es_CO`: Spanish locale for Colombia.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-co"))]
+    es_CO = 111,
+    /// `es_CR`: Spanish locale for Costa Rica.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cr"))]
+    es_CR = 112,
+    /// `es_CU`: Spanish locale for Cuba.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cu"))]
+    es_CU = 113,
+    /// `es_DO`: Spanish locale for Dominican Republic.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-do"))]
+    es_DO = 114,
+    /// `es_EC`: Spanish locale for Ecuador.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ec"))]
+    es_EC = 115,
+    /// `es_ES`: Spanish locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    es_ES = 116,
+    /// `es_ES@euro`: Spanish locale for Spain with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    es_ES_euro = 117,
+    /// `es_GT`: Spanish locale for Guatemala.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-gt"))]
+    es_GT = 118,
+    /// `es_HN`: Spanish locale for Honduras.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-hn"))]
+    es_HN = 119,
+    /// `es_MX`: Spanish locale for Mexico.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-mx"))]
+    es_MX = 120,
+    /// `es_NI`: Spanish locale for Nicaragua.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ni"))]
+    es_NI = 121,
+    /// `es_PA`: Spanish locale for Panama.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pa"))]
+    es_PA = 122,
+    /// `es_PE`: Spanish locale for Peru.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pe"))]
+    es_PE = 123,
+    /// `es_PR`: Spanish locale for Puerto Rico.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pr"))]
+    es_PR = 124,
+    /// `es_PY`: Spanish locale for Paraguay.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-py"))]
+    es_PY = 125,
+    /// `es_SV`: Spanish locale for El Salvador.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-sv"))]
+    es_SV = 126,
+    /// `es_US`: Spanish locale for the USA.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-us"))]
+    es_US = 127,
+    /// `es_UY`: Spanish locale for Uruguay.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-uy"))]
+    es_UY = 128,
+    /// `es_VE`: Spanish locale for Venezuela.
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ve"))]
+    es_VE = 129,
+    /// `et_EE`: Estonian locale for Estonia.
+    #[cfg(any(feature = "all-locales", feature = "lang-et", feature = "region-ee"))]
+    et_EE = 130,
+    /// `eu_ES`: Basque locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    eu_ES = 131,
+    /// `eu_ES@euro`: Basque language locale for Spain with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    eu_ES_euro = 132,
+    /// `fa_IR`: Persian locale for Iran.
+    #[cfg(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir"))]
+    fa_IR = 133,
+    /// `ff_SN`: Fulah locale for Senegal.
+    #[cfg(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn"))]
+    ff_SN = 134,
+    /// `fi_FI`: Finnish locale for Finland.
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    fi_FI = 135,
+    /// `fi_FI@euro`: Finnish locale for Finland with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    fi_FI_euro = 136,
+    /// `fil_PH`: Filipino language locale for Philippines.
+    #[cfg(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph"))]
+    fil_PH = 137,
+    /// `fo_FO`: Faroese locale for Faroe Islands.
+    #[cfg(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo"))]
+    fo_FO = 138,
+    /// `fr_BE`: French locale for Belgium.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    fr_BE = 139,
+    /// `fr_BE@euro`: French locale for Belgium with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    fr_BE_euro = 140,
+    /// `fr_CA`: French locale for Canada.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca"))]
+    fr_CA = 141,
+    /// `fr_CH`: French locale for Switzerland.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch"))]
+    fr_CH = 142,
+    /// `fr_FR`: French locale for France.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    fr_FR = 143,
+    /// `fr_FR@euro`: French locale for France with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    fr_FR_euro = 144,
+    /// `fr_LU`: French locale for Luxemburg.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    fr_LU = 145,
+    /// `fr_LU@euro`: French locale for Luxemburg with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    fr_LU_euro = 146,
+    /// `fur_IT`: Furlan locale for Italy.
+    #[cfg(any(feature = "all-locales", feature = "lang-fur", feature = "region-it"))]
+    fur_IT = 147,
+    /// `fy_DE`: Sater Frisian and North Frisian Locale for Germany.
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-de"))]
+    fy_DE = 148,
+    /// `fy_NL`: Frisian locale for the Netherlands.
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl"))]
+    fy_NL = 149,
+    /// `ga_IE`: Irish locale for Ireland.
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    ga_IE = 150,
+    /// `ga_IE@euro`: Irish locale for Ireland with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    ga_IE_euro = 151,
+    /// `gd_GB`: Scots Gaelic language locale for Great Britain.
+    #[cfg(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb"))]
+    gd_GB = 152,
+    /// `gez_ER`: Ge'ez language locale for Eritrea.
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    gez_ER = 153,
+    /// `gez_ER@abegede`: Ge'ez language locale for Eritrea With Abegede Collation.
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    gez_ER_abegede = 154,
+    /// `gez_ET`: Ge'ez language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    gez_ET = 155,
+    /// `gez_ET@abegede`: Ge'ez language locale for Ethiopia With Abegede Collation.
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    gez_ET_abegede = 156,
+    /// `gl_ES`: Galician locale for Spain.
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    gl_ES = 157,
+    /// `gl_ES@euro`: Galician locale for Spain with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    gl_ES_euro = 158,
+    /// `gu_IN`: Gujarati Language Locale For India.
+    #[cfg(any(feature = "all-locales", feature = "lang-gu", feature = "region-in"))]
+    gu_IN = 159,
+    /// `gv_GB`: Manx Gaelic locale for Britain.
+    #[cfg(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb"))]
+    gv_GB = 160,
+    /// `ha_NG`: Hausa locale for Nigeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng"))]
+    ha_NG = 161,
+    /// `hak_TW`: Hakka Chinese locale for the Republic of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw"))]
+    hak_TW = 162,
+    /// `he_IL`: Hebrew locale for Israel.
+    #[cfg(any(feature = "all-locales", feature = "lang-he", feature = "region-il"))]
+    he_IL = 163,
+    /// `hi_IN`: Hindi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-hi", feature = "region-in"))]
+    hi_IN = 164,
+    /// `hif_FJ`: Fiji Hindi (Latin) language locale for Fiji.
+    #[cfg(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj"))]
+    hif_FJ = 165,
+    /// `hne_IN`: Chhattisgarhi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-hne", feature = "region-in"))]
+    hne_IN = 166,
+    /// `hr_HR`: Croatian locale for Croatia.
+    #[cfg(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr"))]
+    hr_HR = 167,
+    /// `hsb_DE`: Upper Sorbian locale for Germany.
+    #[cfg(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de"))]
+    hsb_DE = 168,
+    /// `ht_HT`: Kreyol locale for Haiti.
+    #[cfg(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht"))]
+    ht_HT = 169,
+    /// `hu_HU`: Hungarian locale for Hungary.
+    #[cfg(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu"))]
+    hu_HU = 170,
+    /// `hy_AM`: Armenian language locale for Armenia.
+    #[cfg(any(feature = "all-locales", feature = "lang-hy", feature = "region-am"))]
+    hy_AM = 171,
+    /// `ia_FR`: Interlingua locale for France.
+    #[cfg(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr"))]
+    ia_FR = 172,
+    /// `id_ID`: Indonesian locale for Indonesia.
+    #[cfg(any(feature = "all-locales", feature = "lang-id", feature = "region-id"))]
+    id_ID = 173,
+    /// `ig_NG`: Igbo locale for Nigeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng"))]
+    ig_NG = 174,
+    /// `ik_CA`: Inupiaq locale for Canada.
+    #[cfg(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca"))]
+    ik_CA = 175,
+    /// `is_IS`: Icelandic locale for Iceland.
+    #[cfg(any(feature = "all-locales", feature = "lang-is", feature = "region-is"))]
+    is_IS = 176,
+    /// `it_CH`: Italian locale for Switzerland.
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-ch"))]
+    it_CH = 177,
+    /// `it_IT`: Italian locale for Italy.
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    it_IT = 178,
+    /// `it_IT@euro`: Italian locale for Italy with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    it_IT_euro = 179,
+    /// `iu_CA`: Inuktitut language locale for Nunavut, Canada.
+    #[cfg(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca"))]
+    iu_CA = 180,
+    /// `ja_JP`: Japanese language locale for Japan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp"))]
+    ja_JP = 181,
+    /// `ka_GE`: Georgian language locale for Georgia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge"))]
+    ka_GE = 182,
+    /// `kab_DZ`: Kabyle language locale for Algeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz"))]
+    kab_DZ = 183,
+    /// `kk_KZ`: Kazakh locale for Kazakhstan.
+    #[cfg(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz"))]
+    kk_KZ = 184,
+    /// `kl_GL`: Greenlandic locale for Greenland.
+    #[cfg(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl"))]
+    kl_GL = 185,
+    /// `km_KH`: Khmer locale for Cambodia.
+    #[cfg(any(feature = "all-locales", feature = "lang-km", feature = "region-kh"))]
+    km_KH = 186,
+    /// `kn_IN`: Kannada language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-kn", feature = "region-in"))]
+    kn_IN = 187,
+    /// `ko_KR`: Korean locale for Republic of Korea.
+    #[cfg(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr"))]
+    ko_KR = 188,
+    /// `kok_IN`: Konkani language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-kok", feature = "region-in"))]
+    kok_IN = 189,
+    /// `ks_IN`: Kashmiri language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    ks_IN = 190,
+    /// `ks_IN@devanagari`: Kashmiri(devanagari) language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    ks_IN_devanagari = 191,
+    /// `ku_TR`: Kurdish (latin) locale for Turkey.
+    #[cfg(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr"))]
+    ku_TR = 192,
+    /// `kw_GB`: Cornish locale for Britain.
+    #[cfg(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb"))]
+    kw_GB = 193,
+    /// `ky_KG`: Kyrgyz Language Locale for Kyrgyzstan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg"))]
+    ky_KG = 194,
+    /// `lb_LU`: Luxembourgish locale for Luxembourg.
+    #[cfg(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu"))]
+    lb_LU = 195,
+    /// `lg_UG`: Luganda locale for Uganda.
+    #[cfg(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug"))]
+    lg_UG = 196,
+    /// `li_BE`: Limburgish Language Locale for Belgium.
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-be"))]
+    li_BE = 197,
+    /// `li_NL`: Limburgish Language Locale for the Netherlands.
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-nl"))]
+    li_NL = 198,
+    /// `lij_IT`: Ligurian locale for Italy.
+    #[cfg(any(feature = "all-locales", feature = "lang-lij", feature = "region-it"))]
+    lij_IT = 199,
+    /// `ln_CD`: Lingala locale for Democratic Republic of the Congo.
+    #[cfg(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd"))]
+    ln_CD = 200,
+    /// `lo_LA`: Lao locale for Laos.
+    #[cfg(any(feature = "all-locales", feature = "lang-lo", feature = "region-la"))]
+    lo_LA = 201,
+    /// `lt_LT`: Lithuanian locale for Lithuania.
+    #[cfg(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt"))]
+    lt_LT = 202,
+    /// `lv_LV`: Latvian locale for Latvia.
+    #[cfg(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv"))]
+    lv_LV = 203,
+    /// `lzh_TW`: Literary Chinese locale for the Republic of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw"))]
+    lzh_TW = 204,
+    /// `mag_IN`: Magahi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-mag", feature = "region-in"))]
+    mag_IN = 205,
+    /// `mai_IN`: Maithili language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-in"))]
+    mai_IN = 206,
+    /// `mai_NP`: Maithili language locale for Nepal.
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-np"))]
+    mai_NP = 207,
+    /// `mfe_MU`: Morisyen locale for Mauritius.
+    #[cfg(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu"))]
+    mfe_MU = 208,
+    /// `mg_MG`: Malagasy locale for Madagascar.
+    #[cfg(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg"))]
+    mg_MG = 209,
+    /// `mhr_RU`: Mari locale for Russia.
+    #[cfg(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru"))]
+    mhr_RU = 210,
+    /// `mi_NZ`: Maori language locale for New Zealand.
+    #[cfg(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz"))]
+    mi_NZ = 211,
+    /// `miq_NI`: Miskito language locale for Nicaragua.
+    #[cfg(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni"))]
+    miq_NI = 212,
+    /// `mjw_IN`: Karbi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in"))]
+    mjw_IN = 213,
+    /// `mk_MK`: Macedonian locale for Macedonia.
+    #[cfg(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk"))]
+    mk_MK = 214,
+    /// `ml_IN`: Malayalam language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ml", feature = "region-in"))]
+    ml_IN = 215,
+    /// `mn_MN`: Mongolian locale for Mongolia.
+    #[cfg(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn"))]
+    mn_MN = 216,
+    /// `mni_IN`: Manipuri language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-mni", feature = "region-in"))]
+    mni_IN = 217,
+    /// `mnw_MM`: Mon language locale for Myanmar.
+    #[cfg(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm"))]
+    mnw_MM = 218,
+    /// `mr_IN`: Marathi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-mr", feature = "region-in"))]
+    mr_IN = 219,
+    /// `ms_MY`: Malay language locale for Malaysia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ms", feature = "region-my"))]
+    ms_MY = 220,
+    /// `mt_MT`: Maltese language locale for Malta.
+    #[cfg(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt"))]
+    mt_MT = 221,
+    /// `my_MM`: Burmese language locale for Myanmar.
+    #[cfg(any(feature = "all-locales", feature = "lang-my", feature = "region-mm"))]
+    my_MM = 222,
+    /// `nan_TW`: Min Nan Chinese locale for the Republic of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    nan_TW = 223,
+    /// `nan_TW@latin`: Minnan language locale for Taiwan.
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    nan_TW_latin = 224,
+    /// `nb_NO`: Norwegian (Bokmål) locale for Norway.
+    #[cfg(any(feature = "all-locales", feature = "lang-nb", feature = "region-no"))]
+    nb_NO = 225,
+    /// `nds_DE`: Low(lands) Saxon Language Locale for Germany.
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-de"))]
+    nds_DE = 226,
+    /// `nds_NL`: Low(lands) Saxon Language Locale for the Netherlands.
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl"))]
+    nds_NL = 227,
+    /// `ne_NP`: Nepali language locale for Nepal.
+    #[cfg(any(feature = "all-locales", feature = "lang-ne", feature = "region-np"))]
+    ne_NP = 228,
+    /// `nhn_MX`: Central Nahuatl for Mexico.
+    #[cfg(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx"))]
+    nhn_MX = 229,
+    /// `niu_NU`: Niuean (Vagahau Niue) locale for Niue.
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu"))]
+    niu_NU = 230,
+    /// `niu_NZ`: Niuean (Vagahau Niue) locale for New Zealand.
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz"))]
+    niu_NZ = 231,
+    /// `nl_AW`: Dutch language locale for Aruba.
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw"))]
+    nl_AW = 232,
+    /// `nl_BE`: Dutch locale for Belgium.
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    nl_BE = 233,
+    /// `nl_BE@euro`: Dutch locale for Belgium with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    nl_BE_euro = 234,
+    /// `nl_NL`: Dutch locale for the Netherlands.
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    nl_NL = 235,
+    /// `nl_NL@euro`: Dutch locale for the Netherlands with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    nl_NL_euro = 236,
+    /// `nn_NO`: Nynorsk language locale for Norway.
+    #[cfg(any(feature = "all-locales", feature = "lang-nn", feature = "region-no"))]
+    nn_NO = 237,
+    /// `nr_ZA`: Southern Ndebele locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-nr", feature = "region-za"))]
+    nr_ZA = 238,
+    /// `nso_ZA`: Northern Sotho locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-nso", feature = "region-za"))]
+    nso_ZA = 239,
+    /// `oc_FR`: Occitan Language Locale for France.
+    #[cfg(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr"))]
+    oc_FR = 240,
+    /// `om_ET`: Oromo language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-et"))]
+    om_ET = 241,
+    /// `om_KE`: Oromo language locale for Kenya.
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-ke"))]
+    om_KE = 242,
+    /// `or_IN`: Odia language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-or", feature = "region-in"))]
+    or_IN = 243,
+    /// `os_RU`: Ossetian locale for Russia.
+    #[cfg(any(feature = "all-locales", feature = "lang-os", feature = "region-ru"))]
+    os_RU = 244,
+    /// `pa_IN`: Punjabi language locale for Indian Punjabi(Gurmukhi).
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-in"))]
+    pa_IN = 245,
+    /// `pa_PK`: Punjabi (Shahmukhi) Language Locale for Pakistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk"))]
+    pa_PK = 246,
+    /// `pap_AW`: Papiamento Language for Aruba.
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw"))]
+    pap_AW = 247,
+    /// `pap_CW`: Papiamento language for Curaçao.
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw"))]
+    pap_CW = 248,
+    /// `pl_PL`: Polish locale for Poland.
+    #[cfg(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl"))]
+    pl_PL = 249,
+    /// `ps_AF`: Pashto locale for Afghanistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ps", feature = "region-af"))]
+    ps_AF = 250,
+    /// `pt_BR`: Portuguese locale for Brasil.
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-br"))]
+    pt_BR = 251,
+    /// `pt_PT`: Portuguese locale for Portugal.
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pt_PT = 252,
+    /// `pt_PT@euro`: Portuguese locale for Portugal with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pt_PT_euro = 253,
+    /// `quz_PE`: Cusco Quechua locale for Peru.
+    #[cfg(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe"))]
+    quz_PE = 254,
+    /// `raj_IN`: Rajasthani language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-raj", feature = "region-in"))]
+    raj_IN = 255,
+    /// `ro_RO`: Romanian locale for Romania.
+    #[cfg(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro"))]
+    ro_RO = 256,
+    /// `ru_RU`: Russian locale for Russia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru"))]
+    ru_RU = 257,
+    /// `ru_UA`: Russian locale for Ukraine.
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua"))]
+    ru_UA = 258,
+    /// `rw_RW`: Kinyarwanda language locale for Rwanda.
+    #[cfg(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw"))]
+    rw_RW = 259,
+    /// `sa_IN`: Sanskrit language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-sa", feature = "region-in"))]
+    sa_IN = 260,
+    /// `sah_RU`: Sakha (Yakut) locale for Russian Federation.
+    #[cfg(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru"))]
+    sah_RU = 261,
+    /// `sat_IN`: Santali language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-sat", feature = "region-in"))]
+    sat_IN = 262,
+    /// `sc_IT`: Sardinian locale for Italy.
+    #[cfg(any(feature = "all-locales", feature = "lang-sc", feature = "region-it"))]
+    sc_IT = 263,
+    /// `sd_IN`: Sindhi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    sd_IN = 264,
+    /// `sd_IN@devanagari`: Sindhi language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    sd_IN_devanagari = 265,
+    /// `se_NO`: Northern Saami language locale for Norway.
+    #[cfg(any(feature = "all-locales", feature = "lang-se", feature = "region-no"))]
+    se_NO = 266,
+    /// `sgs_LT`: Samogitian language locale for Lithuania.
+    #[cfg(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt"))]
+    sgs_LT = 267,
+    /// `shn_MM`: Shan language locale for Myanmar.
+    #[cfg(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm"))]
+    shn_MM = 268,
+    /// `shs_CA`: Shuswap locale for Canada.
+    #[cfg(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca"))]
+    shs_CA = 269,
+    /// `si_LK`: Sinhala language locale for Sri Lanka.
+    #[cfg(any(feature = "all-locales", feature = "lang-si", feature = "region-lk"))]
+    si_LK = 270,
+    /// `sid_ET`: Sidama language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-sid", feature = "region-et"))]
+    sid_ET = 271,
+    /// `sk_SK`: Slovak locale for Slovak.
+    #[cfg(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk"))]
+    sk_SK = 272,
+    /// `sl_SI`: Slovenian locale for Slovenia.
+    #[cfg(any(feature = "all-locales", feature = "lang-sl", feature = "region-si"))]
+    sl_SI = 273,
+    /// `sm_WS`: Samoan language locale for Samoa.
+    #[cfg(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws"))]
+    sm_WS = 274,
+    /// `so_DJ`: Somali language locale for Djibouti.
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-dj"))]
+    so_DJ = 275,
+    /// `so_ET`: Somali language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-et"))]
+    so_ET = 276,
+    /// `so_KE`: Somali language locale for Kenya.
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-ke"))]
+    so_KE = 277,
+    /// `so_SO`: Somali language locale for Somalia.
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-so"))]
+    so_SO = 278,
+    /// `sq_AL`: Albanian language locale for Albania.
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-al"))]
+    sq_AL = 279,
+    /// `sq_MK`: Albanian language locale for Macedonia.
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk"))]
+    sq_MK = 280,
+    /// `sr_ME`: Serbian locale for Montenegro.
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-me"))]
+    sr_ME = 281,
+    /// `sr_RS`: Serbian locale for Serbia.
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    sr_RS = 282,
+    /// `sr_RS@latin`: Serbian Latin locale for Serbia.
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    sr_RS_latin = 283,
+    /// `ss_ZA`: Swati locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-ss", feature = "region-za"))]
+    ss_ZA = 284,
+    /// `st_ZA`: Sotho locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-st", feature = "region-za"))]
+    st_ZA = 285,
+    /// `sv_FI`: Swedish locale for Finland.
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    sv_FI = 286,
+    /// `sv_FI@euro`: Swedish locale for Finland with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    sv_FI_euro = 287,
+    /// `sv_SE`: Swedish locale for Sweden.
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-se"))]
+    sv_SE = 288,
+    /// `sw_KE`: Swahili locale for Kenya.
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke"))]
+    sw_KE = 289,
+    /// `sw_TZ`: Swahili locale for Tanzania.
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz"))]
+    sw_TZ = 290,
+    /// `szl_PL`: Silesian locale for Poland.
+    #[cfg(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl"))]
+    szl_PL = 291,
+    /// `ta_IN`: Tamil language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-in"))]
+    ta_IN = 292,
+    /// `ta_LK`: Tamil language locale for Sri Lanka.
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk"))]
+    ta_LK = 293,
+    /// `tcy_IN`: Tulu language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in"))]
+    tcy_IN = 294,
+    /// `te_IN`: Telugu language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-te", feature = "region-in"))]
+    te_IN = 295,
+    /// `tg_TJ`: Tajik language locale for Tajikistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj"))]
+    tg_TJ = 296,
+    /// `th_TH`: Thai locale for Thailand.
+    #[cfg(any(feature = "all-locales", feature = "lang-th", feature = "region-th"))]
+    th_TH = 297,
+    /// `the_NP`: Tharu language locale for Nepal.
+    #[cfg(any(feature = "all-locales", feature = "lang-the", feature = "region-np"))]
+    the_NP = 298,
+    /// `ti_ER`: Tigrigna language locale for Eritrea.
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-er"))]
+    ti_ER = 299,
+    /// `ti_ET`: Tigrigna language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-et"))]
+    ti_ET = 300,
+    /// `tig_ER`: Tigre language locale for Eritrea.
+    #[cfg(any(feature = "all-locales", feature = "lang-tig", feature = "region-er"))]
+    tig_ER = 301,
+    /// `tk_TM`: Turkmen locale for Turkmenistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm"))]
+    tk_TM = 302,
+    /// `tl_PH`: Tagalog language locale for Philippines.
+    #[cfg(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph"))]
+    tl_PH = 303,
+    /// `tn_ZA`: Tswana locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-tn", feature = "region-za"))]
+    tn_ZA = 304,
+    /// `to_TO`: Tongan language locale for Tonga.
+    #[cfg(any(feature = "all-locales", feature = "lang-to", feature = "region-to"))]
+    to_TO = 305,
+    /// `tpi_PG`: Tok Pisin language locale for Papua New Guinea.
+    #[cfg(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg"))]
+    tpi_PG = 306,
+    /// `tr_CY`: Turkish language locale for Cyprus.
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy"))]
+    tr_CY = 307,
+    /// `tr_TR`: Turkish locale for Turkey.
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr"))]
+    tr_TR = 308,
+    /// `ts_ZA`: Tsonga locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-ts", feature = "region-za"))]
+    ts_ZA = 309,
+    /// `tt_RU`: Tatar language locale for Russia.
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    tt_RU = 310,
+    /// `tt_RU@iqtelif`: Tatar language locale using IQTElif alphabet; for Tatarstan, Russian Federation.
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    tt_RU_iqtelif = 311,
+    /// `ug_CN`: Uyghur locale for China.
+    #[cfg(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn"))]
+    ug_CN = 312,
+    /// `uk_UA`: Ukrainian Language Locale for Ukraine.
+    #[cfg(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua"))]
+    uk_UA = 313,
+    /// `unm_US`: Unami Delaware locale for the USA.
+    #[cfg(any(feature = "all-locales", feature = "lang-unm", feature = "region-us"))]
+    unm_US = 314,
+    /// `ur_IN`: Urdu language locale for India.
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-in"))]
+    ur_IN = 315,
+    /// `ur_PK`: Urdu Language Locale for Pakistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk"))]
+    ur_PK = 316,
+    /// `uz_UZ`: Uzbek (latin) locale for Uzbekistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    uz_UZ = 317,
+    /// `uz_UZ@cyrillic`: Uzbek (cyrillic) locale for Uzbekistan.
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    uz_UZ_cyrillic = 318,
+    /// `ve_ZA`: Venda locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-ve", feature = "region-za"))]
+    ve_ZA = 319,
+    /// `vi_VN`: Vietnamese language locale for Vietnam.
+    #[cfg(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn"))]
+    vi_VN = 320,
+    /// `wa_BE`: Walloon Language Locale for Belgium.
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    wa_BE = 321,
+    /// `wa_BE@euro`: Walloon locale for Belgium with Euro.
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    wa_BE_euro = 322,
+    /// `wae_CH`: Walser locale for Switzerland.
+    #[cfg(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch"))]
+    wae_CH = 323,
+    /// `wal_ET`: Walaita language locale for Ethiopia.
+    #[cfg(any(feature = "all-locales", feature = "lang-wal", feature = "region-et"))]
+    wal_ET = 324,
+    /// `wo_SN`: Wolof locale for Senegal.
+    #[cfg(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn"))]
+    wo_SN = 325,
+    /// `xh_ZA`: Xhosa locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-xh", feature = "region-za"))]
+    xh_ZA = 326,
+    /// `yi_US`: Yiddish Language locale for the USA.
+    #[cfg(any(feature = "all-locales", feature = "lang-yi", feature = "region-us"))]
+    yi_US = 327,
+    /// `yo_NG`: Yoruba locale for Nigeria.
+    #[cfg(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng"))]
+    yo_NG = 328,
+    /// `yue_HK`: Yue Chinese (Cantonese) language locale for Hong Kong.
+    #[cfg(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk"))]
+    yue_HK = 329,
+    /// `yuw_PG`: YauNungon locale for Papua New Guinea.
+    #[cfg(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg"))]
+    yuw_PG = 330,
+    /// `zh_CN`: Chinese locale for Peoples Republic of China.
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn"))]
+    zh_CN = 331,
+    /// `zh_HK`: Chinese language locale for Hong Kong.
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk"))]
+    zh_HK = 332,
+    /// `zh_SG`: Chinese language locale for Singapore.
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg"))]
+    zh_SG = 333,
+    /// `zh_TW`: Chinese locale for Taiwan R.O.C.
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw"))]
+    zh_TW = 334,
+    /// `zu_ZA`: Zulu locale for South Africa.
+    #[cfg(any(feature = "all-locales", feature = "lang-zu", feature = "region-za"))]
+    zu_ZA = 335,
+}
+
+impl Locale {
+    /// The name of the locale in `glibc`, like `de_DE@euro`.
+    pub fn name(self) -> &'static str {
+        LOCALE_NAMES[self as usize]
+    }
+
+    /// The ID of the locale. It stays the same in the next versions of the crate,
+    /// unlike the order of the variants, and it isn't given to another locale if
+    /// this one is removed: it can be stored or sent instead of the name.
+    pub const fn to_u16(self) -> u16 {
+        self as u16
+    }
+
+    /// The locale with an ID, if it is compiled in.
+    pub const fn from_u16(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(Locale::POSIX),
+            #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj"))]
+            1 => Some(Locale::aa_DJ),
+            #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+            2 => Some(Locale::aa_ER),
+            #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+            3 => Some(Locale::aa_ER_saaho),
+            #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-et"))]
+            4 => Some(Locale::aa_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-af", feature = "region-za"))]
+            5 => Some(Locale::af_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe"))]
+            6 => Some(Locale::agr_PE),
+            #[cfg(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh"))]
+            7 => Some(Locale::ak_GH),
+            #[cfg(any(feature = "all-locales", feature = "lang-am", feature = "region-et"))]
+            8 => Some(Locale::am_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-an", feature = "region-es"))]
+            9 => Some(Locale::an_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-anp", feature = "region-in"))]
+            10 => Some(Locale::anp_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae"))]
+            11 => Some(Locale::ar_AE),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh"))]
+            12 => Some(Locale::ar_BH),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz"))]
+            13 => Some(Locale::ar_DZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg"))]
+            14 => Some(Locale::ar_EG),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-in"))]
+            15 => Some(Locale::ar_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq"))]
+            16 => Some(Locale::ar_IQ),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo"))]
+            17 => Some(Locale::ar_JO),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw"))]
+            18 => Some(Locale::ar_KW),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb"))]
+            19 => Some(Locale::ar_LB),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly"))]
+            20 => Some(Locale::ar_LY),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma"))]
+            21 => Some(Locale::ar_MA),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-om"))]
+            22 => Some(Locale::ar_OM),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa"))]
+            23 => Some(Locale::ar_QA),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa"))]
+            24 => Some(Locale::ar_SA),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd"))]
+            25 => Some(Locale::ar_SD),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss"))]
+            26 => Some(Locale::ar_SS),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy"))]
+            27 => Some(Locale::ar_SY),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn"))]
+            28 => Some(Locale::ar_TN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye"))]
+            29 => Some(Locale::ar_YE),
+            #[cfg(any(feature = "all-locales", feature = "lang-as", feature = "region-in"))]
+            30 => Some(Locale::as_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ast", feature = "region-es"))]
+            31 => Some(Locale::ast_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe"))]
+            32 => Some(Locale::ayc_PE),
+            #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-az"))]
+            33 => Some(Locale::az_AZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-ir"))]
+            34 => Some(Locale::az_IR),
+            #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+            35 => Some(Locale::be_BY),
+            #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+            36 => Some(Locale::be_BY_latin),
+            #[cfg(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm"))]
+            37 => Some(Locale::bem_ZM),
+            #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz"))]
+            38 => Some(Locale::ber_DZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma"))]
+            39 => Some(Locale::ber_MA),
+            #[cfg(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg"))]
+            40 => Some(Locale::bg_BG),
+            #[cfg(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in"))]
+            41 => Some(Locale::bhb_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-in"))]
+            42 => Some(Locale::bho_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-np"))]
+            43 => Some(Locale::bho_NP),
+            #[cfg(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu"))]
+            44 => Some(Locale::bi_VU),
+            #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd"))]
+            45 => Some(Locale::bn_BD),
+            #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-in"))]
+            46 => Some(Locale::bn_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn"))]
+            47 => Some(Locale::bo_CN),
+            #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-in"))]
+            48 => Some(Locale::bo_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+            49 => Some(Locale::br_FR),
+            #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+            50 => Some(Locale::br_FR_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-brx", feature = "region-in"))]
+            51 => Some(Locale::brx_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba"))]
+            52 => Some(Locale::bs_BA),
+            #[cfg(any(feature = "all-locales", feature = "lang-byn", feature = "region-er"))]
+            53 => Some(Locale::byn_ER),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad"))]
+            54 => Some(Locale::ca_AD),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+            55 => Some(Locale::ca_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+            56 => Some(Locale::ca_ES_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+            57 => Some(Locale::ca_ES_valencia),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr"))]
+            58 => Some(Locale::ca_FR),
+            #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-it"))]
+            59 => Some(Locale::ca_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru"))]
+            60 => Some(Locale::ce_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-chr", feature = "region-us"))]
+            61 => Some(Locale::chr_US),
+            #[cfg(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw"))]
+            62 => Some(Locale::cmn_TW),
+            #[cfg(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua"))]
+            63 => Some(Locale::crh_UA),
+            #[cfg(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz"))]
+            64 => Some(Locale::cs_CZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl"))]
+            65 => Some(Locale::csb_PL),
+            #[cfg(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru"))]
+            66 => Some(Locale::cv_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb"))]
+            67 => Some(Locale::cy_GB),
+            #[cfg(any(feature = "all-locales", feature = "lang-da", feature = "region-dk"))]
+            68 => Some(Locale::da_DK),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+            69 => Some(Locale::de_AT),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+            70 => Some(Locale::de_AT_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+            71 => Some(Locale::de_BE),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+            72 => Some(Locale::de_BE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-ch"))]
+            73 => Some(Locale::de_CH),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+            74 => Some(Locale::de_DE),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+            75 => Some(Locale::de_DE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-it"))]
+            76 => Some(Locale::de_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-li"))]
+            77 => Some(Locale::de_LI),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+            78 => Some(Locale::de_LU),
+            #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+            79 => Some(Locale::de_LU_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-doi", feature = "region-in"))]
+            80 => Some(Locale::doi_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de"))]
+            81 => Some(Locale::dsb_DE),
+            #[cfg(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv"))]
+            82 => Some(Locale::dv_MV),
+            #[cfg(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt"))]
+            83 => Some(Locale::dz_BT),
+            #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-cy"))]
+            84 => Some(Locale::el_CY),
+            #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+            85 => Some(Locale::el_GR),
+            #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+            86 => Some(Locale::el_GR_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ag"))]
+            87 => Some(Locale::en_AG),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-au"))]
+            88 => Some(Locale::en_AU),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-bw"))]
+            89 => Some(Locale::en_BW),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ca"))]
+            90 => Some(Locale::en_CA),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-dk"))]
+            91 => Some(Locale::en_DK),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-gb"))]
+            92 => Some(Locale::en_GB),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-hk"))]
+            93 => Some(Locale::en_HK),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+            94 => Some(Locale::en_IE),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+            95 => Some(Locale::en_IE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-il"))]
+            96 => Some(Locale::en_IL),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-in"))]
+            97 => Some(Locale::en_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ng"))]
+            98 => Some(Locale::en_NG),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-nz"))]
+            99 => Some(Locale::en_NZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ph"))]
+            100 => Some(Locale::en_PH),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sc"))]
+            101 => Some(Locale::en_SC),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sg"))]
+            102 => Some(Locale::en_SG),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us"))]
+            103 => Some(Locale::en_US),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-za"))]
+            104 => Some(Locale::en_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zm"))]
+            105 => Some(Locale::en_ZM),
+            #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zw"))]
+            106 => Some(Locale::en_ZW),
+            #[cfg(any(feature = "all-locales", feature = "lang-eo"))]
+            107 => Some(Locale::eo),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ar"))]
+            108 => Some(Locale::es_AR),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-bo"))]
+            109 => Some(Locale::es_BO),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cl"))]
+            110 => Some(Locale::es_CL),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-co"))]
+            111 => Some(Locale::es_CO),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cr"))]
+            112 => Some(Locale::es_CR),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cu"))]
+            113 => Some(Locale::es_CU),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-do"))]
+            114 => Some(Locale::es_DO),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ec"))]
+            115 => Some(Locale::es_EC),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+            116 => Some(Locale::es_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+            117 => Some(Locale::es_ES_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-gt"))]
+            118 => Some(Locale::es_GT),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-hn"))]
+            119 => Some(Locale::es_HN),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-mx"))]
+            120 => Some(Locale::es_MX),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ni"))]
+            121 => Some(Locale::es_NI),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pa"))]
+            122 => Some(Locale::es_PA),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pe"))]
+            123 => Some(Locale::es_PE),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pr"))]
+            124 => Some(Locale::es_PR),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-py"))]
+            125 => Some(Locale::es_PY),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-sv"))]
+            126 => Some(Locale::es_SV),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-us"))]
+            127 => Some(Locale::es_US),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-uy"))]
+            128 => Some(Locale::es_UY),
+            #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ve"))]
+            129 => Some(Locale::es_VE),
+            #[cfg(any(feature = "all-locales", feature = "lang-et", feature = "region-ee"))]
+            130 => Some(Locale::et_EE),
+            #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+            131 => Some(Locale::eu_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+            132 => Some(Locale::eu_ES_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir"))]
+            133 => Some(Locale::fa_IR),
+            #[cfg(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn"))]
+            134 => Some(Locale::ff_SN),
+            #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+            135 => Some(Locale::fi_FI),
+            #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+            136 => Some(Locale::fi_FI_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph"))]
+            137 => Some(Locale::fil_PH),
+            #[cfg(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo"))]
+            138 => Some(Locale::fo_FO),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+            139 => Some(Locale::fr_BE),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+            140 => Some(Locale::fr_BE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca"))]
+            141 => Some(Locale::fr_CA),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch"))]
+            142 => Some(Locale::fr_CH),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+            143 => Some(Locale::fr_FR),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+            144 => Some(Locale::fr_FR_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+            145 => Some(Locale::fr_LU),
+            #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+            146 => Some(Locale::fr_LU_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-fur", feature = "region-it"))]
+            147 => Some(Locale::fur_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-de"))]
+            148 => Some(Locale::fy_DE),
+            #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl"))]
+            149 => Some(Locale::fy_NL),
+            #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+            150 => Some(Locale::ga_IE),
+            #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+            151 => Some(Locale::ga_IE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb"))]
+            152 => Some(Locale::gd_GB),
+            #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+            153 => Some(Locale::gez_ER),
+            #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+            154 => Some(Locale::gez_ER_abegede),
+            #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+            155 => Some(Locale::gez_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+            156 => Some(Locale::gez_ET_abegede),
+            #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+            157 => Some(Locale::gl_ES),
+            #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+            158 => Some(Locale::gl_ES_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-gu", feature = "region-in"))]
+            159 => Some(Locale::gu_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb"))]
+            160 => Some(Locale::gv_GB),
+            #[cfg(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng"))]
+            161 => Some(Locale::ha_NG),
+            #[cfg(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw"))]
+            162 => Some(Locale::hak_TW),
+            #[cfg(any(feature = "all-locales", feature = "lang-he", feature = "region-il"))]
+            163 => Some(Locale::he_IL),
+            #[cfg(any(feature = "all-locales", feature = "lang-hi", feature = "region-in"))]
+            164 => Some(Locale::hi_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj"))]
+            165 => Some(Locale::hif_FJ),
+            #[cfg(any(feature = "all-locales", feature = "lang-hne", feature = "region-in"))]
+            166 => Some(Locale::hne_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr"))]
+            167 => Some(Locale::hr_HR),
+            #[cfg(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de"))]
+            168 => Some(Locale::hsb_DE),
+            #[cfg(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht"))]
+            169 => Some(Locale::ht_HT),
+            #[cfg(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu"))]
+            170 => Some(Locale::hu_HU),
+            #[cfg(any(feature = "all-locales", feature = "lang-hy", feature = "region-am"))]
+            171 => Some(Locale::hy_AM),
+            #[cfg(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr"))]
+            172 => Some(Locale::ia_FR),
+            #[cfg(any(feature = "all-locales", feature = "lang-id", feature = "region-id"))]
+            173 => Some(Locale::id_ID),
+            #[cfg(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng"))]
+            174 => Some(Locale::ig_NG),
+            #[cfg(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca"))]
+            175 => Some(Locale::ik_CA),
+            #[cfg(any(feature = "all-locales", feature = "lang-is", feature = "region-is"))]
+            176 => Some(Locale::is_IS),
+            #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-ch"))]
+            177 => Some(Locale::it_CH),
+            #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+            178 => Some(Locale::it_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+            179 => Some(Locale::it_IT_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca"))]
+            180 => Some(Locale::iu_CA),
+            #[cfg(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp"))]
+            181 => Some(Locale::ja_JP),
+            #[cfg(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge"))]
+            182 => Some(Locale::ka_GE),
+            #[cfg(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz"))]
+            183 => Some(Locale::kab_DZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz"))]
+            184 => Some(Locale::kk_KZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl"))]
+            185 => Some(Locale::kl_GL),
+            #[cfg(any(feature = "all-locales", feature = "lang-km", feature = "region-kh"))]
+            186 => Some(Locale::km_KH),
+            #[cfg(any(feature = "all-locales", feature = "lang-kn", feature = "region-in"))]
+            187 => Some(Locale::kn_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr"))]
+            188 => Some(Locale::ko_KR),
+            #[cfg(any(feature = "all-locales", feature = "lang-kok", feature = "region-in"))]
+            189 => Some(Locale::kok_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+            190 => Some(Locale::ks_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+            191 => Some(Locale::ks_IN_devanagari),
+            #[cfg(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr"))]
+            192 => Some(Locale::ku_TR),
+            #[cfg(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb"))]
+            193 => Some(Locale::kw_GB),
+            #[cfg(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg"))]
+            194 => Some(Locale::ky_KG),
+            #[cfg(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu"))]
+            195 => Some(Locale::lb_LU),
+            #[cfg(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug"))]
+            196 => Some(Locale::lg_UG),
+            #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-be"))]
+            197 => Some(Locale::li_BE),
+            #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-nl"))]
+            198 => Some(Locale::li_NL),
+            #[cfg(any(feature = "all-locales", feature = "lang-lij", feature = "region-it"))]
+            199 => Some(Locale::lij_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd"))]
+            200 => Some(Locale::ln_CD),
+            #[cfg(any(feature = "all-locales", feature = "lang-lo", feature = "region-la"))]
+            201 => Some(Locale::lo_LA),
+            #[cfg(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt"))]
+            202 => Some(Locale::lt_LT),
+            #[cfg(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv"))]
+            203 => Some(Locale::lv_LV),
+            #[cfg(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw"))]
+            204 => Some(Locale::lzh_TW),
+            #[cfg(any(feature = "all-locales", feature = "lang-mag", feature = "region-in"))]
+            205 => Some(Locale::mag_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-in"))]
+            206 => Some(Locale::mai_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-np"))]
+            207 => Some(Locale::mai_NP),
+            #[cfg(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu"))]
+            208 => Some(Locale::mfe_MU),
+            #[cfg(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg"))]
+            209 => Some(Locale::mg_MG),
+            #[cfg(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru"))]
+            210 => Some(Locale::mhr_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz"))]
+            211 => Some(Locale::mi_NZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni"))]
+            212 => Some(Locale::miq_NI),
+            #[cfg(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in"))]
+            213 => Some(Locale::mjw_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk"))]
+            214 => Some(Locale::mk_MK),
+            #[cfg(any(feature = "all-locales", feature = "lang-ml", feature = "region-in"))]
+            215 => Some(Locale::ml_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn"))]
+            216 => Some(Locale::mn_MN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mni", feature = "region-in"))]
+            217 => Some(Locale::mni_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm"))]
+            218 => Some(Locale::mnw_MM),
+            #[cfg(any(feature = "all-locales", feature = "lang-mr", feature = "region-in"))]
+            219 => Some(Locale::mr_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ms", feature = "region-my"))]
+            220 => Some(Locale::ms_MY),
+            #[cfg(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt"))]
+            221 => Some(Locale::mt_MT),
+            #[cfg(any(feature = "all-locales", feature = "lang-my", feature = "region-mm"))]
+            222 => Some(Locale::my_MM),
+            #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+            223 => Some(Locale::nan_TW),
+            #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+            224 => Some(Locale::nan_TW_latin),
+            #[cfg(any(feature = "all-locales", feature = "lang-nb", feature = "region-no"))]
+            225 => Some(Locale::nb_NO),
+            #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-de"))]
+            226 => Some(Locale::nds_DE),
+            #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl"))]
+            227 => Some(Locale::nds_NL),
+            #[cfg(any(feature = "all-locales", feature = "lang-ne", feature = "region-np"))]
+            228 => Some(Locale::ne_NP),
+            #[cfg(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx"))]
+            229 => Some(Locale::nhn_MX),
+            #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu"))]
+            230 => Some(Locale::niu_NU),
+            #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz"))]
+            231 => Some(Locale::niu_NZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw"))]
+            232 => Some(Locale::nl_AW),
+            #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+            233 => Some(Locale::nl_BE),
+            #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+            234 => Some(Locale::nl_BE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+            235 => Some(Locale::nl_NL),
+            #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+            236 => Some(Locale::nl_NL_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-nn", feature = "region-no"))]
+            237 => Some(Locale::nn_NO),
+            #[cfg(any(feature = "all-locales", feature = "lang-nr", feature = "region-za"))]
+            238 => Some(Locale::nr_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-nso", feature = "region-za"))]
+            239 => Some(Locale::nso_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr"))]
+            240 => Some(Locale::oc_FR),
+            #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-et"))]
+            241 => Some(Locale::om_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-ke"))]
+            242 => Some(Locale::om_KE),
+            #[cfg(any(feature = "all-locales", feature = "lang-or", feature = "region-in"))]
+            243 => Some(Locale::or_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-os", feature = "region-ru"))]
+            244 => Some(Locale::os_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-in"))]
+            245 => Some(Locale::pa_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk"))]
+            246 => Some(Locale::pa_PK),
+            #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw"))]
+            247 => Some(Locale::pap_AW),
+            #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw"))]
+            248 => Some(Locale::pap_CW),
+            #[cfg(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl"))]
+            249 => Some(Locale::pl_PL),
+            #[cfg(any(feature = "all-locales", feature = "lang-ps", feature = "region-af"))]
+            250 => Some(Locale::ps_AF),
+            #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-br"))]
+            251 => Some(Locale::pt_BR),
+            #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+            252 => Some(Locale::pt_PT),
+            #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+            253 => Some(Locale::pt_PT_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe"))]
+            254 => Some(Locale::quz_PE),
+            #[cfg(any(feature = "all-locales", feature = "lang-raj", feature = "region-in"))]
+            255 => Some(Locale::raj_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro"))]
+            256 => Some(Locale::ro_RO),
+            #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru"))]
+            257 => Some(Locale::ru_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua"))]
+            258 => Some(Locale::ru_UA),
+            #[cfg(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw"))]
+            259 => Some(Locale::rw_RW),
+            #[cfg(any(feature = "all-locales", feature = "lang-sa", feature = "region-in"))]
+            260 => Some(Locale::sa_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru"))]
+            261 => Some(Locale::sah_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-sat", feature = "region-in"))]
+            262 => Some(Locale::sat_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-sc", feature = "region-it"))]
+            263 => Some(Locale::sc_IT),
+            #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+            264 => Some(Locale::sd_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+            265 => Some(Locale::sd_IN_devanagari),
+            #[cfg(any(feature = "all-locales", feature = "lang-se", feature = "region-no"))]
+            266 => Some(Locale::se_NO),
+            #[cfg(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt"))]
+            267 => Some(Locale::sgs_LT),
+            #[cfg(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm"))]
+            268 => Some(Locale::shn_MM),
+            #[cfg(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca"))]
+            269 => Some(Locale::shs_CA),
+            #[cfg(any(feature = "all-locales", feature = "lang-si", feature = "region-lk"))]
+            270 => Some(Locale::si_LK),
+            #[cfg(any(feature = "all-locales", feature = "lang-sid", feature = "region-et"))]
+            271 => Some(Locale::sid_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk"))]
+            272 => Some(Locale::sk_SK),
+            #[cfg(any(feature = "all-locales", feature = "lang-sl", feature = "region-si"))]
+            273 => Some(Locale::sl_SI),
+            #[cfg(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws"))]
+            274 => Some(Locale::sm_WS),
+            #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-dj"))]
+            275 => Some(Locale::so_DJ),
+            #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-et"))]
+            276 => Some(Locale::so_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-ke"))]
+            277 => Some(Locale::so_KE),
+            #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-so"))]
+            278 => Some(Locale::so_SO),
+            #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-al"))]
+            279 => Some(Locale::sq_AL),
+            #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk"))]
+            280 => Some(Locale::sq_MK),
+            #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-me"))]
+            281 => Some(Locale::sr_ME),
+            #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+            282 => Some(Locale::sr_RS),
+            #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+            283 => Some(Locale::sr_RS_latin),
+            #[cfg(any(feature = "all-locales", feature = "lang-ss", feature = "region-za"))]
+            284 => Some(Locale::ss_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-st", feature = "region-za"))]
+            285 => Some(Locale::st_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+            286 => Some(Locale::sv_FI),
+            #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+            287 => Some(Locale::sv_FI_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-se"))]
+            288 => Some(Locale::sv_SE),
+            #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke"))]
+            289 => Some(Locale::sw_KE),
+            #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz"))]
+            290 => Some(Locale::sw_TZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl"))]
+            291 => Some(Locale::szl_PL),
+            #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-in"))]
+            292 => Some(Locale::ta_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk"))]
+            293 => Some(Locale::ta_LK),
+            #[cfg(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in"))]
+            294 => Some(Locale::tcy_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-te", feature = "region-in"))]
+            295 => Some(Locale::te_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj"))]
+            296 => Some(Locale::tg_TJ),
+            #[cfg(any(feature = "all-locales", feature = "lang-th", feature = "region-th"))]
+            297 => Some(Locale::th_TH),
+            #[cfg(any(feature = "all-locales", feature = "lang-the", feature = "region-np"))]
+            298 => Some(Locale::the_NP),
+            #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-er"))]
+            299 => Some(Locale::ti_ER),
+            #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-et"))]
+            300 => Some(Locale::ti_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-tig", feature = "region-er"))]
+            301 => Some(Locale::tig_ER),
+            #[cfg(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm"))]
+            302 => Some(Locale::tk_TM),
+            #[cfg(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph"))]
+            303 => Some(Locale::tl_PH),
+            #[cfg(any(feature = "all-locales", feature = "lang-tn", feature = "region-za"))]
+            304 => Some(Locale::tn_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-to", feature = "region-to"))]
+            305 => Some(Locale::to_TO),
+            #[cfg(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg"))]
+            306 => Some(Locale::tpi_PG),
+            #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy"))]
+            307 => Some(Locale::tr_CY),
+            #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr"))]
+            308 => Some(Locale::tr_TR),
+            #[cfg(any(feature = "all-locales", feature = "lang-ts", feature = "region-za"))]
+            309 => Some(Locale::ts_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+            310 => Some(Locale::tt_RU),
+            #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+            311 => Some(Locale::tt_RU_iqtelif),
+            #[cfg(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn"))]
+            312 => Some(Locale::ug_CN),
+            #[cfg(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua"))]
+            313 => Some(Locale::uk_UA),
+            #[cfg(any(feature = "all-locales", feature = "lang-unm", feature = "region-us"))]
+            314 => Some(Locale::unm_US),
+            #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-in"))]
+            315 => Some(Locale::ur_IN),
+            #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk"))]
+            316 => Some(Locale::ur_PK),
+            #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+            317 => Some(Locale::uz_UZ),
+            #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+            318 => Some(Locale::uz_UZ_cyrillic),
+            #[cfg(any(feature = "all-locales", feature = "lang-ve", feature = "region-za"))]
+            319 => Some(Locale::ve_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn"))]
+            320 => Some(Locale::vi_VN),
+            #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+            321 => Some(Locale::wa_BE),
+            #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+            322 => Some(Locale::wa_BE_euro),
+            #[cfg(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch"))]
+            323 => Some(Locale::wae_CH),
+            #[cfg(any(feature = "all-locales", feature = "lang-wal", feature = "region-et"))]
+            324 => Some(Locale::wal_ET),
+            #[cfg(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn"))]
+            325 => Some(Locale::wo_SN),
+            #[cfg(any(feature = "all-locales", feature = "lang-xh", feature = "region-za"))]
+            326 => Some(Locale::xh_ZA),
+            #[cfg(any(feature = "all-locales", feature = "lang-yi", feature = "region-us"))]
+            327 => Some(Locale::yi_US),
+            #[cfg(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng"))]
+            328 => Some(Locale::yo_NG),
+            #[cfg(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk"))]
+            329 => Some(Locale::yue_HK),
+            #[cfg(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg"))]
+            330 => Some(Locale::yuw_PG),
+            #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn"))]
+            331 => Some(Locale::zh_CN),
+            #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk"))]
+            332 => Some(Locale::zh_HK),
+            #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg"))]
+            333 => Some(Locale::zh_SG),
+            #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw"))]
+            334 => Some(Locale::zh_TW),
+            #[cfg(any(feature = "all-locales", feature = "lang-zu", feature = "region-za"))]
+            335 => Some(Locale::zu_ZA),
+            _ => None,
+        }
+    }
+}
+
+/// The names of the locales, by ID. The IDs of the locales removed have an empty
+/// name.
+const LOCALE_NAMES: &[&str] = &[
+    "POSIX",
+    "aa_DJ",
+    "aa_ER",
+    "aa_ER@saaho",
+    "aa_ET",
+    "af_ZA",
+    "agr_PE",
+    "ak_GH",
+    "am_ET",
+    "an_ES",
+    "anp_IN",
+    "ar_AE",
+    "ar_BH",
+    "ar_DZ",
+    "ar_EG",
+    "ar_IN",
+    "ar_IQ",
+    "ar_JO",
+    "ar_KW",
+    "ar_LB",
+    "ar_LY",
+    "ar_MA",
+    "ar_OM",
+    "ar_QA",
+    "ar_SA",
+    "ar_SD",
+    "ar_SS",
+    "ar_SY",
+    "ar_TN",
+    "ar_YE",
+    "as_IN",
+    "ast_ES",
+    "ayc_PE",
+    "az_AZ",
+    "az_IR",
+    "be_BY",
+    "be_BY@latin",
+    "bem_ZM",
+    "ber_DZ",
+    "ber_MA",
+    "bg_BG",
+    "bhb_IN",
+    "bho_IN",
+    "bho_NP",
+    "bi_VU",
+    "bn_BD",
+    "bn_IN",
+    "bo_CN",
+    "bo_IN",
+    "br_FR",
+    "br_FR@euro",
+    "brx_IN",
+    "bs_BA",
+    "byn_ER",
+    "ca_AD",
+    "ca_ES",
+    "ca_ES@euro",
+    "ca_ES@valencia",
+    "ca_FR",
+    "ca_IT",
+    "ce_RU",
+    "chr_US",
+    "cmn_TW",
+    "crh_UA",
+    "cs_CZ",
+    "csb_PL",
+    "cv_RU",
+    "cy_GB",
+    "da_DK",
+    "de_AT",
+    "de_AT@euro",
+    "de_BE",
+    "de_BE@euro",
+    "de_CH",
+    "de_DE",
+    "de_DE@euro",
+    "de_IT",
+    "de_LI",
+    "de_LU",
+    "de_LU@euro",
+    "doi_IN",
+    "dsb_DE",
+    "dv_MV",
+    "dz_BT",
+    "el_CY",
+    "el_GR",
+    "el_GR@euro",
+    "en_AG",
+    "en_AU",
+    "en_BW",
+    "en_CA",
+    "en_DK",
+    "en_GB",
+    "en_HK",
+    "en_IE",
+    "en_IE@euro",
+    "en_IL",
+    "en_IN",
+    "en_NG",
+    "en_NZ",
+    "en_PH",
+    "en_SC",
+    "en_SG",
+    "en_US",
+    "en_ZA",
+    "en_ZM",
+    "en_ZW",
+    "eo",
+    "es_AR",
+    "es_BO",
+    "es_CL",
+    "es_CO",
+    "es_CR",
+    "es_CU",
+    "es_DO",
+    "es_EC",
+    "es_ES",
+    "es_ES@euro",
+    "es_GT",
+    "es_HN",
+    "es_MX",
+    "es_NI",
+    "es_PA",
+    "es_PE",
+    "es_PR",
+    "es_PY",
+    "es_SV",
+    "es_US",
+    "es_UY",
+    "es_VE",
+    "et_EE",
+    "eu_ES",
+    "eu_ES@euro",
+    "fa_IR",
+    "ff_SN",
+    "fi_FI",
+    "fi_FI@euro",
+    "fil_PH",
+    "fo_FO",
+    "fr_BE",
+    "fr_BE@euro",
+    "fr_CA",
+    "fr_CH",
+    "fr_FR",
+    "fr_FR@euro",
+    "fr_LU",
+    "fr_LU@euro",
+    "fur_IT",
+    "fy_DE",
+    "fy_NL",
+    "ga_IE",
+    "ga_IE@euro",
+    "gd_GB",
+    "gez_ER",
+    "gez_ER@abegede",
+    "gez_ET",
+    "gez_ET@abegede",
+    "gl_ES",
+    "gl_ES@euro",
+    "gu_IN",
+    "gv_GB",
+    "ha_NG",
+    "hak_TW",
+    "he_IL",
+    "hi_IN",
+    "hif_FJ",
+    "hne_IN",
+    "hr_HR",
+    "hsb_DE",
+    "ht_HT",
+    "hu_HU",
+    "hy_AM",
+    "ia_FR",
+    "id_ID",
+    "ig_NG",
+    "ik_CA",
+    "is_IS",
+    "it_CH",
+    "it_IT",
+    "it_IT@euro",
+    "iu_CA",
+    "ja_JP",
+    "ka_GE",
+    "kab_DZ",
+    "kk_KZ",
+    "kl_GL",
+    "km_KH",
+    "kn_IN",
+    "ko_KR",
+    "kok_IN",
+    "ks_IN",
+    "ks_IN@devanagari",
+    "ku_TR",
+    "kw_GB",
+    "ky_KG",
+    "lb_LU",
+    "lg_UG",
+    "li_BE",
+    "li_NL",
+    "lij_IT",
+    "ln_CD",
+    "lo_LA",
+    "lt_LT",
+    "lv_LV",
+    "lzh_TW",
+    "mag_IN",
+    "mai_IN",
+    "mai_NP",
+    "mfe_MU",
+    "mg_MG",
+    "mhr_RU",
+    "mi_NZ",
+    "miq_NI",
+    "mjw_IN",
+    "mk_MK",
+    "ml_IN",
+    "mn_MN",
+    "mni_IN",
+    "mnw_MM",
+    "mr_IN",
+    "ms_MY",
+    "mt_MT",
+    "my_MM",
+    "nan_TW",
+    "nan_TW@latin",
+    "nb_NO",
+    "nds_DE",
+    "nds_NL",
+    "ne_NP",
+    "nhn_MX",
+    "niu_NU",
+    "niu_NZ",
+    "nl_AW",
+    "nl_BE",
+    "nl_BE@euro",
+    "nl_NL",
+    "nl_NL@euro",
+    "nn_NO",
+    "nr_ZA",
+    "nso_ZA",
+    "oc_FR",
+    "om_ET",
+    "om_KE",
+    "or_IN",
+    "os_RU",
+    "pa_IN",
+    "pa_PK",
+    "pap_AW",
+    "pap_CW",
+    "pl_PL",
+    "ps_AF",
+    "pt_BR",
+    "pt_PT",
+    "pt_PT@euro",
+    "quz_PE",
+    "raj_IN",
+    "ro_RO",
+    "ru_RU",
+    "ru_UA",
+    "rw_RW",
+    "sa_IN",
+    "sah_RU",
+    "sat_IN",
+    "sc_IT",
+    "sd_IN",
+    "sd_IN@devanagari",
+    "se_NO",
+    "sgs_LT",
+    "shn_MM",
+    "shs_CA",
+    "si_LK",
+    "sid_ET",
+    "sk_SK",
+    "sl_SI",
+    "sm_WS",
+    "so_DJ",
+    "so_ET",
+    "so_KE",
+    "so_SO",
+    "sq_AL",
+    "sq_MK",
+    "sr_ME",
+    "sr_RS",
+    "sr_RS@latin",
+    "ss_ZA",
+    "st_ZA",
+    "sv_FI",
+    "sv_FI@euro",
+    "sv_SE",
+    "sw_KE",
+    "sw_TZ",
+    "szl_PL",
+    "ta_IN",
+    "ta_LK",
+    "tcy_IN",
+    "te_IN",
+    "tg_TJ",
+    "th_TH",
+    "the_NP",
+    "ti_ER",
+    "ti_ET",
+    "tig_ER",
+    "tk_TM",
+    "tl_PH",
+    "tn_ZA",
+    "to_TO",
+    "tpi_PG",
+    "tr_CY",
+    "tr_TR",
+    "ts_ZA",
+    "tt_RU",
+    "tt_RU@iqtelif",
+    "ug_CN",
+    "uk_UA",
+    "unm_US",
+    "ur_IN",
+    "ur_PK",
+    "uz_UZ",
+    "uz_UZ@cyrillic",
+    "ve_ZA",
+    "vi_VN",
+    "wa_BE",
+    "wa_BE@euro",
+    "wae_CH",
+    "wal_ET",
+    "wo_SN",
+    "xh_ZA",
+    "yi_US",
+    "yo_NG",
+    "yue_HK",
+    "yuw_PG",
+    "zh_CN",
+    "zh_HK",
+    "zh_SG",
+    "zh_TW",
+    "zu_ZA",
+];
+
+/// The locales by the key of their name, sorted by key.
+const LOCALES_BY_NAME: &[(u128, Locale)] = &[
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj"))]
+    (locale_key(b"aa_DJ"), Locale::aa_DJ),
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    (locale_key(b"aa_ER"), Locale::aa_ER),
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    (locale_key(b"aa_ER@saaho"), Locale::aa_ER_saaho),
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-et"))]
+    (locale_key(b"aa_ET"), Locale::aa_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-af", feature = "region-za"))]
+    (locale_key(b"af_ZA"), Locale::af_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe"))]
+    (locale_key(b"agr_PE"), Locale::agr_PE),
+    #[cfg(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh"))]
+    (locale_key(b"ak_GH"), Locale::ak_GH),
+    #[cfg(any(feature = "all-locales", feature = "lang-am", feature = "region-et"))]
+    (locale_key(b"am_ET"), Locale::am_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-an", feature = "region-es"))]
+    (locale_key(b"an_ES"), Locale::an_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-anp", feature = "region-in"))]
+    (locale_key(b"anp_IN"), Locale::anp_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae"))]
+    (locale_key(b"ar_AE"), Locale::ar_AE),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh"))]
+    (locale_key(b"ar_BH"), Locale::ar_BH),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz"))]
+    (locale_key(b"ar_DZ"), Locale::ar_DZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg"))]
+    (locale_key(b"ar_EG"), Locale::ar_EG),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-in"))]
+    (locale_key(b"ar_IN"), Locale::ar_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq"))]
+    (locale_key(b"ar_IQ"), Locale::ar_IQ),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo"))]
+    (locale_key(b"ar_JO"), Locale::ar_JO),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw"))]
+    (locale_key(b"ar_KW"), Locale::ar_KW),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb"))]
+    (locale_key(b"ar_LB"), Locale::ar_LB),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly"))]
+    (locale_key(b"ar_LY"), Locale::ar_LY),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma"))]
+    (locale_key(b"ar_MA"), Locale::ar_MA),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-om"))]
+    (locale_key(b"ar_OM"), Locale::ar_OM),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa"))]
+    (locale_key(b"ar_QA"), Locale::ar_QA),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa"))]
+    (locale_key(b"ar_SA"), Locale::ar_SA),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd"))]
+    (locale_key(b"ar_SD"), Locale::ar_SD),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss"))]
+    (locale_key(b"ar_SS"), Locale::ar_SS),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy"))]
+    (locale_key(b"ar_SY"), Locale::ar_SY),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn"))]
+    (locale_key(b"ar_TN"), Locale::ar_TN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye"))]
+    (locale_key(b"ar_YE"), Locale::ar_YE),
+    #[cfg(any(feature = "all-locales", feature = "lang-as", feature = "region-in"))]
+    (locale_key(b"as_IN"), Locale::as_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ast", feature = "region-es"))]
+    (locale_key(b"ast_ES"), Locale::ast_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe"))]
+    (locale_key(b"ayc_PE"), Locale::ayc_PE),
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-az"))]
+    (locale_key(b"az_AZ"), Locale::az_AZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-ir"))]
+    (locale_key(b"az_IR"), Locale::az_IR),
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    (locale_key(b"be_BY"), Locale::be_BY),
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    (locale_key(b"be_BY@latin"), Locale::be_BY_latin),
+    #[cfg(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm"))]
+    (locale_key(b"bem_ZM"), Locale::bem_ZM),
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz"))]
+    (locale_key(b"ber_DZ"), Locale::ber_DZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma"))]
+    (locale_key(b"ber_MA"), Locale::ber_MA),
+    #[cfg(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg"))]
+    (locale_key(b"bg_BG"), Locale::bg_BG),
+    #[cfg(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in"))]
+    (locale_key(b"bhb_IN"), Locale::bhb_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-in"))]
+    (locale_key(b"bho_IN"), Locale::bho_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-np"))]
+    (locale_key(b"bho_NP"), Locale::bho_NP),
+    #[cfg(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu"))]
+    (locale_key(b"bi_VU"), Locale::bi_VU),
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd"))]
+    (locale_key(b"bn_BD"), Locale::bn_BD),
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-in"))]
+    (locale_key(b"bn_IN"), Locale::bn_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn"))]
+    (locale_key(b"bo_CN"), Locale::bo_CN),
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-in"))]
+    (locale_key(b"bo_IN"), Locale::bo_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    (locale_key(b"br_FR"), Locale::br_FR),
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    (locale_key(b"br_FR@euro"), Locale::br_FR_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-brx", feature = "region-in"))]
+    (locale_key(b"brx_IN"), Locale::brx_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba"))]
+    (locale_key(b"bs_BA"), Locale::bs_BA),
+    #[cfg(any(feature = "all-locales", feature = "lang-byn", feature = "region-er"))]
+    (locale_key(b"byn_ER"), Locale::byn_ER),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad"))]
+    (locale_key(b"ca_AD"), Locale::ca_AD),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    (locale_key(b"ca_ES"), Locale::ca_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    (locale_key(b"ca_ES@euro"), Locale::ca_ES_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    (locale_key(b"ca_ES@valencia"), Locale::ca_ES_valencia),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr"))]
+    (locale_key(b"ca_FR"), Locale::ca_FR),
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-it"))]
+    (locale_key(b"ca_IT"), Locale::ca_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru"))]
+    (locale_key(b"ce_RU"), Locale::ce_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-chr", feature = "region-us"))]
+    (locale_key(b"chr_US"), Locale::chr_US),
+    #[cfg(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw"))]
+    (locale_key(b"cmn_TW"), Locale::cmn_TW),
+    #[cfg(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua"))]
+    (locale_key(b"crh_UA"), Locale::crh_UA),
+    #[cfg(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz"))]
+    (locale_key(b"cs_CZ"), Locale::cs_CZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl"))]
+    (locale_key(b"csb_PL"), Locale::csb_PL),
+    #[cfg(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru"))]
+    (locale_key(b"cv_RU"), Locale::cv_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb"))]
+    (locale_key(b"cy_GB"), Locale::cy_GB),
+    #[cfg(any(feature = "all-locales", feature = "lang-da", feature = "region-dk"))]
+    (locale_key(b"da_DK"), Locale::da_DK),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    (locale_key(b"de_AT"), Locale::de_AT),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    (locale_key(b"de_AT@euro"), Locale::de_AT_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    (locale_key(b"de_BE"), Locale::de_BE),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    (locale_key(b"de_BE@euro"), Locale::de_BE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-ch"))]
+    (locale_key(b"de_CH"), Locale::de_CH),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    (locale_key(b"de_DE"), Locale::de_DE),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    (locale_key(b"de_DE@euro"), Locale::de_DE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-it"))]
+    (locale_key(b"de_IT"), Locale::de_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-li"))]
+    (locale_key(b"de_LI"), Locale::de_LI),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    (locale_key(b"de_LU"), Locale::de_LU),
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    (locale_key(b"de_LU@euro"), Locale::de_LU_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-doi", feature = "region-in"))]
+    (locale_key(b"doi_IN"), Locale::doi_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de"))]
+    (locale_key(b"dsb_DE"), Locale::dsb_DE),
+    #[cfg(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv"))]
+    (locale_key(b"dv_MV"), Locale::dv_MV),
+    #[cfg(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt"))]
+    (locale_key(b"dz_BT"), Locale::dz_BT),
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-cy"))]
+    (locale_key(b"el_CY"), Locale::el_CY),
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    (locale_key(b"el_GR"), Locale::el_GR),
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    (locale_key(b"el_GR@euro"), Locale::el_GR_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ag"))]
+    (locale_key(b"en_AG"), Locale::en_AG),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-au"))]
+    (locale_key(b"en_AU"), Locale::en_AU),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-bw"))]
+    (locale_key(b"en_BW"), Locale::en_BW),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ca"))]
+    (locale_key(b"en_CA"), Locale::en_CA),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-dk"))]
+    (locale_key(b"en_DK"), Locale::en_DK),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-gb"))]
+    (locale_key(b"en_GB"), Locale::en_GB),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-hk"))]
+    (locale_key(b"en_HK"), Locale::en_HK),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    (locale_key(b"en_IE"), Locale::en_IE),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    (locale_key(b"en_IE@euro"), Locale::en_IE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-il"))]
+    (locale_key(b"en_IL"), Locale::en_IL),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-in"))]
+    (locale_key(b"en_IN"), Locale::en_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ng"))]
+    (locale_key(b"en_NG"), Locale::en_NG),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-nz"))]
+    (locale_key(b"en_NZ"), Locale::en_NZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ph"))]
+    (locale_key(b"en_PH"), Locale::en_PH),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sc"))]
+    (locale_key(b"en_SC"), Locale::en_SC),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sg"))]
+    (locale_key(b"en_SG"), Locale::en_SG),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us"))]
+    (locale_key(b"en_US"), Locale::en_US),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-za"))]
+    (locale_key(b"en_ZA"), Locale::en_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zm"))]
+    (locale_key(b"en_ZM"), Locale::en_ZM),
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zw"))]
+    (locale_key(b"en_ZW"), Locale::en_ZW),
+    #[cfg(any(feature = "all-locales", feature = "lang-eo"))]
+    (locale_key(b"eo"), Locale::eo),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ar"))]
+    (locale_key(b"es_AR"), Locale::es_AR),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-bo"))]
+    (locale_key(b"es_BO"), Locale::es_BO),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cl"))]
+    (locale_key(b"es_CL"), Locale::es_CL),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-co"))]
+    (locale_key(b"es_CO"), Locale::es_CO),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cr"))]
+    (locale_key(b"es_CR"), Locale::es_CR),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cu"))]
+    (locale_key(b"es_CU"), Locale::es_CU),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-do"))]
+    (locale_key(b"es_DO"), Locale::es_DO),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ec"))]
+    (locale_key(b"es_EC"), Locale::es_EC),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    (locale_key(b"es_ES"), Locale::es_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    (locale_key(b"es_ES@euro"), Locale::es_ES_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-gt"))]
+    (locale_key(b"es_GT"), Locale::es_GT),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-hn"))]
+    (locale_key(b"es_HN"), Locale::es_HN),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-mx"))]
+    (locale_key(b"es_MX"), Locale::es_MX),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ni"))]
+    (locale_key(b"es_NI"), Locale::es_NI),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pa"))]
+    (locale_key(b"es_PA"), Locale::es_PA),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pe"))]
+    (locale_key(b"es_PE"), Locale::es_PE),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pr"))]
+    (locale_key(b"es_PR"), Locale::es_PR),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-py"))]
+    (locale_key(b"es_PY"), Locale::es_PY),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-sv"))]
+    (locale_key(b"es_SV"), Locale::es_SV),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-us"))]
+    (locale_key(b"es_US"), Locale::es_US),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-uy"))]
+    (locale_key(b"es_UY"), Locale::es_UY),
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ve"))]
+    (locale_key(b"es_VE"), Locale::es_VE),
+    #[cfg(any(feature = "all-locales", feature = "lang-et", feature = "region-ee"))]
+    (locale_key(b"et_EE"), Locale::et_EE),
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    (locale_key(b"eu_ES"), Locale::eu_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    (locale_key(b"eu_ES@euro"), Locale::eu_ES_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir"))]
+    (locale_key(b"fa_IR"), Locale::fa_IR),
+    #[cfg(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn"))]
+    (locale_key(b"ff_SN"), Locale::ff_SN),
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    (locale_key(b"fi_FI"), Locale::fi_FI),
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    (locale_key(b"fi_FI@euro"), Locale::fi_FI_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph"))]
+    (locale_key(b"fil_PH"), Locale::fil_PH),
+    #[cfg(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo"))]
+    (locale_key(b"fo_FO"), Locale::fo_FO),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    (locale_key(b"fr_BE"), Locale::fr_BE),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    (locale_key(b"fr_BE@euro"), Locale::fr_BE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca"))]
+    (locale_key(b"fr_CA"), Locale::fr_CA),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch"))]
+    (locale_key(b"fr_CH"), Locale::fr_CH),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    (locale_key(b"fr_FR"), Locale::fr_FR),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    (locale_key(b"fr_FR@euro"), Locale::fr_FR_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    (locale_key(b"fr_LU"), Locale::fr_LU),
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    (locale_key(b"fr_LU@euro"), Locale::fr_LU_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-fur", feature = "region-it"))]
+    (locale_key(b"fur_IT"), Locale::fur_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-de"))]
+    (locale_key(b"fy_DE"), Locale::fy_DE),
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl"))]
+    (locale_key(b"fy_NL"), Locale::fy_NL),
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    (locale_key(b"ga_IE"), Locale::ga_IE),
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    (locale_key(b"ga_IE@euro"), Locale::ga_IE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb"))]
+    (locale_key(b"gd_GB"), Locale::gd_GB),
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    (locale_key(b"gez_ER"), Locale::gez_ER),
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    (locale_key(b"gez_ER@abegede"), Locale::gez_ER_abegede),
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    (locale_key(b"gez_ET"), Locale::gez_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    (locale_key(b"gez_ET@abegede"), Locale::gez_ET_abegede),
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    (locale_key(b"gl_ES"), Locale::gl_ES),
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    (locale_key(b"gl_ES@euro"), Locale::gl_ES_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-gu", feature = "region-in"))]
+    (locale_key(b"gu_IN"), Locale::gu_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb"))]
+    (locale_key(b"gv_GB"), Locale::gv_GB),
+    #[cfg(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng"))]
+    (locale_key(b"ha_NG"), Locale::ha_NG),
+    #[cfg(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw"))]
+    (locale_key(b"hak_TW"), Locale::hak_TW),
+    #[cfg(any(feature = "all-locales", feature = "lang-he", feature = "region-il"))]
+    (locale_key(b"he_IL"), Locale::he_IL),
+    #[cfg(any(feature = "all-locales", feature = "lang-hi", feature = "region-in"))]
+    (locale_key(b"hi_IN"), Locale::hi_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj"))]
+    (locale_key(b"hif_FJ"), Locale::hif_FJ),
+    #[cfg(any(feature = "all-locales", feature = "lang-hne", feature = "region-in"))]
+    (locale_key(b"hne_IN"), Locale::hne_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr"))]
+    (locale_key(b"hr_HR"), Locale::hr_HR),
+    #[cfg(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de"))]
+    (locale_key(b"hsb_DE"), Locale::hsb_DE),
+    #[cfg(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht"))]
+    (locale_key(b"ht_HT"), Locale::ht_HT),
+    #[cfg(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu"))]
+    (locale_key(b"hu_HU"), Locale::hu_HU),
+    #[cfg(any(feature = "all-locales", feature = "lang-hy", feature = "region-am"))]
+    (locale_key(b"hy_AM"), Locale::hy_AM),
+    #[cfg(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr"))]
+    (locale_key(b"ia_FR"), Locale::ia_FR),
+    #[cfg(any(feature = "all-locales", feature = "lang-id", feature = "region-id"))]
+    (locale_key(b"id_ID"), Locale::id_ID),
+    #[cfg(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng"))]
+    (locale_key(b"ig_NG"), Locale::ig_NG),
+    #[cfg(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca"))]
+    (locale_key(b"ik_CA"), Locale::ik_CA),
+    #[cfg(any(feature = "all-locales", feature = "lang-is", feature = "region-is"))]
+    (locale_key(b"is_IS"), Locale::is_IS),
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-ch"))]
+    (locale_key(b"it_CH"), Locale::it_CH),
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    (locale_key(b"it_IT"), Locale::it_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    (locale_key(b"it_IT@euro"), Locale::it_IT_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca"))]
+    (locale_key(b"iu_CA"), Locale::iu_CA),
+    #[cfg(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp"))]
+    (locale_key(b"ja_JP"), Locale::ja_JP),
+    #[cfg(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge"))]
+    (locale_key(b"ka_GE"), Locale::ka_GE),
+    #[cfg(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz"))]
+    (locale_key(b"kab_DZ"), Locale::kab_DZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz"))]
+    (locale_key(b"kk_KZ"), Locale::kk_KZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl"))]
+    (locale_key(b"kl_GL"), Locale::kl_GL),
+    #[cfg(any(feature = "all-locales", feature = "lang-km", feature = "region-kh"))]
+    (locale_key(b"km_KH"), Locale::km_KH),
+    #[cfg(any(feature = "all-locales", feature = "lang-kn", feature = "region-in"))]
+    (locale_key(b"kn_IN"), Locale::kn_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr"))]
+    (locale_key(b"ko_KR"), Locale::ko_KR),
+    #[cfg(any(feature = "all-locales", feature = "lang-kok", feature = "region-in"))]
+    (locale_key(b"kok_IN"), Locale::kok_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    (locale_key(b"ks_IN"), Locale::ks_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    (locale_key(b"ks_IN@devanagari"), Locale::ks_IN_devanagari),
+    #[cfg(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr"))]
+    (locale_key(b"ku_TR"), Locale::ku_TR),
+    #[cfg(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb"))]
+    (locale_key(b"kw_GB"), Locale::kw_GB),
+    #[cfg(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg"))]
+    (locale_key(b"ky_KG"), Locale::ky_KG),
+    #[cfg(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu"))]
+    (locale_key(b"lb_LU"), Locale::lb_LU),
+    #[cfg(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug"))]
+    (locale_key(b"lg_UG"), Locale::lg_UG),
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-be"))]
+    (locale_key(b"li_BE"), Locale::li_BE),
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-nl"))]
+    (locale_key(b"li_NL"), Locale::li_NL),
+    #[cfg(any(feature = "all-locales", feature = "lang-lij", feature = "region-it"))]
+    (locale_key(b"lij_IT"), Locale::lij_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd"))]
+    (locale_key(b"ln_CD"), Locale::ln_CD),
+    #[cfg(any(feature = "all-locales", feature = "lang-lo", feature = "region-la"))]
+    (locale_key(b"lo_LA"), Locale::lo_LA),
+    #[cfg(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt"))]
+    (locale_key(b"lt_LT"), Locale::lt_LT),
+    #[cfg(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv"))]
+    (locale_key(b"lv_LV"), Locale::lv_LV),
+    #[cfg(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw"))]
+    (locale_key(b"lzh_TW"), Locale::lzh_TW),
+    #[cfg(any(feature = "all-locales", feature = "lang-mag", feature = "region-in"))]
+    (locale_key(b"mag_IN"), Locale::mag_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-in"))]
+    (locale_key(b"mai_IN"), Locale::mai_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-np"))]
+    (locale_key(b"mai_NP"), Locale::mai_NP),
+    #[cfg(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu"))]
+    (locale_key(b"mfe_MU"), Locale::mfe_MU),
+    #[cfg(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg"))]
+    (locale_key(b"mg_MG"), Locale::mg_MG),
+    #[cfg(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru"))]
+    (locale_key(b"mhr_RU"), Locale::mhr_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz"))]
+    (locale_key(b"mi_NZ"), Locale::mi_NZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni"))]
+    (locale_key(b"miq_NI"), Locale::miq_NI),
+    #[cfg(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in"))]
+    (locale_key(b"mjw_IN"), Locale::mjw_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk"))]
+    (locale_key(b"mk_MK"), Locale::mk_MK),
+    #[cfg(any(feature = "all-locales", feature = "lang-ml", feature = "region-in"))]
+    (locale_key(b"ml_IN"), Locale::ml_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn"))]
+    (locale_key(b"mn_MN"), Locale::mn_MN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mni", feature = "region-in"))]
+    (locale_key(b"mni_IN"), Locale::mni_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm"))]
+    (locale_key(b"mnw_MM"), Locale::mnw_MM),
+    #[cfg(any(feature = "all-locales", feature = "lang-mr", feature = "region-in"))]
+    (locale_key(b"mr_IN"), Locale::mr_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ms", feature = "region-my"))]
+    (locale_key(b"ms_MY"), Locale::ms_MY),
+    #[cfg(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt"))]
+    (locale_key(b"mt_MT"), Locale::mt_MT),
+    #[cfg(any(feature = "all-locales", feature = "lang-my", feature = "region-mm"))]
+    (locale_key(b"my_MM"), Locale::my_MM),
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    (locale_key(b"nan_TW"), Locale::nan_TW),
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    (locale_key(b"nan_TW@latin"), Locale::nan_TW_latin),
+    #[cfg(any(feature = "all-locales", feature = "lang-nb", feature = "region-no"))]
+    (locale_key(b"nb_NO"), Locale::nb_NO),
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-de"))]
+    (locale_key(b"nds_DE"), Locale::nds_DE),
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl"))]
+    (locale_key(b"nds_NL"), Locale::nds_NL),
+    #[cfg(any(feature = "all-locales", feature = "lang-ne", feature = "region-np"))]
+    (locale_key(b"ne_NP"), Locale::ne_NP),
+    #[cfg(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx"))]
+    (locale_key(b"nhn_MX"), Locale::nhn_MX),
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu"))]
+    (locale_key(b"niu_NU"), Locale::niu_NU),
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz"))]
+    (locale_key(b"niu_NZ"), Locale::niu_NZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw"))]
+    (locale_key(b"nl_AW"), Locale::nl_AW),
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    (locale_key(b"nl_BE"), Locale::nl_BE),
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    (locale_key(b"nl_BE@euro"), Locale::nl_BE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    (locale_key(b"nl_NL"), Locale::nl_NL),
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    (locale_key(b"nl_NL@euro"), Locale::nl_NL_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-nn", feature = "region-no"))]
+    (locale_key(b"nn_NO"), Locale::nn_NO),
+    #[cfg(any(feature = "all-locales", feature = "lang-nr", feature = "region-za"))]
+    (locale_key(b"nr_ZA"), Locale::nr_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-nso", feature = "region-za"))]
+    (locale_key(b"nso_ZA"), Locale::nso_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr"))]
+    (locale_key(b"oc_FR"), Locale::oc_FR),
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-et"))]
+    (locale_key(b"om_ET"), Locale::om_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-ke"))]
+    (locale_key(b"om_KE"), Locale::om_KE),
+    #[cfg(any(feature = "all-locales", feature = "lang-or", feature = "region-in"))]
+    (locale_key(b"or_IN"), Locale::or_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-os", feature = "region-ru"))]
+    (locale_key(b"os_RU"), Locale::os_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-in"))]
+    (locale_key(b"pa_IN"), Locale::pa_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk"))]
+    (locale_key(b"pa_PK"), Locale::pa_PK),
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw"))]
+    (locale_key(b"pap_AW"), Locale::pap_AW),
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw"))]
+    (locale_key(b"pap_CW"), Locale::pap_CW),
+    #[cfg(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl"))]
+    (locale_key(b"pl_PL"), Locale::pl_PL),
+    (locale_key(b"POSIX"), Locale::POSIX),
+    #[cfg(any(feature = "all-locales", feature = "lang-ps", feature = "region-af"))]
+    (locale_key(b"ps_AF"), Locale::ps_AF),
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-br"))]
+    (locale_key(b"pt_BR"), Locale::pt_BR),
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    (locale_key(b"pt_PT"), Locale::pt_PT),
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    (locale_key(b"pt_PT@euro"), Locale::pt_PT_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe"))]
+    (locale_key(b"quz_PE"), Locale::quz_PE),
+    #[cfg(any(feature = "all-locales", feature = "lang-raj", feature = "region-in"))]
+    (locale_key(b"raj_IN"), Locale::raj_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro"))]
+    (locale_key(b"ro_RO"), Locale::ro_RO),
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru"))]
+    (locale_key(b"ru_RU"), Locale::ru_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua"))]
+    (locale_key(b"ru_UA"), Locale::ru_UA),
+    #[cfg(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw"))]
+    (locale_key(b"rw_RW"), Locale::rw_RW),
+    #[cfg(any(feature = "all-locales", feature = "lang-sa", feature = "region-in"))]
+    (locale_key(b"sa_IN"), Locale::sa_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru"))]
+    (locale_key(b"sah_RU"), Locale::sah_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-sat", feature = "region-in"))]
+    (locale_key(b"sat_IN"), Locale::sat_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-sc", feature = "region-it"))]
+    (locale_key(b"sc_IT"), Locale::sc_IT),
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    (locale_key(b"sd_IN"), Locale::sd_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    (locale_key(b"sd_IN@devanagari"), Locale::sd_IN_devanagari),
+    #[cfg(any(feature = "all-locales", feature = "lang-se", feature = "region-no"))]
+    (locale_key(b"se_NO"), Locale::se_NO),
+    #[cfg(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt"))]
+    (locale_key(b"sgs_LT"), Locale::sgs_LT),
+    #[cfg(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm"))]
+    (locale_key(b"shn_MM"), Locale::shn_MM),
+    #[cfg(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca"))]
+    (locale_key(b"shs_CA"), Locale::shs_CA),
+    #[cfg(any(feature = "all-locales", feature = "lang-si", feature = "region-lk"))]
+    (locale_key(b"si_LK"), Locale::si_LK),
+    #[cfg(any(feature = "all-locales", feature = "lang-sid", feature = "region-et"))]
+    (locale_key(b"sid_ET"), Locale::sid_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk"))]
+    (locale_key(b"sk_SK"), Locale::sk_SK),
+    #[cfg(any(feature = "all-locales", feature = "lang-sl", feature = "region-si"))]
+    (locale_key(b"sl_SI"), Locale::sl_SI),
+    #[cfg(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws"))]
+    (locale_key(b"sm_WS"), Locale::sm_WS),
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-dj"))]
+    (locale_key(b"so_DJ"), Locale::so_DJ),
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-et"))]
+    (locale_key(b"so_ET"), Locale::so_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-ke"))]
+    (locale_key(b"so_KE"), Locale::so_KE),
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-so"))]
+    (locale_key(b"so_SO"), Locale::so_SO),
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-al"))]
+    (locale_key(b"sq_AL"), Locale::sq_AL),
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk"))]
+    (locale_key(b"sq_MK"), Locale::sq_MK),
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-me"))]
+    (locale_key(b"sr_ME"), Locale::sr_ME),
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    (locale_key(b"sr_RS"), Locale::sr_RS),
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    (locale_key(b"sr_RS@latin"), Locale::sr_RS_latin),
+    #[cfg(any(feature = "all-locales", feature = "lang-ss", feature = "region-za"))]
+    (locale_key(b"ss_ZA"), Locale::ss_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-st", feature = "region-za"))]
+    (locale_key(b"st_ZA"), Locale::st_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    (locale_key(b"sv_FI"), Locale::sv_FI),
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    (locale_key(b"sv_FI@euro"), Locale::sv_FI_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-se"))]
+    (locale_key(b"sv_SE"), Locale::sv_SE),
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke"))]
+    (locale_key(b"sw_KE"), Locale::sw_KE),
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz"))]
+    (locale_key(b"sw_TZ"), Locale::sw_TZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl"))]
+    (locale_key(b"szl_PL"), Locale::szl_PL),
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-in"))]
+    (locale_key(b"ta_IN"), Locale::ta_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk"))]
+    (locale_key(b"ta_LK"), Locale::ta_LK),
+    #[cfg(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in"))]
+    (locale_key(b"tcy_IN"), Locale::tcy_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-te", feature = "region-in"))]
+    (locale_key(b"te_IN"), Locale::te_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj"))]
+    (locale_key(b"tg_TJ"), Locale::tg_TJ),
+    #[cfg(any(feature = "all-locales", feature = "lang-th", feature = "region-th"))]
+    (locale_key(b"th_TH"), Locale::th_TH),
+    #[cfg(any(feature = "all-locales", feature = "lang-the", feature = "region-np"))]
+    (locale_key(b"the_NP"), Locale::the_NP),
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-er"))]
+    (locale_key(b"ti_ER"), Locale::ti_ER),
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-et"))]
+    (locale_key(b"ti_ET"), Locale::ti_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-tig", feature = "region-er"))]
+    (locale_key(b"tig_ER"), Locale::tig_ER),
+    #[cfg(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm"))]
+    (locale_key(b"tk_TM"), Locale::tk_TM),
+    #[cfg(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph"))]
+    (locale_key(b"tl_PH"), Locale::tl_PH),
+    #[cfg(any(feature = "all-locales", feature = "lang-tn", feature = "region-za"))]
+    (locale_key(b"tn_ZA"), Locale::tn_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-to", feature = "region-to"))]
+    (locale_key(b"to_TO"), Locale::to_TO),
+    #[cfg(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg"))]
+    (locale_key(b"tpi_PG"), Locale::tpi_PG),
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy"))]
+    (locale_key(b"tr_CY"), Locale::tr_CY),
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr"))]
+    (locale_key(b"tr_TR"), Locale::tr_TR),
+    #[cfg(any(feature = "all-locales", feature = "lang-ts", feature = "region-za"))]
+    (locale_key(b"ts_ZA"), Locale::ts_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    (locale_key(b"tt_RU"), Locale::tt_RU),
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    (locale_key(b"tt_RU@iqtelif"), Locale::tt_RU_iqtelif),
+    #[cfg(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn"))]
+    (locale_key(b"ug_CN"), Locale::ug_CN),
+    #[cfg(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua"))]
+    (locale_key(b"uk_UA"), Locale::uk_UA),
+    #[cfg(any(feature = "all-locales", feature = "lang-unm", feature = "region-us"))]
+    (locale_key(b"unm_US"), Locale::unm_US),
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-in"))]
+    (locale_key(b"ur_IN"), Locale::ur_IN),
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk"))]
+    (locale_key(b"ur_PK"), Locale::ur_PK),
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    (locale_key(b"uz_UZ"), Locale::uz_UZ),
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    (locale_key(b"uz_UZ@cyrillic"), Locale::uz_UZ_cyrillic),
+    #[cfg(any(feature = "all-locales", feature = "lang-ve", feature = "region-za"))]
+    (locale_key(b"ve_ZA"), Locale::ve_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn"))]
+    (locale_key(b"vi_VN"), Locale::vi_VN),
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    (locale_key(b"wa_BE"), Locale::wa_BE),
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    (locale_key(b"wa_BE@euro"), Locale::wa_BE_euro),
+    #[cfg(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch"))]
+    (locale_key(b"wae_CH"), Locale::wae_CH),
+    #[cfg(any(feature = "all-locales", feature = "lang-wal", feature = "region-et"))]
+    (locale_key(b"wal_ET"), Locale::wal_ET),
+    #[cfg(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn"))]
+    (locale_key(b"wo_SN"), Locale::wo_SN),
+    #[cfg(any(feature = "all-locales", feature = "lang-xh", feature = "region-za"))]
+    (locale_key(b"xh_ZA"), Locale::xh_ZA),
+    #[cfg(any(feature = "all-locales", feature = "lang-yi", feature = "region-us"))]
+    (locale_key(b"yi_US"), Locale::yi_US),
+    #[cfg(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng"))]
+    (locale_key(b"yo_NG"), Locale::yo_NG),
+    #[cfg(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk"))]
+    (locale_key(b"yue_HK"), Locale::yue_HK),
+    #[cfg(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg"))]
+    (locale_key(b"yuw_PG"), Locale::yuw_PG),
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn"))]
+    (locale_key(b"zh_CN"), Locale::zh_CN),
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk"))]
+    (locale_key(b"zh_HK"), Locale::zh_HK),
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg"))]
+    (locale_key(b"zh_SG"), Locale::zh_SG),
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw"))]
+    (locale_key(b"zh_TW"), Locale::zh_TW),
+    #[cfg(any(feature = "all-locales", feature = "lang-zu", feature = "region-za"))]
+    (locale_key(b"zu_ZA"), Locale::zu_ZA),
+];
+
+/// The length of the longest name that has a key.
+const LOCALE_KEY_LEN: usize = 16;
+
+/// The key of the name of a locale, compared in one step: its bytes, in lowercase
+/// and with `_` for `-`, padded with zeros. The keys are sorted like the names.
+const fn locale_key(name: &[u8]) -> u128 {
+    let mut key = 0;
+    let mut i = 0;
+    while i < LOCALE_KEY_LEN {
+        key <<= 8;
+        if i < name.len() {
+            key |= match name[i] {
+                b'-' => b'_',
+                x => x.to_ascii_lowercase(),
+            } as u128;
+        }
+        i += 1;
+    }
+    key
+}
+
+impl core::fmt::Display for Locale {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::fmt::Debug for Locale {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Locale {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{:?}", self);
+    }
+}
+
+impl core::str::FromStr for Locale {
+    type Err = UnknownLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        core::convert::TryFrom::<&str>::try_from(s)
+    }
+}
+
+/// Parses the name of a locale, ignoring the case of the ASCII letters and with `-`
+/// or `_` between its parts: `fr_BE`, `fr-be` and `FR_BE` are `Locale::fr_BE`.
+impl core::convert::TryFrom<&str> for Locale {
+    type Error = UnknownLocale;
+
+    fn try_from(i: &str) -> Result<Self, Self::Error> {
+        if i.len() > LOCALE_KEY_LEN {
+            return Err(UnknownLocale::new(i));
+        }
+        let key = locale_key(i.as_bytes());
+        // The key is padded with zeros: the length tells `fr_BE` from `fr_BE\0`.
+        LOCALES_BY_NAME
+            .binary_search_by(|(x, _)| x.cmp(&key))
+            .ok()
+            .map(|index| LOCALES_BY_NAME[index].1)
+            .filter(|locale| locale.name().len() == i.len())
+            .ok_or_else(|| UnknownLocale::new(i))
+    }
+}
+
+/// Constants and modules used by `locale_match!`.
+///
+/// `#[cfg]` attributes can't be used in the expansion of the macro as they would be
+/// evaluated in the crate calling it. Locales that are not selected are aliased to
+/// `POSIX` here instead; their arms are unreachable.
+#[doc(hidden)]
+#[allow(non_upper_case_globals,unused_imports)]
+pub mod __locale_match {
+    pub use super::POSIX;
+    pub const POSIX: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj"))]
+    pub use super::aa_DJ;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj"))]
+    pub const aa_DJ: super::Locale = super::Locale::aa_DJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj")))]
+    pub use super::POSIX as aa_DJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-dj")))]
+    pub const aa_DJ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    pub use super::aa_ER;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    pub const aa_ER: super::Locale = super::Locale::aa_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-er")))]
+    pub use super::POSIX as aa_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-er")))]
+    pub const aa_ER: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    pub use super::aa_ER_saaho;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-er"))]
+    pub const aa_ER_saaho: super::Locale = super::Locale::aa_ER_saaho;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-er")))]
+    pub use super::POSIX as aa_ER_saaho;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-er")))]
+    pub const aa_ER_saaho: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-et"))]
+    pub use super::aa_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-aa", feature = "region-et"))]
+    pub const aa_ET: super::Locale = super::Locale::aa_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-et")))]
+    pub use super::POSIX as aa_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-aa", feature = "region-et")))]
+    pub const aa_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-af", feature = "region-za"))]
+    pub use super::af_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-af", feature = "region-za"))]
+    pub const af_ZA: super::Locale = super::Locale::af_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-af", feature = "region-za")))]
+    pub use super::POSIX as af_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-af", feature = "region-za")))]
+    pub const af_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe"))]
+    pub use super::agr_PE;
+    #[cfg(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe"))]
+    pub const agr_PE: super::Locale = super::Locale::agr_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe")))]
+    pub use super::POSIX as agr_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-agr", feature = "region-pe")))]
+    pub const agr_PE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh"))]
+    pub use super::ak_GH;
+    #[cfg(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh"))]
+    pub const ak_GH: super::Locale = super::Locale::ak_GH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh")))]
+    pub use super::POSIX as ak_GH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ak", feature = "region-gh")))]
+    pub const ak_GH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-am", feature = "region-et"))]
+    pub use super::am_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-am", feature = "region-et"))]
+    pub const am_ET: super::Locale = super::Locale::am_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-am", feature = "region-et")))]
+    pub use super::POSIX as am_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-am", feature = "region-et")))]
+    pub const am_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-an", feature = "region-es"))]
+    pub use super::an_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-an", feature = "region-es"))]
+    pub const an_ES: super::Locale = super::Locale::an_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-an", feature = "region-es")))]
+    pub use super::POSIX as an_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-an", feature = "region-es")))]
+    pub const an_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-anp", feature = "region-in"))]
+    pub use super::anp_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-anp", feature = "region-in"))]
+    pub const anp_IN: super::Locale = super::Locale::anp_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-anp", feature = "region-in")))]
+    pub use super::POSIX as anp_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-anp", feature = "region-in")))]
+    pub const anp_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae"))]
+    pub use super::ar_AE;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae"))]
+    pub const ar_AE: super::Locale = super::Locale::ar_AE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae")))]
+    pub use super::POSIX as ar_AE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ae")))]
+    pub const ar_AE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh"))]
+    pub use super::ar_BH;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh"))]
+    pub const ar_BH: super::Locale = super::Locale::ar_BH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh")))]
+    pub use super::POSIX as ar_BH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-bh")))]
+    pub const ar_BH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz"))]
+    pub use super::ar_DZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz"))]
+    pub const ar_DZ: super::Locale = super::Locale::ar_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz")))]
+    pub use super::POSIX as ar_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-dz")))]
+    pub const ar_DZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg"))]
+    pub use super::ar_EG;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg"))]
+    pub const ar_EG: super::Locale = super::Locale::ar_EG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg")))]
+    pub use super::POSIX as ar_EG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-eg")))]
+    pub const ar_EG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-in"))]
+    pub use super::ar_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-in"))]
+    pub const ar_IN: super::Locale = super::Locale::ar_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-in")))]
+    pub use super::POSIX as ar_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-in")))]
+    pub const ar_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq"))]
+    pub use super::ar_IQ;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq"))]
+    pub const ar_IQ: super::Locale = super::Locale::ar_IQ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq")))]
+    pub use super::POSIX as ar_IQ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-iq")))]
+    pub const ar_IQ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo"))]
+    pub use super::ar_JO;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo"))]
+    pub const ar_JO: super::Locale = super::Locale::ar_JO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo")))]
+    pub use super::POSIX as ar_JO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-jo")))]
+    pub const ar_JO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw"))]
+    pub use super::ar_KW;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw"))]
+    pub const ar_KW: super::Locale = super::Locale::ar_KW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw")))]
+    pub use super::POSIX as ar_KW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-kw")))]
+    pub const ar_KW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb"))]
+    pub use super::ar_LB;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb"))]
+    pub const ar_LB: super::Locale = super::Locale::ar_LB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb")))]
+    pub use super::POSIX as ar_LB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-lb")))]
+    pub const ar_LB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly"))]
+    pub use super::ar_LY;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly"))]
+    pub const ar_LY: super::Locale = super::Locale::ar_LY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly")))]
+    pub use super::POSIX as ar_LY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ly")))]
+    pub const ar_LY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma"))]
+    pub use super::ar_MA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma"))]
+    pub const ar_MA: super::Locale = super::Locale::ar_MA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma")))]
+    pub use super::POSIX as ar_MA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ma")))]
+    pub const ar_MA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-om"))]
+    pub use super::ar_OM;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-om"))]
+    pub const ar_OM: super::Locale = super::Locale::ar_OM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-om")))]
+    pub use super::POSIX as ar_OM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-om")))]
+    pub const ar_OM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa"))]
+    pub use super::ar_QA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa"))]
+    pub const ar_QA: super::Locale = super::Locale::ar_QA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa")))]
+    pub use super::POSIX as ar_QA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-qa")))]
+    pub const ar_QA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa"))]
+    pub use super::ar_SA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa"))]
+    pub const ar_SA: super::Locale = super::Locale::ar_SA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa")))]
+    pub use super::POSIX as ar_SA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sa")))]
+    pub const ar_SA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd"))]
+    pub use super::ar_SD;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd"))]
+    pub const ar_SD: super::Locale = super::Locale::ar_SD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd")))]
+    pub use super::POSIX as ar_SD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sd")))]
+    pub const ar_SD: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss"))]
+    pub use super::ar_SS;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss"))]
+    pub const ar_SS: super::Locale = super::Locale::ar_SS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss")))]
+    pub use super::POSIX as ar_SS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ss")))]
+    pub const ar_SS: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy"))]
+    pub use super::ar_SY;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy"))]
+    pub const ar_SY: super::Locale = super::Locale::ar_SY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy")))]
+    pub use super::POSIX as ar_SY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-sy")))]
+    pub const ar_SY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn"))]
+    pub use super::ar_TN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn"))]
+    pub const ar_TN: super::Locale = super::Locale::ar_TN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn")))]
+    pub use super::POSIX as ar_TN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-tn")))]
+    pub const ar_TN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye"))]
+    pub use super::ar_YE;
+    #[cfg(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye"))]
+    pub const ar_YE: super::Locale = super::Locale::ar_YE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye")))]
+    pub use super::POSIX as ar_YE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ar", feature = "region-ye")))]
+    pub const ar_YE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-as", feature = "region-in"))]
+    pub use super::as_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-as", feature = "region-in"))]
+    pub const as_IN: super::Locale = super::Locale::as_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-as", feature = "region-in")))]
+    pub use super::POSIX as as_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-as", feature = "region-in")))]
+    pub const as_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ast", feature = "region-es"))]
+    pub use super::ast_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-ast", feature = "region-es"))]
+    pub const ast_ES: super::Locale = super::Locale::ast_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ast", feature = "region-es")))]
+    pub use super::POSIX as ast_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ast", feature = "region-es")))]
+    pub const ast_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe"))]
+    pub use super::ayc_PE;
+    #[cfg(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe"))]
+    pub const ayc_PE: super::Locale = super::Locale::ayc_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe")))]
+    pub use super::POSIX as ayc_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ayc", feature = "region-pe")))]
+    pub const ayc_PE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-az"))]
+    pub use super::az_AZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-az"))]
+    pub const az_AZ: super::Locale = super::Locale::az_AZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-az", feature = "region-az")))]
+    pub use super::POSIX as az_AZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-az", feature = "region-az")))]
+    pub const az_AZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-ir"))]
+    pub use super::az_IR;
+    #[cfg(any(feature = "all-locales", feature = "lang-az", feature = "region-ir"))]
+    pub const az_IR: super::Locale = super::Locale::az_IR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-az", feature = "region-ir")))]
+    pub use super::POSIX as az_IR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-az", feature = "region-ir")))]
+    pub const az_IR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    pub use super::be_BY;
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    pub const be_BY: super::Locale = super::Locale::be_BY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-be", feature = "region-by")))]
+    pub use super::POSIX as be_BY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-be", feature = "region-by")))]
+    pub const be_BY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    pub use super::be_BY_latin;
+    #[cfg(any(feature = "all-locales", feature = "lang-be", feature = "region-by"))]
+    pub const be_BY_latin: super::Locale = super::Locale::be_BY_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-be", feature = "region-by")))]
+    pub use super::POSIX as be_BY_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-be", feature = "region-by")))]
+    pub const be_BY_latin: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm"))]
+    pub use super::bem_ZM;
+    #[cfg(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm"))]
+    pub const bem_ZM: super::Locale = super::Locale::bem_ZM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm")))]
+    pub use super::POSIX as bem_ZM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bem", feature = "region-zm")))]
+    pub const bem_ZM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz"))]
+    pub use super::ber_DZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz"))]
+    pub const ber_DZ: super::Locale = super::Locale::ber_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz")))]
+    pub use super::POSIX as ber_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ber", feature = "region-dz")))]
+    pub const ber_DZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma"))]
+    pub use super::ber_MA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma"))]
+    pub const ber_MA: super::Locale = super::Locale::ber_MA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma")))]
+    pub use super::POSIX as ber_MA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ber", feature = "region-ma")))]
+    pub const ber_MA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg"))]
+    pub use super::bg_BG;
+    #[cfg(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg"))]
+    pub const bg_BG: super::Locale = super::Locale::bg_BG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg")))]
+    pub use super::POSIX as bg_BG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bg", feature = "region-bg")))]
+    pub const bg_BG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in"))]
+    pub use super::bhb_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in"))]
+    pub const bhb_IN: super::Locale = super::Locale::bhb_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in")))]
+    pub use super::POSIX as bhb_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bhb", feature = "region-in")))]
+    pub const bhb_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-in"))]
+    pub use super::bho_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-in"))]
+    pub const bho_IN: super::Locale = super::Locale::bho_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bho", feature = "region-in")))]
+    pub use super::POSIX as bho_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bho", feature = "region-in")))]
+    pub const bho_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-np"))]
+    pub use super::bho_NP;
+    #[cfg(any(feature = "all-locales", feature = "lang-bho", feature = "region-np"))]
+    pub const bho_NP: super::Locale = super::Locale::bho_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bho", feature = "region-np")))]
+    pub use super::POSIX as bho_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bho", feature = "region-np")))]
+    pub const bho_NP: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu"))]
+    pub use super::bi_VU;
+    #[cfg(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu"))]
+    pub const bi_VU: super::Locale = super::Locale::bi_VU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu")))]
+    pub use super::POSIX as bi_VU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bi", feature = "region-vu")))]
+    pub const bi_VU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd"))]
+    pub use super::bn_BD;
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd"))]
+    pub const bn_BD: super::Locale = super::Locale::bn_BD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd")))]
+    pub use super::POSIX as bn_BD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bn", feature = "region-bd")))]
+    pub const bn_BD: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-in"))]
+    pub use super::bn_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-bn", feature = "region-in"))]
+    pub const bn_IN: super::Locale = super::Locale::bn_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bn", feature = "region-in")))]
+    pub use super::POSIX as bn_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bn", feature = "region-in")))]
+    pub const bn_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn"))]
+    pub use super::bo_CN;
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn"))]
+    pub const bo_CN: super::Locale = super::Locale::bo_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn")))]
+    pub use super::POSIX as bo_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bo", feature = "region-cn")))]
+    pub const bo_CN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-in"))]
+    pub use super::bo_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-bo", feature = "region-in"))]
+    pub const bo_IN: super::Locale = super::Locale::bo_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bo", feature = "region-in")))]
+    pub use super::POSIX as bo_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bo", feature = "region-in")))]
+    pub const bo_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    pub use super::br_FR;
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    pub const br_FR: super::Locale = super::Locale::br_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-br", feature = "region-fr")))]
+    pub use super::POSIX as br_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-br", feature = "region-fr")))]
+    pub const br_FR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    pub use super::br_FR_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-br", feature = "region-fr"))]
+    pub const br_FR_euro: super::Locale = super::Locale::br_FR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-br", feature = "region-fr")))]
+    pub use super::POSIX as br_FR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-br", feature = "region-fr")))]
+    pub const br_FR_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-brx", feature = "region-in"))]
+    pub use super::brx_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-brx", feature = "region-in"))]
+    pub const brx_IN: super::Locale = super::Locale::brx_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-brx", feature = "region-in")))]
+    pub use super::POSIX as brx_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-brx", feature = "region-in")))]
+    pub const brx_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba"))]
+    pub use super::bs_BA;
+    #[cfg(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba"))]
+    pub const bs_BA: super::Locale = super::Locale::bs_BA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba")))]
+    pub use super::POSIX as bs_BA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-bs", feature = "region-ba")))]
+    pub const bs_BA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-byn", feature = "region-er"))]
+    pub use super::byn_ER;
+    #[cfg(any(feature = "all-locales", feature = "lang-byn", feature = "region-er"))]
+    pub const byn_ER: super::Locale = super::Locale::byn_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-byn", feature = "region-er")))]
+    pub use super::POSIX as byn_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-byn", feature = "region-er")))]
+    pub const byn_ER: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad"))]
+    pub use super::ca_AD;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad"))]
+    pub const ca_AD: super::Locale = super::Locale::ca_AD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad")))]
+    pub use super::POSIX as ca_AD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-ad")))]
+    pub const ca_AD: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub use super::ca_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub const ca_ES: super::Locale = super::Locale::ca_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub use super::POSIX as ca_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub const ca_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub use super::ca_ES_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub const ca_ES_euro: super::Locale = super::Locale::ca_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub use super::POSIX as ca_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub const ca_ES_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub use super::ca_ES_valencia;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-es"))]
+    pub const ca_ES_valencia: super::Locale = super::Locale::ca_ES_valencia;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub use super::POSIX as ca_ES_valencia;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-es")))]
+    pub const ca_ES_valencia: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr"))]
+    pub use super::ca_FR;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr"))]
+    pub const ca_FR: super::Locale = super::Locale::ca_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr")))]
+    pub use super::POSIX as ca_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-fr")))]
+    pub const ca_FR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-it"))]
+    pub use super::ca_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-ca", feature = "region-it"))]
+    pub const ca_IT: super::Locale = super::Locale::ca_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-it")))]
+    pub use super::POSIX as ca_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ca", feature = "region-it")))]
+    pub const ca_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru"))]
+    pub use super::ce_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru"))]
+    pub const ce_RU: super::Locale = super::Locale::ce_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru")))]
+    pub use super::POSIX as ce_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ce", feature = "region-ru")))]
+    pub const ce_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-chr", feature = "region-us"))]
+    pub use super::chr_US;
+    #[cfg(any(feature = "all-locales", feature = "lang-chr", feature = "region-us"))]
+    pub const chr_US: super::Locale = super::Locale::chr_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-chr", feature = "region-us")))]
+    pub use super::POSIX as chr_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-chr", feature = "region-us")))]
+    pub const chr_US: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw"))]
+    pub use super::cmn_TW;
+    #[cfg(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw"))]
+    pub const cmn_TW: super::Locale = super::Locale::cmn_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw")))]
+    pub use super::POSIX as cmn_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cmn", feature = "region-tw")))]
+    pub const cmn_TW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua"))]
+    pub use super::crh_UA;
+    #[cfg(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua"))]
+    pub const crh_UA: super::Locale = super::Locale::crh_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua")))]
+    pub use super::POSIX as crh_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-crh", feature = "region-ua")))]
+    pub const crh_UA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz"))]
+    pub use super::cs_CZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz"))]
+    pub const cs_CZ: super::Locale = super::Locale::cs_CZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz")))]
+    pub use super::POSIX as cs_CZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cs", feature = "region-cz")))]
+    pub const cs_CZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl"))]
+    pub use super::csb_PL;
+    #[cfg(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl"))]
+    pub const csb_PL: super::Locale = super::Locale::csb_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl")))]
+    pub use super::POSIX as csb_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-csb", feature = "region-pl")))]
+    pub const csb_PL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru"))]
+    pub use super::cv_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru"))]
+    pub const cv_RU: super::Locale = super::Locale::cv_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru")))]
+    pub use super::POSIX as cv_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cv", feature = "region-ru")))]
+    pub const cv_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb"))]
+    pub use super::cy_GB;
+    #[cfg(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb"))]
+    pub const cy_GB: super::Locale = super::Locale::cy_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb")))]
+    pub use super::POSIX as cy_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-cy", feature = "region-gb")))]
+    pub const cy_GB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-da", feature = "region-dk"))]
+    pub use super::da_DK;
+    #[cfg(any(feature = "all-locales", feature = "lang-da", feature = "region-dk"))]
+    pub const da_DK: super::Locale = super::Locale::da_DK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-da", feature = "region-dk")))]
+    pub use super::POSIX as da_DK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-da", feature = "region-dk")))]
+    pub const da_DK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    pub use super::de_AT;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    pub const de_AT: super::Locale = super::Locale::de_AT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-at")))]
+    pub use super::POSIX as de_AT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-at")))]
+    pub const de_AT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    pub use super::de_AT_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-at"))]
+    pub const de_AT_euro: super::Locale = super::Locale::de_AT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-at")))]
+    pub use super::POSIX as de_AT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-at")))]
+    pub const de_AT_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    pub use super::de_BE;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    pub const de_BE: super::Locale = super::Locale::de_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-be")))]
+    pub use super::POSIX as de_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-be")))]
+    pub const de_BE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    pub use super::de_BE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-be"))]
+    pub const de_BE_euro: super::Locale = super::Locale::de_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-be")))]
+    pub use super::POSIX as de_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-be")))]
+    pub const de_BE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-ch"))]
+    pub use super::de_CH;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-ch"))]
+    pub const de_CH: super::Locale = super::Locale::de_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-ch")))]
+    pub use super::POSIX as de_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-ch")))]
+    pub const de_CH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    pub use super::de_DE;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    pub const de_DE: super::Locale = super::Locale::de_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-de")))]
+    pub use super::POSIX as de_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-de")))]
+    pub const de_DE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    pub use super::de_DE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-de"))]
+    pub const de_DE_euro: super::Locale = super::Locale::de_DE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-de")))]
+    pub use super::POSIX as de_DE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-de")))]
+    pub const de_DE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-it"))]
+    pub use super::de_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-it"))]
+    pub const de_IT: super::Locale = super::Locale::de_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-it")))]
+    pub use super::POSIX as de_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-it")))]
+    pub const de_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-li"))]
+    pub use super::de_LI;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-li"))]
+    pub const de_LI: super::Locale = super::Locale::de_LI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-li")))]
+    pub use super::POSIX as de_LI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-li")))]
+    pub const de_LI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    pub use super::de_LU;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    pub const de_LU: super::Locale = super::Locale::de_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-lu")))]
+    pub use super::POSIX as de_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-lu")))]
+    pub const de_LU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    pub use super::de_LU_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-de", feature = "region-lu"))]
+    pub const de_LU_euro: super::Locale = super::Locale::de_LU_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-lu")))]
+    pub use super::POSIX as de_LU_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-de", feature = "region-lu")))]
+    pub const de_LU_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-doi", feature = "region-in"))]
+    pub use super::doi_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-doi", feature = "region-in"))]
+    pub const doi_IN: super::Locale = super::Locale::doi_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-doi", feature = "region-in")))]
+    pub use super::POSIX as doi_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-doi", feature = "region-in")))]
+    pub const doi_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de"))]
+    pub use super::dsb_DE;
+    #[cfg(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de"))]
+    pub const dsb_DE: super::Locale = super::Locale::dsb_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de")))]
+    pub use super::POSIX as dsb_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dsb", feature = "region-de")))]
+    pub const dsb_DE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv"))]
+    pub use super::dv_MV;
+    #[cfg(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv"))]
+    pub const dv_MV: super::Locale = super::Locale::dv_MV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv")))]
+    pub use super::POSIX as dv_MV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dv", feature = "region-mv")))]
+    pub const dv_MV: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt"))]
+    pub use super::dz_BT;
+    #[cfg(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt"))]
+    pub const dz_BT: super::Locale = super::Locale::dz_BT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt")))]
+    pub use super::POSIX as dz_BT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-dz", feature = "region-bt")))]
+    pub const dz_BT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-cy"))]
+    pub use super::el_CY;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-cy"))]
+    pub const el_CY: super::Locale = super::Locale::el_CY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-cy")))]
+    pub use super::POSIX as el_CY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-cy")))]
+    pub const el_CY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    pub use super::el_GR;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    pub const el_GR: super::Locale = super::Locale::el_GR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-gr")))]
+    pub use super::POSIX as el_GR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-gr")))]
+    pub const el_GR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    pub use super::el_GR_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-el", feature = "region-gr"))]
+    pub const el_GR_euro: super::Locale = super::Locale::el_GR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-gr")))]
+    pub use super::POSIX as el_GR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-el", feature = "region-gr")))]
+    pub const el_GR_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ag"))]
+    pub use super::en_AG;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ag"))]
+    pub const en_AG: super::Locale = super::Locale::en_AG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ag")))]
+    pub use super::POSIX as en_AG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ag")))]
+    pub const en_AG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-au"))]
+    pub use super::en_AU;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-au"))]
+    pub const en_AU: super::Locale = super::Locale::en_AU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-au")))]
+    pub use super::POSIX as en_AU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-au")))]
+    pub const en_AU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-bw"))]
+    pub use super::en_BW;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-bw"))]
+    pub const en_BW: super::Locale = super::Locale::en_BW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-bw")))]
+    pub use super::POSIX as en_BW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-bw")))]
+    pub const en_BW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ca"))]
+    pub use super::en_CA;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ca"))]
+    pub const en_CA: super::Locale = super::Locale::en_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ca")))]
+    pub use super::POSIX as en_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ca")))]
+    pub const en_CA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-dk"))]
+    pub use super::en_DK;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-dk"))]
+    pub const en_DK: super::Locale = super::Locale::en_DK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-dk")))]
+    pub use super::POSIX as en_DK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-dk")))]
+    pub const en_DK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-gb"))]
+    pub use super::en_GB;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-gb"))]
+    pub const en_GB: super::Locale = super::Locale::en_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-gb")))]
+    pub use super::POSIX as en_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-gb")))]
+    pub const en_GB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-hk"))]
+    pub use super::en_HK;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-hk"))]
+    pub const en_HK: super::Locale = super::Locale::en_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-hk")))]
+    pub use super::POSIX as en_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-hk")))]
+    pub const en_HK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    pub use super::en_IE;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    pub const en_IE: super::Locale = super::Locale::en_IE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ie")))]
+    pub use super::POSIX as en_IE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ie")))]
+    pub const en_IE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    pub use super::en_IE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ie"))]
+    pub const en_IE_euro: super::Locale = super::Locale::en_IE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ie")))]
+    pub use super::POSIX as en_IE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ie")))]
+    pub const en_IE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-il"))]
+    pub use super::en_IL;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-il"))]
+    pub const en_IL: super::Locale = super::Locale::en_IL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-il")))]
+    pub use super::POSIX as en_IL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-il")))]
+    pub const en_IL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-in"))]
+    pub use super::en_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-in"))]
+    pub const en_IN: super::Locale = super::Locale::en_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-in")))]
+    pub use super::POSIX as en_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-in")))]
+    pub const en_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ng"))]
+    pub use super::en_NG;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ng"))]
+    pub const en_NG: super::Locale = super::Locale::en_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ng")))]
+    pub use super::POSIX as en_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ng")))]
+    pub const en_NG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-nz"))]
+    pub use super::en_NZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-nz"))]
+    pub const en_NZ: super::Locale = super::Locale::en_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-nz")))]
+    pub use super::POSIX as en_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-nz")))]
+    pub const en_NZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ph"))]
+    pub use super::en_PH;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-ph"))]
+    pub const en_PH: super::Locale = super::Locale::en_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ph")))]
+    pub use super::POSIX as en_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-ph")))]
+    pub const en_PH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sc"))]
+    pub use super::en_SC;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sc"))]
+    pub const en_SC: super::Locale = super::Locale::en_SC;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-sc")))]
+    pub use super::POSIX as en_SC;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-sc")))]
+    pub const en_SC: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sg"))]
+    pub use super::en_SG;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-sg"))]
+    pub const en_SG: super::Locale = super::Locale::en_SG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-sg")))]
+    pub use super::POSIX as en_SG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-sg")))]
+    pub const en_SG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us"))]
+    pub use super::en_US;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us"))]
+    pub const en_US: super::Locale = super::Locale::en_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us")))]
+    pub use super::POSIX as en_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "minimal", feature = "region-us")))]
+    pub const en_US: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-za"))]
+    pub use super::en_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-za"))]
+    pub const en_ZA: super::Locale = super::Locale::en_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-za")))]
+    pub use super::POSIX as en_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-za")))]
+    pub const en_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zm"))]
+    pub use super::en_ZM;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zm"))]
+    pub const en_ZM: super::Locale = super::Locale::en_ZM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-zm")))]
+    pub use super::POSIX as en_ZM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-zm")))]
+    pub const en_ZM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zw"))]
+    pub use super::en_ZW;
+    #[cfg(any(feature = "all-locales", feature = "lang-en", feature = "region-zw"))]
+    pub const en_ZW: super::Locale = super::Locale::en_ZW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-zw")))]
+    pub use super::POSIX as en_ZW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-en", feature = "region-zw")))]
+    pub const en_ZW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-eo"))]
+    pub use super::eo;
+    #[cfg(any(feature = "all-locales", feature = "lang-eo"))]
+    pub const eo: super::Locale = super::Locale::eo;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eo")))]
+    pub use super::POSIX as eo;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eo")))]
+    pub const eo: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ar"))]
+    pub use super::es_AR;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ar"))]
+    pub const es_AR: super::Locale = super::Locale::es_AR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ar")))]
+    pub use super::POSIX as es_AR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ar")))]
+    pub const es_AR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-bo"))]
+    pub use super::es_BO;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-bo"))]
+    pub const es_BO: super::Locale = super::Locale::es_BO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-bo")))]
+    pub use super::POSIX as es_BO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-bo")))]
+    pub const es_BO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cl"))]
+    pub use super::es_CL;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cl"))]
+    pub const es_CL: super::Locale = super::Locale::es_CL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cl")))]
+    pub use super::POSIX as es_CL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cl")))]
+    pub const es_CL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-co"))]
+    pub use super::es_CO;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-co"))]
+    pub const es_CO: super::Locale = super::Locale::es_CO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-co")))]
+    pub use super::POSIX as es_CO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-co")))]
+    pub const es_CO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cr"))]
+    pub use super::es_CR;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cr"))]
+    pub const es_CR: super::Locale = super::Locale::es_CR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cr")))]
+    pub use super::POSIX as es_CR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cr")))]
+    pub const es_CR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cu"))]
+    pub use super::es_CU;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-cu"))]
+    pub const es_CU: super::Locale = super::Locale::es_CU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cu")))]
+    pub use super::POSIX as es_CU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-cu")))]
+    pub const es_CU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-do"))]
+    pub use super::es_DO;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-do"))]
+    pub const es_DO: super::Locale = super::Locale::es_DO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-do")))]
+    pub use super::POSIX as es_DO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-do")))]
+    pub const es_DO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ec"))]
+    pub use super::es_EC;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ec"))]
+    pub const es_EC: super::Locale = super::Locale::es_EC;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ec")))]
+    pub use super::POSIX as es_EC;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ec")))]
+    pub const es_EC: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    pub use super::es_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    pub const es_ES: super::Locale = super::Locale::es_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-es")))]
+    pub use super::POSIX as es_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-es")))]
+    pub const es_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    pub use super::es_ES_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-es"))]
+    pub const es_ES_euro: super::Locale = super::Locale::es_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-es")))]
+    pub use super::POSIX as es_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-es")))]
+    pub const es_ES_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-gt"))]
+    pub use super::es_GT;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-gt"))]
+    pub const es_GT: super::Locale = super::Locale::es_GT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-gt")))]
+    pub use super::POSIX as es_GT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-gt")))]
+    pub const es_GT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-hn"))]
+    pub use super::es_HN;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-hn"))]
+    pub const es_HN: super::Locale = super::Locale::es_HN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-hn")))]
+    pub use super::POSIX as es_HN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-hn")))]
+    pub const es_HN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-mx"))]
+    pub use super::es_MX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-mx"))]
+    pub const es_MX: super::Locale = super::Locale::es_MX;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-mx")))]
+    pub use super::POSIX as es_MX;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-mx")))]
+    pub const es_MX: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ni"))]
+    pub use super::es_NI;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ni"))]
+    pub const es_NI: super::Locale = super::Locale::es_NI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ni")))]
+    pub use super::POSIX as es_NI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ni")))]
+    pub const es_NI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pa"))]
+    pub use super::es_PA;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pa"))]
+    pub const es_PA: super::Locale = super::Locale::es_PA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pa")))]
+    pub use super::POSIX as es_PA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pa")))]
+    pub const es_PA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pe"))]
+    pub use super::es_PE;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pe"))]
+    pub const es_PE: super::Locale = super::Locale::es_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pe")))]
+    pub use super::POSIX as es_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pe")))]
+    pub const es_PE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pr"))]
+    pub use super::es_PR;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-pr"))]
+    pub const es_PR: super::Locale = super::Locale::es_PR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pr")))]
+    pub use super::POSIX as es_PR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-pr")))]
+    pub const es_PR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-py"))]
+    pub use super::es_PY;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-py"))]
+    pub const es_PY: super::Locale = super::Locale::es_PY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-py")))]
+    pub use super::POSIX as es_PY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-py")))]
+    pub const es_PY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-sv"))]
+    pub use super::es_SV;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-sv"))]
+    pub const es_SV: super::Locale = super::Locale::es_SV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-sv")))]
+    pub use super::POSIX as es_SV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-sv")))]
+    pub const es_SV: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-us"))]
+    pub use super::es_US;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-us"))]
+    pub const es_US: super::Locale = super::Locale::es_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-us")))]
+    pub use super::POSIX as es_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-us")))]
+    pub const es_US: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-uy"))]
+    pub use super::es_UY;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-uy"))]
+    pub const es_UY: super::Locale = super::Locale::es_UY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-uy")))]
+    pub use super::POSIX as es_UY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-uy")))]
+    pub const es_UY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ve"))]
+    pub use super::es_VE;
+    #[cfg(any(feature = "all-locales", feature = "lang-es", feature = "region-ve"))]
+    pub const es_VE: super::Locale = super::Locale::es_VE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ve")))]
+    pub use super::POSIX as es_VE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-es", feature = "region-ve")))]
+    pub const es_VE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-et", feature = "region-ee"))]
+    pub use super::et_EE;
+    #[cfg(any(feature = "all-locales", feature = "lang-et", feature = "region-ee"))]
+    pub const et_EE: super::Locale = super::Locale::et_EE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-et", feature = "region-ee")))]
+    pub use super::POSIX as et_EE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-et", feature = "region-ee")))]
+    pub const et_EE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    pub use super::eu_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    pub const eu_ES: super::Locale = super::Locale::eu_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eu", feature = "region-es")))]
+    pub use super::POSIX as eu_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eu", feature = "region-es")))]
+    pub const eu_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    pub use super::eu_ES_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-eu", feature = "region-es"))]
+    pub const eu_ES_euro: super::Locale = super::Locale::eu_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eu", feature = "region-es")))]
+    pub use super::POSIX as eu_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-eu", feature = "region-es")))]
+    pub const eu_ES_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir"))]
+    pub use super::fa_IR;
+    #[cfg(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir"))]
+    pub const fa_IR: super::Locale = super::Locale::fa_IR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir")))]
+    pub use super::POSIX as fa_IR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fa", feature = "region-ir")))]
+    pub const fa_IR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn"))]
+    pub use super::ff_SN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn"))]
+    pub const ff_SN: super::Locale = super::Locale::ff_SN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn")))]
+    pub use super::POSIX as ff_SN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ff", feature = "region-sn")))]
+    pub const ff_SN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    pub use super::fi_FI;
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    pub const fi_FI: super::Locale = super::Locale::fi_FI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi")))]
+    pub use super::POSIX as fi_FI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi")))]
+    pub const fi_FI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    pub use super::fi_FI_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi"))]
+    pub const fi_FI_euro: super::Locale = super::Locale::fi_FI_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi")))]
+    pub use super::POSIX as fi_FI_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fi", feature = "region-fi")))]
+    pub const fi_FI_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph"))]
+    pub use super::fil_PH;
+    #[cfg(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph"))]
+    pub const fil_PH: super::Locale = super::Locale::fil_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph")))]
+    pub use super::POSIX as fil_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fil", feature = "region-ph")))]
+    pub const fil_PH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo"))]
+    pub use super::fo_FO;
+    #[cfg(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo"))]
+    pub const fo_FO: super::Locale = super::Locale::fo_FO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo")))]
+    pub use super::POSIX as fo_FO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fo", feature = "region-fo")))]
+    pub const fo_FO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    pub use super::fr_BE;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    pub const fr_BE: super::Locale = super::Locale::fr_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-be")))]
+    pub use super::POSIX as fr_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-be")))]
+    pub const fr_BE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    pub use super::fr_BE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
+    pub const fr_BE_euro: super::Locale = super::Locale::fr_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-be")))]
+    pub use super::POSIX as fr_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-be")))]
+    pub const fr_BE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca"))]
+    pub use super::fr_CA;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca"))]
+    pub const fr_CA: super::Locale = super::Locale::fr_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca")))]
+    pub use super::POSIX as fr_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-ca")))]
+    pub const fr_CA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch"))]
+    pub use super::fr_CH;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch"))]
+    pub const fr_CH: super::Locale = super::Locale::fr_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch")))]
+    pub use super::POSIX as fr_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-ch")))]
+    pub const fr_CH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    pub use super::fr_FR;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    pub const fr_FR: super::Locale = super::Locale::fr_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr")))]
+    pub use super::POSIX as fr_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr")))]
+    pub const fr_FR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    pub use super::fr_FR_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr"))]
+    pub const fr_FR_euro: super::Locale = super::Locale::fr_FR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr")))]
+    pub use super::POSIX as fr_FR_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-fr")))]
+    pub const fr_FR_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    pub use super::fr_LU;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    pub const fr_LU: super::Locale = super::Locale::fr_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu")))]
+    pub use super::POSIX as fr_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu")))]
+    pub const fr_LU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    pub use super::fr_LU_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu"))]
+    pub const fr_LU_euro: super::Locale = super::Locale::fr_LU_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu")))]
+    pub use super::POSIX as fr_LU_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fr", feature = "region-lu")))]
+    pub const fr_LU_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fur", feature = "region-it"))]
+    pub use super::fur_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-fur", feature = "region-it"))]
+    pub const fur_IT: super::Locale = super::Locale::fur_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fur", feature = "region-it")))]
+    pub use super::POSIX as fur_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fur", feature = "region-it")))]
+    pub const fur_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-de"))]
+    pub use super::fy_DE;
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-de"))]
+    pub const fy_DE: super::Locale = super::Locale::fy_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fy", feature = "region-de")))]
+    pub use super::POSIX as fy_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fy", feature = "region-de")))]
+    pub const fy_DE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl"))]
+    pub use super::fy_NL;
+    #[cfg(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl"))]
+    pub const fy_NL: super::Locale = super::Locale::fy_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl")))]
+    pub use super::POSIX as fy_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-fy", feature = "region-nl")))]
+    pub const fy_NL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    pub use super::ga_IE;
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    pub const ga_IE: super::Locale = super::Locale::ga_IE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie")))]
+    pub use super::POSIX as ga_IE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie")))]
+    pub const ga_IE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    pub use super::ga_IE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie"))]
+    pub const ga_IE_euro: super::Locale = super::Locale::ga_IE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie")))]
+    pub use super::POSIX as ga_IE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ga", feature = "region-ie")))]
+    pub const ga_IE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb"))]
+    pub use super::gd_GB;
+    #[cfg(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb"))]
+    pub const gd_GB: super::Locale = super::Locale::gd_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb")))]
+    pub use super::POSIX as gd_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gd", feature = "region-gb")))]
+    pub const gd_GB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    pub use super::gez_ER;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    pub const gez_ER: super::Locale = super::Locale::gez_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-er")))]
+    pub use super::POSIX as gez_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-er")))]
+    pub const gez_ER: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    pub use super::gez_ER_abegede;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-er"))]
+    pub const gez_ER_abegede: super::Locale = super::Locale::gez_ER_abegede;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-er")))]
+    pub use super::POSIX as gez_ER_abegede;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-er")))]
+    pub const gez_ER_abegede: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    pub use super::gez_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    pub const gez_ET: super::Locale = super::Locale::gez_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-et")))]
+    pub use super::POSIX as gez_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-et")))]
+    pub const gez_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    pub use super::gez_ET_abegede;
+    #[cfg(any(feature = "all-locales", feature = "lang-gez", feature = "region-et"))]
+    pub const gez_ET_abegede: super::Locale = super::Locale::gez_ET_abegede;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-et")))]
+    pub use super::POSIX as gez_ET_abegede;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gez", feature = "region-et")))]
+    pub const gez_ET_abegede: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    pub use super::gl_ES;
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    pub const gl_ES: super::Locale = super::Locale::gl_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gl", feature = "region-es")))]
+    pub use super::POSIX as gl_ES;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gl", feature = "region-es")))]
+    pub const gl_ES: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    pub use super::gl_ES_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-gl", feature = "region-es"))]
+    pub const gl_ES_euro: super::Locale = super::Locale::gl_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gl", feature = "region-es")))]
+    pub use super::POSIX as gl_ES_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gl", feature = "region-es")))]
+    pub const gl_ES_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gu", feature = "region-in"))]
+    pub use super::gu_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-gu", feature = "region-in"))]
+    pub const gu_IN: super::Locale = super::Locale::gu_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gu", feature = "region-in")))]
+    pub use super::POSIX as gu_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gu", feature = "region-in")))]
+    pub const gu_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb"))]
+    pub use super::gv_GB;
+    #[cfg(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb"))]
+    pub const gv_GB: super::Locale = super::Locale::gv_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb")))]
+    pub use super::POSIX as gv_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-gv", feature = "region-gb")))]
+    pub const gv_GB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng"))]
+    pub use super::ha_NG;
+    #[cfg(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng"))]
+    pub const ha_NG: super::Locale = super::Locale::ha_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng")))]
+    pub use super::POSIX as ha_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ha", feature = "region-ng")))]
+    pub const ha_NG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw"))]
+    pub use super::hak_TW;
+    #[cfg(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw"))]
+    pub const hak_TW: super::Locale = super::Locale::hak_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw")))]
+    pub use super::POSIX as hak_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hak", feature = "region-tw")))]
+    pub const hak_TW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-he", feature = "region-il"))]
+    pub use super::he_IL;
+    #[cfg(any(feature = "all-locales", feature = "lang-he", feature = "region-il"))]
+    pub const he_IL: super::Locale = super::Locale::he_IL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-he", feature = "region-il")))]
+    pub use super::POSIX as he_IL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-he", feature = "region-il")))]
+    pub const he_IL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hi", feature = "region-in"))]
+    pub use super::hi_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-hi", feature = "region-in"))]
+    pub const hi_IN: super::Locale = super::Locale::hi_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hi", feature = "region-in")))]
+    pub use super::POSIX as hi_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hi", feature = "region-in")))]
+    pub const hi_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj"))]
+    pub use super::hif_FJ;
+    #[cfg(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj"))]
+    pub const hif_FJ: super::Locale = super::Locale::hif_FJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj")))]
+    pub use super::POSIX as hif_FJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hif", feature = "region-fj")))]
+    pub const hif_FJ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hne", feature = "region-in"))]
+    pub use super::hne_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-hne", feature = "region-in"))]
+    pub const hne_IN: super::Locale = super::Locale::hne_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hne", feature = "region-in")))]
+    pub use super::POSIX as hne_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hne", feature = "region-in")))]
+    pub const hne_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr"))]
+    pub use super::hr_HR;
+    #[cfg(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr"))]
+    pub const hr_HR: super::Locale = super::Locale::hr_HR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr")))]
+    pub use super::POSIX as hr_HR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hr", feature = "region-hr")))]
+    pub const hr_HR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de"))]
+    pub use super::hsb_DE;
+    #[cfg(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de"))]
+    pub const hsb_DE: super::Locale = super::Locale::hsb_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de")))]
+    pub use super::POSIX as hsb_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hsb", feature = "region-de")))]
+    pub const hsb_DE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht"))]
+    pub use super::ht_HT;
+    #[cfg(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht"))]
+    pub const ht_HT: super::Locale = super::Locale::ht_HT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht")))]
+    pub use super::POSIX as ht_HT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ht", feature = "region-ht")))]
+    pub const ht_HT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu"))]
+    pub use super::hu_HU;
+    #[cfg(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu"))]
+    pub const hu_HU: super::Locale = super::Locale::hu_HU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu")))]
+    pub use super::POSIX as hu_HU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hu", feature = "region-hu")))]
+    pub const hu_HU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-hy", feature = "region-am"))]
+    pub use super::hy_AM;
+    #[cfg(any(feature = "all-locales", feature = "lang-hy", feature = "region-am"))]
+    pub const hy_AM: super::Locale = super::Locale::hy_AM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hy", feature = "region-am")))]
+    pub use super::POSIX as hy_AM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-hy", feature = "region-am")))]
+    pub const hy_AM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr"))]
+    pub use super::ia_FR;
+    #[cfg(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr"))]
+    pub const ia_FR: super::Locale = super::Locale::ia_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr")))]
+    pub use super::POSIX as ia_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ia", feature = "region-fr")))]
+    pub const ia_FR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-id", feature = "region-id"))]
+    pub use super::id_ID;
+    #[cfg(any(feature = "all-locales", feature = "lang-id", feature = "region-id"))]
+    pub const id_ID: super::Locale = super::Locale::id_ID;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-id", feature = "region-id")))]
+    pub use super::POSIX as id_ID;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-id", feature = "region-id")))]
+    pub const id_ID: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng"))]
+    pub use super::ig_NG;
+    #[cfg(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng"))]
+    pub const ig_NG: super::Locale = super::Locale::ig_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng")))]
+    pub use super::POSIX as ig_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ig", feature = "region-ng")))]
+    pub const ig_NG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca"))]
+    pub use super::ik_CA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca"))]
+    pub const ik_CA: super::Locale = super::Locale::ik_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca")))]
+    pub use super::POSIX as ik_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ik", feature = "region-ca")))]
+    pub const ik_CA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-is", feature = "region-is"))]
+    pub use super::is_IS;
+    #[cfg(any(feature = "all-locales", feature = "lang-is", feature = "region-is"))]
+    pub const is_IS: super::Locale = super::Locale::is_IS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-is", feature = "region-is")))]
+    pub use super::POSIX as is_IS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-is", feature = "region-is")))]
+    pub const is_IS: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-ch"))]
+    pub use super::it_CH;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-ch"))]
+    pub const it_CH: super::Locale = super::Locale::it_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-ch")))]
+    pub use super::POSIX as it_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-ch")))]
+    pub const it_CH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    pub use super::it_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    pub const it_IT: super::Locale = super::Locale::it_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-it")))]
+    pub use super::POSIX as it_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-it")))]
+    pub const it_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    pub use super::it_IT_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-it", feature = "region-it"))]
+    pub const it_IT_euro: super::Locale = super::Locale::it_IT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-it")))]
+    pub use super::POSIX as it_IT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-it", feature = "region-it")))]
+    pub const it_IT_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca"))]
+    pub use super::iu_CA;
+    #[cfg(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca"))]
+    pub const iu_CA: super::Locale = super::Locale::iu_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca")))]
+    pub use super::POSIX as iu_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-iu", feature = "region-ca")))]
+    pub const iu_CA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp"))]
+    pub use super::ja_JP;
+    #[cfg(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp"))]
+    pub const ja_JP: super::Locale = super::Locale::ja_JP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp")))]
+    pub use super::POSIX as ja_JP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ja", feature = "region-jp")))]
+    pub const ja_JP: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge"))]
+    pub use super::ka_GE;
+    #[cfg(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge"))]
+    pub const ka_GE: super::Locale = super::Locale::ka_GE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge")))]
+    pub use super::POSIX as ka_GE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ka", feature = "region-ge")))]
+    pub const ka_GE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz"))]
+    pub use super::kab_DZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz"))]
+    pub const kab_DZ: super::Locale = super::Locale::kab_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz")))]
+    pub use super::POSIX as kab_DZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kab", feature = "region-dz")))]
+    pub const kab_DZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz"))]
+    pub use super::kk_KZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz"))]
+    pub const kk_KZ: super::Locale = super::Locale::kk_KZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz")))]
+    pub use super::POSIX as kk_KZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kk", feature = "region-kz")))]
+    pub const kk_KZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl"))]
+    pub use super::kl_GL;
+    #[cfg(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl"))]
+    pub const kl_GL: super::Locale = super::Locale::kl_GL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl")))]
+    pub use super::POSIX as kl_GL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kl", feature = "region-gl")))]
+    pub const kl_GL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-km", feature = "region-kh"))]
+    pub use super::km_KH;
+    #[cfg(any(feature = "all-locales", feature = "lang-km", feature = "region-kh"))]
+    pub const km_KH: super::Locale = super::Locale::km_KH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-km", feature = "region-kh")))]
+    pub use super::POSIX as km_KH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-km", feature = "region-kh")))]
+    pub const km_KH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kn", feature = "region-in"))]
+    pub use super::kn_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-kn", feature = "region-in"))]
+    pub const kn_IN: super::Locale = super::Locale::kn_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kn", feature = "region-in")))]
+    pub use super::POSIX as kn_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kn", feature = "region-in")))]
+    pub const kn_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr"))]
+    pub use super::ko_KR;
+    #[cfg(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr"))]
+    pub const ko_KR: super::Locale = super::Locale::ko_KR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr")))]
+    pub use super::POSIX as ko_KR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ko", feature = "region-kr")))]
+    pub const ko_KR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kok", feature = "region-in"))]
+    pub use super::kok_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-kok", feature = "region-in"))]
+    pub const kok_IN: super::Locale = super::Locale::kok_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kok", feature = "region-in")))]
+    pub use super::POSIX as kok_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kok", feature = "region-in")))]
+    pub const kok_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    pub use super::ks_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    pub const ks_IN: super::Locale = super::Locale::ks_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ks", feature = "region-in")))]
+    pub use super::POSIX as ks_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ks", feature = "region-in")))]
+    pub const ks_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    pub use super::ks_IN_devanagari;
+    #[cfg(any(feature = "all-locales", feature = "lang-ks", feature = "region-in"))]
+    pub const ks_IN_devanagari: super::Locale = super::Locale::ks_IN_devanagari;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ks", feature = "region-in")))]
+    pub use super::POSIX as ks_IN_devanagari;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ks", feature = "region-in")))]
+    pub const ks_IN_devanagari: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr"))]
+    pub use super::ku_TR;
+    #[cfg(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr"))]
+    pub const ku_TR: super::Locale = super::Locale::ku_TR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr")))]
+    pub use super::POSIX as ku_TR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ku", feature = "region-tr")))]
+    pub const ku_TR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb"))]
+    pub use super::kw_GB;
+    #[cfg(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb"))]
+    pub const kw_GB: super::Locale = super::Locale::kw_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb")))]
+    pub use super::POSIX as kw_GB;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-kw", feature = "region-gb")))]
+    pub const kw_GB: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg"))]
+    pub use super::ky_KG;
+    #[cfg(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg"))]
+    pub const ky_KG: super::Locale = super::Locale::ky_KG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg")))]
+    pub use super::POSIX as ky_KG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ky", feature = "region-kg")))]
+    pub const ky_KG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu"))]
+    pub use super::lb_LU;
+    #[cfg(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu"))]
+    pub const lb_LU: super::Locale = super::Locale::lb_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu")))]
+    pub use super::POSIX as lb_LU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lb", feature = "region-lu")))]
+    pub const lb_LU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug"))]
+    pub use super::lg_UG;
+    #[cfg(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug"))]
+    pub const lg_UG: super::Locale = super::Locale::lg_UG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug")))]
+    pub use super::POSIX as lg_UG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lg", feature = "region-ug")))]
+    pub const lg_UG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-be"))]
+    pub use super::li_BE;
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-be"))]
+    pub const li_BE: super::Locale = super::Locale::li_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-li", feature = "region-be")))]
+    pub use super::POSIX as li_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-li", feature = "region-be")))]
+    pub const li_BE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-nl"))]
+    pub use super::li_NL;
+    #[cfg(any(feature = "all-locales", feature = "lang-li", feature = "region-nl"))]
+    pub const li_NL: super::Locale = super::Locale::li_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-li", feature = "region-nl")))]
+    pub use super::POSIX as li_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-li", feature = "region-nl")))]
+    pub const li_NL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lij", feature = "region-it"))]
+    pub use super::lij_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-lij", feature = "region-it"))]
+    pub const lij_IT: super::Locale = super::Locale::lij_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lij", feature = "region-it")))]
+    pub use super::POSIX as lij_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lij", feature = "region-it")))]
+    pub const lij_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd"))]
+    pub use super::ln_CD;
+    #[cfg(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd"))]
+    pub const ln_CD: super::Locale = super::Locale::ln_CD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd")))]
+    pub use super::POSIX as ln_CD;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ln", feature = "region-cd")))]
+    pub const ln_CD: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lo", feature = "region-la"))]
+    pub use super::lo_LA;
+    #[cfg(any(feature = "all-locales", feature = "lang-lo", feature = "region-la"))]
+    pub const lo_LA: super::Locale = super::Locale::lo_LA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lo", feature = "region-la")))]
+    pub use super::POSIX as lo_LA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lo", feature = "region-la")))]
+    pub const lo_LA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt"))]
+    pub use super::lt_LT;
+    #[cfg(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt"))]
+    pub const lt_LT: super::Locale = super::Locale::lt_LT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt")))]
+    pub use super::POSIX as lt_LT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lt", feature = "region-lt")))]
+    pub const lt_LT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv"))]
+    pub use super::lv_LV;
+    #[cfg(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv"))]
+    pub const lv_LV: super::Locale = super::Locale::lv_LV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv")))]
+    pub use super::POSIX as lv_LV;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lv", feature = "region-lv")))]
+    pub const lv_LV: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw"))]
+    pub use super::lzh_TW;
+    #[cfg(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw"))]
+    pub const lzh_TW: super::Locale = super::Locale::lzh_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw")))]
+    pub use super::POSIX as lzh_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-lzh", feature = "region-tw")))]
+    pub const lzh_TW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mag", feature = "region-in"))]
+    pub use super::mag_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mag", feature = "region-in"))]
+    pub const mag_IN: super::Locale = super::Locale::mag_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mag", feature = "region-in")))]
+    pub use super::POSIX as mag_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mag", feature = "region-in")))]
+    pub const mag_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-in"))]
+    pub use super::mai_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-in"))]
+    pub const mai_IN: super::Locale = super::Locale::mai_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mai", feature = "region-in")))]
+    pub use super::POSIX as mai_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mai", feature = "region-in")))]
+    pub const mai_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-np"))]
+    pub use super::mai_NP;
+    #[cfg(any(feature = "all-locales", feature = "lang-mai", feature = "region-np"))]
+    pub const mai_NP: super::Locale = super::Locale::mai_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mai", feature = "region-np")))]
+    pub use super::POSIX as mai_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mai", feature = "region-np")))]
+    pub const mai_NP: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu"))]
+    pub use super::mfe_MU;
+    #[cfg(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu"))]
+    pub const mfe_MU: super::Locale = super::Locale::mfe_MU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu")))]
+    pub use super::POSIX as mfe_MU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mfe", feature = "region-mu")))]
+    pub const mfe_MU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg"))]
+    pub use super::mg_MG;
+    #[cfg(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg"))]
+    pub const mg_MG: super::Locale = super::Locale::mg_MG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg")))]
+    pub use super::POSIX as mg_MG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mg", feature = "region-mg")))]
+    pub const mg_MG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru"))]
+    pub use super::mhr_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru"))]
+    pub const mhr_RU: super::Locale = super::Locale::mhr_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru")))]
+    pub use super::POSIX as mhr_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mhr", feature = "region-ru")))]
+    pub const mhr_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz"))]
+    pub use super::mi_NZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz"))]
+    pub const mi_NZ: super::Locale = super::Locale::mi_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz")))]
+    pub use super::POSIX as mi_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mi", feature = "region-nz")))]
+    pub const mi_NZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni"))]
+    pub use super::miq_NI;
+    #[cfg(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni"))]
+    pub const miq_NI: super::Locale = super::Locale::miq_NI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni")))]
+    pub use super::POSIX as miq_NI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-miq", feature = "region-ni")))]
+    pub const miq_NI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in"))]
+    pub use super::mjw_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in"))]
+    pub const mjw_IN: super::Locale = super::Locale::mjw_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in")))]
+    pub use super::POSIX as mjw_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mjw", feature = "region-in")))]
+    pub const mjw_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk"))]
+    pub use super::mk_MK;
+    #[cfg(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk"))]
+    pub const mk_MK: super::Locale = super::Locale::mk_MK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk")))]
+    pub use super::POSIX as mk_MK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mk", feature = "region-mk")))]
+    pub const mk_MK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ml", feature = "region-in"))]
+    pub use super::ml_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ml", feature = "region-in"))]
+    pub const ml_IN: super::Locale = super::Locale::ml_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ml", feature = "region-in")))]
+    pub use super::POSIX as ml_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ml", feature = "region-in")))]
+    pub const ml_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn"))]
+    pub use super::mn_MN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn"))]
+    pub const mn_MN: super::Locale = super::Locale::mn_MN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn")))]
+    pub use super::POSIX as mn_MN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mn", feature = "region-mn")))]
+    pub const mn_MN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mni", feature = "region-in"))]
+    pub use super::mni_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mni", feature = "region-in"))]
+    pub const mni_IN: super::Locale = super::Locale::mni_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mni", feature = "region-in")))]
+    pub use super::POSIX as mni_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mni", feature = "region-in")))]
+    pub const mni_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm"))]
+    pub use super::mnw_MM;
+    #[cfg(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm"))]
+    pub const mnw_MM: super::Locale = super::Locale::mnw_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm")))]
+    pub use super::POSIX as mnw_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mnw", feature = "region-mm")))]
+    pub const mnw_MM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mr", feature = "region-in"))]
+    pub use super::mr_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-mr", feature = "region-in"))]
+    pub const mr_IN: super::Locale = super::Locale::mr_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mr", feature = "region-in")))]
+    pub use super::POSIX as mr_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mr", feature = "region-in")))]
+    pub const mr_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ms", feature = "region-my"))]
+    pub use super::ms_MY;
+    #[cfg(any(feature = "all-locales", feature = "lang-ms", feature = "region-my"))]
+    pub const ms_MY: super::Locale = super::Locale::ms_MY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ms", feature = "region-my")))]
+    pub use super::POSIX as ms_MY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ms", feature = "region-my")))]
+    pub const ms_MY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt"))]
+    pub use super::mt_MT;
+    #[cfg(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt"))]
+    pub const mt_MT: super::Locale = super::Locale::mt_MT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt")))]
+    pub use super::POSIX as mt_MT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-mt", feature = "region-mt")))]
+    pub const mt_MT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-my", feature = "region-mm"))]
+    pub use super::my_MM;
+    #[cfg(any(feature = "all-locales", feature = "lang-my", feature = "region-mm"))]
+    pub const my_MM: super::Locale = super::Locale::my_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-my", feature = "region-mm")))]
+    pub use super::POSIX as my_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-my", feature = "region-mm")))]
+    pub const my_MM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    pub use super::nan_TW;
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    pub const nan_TW: super::Locale = super::Locale::nan_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw")))]
+    pub use super::POSIX as nan_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw")))]
+    pub const nan_TW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    pub use super::nan_TW_latin;
+    #[cfg(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw"))]
+    pub const nan_TW_latin: super::Locale = super::Locale::nan_TW_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw")))]
+    pub use super::POSIX as nan_TW_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nan", feature = "region-tw")))]
+    pub const nan_TW_latin: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nb", feature = "region-no"))]
+    pub use super::nb_NO;
+    #[cfg(any(feature = "all-locales", feature = "lang-nb", feature = "region-no"))]
+    pub const nb_NO: super::Locale = super::Locale::nb_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nb", feature = "region-no")))]
+    pub use super::POSIX as nb_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nb", feature = "region-no")))]
+    pub const nb_NO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-de"))]
+    pub use super::nds_DE;
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-de"))]
+    pub const nds_DE: super::Locale = super::Locale::nds_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nds", feature = "region-de")))]
+    pub use super::POSIX as nds_DE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nds", feature = "region-de")))]
+    pub const nds_DE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl"))]
+    pub use super::nds_NL;
+    #[cfg(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl"))]
+    pub const nds_NL: super::Locale = super::Locale::nds_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl")))]
+    pub use super::POSIX as nds_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nds", feature = "region-nl")))]
+    pub const nds_NL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ne", feature = "region-np"))]
+    pub use super::ne_NP;
+    #[cfg(any(feature = "all-locales", feature = "lang-ne", feature = "region-np"))]
+    pub const ne_NP: super::Locale = super::Locale::ne_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ne", feature = "region-np")))]
+    pub use super::POSIX as ne_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ne", feature = "region-np")))]
+    pub const ne_NP: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx"))]
+    pub use super::nhn_MX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx"))]
+    pub const nhn_MX: super::Locale = super::Locale::nhn_MX;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx")))]
+    pub use super::POSIX as nhn_MX;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nhn", feature = "region-mx")))]
+    pub const nhn_MX: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu"))]
+    pub use super::niu_NU;
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu"))]
+    pub const niu_NU: super::Locale = super::Locale::niu_NU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu")))]
+    pub use super::POSIX as niu_NU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-niu", feature = "region-nu")))]
+    pub const niu_NU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz"))]
+    pub use super::niu_NZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz"))]
+    pub const niu_NZ: super::Locale = super::Locale::niu_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz")))]
+    pub use super::POSIX as niu_NZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-niu", feature = "region-nz")))]
+    pub const niu_NZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw"))]
+    pub use super::nl_AW;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw"))]
+    pub const nl_AW: super::Locale = super::Locale::nl_AW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw")))]
+    pub use super::POSIX as nl_AW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-aw")))]
+    pub const nl_AW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    pub use super::nl_BE;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    pub const nl_BE: super::Locale = super::Locale::nl_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-be")))]
+    pub use super::POSIX as nl_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-be")))]
+    pub const nl_BE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    pub use super::nl_BE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-be"))]
+    pub const nl_BE_euro: super::Locale = super::Locale::nl_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-be")))]
+    pub use super::POSIX as nl_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-be")))]
+    pub const nl_BE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    pub use super::nl_NL;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    pub const nl_NL: super::Locale = super::Locale::nl_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl")))]
+    pub use super::POSIX as nl_NL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl")))]
+    pub const nl_NL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    pub use super::nl_NL_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl"))]
+    pub const nl_NL_euro: super::Locale = super::Locale::nl_NL_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl")))]
+    pub use super::POSIX as nl_NL_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nl", feature = "region-nl")))]
+    pub const nl_NL_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nn", feature = "region-no"))]
+    pub use super::nn_NO;
+    #[cfg(any(feature = "all-locales", feature = "lang-nn", feature = "region-no"))]
+    pub const nn_NO: super::Locale = super::Locale::nn_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nn", feature = "region-no")))]
+    pub use super::POSIX as nn_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nn", feature = "region-no")))]
+    pub const nn_NO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nr", feature = "region-za"))]
+    pub use super::nr_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-nr", feature = "region-za"))]
+    pub const nr_ZA: super::Locale = super::Locale::nr_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nr", feature = "region-za")))]
+    pub use super::POSIX as nr_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nr", feature = "region-za")))]
+    pub const nr_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-nso", feature = "region-za"))]
+    pub use super::nso_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-nso", feature = "region-za"))]
+    pub const nso_ZA: super::Locale = super::Locale::nso_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nso", feature = "region-za")))]
+    pub use super::POSIX as nso_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-nso", feature = "region-za")))]
+    pub const nso_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr"))]
+    pub use super::oc_FR;
+    #[cfg(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr"))]
+    pub const oc_FR: super::Locale = super::Locale::oc_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr")))]
+    pub use super::POSIX as oc_FR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-oc", feature = "region-fr")))]
+    pub const oc_FR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-et"))]
+    pub use super::om_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-et"))]
+    pub const om_ET: super::Locale = super::Locale::om_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-om", feature = "region-et")))]
+    pub use super::POSIX as om_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-om", feature = "region-et")))]
+    pub const om_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-ke"))]
+    pub use super::om_KE;
+    #[cfg(any(feature = "all-locales", feature = "lang-om", feature = "region-ke"))]
+    pub const om_KE: super::Locale = super::Locale::om_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-om", feature = "region-ke")))]
+    pub use super::POSIX as om_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-om", feature = "region-ke")))]
+    pub const om_KE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-or", feature = "region-in"))]
+    pub use super::or_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-or", feature = "region-in"))]
+    pub const or_IN: super::Locale = super::Locale::or_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-or", feature = "region-in")))]
+    pub use super::POSIX as or_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-or", feature = "region-in")))]
+    pub const or_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-os", feature = "region-ru"))]
+    pub use super::os_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-os", feature = "region-ru"))]
+    pub const os_RU: super::Locale = super::Locale::os_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-os", feature = "region-ru")))]
+    pub use super::POSIX as os_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-os", feature = "region-ru")))]
+    pub const os_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-in"))]
+    pub use super::pa_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-in"))]
+    pub const pa_IN: super::Locale = super::Locale::pa_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pa", feature = "region-in")))]
+    pub use super::POSIX as pa_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pa", feature = "region-in")))]
+    pub const pa_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk"))]
+    pub use super::pa_PK;
+    #[cfg(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk"))]
+    pub const pa_PK: super::Locale = super::Locale::pa_PK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk")))]
+    pub use super::POSIX as pa_PK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pa", feature = "region-pk")))]
+    pub const pa_PK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw"))]
+    pub use super::pap_AW;
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw"))]
+    pub const pap_AW: super::Locale = super::Locale::pap_AW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw")))]
+    pub use super::POSIX as pap_AW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pap", feature = "region-aw")))]
+    pub const pap_AW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw"))]
+    pub use super::pap_CW;
+    #[cfg(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw"))]
+    pub const pap_CW: super::Locale = super::Locale::pap_CW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw")))]
+    pub use super::POSIX as pap_CW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pap", feature = "region-cw")))]
+    pub const pap_CW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl"))]
+    pub use super::pl_PL;
+    #[cfg(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl"))]
+    pub const pl_PL: super::Locale = super::Locale::pl_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl")))]
+    pub use super::POSIX as pl_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pl", feature = "region-pl")))]
+    pub const pl_PL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ps", feature = "region-af"))]
+    pub use super::ps_AF;
+    #[cfg(any(feature = "all-locales", feature = "lang-ps", feature = "region-af"))]
+    pub const ps_AF: super::Locale = super::Locale::ps_AF;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ps", feature = "region-af")))]
+    pub use super::POSIX as ps_AF;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ps", feature = "region-af")))]
+    pub const ps_AF: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-br"))]
+    pub use super::pt_BR;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-br"))]
+    pub const pt_BR: super::Locale = super::Locale::pt_BR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-br")))]
+    pub use super::POSIX as pt_BR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-br")))]
+    pub const pt_BR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pub use super::pt_PT;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pub const pt_PT: super::Locale = super::Locale::pt_PT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt")))]
+    pub use super::POSIX as pt_PT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt")))]
+    pub const pt_PT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pub use super::pt_PT_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt"))]
+    pub const pt_PT_euro: super::Locale = super::Locale::pt_PT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt")))]
+    pub use super::POSIX as pt_PT_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-pt", feature = "region-pt")))]
+    pub const pt_PT_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe"))]
+    pub use super::quz_PE;
+    #[cfg(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe"))]
+    pub const quz_PE: super::Locale = super::Locale::quz_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe")))]
+    pub use super::POSIX as quz_PE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-quz", feature = "region-pe")))]
+    pub const quz_PE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-raj", feature = "region-in"))]
+    pub use super::raj_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-raj", feature = "region-in"))]
+    pub const raj_IN: super::Locale = super::Locale::raj_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-raj", feature = "region-in")))]
+    pub use super::POSIX as raj_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-raj", feature = "region-in")))]
+    pub const raj_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro"))]
+    pub use super::ro_RO;
+    #[cfg(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro"))]
+    pub const ro_RO: super::Locale = super::Locale::ro_RO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro")))]
+    pub use super::POSIX as ro_RO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ro", feature = "region-ro")))]
+    pub const ro_RO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru"))]
+    pub use super::ru_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru"))]
+    pub const ru_RU: super::Locale = super::Locale::ru_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru")))]
+    pub use super::POSIX as ru_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ru", feature = "region-ru")))]
+    pub const ru_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua"))]
+    pub use super::ru_UA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua"))]
+    pub const ru_UA: super::Locale = super::Locale::ru_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua")))]
+    pub use super::POSIX as ru_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ru", feature = "region-ua")))]
+    pub const ru_UA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw"))]
+    pub use super::rw_RW;
+    #[cfg(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw"))]
+    pub const rw_RW: super::Locale = super::Locale::rw_RW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw")))]
+    pub use super::POSIX as rw_RW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-rw", feature = "region-rw")))]
+    pub const rw_RW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sa", feature = "region-in"))]
+    pub use super::sa_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-sa", feature = "region-in"))]
+    pub const sa_IN: super::Locale = super::Locale::sa_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sa", feature = "region-in")))]
+    pub use super::POSIX as sa_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sa", feature = "region-in")))]
+    pub const sa_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru"))]
+    pub use super::sah_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru"))]
+    pub const sah_RU: super::Locale = super::Locale::sah_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru")))]
+    pub use super::POSIX as sah_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sah", feature = "region-ru")))]
+    pub const sah_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sat", feature = "region-in"))]
+    pub use super::sat_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-sat", feature = "region-in"))]
+    pub const sat_IN: super::Locale = super::Locale::sat_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sat", feature = "region-in")))]
+    pub use super::POSIX as sat_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sat", feature = "region-in")))]
+    pub const sat_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sc", feature = "region-it"))]
+    pub use super::sc_IT;
+    #[cfg(any(feature = "all-locales", feature = "lang-sc", feature = "region-it"))]
+    pub const sc_IT: super::Locale = super::Locale::sc_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sc", feature = "region-it")))]
+    pub use super::POSIX as sc_IT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sc", feature = "region-it")))]
+    pub const sc_IT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    pub use super::sd_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    pub const sd_IN: super::Locale = super::Locale::sd_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sd", feature = "region-in")))]
+    pub use super::POSIX as sd_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sd", feature = "region-in")))]
+    pub const sd_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    pub use super::sd_IN_devanagari;
+    #[cfg(any(feature = "all-locales", feature = "lang-sd", feature = "region-in"))]
+    pub const sd_IN_devanagari: super::Locale = super::Locale::sd_IN_devanagari;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sd", feature = "region-in")))]
+    pub use super::POSIX as sd_IN_devanagari;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sd", feature = "region-in")))]
+    pub const sd_IN_devanagari: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-se", feature = "region-no"))]
+    pub use super::se_NO;
+    #[cfg(any(feature = "all-locales", feature = "lang-se", feature = "region-no"))]
+    pub const se_NO: super::Locale = super::Locale::se_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-se", feature = "region-no")))]
+    pub use super::POSIX as se_NO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-se", feature = "region-no")))]
+    pub const se_NO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt"))]
+    pub use super::sgs_LT;
+    #[cfg(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt"))]
+    pub const sgs_LT: super::Locale = super::Locale::sgs_LT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt")))]
+    pub use super::POSIX as sgs_LT;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sgs", feature = "region-lt")))]
+    pub const sgs_LT: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm"))]
+    pub use super::shn_MM;
+    #[cfg(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm"))]
+    pub const shn_MM: super::Locale = super::Locale::shn_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm")))]
+    pub use super::POSIX as shn_MM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-shn", feature = "region-mm")))]
+    pub const shn_MM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca"))]
+    pub use super::shs_CA;
+    #[cfg(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca"))]
+    pub const shs_CA: super::Locale = super::Locale::shs_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca")))]
+    pub use super::POSIX as shs_CA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-shs", feature = "region-ca")))]
+    pub const shs_CA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-si", feature = "region-lk"))]
+    pub use super::si_LK;
+    #[cfg(any(feature = "all-locales", feature = "lang-si", feature = "region-lk"))]
+    pub const si_LK: super::Locale = super::Locale::si_LK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-si", feature = "region-lk")))]
+    pub use super::POSIX as si_LK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-si", feature = "region-lk")))]
+    pub const si_LK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sid", feature = "region-et"))]
+    pub use super::sid_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-sid", feature = "region-et"))]
+    pub const sid_ET: super::Locale = super::Locale::sid_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sid", feature = "region-et")))]
+    pub use super::POSIX as sid_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sid", feature = "region-et")))]
+    pub const sid_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk"))]
+    pub use super::sk_SK;
+    #[cfg(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk"))]
+    pub const sk_SK: super::Locale = super::Locale::sk_SK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk")))]
+    pub use super::POSIX as sk_SK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sk", feature = "region-sk")))]
+    pub const sk_SK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sl", feature = "region-si"))]
+    pub use super::sl_SI;
+    #[cfg(any(feature = "all-locales", feature = "lang-sl", feature = "region-si"))]
+    pub const sl_SI: super::Locale = super::Locale::sl_SI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sl", feature = "region-si")))]
+    pub use super::POSIX as sl_SI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sl", feature = "region-si")))]
+    pub const sl_SI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws"))]
+    pub use super::sm_WS;
+    #[cfg(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws"))]
+    pub const sm_WS: super::Locale = super::Locale::sm_WS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws")))]
+    pub use super::POSIX as sm_WS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sm", feature = "region-ws")))]
+    pub const sm_WS: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-dj"))]
+    pub use super::so_DJ;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-dj"))]
+    pub const so_DJ: super::Locale = super::Locale::so_DJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-dj")))]
+    pub use super::POSIX as so_DJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-dj")))]
+    pub const so_DJ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-et"))]
+    pub use super::so_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-et"))]
+    pub const so_ET: super::Locale = super::Locale::so_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-et")))]
+    pub use super::POSIX as so_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-et")))]
+    pub const so_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-ke"))]
+    pub use super::so_KE;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-ke"))]
+    pub const so_KE: super::Locale = super::Locale::so_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-ke")))]
+    pub use super::POSIX as so_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-ke")))]
+    pub const so_KE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-so"))]
+    pub use super::so_SO;
+    #[cfg(any(feature = "all-locales", feature = "lang-so", feature = "region-so"))]
+    pub const so_SO: super::Locale = super::Locale::so_SO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-so")))]
+    pub use super::POSIX as so_SO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-so", feature = "region-so")))]
+    pub const so_SO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-al"))]
+    pub use super::sq_AL;
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-al"))]
+    pub const sq_AL: super::Locale = super::Locale::sq_AL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sq", feature = "region-al")))]
+    pub use super::POSIX as sq_AL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sq", feature = "region-al")))]
+    pub const sq_AL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk"))]
+    pub use super::sq_MK;
+    #[cfg(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk"))]
+    pub const sq_MK: super::Locale = super::Locale::sq_MK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk")))]
+    pub use super::POSIX as sq_MK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sq", feature = "region-mk")))]
+    pub const sq_MK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-me"))]
+    pub use super::sr_ME;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-me"))]
+    pub const sr_ME: super::Locale = super::Locale::sr_ME;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-me")))]
+    pub use super::POSIX as sr_ME;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-me")))]
+    pub const sr_ME: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    pub use super::sr_RS;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    pub const sr_RS: super::Locale = super::Locale::sr_RS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs")))]
+    pub use super::POSIX as sr_RS;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs")))]
+    pub const sr_RS: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    pub use super::sr_RS_latin;
+    #[cfg(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs"))]
+    pub const sr_RS_latin: super::Locale = super::Locale::sr_RS_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs")))]
+    pub use super::POSIX as sr_RS_latin;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sr", feature = "region-rs")))]
+    pub const sr_RS_latin: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ss", feature = "region-za"))]
+    pub use super::ss_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ss", feature = "region-za"))]
+    pub const ss_ZA: super::Locale = super::Locale::ss_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ss", feature = "region-za")))]
+    pub use super::POSIX as ss_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ss", feature = "region-za")))]
+    pub const ss_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-st", feature = "region-za"))]
+    pub use super::st_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-st", feature = "region-za"))]
+    pub const st_ZA: super::Locale = super::Locale::st_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-st", feature = "region-za")))]
+    pub use super::POSIX as st_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-st", feature = "region-za")))]
+    pub const st_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    pub use super::sv_FI;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    pub const sv_FI: super::Locale = super::Locale::sv_FI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi")))]
+    pub use super::POSIX as sv_FI;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi")))]
+    pub const sv_FI: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    pub use super::sv_FI_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi"))]
+    pub const sv_FI_euro: super::Locale = super::Locale::sv_FI_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi")))]
+    pub use super::POSIX as sv_FI_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-fi")))]
+    pub const sv_FI_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-se"))]
+    pub use super::sv_SE;
+    #[cfg(any(feature = "all-locales", feature = "lang-sv", feature = "region-se"))]
+    pub const sv_SE: super::Locale = super::Locale::sv_SE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-se")))]
+    pub use super::POSIX as sv_SE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sv", feature = "region-se")))]
+    pub const sv_SE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke"))]
+    pub use super::sw_KE;
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke"))]
+    pub const sw_KE: super::Locale = super::Locale::sw_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke")))]
+    pub use super::POSIX as sw_KE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sw", feature = "region-ke")))]
+    pub const sw_KE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz"))]
+    pub use super::sw_TZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz"))]
+    pub const sw_TZ: super::Locale = super::Locale::sw_TZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz")))]
+    pub use super::POSIX as sw_TZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-sw", feature = "region-tz")))]
+    pub const sw_TZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl"))]
+    pub use super::szl_PL;
+    #[cfg(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl"))]
+    pub const szl_PL: super::Locale = super::Locale::szl_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl")))]
+    pub use super::POSIX as szl_PL;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-szl", feature = "region-pl")))]
+    pub const szl_PL: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-in"))]
+    pub use super::ta_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-in"))]
+    pub const ta_IN: super::Locale = super::Locale::ta_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ta", feature = "region-in")))]
+    pub use super::POSIX as ta_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ta", feature = "region-in")))]
+    pub const ta_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk"))]
+    pub use super::ta_LK;
+    #[cfg(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk"))]
+    pub const ta_LK: super::Locale = super::Locale::ta_LK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk")))]
+    pub use super::POSIX as ta_LK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ta", feature = "region-lk")))]
+    pub const ta_LK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in"))]
+    pub use super::tcy_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in"))]
+    pub const tcy_IN: super::Locale = super::Locale::tcy_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in")))]
+    pub use super::POSIX as tcy_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tcy", feature = "region-in")))]
+    pub const tcy_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-te", feature = "region-in"))]
+    pub use super::te_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-te", feature = "region-in"))]
+    pub const te_IN: super::Locale = super::Locale::te_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-te", feature = "region-in")))]
+    pub use super::POSIX as te_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-te", feature = "region-in")))]
+    pub const te_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj"))]
+    pub use super::tg_TJ;
+    #[cfg(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj"))]
+    pub const tg_TJ: super::Locale = super::Locale::tg_TJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj")))]
+    pub use super::POSIX as tg_TJ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tg", feature = "region-tj")))]
+    pub const tg_TJ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-th", feature = "region-th"))]
+    pub use super::th_TH;
+    #[cfg(any(feature = "all-locales", feature = "lang-th", feature = "region-th"))]
+    pub const th_TH: super::Locale = super::Locale::th_TH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-th", feature = "region-th")))]
+    pub use super::POSIX as th_TH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-th", feature = "region-th")))]
+    pub const th_TH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-the", feature = "region-np"))]
+    pub use super::the_NP;
+    #[cfg(any(feature = "all-locales", feature = "lang-the", feature = "region-np"))]
+    pub const the_NP: super::Locale = super::Locale::the_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-the", feature = "region-np")))]
+    pub use super::POSIX as the_NP;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-the", feature = "region-np")))]
+    pub const the_NP: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-er"))]
+    pub use super::ti_ER;
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-er"))]
+    pub const ti_ER: super::Locale = super::Locale::ti_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ti", feature = "region-er")))]
+    pub use super::POSIX as ti_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ti", feature = "region-er")))]
+    pub const ti_ER: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-et"))]
+    pub use super::ti_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-ti", feature = "region-et"))]
+    pub const ti_ET: super::Locale = super::Locale::ti_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ti", feature = "region-et")))]
+    pub use super::POSIX as ti_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ti", feature = "region-et")))]
+    pub const ti_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tig", feature = "region-er"))]
+    pub use super::tig_ER;
+    #[cfg(any(feature = "all-locales", feature = "lang-tig", feature = "region-er"))]
+    pub const tig_ER: super::Locale = super::Locale::tig_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tig", feature = "region-er")))]
+    pub use super::POSIX as tig_ER;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tig", feature = "region-er")))]
+    pub const tig_ER: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm"))]
+    pub use super::tk_TM;
+    #[cfg(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm"))]
+    pub const tk_TM: super::Locale = super::Locale::tk_TM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm")))]
+    pub use super::POSIX as tk_TM;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tk", feature = "region-tm")))]
+    pub const tk_TM: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph"))]
+    pub use super::tl_PH;
+    #[cfg(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph"))]
+    pub const tl_PH: super::Locale = super::Locale::tl_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph")))]
+    pub use super::POSIX as tl_PH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tl", feature = "region-ph")))]
+    pub const tl_PH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tn", feature = "region-za"))]
+    pub use super::tn_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-tn", feature = "region-za"))]
+    pub const tn_ZA: super::Locale = super::Locale::tn_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tn", feature = "region-za")))]
+    pub use super::POSIX as tn_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tn", feature = "region-za")))]
+    pub const tn_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-to", feature = "region-to"))]
+    pub use super::to_TO;
+    #[cfg(any(feature = "all-locales", feature = "lang-to", feature = "region-to"))]
+    pub const to_TO: super::Locale = super::Locale::to_TO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-to", feature = "region-to")))]
+    pub use super::POSIX as to_TO;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-to", feature = "region-to")))]
+    pub const to_TO: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg"))]
+    pub use super::tpi_PG;
+    #[cfg(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg"))]
+    pub const tpi_PG: super::Locale = super::Locale::tpi_PG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg")))]
+    pub use super::POSIX as tpi_PG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tpi", feature = "region-pg")))]
+    pub const tpi_PG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy"))]
+    pub use super::tr_CY;
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy"))]
+    pub const tr_CY: super::Locale = super::Locale::tr_CY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy")))]
+    pub use super::POSIX as tr_CY;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tr", feature = "region-cy")))]
+    pub const tr_CY: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr"))]
+    pub use super::tr_TR;
+    #[cfg(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr"))]
+    pub const tr_TR: super::Locale = super::Locale::tr_TR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr")))]
+    pub use super::POSIX as tr_TR;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tr", feature = "region-tr")))]
+    pub const tr_TR: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ts", feature = "region-za"))]
+    pub use super::ts_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ts", feature = "region-za"))]
+    pub const ts_ZA: super::Locale = super::Locale::ts_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ts", feature = "region-za")))]
+    pub use super::POSIX as ts_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ts", feature = "region-za")))]
+    pub const ts_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    pub use super::tt_RU;
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    pub const tt_RU: super::Locale = super::Locale::tt_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru")))]
+    pub use super::POSIX as tt_RU;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru")))]
+    pub const tt_RU: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    pub use super::tt_RU_iqtelif;
+    #[cfg(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru"))]
+    pub const tt_RU_iqtelif: super::Locale = super::Locale::tt_RU_iqtelif;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru")))]
+    pub use super::POSIX as tt_RU_iqtelif;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-tt", feature = "region-ru")))]
+    pub const tt_RU_iqtelif: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn"))]
+    pub use super::ug_CN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn"))]
+    pub const ug_CN: super::Locale = super::Locale::ug_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn")))]
+    pub use super::POSIX as ug_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ug", feature = "region-cn")))]
+    pub const ug_CN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua"))]
+    pub use super::uk_UA;
+    #[cfg(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua"))]
+    pub const uk_UA: super::Locale = super::Locale::uk_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua")))]
+    pub use super::POSIX as uk_UA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uk", feature = "region-ua")))]
+    pub const uk_UA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-unm", feature = "region-us"))]
+    pub use super::unm_US;
+    #[cfg(any(feature = "all-locales", feature = "lang-unm", feature = "region-us"))]
+    pub const unm_US: super::Locale = super::Locale::unm_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-unm", feature = "region-us")))]
+    pub use super::POSIX as unm_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-unm", feature = "region-us")))]
+    pub const unm_US: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-in"))]
+    pub use super::ur_IN;
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-in"))]
+    pub const ur_IN: super::Locale = super::Locale::ur_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ur", feature = "region-in")))]
+    pub use super::POSIX as ur_IN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ur", feature = "region-in")))]
+    pub const ur_IN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk"))]
+    pub use super::ur_PK;
+    #[cfg(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk"))]
+    pub const ur_PK: super::Locale = super::Locale::ur_PK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk")))]
+    pub use super::POSIX as ur_PK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ur", feature = "region-pk")))]
+    pub const ur_PK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    pub use super::uz_UZ;
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    pub const uz_UZ: super::Locale = super::Locale::uz_UZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz")))]
+    pub use super::POSIX as uz_UZ;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz")))]
+    pub const uz_UZ: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    pub use super::uz_UZ_cyrillic;
+    #[cfg(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz"))]
+    pub const uz_UZ_cyrillic: super::Locale = super::Locale::uz_UZ_cyrillic;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz")))]
+    pub use super::POSIX as uz_UZ_cyrillic;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-uz", feature = "region-uz")))]
+    pub const uz_UZ_cyrillic: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-ve", feature = "region-za"))]
+    pub use super::ve_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-ve", feature = "region-za"))]
+    pub const ve_ZA: super::Locale = super::Locale::ve_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ve", feature = "region-za")))]
+    pub use super::POSIX as ve_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-ve", feature = "region-za")))]
+    pub const ve_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn"))]
+    pub use super::vi_VN;
+    #[cfg(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn"))]
+    pub const vi_VN: super::Locale = super::Locale::vi_VN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn")))]
+    pub use super::POSIX as vi_VN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-vi", feature = "region-vn")))]
+    pub const vi_VN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    pub use super::wa_BE;
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    pub const wa_BE: super::Locale = super::Locale::wa_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wa", feature = "region-be")))]
+    pub use super::POSIX as wa_BE;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wa", feature = "region-be")))]
+    pub const wa_BE: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    pub use super::wa_BE_euro;
+    #[cfg(any(feature = "all-locales", feature = "lang-wa", feature = "region-be"))]
+    pub const wa_BE_euro: super::Locale = super::Locale::wa_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wa", feature = "region-be")))]
+    pub use super::POSIX as wa_BE_euro;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wa", feature = "region-be")))]
+    pub const wa_BE_euro: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch"))]
+    pub use super::wae_CH;
+    #[cfg(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch"))]
+    pub const wae_CH: super::Locale = super::Locale::wae_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch")))]
+    pub use super::POSIX as wae_CH;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wae", feature = "region-ch")))]
+    pub const wae_CH: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-wal", feature = "region-et"))]
+    pub use super::wal_ET;
+    #[cfg(any(feature = "all-locales", feature = "lang-wal", feature = "region-et"))]
+    pub const wal_ET: super::Locale = super::Locale::wal_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wal", feature = "region-et")))]
+    pub use super::POSIX as wal_ET;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wal", feature = "region-et")))]
+    pub const wal_ET: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn"))]
+    pub use super::wo_SN;
+    #[cfg(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn"))]
+    pub const wo_SN: super::Locale = super::Locale::wo_SN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn")))]
+    pub use super::POSIX as wo_SN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-wo", feature = "region-sn")))]
+    pub const wo_SN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-xh", feature = "region-za"))]
+    pub use super::xh_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-xh", feature = "region-za"))]
+    pub const xh_ZA: super::Locale = super::Locale::xh_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-xh", feature = "region-za")))]
+    pub use super::POSIX as xh_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-xh", feature = "region-za")))]
+    pub const xh_ZA: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-yi", feature = "region-us"))]
+    pub use super::yi_US;
+    #[cfg(any(feature = "all-locales", feature = "lang-yi", feature = "region-us"))]
+    pub const yi_US: super::Locale = super::Locale::yi_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yi", feature = "region-us")))]
+    pub use super::POSIX as yi_US;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yi", feature = "region-us")))]
+    pub const yi_US: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng"))]
+    pub use super::yo_NG;
+    #[cfg(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng"))]
+    pub const yo_NG: super::Locale = super::Locale::yo_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng")))]
+    pub use super::POSIX as yo_NG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yo", feature = "region-ng")))]
+    pub const yo_NG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk"))]
+    pub use super::yue_HK;
+    #[cfg(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk"))]
+    pub const yue_HK: super::Locale = super::Locale::yue_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk")))]
+    pub use super::POSIX as yue_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yue", feature = "region-hk")))]
+    pub const yue_HK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg"))]
+    pub use super::yuw_PG;
+    #[cfg(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg"))]
+    pub const yuw_PG: super::Locale = super::Locale::yuw_PG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg")))]
+    pub use super::POSIX as yuw_PG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-yuw", feature = "region-pg")))]
+    pub const yuw_PG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn"))]
+    pub use super::zh_CN;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn"))]
+    pub const zh_CN: super::Locale = super::Locale::zh_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn")))]
+    pub use super::POSIX as zh_CN;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-cn")))]
+    pub const zh_CN: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk"))]
+    pub use super::zh_HK;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk"))]
+    pub const zh_HK: super::Locale = super::Locale::zh_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk")))]
+    pub use super::POSIX as zh_HK;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-hk")))]
+    pub const zh_HK: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg"))]
+    pub use super::zh_SG;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg"))]
+    pub const zh_SG: super::Locale = super::Locale::zh_SG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg")))]
+    pub use super::POSIX as zh_SG;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-sg")))]
+    pub const zh_SG: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw"))]
+    pub use super::zh_TW;
+    #[cfg(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw"))]
+    pub const zh_TW: super::Locale = super::Locale::zh_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw")))]
+    pub use super::POSIX as zh_TW;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zh", feature = "region-tw")))]
+    pub const zh_TW: super::Locale = super::Locale::POSIX;
+    #[cfg(any(feature = "all-locales", feature = "lang-zu", feature = "region-za"))]
+    pub use super::zu_ZA;
+    #[cfg(any(feature = "all-locales", feature = "lang-zu", feature = "region-za"))]
+    pub const zu_ZA: super::Locale = super::Locale::zu_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zu", feature = "region-za")))]
+    pub use super::POSIX as zu_ZA;
+    #[cfg(not(any(feature = "all-locales", feature = "lang-zu", feature = "region-za")))]
+    pub const zu_ZA: super::Locale = super::Locale::POSIX;
+}
+
+/// Look up an item of a locale: `locale_match!(locale => LC_TIME::D_FMT)` is the
+/// constant of the module of the locale, `LC_TIME::D_FMT` here.
+///
+/// With `in provider, name`, the item is looked up in the locale named `name` of a
+/// [`LocaleProvider`] instead, with the method of the `Dyn*Info` trait of the
+/// category. It returns `None` if the provider doesn't have the locale:
+///
+/// ```
+/// use pure_rust_locales::{locale_match, Builtin, Locale};
+///
+/// assert_eq!(locale_match!(Locale::fr_BE => LC_TIME::D_FMT), "%d/%m/%y");
+/// assert_eq!(locale_match!(in Builtin, "fr_BE" => LC_TIME::D_FMT), Some("%d/%m/%y"));
+/// assert_eq!(locale_match!(in Builtin, "xx_XX" => LC_TIME::D_FMT), None);
+/// ```
+#[macro_export]
+macro_rules! locale_match {
+    (in $provider:expr, $name:expr => $category:ident :: $item:ident) => {
+        $crate::LocaleProvider::locale(&$provider, $name)
+            .map($crate::__dyn::$category::$item)
+    };
+    ($locale:expr => $($item:ident)::+) => {{
+        #[allow(unreachable_patterns)]
+        let value = match $locale {
+            $crate::__locale_match::POSIX => $crate::__locale_match::POSIX::$($item)::+,
+            $crate::__locale_match::aa_DJ => $crate::__locale_match::aa_DJ::$($item)::+,
+            $crate::__locale_match::aa_ER => $crate::__locale_match::aa_ER::$($item)::+,
+            $crate::__locale_match::aa_ER_saaho => $crate::__locale_match::aa_ER_saaho::$($item)::+,
+            $crate::__locale_match::aa_ET => $crate::__locale_match::aa_ET::$($item)::+,
+            $crate::__locale_match::af_ZA => $crate::__locale_match::af_ZA::$($item)::+,
+            $crate::__locale_match::agr_PE => $crate::__locale_match::agr_PE::$($item)::+,
+            $crate::__locale_match::ak_GH => $crate::__locale_match::ak_GH::$($item)::+,
+            $crate::__locale_match::am_ET => $crate::__locale_match::am_ET::$($item)::+,
+            $crate::__locale_match::an_ES => $crate::__locale_match::an_ES::$($item)::+,
+            $crate::__locale_match::anp_IN => $crate::__locale_match::anp_IN::$($item)::+,
+            $crate::__locale_match::ar_AE => $crate::__locale_match::ar_AE::$($item)::+,
+            $crate::__locale_match::ar_BH => $crate::__locale_match::ar_BH::$($item)::+,
+            $crate::__locale_match::ar_DZ => $crate::__locale_match::ar_DZ::$($item)::+,
+            $crate::__locale_match::ar_EG => $crate::__locale_match::ar_EG::$($item)::+,
+            $crate::__locale_match::ar_IN => $crate::__locale_match::ar_IN::$($item)::+,
+            $crate::__locale_match::ar_IQ => $crate::__locale_match::ar_IQ::$($item)::+,
+            $crate::__locale_match::ar_JO => $crate::__locale_match::ar_JO::$($item)::+,
+            $crate::__locale_match::ar_KW => $crate::__locale_match::ar_KW::$($item)::+,
+            $crate::__locale_match::ar_LB => $crate::__locale_match::ar_LB::$($item)::+,
+            $crate::__locale_match::ar_LY => $crate::__locale_match::ar_LY::$($item)::+,
+            $crate::__locale_match::ar_MA => $crate::__locale_match::ar_MA::$($item)::+,
+            $crate::__locale_match::ar_OM => $crate::__locale_match::ar_OM::$($item)::+,
+            $crate::__locale_match::ar_QA => $crate::__locale_match::ar_QA::$($item)::+,
+            $crate::__locale_match::ar_SA => $crate::__locale_match::ar_SA::$($item)::+,
+            $crate::__locale_match::ar_SD => $crate::__locale_match::ar_SD::$($item)::+,
+            $crate::__locale_match::ar_SS => $crate::__locale_match::ar_SS::$($item)::+,
+            $crate::__locale_match::ar_SY => $crate::__locale_match::ar_SY::$($item)::+,
+            $crate::__locale_match::ar_TN => $crate::__locale_match::ar_TN::$($item)::+,
+            $crate::__locale_match::ar_YE => $crate::__locale_match::ar_YE::$($item)::+,
+            $crate::__locale_match::as_IN => $crate::__locale_match::as_IN::$($item)::+,
+            $crate::__locale_match::ast_ES => $crate::__locale_match::ast_ES::$($item)::+,
+            $crate::__locale_match::ayc_PE => $crate::__locale_match::ayc_PE::$($item)::+,
+            $crate::__locale_match::az_AZ => $crate::__locale_match::az_AZ::$($item)::+,
+            $crate::__locale_match::az_IR => $crate::__locale_match::az_IR::$($item)::+,
+            $crate::__locale_match::be_BY => $crate::__locale_match::be_BY::$($item)::+,
+            $crate::__locale_match::be_BY_latin => $crate::__locale_match::be_BY_latin::$($item)::+,
+            $crate::__locale_match::bem_ZM => $crate::__locale_match::bem_ZM::$($item)::+,
+            $crate::__locale_match::ber_DZ => $crate::__locale_match::ber_DZ::$($item)::+,
+            $crate::__locale_match::ber_MA => $crate::__locale_match::ber_MA::$($item)::+,
+            $crate::__locale_match::bg_BG => $crate::__locale_match::bg_BG::$($item)::+,
+            $crate::__locale_match::bhb_IN => $crate::__locale_match::bhb_IN::$($item)::+,
+            $crate::__locale_match::bho_IN => $crate::__locale_match::bho_IN::$($item)::+,
+            $crate::__locale_match::bho_NP => $crate::__locale_match::bho_NP::$($item)::+,
+            $crate::__locale_match::bi_VU => $crate::__locale_match::bi_VU::$($item)::+,
+            $crate::__locale_match::bn_BD => $crate::__locale_match::bn_BD::$($item)::+,
+            $crate::__locale_match::bn_IN => $crate::__locale_match::bn_IN::$($item)::+,
+            $crate::__locale_match::bo_CN => $crate::__locale_match::bo_CN::$($item)::+,
+            $crate::__locale_match::bo_IN => $crate::__locale_match::bo_IN::$($item)::+,
+            $crate::__locale_match::br_FR => $crate::__locale_match::br_FR::$($item)::+,
+            $crate::__locale_match::br_FR_euro => $crate::__locale_match::br_FR_euro::$($item)::+,
+            $crate::__locale_match::brx_IN => $crate::__locale_match::brx_IN::$($item)::+,
+            $crate::__locale_match::bs_BA => $crate::__locale_match::bs_BA::$($item)::+,
+            $crate::__locale_match::byn_ER => $crate::__locale_match::byn_ER::$($item)::+,
+            $crate::__locale_match::ca_AD => $crate::__locale_match::ca_AD::$($item)::+,
+            $crate::__locale_match::ca_ES => $crate::__locale_match::ca_ES::$($item)::+,
+            $crate::__locale_match::ca_ES_euro => $crate::__locale_match::ca_ES_euro::$($item)::+,
+            $crate::__locale_match::ca_ES_valencia => $crate::__locale_match::ca_ES_valencia::$($item)::+,
+            $crate::__locale_match::ca_FR => $crate::__locale_match::ca_FR::$($item)::+,
+            $crate::__locale_match::ca_IT => $crate::__locale_match::ca_IT::$($item)::+,
+            $crate::__locale_match::ce_RU => $crate::__locale_match::ce_RU::$($item)::+,
+            $crate::__locale_match::chr_US => $crate::__locale_match::chr_US::$($item)::+,
+            $crate::__locale_match::cmn_TW => $crate::__locale_match::cmn_TW::$($item)::+,
+            $crate::__locale_match::crh_UA => $crate::__locale_match::crh_UA::$($item)::+,
+            $crate::__locale_match::cs_CZ => $crate::__locale_match::cs_CZ::$($item)::+,
+            $crate::__locale_match::csb_PL => $crate::__locale_match::csb_PL::$($item)::+,
+            $crate::__locale_match::cv_RU => $crate::__locale_match::cv_RU::$($item)::+,
+            $crate::__locale_match::cy_GB => $crate::__locale_match::cy_GB::$($item)::+,
+            $crate::__locale_match::da_DK => $crate::__locale_match::da_DK::$($item)::+,
+            $crate::__locale_match::de_AT => $crate::__locale_match::de_AT::$($item)::+,
+            $crate::__locale_match::de_AT_euro => $crate::__locale_match::de_AT_euro::$($item)::+,
+            $crate::__locale_match::de_BE => $crate::__locale_match::de_BE::$($item)::+,
+            $crate::__locale_match::de_BE_euro => $crate::__locale_match::de_BE_euro::$($item)::+,
+            $crate::__locale_match::de_CH => $crate::__locale_match::de_CH::$($item)::+,
+            $crate::__locale_match::de_DE => $crate::__locale_match::de_DE::$($item)::+,
+            $crate::__locale_match::de_DE_euro => $crate::__locale_match::de_DE_euro::$($item)::+,
+            $crate::__locale_match::de_IT => $crate::__locale_match::de_IT::$($item)::+,
+            $crate::__locale_match::de_LI => $crate::__locale_match::de_LI::$($item)::+,
+            $crate::__locale_match::de_LU => $crate::__locale_match::de_LU::$($item)::+,
+            $crate::__locale_match::de_LU_euro => $crate::__locale_match::de_LU_euro::$($item)::+,
+            $crate::__locale_match::doi_IN => $crate::__locale_match::doi_IN::$($item)::+,
+            $crate::__locale_match::dsb_DE => $crate::__locale_match::dsb_DE::$($item)::+,
+            $crate::__locale_match::dv_MV => $crate::__locale_match::dv_MV::$($item)::+,
+            $crate::__locale_match::dz_BT => $crate::__locale_match::dz_BT::$($item)::+,
+            $crate::__locale_match::el_CY => $crate::__locale_match::el_CY::$($item)::+,
+            $crate::__locale_match::el_GR => $crate::__locale_match::el_GR::$($item)::+,
+            $crate::__locale_match::el_GR_euro => $crate::__locale_match::el_GR_euro::$($item)::+,
+            $crate::__locale_match::en_AG => $crate::__locale_match::en_AG::$($item)::+,
+            $crate::__locale_match::en_AU => $crate::__locale_match::en_AU::$($item)::+,
+            $crate::__locale_match::en_BW => $crate::__locale_match::en_BW::$($item)::+,
+            $crate::__locale_match::en_CA => $crate::__locale_match::en_CA::$($item)::+,
+            $crate::__locale_match::en_DK => $crate::__locale_match::en_DK::$($item)::+,
+            $crate::__locale_match::en_GB => $crate::__locale_match::en_GB::$($item)::+,
+            $crate::__locale_match::en_HK => $crate::__locale_match::en_HK::$($item)::+,
+            $crate::__locale_match::en_IE => $crate::__locale_match::en_IE::$($item)::+,
+            $crate::__locale_match::en_IE_euro => $crate::__locale_match::en_IE_euro::$($item)::+,
+            $crate::__locale_match::en_IL => $crate::__locale_match::en_IL::$($item)::+,
+            $crate::__locale_match::en_IN => $crate::__locale_match::en_IN::$($item)::+,
+            $crate::__locale_match::en_NG => $crate::__locale_match::en_NG::$($item)::+,
+            $crate::__locale_match::en_NZ => $crate::__locale_match::en_NZ::$($item)::+,
+            $crate::__locale_match::en_PH => $crate::__locale_match::en_PH::$($item)::+,
+            $crate::__locale_match::en_SC => $crate::__locale_match::en_SC::$($item)::+,
+            $crate::__locale_match::en_SG => $crate::__locale_match::en_SG::$($item)::+,
+            $crate::__locale_match::en_US => $crate::__locale_match::en_US::$($item)::+,
+            $crate::__locale_match::en_ZA => $crate::__locale_match::en_ZA::$($item)::+,
+            $crate::__locale_match::en_ZM => $crate::__locale_match::en_ZM::$($item)::+,
+            $crate::__locale_match::en_ZW => $crate::__locale_match::en_ZW::$($item)::+,
+            $crate::__locale_match::eo => $crate::__locale_match::eo::$($item)::+,
+            $crate::__locale_match::es_AR => $crate::__locale_match::es_AR::$($item)::+,
+            $crate::__locale_match::es_BO => $crate::__locale_match::es_BO::$($item)::+,
+            $crate::__locale_match::es_CL => $crate::__locale_match::es_CL::$($item)::+,
+            $crate::__locale_match::es_CO => $crate::__locale_match::es_CO::$($item)::+,
+            $crate::__locale_match::es_CR => $crate::__locale_match::es_CR::$($item)::+,
+            $crate::__locale_match::es_CU => $crate::__locale_match::es_CU::$($item)::+,
+            $crate::__locale_match::es_DO => $crate::__locale_match::es_DO::$($item)::+,
+            $crate::__locale_match::es_EC => $crate::__locale_match::es_EC::$($item)::+,
+            $crate::__locale_match::es_ES => $crate::__locale_match::es_ES::$($item)::+,
+            $crate::__locale_match::es_ES_euro => $crate::__locale_match::es_ES_euro::$($item)::+,
+            $crate::__locale_match::es_GT => $crate::__locale_match::es_GT::$($item)::+,
+            $crate::__locale_match::es_HN => $crate::__locale_match::es_HN::$($item)::+,
+            $crate::__locale_match::es_MX => $crate::__locale_match::es_MX::$($item)::+,
+            $crate::__locale_match::es_NI => $crate::__locale_match::es_NI::$($item)::+,
+            $crate::__locale_match::es_PA => $crate::__locale_match::es_PA::$($item)::+,
+            $crate::__locale_match::es_PE => $crate::__locale_match::es_PE::$($item)::+,
+            $crate::__locale_match::es_PR => $crate::__locale_match::es_PR::$($item)::+,
+            $crate::__locale_match::es_PY => $crate::__locale_match::es_PY::$($item)::+,
+            $crate::__locale_match::es_SV => $crate::__locale_match::es_SV::$($item)::+,
+            $crate::__locale_match::es_US => $crate::__locale_match::es_US::$($item)::+,
+            $crate::__locale_match::es_UY => $crate::__locale_match::es_UY::$($item)::+,
+            $crate::__locale_match::es_VE => $crate::__locale_match::es_VE::$($item)::+,
+            $crate::__locale_match::et_EE => $crate::__locale_match::et_EE::$($item)::+,
+            $crate::__locale_match::eu_ES => $crate::__locale_match::eu_ES::$($item)::+,
+            $crate::__locale_match::eu_ES_euro => $crate::__locale_match::eu_ES_euro::$($item)::+,
+            $crate::__locale_match::fa_IR => $crate::__locale_match::fa_IR::$($item)::+,
+            $crate::__locale_match::ff_SN => $crate::__locale_match::ff_SN::$($item)::+,
+            $crate::__locale_match::fi_FI => $crate::__locale_match::fi_FI::$($item)::+,
+            $crate::__locale_match::fi_FI_euro => $crate::__locale_match::fi_FI_euro::$($item)::+,
+            $crate::__locale_match::fil_PH => $crate::__locale_match::fil_PH::$($item)::+,
+            $crate::__locale_match::fo_FO => $crate::__locale_match::fo_FO::$($item)::+,
+            $crate::__locale_match::fr_BE => $crate::__locale_match::fr_BE::$($item)::+,
+            $crate::__locale_match::fr_BE_euro => $crate::__locale_match::fr_BE_euro::$($item)::+,
+            $crate::__locale_match::fr_CA => $crate::__locale_match::fr_CA::$($item)::+,
+            $crate::__locale_match::fr_CH => $crate::__locale_match::fr_CH::$($item)::+,
+            $crate::__locale_match::fr_FR => $crate::__locale_match::fr_FR::$($item)::+,
+            $crate::__locale_match::fr_FR_euro => $crate::__locale_match::fr_FR_euro::$($item)::+,
+            $crate::__locale_match::fr_LU => $crate::__locale_match::fr_LU::$($item)::+,
+            $crate::__locale_match::fr_LU_euro => $crate::__locale_match::fr_LU_euro::$($item)::+,
+            $crate::__locale_match::fur_IT => $crate::__locale_match::fur_IT::$($item)::+,
+            $crate::__locale_match::fy_DE => $crate::__locale_match::fy_DE::$($item)::+,
+            $crate::__locale_match::fy_NL => $crate::__locale_match::fy_NL::$($item)::+,
+            $crate::__locale_match::ga_IE => $crate::__locale_match::ga_IE::$($item)::+,
+            $crate::__locale_match::ga_IE_euro => $crate::__locale_match::ga_IE_euro::$($item)::+,
+            $crate::__locale_match::gd_GB => $crate::__locale_match::gd_GB::$($item)::+,
+            $crate::__locale_match::gez_ER => $crate::__locale_match::gez_ER::$($item)::+,
+            $crate::__locale_match::gez_ER_abegede => $crate::__locale_match::gez_ER_abegede::$($item)::+,
+            $crate::__locale_match::gez_ET => $crate::__locale_match::gez_ET::$($item)::+,
+            $crate::__locale_match::gez_ET_abegede => $crate::__locale_match::gez_ET_abegede::$($item)::+,
+            $crate::__locale_match::gl_ES => $crate::__locale_match::gl_ES::$($item)::+,
+            $crate::__locale_match::gl_ES_euro => $crate::__locale_match::gl_ES_euro::$($item)::+,
+            $crate::__locale_match::gu_IN => $crate::__locale_match::gu_IN::$($item)::+,
+            $crate::__locale_match::gv_GB => $crate::__locale_match::gv_GB::$($item)::+,
+            $crate::__locale_match::ha_NG => $crate::__locale_match::ha_NG::$($item)::+,
+            $crate::__locale_match::hak_TW => $crate::__locale_match::hak_TW::$($item)::+,
+            $crate::__locale_match::he_IL => $crate::__locale_match::he_IL::$($item)::+,
+            $crate::__locale_match::hi_IN => $crate::__locale_match::hi_IN::$($item)::+,
+            $crate::__locale_match::hif_FJ => $crate::__locale_match::hif_FJ::$($item)::+,
+            $crate::__locale_match::hne_IN => $crate::__locale_match::hne_IN::$($item)::+,
+            $crate::__locale_match::hr_HR => $crate::__locale_match::hr_HR::$($item)::+,
+            $crate::__locale_match::hsb_DE => $crate::__locale_match::hsb_DE::$($item)::+,
+            $crate::__locale_match::ht_HT => $crate::__locale_match::ht_HT::$($item)::+,
+            $crate::__locale_match::hu_HU => $crate::__locale_match::hu_HU::$($item)::+,
+            $crate::__locale_match::hy_AM => $crate::__locale_match::hy_AM::$($item)::+,
+            $crate::__locale_match::ia_FR => $crate::__locale_match::ia_FR::$($item)::+,
+            $crate::__locale_match::id_ID => $crate::__locale_match::id_ID::$($item)::+,
+            $crate::__locale_match::ig_NG => $crate::__locale_match::ig_NG::$($item)::+,
+            $crate::__locale_match::ik_CA => $crate::__locale_match::ik_CA::$($item)::+,
+            $crate::__locale_match::is_IS => $crate::__locale_match::is_IS::$($item)::+,
+            $crate::__locale_match::it_CH => $crate::__locale_match::it_CH::$($item)::+,
+            $crate::__locale_match::it_IT => $crate::__locale_match::it_IT::$($item)::+,
+            $crate::__locale_match::it_IT_euro => $crate::__locale_match::it_IT_euro::$($item)::+,
+            $crate::__locale_match::iu_CA => $crate::__locale_match::iu_CA::$($item)::+,
+            $crate::__locale_match::ja_JP => $crate::__locale_match::ja_JP::$($item)::+,
+            $crate::__locale_match::ka_GE => $crate::__locale_match::ka_GE::$($item)::+,
+            $crate::__locale_match::kab_DZ => $crate::__locale_match::kab_DZ::$($item)::+,
+            $crate::__locale_match::kk_KZ => $crate::__locale_match::kk_KZ::$($item)::+,
+            $crate::__locale_match::kl_GL => $crate::__locale_match::kl_GL::$($item)::+,
+            $crate::__locale_match::km_KH => $crate::__locale_match::km_KH::$($item)::+,
+            $crate::__locale_match::kn_IN => $crate::__locale_match::kn_IN::$($item)::+,
+            $crate::__locale_match::ko_KR => $crate::__locale_match::ko_KR::$($item)::+,
+            $crate::__locale_match::kok_IN => $crate::__locale_match::kok_IN::$($item)::+,
+            $crate::__locale_match::ks_IN => $crate::__locale_match::ks_IN::$($item)::+,
+            $crate::__locale_match::ks_IN_devanagari => $crate::__locale_match::ks_IN_devanagari::$($item)::+,
+            $crate::__locale_match::ku_TR => $crate::__locale_match::ku_TR::$($item)::+,
+            $crate::__locale_match::kw_GB => $crate::__locale_match::kw_GB::$($item)::+,
+            $crate::__locale_match::ky_KG => $crate::__locale_match::ky_KG::$($item)::+,
+            $crate::__locale_match::lb_LU => $crate::__locale_match::lb_LU::$($item)::+,
+            $crate::__locale_match::lg_UG => $crate::__locale_match::lg_UG::$($item)::+,
+            $crate::__locale_match::li_BE => $crate::__locale_match::li_BE::$($item)::+,
+            $crate::__locale_match::li_NL => $crate::__locale_match::li_NL::$($item)::+,
+            $crate::__locale_match::lij_IT => $crate::__locale_match::lij_IT::$($item)::+,
+            $crate::__locale_match::ln_CD => $crate::__locale_match::ln_CD::$($item)::+,
+            $crate::__locale_match::lo_LA => $crate::__locale_match::lo_LA::$($item)::+,
+            $crate::__locale_match::lt_LT => $crate::__locale_match::lt_LT::$($item)::+,
+            $crate::__locale_match::lv_LV => $crate::__locale_match::lv_LV::$($item)::+,
+            $crate::__locale_match::lzh_TW => $crate::__locale_match::lzh_TW::$($item)::+,
+            $crate::__locale_match::mag_IN => $crate::__locale_match::mag_IN::$($item)::+,
+            $crate::__locale_match::mai_IN => $crate::__locale_match::mai_IN::$($item)::+,
+            $crate::__locale_match::mai_NP => $crate::__locale_match::mai_NP::$($item)::+,
+            $crate::__locale_match::mfe_MU => $crate::__locale_match::mfe_MU::$($item)::+,
+            $crate::__locale_match::mg_MG => $crate::__locale_match::mg_MG::$($item)::+,
+            $crate::__locale_match::mhr_RU => $crate::__locale_match::mhr_RU::$($item)::+,
+            $crate::__locale_match::mi_NZ => $crate::__locale_match::mi_NZ::$($item)::+,
+            $crate::__locale_match::miq_NI => $crate::__locale_match::miq_NI::$($item)::+,
+            $crate::__locale_match::mjw_IN => $crate::__locale_match::mjw_IN::$($item)::+,
+            $crate::__locale_match::mk_MK => $crate::__locale_match::mk_MK::$($item)::+,
+            $crate::__locale_match::ml_IN => $crate::__locale_match::ml_IN::$($item)::+,
+            $crate::__locale_match::mn_MN => $crate::__locale_match::mn_MN::$($item)::+,
+            $crate::__locale_match::mni_IN => $crate::__locale_match::mni_IN::$($item)::+,
+            $crate::__locale_match::mnw_MM => $crate::__locale_match::mnw_MM::$($item)::+,
+            $crate::__locale_match::mr_IN => $crate::__locale_match::mr_IN::$($item)::+,
+            $crate::__locale_match::ms_MY => $crate::__locale_match::ms_MY::$($item)::+,
+            $crate::__locale_match::mt_MT => $crate::__locale_match::mt_MT::$($item)::+,
+            $crate::__locale_match::my_MM => $crate::__locale_match::my_MM::$($item)::+,
+            $crate::__locale_match::nan_TW => $crate::__locale_match::nan_TW::$($item)::+,
+            $crate::__locale_match::nan_TW_latin => $crate::__locale_match::nan_TW_latin::$($item)::+,
+            $crate::__locale_match::nb_NO => $crate::__locale_match::nb_NO::$($item)::+,
+            $crate::__locale_match::nds_DE => $crate::__locale_match::nds_DE::$($item)::+,
+            $crate::__locale_match::nds_NL => $crate::__locale_match::nds_NL::$($item)::+,
+            $crate::__locale_match::ne_NP => $crate::__locale_match::ne_NP::$($item)::+,
+            $crate::__locale_match::nhn_MX => $crate::__locale_match::nhn_MX::$($item)::+,
+            $crate::__locale_match::niu_NU => $crate::__locale_match::niu_NU::$($item)::+,
+            $crate::__locale_match::niu_NZ => $crate::__locale_match::niu_NZ::$($item)::+,
+            $crate::__locale_match::nl_AW => $crate::__locale_match::nl_AW::$($item)::+,
+            $crate::__locale_match::nl_BE => $crate::__locale_match::nl_BE::$($item)::+,
+            $crate::__locale_match::nl_BE_euro => $crate::__locale_match::nl_BE_euro::$($item)::+,
+            $crate::__locale_match::nl_NL => $crate::__locale_match::nl_NL::$($item)::+,
+            $crate::__locale_match::nl_NL_euro => $crate::__locale_match::nl_NL_euro::$($item)::+,
+            $crate::__locale_match::nn_NO => $crate::__locale_match::nn_NO::$($item)::+,
+            $crate::__locale_match::nr_ZA => $crate::__locale_match::nr_ZA::$($item)::+,
+            $crate::__locale_match::nso_ZA => $crate::__locale_match::nso_ZA::$($item)::+,
+            $crate::__locale_match::oc_FR => $crate::__locale_match::oc_FR::$($item)::+,
+            $crate::__locale_match::om_ET => $crate::__locale_match::om_ET::$($item)::+,
+            $crate::__locale_match::om_KE => $crate::__locale_match::om_KE::$($item)::+,
+            $crate::__locale_match::or_IN => $crate::__locale_match::or_IN::$($item)::+,
+            $crate::__locale_match::os_RU => $crate::__locale_match::os_RU::$($item)::+,
+            $crate::__locale_match::pa_IN => $crate::__locale_match::pa_IN::$($item)::+,
+            $crate::__locale_match::pa_PK => $crate::__locale_match::pa_PK::$($item)::+,
+            $crate::__locale_match::pap_AW => $crate::__locale_match::pap_AW::$($item)::+,
+            $crate::__locale_match::pap_CW => $crate::__locale_match::pap_CW::$($item)::+,
+            $crate::__locale_match::pl_PL => $crate::__locale_match::pl_PL::$($item)::+,
+            $crate::__locale_match::ps_AF => $crate::__locale_match::ps_AF::$($item)::+,
+            $crate::__locale_match::pt_BR => $crate::__locale_match::pt_BR::$($item)::+,
+            $crate::__locale_match::pt_PT => $crate::__locale_match::pt_PT::$($item)::+,
+            $crate::__locale_match::pt_PT_euro => $crate::__locale_match::pt_PT_euro::$($item)::+,
+            $crate::__locale_match::quz_PE => $crate::__locale_match::quz_PE::$($item)::+,
+            $crate::__locale_match::raj_IN => $crate::__locale_match::raj_IN::$($item)::+,
+            $crate::__locale_match::ro_RO => $crate::__locale_match::ro_RO::$($item)::+,
+            $crate::__locale_match::ru_RU => $crate::__locale_match::ru_RU::$($item)::+,
+            $crate::__locale_match::ru_UA => $crate::__locale_match::ru_UA::$($item)::+,
+            $crate::__locale_match::rw_RW => $crate::__locale_match::rw_RW::$($item)::+,
+            $crate::__locale_match::sa_IN => $crate::__locale_match::sa_IN::$($item)::+,
+            $crate::__locale_match::sah_RU => $crate::__locale_match::sah_RU::$($item)::+,
+            $crate::__locale_match::sat_IN => $crate::__locale_match::sat_IN::$($item)::+,
+            $crate::__locale_match::sc_IT => $crate::__locale_match::sc_IT::$($item)::+,
+            $crate::__locale_match::sd_IN => $crate::__locale_match::sd_IN::$($item)::+,
+            $crate::__locale_match::sd_IN_devanagari => $crate::__locale_match::sd_IN_devanagari::$($item)::+,
+            $crate::__locale_match::se_NO => $crate::__locale_match::se_NO::$($item)::+,
+            $crate::__locale_match::sgs_LT => $crate::__locale_match::sgs_LT::$($item)::+,
+            $crate::__locale_match::shn_MM => $crate::__locale_match::shn_MM::$($item)::+,
+            $crate::__locale_match::shs_CA => $crate::__locale_match::shs_CA::$($item)::+,
+            $crate::__locale_match::si_LK => $crate::__locale_match::si_LK::$($item)::+,
+            $crate::__locale_match::sid_ET => $crate::__locale_match::sid_ET::$($item)::+,
+            $crate::__locale_match::sk_SK => $crate::__locale_match::sk_SK::$($item)::+,
+            $crate::__locale_match::sl_SI => $crate::__locale_match::sl_SI::$($item)::+,
+            $crate::__locale_match::sm_WS => $crate::__locale_match::sm_WS::$($item)::+,
+            $crate::__locale_match::so_DJ => $crate::__locale_match::so_DJ::$($item)::+,
+            $crate::__locale_match::so_ET => $crate::__locale_match::so_ET::$($item)::+,
+            $crate::__locale_match::so_KE => $crate::__locale_match::so_KE::$($item)::+,
+            $crate::__locale_match::so_SO => $crate::__locale_match::so_SO::$($item)::+,
+            $crate::__locale_match::sq_AL => $crate::__locale_match::sq_AL::$($item)::+,
+            $crate::__locale_match::sq_MK => $crate::__locale_match::sq_MK::$($item)::+,
+            $crate::__locale_match::sr_ME => $crate::__locale_match::sr_ME::$($item)::+,
+            $crate::__locale_match::sr_RS => $crate::__locale_match::sr_RS::$($item)::+,
+            $crate::__locale_match::sr_RS_latin => $crate::__locale_match::sr_RS_latin::$($item)::+,
+            $crate::__locale_match::ss_ZA => $crate::__locale_match::ss_ZA::$($item)::+,
+            $crate::__locale_match::st_ZA => $crate::__locale_match::st_ZA::$($item)::+,
+            $crate::__locale_match::sv_FI => $crate::__locale_match::sv_FI::$($item)::+,
+            $crate::__locale_match::sv_FI_euro => $crate::__locale_match::sv_FI_euro::$($item)::+,
+            $crate::__locale_match::sv_SE => $crate::__locale_match::sv_SE::$($item)::+,
+            $crate::__locale_match::sw_KE => $crate::__locale_match::sw_KE::$($item)::+,
+            $crate::__locale_match::sw_TZ => $crate::__locale_match::sw_TZ::$($item)::+,
+            $crate::__locale_match::szl_PL => $crate::__locale_match::szl_PL::$($item)::+,
+            $crate::__locale_match::ta_IN => $crate::__locale_match::ta_IN::$($item)::+,
+            $crate::__locale_match::ta_LK => $crate::__locale_match::ta_LK::$($item)::+,
+            $crate::__locale_match::tcy_IN => $crate::__locale_match::tcy_IN::$($item)::+,
+            $crate::__locale_match::te_IN => $crate::__locale_match::te_IN::$($item)::+,
+            $crate::__locale_match::tg_TJ => $crate::__locale_match::tg_TJ::$($item)::+,
+            $crate::__locale_match::th_TH => $crate::__locale_match::th_TH::$($item)::+,
+            $crate::__locale_match::the_NP => $crate::__locale_match::the_NP::$($item)::+,
+            $crate::__locale_match::ti_ER => $crate::__locale_match::ti_ER::$($item)::+,
+            $crate::__locale_match::ti_ET => $crate::__locale_match::ti_ET::$($item)::+,
+            $crate::__locale_match::tig_ER => $crate::__locale_match::tig_ER::$($item)::+,
+            $crate::__locale_match::tk_TM => $crate::__locale_match::tk_TM::$($item)::+,
+            $crate::__locale_match::tl_PH => $crate::__locale_match::tl_PH::$($item)::+,
+            $crate::__locale_match::tn_ZA => $crate::__locale_match::tn_ZA::$($item)::+,
+            $crate::__locale_match::to_TO => $crate::__locale_match::to_TO::$($item)::+,
+            $crate::__locale_match::tpi_PG => $crate::__locale_match::tpi_PG::$($item)::+,
+            $crate::__locale_match::tr_CY => $crate::__locale_match::tr_CY::$($item)::+,
+            $crate::__locale_match::tr_TR => $crate::__locale_match::tr_TR::$($item)::+,
+            $crate::__locale_match::ts_ZA => $crate::__locale_match::ts_ZA::$($item)::+,
+            $crate::__locale_match::tt_RU => $crate::__locale_match::tt_RU::$($item)::+,
+            $crate::__locale_match::tt_RU_iqtelif => $crate::__locale_match::tt_RU_iqtelif::$($item)::+,
+            $crate::__locale_match::ug_CN => $crate::__locale_match::ug_CN::$($item)::+,
+            $crate::__locale_match::uk_UA => $crate::__locale_match::uk_UA::$($item)::+,
+            $crate::__locale_match::unm_US => $crate::__locale_match::unm_US::$($item)::+,
+            $crate::__locale_match::ur_IN => $crate::__locale_match::ur_IN::$($item)::+,
+            $crate::__locale_match::ur_PK => $crate::__locale_match::ur_PK::$($item)::+,
+            $crate::__locale_match::uz_UZ => $crate::__locale_match::uz_UZ::$($item)::+,
+            $crate::__locale_match::uz_UZ_cyrillic => $crate::__locale_match::uz_UZ_cyrillic::$($item)::+,
+            $crate::__locale_match::ve_ZA => $crate::__locale_match::ve_ZA::$($item)::+,
+            $crate::__locale_match::vi_VN => $crate::__locale_match::vi_VN::$($item)::+,
+            $crate::__locale_match::wa_BE => $crate::__locale_match::wa_BE::$($item)::+,
+            $crate::__locale_match::wa_BE_euro => $crate::__locale_match::wa_BE_euro::$($item)::+,
+            $crate::__locale_match::wae_CH => $crate::__locale_match::wae_CH::$($item)::+,
+            $crate::__locale_match::wal_ET => $crate::__locale_match::wal_ET::$($item)::+,
+            $crate::__locale_match::wo_SN => $crate::__locale_match::wo_SN::$($item)::+,
+            $crate::__locale_match::xh_ZA => $crate::__locale_match::xh_ZA::$($item)::+,
+            $crate::__locale_match::yi_US => $crate::__locale_match::yi_US::$($item)::+,
+            $crate::__locale_match::yo_NG => $crate::__locale_match::yo_NG::$($item)::+,
+            $crate::__locale_match::yue_HK => $crate::__locale_match::yue_HK::$($item)::+,
+            $crate::__locale_match::yuw_PG => $crate::__locale_match::yuw_PG::$($item)::+,
+            $crate::__locale_match::zh_CN => $crate::__locale_match::zh_CN::$($item)::+,
+            $crate::__locale_match::zh_HK => $crate::__locale_match::zh_HK::$($item)::+,
+            $crate::__locale_match::zh_SG => $crate::__locale_match::zh_SG::$($item)::+,
+            $crate::__locale_match::zh_TW => $crate::__locale_match::zh_TW::$($item)::+,
+            $crate::__locale_match::zu_ZA => $crate::__locale_match::zu_ZA::$($item)::+,
+            _ => ::core::unreachable!(),
+        };
+        value
+    }}
+}
+
+/// Like [`locale_match!`] but for a [`LocaleSet`]: the first segment of the path is the
+/// category and the locale of that category is used, from a provider with
+/// `in provider, set`.
+#[macro_export]
+macro_rules! locale_set_match {
+    (in $provider:expr, $set:expr => $category:ident :: $item:ident) => {
+        $crate::locale_match!(
+            in $provider,
+            $crate::LocaleSet::get(&$set, $crate::Category::$category).name()
+                => $category::$item
+        )
+    };
+    ($set:expr => $category:ident $(::$item:ident)+) => {
+        $crate::locale_match!(
+            $crate::LocaleSet::get(&$set, $crate::Category::$category)
+                => $category $(::$item)+
+        )
+    };
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::POSIX
+    }
+}
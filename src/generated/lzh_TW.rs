@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("TWN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TWN");
+    /// `Some("TW")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TW");
+    /// `Some("RC")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RC");
+    /// `Some("957")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("957");
+    /// `Some("中華民國")`
+    pub const COUNTRY_NAME: Option<&str> = Some("中華民國");
+    /// `Some(158)`
+    pub const COUNTRY_NUM: Option<i64> = Some(158);
+    /// `Some("TW")`
+    pub const COUNTRY_POST: Option<&str> = Some("TW");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("lzh")`
+    pub const LANG_LIB: Option<&str> = Some("lzh");
+    /// `Some("漢語文言")`
+    pub const LANG_NAME: Option<&str> = Some("漢語文言");
+    /// `Some("lzh")`
+    pub const LANG_TERM: Option<&str> = Some("lzh");
+    /// `Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2013-06-02")`
+    pub const DATE: Option<&str> = Some("2013-06-02");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Literary Chinese")`
+    pub const LANGUAGE: Option<&str> = Some("Literary Chinese");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Taiwan")`
+    pub const TERRITORY: Option<&str> = Some("Taiwan");
+    /// `Some("Literary Chinese locale for the Republic of China")`
+    pub const TITLE: Option<&str> = Some("Literary Chinese locale for the Republic of China");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNｎＮ非]"`
+    pub const NOEXPR: &str = "^[-0nNｎＮ非]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yYｙＹ是]"`
+    pub const YESEXPR: &str = "^[+1yYｙＹ是]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub mod LC_MONETARY {
+    /// `"NT$"`
+    pub const CURRENCY_SYMBOL: &str = "NT$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TWD "`
+    pub const INT_CURR_SYMBOL: &str = "TWD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(0)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(0);
+    /// `Some(1)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(1);
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[4]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_4;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[4]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_5;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("886")`
+    pub const INT_PREFIX: Option<&str> = Some("886");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A-%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A-%l");
+    /// `Some("+%c-%a-%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c-%a-%l");
+}
+pub mod LC_TIME {
+    /// `&["日", "一", "二", "三", "四", "五", "六"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_24;
+    /// `&[" 一 ", " 二 ", " 三 ", " 四 ", " 五 ", " 六 ", " 七 ", " 八 ", " 九 ", " 十 ", "十一", "十二"]`
+    pub const ABMON: &[&str] = &[" 一 ", " 二 ", " 三 ", " 四 ", " 五 ", " 六 ", " 七 ", " 八 ", " 九 ", " 十 ", "十一", "十二"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "廿", "廿一", "廿二", "廿三", "廿四", "廿五", "廿六", "廿七", "廿八", "廿九", "卅", "卅一"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(&["〇", "一", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二", "十三", "十四", "十五", "十六", "十七", "十八", "十九", "廿", "廿一", "廿二", "廿三", "廿四", "廿五", "廿六", "廿七", "廿八", "廿九", "卅", "卅一"]);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["朝", "暮"]`
+    pub const AM_PM: &[&str] = &["朝", "暮"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("公曆 %C%Oy年 %B %Oe日 %A %OH時%OM分%OS秒")`
+    pub const DATE_FMT: Option<&str> = Some("公曆 %C%Oy年 %B %Oe日 %A %OH時%OM分%OS秒");
+    /// `&["週日", "週一", "週二", "週三", "週四", "週五", "週六"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_27;
+    /// `"%OC%Oy年%B%Od日"`
+    pub const D_FMT: &str = "%OC%Oy年%B%Od日";
+    /// `"%OC%Oy年%B%Od日 (%A) %OH時%OM分%OS秒"`
+    pub const D_T_FMT: &str = "%OC%Oy年%B%Od日 (%A) %OH時%OM分%OS秒";
+    /// `Some(&["+:2:1913/01/01:+*:民國:%EC%Ey年", "+:1:1912/01/01:1912/12/31:民國:%EC元年", "+:1:1911/12/31:-*:民前:%EC%Ey年"])`
+    pub const ERA: Option<&[&str]> = Some(crate::__data::LC_TIME::ERA_0);
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_31;
+    /// `"%OH時%OM分%OS秒"`
+    pub const T_FMT: &str = "%OH時%OM分%OS秒";
+    /// `"%p %OI時%OM分%OS秒"`
+    pub const T_FMT_AMPM: &str = "%p %OI時%OM分%OS秒";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p %OI時%OM分%OS秒")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %OI時%OM分%OS秒");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
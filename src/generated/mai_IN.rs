@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("mai")`
+    pub const LANG_LIB: Option<&str> = Some("mai");
+    /// `Some("म\u{948}थिली")`
+    pub const LANG_NAME: Option<&str> = Some("म\u{948}थिली");
+    /// `Some("mai")`
+    pub const LANG_TERM: Option<&str> = Some("mai");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("B-3/302, Lunkad Daffodills, Viman Nagar, Pune, India")`
+    pub const ADDRESS: Option<&str> = Some("B-3/302, Lunkad Daffodills, Viman Nagar, Pune, India");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-07-24")`
+    pub const DATE: Option<&str> = Some("2017-07-24");
+    /// `Some("rajeshkajha@yahoo.com,akhilesh.k@samusng.com")`
+    pub const EMAIL: Option<&str> = Some("rajeshkajha@yahoo.com,akhilesh.k@samusng.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Maithili")`
+    pub const LANGUAGE: Option<&str> = Some("Maithili");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Maithili Computing Research Center, Pune, India")`
+    pub const SOURCE: Option<&str> = Some("Maithili Computing Research Center, Pune, India");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Maithili language locale for India")`
+    pub const TITLE: Option<&str> = Some("Maithili language locale for India");
+}
+pub use super::hi_IN::LC_MESSAGES;
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["रवि", "सोम", "म\u{902}गल", "ब\u{941}ध", "ब\u{943}हस\u{94d}पती", "श\u{941}क\u{94d}र", "शनी"]`
+    pub const ABDAY: &[&str] = &["रवि", "सोम", "म\u{902}गल", "ब\u{941}ध", "ब\u{943}हस\u{94d}पती", "श\u{941}क\u{94d}र", "शनी"];
+    /// `&["ब\u{948}साख", "ज\u{947}ठ", "अषाढ\u{93c}", "सावोन", "भादो", "आसिन", "कातिक", "अगहन", "प\u{942}स", "माघ", "फाग\u{941}न", "च\u{948}ति"]`
+    pub const ABMON: &[&str] = &["ब\u{948}साख", "ज\u{947}ठ", "अषाढ\u{93c}", "सावोन", "भादो", "आसिन", "कातिक", "अगहन", "प\u{942}स", "माघ", "फाग\u{941}न", "च\u{948}ति"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["रविदिन", "सोमदिन", "म\u{902}गलदिन", "ब\u{941}धदिन", "ब\u{943}हस\u{94d}पतीदिन", "श\u{941}क\u{94d}रदिन", "शनीदिन"]`
+    pub const DAY: &[&str] = &["रविदिन", "सोमदिन", "म\u{902}गलदिन", "ब\u{941}धदिन", "ब\u{943}हस\u{94d}पतीदिन", "श\u{941}क\u{94d}रदिन", "शनीदिन"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ब\u{948}साख", "ज\u{947}ठ", "अषाढ\u{93c}", "सावोन", "भादो", "आसिन", "कातिक", "अगहन", "प\u{942}स", "माघ", "फाग\u{941}न", "च\u{948}ति"]`
+    pub const MON: &[&str] = &["ब\u{948}साख", "ज\u{947}ठ", "अषाढ\u{93c}", "सावोन", "भादो", "आसिन", "कातिक", "अगहन", "प\u{942}स", "माघ", "फाग\u{941}न", "च\u{948}ति"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
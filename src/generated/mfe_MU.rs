@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("MUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MUS");
+    /// `Some("MU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MU");
+    /// `Some("MS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MS");
+    /// `Some("978-613,978-620,978-99903,978-99949")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-613,978-620,978-99903,978-99949");
+    /// `Some("Moris")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Moris");
+    /// `Some(480)`
+    pub const COUNTRY_NUM: Option<i64> = Some(480);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("mfe")`
+    pub const LANG_LIB: Option<&str> = Some("mfe");
+    /// `Some("kreol morisien")`
+    pub const LANG_NAME: Option<&str> = Some("kreol morisien");
+    /// `Some("mfe")`
+    pub const LANG_TERM: Option<&str> = Some("mfe");
+    /// `Some("%f%N%h%s%N%T")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%h%s%N%T");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-08-18")`
+    pub const DATE: Option<&str> = Some("2017-08-18");
+    /// `Some("akhilesh.k@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Morisyen")`
+    pub const LANGUAGE: Option<&str> = Some("Morisyen");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Mauritius")`
+    pub const TERRITORY: Option<&str> = Some("Mauritius");
+    /// `Some("Morisyen locale for Mauritius")`
+    pub const TITLE: Option<&str> = Some("Morisyen locale for Mauritius");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Non")`
+    pub const NOSTR: Option<&str> = Some("Non");
+    /// `"^[+1yYwW]"`
+    pub const YESEXPR: &str = "^[+1yYwW]";
+    /// `Some("Wi")`
+    pub const YESSTR: Option<&str> = Some("Wi");
+}
+pub mod LC_MONETARY {
+    /// `"₨"`
+    pub const CURRENCY_SYMBOL: &str = "₨";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MUR "`
+    pub const INT_CURR_SYMBOL: &str = "MUR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("230")`
+    pub const INT_PREFIX: Option<&str> = Some("230");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["dim", "lin", "mar", "mer", "ze", "van", "sam"]`
+    pub const ABDAY: &[&str] = &["dim", "lin", "mar", "mer", "ze", "van", "sam"];
+    /// `&["zan", "fev", "mar", "avr", "me", "zin", "zil", "out", "sep", "okt", "nov", "des"]`
+    pub const ABMON: &[&str] = &["zan", "fev", "mar", "avr", "me", "zin", "zil", "out", "sep", "okt", "nov", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %H:%M:%S %Z %Y");
+    /// `&["dimans", "lindi", "mardi", "merkredi", "zedi", "vandredi", "samdi"]`
+    pub const DAY: &[&str] = &["dimans", "lindi", "mardi", "merkredi", "zedi", "vandredi", "samdi"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["zanvie", "fevriye", "mars", "avril", "me", "zin", "zilye", "out", "septam", "oktob", "novam", "desam"]`
+    pub const MON: &[&str] = &["zanvie", "fevriye", "mars", "avril", "me", "zin", "zilye", "out", "septam", "oktob", "novam", "desam"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %P %Z"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %P %Z";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%l:%M:%S %P %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %P %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("MDG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MDG");
+    /// `Some("MG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MG");
+    /// `Some("RM")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RM");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Madagasikara")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Madagasikara");
+    /// `Some(450)`
+    pub const COUNTRY_NUM: Option<i64> = Some(450);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("mg")`
+    pub const LANG_AB: Option<&str> = Some("mg");
+    /// `Some("mlg")`
+    pub const LANG_LIB: Option<&str> = Some("mlg");
+    /// `Some("Malagasy")`
+    pub const LANG_NAME: Option<&str> = Some("Malagasy");
+    /// `Some("mlg")`
+    pub const LANG_TERM: Option<&str> = Some("mlg");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Rado Ramarotafika,Do-Risika RAFIEFERANTSIARONJY")`
+    pub const CONTACT: Option<&str> = Some("Rado Ramarotafika,Do-Risika RAFIEFERANTSIARONJY");
+    /// `Some("2005-02-02")`
+    pub const DATE: Option<&str> = Some("2005-02-02");
+    /// `Some("rado@linuxmg.org,dourix@free.fr")`
+    pub const EMAIL: Option<&str> = Some("rado@linuxmg.org,dourix@free.fr");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Malagasy")`
+    pub const LANGUAGE: Option<&str> = Some("Malagasy");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("The Debian Project modified by GNU/Linux Malagasy")`
+    pub const SOURCE: Option<&str> = Some("The Debian Project modified by GNU/Linux Malagasy");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Madagascar")`
+    pub const TERRITORY: Option<&str> = Some("Madagascar");
+    /// `Some("Malagasy locale for Madagascar")`
+    pub const TITLE: Option<&str> = Some("Malagasy locale for Madagascar");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0tTnN]"`
+    pub const NOEXPR: &str = "^[-0tTnN]";
+    /// `Some("Tsia")`
+    pub const NOSTR: Option<&str> = Some("Tsia");
+    /// `"^[+1eEyY]"`
+    pub const YESEXPR: &str = "^[+1eEyY]";
+    /// `Some("Eny")`
+    pub const YESSTR: Option<&str> = Some("Eny");
+}
+pub mod LC_MONETARY {
+    /// `"Ar"`
+    pub const CURRENCY_SYMBOL: &str = "Ar";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MGA "`
+    pub const INT_CURR_SYMBOL: &str = "MGA ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("261")`
+    pub const INT_PREFIX: Option<&str> = Some("261");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["lhd", "lts", "tlt", "lrb", "lkm", "zom", "sab"]`
+    pub const ABDAY: &[&str] = &["lhd", "lts", "tlt", "lrb", "lkm", "zom", "sab"];
+    /// `&["Jan", "Feb", "Mar", "Apr", "Mey", "Jon", "Jol", "Aog", "Sep", "Okt", "Nov", "Des"]`
+    pub const ABMON: &[&str] = &["Jan", "Feb", "Mar", "Apr", "Mey", "Jon", "Jol", "Aog", "Sep", "Okt", "Nov", "Des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["alahady", "alatsinainy", "talata", "alarobia", "alakamisy", "zoma", "sabotsy"]`
+    pub const DAY: &[&str] = &["alahady", "alatsinainy", "talata", "alarobia", "alakamisy", "zoma", "sabotsy"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Janoary", "Febroary", "Martsa", "Aprily", "Mey", "Jona", "Jolay", "Aogositra", "Septambra", "Oktobra", "Novambra", "Desambra"]`
+    pub const MON: &[&str] = &["Janoary", "Febroary", "Martsa", "Aprily", "Mey", "Jona", "Jolay", "Aogositra", "Septambra", "Oktobra", "Novambra", "Desambra"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
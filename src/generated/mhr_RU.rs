@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NAME: Option<&str> = None;
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("mhr")`
+    pub const LANG_LIB: Option<&str> = Some("mhr");
+    /// `Some("марий йылме")`
+    pub const LANG_NAME: Option<&str> = Some("марий йылме");
+    /// `Some("mhr")`
+    pub const LANG_TERM: Option<&str> = Some("mhr");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("112, Komsomolskaya Street, Yoshkar-Ola, Mari El Republic, Russia")`
+    pub const ADDRESS: Option<&str> = Some("112, Komsomolskaya Street, Yoshkar-Ola, Mari El Republic, Russia");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Vyacheslav Kileev")`
+    pub const CONTACT: Option<&str> = Some("Vyacheslav Kileev");
+    /// `Some("2011-04-18")`
+    pub const DATE: Option<&str> = Some("2011-04-18");
+    /// `Some("slavakileev@yandex.ru")`
+    pub const EMAIL: Option<&str> = Some("slavakileev@yandex.ru");
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Meadow Mari")`
+    pub const LANGUAGE: Option<&str> = Some("Meadow Mari");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("PeshSajSoft Ltd.")`
+    pub const SOURCE: Option<&str> = Some("PeshSajSoft Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Russia")`
+    pub const TERRITORY: Option<&str> = Some("Russia");
+    /// `Some("Mari locale for Russia")`
+    pub const TITLE: Option<&str> = Some("Mari locale for Russia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNУу]"`
+    pub const NOEXPR: &str = "^[-0nNУу]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yYТт]"`
+    pub const YESEXPR: &str = "^[+1yYТт]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub mod LC_MONETARY {
+    /// `"₽"`
+    pub const CURRENCY_SYMBOL: &str = "₽";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"RUB "`
+    pub const INT_CURR_SYMBOL: &str = "RUB ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::ru_RU::LC_NUMERIC;
+pub use super::ru_RU::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Ршр", "Шчм", "Кжм", "Вгч", "Изр", "Кгр", "Шмт"]`
+    pub const ABDAY: &[&str] = &["Ршр", "Шчм", "Кжм", "Вгч", "Изр", "Кгр", "Шмт"];
+    /// `&["Шрк", "Пгж", "Ӱрн", "Вшр", "Ага", "Пдш", "Срм", "Срл", "Идм", "Шыж", "Клм", "Тел"]`
+    pub const ABMON: &[&str] = &["Шрк", "Пгж", "Ӱрн", "Вшр", "Ага", "Пдш", "Срм", "Срл", "Идм", "Шыж", "Клм", "Тел"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A %Y %B %d %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%A %Y %B %d %H:%M:%S %Z");
+    /// `&["Рушарня", "Шочмо", "Кушкыжмо", "Вӱргече", "Изарня", "Кугарня", "Шуматкече"]`
+    pub const DAY: &[&str] = &["Рушарня", "Шочмо", "Кушкыжмо", "Вӱргече", "Изарня", "Кугарня", "Шуматкече"];
+    /// `"%Y.%m.%d"`
+    pub const D_FMT: &str = "%Y.%m.%d";
+    /// `"%A %Y %B %d %T"`
+    pub const D_T_FMT: &str = "%A %Y %B %d %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Шорыкйол", "Пургыж", "Ӱярня", "Вӱдшор", "Ага", "Пеледыш", "Сӱрем", "Сорла", "Идым", "Шыжа", "Кылме", "Теле"]`
+    pub const MON: &[&str] = &["Шорыкйол", "Пургыж", "Ӱярня", "Вӱдшор", "Ага", "Пеледыш", "Сӱрем", "Сорла", "Идым", "Шыжа", "Кылме", "Теле"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,137 @@
+pub mod LC_ADDRESS {
+    /// `Some("NZL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NZL");
+    /// `Some("NZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NZ");
+    /// `Some("NZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Aotearoa")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Aotearoa");
+    /// `Some(554)`
+    pub const COUNTRY_NUM: Option<i64> = Some(554);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("mi")`
+    pub const LANG_AB: Option<&str> = Some("mi");
+    /// `Some("mao")`
+    pub const LANG_LIB: Option<&str> = Some("mao");
+    /// `Some("Māori")`
+    pub const LANG_NAME: Option<&str> = Some("Māori");
+    /// `Some("mri")`
+    pub const LANG_TERM: Option<&str> = Some("mri");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_14);
+    /// `Some("James Gasson, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("James Gasson, Pablo Saratxaga");
+    /// `Some("2001-01-28")`
+    pub const DATE: Option<&str> = Some("2001-01-28");
+    /// `Some("james.gasson@clear.net.nz, pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("james.gasson@clear.net.nz, pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Maori")`
+    pub const LANGUAGE: Option<&str> = Some("Maori");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("James Gasson")`
+    pub const SOURCE: Option<&str> = Some("James Gasson");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("New Zealand")`
+    pub const TERRITORY: Option<&str> = Some("New Zealand");
+    /// `Some("Maori language locale for New Zealand")`
+    pub const TITLE: Option<&str> = Some("Maori language locale for New Zealand");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNkK]"`
+    pub const NOEXPR: &str = "^[-0nNkK]";
+    /// `Some("kāore")`
+    pub const NOSTR: Option<&str> = Some("kāore");
+    /// `"^[+1yYāĀäÄaA]"`
+    pub const YESEXPR: &str = "^[+1yYāĀäÄaA]";
+    /// `Some("āe")`
+    pub const YESSTR: Option<&str> = Some("āe");
+}
+pub use super::en_NZ::LC_MONETARY;
+pub use super::en_NZ::LC_NUMERIC;
+pub use super::en_NZ::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Ta", "Ma", "Tū", "We", "Tāi", "Pa", "Hā"]`
+    pub const ABDAY: &[&str] = &["Ta", "Ma", "Tū", "We", "Tāi", "Pa", "Hā"];
+    /// `&["Kohi", "Hui", "Pou", "Pae", "Hara", "Pipi", "Hōngoi", "Here", "Mahu", "Whi-nu", "Whi-ra", "Haki"]`
+    pub const ABMON: &[&str] = &["Kohi", "Hui", "Pou", "Pae", "Hara", "Pipi", "Hōngoi", "Here", "Mahu", "Whi-nu", "Whi-ra", "Haki"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Rātapu", "Mane", "Tūrei", "Wenerei", "Tāite", "Paraire", "Hātarei"]`
+    pub const DAY: &[&str] = &["Rātapu", "Mane", "Tūrei", "Wenerei", "Tāite", "Paraire", "Hātarei"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"Te %A, te %d o %B, %Y %T %Z"`
+    pub const D_T_FMT: &str = "Te %A, te %d o %B, %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Kohi-tātea", "Hui-tanguru", "Poutū-te-rangi", "Paenga-whāwhā", "Haratua", "Pipiri", "Hōngoingoi", "Here-turi-kōkā", "Mahuru", "Whiringa-ā-nuku", "Whiringa-ā-rangi", "Hakihea"]`
+    pub const MON: &[&str] = &["Kohi-tātea", "Hui-tanguru", "Poutū-te-rangi", "Paenga-whāwhā", "Haratua", "Pipiri", "Hōngoingoi", "Here-turi-kōkā", "Mahuru", "Whiringa-ā-nuku", "Whiringa-ā-rangi", "Hakihea"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
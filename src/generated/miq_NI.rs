@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("NIC")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NIC");
+    /// `Some("NI")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NI");
+    /// `Some("NIC")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NIC");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Nicaragua")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Nicaragua");
+    /// `Some(558)`
+    pub const COUNTRY_NUM: Option<i64> = Some(558);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("miq")`
+    pub const LANG_LIB: Option<&str> = Some("miq");
+    /// `Some("Mískitu")`
+    pub const LANG_NAME: Option<&str> = Some("Mískitu");
+    /// `Some("miq")`
+    pub const LANG_TERM: Option<&str> = Some("miq");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `None`
+    pub const CONTACT: Option<&str> = None;
+    /// `Some("2016-08-20")`
+    pub const DATE: Option<&str> = Some("2016-08-20");
+    /// `None`
+    pub const EMAIL: Option<&str> = None;
+    /// `None`
+    pub const FAX: Option<&str> = None;
+    /// `Some("Miskito")`
+    pub const LANGUAGE: Option<&str> = Some("Miskito");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `None`
+    pub const SOURCE: Option<&str> = None;
+    /// `None`
+    pub const TEL: Option<&str> = None;
+    /// `Some("Nicaragua")`
+    pub const TERRITORY: Option<&str> = Some("Nicaragua");
+    /// `Some("Miskito language locale for Nicaragua")`
+    pub const TITLE: Option<&str> = Some("Miskito language locale for Nicaragua");
+}
+pub use super::es_NI::LC_MESSAGES;
+pub use super::es_NI::LC_MONETARY;
+pub use super::es_NI::LC_NUMERIC;
+pub use super::es_NI::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["san", "mun", "tius", "wens", "taus", "prai", "sat"]`
+    pub const ABDAY: &[&str] = &["san", "mun", "tius", "wens", "taus", "prai", "sat"];
+    /// `&["siakwa kati", "kuswa kati", "kakamuk kati", "lî wainhka kati", "lih mairin kati", "lî kati", "pastara kati", "sikla kati", "wîs kati", "waupasa kati", "yahbra kati", "trisu kati"]`
+    pub const ABMON: &[&str] = &["siakwa kati", "kuswa kati", "kakamuk kati", "lî wainhka kati", "lih mairin kati", "lî kati", "pastara kati", "sikla kati", "wîs kati", "waupasa kati", "yahbra kati", "trisu kati"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["VM", "NM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_2;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %b %e %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %b %e %H:%M:%S %Z %Y");
+    /// `&["sandi", "mundi", "tiusdi", "wensde", "tausde", "praidi", "satadi"]`
+    pub const DAY: &[&str] = &["sandi", "mundi", "tiusdi", "wensde", "tausde", "praidi", "satadi"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(1)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(1);
+    /// `Some(2)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(2);
+    /// `&["siakwa kati", "kuswa kati", "kakamuk kati", "lî wainhka kati", "lih mairin kati", "lî kati", "pastara kati", "sikla kati", "wîs kati", "waupasa kati", "yahbra kati", "trisu kati"]`
+    pub const MON: &[&str] = &["siakwa kati", "kuswa kati", "kakamuk kati", "lî wainhka kati", "lih mairin kati", "lî kati", "pastara kati", "sikla kati", "wîs kati", "waupasa kati", "yahbra kati", "trisu kati"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 7])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_2);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,144 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Bhorot")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Bhorot");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("mjw")`
+    pub const LANG_LIB: Option<&str> = Some("mjw");
+    /// `Some("Karbi")`
+    pub const LANG_NAME: Option<&str> = Some("Karbi");
+    /// `Some("mjw")`
+    pub const LANG_TERM: Option<&str> = Some("mjw");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_12);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2013-08-08")`
+    pub const DATE: Option<&str> = Some("2013-08-08");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Karbi")`
+    pub const LANGUAGE: Option<&str> = Some("Karbi");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Jor Teron")`
+    pub const SOURCE: Option<&str> = Some("Jor Teron");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Karbi language locale for India")`
+    pub const TITLE: Option<&str> = Some("Karbi language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("no")`
+    pub const NOSTR: Option<&str> = Some("no");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("yes")`
+    pub const YESSTR: Option<&str> = Some("yes");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Bhom", "Ur", "Dur", "Tkel", "Tkem", "Bhta", "Bhti"]`
+    pub const ABDAY: &[&str] = &["Bhom", "Ur", "Dur", "Tkel", "Tkem", "Bhta", "Bhti"];
+    /// `&["Ark", "Thang", "The", "Jang", "Aru", "Vos", "Jak", "Pai", "Chi", "Phe", "Phai", "Mati"]`
+    pub const ABMON: &[&str] = &["Ark", "Thang", "The", "Jang", "Aru", "Vos", "Jak", "Pai", "Chi", "Phe", "Phai", "Mati"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Bhomkuru", "Urmi", "Durmi", "Thelang", "Theman", "Bhomta", "Bhomti"]`
+    pub const DAY: &[&str] = &["Bhomkuru", "Urmi", "Durmi", "Thelang", "Theman", "Bhomta", "Bhomti"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %B %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(1)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(1);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Arkoi", "Thangthang", "There", "Jangmi", "Aru", "Vosik", "Jakhong", "Paipai", "Chiti", "Phere", "Phaikuni", "Matijong"]`
+    pub const MON: &[&str] = &["Arkoi", "Thangthang", "There", "Jangmi", "Aru", "Vosik", "Jakhong", "Paipai", "Chiti", "Phere", "Phaikuni", "Matijong"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("MKD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MKD");
+    /// `Some("MK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MK");
+    /// `Some("MK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MK");
+    /// `Some("9989")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("9989");
+    /// `Some("Македонија")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Македонија");
+    /// `Some(807)`
+    pub const COUNTRY_NUM: Option<i64> = Some(807);
+    /// `Some("MK")`
+    pub const COUNTRY_POST: Option<&str> = Some("MK");
+    /// `Some("mk")`
+    pub const LANG_AB: Option<&str> = Some("mk");
+    /// `Some("mac")`
+    pub const LANG_LIB: Option<&str> = Some("mac");
+    /// `Some("македонски")`
+    pub const LANG_NAME: Option<&str> = Some("македонски");
+    /// `Some("mkd")`
+    pub const LANG_TERM: Option<&str> = Some("mkd");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Damjan Georgievski")`
+    pub const CONTACT: Option<&str> = Some("Damjan Georgievski");
+    /// `Some("2006-09-12")`
+    pub const DATE: Option<&str> = Some("2006-09-12");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Macedonian")`
+    pub const LANGUAGE: Option<&str> = Some("Macedonian");
+    /// `Some("2.2")`
+    pub const REVISION: Option<&str> = Some("2.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Macedonia")`
+    pub const TERRITORY: Option<&str> = Some("Macedonia");
+    /// `Some("Macedonian locale for Macedonia")`
+    pub const TITLE: Option<&str> = Some("Macedonian locale for Macedonia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNНн]"`
+    pub const NOEXPR: &str = "^[-0nNНн]";
+    /// `Some("не")`
+    pub const NOSTR: Option<&str> = Some("не");
+    /// `"^[+1yYДдdD]"`
+    pub const YESEXPR: &str = "^[+1yYДдdD]";
+    /// `Some("да")`
+    pub const YESSTR: Option<&str> = Some("да");
+}
+pub mod LC_MONETARY {
+    /// `"ден"`
+    pub const CURRENCY_SYMBOL: &str = "ден";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MKD "`
+    pub const INT_CURR_SYMBOL: &str = "MKD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("389")`
+    pub const INT_PREFIX: Option<&str> = Some("389");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["нед", "пон", "вто", "сре", "чет", "пет", "саб"]`
+    pub const ABDAY: &[&str] = &["нед", "пон", "вто", "сре", "чет", "пет", "саб"];
+    /// `&["јан", "фев", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "ное", "дек"]`
+    pub const ABMON: &[&str] = &["јан", "фев", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "ное", "дек"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %d %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %d %b %H:%M:%S %Z %Y");
+    /// `&["недела", "понеделник", "вторник", "среда", "четврток", "петок", "сабота"]`
+    pub const DAY: &[&str] = &["недела", "понеделник", "вторник", "среда", "четврток", "петок", "сабота"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a, %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a, %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"]`
+    pub const MON: &[&str] = &["јануари", "февруари", "март", "април", "мај", "јуни", "јули", "август", "септември", "октомври", "ноември", "декември"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,145 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ഇന\u{d4d}ത\u{d4d}യ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ഇന\u{d4d}ത\u{d4d}യ");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ml")`
+    pub const LANG_AB: Option<&str> = Some("ml");
+    /// `Some("mal")`
+    pub const LANG_LIB: Option<&str> = Some("mal");
+    /// `Some("മലയ\u{d3e}ളം")`
+    pub const LANG_NAME: Option<&str> = Some("മലയ\u{d3e}ളം");
+    /// `Some("mal")`
+    pub const LANG_TERM: Option<&str> = Some("mal");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-February-01")`
+    pub const DATE: Option<&str> = Some("2003-February-01");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Malayalam")`
+    pub const LANGUAGE: Option<&str> = Some("Malayalam");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Free Software Foundation of India, Trivandrum")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation of India, Trivandrum");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Malayalam language locale for India")`
+    pub const TITLE: Option<&str> = Some("Malayalam language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNഅ]"`
+    pub const NOEXPR: &str = "^[-0nNഅ]";
+    /// `Some("അല\u{d4d}ല")`
+    pub const NOSTR: Option<&str> = Some("അല\u{d4d}ല");
+    /// `"^[+1yYഉ]"`
+    pub const YESEXPR: &str = "^[+1yYഉ]";
+    /// `Some("ഉവ\u{d4d}വ\u{d4d}")`
+    pub const YESSTR: Option<&str> = Some("ഉവ\u{d4d}വ\u{d4d}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ഞ\u{d3e}", "തി", "ചൊ", "ബ\u{d41}", "വ\u{d4d}യ\u{d3e}", "വെ", "ശ"]`
+    pub const ABDAY: &[&str] = &["ഞ\u{d3e}", "തി", "ചൊ", "ബ\u{d41}", "വ\u{d4d}യ\u{d3e}", "വെ", "ശ"];
+    /// `&["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"]`
+    pub const ABMON: &[&str] = &["ജന\u{d41}", "ഫെബ\u{d4d}ര\u{d41}", "മ\u{d3e}ർ", "ഏപ\u{d4d}രി", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗ", "സെപ\u{d4d}റ\u{d4d}റം", "ഒക\u{d4d}ടോ", "നവം", "ഡിസം"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ര\u{d3e}വിലെ", "വൈക\u{d41}"]`
+    pub const AM_PM: &[&str] = &["ര\u{d3e}വിലെ", "വൈക\u{d41}"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ഞ\u{d3e}യര\u{d4d}\u{200d}", "തിങ\u{d4d}കള\u{d4d}\u{200d}", "ചൊവ\u{d4d}വ", "ബ\u{d41}ധന\u{d4d}\u{200d}", "വ\u{d4d}യ\u{d3e}ഴം", "വെള\u{d4d}ളി", "ശനി"]`
+    pub const DAY: &[&str] = &["ഞ\u{d3e}യര\u{d4d}\u{200d}", "തിങ\u{d4d}കള\u{d4d}\u{200d}", "ചൊവ\u{d4d}വ", "ബ\u{d41}ധന\u{d4d}\u{200d}", "വ\u{d4d}യ\u{d3e}ഴം", "വെള\u{d4d}ളി", "ശനി"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %B %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"]`
+    pub const MON: &[&str] = &["ജന\u{d41}വരി", "ഫെബ\u{d4d}ര\u{d41}വരി", "മ\u{d3e}ർച\u{d4d}ച\u{d4d}", "ഏപ\u{d4d}രിൽ", "മേയ\u{d4d}", "ജ\u{d42}ൺ", "ജ\u{d42}ലൈ", "ഓഗസ\u{d4d}റ\u{d4d}റ\u{d4d}", "സെപ\u{d4d}റ\u{d4d}റംബർ", "ഒക\u{d4d}\u{200c}ടോബർ", "നവംബർ", "ഡിസംബർ"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
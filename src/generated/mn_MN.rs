@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("MNG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MNG");
+    /// `Some("MN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MN");
+    /// `Some("MGL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MGL");
+    /// `Some("99929")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("99929");
+    /// `Some("Монгол")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Монгол");
+    /// `Some(496)`
+    pub const COUNTRY_NUM: Option<i64> = Some(496);
+    /// `Some("MN")`
+    pub const COUNTRY_POST: Option<&str> = Some("MN");
+    /// `Some("mn")`
+    pub const LANG_AB: Option<&str> = Some("mn");
+    /// `Some("mon")`
+    pub const LANG_LIB: Option<&str> = Some("mon");
+    /// `Some("монгол")`
+    pub const LANG_NAME: Option<&str> = Some("монгол");
+    /// `Some("mon")`
+    pub const LANG_TERM: Option<&str> = Some("mon");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2005-05-21")`
+    pub const DATE: Option<&str> = Some("2005-05-21");
+    /// `Some("badral@chinggis.com")`
+    pub const EMAIL: Option<&str> = Some("badral@chinggis.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Mongolian")`
+    pub const LANGUAGE: Option<&str> = Some("Mongolian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Sanlig Badral")`
+    pub const SOURCE: Option<&str> = Some("Sanlig Badral");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Mongolia")`
+    pub const TERRITORY: Option<&str> = Some("Mongolia");
+    /// `Some("Mongolian locale for Mongolia")`
+    pub const TITLE: Option<&str> = Some("Mongolian locale for Mongolia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0үҮnN]"`
+    pub const NOEXPR: &str = "^[-0үҮnN]";
+    /// `Some("үгүй")`
+    pub const NOSTR: Option<&str> = Some("үгүй");
+    /// `"^[+1тТyY]"`
+    pub const YESEXPR: &str = "^[+1тТyY]";
+    /// `Some("тийм")`
+    pub const YESSTR: Option<&str> = Some("тийм");
+}
+pub mod LC_MONETARY {
+    /// `"₮"`
+    pub const CURRENCY_SYMBOL: &str = "₮";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MNT "`
+    pub const INT_CURR_SYMBOL: &str = "MNT ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("976")`
+    pub const INT_PREFIX: Option<&str> = Some("976");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `Some("(0%a) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(0%a) %l");
+    /// `Some("(+%c %a) %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("(+%c %a) %l");
+}
+pub mod LC_TIME {
+    /// `&["Ня", "Да", "Мя", "Лх", "Пү", "Ба", "Бя"]`
+    pub const ABDAY: &[&str] = &["Ня", "Да", "Мя", "Лх", "Пү", "Ба", "Бя"];
+    /// `&["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"]`
+    pub const ABMON: &[&str] = &["1-р сар", "2-р сар", "3-р сар", "4-р сар", "5-р сар", "6-р сар", "7-р сар", "8-р сар", "9-р сар", "10-р сар", "11-р сар", "12-р сар"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Z %Y оны %B %e, %a %H:%M:%S")`
+    pub const DATE_FMT: Option<&str> = Some("%Z %Y оны %B %e, %a %H:%M:%S");
+    /// `&["Ням", "Даваа", "Мягмар", "Лхагва", "Пүрэв", "Баасан", "Бямба"]`
+    pub const DAY: &[&str] = &["Ням", "Даваа", "Мягмар", "Лхагва", "Пүрэв", "Баасан", "Бямба"];
+    /// `"%Y.%m.%d"`
+    pub const D_FMT: &str = "%Y.%m.%d";
+    /// `"%Y %b %d, %a %T"`
+    pub const D_T_FMT: &str = "%Y %b %d, %a %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Нэгдүгээр сар", "Хоёрдугаар сар", "Гуравдугаар сар", "Дөрөвдүгээр сар", "Тавдугаар сар", "Зургадугаар сар", "Долдугаар сар", "Наймдугаар сар", "Есдүгээр сар", "Аравдугаар сар", "Арван нэгдүгээр сар", "Арван хоёрдугаар сар"]`
+    pub const MON: &[&str] = &["Нэгдүгээр сар", "Хоёрдугаар сар", "Гуравдугаар сар", "Дөрөвдүгээр сар", "Тавдугаар сар", "Зургадугаар сар", "Долдугаар сар", "Наймдугаар сар", "Есдүгээр сар", "Аравдугаар сар", "Арван нэгдүгээр сар", "Арван хоёрдугаар сар"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ভ\u{9be}রত")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ভ\u{9be}রত");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("mni")`
+    pub const LANG_LIB: Option<&str> = Some("mni");
+    /// `Some("মৈতৈলোন\u{9cd}")`
+    pub const LANG_NAME: Option<&str> = Some("মৈতৈলোন\u{9cd}");
+    /// `Some("mni")`
+    pub const LANG_TERM: Option<&str> = Some("mni");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ")`
+    pub const ADDRESS: Option<&str> = Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2012-01-17")`
+    pub const DATE: Option<&str> = Some("2012-01-17");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Manipuri")`
+    pub const LANGUAGE: Option<&str> = Some("Manipuri");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Manipuri language locale for India")`
+    pub const TITLE: Option<&str> = Some("Manipuri language locale for India");
+}
+pub use super::en_IN::LC_MESSAGES;
+pub use super::bn_IN::LC_MONETARY;
+pub use super::bn_IN::LC_NUMERIC;
+pub use super::bn_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["নোং", "নিং", "লৈব\u{9be}ক", "য়\u{9c1}ম", "শগোল", "ইর\u{9be}", "থ\u{9be}ং"]`
+    pub const ABDAY: &[&str] = &["নোং", "নিং", "লৈব\u{9be}ক", "য়\u{9c1}ম", "শগোল", "ইর\u{9be}", "থ\u{9be}ং"];
+    /// `&["জ\u{9be}ন", "ফেব", "ম\u{9be}র", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ", "ওক\u{9cd}ত", "নবে", "ডিস"]`
+    pub const ABMON: &[&str] = &["জ\u{9be}ন", "ফেব", "ম\u{9be}র", "এপ\u{9cd}রি", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল", "আগ", "সেপ", "ওক\u{9cd}ত", "নবে", "ডিস"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["এ.ম.", "প.ম."]`
+    pub const AM_PM: &[&str] = &["এ.ম.", "প.ম."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["নোংম\u{9be}ইজিং", "নিংথৌক\u{9be}ব\u{9be}", "লৈব\u{9be}কপোকপ\u{9be}", "য়\u{9c1}মশকৈশ\u{9be}", "শগোলশেন", "ইর\u{9be}ই", "থ\u{9be}ংজ"]`
+    pub const DAY: &[&str] = &["নোংম\u{9be}ইজিং", "নিংথৌক\u{9be}ব\u{9be}", "লৈব\u{9be}কপোকপ\u{9be}", "য়\u{9c1}মশকৈশ\u{9be}", "শগোলশেন", "ইর\u{9be}ই", "থ\u{9be}ংজ"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["জ\u{9be}ন\u{9c1}ৱ\u{9be}রি", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "সেপ\u{9cd}তেম\u{9cd}বর", "ওক\u{9cd}তোবর", "নবেম\u{9cd}বর", "ডিসেম\u{9cd}বর"]`
+    pub const MON: &[&str] = &["জ\u{9be}ন\u{9c1}ৱ\u{9be}রি", "ফেব\u{9cd}র\u{9c1}ৱ\u{9be}রি", "ম\u{9be}র\u{9cd}চ", "এপ\u{9cd}রিল", "মে", "জ\u{9c1}ন", "জ\u{9c1}ল\u{9be}ই", "আগষ\u{9cd}ট", "সেপ\u{9cd}তেম\u{9cd}বর", "ওক\u{9cd}তোবর", "নবেম\u{9cd}বর", "ডিসেম\u{9cd}বর"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
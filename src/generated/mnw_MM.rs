@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("MMR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MMR");
+    /// `Some("MM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MM");
+    /// `Some("MYA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MYA");
+    /// `Some("99971")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("99971");
+    /// `Some("ဗ\u{105f}ာ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ဗ\u{105f}ာ");
+    /// `Some(104)`
+    pub const COUNTRY_NUM: Option<i64> = Some(104);
+    /// `Some("Myanmar")`
+    pub const COUNTRY_POST: Option<&str> = Some("Myanmar");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("mnw")`
+    pub const LANG_LIB: Option<&str> = Some("mnw");
+    /// `Some("မန\u{103a}")`
+    pub const LANG_NAME: Option<&str> = Some("မန\u{103a}");
+    /// `Some("mnw")`
+    pub const LANG_TERM: Option<&str> = Some("mnw");
+    /// `Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Mawlamyine, Mon State, Myanmar")`
+    pub const ADDRESS: Option<&str> = Some("Mawlamyine, Mon State, Myanmar");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Talachan Mon")`
+    pub const CONTACT: Option<&str> = Some("Talachan Mon");
+    /// `Some("2019-10-27")`
+    pub const DATE: Option<&str> = Some("2019-10-27");
+    /// `Some("talachanmon@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("talachanmon@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Mon")`
+    pub const LANGUAGE: Option<&str> = Some("Mon");
+    /// `Some("1")`
+    pub const REVISION: Option<&str> = Some("1");
+    /// `Some("Mon Localization Team https://facebook.com/groups/monlocalization")`
+    pub const SOURCE: Option<&str> = Some("Mon Localization Team https://facebook.com/groups/monlocalization");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Myanmar")`
+    pub const TERRITORY: Option<&str> = Some("Myanmar");
+    /// `Some("Mon language locale for Myanmar")`
+    pub const TITLE: Option<&str> = Some("Mon language locale for Myanmar");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNဟ]"`
+    pub const NOEXPR: &str = "^[-0nNဟ]";
+    /// `Some("ဟ\u{103d}\u{1036}")`
+    pub const NOSTR: Option<&str> = Some("ဟ\u{103d}\u{1036}");
+    /// `"^[+1yYယ]"`
+    pub const YESEXPR: &str = "^[+1yYယ]";
+    /// `Some("ယ\u{103d}\u{1036}")`
+    pub const YESSTR: Option<&str> = Some("ယ\u{103d}\u{1036}");
+}
+pub mod LC_MONETARY {
+    /// `"K"`
+    pub const CURRENCY_SYMBOL: &str = "K";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MMK "`
+    pub const INT_CURR_SYMBOL: &str = "MMK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::my_MM::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ဒ\u{102d}\u{102f}တ\u{103a}", "စန\u{103a}", "ၚာ", "ဝါ", "တ\u{102d}", "သ\u{102d}\u{102f}က\u{103a}", "သဝ\u{103a}"]`
+    pub const ABDAY: &[&str] = &["ဒ\u{102d}\u{102f}တ\u{103a}", "စန\u{103a}", "ၚာ", "ဝါ", "တ\u{102d}", "သ\u{102d}\u{102f}က\u{103a}", "သဝ\u{103a}"];
+    /// `&["ဂျာန\u{103a}", "ဝ\u{103e}ေဝ\u{103a}", "မာတ\u{103a}", "အေ", "မေ", "ဂျ\u{102f}န\u{103a}", "ဂျ\u{1030}", "အဝ\u{103a}", "သ\u{102d}ပ\u{103a}", "အ\u{1036}က\u{103a}", "နဝ\u{103a}", "ဒ\u{1033}"]`
+    pub const ABMON: &[&str] = &["ဂျာန\u{103a}", "ဝ\u{103e}ေဝ\u{103a}", "မာတ\u{103a}", "အေ", "မေ", "ဂျ\u{102f}န\u{103a}", "ဂျ\u{1030}", "အဝ\u{103a}", "သ\u{102d}ပ\u{103a}", "အ\u{1036}က\u{103a}", "နဝ\u{103a}", "ဒ\u{1033}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["၀၀", "၀၁", "၀၂", "၀၃", "၀၄", "၀၅", "၀၆", "၀၇", "၀၈", "၀၉", "၁၀", "၁၁", "၁၂", "၁၃", "၁၄", "၁၅", "၁၆", "၁၇", "၁၈", "၁၉", "၂၀", "၂၁", "၂၂", "၂၃", "၂၄", "၂၅", "၂၆", "၂၇", "၂၈", "၂၉", "၃၀", "၃၁", "၃၂", "၃၃", "၃၄", "၃၅", "၃၆", "၃၇", "၃၈", "၃၉", "၄၀", "၄၁", "၄၂", "၄၃", "၄၄", "၄၅", "၄၆", "၄၇", "၄၈", "၄၉", "၅၀", "၅၁", "၅၂", "၅၃", "၅၄", "၅၅", "၅၆", "၅၇", "၅၈", "၅၉", "၆၀", "၆၁", "၆၂", "၆၃", "၆၄", "၆၅", "၆၆", "၆၇", "၆၈", "၆၉", "၇၀", "၇၁", "၇၂", "၇၃", "၇၄", "၇၅", "၇၆", "၇၇", "၇၈", "၇၉", "၈၀", "၈၁", "၈၂", "၈၃", "၈၄", "၈၅", "၈၆", "၈၇", "၈၈", "၈၉", "၉၀", "၉၁", "၉၂", "၉၃", "၉၄", "၉၅", "၉၆", "၉၇", "၉၈", "၉၉"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(crate::__data::LC_TIME::ALT_DIGITS_1);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ဂယး", "တ\u{1039}ၚ\u{1032}စေၚ\u{103a}"]`
+    pub const AM_PM: &[&str] = &["ဂယး", "တ\u{1039}ၚ\u{1032}စေၚ\u{103a}"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["အဒ\u{102d}\u{102f}တ\u{103a}", "စန\u{103a}", "အၚာ", "ဗ\u{102f}ဒ\u{1039}ဓဝါ", "ဗြ\u{1034}ဗ\u{1039}တ\u{102d}", "သ\u{102d}\u{102f}က\u{103a}", "သ\u{1039}ၚ\u{102d}သဝ\u{103a}"]`
+    pub const DAY: &[&str] = &["အဒ\u{102d}\u{102f}တ\u{103a}", "စန\u{103a}", "အၚာ", "ဗ\u{102f}ဒ\u{1039}ဓဝါ", "ဗြ\u{1034}ဗ\u{1039}တ\u{102d}", "သ\u{102d}\u{102f}က\u{103a}", "သ\u{1039}ၚ\u{102d}သဝ\u{103a}"];
+    /// `"%OC%Oy %b %Od %A"`
+    pub const D_FMT: &str = "%OC%Oy %b %Od %A";
+    /// `"%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z"`
+    pub const D_T_FMT: &str = "%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ဂျာန\u{103a}နျ\u{1030}အာရ\u{1033}", "ဝ\u{103e}ေဝ\u{103a}ဗြ\u{1030}အာရ\u{1033}", "မာတ\u{103a}ချ\u{103a}", "အေပြေယ\u{103a}လ\u{103a}", "မေ", "ဂျ\u{102f}န\u{103a}", "ဂျ\u{1030}လာၚ\u{103a}", "အဝ\u{103a}ဂါတ\u{103a}", "သ\u{102d}ပ\u{103a}တ\u{102e}ဗာ", "အ\u{1036}က\u{103a}တဝ\u{103a}ဗာ", "နဝ\u{103a}ဝါမ\u{103a}ဗာ", "ဒ\u{1033}သ\u{102e}ဗာ"]`
+    pub const MON: &[&str] = &["ဂျာန\u{103a}နျ\u{1030}အာရ\u{1033}", "ဝ\u{103e}ေဝ\u{103a}ဗြ\u{1030}အာရ\u{1033}", "မာတ\u{103a}ချ\u{103a}", "အေပြေယ\u{103a}လ\u{103a}", "မေ", "ဂျ\u{102f}န\u{103a}", "ဂျ\u{1030}လာၚ\u{103a}", "အဝ\u{103a}ဂါတ\u{103a}", "သ\u{102d}ပ\u{103a}တ\u{102e}ဗာ", "အ\u{1036}က\u{103a}တဝ\u{103a}ဗာ", "နဝ\u{103a}ဝါမ\u{103a}ဗာ", "ဒ\u{1033}သ\u{102e}ဗာ"];
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT: &str = "%OI:%OM:%OS %p";
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT_AMPM: &str = "%OI:%OM:%OS %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%OI:%OM:%OS %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%OI:%OM:%OS %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारत")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारत");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("mr")`
+    pub const LANG_AB: Option<&str> = Some("mr");
+    /// `Some("mar")`
+    pub const LANG_LIB: Option<&str> = Some("mar");
+    /// `Some("मराठी")`
+    pub const LANG_NAME: Option<&str> = Some("मराठी");
+    /// `Some("mar")`
+    pub const LANG_TERM: Option<&str> = Some("mar");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-21")`
+    pub const DATE: Option<&str> = Some("2000-07-21");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Marathi")`
+    pub const LANGUAGE: Option<&str> = Some("Marathi");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Marathi language locale for India")`
+    pub const TITLE: Option<&str> = Some("Marathi language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("नाही")`
+    pub const NOSTR: Option<&str> = Some("नाही");
+    /// `"^[+1yYह]"`
+    pub const YESEXPR: &str = "^[+1yYह]";
+    /// `Some("होय")`
+    pub const YESSTR: Option<&str> = Some("होय");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["रवि", "सोम", "म\u{902}गळ", "ब\u{941}ध", "ग\u{941}र\u{941}", "श\u{941}क\u{94d}र", "शनि"]`
+    pub const ABDAY: &[&str] = &["रवि", "सोम", "म\u{902}गळ", "ब\u{941}ध", "ग\u{941}र\u{941}", "श\u{941}क\u{94d}र", "शनि"];
+    /// `&["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"]`
+    pub const ABMON: &[&str] = &["जान\u{947}", "फ\u{947}ब\u{94d}र\u{941}", "मार\u{94d}च", "एप\u{94d}रि", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑग", "सप\u{94d}ट\u{947}\u{902}", "ऑक\u{94d}टो", "नोव\u{94d}ह\u{947}\u{902}", "डिस\u{947}\u{902}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["म.प\u{942}.", "म.न\u{902}."]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_11;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["रविवार", "सोमवार", "म\u{902}गळवार", "ब\u{941}धवार", "ग\u{941}र\u{941}वार", "श\u{941}क\u{94d}रवार", "शनिवार"]`
+    pub const DAY: &[&str] = &["रविवार", "सोमवार", "म\u{902}गळवार", "ब\u{941}धवार", "ग\u{941}र\u{941}वार", "श\u{941}क\u{94d}रवार", "शनिवार"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"]`
+    pub const MON: &[&str] = &["जान\u{947}वारी", "फ\u{947}ब\u{94d}र\u{941}वारी", "मार\u{94d}च", "एप\u{94d}रिल", "म\u{947}", "ज\u{942}न", "ज\u{941}ल\u{948}", "ऑगस\u{94d}ट", "सप\u{94d}ट\u{947}\u{902}बर", "ऑक\u{94d}टोबर", "नोव\u{94d}ह\u{947}\u{902}बर", "डिस\u{947}\u{902}बर"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
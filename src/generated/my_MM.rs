@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `None`
+    pub const COUNTRY_AB3: Option<&str> = None;
+    /// `Some("MM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MM");
+    /// `Some("MYA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MYA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("မြန\u{103a}မာ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("မြန\u{103a}မာ");
+    /// `Some(104)`
+    pub const COUNTRY_NUM: Option<i64> = Some(104);
+    /// `Some("Myanmar")`
+    pub const COUNTRY_POST: Option<&str> = Some("Myanmar");
+    /// `Some("my")`
+    pub const LANG_AB: Option<&str> = Some("my");
+    /// `Some("bur")`
+    pub const LANG_LIB: Option<&str> = Some("bur");
+    /// `Some("မြန\u{103a}မာ")`
+    pub const LANG_NAME: Option<&str> = Some("မြန\u{103a}မာ");
+    /// `Some("mya")`
+    pub const LANG_TERM: Option<&str> = Some("mya");
+    /// `Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Yangon, Myanmar")`
+    pub const ADDRESS: Option<&str> = Some("Yangon, Myanmar");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_NAME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_3);
+    /// `Some("Keith Stribley")`
+    pub const CONTACT: Option<&str> = Some("Keith Stribley");
+    /// `Some("2009-10-02")`
+    pub const DATE: Option<&str> = Some("2009-10-02");
+    /// `Some("devel@thanlwinsoft.org")`
+    pub const EMAIL: Option<&str> = Some("devel@thanlwinsoft.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Burmese")`
+    pub const LANGUAGE: Option<&str> = Some("Burmese");
+    /// `Some("1.3")`
+    pub const REVISION: Option<&str> = Some("1.3");
+    /// `Some("ThanLwinSoft http:/www.thanlwinsoft.org")`
+    pub const SOURCE: Option<&str> = Some("ThanLwinSoft http:/www.thanlwinsoft.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Myanmar (Burma)")`
+    pub const TERRITORY: Option<&str> = Some("Myanmar (Burma)");
+    /// `Some("Burmese language locale for Myanmar")`
+    pub const TITLE: Option<&str> = Some("Burmese language locale for Myanmar");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNမ]"`
+    pub const NOEXPR: &str = "^[-0nNမ]";
+    /// `Some("မဟ\u{102f}တ\u{103a}ဘ\u{1030}း")`
+    pub const NOSTR: Option<&str> = Some("မဟ\u{102f}တ\u{103a}ဘ\u{1030}း");
+    /// `"^[+1yYဟ]"`
+    pub const YESEXPR: &str = "^[+1yYဟ]";
+    /// `Some("ဟ\u{102f}တ\u{103a}တယ\u{103a}")`
+    pub const YESSTR: Option<&str> = Some("ဟ\u{102f}တ\u{103a}တယ\u{103a}");
+}
+pub mod LC_MONETARY {
+    /// `"K"`
+    pub const CURRENCY_SYMBOL: &str = "K";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MMK "`
+    pub const INT_CURR_SYMBOL: &str = "MMK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("95")`
+    pub const INT_PREFIX: Option<&str> = Some("95");
+    /// `Some("0")`
+    pub const INT_SELECT: Option<&str> = Some("0");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["န\u{103d}ေ", "လာ", "ဂါ", "ဟ\u{1030}း", "တေး", "သော", "နေ"]`
+    pub const ABDAY: &[&str] = &["န\u{103d}ေ", "လာ", "ဂါ", "ဟ\u{1030}း", "တေး", "သော", "နေ"];
+    /// `&["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"]`
+    pub const ABMON: &[&str] = &["ဇန\u{103a}", "ဖေ", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}", "ဩ", "စက\u{103a}", "အောက\u{103a}", "န\u{102d}\u{102f}", "ဒ\u{102e}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["၀၀", "၀၁", "၀၂", "၀၃", "၀၄", "၀၅", "၀၆", "၀၇", "၀၈", "၀၉", "၁၀", "၁၁", "၁၂", "၁၃", "၁၄", "၁၅", "၁၆", "၁၇", "၁၈", "၁၉", "၂၀", "၂၁", "၂၂", "၂၃", "၂၄", "၂၅", "၂၆", "၂၇", "၂၈", "၂၉", "၃၀", "၃၁", "၃၂", "၃၃", "၃၄", "၃၅", "၃၆", "၃၇", "၃၈", "၃၉", "၄၀", "၄၁", "၄၂", "၄၃", "၄၄", "၄၅", "၄၆", "၄၇", "၄၈", "၄၉", "၅၀", "၅၁", "၅၂", "၅၃", "၅၄", "၅၅", "၅၆", "၅၇", "၅၈", "၅၉", "၆၀", "၆၁", "၆၂", "၆၃", "၆၄", "၆၅", "၆၆", "၆၇", "၆၈", "၆၉", "၇၀", "၇၁", "၇၂", "၇၃", "၇၄", "၇၅", "၇၆", "၇၇", "၇၈", "၇၉", "၈၀", "၈၁", "၈၂", "၈၃", "၈၄", "၈၅", "၈၆", "၈၇", "၈၈", "၈၉", "၉၀", "၉၁", "၉၂", "၉၃", "၉၄", "၉၅", "၉၆", "၉၇", "၉၈", "၉၉"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(crate::__data::LC_TIME::ALT_DIGITS_1);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["န\u{1036}နက\u{103a}", "ညနေ"]`
+    pub const AM_PM: &[&str] = &["န\u{1036}နက\u{103a}", "ညနေ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["တနင\u{103a}\u{1039}ဂန\u{103d}ေ", "တနင\u{103a}\u{1039}လာ", "အင\u{103a}\u{1039}ဂါ", "ဗ\u{102f}ဒ\u{1039}ဓဟ\u{1030}း", "ကြာသပတေး", "သောကြာ", "စနေ"]`
+    pub const DAY: &[&str] = &["တနင\u{103a}\u{1039}ဂန\u{103d}ေ", "တနင\u{103a}\u{1039}လာ", "အင\u{103a}\u{1039}ဂါ", "ဗ\u{102f}ဒ\u{1039}ဓဟ\u{1030}း", "ကြာသပတေး", "သောကြာ", "စနေ"];
+    /// `"%OC%Oy %b %Od %A"`
+    pub const D_FMT: &str = "%OC%Oy %b %Od %A";
+    /// `"%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z"`
+    pub const D_T_FMT: &str = "%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"]`
+    pub const MON: &[&str] = &["ဇန\u{103a}နဝါရ\u{102e}", "ဖေဖော\u{103a}ဝါရ\u{102e}", "မတ\u{103a}", "ဧပြ\u{102e}", "မေ", "ဇ\u{103d}န\u{103a}", "ဇ\u{1030}လ\u{102d}\u{102f}င\u{103a}", "ဩဂ\u{102f}တ\u{103a}", "စက\u{103a}တင\u{103a}ဘာ", "အောက\u{103a}တ\u{102d}\u{102f}ဘာ", "န\u{102d}\u{102f}ဝင\u{103a}ဘာ", "ဒ\u{102e}ဇင\u{103a}ဘာ"];
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT: &str = "%OI:%OM:%OS %p";
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT_AMPM: &str = "%OI:%OM:%OS %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%OI:%OM:%OS %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%OI:%OM:%OS %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,140 @@
+pub mod LC_ADDRESS {
+    /// `Some("TWN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TWN");
+    /// `Some("TW")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TW");
+    /// `Some("RC")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RC");
+    /// `Some("957")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("957");
+    /// `Some("Tâi-oân")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Tâi-oân");
+    /// `Some(158)`
+    pub const COUNTRY_NUM: Option<i64> = Some(158);
+    /// `Some("TW")`
+    pub const COUNTRY_POST: Option<&str> = Some("TW");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("nan")`
+    pub const LANG_LIB: Option<&str> = Some("nan");
+    /// `Some("Bân-lâm-gú")`
+    pub const LANG_NAME: Option<&str> = Some("Bân-lâm-gú");
+    /// `Some("nan")`
+    pub const LANG_TERM: Option<&str> = Some("nan");
+    /// `Some("%f%N%a%N%d%N%b%N%r %e %h %s%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%r %e %h %s%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_14);
+    /// `Some("Arne Goetje")`
+    pub const CONTACT: Option<&str> = Some("Arne Goetje");
+    /// `Some("2008-06-16")`
+    pub const DATE: Option<&str> = Some("2008-06-16");
+    /// `Some("arne@canonical.com")`
+    pub const EMAIL: Option<&str> = Some("arne@canonical.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Min Nan Chinese")`
+    pub const LANGUAGE: Option<&str> = Some("Min Nan Chinese");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Taiwan")`
+    pub const TERRITORY: Option<&str> = Some("Taiwan");
+    /// `Some("Minnan language locale for Taiwan")`
+    pub const TITLE: Option<&str> = Some("Minnan language locale for Taiwan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNmM]"`
+    pub const NOEXPR: &str = "^[-0nNmM]";
+    /// `Some("M\u{304}-Sī")`
+    pub const NOSTR: Option<&str> = Some("M\u{304}-Sī");
+    /// `"^[+1yYsS]"`
+    pub const YESEXPR: &str = "^[+1yYsS]";
+    /// `Some("Sī")`
+    pub const YESSTR: Option<&str> = Some("Sī");
+}
+pub use super::zh_TW::LC_MONETARY;
+pub use super::zh_TW::LC_NUMERIC;
+pub use super::zh_TW::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["lp", "p1", "p2", "p3", "p4", "p5", "p6"]`
+    pub const ABDAY: &[&str] = &["lp", "p1", "p2", "p3", "p4", "p5", "p6"];
+    /// `&["1g", "2g", "3g", "4g", "5g", "6g", "7g", "8g", "9g", "10g", "11g", "12g"]`
+    pub const ABMON: &[&str] = &["1g", "2g", "3g", "4g", "5g", "6g", "7g", "8g", "9g", "10g", "11g", "12g"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["téng-po\u{358}", "ē-po\u{358}"]`
+    pub const AM_PM: &[&str] = &["téng-po\u{358}", "ē-po\u{358}"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y %b %d (%a) %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y %b %d (%a) %H:%M:%S %Z");
+    /// `&["lé-pài-ji\u{30d}t", "pài-it", "pài-jī", "pài-saⁿ", "pài-sì", "pài-gō\u{358}", "pài-la\u{30d}k"]`
+    pub const DAY: &[&str] = &["lé-pài-ji\u{30d}t", "pài-it", "pài-jī", "pài-saⁿ", "pài-sì", "pài-gō\u{358}", "pài-la\u{30d}k"];
+    /// `"%F"`
+    pub const D_FMT: &str = "%F";
+    /// `"%Y %b %d (%a) %H:%M:%S %Z"`
+    pub const D_T_FMT: &str = "%Y %b %d (%a) %H:%M:%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["1goe\u{30d}h", "2goe\u{30d}h", "3goe\u{30d}h", "4goe\u{30d}h", "5goe\u{30d}h", "6goe\u{30d}h", "7goe\u{30d}h", "8goe\u{30d}h", "9goe\u{30d}h", "10goe\u{30d}h", "11goe\u{30d}h", "12goe\u{30d}h"]`
+    pub const MON: &[&str] = &["1goe\u{30d}h", "2goe\u{30d}h", "3goe\u{30d}h", "4goe\u{30d}h", "5goe\u{30d}h", "6goe\u{30d}h", "7goe\u{30d}h", "8goe\u{30d}h", "9goe\u{30d}h", "10goe\u{30d}h", "11goe\u{30d}h", "12goe\u{30d}h"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
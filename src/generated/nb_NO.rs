@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("NOR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NOR");
+    /// `Some("NO")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NO");
+    /// `Some("N")`
+    pub const COUNTRY_CAR: Option<&str> = Some("N");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Norge")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Norge");
+    /// `Some(578)`
+    pub const COUNTRY_NUM: Option<i64> = Some(578);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("nb")`
+    pub const LANG_AB: Option<&str> = Some("nb");
+    /// `Some("nob")`
+    pub const LANG_LIB: Option<&str> = Some("nob");
+    /// `Some("norsk bokmål")`
+    pub const LANG_NAME: Option<&str> = Some("norsk bokmål");
+    /// `Some("nob")`
+    pub const LANG_TERM: Option<&str> = Some("nob");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("University Library, Drammensveien 41, N-9242 Oslo, Norge")`
+    pub const ADDRESS: Option<&str> = Some("University Library, Drammensveien 41, N-9242 Oslo, Norge");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Norwegian Bokmål")`
+    pub const LANGUAGE: Option<&str> = Some("Norwegian Bokmål");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Norsk Standardiseringsforbund")`
+    pub const SOURCE: Option<&str> = Some("Norsk Standardiseringsforbund");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Norway")`
+    pub const TERRITORY: Option<&str> = Some("Norway");
+    /// `Some("Norwegian (Bokmål) locale for Norway")`
+    pub const TITLE: Option<&str> = Some("Norwegian (Bokmål) locale for Norway");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0Nn]"`
+    pub const NOEXPR: &str = "^[-0Nn]";
+    /// `Some("nei")`
+    pub const NOSTR: Option<&str> = Some("nei");
+    /// `"^[+1JjYy]"`
+    pub const YESEXPR: &str = "^[+1JjYy]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub mod LC_MONETARY {
+    /// `"kr"`
+    pub const CURRENCY_SYMBOL: &str = "kr";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"NOK "`
+    pub const INT_CURR_SYMBOL: &str = "NOK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("47")`
+    pub const INT_PREFIX: Option<&str> = Some("47");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%l");
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["sø.", "ma.", "ti.", "on.", "to.", "fr.", "lø."]`
+    pub const ABDAY: &[&str] = &["sø.", "ma.", "ti.", "on.", "to.", "fr.", "lø."];
+    /// `&["jan.", "feb.", "mars", "april", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_19;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %d. %b %H:%M:%S %z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %d. %b %H:%M:%S %z %Y");
+    /// `&["søndag", "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_14;
+    /// `"%d. %b %Y"`
+    pub const D_FMT: &str = "%d. %b %Y";
+    /// `"%a %d. %b %Y kl. %H.%M %z"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y kl. %H.%M %z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_13;
+    /// `"kl. %H.%M %z"`
+    pub const T_FMT: &str = "kl. %H.%M %z";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("NPL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NPL");
+    /// `Some("NP")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NP");
+    /// `Some("NEP")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NEP");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("न\u{947}पाल")`
+    pub const COUNTRY_NAME: Option<&str> = Some("न\u{947}पाल");
+    /// `Some(524)`
+    pub const COUNTRY_NUM: Option<i64> = Some(524);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ne")`
+    pub const LANG_AB: Option<&str> = Some("ne");
+    /// `Some("nep")`
+    pub const LANG_LIB: Option<&str> = Some("nep");
+    /// `Some("न\u{947}पाली")`
+    pub const LANG_NAME: Option<&str> = Some("न\u{947}पाली");
+    /// `Some("nep")`
+    pub const LANG_TERM: Option<&str> = Some("nep");
+    /// `Some("%f%N%h%s%N%T")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%h%s%N%T");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Rato Bangala, Patan Dhoka,Lalitpur,Nepal")`
+    pub const ADDRESS: Option<&str> = Some("Rato Bangala, Patan Dhoka,Lalitpur,Nepal");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-05-12")`
+    pub const DATE: Option<&str> = Some("2003-05-12");
+    /// `Some("info@mpp.org.np")`
+    pub const EMAIL: Option<&str> = Some("info@mpp.org.np");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Nepali")`
+    pub const LANGUAGE: Option<&str> = Some("Nepali");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Madan Puraskar Pustakalaya")`
+    pub const SOURCE: Option<&str> = Some("Madan Puraskar Pustakalaya");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nepal")`
+    pub const TERRITORY: Option<&str> = Some("Nepal");
+    /// `Some("Nepali language locale for Nepal")`
+    pub const TITLE: Option<&str> = Some("Nepali language locale for Nepal");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("होइन")`
+    pub const NOSTR: Option<&str> = Some("होइन");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("हो")`
+    pub const YESSTR: Option<&str> = Some("हो");
+}
+pub mod LC_MONETARY {
+    /// `"र\u{942}"`
+    pub const CURRENCY_SYMBOL: &str = "र\u{942}";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"NPR "`
+    pub const INT_CURR_SYMBOL: &str = "NPR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("977")`
+    pub const INT_PREFIX: Option<&str> = Some("977");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a%t%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a%t%l");
+}
+pub mod LC_TIME {
+    /// `&["आइत", "सोम", "मङ\u{94d}गल", "ब\u{941}ध", "बिही", "श\u{941}क\u{94d}र", "शनि"]`
+    pub const ABDAY: &[&str] = &["आइत", "सोम", "मङ\u{94d}गल", "ब\u{941}ध", "बिही", "श\u{941}क\u{94d}र", "शनि"];
+    /// `&["जन", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"]`
+    pub const ABMON: &[&str] = &["जन", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["आइतबार", "सोमबार", "मङ\u{94d}गलबार", "ब\u{941}धबार", "बिहीबार", "श\u{941}क\u{94d}रबार", "शनिबार"]`
+    pub const DAY: &[&str] = &["आइतबार", "सोमबार", "मङ\u{94d}गलबार", "ब\u{941}धबार", "बिहीबार", "श\u{941}क\u{94d}रबार", "शनिबार"];
+    /// `"%y/%-m/%-d"`
+    pub const D_FMT: &str = "%y/%-m/%-d";
+    /// `"%Y %B %d %I:%M:%S %p"`
+    pub const D_T_FMT: &str = "%Y %B %d %I:%M:%S %p";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"]`
+    pub const MON: &[&str] = &["जनवरी", "फ\u{947}ब\u{94d}र\u{941}अरी", "मार\u{94d}च", "अप\u{94d}रिल", "म\u{947}", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}ट", "स\u{947}प\u{94d}ट\u{947}म\u{94d}बर", "अक\u{94d}टोबर", "नोभ\u{947}म\u{94d}बर", "डिस\u{947}म\u{94d}बर"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
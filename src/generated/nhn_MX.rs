@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("MEX")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MEX");
+    /// `Some("MX")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MX");
+    /// `Some("MEX")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MEX");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Mexihco")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Mexihco");
+    /// `Some(484)`
+    pub const COUNTRY_NUM: Option<i64> = Some(484);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("nhn")`
+    pub const LANG_LIB: Option<&str> = Some("nhn");
+    /// `Some("Tlahco nāhuatlahtōlli")`
+    pub const LANG_NAME: Option<&str> = Some("Tlahco nāhuatlahtōlli");
+    /// `Some("nhn")`
+    pub const LANG_TERM: Option<&str> = Some("nhn");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_8);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2012-08-19")`
+    pub const DATE: Option<&str> = Some("2012-08-19");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Central Nahuatl")`
+    pub const LANGUAGE: Option<&str> = Some("Central Nahuatl");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Mexico")`
+    pub const TERRITORY: Option<&str> = Some("Mexico");
+    /// `Some("Central Nahuatl for Mexico")`
+    pub const TITLE: Option<&str> = Some("Central Nahuatl for Mexico");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNaA]"`
+    pub const NOEXPR: &str = "^[-0nNaA]";
+    /// `Some("ahmo")`
+    pub const NOSTR: Option<&str> = Some("ahmo");
+    /// `"^[+1sSqQyY]"`
+    pub const YESEXPR: &str = "^[+1sSqQyY]";
+    /// `Some("quema")`
+    pub const YESSTR: Option<&str> = Some("quema");
+}
+pub use super::es_MX::LC_MONETARY;
+pub use super::es_MX::LC_NUMERIC;
+pub use super::es_MX::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["teo", "cei", "ome", "yei", "nau", "mac", "chi"]`
+    pub const ABDAY: &[&str] = &["teo", "cei", "ome", "yei", "nau", "mac", "chi"];
+    /// `&["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_11;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["teoilhuitl", "ceilhuitl", "omeilhuitl", "yeilhuitl", "nahuilhuitl", "macuililhuitl", "chicuaceilhuitl"]`
+    pub const DAY: &[&str] = &["teoilhuitl", "ceilhuitl", "omeilhuitl", "yeilhuitl", "nahuilhuitl", "macuililhuitl", "chicuaceilhuitl"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_9;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,147 @@
+pub mod LC_ADDRESS {
+    /// `Some("NIU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NIU");
+    /// `Some("NU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NU");
+    /// `None`
+    pub const COUNTRY_CAR: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Niuē")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Niuē");
+    /// `Some(570)`
+    pub const COUNTRY_NUM: Option<i64> = Some(570);
+    /// `Some("NU")`
+    pub const COUNTRY_POST: Option<&str> = Some("NU");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("niu")`
+    pub const LANG_LIB: Option<&str> = Some("niu");
+    /// `Some("ko e vagahau Niuē")`
+    pub const LANG_NAME: Option<&str> = Some("ko e vagahau Niuē");
+    /// `Some("niu")`
+    pub const LANG_TERM: Option<&str> = Some("niu");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("PO Box 229, Fatalupe, Alofi, Niue")`
+    pub const ADDRESS: Option<&str> = Some("PO Box 229, Fatalupe, Alofi, Niue");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Emani Fakaotimanava-Lui")`
+    pub const CONTACT: Option<&str> = Some("Emani Fakaotimanava-Lui");
+    /// `Some("2012-07-18")`
+    pub const DATE: Option<&str> = Some("2012-07-18");
+    /// `Some("emani@niue.nu")`
+    pub const EMAIL: Option<&str> = Some("emani@niue.nu");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Niuean")`
+    pub const LANGUAGE: Option<&str> = Some("Niuean");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RockET Systems")`
+    pub const SOURCE: Option<&str> = Some("RockET Systems");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Niue")`
+    pub const TERRITORY: Option<&str> = Some("Niue");
+    /// `Some("Niuean (Vagahau Niue) locale for Niue")`
+    pub const TITLE: Option<&str> = Some("Niuean (Vagahau Niue) locale for Niue");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Nakai")`
+    pub const NOSTR: Option<&str> = Some("Nakai");
+    /// `"^[+1yYēĒ]"`
+    pub const YESEXPR: &str = "^[+1yYēĒ]";
+    /// `Some("Talia")`
+    pub const YESSTR: Option<&str> = Some("Talia");
+}
+pub use super::en_NZ::LC_MONETARY;
+pub use super::en_NZ::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("683")`
+    pub const INT_PREFIX: Option<&str> = Some("683");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Tapu", "Gofua", "Ua", "Lotu", "Tuloto", "Falaile", "Faiumu"]`
+    pub const ABDAY: &[&str] = &["Tapu", "Gofua", "Ua", "Lotu", "Tuloto", "Falaile", "Faiumu"];
+    /// `&["Ian", "Fep", "Mas", "Ape", "Me", "Iun", "Iul", "Aok", "Sep", "Oke", "Nov", "Tes"]`
+    pub const ABMON: &[&str] = &["Ian", "Fep", "Mas", "Ape", "Me", "Iun", "Iul", "Aok", "Sep", "Oke", "Nov", "Tes"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Aho Tapu", "Aho Gofua", "Aho Ua", "Aho Lotu", "Aho Tuloto", "Aho Falaile", "Aho Faiumu"]`
+    pub const DAY: &[&str] = &["Aho Tapu", "Aho Gofua", "Aho Ua", "Aho Lotu", "Aho Tuloto", "Aho Falaile", "Aho Faiumu"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ianuali", "Fepuali", "Masi", "Apelila", "Me", "Iuni", "Iulai", "Aokuso", "Sepetema", "Oketopa", "Novema", "Tesemo"]`
+    pub const MON: &[&str] = &["Ianuali", "Fepuali", "Masi", "Apelila", "Me", "Iuni", "Iulai", "Aokuso", "Sepetema", "Oketopa", "Novema", "Tesemo"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,74 @@
+pub mod LC_ADDRESS {
+    /// `Some("NZL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NZL");
+    /// `Some("NZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NZ");
+    /// `Some("NZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NAME: Option<&str> = None;
+    /// `Some(554)`
+    pub const COUNTRY_NUM: Option<i64> = Some(554);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("niu")`
+    pub const LANG_LIB: Option<&str> = Some("niu");
+    /// `Some("ko e vagahau Niuē")`
+    pub const LANG_NAME: Option<&str> = Some("ko e vagahau Niuē");
+    /// `Some("niu")`
+    pub const LANG_TERM: Option<&str> = Some("niu");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("PO Box 229, Fatalupe, Alofi, Niue")`
+    pub const ADDRESS: Option<&str> = Some("PO Box 229, Fatalupe, Alofi, Niue");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Emani Fakaotimanava-Lui")`
+    pub const CONTACT: Option<&str> = Some("Emani Fakaotimanava-Lui");
+    /// `Some("2012-07-18")`
+    pub const DATE: Option<&str> = Some("2012-07-18");
+    /// `Some("emani@niue.nu")`
+    pub const EMAIL: Option<&str> = Some("emani@niue.nu");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Niuean")`
+    pub const LANGUAGE: Option<&str> = Some("Niuean");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RockET Systems")`
+    pub const SOURCE: Option<&str> = Some("RockET Systems");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("New Zealand")`
+    pub const TERRITORY: Option<&str> = Some("New Zealand");
+    /// `Some("Niuean (Vagahau Niue) locale for New Zealand")`
+    pub const TITLE: Option<&str> = Some("Niuean (Vagahau Niue) locale for New Zealand");
+}
+pub use super::niu_NU::LC_MESSAGES;
+pub use super::en_NZ::LC_MONETARY;
+pub use super::en_NZ::LC_NUMERIC;
+pub use super::en_NZ::LC_TELEPHONE;
+pub use super::niu_NU::LC_TIME;
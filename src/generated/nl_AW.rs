@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("ABW")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ABW");
+    /// `Some("AW")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AW");
+    /// `Some("AUA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AUA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Aruba")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Aruba");
+    /// `Some(533)`
+    pub const COUNTRY_NUM: Option<i64> = Some(533);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("nl")`
+    pub const LANG_AB: Option<&str> = Some("nl");
+    /// `Some("dut")`
+    pub const LANG_LIB: Option<&str> = Some("dut");
+    /// `Some("Nederlands")`
+    pub const LANG_NAME: Option<&str> = Some("Nederlands");
+    /// `Some("nld")`
+    pub const LANG_TERM: Option<&str> = Some("nld");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2008-09-16")`
+    pub const DATE: Option<&str> = Some("2008-09-16");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Dutch")`
+    pub const LANGUAGE: Option<&str> = Some("Dutch");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Aruba")`
+    pub const TERRITORY: Option<&str> = Some("Aruba");
+    /// `Some("Dutch language locale for Aruba")`
+    pub const TITLE: Option<&str> = Some("Dutch language locale for Aruba");
+}
+pub use super::nl_NL::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"Afl."`
+    pub const CURRENCY_SYMBOL: &str = "Afl.";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"AWG "`
+    pub const INT_CURR_SYMBOL: &str = "AWG ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `2`
+    pub const N_SEP_BY_SPACE: i64 = 2;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::nl_NL::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("297")`
+    pub const INT_PREFIX: Option<&str> = Some("297");
+    /// `Some("011")`
+    pub const INT_SELECT: Option<&str> = Some("011");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["zo", "ma", "di", "wo", "do", "vr", "za"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_14;
+    /// `&["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_16;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %Y %k:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %Y %k:%M:%S %Z");
+    /// `&["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_15;
+    /// `"%d-%m-%y"`
+    pub const D_FMT: &str = "%d-%m-%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_14;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("BEL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BEL");
+    /// `Some("BE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BE");
+    /// `Some("B")`
+    pub const COUNTRY_CAR: Option<&str> = Some("B");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("België")`
+    pub const COUNTRY_NAME: Option<&str> = Some("België");
+    /// `Some(56)`
+    pub const COUNTRY_NUM: Option<i64> = Some(56);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("nl")`
+    pub const LANG_AB: Option<&str> = Some("nl");
+    /// `Some("dut")`
+    pub const LANG_LIB: Option<&str> = Some("dut");
+    /// `Some("Nederlands")`
+    pub const LANG_NAME: Option<&str> = Some("Nederlands");
+    /// `Some("nld")`
+    pub const LANG_TERM: Option<&str> = Some("nld");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Flemish")`
+    pub const LANGUAGE: Option<&str> = Some("Flemish");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Belgium")`
+    pub const TERRITORY: Option<&str> = Some("Belgium");
+    /// `Some("Dutch locale for Belgium")`
+    pub const TITLE: Option<&str> = Some("Dutch locale for Belgium");
+}
+pub use super::nl_NL::LC_MESSAGES;
+pub use super::nl_NL::LC_MONETARY;
+pub use super::fr_BE::LC_NUMERIC;
+pub use super::fr_BE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["zo", "ma", "di", "wo", "do", "vr", "za"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_14;
+    /// `&["jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_16;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["zondag", "maandag", "dinsdag", "woensdag", "donderdag", "vrijdag", "zaterdag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_15;
+    /// `"%d-%m-%y"`
+    pub const D_FMT: &str = "%d-%m-%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_14;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
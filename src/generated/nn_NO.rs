@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("NOR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NOR");
+    /// `Some("NO")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NO");
+    /// `Some("N")`
+    pub const COUNTRY_CAR: Option<&str> = Some("N");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Noreg")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Noreg");
+    /// `Some(578)`
+    pub const COUNTRY_NUM: Option<i64> = Some(578);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("nn")`
+    pub const LANG_AB: Option<&str> = Some("nn");
+    /// `Some("nno")`
+    pub const LANG_LIB: Option<&str> = Some("nno");
+    /// `Some("nynorsk")`
+    pub const LANG_NAME: Option<&str> = Some("nynorsk");
+    /// `Some("nno")`
+    pub const LANG_TERM: Option<&str> = Some("nno");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-08-31")`
+    pub const DATE: Option<&str> = Some("2000-08-31");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Norwegian Nynorsk")`
+    pub const LANGUAGE: Option<&str> = Some("Norwegian Nynorsk");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Norway")`
+    pub const TERRITORY: Option<&str> = Some("Norway");
+    /// `Some("Nynorsk language locale for Norway")`
+    pub const TITLE: Option<&str> = Some("Nynorsk language locale for Norway");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0Nn]"`
+    pub const NOEXPR: &str = "^[-0Nn]";
+    /// `Some("nei")`
+    pub const NOSTR: Option<&str> = Some("nei");
+    /// `"^[+1JjYy]"`
+    pub const YESEXPR: &str = "^[+1JjYy]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub mod LC_MONETARY {
+    /// `"kr"`
+    pub const CURRENCY_SYMBOL: &str = "kr";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"NOK "`
+    pub const INT_CURR_SYMBOL: &str = "NOK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `3`
+    pub const N_SIGN_POSN: i64 = 3;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::nb_NO::LC_NUMERIC;
+pub use super::nb_NO::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["su.", "må.", "ty.", "on.", "to.", "fr.", "la."]`
+    pub const ABDAY: &[&str] = &["su.", "må.", "ty.", "on.", "to.", "fr.", "la."];
+    /// `&["jan.", "feb.", "mars", "april", "mai", "juni", "juli", "aug.", "sep.", "okt.", "nov.", "des."]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_19;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %d. %b %H:%M:%S %z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %d. %b %H:%M:%S %z %Y");
+    /// `&["sundag ", "måndag ", "tysdag ", "onsdag ", "torsdag ", "fredag ", "laurdag "]`
+    pub const DAY: &[&str] = &["sundag ", "måndag ", "tysdag ", "onsdag ", "torsdag ", "fredag ", "laurdag "];
+    /// `"%d. %b %Y"`
+    pub const D_FMT: &str = "%d. %b %Y";
+    /// `"%a %d. %b %Y kl. %H.%M %z"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y kl. %H.%M %z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januar", "februar", "mars", "april", "mai", "juni", "juli", "august", "september", "oktober", "november", "desember"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_13;
+    /// `"kl. %H.%M %z"`
+    pub const T_FMT: &str = "kl. %H.%M %z";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,151 @@
+pub mod LC_ADDRESS {
+    /// `Some("FRA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FRA");
+    /// `Some("FR")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FR");
+    /// `Some("F")`
+    pub const COUNTRY_CAR: Option<&str> = Some("F");
+    /// `Some("979-10")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("979-10");
+    /// `Some("França")`
+    pub const COUNTRY_NAME: Option<&str> = Some("França");
+    /// `Some(250)`
+    pub const COUNTRY_NUM: Option<i64> = Some(250);
+    /// `Some("F")`
+    pub const COUNTRY_POST: Option<&str> = Some("F");
+    /// `Some("oc")`
+    pub const LANG_AB: Option<&str> = Some("oc");
+    /// `Some("oci")`
+    pub const LANG_LIB: Option<&str> = Some("oci");
+    /// `Some("occitan")`
+    pub const LANG_NAME: Option<&str> = Some("occitan");
+    /// `Some("oci")`
+    pub const LANG_TERM: Option<&str> = Some("oci");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Los Genets, 12290 Pont de Salars")`
+    pub const ADDRESS: Option<&str> = Some("Los Genets, 12290 Pont de Salars");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+        pub const CATEGORY: Option<&[&[&str]]> = Some(&[
+        &["i18n:2012", "LC_IDENTIFICATION"],
+        &["i18n:2012", "LC_ADDRESS"],
+        &["i18n:2012", "LC_CTYPE"],
+        &["i18n:2012", "LC_COLLATE"],
+        &["i18n:2012", "LC_MESSAGES"],
+        &["i18n:2012", "LC_MONETARY"],
+        &["i18n:2012", "LC_NAME"],
+        &["i18n:2012", "LC_NUMERIC"],
+        &["i18n:2012", "LC_PAPER"],
+        &["i18n:2012", "LC_MEASUREMENT"],
+        &["i18n:2012", "LC_TIME"],
+        &["i18n:2012", "LC_TELEPHONE"],
+    ]);
+    /// `Some("Jean-Paul Fraysse")`
+    pub const CONTACT: Option<&str> = Some("Jean-Paul Fraysse");
+    /// `Some("2018-04-12")`
+    pub const DATE: Option<&str> = Some("2018-04-12");
+    /// `Some("Jean-Paul.Fraysse@wanadoo.fr")`
+    pub const EMAIL: Option<&str> = Some("Jean-Paul.Fraysse@wanadoo.fr");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Occitan")`
+    pub const LANGUAGE: Option<&str> = Some("Occitan");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("France")`
+    pub const TERRITORY: Option<&str> = Some("France");
+    /// `Some("Occitan Language Locale for France")`
+    pub const TITLE: Option<&str> = Some("Occitan Language Locale for France");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1oOsSyY]"`
+    pub const YESEXPR: &str = "^[+1oOsSyY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::fr_FR::LC_MONETARY;
+pub use super::fr_FR::LC_NUMERIC;
+pub use super::fr_FR::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["dg.", "dl.", "dm.", "dc.", "dj.", "dv.", "ds."]`
+    pub const ABDAY: &[&str] = &["dg.", "dl.", "dm.", "dc.", "dj.", "dv.", "ds."];
+    /// `&["gen.", "febr.", "març", "abr.", "mai", "junh", "jul.", "ago.", "set.", "oct.", "nov.", "dec."]`
+    pub const ABMON: &[&str] = &["gen.", "febr.", "març", "abr.", "mai", "junh", "jul.", "ago.", "set.", "oct.", "nov.", "dec."];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["genièr", "febrièr", "març", "abrial", "mai", "junh", "julhet", "agost", "setembre", "octòbre", "novembre", "decembre"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["genièr", "febrièr", "març", "abrial", "mai", "junh", "julhet", "agost", "setembre", "octòbre", "novembre", "decembre"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dimenge", "diluns", "dimars", "dimècres", "dijóus", "divendres", "dissabte"]`
+    pub const DAY: &[&str] = &["dimenge", "diluns", "dimars", "dimècres", "dijóus", "divendres", "dissabte"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["de genièr", "de febrièr", "de març", "d’abrial", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"]`
+    pub const MON: &[&str] = &["de genièr", "de febrièr", "de març", "d’abrial", "de mai", "de junh", "de julhet", "d’agost", "de setembre", "d’octòbre", "de novembre", "de decembre"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
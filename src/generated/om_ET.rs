@@ -0,0 +1,141 @@
+pub mod LC_ADDRESS {
+    /// `Some("ETH")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ETH");
+    /// `Some("ET")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ET");
+    /// `Some("ETH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ETH");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Itoophiyaa")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Itoophiyaa");
+    /// `Some(231)`
+    pub const COUNTRY_NUM: Option<i64> = Some(231);
+    /// `Some("ETH")`
+    pub const COUNTRY_POST: Option<&str> = Some("ETH");
+    /// `Some("om")`
+    pub const LANG_AB: Option<&str> = Some("om");
+    /// `Some("orm")`
+    pub const LANG_LIB: Option<&str> = Some("orm");
+    /// `Some("Oromoo")`
+    pub const LANG_NAME: Option<&str> = Some("Oromoo");
+    /// `Some("orm")`
+    pub const LANG_TERM: Option<&str> = Some("orm");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_1);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Oromo")`
+    pub const LANGUAGE: Option<&str> = Some("Oromo");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation & Sagalee Oromoo Publishing Co. Inc.")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation & Sagalee Oromoo Publishing Co. Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Ethiopia")`
+    pub const TERRITORY: Option<&str> = Some("Ethiopia");
+    /// `Some("Oromo language locale for Ethiopia.")`
+    pub const TITLE: Option<&str> = Some("Oromo language locale for Ethiopia.");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0mMnN]"`
+    pub const NOEXPR: &str = "^[-0mMnN]";
+    /// `Some("miti")`
+    pub const NOSTR: Option<&str> = Some("miti");
+    /// `"^[+1eEyY]"`
+    pub const YESEXPR: &str = "^[+1eEyY]";
+    /// `Some("eeyyee")`
+    pub const YESSTR: Option<&str> = Some("eeyyee");
+}
+pub use super::ti_ET::LC_MONETARY;
+pub use super::ti_ET::LC_NUMERIC;
+pub use super::ti_ET::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Dil", "Wix", "Qib", "Rob", "Kam", "Jim", "San"]`
+    pub const ABDAY: &[&str] = &["Dil", "Wix", "Qib", "Rob", "Kam", "Jim", "San"];
+    /// `&["Ama", "Gur", "Bit", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sad", "Mud"]`
+    pub const ABMON: &[&str] = &["Ama", "Gur", "Bit", "Elb", "Cam", "Wax", "Ado", "Hag", "Ful", "Onk", "Sad", "Mud"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["WD", "WB"]`
+    pub const AM_PM: &[&str] = &["WD", "WB"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A, %B %e, %r %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %B %e, %r %Z %Y");
+    /// `&["Dilbata", "Wiixata", "Qibxata", "Roobii", "Kamiisa", "Jimaata", "Sanbata"]`
+    pub const DAY: &[&str] = &["Dilbata", "Wiixata", "Qibxata", "Roobii", "Kamiisa", "Jimaata", "Sanbata"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A, %B %e, %Y %l:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A, %B %e, %Y %l:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Amajjii", "Guraandhala", "Bitooteessa", "Elba", "Caamsa", "Waxabajjii", "Adooleessa", "Hagayya", "Fuulbana", "Onkololeessa", "Sadaasa", "Muddee"]`
+    pub const MON: &[&str] = &["Amajjii", "Guraandhala", "Bitooteessa", "Elba", "Caamsa", "Waxabajjii", "Adooleessa", "Hagayya", "Fuulbana", "Onkololeessa", "Sadaasa", "Muddee"];
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%A, %B %e, %Y %r %Z"`
+        pub const D_T_FMT: &str = "%A, %B %e, %Y %r %Z";
+        /// `Some("%l:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
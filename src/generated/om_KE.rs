@@ -0,0 +1,133 @@
+pub mod LC_ADDRESS {
+    /// `Some("KEN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KEN");
+    /// `Some("KE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KE");
+    /// `Some("EAK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("EAK");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Keeniyaa")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Keeniyaa");
+    /// `Some(404)`
+    pub const COUNTRY_NUM: Option<i64> = Some(404);
+    /// `Some("KEN")`
+    pub const COUNTRY_POST: Option<&str> = Some("KEN");
+    /// `Some("om")`
+    pub const LANG_AB: Option<&str> = Some("om");
+    /// `Some("orm")`
+    pub const LANG_LIB: Option<&str> = Some("orm");
+    /// `Some("Oromoo")`
+    pub const LANG_NAME: Option<&str> = Some("Oromoo");
+    /// `Some("orm")`
+    pub const LANG_TERM: Option<&str> = Some("orm");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_1);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Oromo")`
+    pub const LANGUAGE: Option<&str> = Some("Oromo");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation & Sagalee Oromoo Publishing Co. Inc.")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation & Sagalee Oromoo Publishing Co. Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Kenya")`
+    pub const TERRITORY: Option<&str> = Some("Kenya");
+    /// `Some("Oromo language locale for Kenya.")`
+    pub const TITLE: Option<&str> = Some("Oromo language locale for Kenya.");
+}
+pub use super::om_ET::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"Ksh"`
+    pub const CURRENCY_SYMBOL: &str = "Ksh";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"KES "`
+    pub const INT_CURR_SYMBOL: &str = "KES ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("254")`
+    pub const INT_PREFIX: Option<&str> = Some("254");
+    /// `Some("000")`
+    pub const INT_SELECT: Option<&str> = Some("000");
+    /// `Some("%a-%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a-%l");
+    /// `Some("%c-%a-%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("%c-%a-%l");
+}
+pub use super::om_ET::LC_TIME;
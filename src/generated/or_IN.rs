@@ -0,0 +1,154 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ଭ\u{b3e}ରତ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ଭ\u{b3e}ରତ");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("or")`
+    pub const LANG_AB: Option<&str> = Some("or");
+    /// `Some("ori")`
+    pub const LANG_LIB: Option<&str> = Some("ori");
+    /// `Some("ଓଡ\u{b3c}\u{b3f}ଆ")`
+    pub const LANG_NAME: Option<&str> = Some("ଓଡ\u{b3c}\u{b3f}ଆ");
+    /// `Some("ori")`
+    pub const LANG_TERM: Option<&str> = Some("ori");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2006-05-25")`
+    pub const DATE: Option<&str> = Some("2006-05-25");
+    /// `Some("bug-glibc@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Odia")`
+    pub const LANGUAGE: Option<&str> = Some("Odia");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM AP Linux Technology Center, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM AP Linux Technology Center, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Odia language locale for India")`
+    pub const TITLE: Option<&str> = Some("Odia language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNନ]"`
+    pub const NOEXPR: &str = "^[-0nNନ]";
+    /// `Some("ନ\u{b3e}")`
+    pub const NOSTR: Option<&str> = Some("ନ\u{b3e}");
+    /// `"^[+1yYହ]"`
+    pub const YESEXPR: &str = "^[+1yYହ]";
+    /// `Some("ହ\u{b01}")`
+    pub const YESSTR: Option<&str> = Some("ହ\u{b01}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("91")`
+    pub const INT_PREFIX: Option<&str> = Some("91");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["ରବ\u{b3f}", "ସୋମ", "ମଙ\u{b4d}ଗଳ", "ବ\u{b41}ଧ", "ଗ\u{b41}ର\u{b41}", "ଶ\u{b41}କ\u{b4d}ର", "ଶନ\u{b3f}"]`
+    pub const ABDAY: &[&str] = &["ରବ\u{b3f}", "ସୋମ", "ମଙ\u{b4d}ଗଳ", "ବ\u{b41}ଧ", "ଗ\u{b41}ର\u{b41}", "ଶ\u{b41}କ\u{b4d}ର", "ଶନ\u{b3f}"];
+    /// `&["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"]`
+    pub const ABMON: &[&str] = &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["୦", "୧", "୨", "୩", "୪", "୫", "୬", "୭", "୮", "୯", "୧୦", "୧୧", "୧୨", "୧୩", "୧୪", "୧୫", "୧୬", "୧୭", "୧୮", "୧୯", "୨୦", "୨୧", "୨୨", "୨୩", "୨୪", "୨୫", "୨୬", "୨୭", "୨୮", "୨୯", "୩୦", "୩୧", "୩୨", "୩୩", "୩୪", "୩୫", "୩୬", "୩୭", "୩୮", "୩୯", "୪୦", "୪୧", "୪୨", "୪୩", "୪୪", "୪୫", "୪୬", "୪୭", "୪୮", "୪୯", "୫୦", "୫୧", "୫୨", "୫୩", "୫୪", "୫୫", "୫୬", "୫୭", "୫୮", "୫୯", "୬୦", "୬୧", "୬୨", "୬୩", "୬୪", "୬୫", "୬୬", "୬୭", "୬୮", "୬୯", "୭୦", "୭୧", "୭୨", "୭୩", "୭୪", "୭୫", "୭୬", "୭୭", "୭୮", "୭୯", "୮୦", "୮୧", "୮୨", "୮୩", "୮୪", "୮୫", "୮୬", "୮୭", "୮୮", "୮୯", "୯୦", "୯୧", "୯୨", "୯୩", "୯୪", "୯୫", "୯୬", "୯୭", "୯୮", "୯୯"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(&["୦", "୧", "୨", "୩", "୪", "୫", "୬", "୭", "୮", "୯", "୧୦", "୧୧", "୧୨", "୧୩", "୧୪", "୧୫", "୧୬", "୧୭", "୧୮", "୧୯", "୨୦", "୨୧", "୨୨", "୨୩", "୨୪", "୨୫", "୨୬", "୨୭", "୨୮", "୨୯", "୩୦", "୩୧", "୩୨", "୩୩", "୩୪", "୩୫", "୩୬", "୩୭", "୩୮", "୩୯", "୪୦", "୪୧", "୪୨", "୪୩", "୪୪", "୪୫", "୪୬", "୪୭", "୪୮", "୪୯", "୫୦", "୫୧", "୫୨", "୫୩", "୫୪", "୫୫", "୫୬", "୫୭", "୫୮", "୫୯", "୬୦", "୬୧", "୬୨", "୬୩", "୬୪", "୬୫", "୬୬", "୬୭", "୬୮", "୬୯", "୭୦", "୭୧", "୭୨", "୭୩", "୭୪", "୭୫", "୭୬", "୭୭", "୭୮", "୭୯", "୮୦", "୮୧", "୮୨", "୮୩", "୮୪", "୮୫", "୮୬", "୮୭", "୮୮", "୮୯", "୯୦", "୯୧", "୯୨", "୯୩", "୯୪", "୯୫", "୯୬", "୯୭", "୯୮", "୯୯"]);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ରବ\u{b3f}ବ\u{b3e}ର", "ସୋମବ\u{b3e}ର", "ମଙ\u{b4d}ଗଳବ\u{b3e}ର", "ବ\u{b41}ଧବ\u{b3e}ର", "ଗ\u{b41}ର\u{b41}ବ\u{b3e}ର", "ଶ\u{b41}କ\u{b4d}ରବ\u{b3e}ର", "ଶନ\u{b3f}ବ\u{b3e}ର"]`
+    pub const DAY: &[&str] = &["ରବ\u{b3f}ବ\u{b3e}ର", "ସୋମବ\u{b3e}ର", "ମଙ\u{b4d}ଗଳବ\u{b3e}ର", "ବ\u{b41}ଧବ\u{b3e}ର", "ଗ\u{b41}ର\u{b41}ବ\u{b3e}ର", "ଶ\u{b41}କ\u{b4d}ରବ\u{b3e}ର", "ଶନ\u{b3f}ବ\u{b3e}ର"];
+    /// `"%Od-%Om-%Oy"`
+    pub const D_FMT: &str = "%Od-%Om-%Oy";
+    /// `"%Oe %B %Oy %OI:%OM:%OS %p %Z"`
+    pub const D_T_FMT: &str = "%Oe %B %Oy %OI:%OM:%OS %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"]`
+    pub const MON: &[&str] = &["ଜ\u{b3e}ନ\u{b41}ଆରୀ", "ଫେବ\u{b43}ଆରୀ", "ମ\u{b3e}ର\u{b4d}ଚ\u{b4d}ଚ", "ଅପ\u{b4d}ରେଲ", "ମଇ", "ଜ\u{b41}ନ", "ଜ\u{b41}ଲ\u{b3e}ଇ", "ଅଗଷ\u{b4d}ଟ", "ସେପ\u{b4d}ଟେମ\u{b4d}ବର", "ଅକ\u{b4d}ଟୋବର", "ନଭେମ\u{b4d}ବର", "ଡ\u{b3f}ସେମ\u{b4d}ବର"];
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT: &str = "%OI:%OM:%OS %p";
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT_AMPM: &str = "%OI:%OM:%OS %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%OI:%OM:%OS %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%OI:%OM:%OS %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
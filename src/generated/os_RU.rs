@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Уӕрӕсе")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Уӕрӕсе");
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("os")`
+    pub const LANG_AB: Option<&str> = Some("os");
+    /// `Some("oss")`
+    pub const LANG_LIB: Option<&str> = Some("oss");
+    /// `Some("ирон")`
+    pub const LANG_NAME: Option<&str> = Some("ирон");
+    /// `Some("oss")`
+    pub const LANG_TERM: Option<&str> = Some("oss");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Ossetic")`
+    pub const LANGUAGE: Option<&str> = Some("Ossetic");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Russia")`
+    pub const TERRITORY: Option<&str> = Some("Russia");
+    /// `Some("Ossetian locale for Russia")`
+    pub const TITLE: Option<&str> = Some("Ossetian locale for Russia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNнН]"`
+    pub const NOEXPR: &str = "^[-0nNнН]";
+    /// `Some("нӕйы")`
+    pub const NOSTR: Option<&str> = Some("нӕйы");
+    /// `"^[+1yYуУ]"`
+    pub const YESEXPR: &str = "^[+1yYуУ]";
+    /// `Some("уойы")`
+    pub const YESSTR: Option<&str> = Some("уойы");
+}
+pub use super::ru_RU::LC_MONETARY;
+pub use super::ru_RU::LC_NUMERIC;
+pub use super::ru_RU::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Хцб", "Крс", "Дцг", "Æрт", "Цпр", "Мрб", "Сбт"]`
+    pub const ABDAY: &[&str] = &["Хцб", "Крс", "Дцг", "Æрт", "Цпр", "Мрб", "Сбт"];
+    /// `&["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_21;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["Январь", "Февраль", "Мартъи", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["Январь", "Февраль", "Мартъи", "Апрель", "Май", "Июнь", "Июль", "Август", "Сентябрь", "Октябрь", "Ноябрь", "Декабрь"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Хуыцаубон", "Къуырисæр", "Дыццæг", "Æртыццæг", "Цыппæрæм", "Майрæмбон", "Сабат"]`
+    pub const DAY: &[&str] = &["Хуыцаубон", "Къуырисæр", "Дыццæг", "Æртыццæг", "Цыппæрæм", "Майрæмбон", "Сабат"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["январы", "февралы", "мартъийы", "апрелы", "майы", "июны", "июлы", "августы", "сентябры", "октябры", "ноябры", "декабры"]`
+    pub const MON: &[&str] = &["январы", "февралы", "мартъийы", "апрелы", "майы", "июны", "июлы", "августы", "сентябры", "октябры", "ноябры", "декабры"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ਭਾਰਤ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ਭਾਰਤ");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("pa")`
+    pub const LANG_AB: Option<&str> = Some("pa");
+    /// `Some("pan")`
+    pub const LANG_LIB: Option<&str> = Some("pan");
+    /// `Some("ਪ\u{a70}ਜਾਬੀ")`
+    pub const LANG_NAME: Option<&str> = Some("ਪ\u{a70}ਜਾਬੀ");
+    /// `Some("pan")`
+    pub const LANG_TERM: Option<&str> = Some("pan");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-09-30")`
+    pub const DATE: Option<&str> = Some("2004-09-30");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Punjabi")`
+    pub const LANGUAGE: Option<&str> = Some("Punjabi");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("IndLinux.org")`
+    pub const SOURCE: Option<&str> = Some("IndLinux.org");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Punjabi language locale for Indian Punjabi(Gurmukhi)")`
+    pub const TITLE: Option<&str> = Some("Punjabi language locale for Indian Punjabi(Gurmukhi)");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNਨ]"`
+    pub const NOEXPR: &str = "^[-0nNਨ]";
+    /// `Some("ਨਹੀ\u{a02}")`
+    pub const NOSTR: Option<&str> = Some("ਨਹੀ\u{a02}");
+    /// `"^[+1yYਹ]"`
+    pub const YESEXPR: &str = "^[+1yYਹ]";
+    /// `Some("ਹਾ\u{a02}")`
+    pub const YESSTR: Option<&str> = Some("ਹਾ\u{a02}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ਐਤ", "ਸ\u{a4b}ਮ", "ਮ\u{a70}ਗਲ", "ਬ\u{a41}\u{a71}ਧ", "ਵੀਰ", "ਸ਼\u{a41}\u{a71}ਕਰ", "ਸ਼ਨਿ\u{a71}ਚਰ"]`
+    pub const ABDAY: &[&str] = &["ਐਤ", "ਸ\u{a4b}ਮ", "ਮ\u{a70}ਗਲ", "ਬ\u{a41}\u{a71}ਧ", "ਵੀਰ", "ਸ਼\u{a41}\u{a71}ਕਰ", "ਸ਼ਨਿ\u{a71}ਚਰ"];
+    /// `&["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"]`
+    pub const ABMON: &[&str] = &["ਜਨ", "ਫ\u{a3c}ਰ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾ", "ਅਗ", "ਸਤ\u{a70}", "ਅਕਤ\u{a42}", "ਨਵ\u{a70}", "ਦਸ\u{a70}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ਸਵ\u{a47}ਰ\u{a47}", "ਸ਼ਾਮ"]`
+    pub const AM_PM: &[&str] = &["ਸਵ\u{a47}ਰ\u{a47}", "ਸ਼ਾਮ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ਐਤਵਾਰ", "ਸ\u{a4b}ਮਵਾਰ", "ਮ\u{a70}ਗਲਵਾਰ", "ਬ\u{a41}\u{a71}ਧਵਾਰ", "ਵੀਰਵਾਰ", "ਸ਼\u{a41}\u{a71}ਕਰਵਾਰ", "ਸ਼ਨਿ\u{a71}ਚਰਵਾਰ"]`
+    pub const DAY: &[&str] = &["ਐਤਵਾਰ", "ਸ\u{a4b}ਮਵਾਰ", "ਮ\u{a70}ਗਲਵਾਰ", "ਬ\u{a41}\u{a71}ਧਵਾਰ", "ਵੀਰਵਾਰ", "ਸ਼\u{a41}\u{a71}ਕਰਵਾਰ", "ਸ਼ਨਿ\u{a71}ਚਰਵਾਰ"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"]`
+    pub const MON: &[&str] = &["ਜਨਵਰੀ", "ਫ\u{a3c}ਰਵਰੀ", "ਮਾਰਚ", "ਅਪ\u{a4d}ਰ\u{a48}ਲ", "ਮਈ", "ਜ\u{a42}ਨ", "ਜ\u{a41}ਲਾਈ", "ਅਗਸਤ", "ਸਤ\u{a70}ਬਰ", "ਅਕਤ\u{a42}ਬਰ", "ਨਵ\u{a70}ਬਰ", "ਦਸ\u{a70}ਬਰ"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
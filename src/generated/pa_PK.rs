@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("PAK")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PAK");
+    /// `Some("PK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PK");
+    /// `Some("PK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PK");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("پکستان")`
+    pub const COUNTRY_NAME: Option<&str> = Some("پکستان");
+    /// `Some(586)`
+    pub const COUNTRY_NUM: Option<i64> = Some(586);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("pa")`
+    pub const LANG_AB: Option<&str> = Some("pa");
+    /// `Some("pan")`
+    pub const LANG_LIB: Option<&str> = Some("pan");
+    /// `Some("پنجابی")`
+    pub const LANG_NAME: Option<&str> = Some("پنجابی");
+    /// `Some("pan")`
+    pub const LANG_TERM: Option<&str> = Some("pan");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-11")`
+    pub const DATE: Option<&str> = Some("2000-07-11");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Punjabi")`
+    pub const LANGUAGE: Option<&str> = Some("Punjabi");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Pakistan")`
+    pub const TERRITORY: Option<&str> = Some("Pakistan");
+    /// `Some("Punjabi (Shahmukhi) Language Locale for Pakistan")`
+    pub const TITLE: Option<&str> = Some("Punjabi (Shahmukhi) Language Locale for Pakistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNنo]"`
+    pub const NOEXPR: &str = "^[-0nNنo]";
+    /// `Some("نهيں")`
+    pub const NOSTR: Option<&str> = Some("نهيں");
+    /// `"^[+1yYهبf]"`
+    pub const YESEXPR: &str = "^[+1yYهبf]";
+    /// `Some("بلكل")`
+    pub const YESSTR: Option<&str> = Some("بلكل");
+}
+pub use super::ur_PK::LC_MONETARY;
+pub use super::ur_PK::LC_NUMERIC;
+pub use super::ur_PK::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_17;
+    /// `&["جنوري", "فروري", "مارچ", "اپريل", "م\u{653}ی", "جون", "جولاي", "اگست", "ستمبر", "اكتوبر", "نومبر", "دسمبر"]`
+    pub const ABMON: &[&str] = &["جنوري", "فروري", "مارچ", "اپريل", "م\u{653}ی", "جون", "جولاي", "اگست", "ستمبر", "اكتوبر", "نومبر", "دسمبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ص", "ش"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_8;
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_18;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"و %H:%M:%S %Z ت %d %B %Y"`
+    pub const D_T_FMT: &str = "و %H:%M:%S %Z ت %d %B %Y";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["جنوري", "فروري", "مارچ", "اپريل", "م\u{653}ی", "جون", "جولاي", "اگست", "ستمبر", "اكتوبر", "نومبر", "دسمبر"]`
+    pub const MON: &[&str] = &["جنوري", "فروري", "مارچ", "اپريل", "م\u{653}ی", "جون", "جولاي", "اگست", "ستمبر", "اكتوبر", "نومبر", "دسمبر"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%P %I:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%P %I:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%P %I:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%P %I:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
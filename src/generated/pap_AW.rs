@@ -0,0 +1,196 @@
+pub mod LC_ADDRESS {
+    /// `Some("ABW")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ABW");
+    /// `Some("AW")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AW");
+    /// `Some("AUA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AUA");
+    /// `Some("")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("");
+    /// `Some("Aruba")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Aruba");
+    /// `Some(533)`
+    pub const COUNTRY_NUM: Option<i64> = Some(533);
+    /// `Some("AW")`
+    pub const COUNTRY_POST: Option<&str> = Some("AW");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("pap")`
+    pub const LANG_LIB: Option<&str> = Some("pap");
+    /// `Some("Papiamento")`
+    pub const LANG_NAME: Option<&str> = Some("Papiamento");
+    /// `Some("pap")`
+    pub const LANG_TERM: Option<&str> = Some("pap");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_4);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2013-09-27")`
+    pub const DATE: Option<&str> = Some("2013-09-27");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Papiamento")`
+    pub const LANGUAGE: Option<&str> = Some("Papiamento");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("information from native speaker")`
+    pub const SOURCE: Option<&str> = Some("information from native speaker");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Aruba")`
+    pub const TERRITORY: Option<&str> = Some("Aruba");
+    /// `Some("Papiamento Language for Aruba")`
+    pub const TITLE: Option<&str> = Some("Papiamento Language for Aruba");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nò")`
+    pub const NOSTR: Option<&str> = Some("nò");
+    /// `"^[+1sSyY]"`
+    pub const YESEXPR: &str = "^[+1sSyY]";
+    /// `Some("sí")`
+    pub const YESSTR: Option<&str> = Some("sí");
+}
+pub mod LC_MONETARY {
+    /// `"ƒ"`
+    pub const CURRENCY_SYMBOL: &str = "ƒ";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"AWG "`
+    pub const INT_CURR_SYMBOL: &str = "AWG ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const N_SIGN_POSN: i64 = 2;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("297")`
+    pub const INT_PREFIX: Option<&str> = Some("297");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["do", "lu", "ma", "we", "ra", "bi", "sa"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_9;
+    /// `&["Yan", "Feb", "Mar", "Apr", "Mei", "Yün", "Yül", "Oug", "Sèp", "Okt", "Nov", "Des"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_9;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Djadomingo", "Djaluna", "Djamars", "Djawebs", "Djarason", "Djabierne", "Djasabra"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_2;
+    /// `"%d-%m-%y"`
+    pub const D_FMT: &str = "%d-%m-%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Yanüari", "Febrüari", "Mart", "Aprel", "Mei", "Yüni", "Yüli", "Ougùstùs", "Sèptèmber", "Oktober", "Novèmber", "Desèmber"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_8;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
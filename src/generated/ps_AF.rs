@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("AFG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("AFG");
+    /// `Some("AF")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AF");
+    /// `Some("AFG")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AFG");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("افغانستان")`
+    pub const COUNTRY_NAME: Option<&str> = Some("افغانستان");
+    /// `Some(4)`
+    pub const COUNTRY_NUM: Option<i64> = Some(4);
+    /// `Some("AF")`
+    pub const COUNTRY_POST: Option<&str> = Some("AF");
+    /// `Some("ps")`
+    pub const LANG_AB: Option<&str> = Some("ps");
+    /// `Some("pus")`
+    pub const LANG_LIB: Option<&str> = Some("pus");
+    /// `Some("پښتو")`
+    pub const LANG_NAME: Option<&str> = Some("پښتو");
+    /// `Some("pus")`
+    pub const LANG_TERM: Option<&str> = Some("pus");
+    /// `Some("%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%s %h %e %r%N%C-%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("see e-mail.")`
+    pub const ADDRESS: Option<&str> = Some("see e-mail.");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_9);
+    /// `Some("Nasir Gulzade")`
+    pub const CONTACT: Option<&str> = Some("Nasir Gulzade");
+    /// `Some("2009-01-16")`
+    pub const DATE: Option<&str> = Some("2009-01-16");
+    /// `Some("nasirgulzade@hotmail.com")`
+    pub const EMAIL: Option<&str> = Some("nasirgulzade@hotmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Pashto")`
+    pub const LANGUAGE: Option<&str> = Some("Pashto");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("Nasir Gulzade")`
+    pub const SOURCE: Option<&str> = Some("Nasir Gulzade");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Afghanistan")`
+    pub const TERRITORY: Option<&str> = Some("Afghanistan");
+    /// `Some("Pashto locale for Afghanistan")`
+    pub const TITLE: Option<&str> = Some("Pashto locale for Afghanistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNخنo]"`
+    pub const NOEXPR: &str = "^[-0nNخنo]";
+    /// `Some("نه")`
+    pub const NOSTR: Option<&str> = Some("نه");
+    /// `"^[+1yYبf]"`
+    pub const YESEXPR: &str = "^[+1yYبf]";
+    /// `Some("هو")`
+    pub const YESSTR: Option<&str> = Some("هو");
+}
+pub mod LC_MONETARY {
+    /// `"؋"`
+    pub const CURRENCY_SYMBOL: &str = "؋";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"AFN "`
+    pub const INT_CURR_SYMBOL: &str = "AFN ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"٫"`
+    pub const MON_DECIMAL_POINT: &str = "٫";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"٬"`
+    pub const MON_THOUSANDS_SEP: &str = "٬";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"٫"`
+    pub const DECIMAL_POINT: &str = "٫";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `"٬"`
+    pub const THOUSANDS_SEP: &str = "٬";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("93")`
+    pub const INT_PREFIX: Option<&str> = Some("93");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%A‒%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A‒%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ی.", "د.", "س.", "چ.", "پ.", "ج.", "ش."]`
+    pub const ABDAY: &[&str] = &["ی.", "د.", "س.", "چ.", "پ.", "ج.", "ش."];
+    /// `&["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"]`
+    pub const ABMON: &[&str] = &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["غ.م.", "غ.و."]`
+    pub const AM_PM: &[&str] = &["غ.م.", "غ.و."];
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "پنجشنبه", "جمعه", "شنبه"]`
+    pub const DAY: &[&str] = &["یکشنبه", "دوشنبه", "سه\u{200c}شنبه", "چارشنبه", "پنجشنبه", "جمعه", "شنبه"];
+    /// `"د %Y د %B %e"`
+    pub const D_FMT: &str = "د %Y د %B %e";
+    /// `"%A د %Y د %B %e، %Z %H:%M:%S"`
+    pub const D_T_FMT: &str = "%A د %Y د %B %e، %Z %H:%M:%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `Some(7)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(7);
+    /// `&["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"]`
+    pub const MON: &[&str] = &["جنوري", "فبروري", "مارچ", "اپریل", "مۍ", "جون", "جولای", "اګست", "سپتمبر", "اکتوبر", "نومبر", "دسمبر"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"\u{202b}%I:%M:%S %p\u{202c}"`
+    pub const T_FMT_AMPM: &str = "\u{202b}%I:%M:%S %p\u{202c}";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("\u{202b}%I:%M:%S %p\u{202c}")`
+        pub const T_FMT_AMPM: Option<&str> = Some("\u{202b}%I:%M:%S %p\u{202c}");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,133 @@
+pub mod LC_ADDRESS {
+    /// `Some("UKR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("UKR");
+    /// `Some("UA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("UA");
+    /// `Some("UA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("UA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Украина")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Украина");
+    /// `Some(804)`
+    pub const COUNTRY_NUM: Option<i64> = Some(804);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ru")`
+    pub const LANG_AB: Option<&str> = Some("ru");
+    /// `Some("rus")`
+    pub const LANG_LIB: Option<&str> = Some("rus");
+    /// `Some("русский")`
+    pub const LANG_NAME: Option<&str> = Some("русский");
+    /// `Some("rus")`
+    pub const LANG_TERM: Option<&str> = Some("rus");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Russian")`
+    pub const LANGUAGE: Option<&str> = Some("Russian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RFC 2319")`
+    pub const SOURCE: Option<&str> = Some("RFC 2319");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Ukraine")`
+    pub const TERRITORY: Option<&str> = Some("Ukraine");
+    /// `Some("Russian locale for Ukraine")`
+    pub const TITLE: Option<&str> = Some("Russian locale for Ukraine");
+}
+pub use super::ru_RU::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"₴"`
+    pub const CURRENCY_SYMBOL: &str = "₴";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"UAH "`
+    pub const INT_CURR_SYMBOL: &str = "UAH ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("380")`
+    pub const INT_PREFIX: Option<&str> = Some("380");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub use super::ru_RU::LC_TIME;
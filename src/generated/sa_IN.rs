@@ -0,0 +1,154 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("भारतम\u{94d}")`
+    pub const COUNTRY_NAME: Option<&str> = Some("भारतम\u{94d}");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("sa")`
+    pub const LANG_AB: Option<&str> = Some("sa");
+    /// `Some("san")`
+    pub const LANG_LIB: Option<&str> = Some("san");
+    /// `Some("स\u{902}स\u{94d}क\u{943}तम\u{94d}")`
+    pub const LANG_NAME: Option<&str> = Some("स\u{902}स\u{94d}क\u{943}तम\u{94d}");
+    /// `Some("san")`
+    pub const LANG_TERM: Option<&str> = Some("san");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Christian Perrier")`
+    pub const CONTACT: Option<&str> = Some("Christian Perrier");
+    /// `Some("2005-09-25")`
+    pub const DATE: Option<&str> = Some("2005-09-25");
+    /// `Some("bubulle@debian.org")`
+    pub const EMAIL: Option<&str> = Some("bubulle@debian.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Sanskrit")`
+    pub const LANGUAGE: Option<&str> = Some("Sanskrit");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("The Debian project")`
+    pub const SOURCE: Option<&str> = Some("The Debian project");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Sanskrit language locale for India")`
+    pub const TITLE: Option<&str> = Some("Sanskrit language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNन]"`
+    pub const NOEXPR: &str = "^[-0nNन]";
+    /// `Some("न")`
+    pub const NOSTR: Option<&str> = Some("न");
+    /// `"^[+1aAyYआ]"`
+    pub const YESEXPR: &str = "^[+1aAyYआ]";
+    /// `Some("आम\u{94d}")`
+    pub const YESSTR: Option<&str> = Some("आम\u{94d}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("91")`
+    pub const INT_PREFIX: Option<&str> = Some("91");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["रविः", "सोम:", "म\u{902}गल:", "ब\u{941}ध:", "ब\u{943}हस\u{94d}पतिः", "श\u{941}क\u{94d}र", "शनि:"]`
+    pub const ABDAY: &[&str] = &["रविः", "सोम:", "म\u{902}गल:", "ब\u{941}ध:", "ब\u{943}हस\u{94d}पतिः", "श\u{941}क\u{94d}र", "शनि:"];
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_28;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["रविवासर:", "सोमवासर:", "म\u{902}गलवासर:", "ब\u{941}धवासर:", "ब\u{943}हस\u{94d}पतिवासरः", "श\u{941}क\u{94d}रवासर", "शनिवासर:"]`
+    pub const DAY: &[&str] = &["रविवासर:", "सोमवासर:", "म\u{902}गलवासर:", "ब\u{941}धवासर:", "ब\u{943}हस\u{94d}पतिवासरः", "श\u{941}क\u{94d}रवासर", "शनिवासर:"];
+    /// `"%-d-%m-%y"`
+    pub const D_FMT: &str = "%-d-%m-%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_24;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
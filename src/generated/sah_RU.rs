@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Арассыыйа")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Арассыыйа");
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("sah")`
+    pub const LANG_LIB: Option<&str> = Some("sah");
+    /// `Some("саха тыла")`
+    pub const LANG_NAME: Option<&str> = Some("саха тыла");
+    /// `Some("sah")`
+    pub const LANG_TERM: Option<&str> = Some("sah");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_10);
+    /// `Some("Valery Timiriliyev")`
+    pub const CONTACT: Option<&str> = Some("Valery Timiriliyev");
+    /// `Some("2018-07-06")`
+    pub const DATE: Option<&str> = Some("2018-07-06");
+    /// `Some("timiriliyev@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("timiriliyev@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Sakha")`
+    pub const LANGUAGE: Option<&str> = Some("Sakha");
+    /// `Some("1.1.0")`
+    pub const REVISION: Option<&str> = Some("1.1.0");
+    /// `Some("Valery Timiriliyev")`
+    pub const SOURCE: Option<&str> = Some("Valery Timiriliyev");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Russian Federation")`
+    pub const TERRITORY: Option<&str> = Some("Russian Federation");
+    /// `Some("Sakha (Yakut) locale for Russian Federation")`
+    pub const TITLE: Option<&str> = Some("Sakha (Yakut) locale for Russian Federation");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNнНсС]"`
+    pub const NOEXPR: &str = "^[-0nNнНсС]";
+    /// `Some("суох")`
+    pub const NOSTR: Option<&str> = Some("суох");
+    /// `"^[+1yYдДэЭ]"`
+    pub const YESEXPR: &str = "^[+1yYдДэЭ]";
+    /// `Some("ээх")`
+    pub const YESSTR: Option<&str> = Some("ээх");
+}
+pub use super::ru_RU::LC_MONETARY;
+pub use super::ru_RU::LC_NUMERIC;
+pub use super::ru_RU::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["бс", "бн", "оп", "ср", "чп", "бт", "сб"]`
+    pub const ABDAY: &[&str] = &["бс", "бн", "оп", "ср", "чп", "бт", "сб"];
+    /// `&["тохс", "олун", "кул", "муус", "ыам", "бэс", "от", "атыр", "бал", "алт", "сэт", "ахс"]`
+    pub const ABMON: &[&str] = &["тохс", "олун", "кул", "муус", "ыам", "бэс", "от", "атыр", "бал", "алт", "сэт", "ахс"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйа", "бэс ыйа", "от ыйа", "атырдьах ыйа", "балаҕан ыйа", "алтынньы", "сэтинньи", "ахсынньы"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйа", "бэс ыйа", "от ыйа", "атырдьах ыйа", "балаҕан ыйа", "алтынньы", "сэтинньи", "ахсынньы"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %Y с. %B %e к. %T (%Z)")`
+    pub const DATE_FMT: Option<&str> = Some("%a %Y с. %B %e к. %T (%Z)");
+    /// `&["баскыһыанньа", "бэнидиэнньик", "оптуорунньук", "сэрэдэ", "чэппиэр", "бээтинсэ", "субуота"]`
+    pub const DAY: &[&str] = &["баскыһыанньа", "бэнидиэнньик", "оптуорунньук", "сэрэдэ", "чэппиэр", "бээтинсэ", "субуота"];
+    /// `"%Y.%m.%d"`
+    pub const D_FMT: &str = "%Y.%m.%d";
+    /// `"%a %Y %b %d %T (%Z)"`
+    pub const D_T_FMT: &str = "%a %Y %b %d %T (%Z)";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `Some(2)`
+    pub const FIRST_WORKDAY: Option<i64> = Some(2);
+    /// `&["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйын", "бэс ыйын", "от ыйын", "атырдьах ыйын", "балаҕан ыйын", "алтынньы", "сэтинньи", "ахсынньы"]`
+    pub const MON: &[&str] = &["тохсунньу", "олунньу", "кулун тутар", "муус устар", "ыам ыйын", "бэс ыйын", "от ыйын", "атырдьах ыйын", "балаҕан ыйын", "алтынньы", "сэтинньи", "ахсынньы"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ᱥᱤᱧᱚᱛ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ᱥᱤᱧᱚᱛ");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("sat")`
+    pub const LANG_LIB: Option<&str> = Some("sat");
+    /// `Some("ᱥᱟᱱᱛᱟᱲᱤ")`
+    pub const LANG_NAME: Option<&str> = Some("ᱥᱟᱱᱛᱟᱲᱤ");
+    /// `Some("sat")`
+    pub const LANG_TERM: Option<&str> = Some("sat");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ")`
+    pub const ADDRESS: Option<&str> = Some("Level 1, Tower X, Cybercity, Magarpatta City, Hadapsar, Pune-411013 ");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2012-01-17")`
+    pub const DATE: Option<&str> = Some("2012-01-17");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Santali")`
+    pub const LANGUAGE: Option<&str> = Some("Santali");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Red Hat Pune")`
+    pub const SOURCE: Option<&str> = Some("Red Hat Pune");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Santali language locale for India")`
+    pub const TITLE: Option<&str> = Some("Santali language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNब]"`
+    pub const NOEXPR: &str = "^[-0nNब]";
+    /// `Some("बाङ")`
+    pub const NOSTR: Option<&str> = Some("बाङ");
+    /// `"^[+1yYह]"`
+    pub const YESEXPR: &str = "^[+1yYह]";
+    /// `Some("होय")`
+    pub const YESSTR: Option<&str> = Some("होय");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub use super::hi_IN::LC_NUMERIC;
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["सि\u{902}ग\u{947}", "ओत\u{947}", "बाल\u{947}", "साग\u{941}न", "सारदी", "जार\u{941}म", "ञ\u{941}ह\u{941}म"]`
+    pub const ABDAY: &[&str] = &["सि\u{902}ग\u{947}", "ओत\u{947}", "बाल\u{947}", "साग\u{941}न", "सारदी", "जार\u{941}म", "ञ\u{941}ह\u{941}म"];
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अखथबर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const ABMON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अखथबर", "नवम\u{94d}बर", "दिसम\u{94d}बर"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["सि\u{902}ग\u{947}मा\u{901}हा\u{901}", "ओत\u{947}मा\u{901}हा\u{901}", "बाल\u{947}मा\u{901}हा\u{901}", "साग\u{941}नमा\u{901}हा\u{901}", "सारदीमा\u{901}हा\u{901}", "जार\u{941}ममा\u{901}हा\u{901}", "ञ\u{941}ह\u{941}ममा\u{901}हा\u{901}"]`
+    pub const DAY: &[&str] = &["सि\u{902}ग\u{947}मा\u{901}हा\u{901}", "ओत\u{947}मा\u{901}हा\u{901}", "बाल\u{947}मा\u{901}हा\u{901}", "साग\u{941}नमा\u{901}हा\u{901}", "सारदीमा\u{901}हा\u{901}", "जार\u{941}ममा\u{901}हा\u{901}", "ञ\u{941}ह\u{941}ममा\u{901}हा\u{901}"];
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अखथबर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const MON: &[&str] = &["जनवरी", "फरवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{941}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अखथबर", "नवम\u{94d}बर", "दिसम\u{94d}बर"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
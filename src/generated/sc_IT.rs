@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("ITA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ITA");
+    /// `Some("IT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IT");
+    /// `Some("I")`
+    pub const COUNTRY_CAR: Option<&str> = Some("I");
+    /// `Some("978-88,979-12")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978-88,979-12");
+    /// `Some("Itàlia")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Itàlia");
+    /// `Some(380)`
+    pub const COUNTRY_NUM: Option<i64> = Some(380);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("sc")`
+    pub const LANG_AB: Option<&str> = Some("sc");
+    /// `Some("srd")`
+    pub const LANG_LIB: Option<&str> = Some("srd");
+    /// `Some("sardu")`
+    pub const LANG_NAME: Option<&str> = Some("sardu");
+    /// `Some("srd")`
+    pub const LANG_TERM: Option<&str> = Some("srd");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Massimeddu Cireddu")`
+    pub const CONTACT: Option<&str> = Some("Massimeddu Cireddu");
+    /// `Some("2017-07-21")`
+    pub const DATE: Option<&str> = Some("2017-07-21");
+    /// `Some("massimeddu@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("massimeddu@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Sardinian")`
+    pub const LANGUAGE: Option<&str> = Some("Sardinian");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("Sardinian Translators Team")`
+    pub const SOURCE: Option<&str> = Some("Sardinian Translators Team");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Italy")`
+    pub const TERRITORY: Option<&str> = Some("Italy");
+    /// `Some("Sardinian locale for Italy")`
+    pub const TITLE: Option<&str> = Some("Sardinian locale for Italy");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nono")`
+    pub const NOSTR: Option<&str> = Some("nono");
+    /// `"^[+1eEaAsSoOyY]"`
+    pub const YESEXPR: &str = "^[+1eEaAsSoOyY]";
+    /// `Some("eja")`
+    pub const YESSTR: Option<&str> = Some("eja");
+}
+pub use super::it_IT::LC_MONETARY;
+pub use super::it_IT::LC_NUMERIC;
+pub use super::it_IT::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Dom", "Lun", "Mar", "Mèr", "Giò", "Che", "Sàb"]`
+    pub const ABDAY: &[&str] = &["Dom", "Lun", "Mar", "Mèr", "Giò", "Che", "Sàb"];
+    /// `&["Ghe", "Fre", "Mar", "Abr", "Maj", "Làm", "Arg", "Aus", "Cab", "Lad", "Onn", "Nad"]`
+    pub const ABMON: &[&str] = &["Ghe", "Fre", "Mar", "Abr", "Maj", "Làm", "Arg", "Aus", "Cab", "Lad", "Onn", "Nad"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %Y %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %Y %H:%M:%S %Z");
+    /// `&["Domìnigu", "Lunis", "Martis", "Mèrcuris", "Giòbia", "Chenàbura", "Sàbadu"]`
+    pub const DAY: &[&str] = &["Domìnigu", "Lunis", "Martis", "Mèrcuris", "Giòbia", "Chenàbura", "Sàbadu"];
+    /// `"%d. %m. %y"`
+    pub const D_FMT: &str = "%d. %m. %y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ghennàrgiu", "Freàrgiu", "Martzu", "Abrile", "Maju", "Làmpadas", "Argiolas/Trìulas", "Austu", "Cabudanni", "Santugaine/Ladàmine", "Onniasantu/Santandria", "Nadale/Idas"]`
+    pub const MON: &[&str] = &["Ghennàrgiu", "Freàrgiu", "Martzu", "Abrile", "Maju", "Làmpadas", "Argiolas/Trìulas", "Austu", "Cabudanni", "Santugaine/Ladàmine", "Onniasantu/Santandria", "Nadale/Idas"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
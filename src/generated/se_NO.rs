@@ -0,0 +1,196 @@
+pub mod LC_ADDRESS {
+    /// `Some("NOR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NOR");
+    /// `Some("NO")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NO");
+    /// `Some("N")`
+    pub const COUNTRY_CAR: Option<&str> = Some("N");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Norga")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Norga");
+    /// `Some(578)`
+    pub const COUNTRY_NUM: Option<i64> = Some(578);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("se")`
+    pub const LANG_AB: Option<&str> = Some("se");
+    /// `Some("sme")`
+    pub const LANG_LIB: Option<&str> = Some("sme");
+    /// `Some("davvisámegiella")`
+    pub const LANG_NAME: Option<&str> = Some("davvisámegiella");
+    /// `Some("sme")`
+    pub const LANG_TERM: Option<&str> = Some("sme");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Børre Gaup")`
+    pub const CONTACT: Option<&str> = Some("Børre Gaup");
+    /// `Some("2001-11-09")`
+    pub const DATE: Option<&str> = Some("2001-11-09");
+    /// `Some("boerre.gaup@pc.nu")`
+    pub const EMAIL: Option<&str> = Some("boerre.gaup@pc.nu");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Northern Sami")`
+    pub const LANGUAGE: Option<&str> = Some("Northern Sami");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("http://www.hum.uit.no/a/trond/loc.html")`
+    pub const SOURCE: Option<&str> = Some("http://www.hum.uit.no/a/trond/loc.html");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Norway")`
+    pub const TERRITORY: Option<&str> = Some("Norway");
+    /// `Some("Northern Saami language locale for Norway")`
+    pub const TITLE: Option<&str> = Some("Northern Saami language locale for Norway");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0IiNn]"`
+    pub const NOEXPR: &str = "^[-0IiNn]";
+    /// `Some("ii")`
+    pub const NOSTR: Option<&str> = Some("ii");
+    /// `"^[+1JjYy]"`
+    pub const YESEXPR: &str = "^[+1JjYy]";
+    /// `Some("jo")`
+    pub const YESSTR: Option<&str> = Some("jo");
+}
+pub mod LC_MONETARY {
+    /// `"kr"`
+    pub const CURRENCY_SYMBOL: &str = "kr";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"NOK "`
+    pub const INT_CURR_SYMBOL: &str = "NOK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("47")`
+    pub const INT_PREFIX: Option<&str> = Some("47");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%l");
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["sotn", "vuos", "maŋ", "gask", "duor", "bear", "láv"]`
+    pub const ABDAY: &[&str] = &["sotn", "vuos", "maŋ", "gask", "duor", "bear", "láv"];
+    /// `&["ođđj", "guov", "njuk", "cuoŋ", "mies", "geas", "suoi", "borg", "čakč", "golg", "skáb", "juov"]`
+    pub const ABMON: &[&str] = &["ođđj", "guov", "njuk", "cuoŋ", "mies", "geas", "suoi", "borg", "čakč", "golg", "skáb", "juov"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A, %B %d. b. %Y %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %B %d. b. %Y %H:%M:%S %Z");
+    /// `&["sotnabeaivi", "vuossárga", "maŋŋebarga", "gaskavahkku", "duorasdat", "bearjadat", "lávvardat"]`
+    pub const DAY: &[&str] = &["sotnabeaivi", "vuossárga", "maŋŋebarga", "gaskavahkku", "duorasdat", "bearjadat", "lávvardat"];
+    /// `"%Y-%m-%d"`
+    pub const D_FMT: &str = "%Y-%m-%d";
+    /// `"%a, %b %e. b. %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a, %b %e. b. %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ođđajagemánnu", "guovvamánnu", "njukčamánnu", "cuoŋománnu", "miessemánnu", "geassemánnu", "suoidnemánnu", "borgemánnu", "čakčamánnu", "golggotmánnu", "skábmamánnu", "juovlamánnu"]`
+    pub const MON: &[&str] = &["ođđajagemánnu", "guovvamánnu", "njukčamánnu", "cuoŋománnu", "miessemánnu", "geassemánnu", "suoidnemánnu", "borgemánnu", "čakčamánnu", "golggotmánnu", "skábmamánnu", "juovlamánnu"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("LTU")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LTU");
+    /// `Some("LT")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LT");
+    /// `Some("LT")`
+    pub const COUNTRY_CAR: Option<&str> = Some("LT");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Lietova")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Lietova");
+    /// `Some(440)`
+    pub const COUNTRY_NUM: Option<i64> = Some(440);
+    /// `Some("LT")`
+    pub const COUNTRY_POST: Option<&str> = Some("LT");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("sgs")`
+    pub const LANG_LIB: Option<&str> = Some("sgs");
+    /// `Some("Žemaitėškā")`
+    pub const LANG_NAME: Option<&str> = Some("Žemaitėškā");
+    /// `Some("sgs")`
+    pub const LANG_TERM: Option<&str> = Some("sgs");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Arnas Udovičius")`
+    pub const CONTACT: Option<&str> = Some("Arnas Udovičius");
+    /// `Some("2016-04-21")`
+    pub const DATE: Option<&str> = Some("2016-04-21");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Samogitian")`
+    pub const LANGUAGE: Option<&str> = Some("Samogitian");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Lithuania")`
+    pub const TERRITORY: Option<&str> = Some("Lithuania");
+    /// `Some("Samogitian language locale for Lithuania")`
+    pub const TITLE: Option<&str> = Some("Samogitian language locale for Lithuania");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0Nn]"`
+    pub const NOEXPR: &str = "^[-0Nn]";
+    /// `Some("nē")`
+    pub const NOSTR: Option<&str> = Some("nē");
+    /// `"^[+1TtYy]"`
+    pub const YESEXPR: &str = "^[+1TtYy]";
+    /// `Some("tǡp")`
+    pub const YESSTR: Option<&str> = Some("tǡp");
+}
+pub use super::lt_LT::LC_MONETARY;
+pub use super::lt_LT::LC_NUMERIC;
+pub use super::lt_LT::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Nd", "Pn", "Ot", "Sr", "Čt", "Pt", "Sb"]`
+    pub const ABDAY: &[&str] = &["Nd", "Pn", "Ot", "Sr", "Čt", "Pt", "Sb"];
+    /// `&["Sau", "Vas", "Kuo", "Bal", "Geg", "Bėr", "Lëp", "Rgp", "Sie", "Spa", "Lap", "Grd"]`
+    pub const ABMON: &[&str] = &["Sau", "Vas", "Kuo", "Bal", "Geg", "Bėr", "Lëp", "Rgp", "Sie", "Spa", "Lap", "Grd"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["nedielės dëna", "panedielis", "oterninks", "sereda", "četvergs", "petnīčė", "sobata"]`
+    pub const DAY: &[&str] = &["nedielės dëna", "panedielis", "oterninks", "sereda", "četvergs", "petnīčė", "sobata"];
+    /// `"%Y.%m.%d"`
+    pub const D_FMT: &str = "%Y.%m.%d";
+    /// `"%Y m. %B %d d. %T"`
+    pub const D_T_FMT: &str = "%Y m. %B %d d. %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["sausė", "vasarė", "kuova", "balondė", "gegožės", "bėrželė", "lëpas", "rogpjūtė", "siejės", "spalė", "lapkrėstė", "grůdė"]`
+    pub const MON: &[&str] = &["sausė", "vasarė", "kuova", "balondė", "gegožės", "bėrželė", "lëpas", "rogpjūtė", "siejės", "spalė", "lapkrėstė", "grůdė"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
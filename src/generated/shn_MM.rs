@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `None`
+    pub const COUNTRY_AB3: Option<&str> = None;
+    /// `Some("MM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MM");
+    /// `Some("MYA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MYA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("မ\u{102d}\u{1030}င\u{103a}းမၢၼ\u{103a}ႈ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("မ\u{102d}\u{1030}င\u{103a}းမၢၼ\u{103a}ႈ");
+    /// `Some(104)`
+    pub const COUNTRY_NUM: Option<i64> = Some(104);
+    /// `Some("Myanmar")`
+    pub const COUNTRY_POST: Option<&str> = Some("Myanmar");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("shn")`
+    pub const LANG_LIB: Option<&str> = Some("shn");
+    /// `Some("လ\u{102d}ၵ\u{103a}ႈတ\u{1086}း")`
+    pub const LANG_NAME: Option<&str> = Some("လ\u{102d}ၵ\u{103a}ႈတ\u{1086}း");
+    /// `Some("shn")`
+    pub const LANG_TERM: Option<&str> = Some("shn");
+    /// `Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%d%N%f%N%b%N%h%t%r%t%e%t%s%N%T%t%z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Yangon, Myanmar")`
+    pub const ADDRESS: Option<&str> = Some("Yangon, Myanmar");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_NAME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_3);
+    /// `Some("Bone Pyae Sone")`
+    pub const CONTACT: Option<&str> = Some("Bone Pyae Sone");
+    /// `Some("2012-07-01")`
+    pub const DATE: Option<&str> = Some("2012-07-01");
+    /// `Some("bone.burma@mail.com")`
+    pub const EMAIL: Option<&str> = Some("bone.burma@mail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Shan")`
+    pub const LANGUAGE: Option<&str> = Some("Shan");
+    /// `Some("1.3")`
+    pub const REVISION: Option<&str> = Some("1.3");
+    /// `Some("ubuntu Myanmar LoCo Team http:/www.ubuntu-mm.net")`
+    pub const SOURCE: Option<&str> = Some("ubuntu Myanmar LoCo Team http:/www.ubuntu-mm.net");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Myanmar")`
+    pub const TERRITORY: Option<&str> = Some("Myanmar");
+    /// `Some("Shan language locale for Myanmar")`
+    pub const TITLE: Option<&str> = Some("Shan language locale for Myanmar");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNမ]"`
+    pub const NOEXPR: &str = "^[-0nNမ]";
+    /// `Some("မဟ\u{102f}တ\u{103a}ဘ\u{1030}း")`
+    pub const NOSTR: Option<&str> = Some("မဟ\u{102f}တ\u{103a}ဘ\u{1030}း");
+    /// `"^[+1yYဟ]"`
+    pub const YESEXPR: &str = "^[+1yYဟ]";
+    /// `Some("ဟ\u{102f}တ\u{103a}တယ\u{103a}")`
+    pub const YESSTR: Option<&str> = Some("ဟ\u{102f}တ\u{103a}တယ\u{103a}");
+}
+pub mod LC_MONETARY {
+    /// `"Ks"`
+    pub const CURRENCY_SYMBOL: &str = "Ks";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MMK "`
+    pub const INT_CURR_SYMBOL: &str = "MMK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::my_MM::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["တ\u{102d}တ\u{1037}\u{103a}", "ၸၼ\u{103a}", "ၵၢၼ\u{103a}း", "ပ\u{102f}တ\u{1037}\u{103a}", "ၽတ\u{103a}း", "သ\u{102f}ၵ\u{103a}း", "သဝ\u{103a}"]`
+    pub const ABDAY: &[&str] = &["တ\u{102d}တ\u{1037}\u{103a}", "ၸၼ\u{103a}", "ၵၢၼ\u{103a}း", "ပ\u{102f}တ\u{1037}\u{103a}", "ၽတ\u{103a}း", "သ\u{102f}ၵ\u{103a}း", "သဝ\u{103a}"];
+    /// `&["လ\u{102d}\u{1030}ၼ\u{103a}ၵမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သၢမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102e}", "လ\u{102d}\u{1030}ၼ\u{103a}ႁႃႈ", "လ\u{102d}\u{1030}ၼ\u{103a}ႁ\u{1030}ၵ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ပ\u{1085}တ\u{103a}ႇ", "လ\u{102d}\u{1030}ၼ\u{103a}ၵဝ\u{103a}ႈ", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}းသ\u{103d}င\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}င\u{103a}"]`
+    pub const ABMON: &[&str] = &["လ\u{102d}\u{1030}ၼ\u{103a}ၵမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သၢမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102e}", "လ\u{102d}\u{1030}ၼ\u{103a}ႁႃႈ", "လ\u{102d}\u{1030}ၼ\u{103a}ႁ\u{1030}ၵ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ပ\u{1085}တ\u{103a}ႇ", "လ\u{102d}\u{1030}ၼ\u{103a}ၵဝ\u{103a}ႈ", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}းသ\u{103d}င\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}င\u{103a}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `Some(&["႐႐", "႐႑", "႐႒", "႐႓", "႐႔", "႐႕", "႐႖", "႐႗", "႐႘", "႐႙", "႑႐", "႑႑", "႑႒", "႑႓", "႑႔", "႑႕", "႑႖", "႑႗", "႑႘", "႑႙", "႒႐", "႒႑", "႒႒", "႒႓", "႒႔", "႒႕", "႒႖", "႒႗", "႒႘", "႒႙", "႓႐", "႓႑", "႓႒", "႓႓", "႓႔", "႓႕", "႓႖", "႓႗", "႓႘", "႓႙", "႔႐", "႔႑", "႔႒", "႔႓", "႔႔", "႔႕", "႔႖", "႔႗", "႔႘", "႔႙", "႕႐", "႕႑", "႕႒", "႕႓", "႕႔", "႕႕", "႕႖", "႕႗", "႕႘", "႕႙", "႖႐", "႖႑", "႖႒", "႖႓", "႖႔", "႖႕", "႖႖", "႖႗", "႖႘", "႖႙", "႗႐", "႗႑", "႗႒", "႗႓", "႗႔", "႗႕", "႗႖", "႗႗", "႗႘", "႗႙", "႘႐", "႘႑", "႘႒", "႘႓", "႘႔", "႘႕", "႘႖", "႘႗", "႘႘", "႘႙", "႙႐", "႙႑", "႙႒", "႙႓", "႙႔", "႙႕", "႙႖", "႙႗", "႙႘", "႙႙"])`
+    pub const ALT_DIGITS: Option<&[&str]> = Some(&["႐႐", "႐႑", "႐႒", "႐႓", "႐႔", "႐႕", "႐႖", "႐႗", "႐႘", "႐႙", "႑႐", "႑႑", "႑႒", "႑႓", "႑႔", "႑႕", "႑႖", "႑႗", "႑႘", "႑႙", "႒႐", "႒႑", "႒႒", "႒႓", "႒႔", "႒႕", "႒႖", "႒႗", "႒႘", "႒႙", "႓႐", "႓႑", "႓႒", "႓႓", "႓႔", "႓႕", "႓႖", "႓႗", "႓႘", "႓႙", "႔႐", "႔႑", "႔႒", "႔႓", "႔႔", "႔႕", "႔႖", "႔႗", "႔႘", "႔႙", "႕႐", "႕႑", "႕႒", "႕႓", "႕႔", "႕႕", "႕႖", "႕႗", "႕႘", "႕႙", "႖႐", "႖႑", "႖႒", "႖႓", "႖႔", "႖႕", "႖႖", "႖႗", "႖႘", "႖႙", "႗႐", "႗႑", "႗႒", "႗႓", "႗႔", "႗႕", "႗႖", "႗႗", "႗႘", "႗႙", "႘႐", "႘႑", "႘႒", "႘႓", "႘႔", "႘႕", "႘႖", "႘႗", "႘႘", "႘႙", "႙႐", "႙႑", "႙႒", "႙႓", "႙႔", "႙႕", "႙႖", "႙႗", "႙႘", "႙႙"]);
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ၵၢင\u{103a}ၼ\u{1082}\u{103a}", "တၢမ\u{103a}းၶမ\u{103a}ႈ"]`
+    pub const AM_PM: &[&str] = &["ၵၢင\u{103a}ၼ\u{1082}\u{103a}", "တၢမ\u{103a}းၶမ\u{103a}ႈ"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ဝၼ\u{103a}းဢႃးတ\u{102d}တ\u{103a}ႉ", "ဝၼ\u{103a}းၸၼ\u{103a}", "ဝၼ\u{103a}း\u{200b}ဢၢင\u{103a}း\u{200b}ၵၢၼ\u{103a}း", "ဝၼ\u{103a}းပ\u{1030}တ\u{103a}ႉ", "ဝၼ\u{103a}းၽတ\u{103a}း", "ဝၼ\u{103a}းသ\u{102f}ၵ\u{103a}း", "ဝၼ\u{103a}းသဝ\u{103a}"]`
+    pub const DAY: &[&str] = &["ဝၼ\u{103a}းဢႃးတ\u{102d}တ\u{103a}ႉ", "ဝၼ\u{103a}းၸၼ\u{103a}", "ဝၼ\u{103a}း\u{200b}ဢၢင\u{103a}း\u{200b}ၵၢၼ\u{103a}း", "ဝၼ\u{103a}းပ\u{1030}တ\u{103a}ႉ", "ဝၼ\u{103a}းၽတ\u{103a}း", "ဝၼ\u{103a}းသ\u{102f}ၵ\u{103a}း", "ဝၼ\u{103a}းသဝ\u{103a}"];
+    /// `"%OC%Oy %b %Od %A"`
+    pub const D_FMT: &str = "%OC%Oy %b %Od %A";
+    /// `"%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z"`
+    pub const D_T_FMT: &str = "%OC%Oy %b %Od %A %OI:%OM:%OS %Op %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["လ\u{102d}\u{1030}ၼ\u{103a}ၵမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သၢမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102e}", "လ\u{102d}\u{1030}ၼ\u{103a}ႁႃႈ", "လ\u{102d}\u{1030}ၼ\u{103a}ႁ\u{1030}ၵ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ပ\u{1085}တ\u{103a}ႇ", "လ\u{102d}\u{1030}ၼ\u{103a}ၵဝ\u{103a}ႈ", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}းသ\u{103d}င\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}င\u{103a}"]`
+    pub const MON: &[&str] = &["လ\u{102d}\u{1030}ၼ\u{103a}ၵမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သၢမ\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102e}", "လ\u{102d}\u{1030}ၼ\u{103a}ႁႃႈ", "လ\u{102d}\u{1030}ၼ\u{103a}ႁ\u{1030}ၵ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}ပ\u{1085}တ\u{103a}ႇ", "လ\u{102d}\u{1030}ၼ\u{103a}ၵဝ\u{103a}ႈ", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}း", "လ\u{102d}\u{1030}ၼ\u{103a}သ\u{102d}ပ\u{103a}းဢ\u{102d}တ\u{103a}းသ\u{103d}င\u{103a}", "လ\u{102d}\u{1030}ၼ\u{103a}ၸ\u{1035}င\u{103a}"];
+    /// `"%OH:%OM:%OS %p"`
+    pub const T_FMT: &str = "%OH:%OM:%OS %p";
+    /// `"%OI:%OM:%OS %p"`
+    pub const T_FMT_AMPM: &str = "%OI:%OM:%OS %p";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%OI:%OM:%OS %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%OI:%OM:%OS %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
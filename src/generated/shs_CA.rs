@@ -0,0 +1,135 @@
+pub mod LC_ADDRESS {
+    /// `Some("CAN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CAN");
+    /// `Some("CA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CA");
+    /// `Some("CDN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CDN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NAME: Option<&str> = None;
+    /// `Some(124)`
+    pub const COUNTRY_NUM: Option<i64> = Some(124);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("shs")`
+    pub const LANG_LIB: Option<&str> = Some("shs");
+    /// `Some("Secwepemctsín")`
+    pub const LANG_NAME: Option<&str> = Some("Secwepemctsín");
+    /// `Some("shs")`
+    pub const LANG_TERM: Option<&str> = Some("shs");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("745 Ska-Hiish Dr, Chase BC V0E 1M3")`
+    pub const ADDRESS: Option<&str> = Some("745 Ska-Hiish Dr, Chase BC V0E 1M3");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2008-01-15")`
+    pub const DATE: Option<&str> = Some("2008-01-15");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Shuswap")`
+    pub const LANGUAGE: Option<&str> = Some("Shuswap");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Neskie Manuel")`
+    pub const SOURCE: Option<&str> = Some("Neskie Manuel");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Canada")`
+    pub const TERRITORY: Option<&str> = Some("Canada");
+    /// `Some("Shuswap locale for Canada")`
+    pub const TITLE: Option<&str> = Some("Shuswap locale for Canada");
+}
+pub use super::en_CA::LC_MESSAGES;
+pub use super::en_CA::LC_MONETARY;
+pub use super::en_CA::LC_NUMERIC;
+pub use super::en_CA::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sxe", "Spe", "Sel", "Ske", "Sme", "Sts", "Stq"]`
+    pub const ABDAY: &[&str] = &["Sxe", "Spe", "Sel", "Ske", "Sme", "Sts", "Stq"];
+    /// `&["Kwe", "Tsi", "Sqe", "Éwt", "Ell", "Tsp", "Tqw", "Ct\u{313}é", "Qel", "Wél", "U7l", "Tet"]`
+    pub const ABMON: &[&str] = &["Kwe", "Tsi", "Sqe", "Éwt", "Ell", "Tsp", "Tqw", "Ct\u{313}é", "Qel", "Wél", "U7l", "Tet"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sxetspesq\u{313}t", "Spetkesq\u{313}t", "Selesq\u{313}t", "Skellesq\u{313}t", "Smesesq\u{313}t", "Stselkstesq\u{313}t", "Stqmekstesq\u{313}t"]`
+    pub const DAY: &[&str] = &["Sxetspesq\u{313}t", "Spetkesq\u{313}t", "Selesq\u{313}t", "Skellesq\u{313}t", "Smesesq\u{313}t", "Stselkstesq\u{313}t", "Stqmekstesq\u{313}t"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Pellkwet\u{313}min", "Pelctsipwen\u{313}ten", "Pellsqépts", "Peslléwten", "Pell7ell7é7llqten", "Pelltspéntsk", "Pelltqwelq\u{313}wél\u{313}t", "Pellct\u{313}éxel\u{313}cten", "Pesqelqlélten", "Pesllwélsten", "Pellc7ell7é7llcwten\u{313}", "Pelltetétq\u{313}em"]`
+    pub const MON: &[&str] = &["Pellkwet\u{313}min", "Pelctsipwen\u{313}ten", "Pellsqépts", "Peslléwten", "Pell7ell7é7llqten", "Pelltspéntsk", "Pelltqwelq\u{313}wél\u{313}t", "Pellct\u{313}éxel\u{313}cten", "Pesqelqlélten", "Pesllwélsten", "Pellc7ell7é7llcwten\u{313}", "Pelltetétq\u{313}em"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("LKA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LKA");
+    /// `Some("LK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LK");
+    /// `Some("CL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ශ\u{dca}\u{200d}ර\u{dd3} ලංක\u{dcf}ව")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ශ\u{dca}\u{200d}ර\u{dd3} ලංක\u{dcf}ව");
+    /// `Some(144)`
+    pub const COUNTRY_NUM: Option<i64> = Some(144);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("si")`
+    pub const LANG_AB: Option<&str> = Some("si");
+    /// `Some("sin")`
+    pub const LANG_LIB: Option<&str> = Some("sin");
+    /// `Some("ස\u{dd2}ංහල")`
+    pub const LANG_NAME: Option<&str> = Some("ස\u{dd2}ංහල");
+    /// `Some("sin")`
+    pub const LANG_TERM: Option<&str> = Some("sin");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004.10.01")`
+    pub const DATE: Option<&str> = Some("2004.10.01");
+    /// `Some("libc-locales@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-locales@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Sinhala")`
+    pub const LANGUAGE: Option<&str> = Some("Sinhala");
+    /// `Some("0.9")`
+    pub const REVISION: Option<&str> = Some("0.9");
+    /// `Some("Lanka Linux User Group (LKLUG) www.lug.lk, sinhala.linux.lk")`
+    pub const SOURCE: Option<&str> = Some("Lanka Linux User Group (LKLUG) www.lug.lk, sinhala.linux.lk");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Sri Lanka")`
+    pub const TERRITORY: Option<&str> = Some("Sri Lanka");
+    /// `Some("Sinhala language locale for Sri Lanka")`
+    pub const TITLE: Option<&str> = Some("Sinhala language locale for Sri Lanka");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNන]"`
+    pub const NOEXPR: &str = "^[-0nNන]";
+    /// `Some("නැත")`
+    pub const NOSTR: Option<&str> = Some("නැත");
+    /// `"^[+1yYඔ]"`
+    pub const YESEXPR: &str = "^[+1yYඔ]";
+    /// `Some("ඔව\u{dca}")`
+    pub const YESSTR: Option<&str> = Some("ඔව\u{dca}");
+}
+pub mod LC_MONETARY {
+    /// `"ර\u{dd4}"`
+    pub const CURRENCY_SYMBOL: &str = "ර\u{dd4}";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"LKR "`
+    pub const INT_CURR_SYMBOL: &str = "LKR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("94")`
+    pub const INT_PREFIX: Option<&str> = Some("94");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("(%a) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%a) %l");
+    /// `Some("+%c (%a) %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c (%a) %l");
+}
+pub mod LC_TIME {
+    /// `&["ඉ", "ස", "අ", "බ", "බ\u{dca}\u{200d}ර", "ස\u{dd2}", "සෙ"]`
+    pub const ABDAY: &[&str] = &["ඉ", "ස", "අ", "බ", "බ\u{dca}\u{200d}ර", "ස\u{dd2}", "සෙ"];
+    /// `&["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}", "අප\u{dca}\u{200d}ර\u{dd2}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නෙවැ", "දෙසැ"]`
+    pub const ABMON: &[&str] = &["ජන", "පෙබ", "ම\u{dcf}ර\u{dca}", "අප\u{dca}\u{200d}ර\u{dd2}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝ", "සැප\u{dca}", "ඔක\u{dca}", "නෙවැ", "දෙසැ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["පෙ.ව.", "ප.ව."]`
+    pub const AM_PM: &[&str] = &["පෙ.ව.", "ප.ව."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y %B %e වැන\u{dd2} %A %H:%M:%S %z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y %B %e වැන\u{dd2} %A %H:%M:%S %z");
+    /// `&["ඉර\u{dd2}ද\u{dcf}", "සඳ\u{dd4}ද\u{dcf}", "අඟහර\u{dd4}ව\u{dcf}ද\u{dcf}", "බද\u{dcf}ද\u{dcf}", "බ\u{dca}\u{200d}රහස\u{dca}පත\u{dd2}න\u{dca}ද\u{dcf}", "ස\u{dd2}ක\u{dd4}ර\u{dcf}ද\u{dcf}", "සෙනස\u{dd4}ර\u{dcf}ද\u{dcf}"]`
+    pub const DAY: &[&str] = &["ඉර\u{dd2}ද\u{dcf}", "සඳ\u{dd4}ද\u{dcf}", "අඟහර\u{dd4}ව\u{dcf}ද\u{dcf}", "බද\u{dcf}ද\u{dcf}", "බ\u{dca}\u{200d}රහස\u{dca}පත\u{dd2}න\u{dca}ද\u{dcf}", "ස\u{dd2}ක\u{dd4}ර\u{dcf}ද\u{dcf}", "සෙනස\u{dd4}ර\u{dcf}ද\u{dcf}"];
+    /// `"%Y-%m-%d"`
+    pub const D_FMT: &str = "%Y-%m-%d";
+    /// `"%Y-%m-%d %H:%M:%S %z"`
+    pub const D_T_FMT: &str = "%Y-%m-%d %H:%M:%S %z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"]`
+    pub const MON: &[&str] = &["ජනව\u{dcf}ර\u{dd2}", "පෙබරව\u{dcf}ර\u{dd2}", "ම\u{dcf}ර\u{dca}ත\u{dd4}", "අප\u{dca}\u{200d}රේල\u{dca}", "මැය\u{dd2}", "ජ\u{dd6}න\u{dd2}", "ජ\u{dd6}ල\u{dd2}", "අගෝස\u{dca}ත\u{dd4}", "සැප\u{dca}තැම\u{dca}බර\u{dca}", "ඔක\u{dca}තෝබර\u{dca}", "නොවැම\u{dca}බර\u{dca}", "දෙසැම\u{dca}බර\u{dca}"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%p %I:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%p %I:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p %I:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %I:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
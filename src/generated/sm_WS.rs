@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("WSM")`
+    pub const COUNTRY_AB3: Option<&str> = Some("WSM");
+    /// `Some("WS")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("WS");
+    /// `None`
+    pub const COUNTRY_CAR: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Sāmoa")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Sāmoa");
+    /// `Some(882)`
+    pub const COUNTRY_NUM: Option<i64> = Some(882);
+    /// `Some("%a %N   %f %N   %d %N   %b %N   %h %s %e %r %N   %T, %c  %N")`
+    pub const COUNTRY_POST: Option<&str> = Some("%a %N   %f %N   %d %N   %b %N   %h %s %e %r %N   %T, %c  %N");
+    /// `Some("sm")`
+    pub const LANG_AB: Option<&str> = Some("sm");
+    /// `Some("smo")`
+    pub const LANG_LIB: Option<&str> = Some("smo");
+    /// `Some("Gagana faʻa Sāmoa")`
+    pub const LANG_NAME: Option<&str> = Some("Gagana faʻa Sāmoa");
+    /// `Some("smo")`
+    pub const LANG_TERM: Option<&str> = Some("smo");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-03-21")`
+    pub const DATE: Option<&str> = Some("2017-03-21");
+    /// `Some("akhilesh.k@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Samoan")`
+    pub const LANGUAGE: Option<&str> = Some("Samoan");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Samsung Electronics Co., Ltd.")`
+    pub const SOURCE: Option<&str> = Some("Samsung Electronics Co., Ltd.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Samoa")`
+    pub const TERRITORY: Option<&str> = Some("Samoa");
+    /// `Some("Samoan language locale for Samoa")`
+    pub const TITLE: Option<&str> = Some("Samoan language locale for Samoa");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0lLnN]"`
+    pub const NOEXPR: &str = "^[-0lLnN]";
+    /// `Some("leai")`
+    pub const NOSTR: Option<&str> = Some("leai");
+    /// `"^[+1iIyY]"`
+    pub const YESEXPR: &str = "^[+1iIyY]";
+    /// `Some("ioe")`
+    pub const YESSTR: Option<&str> = Some("ioe");
+}
+pub mod LC_MONETARY {
+    /// `"WS$"`
+    pub const CURRENCY_SYMBOL: &str = "WS$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"WST "`
+    pub const INT_CURR_SYMBOL: &str = "WST ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `Some(1)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("685")`
+    pub const INT_PREFIX: Option<&str> = Some("685");
+    /// `Some("0")`
+    pub const INT_SELECT: Option<&str> = Some("0");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Aso Sa", "Aso Gaf", "Aso Lua", "Aso Lul", "Aso Tof", "Aso Far", "Aso To'"]`
+    pub const ABDAY: &[&str] = &["Aso Sa", "Aso Gaf", "Aso Lua", "Aso Lul", "Aso Tof", "Aso Far", "Aso To'"];
+    /// `&["Ian", "Fep", "Mat", "Ape", "Me", "Iun", "Iul", "Aug", "Set", "Oke", "Nov", "Tes"]`
+    pub const ABMON: &[&str] = &["Ian", "Fep", "Mat", "Ape", "Me", "Iun", "Iul", "Aug", "Set", "Oke", "Nov", "Tes"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Aso Sa", "Aso Gafua", "Aso Lua", "Aso Lulu", "Aso Tofi", "Aso Farail", "Aso To'ana'i"]`
+    pub const DAY: &[&str] = &["Aso Sa", "Aso Gafua", "Aso Lua", "Aso Lulu", "Aso Tofi", "Aso Farail", "Aso To'ana'i"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ianuari", "Fepuari", "Mati", "Aperila", "Me", "Iuni", "Iulai", "Auguso", "Setema", "Oketopa", "Novema", "Tesema"]`
+    pub const MON: &[&str] = &["Ianuari", "Fepuari", "Mati", "Aperila", "Me", "Iuni", "Iulai", "Auguso", "Setema", "Oketopa", "Novema", "Tesema"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
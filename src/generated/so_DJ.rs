@@ -0,0 +1,141 @@
+pub mod LC_ADDRESS {
+    /// `Some("DJI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("DJI");
+    /// `Some("DJ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("DJ");
+    /// `Some("DJI")`
+    pub const COUNTRY_CAR: Option<&str> = Some("DJI");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Jabuuti")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Jabuuti");
+    /// `Some(262)`
+    pub const COUNTRY_NUM: Option<i64> = Some(262);
+    /// `Some("DJI")`
+    pub const COUNTRY_POST: Option<&str> = Some("DJI");
+    /// `Some("so")`
+    pub const LANG_AB: Option<&str> = Some("so");
+    /// `Some("som")`
+    pub const LANG_LIB: Option<&str> = Some("som");
+    /// `Some("Soomaali")`
+    pub const LANG_NAME: Option<&str> = Some("Soomaali");
+    /// `Some("som")`
+    pub const LANG_TERM: Option<&str> = Some("som");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_2);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Somali")`
+    pub const LANGUAGE: Option<&str> = Some("Somali");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Djibouti")`
+    pub const TERRITORY: Option<&str> = Some("Djibouti");
+    /// `Some("Somali language locale for Djibouti.")`
+    pub const TITLE: Option<&str> = Some("Somali language locale for Djibouti.");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("maya")`
+    pub const NOSTR: Option<&str> = Some("maya");
+    /// `"^[+1oOyY]"`
+    pub const YESEXPR: &str = "^[+1oOyY]";
+    /// `Some("haa")`
+    pub const YESSTR: Option<&str> = Some("haa");
+}
+pub use super::aa_DJ::LC_MONETARY;
+pub use super::aa_DJ::LC_NUMERIC;
+pub use super::aa_DJ::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Axd", "Isn", "Tal", "Arb", "Kha", "Jim", "Sab"]`
+    pub const ABDAY: &[&str] = &["Axd", "Isn", "Tal", "Arb", "Kha", "Jim", "Sab"];
+    /// `&["Kob", "Lab", "Sad", "Afr", "Sha", "Lix", "Tod", "Sid", "Sag", "Tob", "KIT", "LIT"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_7;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["subaxnimo", "galabnimo"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_6;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %b %e %r %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %b %e %r %Z %Y");
+    /// `&["Axad", "Isniin", "Salaaso", "Arbaco", "Khamiis", "Jimco", "Sabti"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_1;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %l:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %l:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(7)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(7);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Bisha Koobaad", "Bisha Labaad", "Bisha Saddexaad", "Bisha Afraad", "Bisha Shanaad", "Bisha Lixaad", "Bisha Todobaad", "Bisha Sideedaad", "Bisha Sagaalaad", "Bisha Tobnaad", "Bisha Kow iyo Tobnaad", "Bisha Laba iyo Tobnaad"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_1;
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a %d %b %Y %r %Z"`
+        pub const D_T_FMT: &str = "%a %d %b %Y %r %Z";
+        /// `Some("%l:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
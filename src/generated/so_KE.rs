@@ -0,0 +1,133 @@
+pub mod LC_ADDRESS {
+    /// `Some("KEN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("KEN");
+    /// `Some("KE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("KE");
+    /// `Some("EAK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("EAK");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Kiiniya")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Kiiniya");
+    /// `Some(404)`
+    pub const COUNTRY_NUM: Option<i64> = Some(404);
+    /// `Some("KEN")`
+    pub const COUNTRY_POST: Option<&str> = Some("KEN");
+    /// `Some("so")`
+    pub const LANG_AB: Option<&str> = Some("so");
+    /// `Some("som")`
+    pub const LANG_LIB: Option<&str> = Some("som");
+    /// `Some("Soomaali")`
+    pub const LANG_NAME: Option<&str> = Some("Soomaali");
+    /// `Some("som")`
+    pub const LANG_TERM: Option<&str> = Some("som");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_2);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Somali")`
+    pub const LANGUAGE: Option<&str> = Some("Somali");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Kenya")`
+    pub const TERRITORY: Option<&str> = Some("Kenya");
+    /// `Some("Somali language locale for Kenya")`
+    pub const TITLE: Option<&str> = Some("Somali language locale for Kenya");
+}
+pub use super::so_SO::LC_MESSAGES;
+pub use super::om_KE::LC_MONETARY;
+pub use super::om_KE::LC_NUMERIC;
+pub use super::om_KE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Axd", "Isn", "Sal", "Arb", "Kha", "Jim", "Sab"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_1;
+    /// `&["Kob", "Lab", "Sad", "Afr", "Sha", "Lix", "Tod", "Sid", "Sag", "Tob", "KIT", "LIT"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_7;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["subaxnimo", "galabnimo"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_6;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A, %B %e, %X %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %B %e, %X %Z %Y");
+    /// `&["Axad", "Isniin", "Salaaso", "Arbaco", "Khamiis", "Jimco", "Sabti"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_1;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A, %B %e, %Y %T %Z"`
+    pub const D_T_FMT: &str = "%A, %B %e, %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Bisha Koobaad", "Bisha Labaad", "Bisha Saddexaad", "Bisha Afraad", "Bisha Shanaad", "Bisha Lixaad", "Bisha Todobaad", "Bisha Sideedaad", "Bisha Sagaalaad", "Bisha Tobnaad", "Bisha Kow iyo Tobnaad", "Bisha Laba iyo Tobnaad"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_1;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%A, %B %e, %Y %X %Z"`
+        pub const D_T_FMT: &str = "%A, %B %e, %Y %X %Z";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("ALB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ALB");
+    /// `Some("AL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("AL");
+    /// `Some("AL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("AL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Shqipëri")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Shqipëri");
+    /// `Some(8)`
+    pub const COUNTRY_NUM: Option<i64> = Some(8);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("sq")`
+    pub const LANG_AB: Option<&str> = Some("sq");
+    /// `Some("alb")`
+    pub const LANG_LIB: Option<&str> = Some("alb");
+    /// `Some("shqip")`
+    pub const LANG_NAME: Option<&str> = Some("shqip");
+    /// `Some("sqi")`
+    pub const LANG_TERM: Option<&str> = Some("sqi");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-07-01")`
+    pub const DATE: Option<&str> = Some("2004-07-01");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Albanian")`
+    pub const LANGUAGE: Option<&str> = Some("Albanian");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Albania")`
+    pub const TERRITORY: Option<&str> = Some("Albania");
+    /// `Some("Albanian language locale for Albania")`
+    pub const TITLE: Option<&str> = Some("Albanian language locale for Albania");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNjJ]"`
+    pub const NOEXPR: &str = "^[-0nNjJ]";
+    /// `Some("jo")`
+    pub const NOSTR: Option<&str> = Some("jo");
+    /// `"^[+1yYpP]"`
+    pub const YESEXPR: &str = "^[+1yYpP]";
+    /// `Some("po")`
+    pub const YESSTR: Option<&str> = Some("po");
+}
+pub mod LC_MONETARY {
+    /// `"L"`
+    pub const CURRENCY_SYMBOL: &str = "L";
+    /// `3`
+    pub const FRAC_DIGITS: i64 = 3;
+    /// `"ALL "`
+    pub const INT_CURR_SYMBOL: &str = "ALL ";
+    /// `3`
+    pub const INT_FRAC_DIGITS: i64 = 3;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("355")`
+    pub const INT_PREFIX: Option<&str> = Some("355");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["Die", "Hën", "Mar", "Mër", "Enj", "Pre", "Sht"]`
+    pub const ABDAY: &[&str] = &["Die", "Hën", "Mar", "Mër", "Enj", "Pre", "Sht"];
+    /// `&["Jan", "Shk", "Mar", "Pri", "Maj", "Qer", "Kor", "Gsh", "Sht", "Tet", "Nën", "Dhj"]`
+    pub const ABMON: &[&str] = &["Jan", "Shk", "Mar", "Pri", "Maj", "Qer", "Kor", "Gsh", "Sht", "Tet", "Nën", "Dhj"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["PD", "MD"]`
+    pub const AM_PM: &[&str] = &["PD", "MD"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d %b %Y %I:%M:%S.%p %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d %b %Y %I:%M:%S.%p %Z");
+    /// `&["e diel", "e hënë", "e martë", "e mërkurë", "e enjte", "e premte", "e shtunë"]`
+    pub const DAY: &[&str] = &["e diel", "e hënë", "e martë", "e mërkurë", "e enjte", "e premte", "e shtunë"];
+    /// `"%-d.%-m.%y"`
+    pub const D_FMT: &str = "%-d.%-m.%y";
+    /// `"%a %-d %b %Y %I:%M:%S.%p"`
+    pub const D_T_FMT: &str = "%a %-d %b %Y %I:%M:%S.%p";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"]`
+    pub const MON: &[&str] = &["janar", "shkurt", "mars", "prill", "maj", "qershor", "korrik", "gusht", "shtator", "tetor", "nëntor", "dhjetor"];
+    /// `"%I:%M:%S.%p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S.%p %Z";
+    /// `"%I:%M:%S.%p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S.%p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S.%p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S.%p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
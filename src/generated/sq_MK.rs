@@ -0,0 +1,117 @@
+pub mod LC_ADDRESS {
+    /// `Some("MKD")`
+    pub const COUNTRY_AB3: Option<&str> = Some("MKD");
+    /// `Some("MK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("MK");
+    /// `Some("MK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("MK");
+    /// `Some("9989")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("9989");
+    /// `Some("Maqedoni")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Maqedoni");
+    /// `Some(807)`
+    pub const COUNTRY_NUM: Option<i64> = Some(807);
+    /// `Some("MK")`
+    pub const COUNTRY_POST: Option<&str> = Some("MK");
+    /// `Some("sq")`
+    pub const LANG_AB: Option<&str> = Some("sq");
+    /// `Some("alb")`
+    pub const LANG_LIB: Option<&str> = Some("alb");
+    /// `Some("shqip")`
+    pub const LANG_NAME: Option<&str> = Some("shqip");
+    /// `Some("sqi")`
+    pub const LANG_TERM: Option<&str> = Some("sqi");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("465 4th St. 2nd Floor, Palisades Park, NJ 07650, U.S.A.")`
+    pub const ADDRESS: Option<&str> = Some("465 4th St. 2nd Floor, Palisades Park, NJ 07650, U.S.A.");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2010-04-06")`
+    pub const DATE: Option<&str> = Some("2010-04-06");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Albanian")`
+    pub const LANGUAGE: Option<&str> = Some("Albanian");
+    /// `Some("0.01")`
+    pub const REVISION: Option<&str> = Some("0.01");
+    /// `Some("Albanian Language Team")`
+    pub const SOURCE: Option<&str> = Some("Albanian Language Team");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Macedonia")`
+    pub const TERRITORY: Option<&str> = Some("Macedonia");
+    /// `Some("Albanian language locale for Macedonia")`
+    pub const TITLE: Option<&str> = Some("Albanian language locale for Macedonia");
+}
+pub use super::sq_AL::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"den"`
+    pub const CURRENCY_SYMBOL: &str = "den";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"MKD "`
+    pub const INT_CURR_SYMBOL: &str = "MKD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::sq_AL::LC_NUMERIC;
+pub use super::mk_MK::LC_TELEPHONE;
+pub use super::sq_AL::LC_TIME;
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("SRB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SRB");
+    /// `Some("RS")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RS");
+    /// `Some("SRB")`
+    pub const COUNTRY_CAR: Option<&str> = Some("SRB");
+    /// `Some("86")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("86");
+    /// `Some("Србија")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Србија");
+    /// `Some(688)`
+    pub const COUNTRY_NUM: Option<i64> = Some(688);
+    /// `Some("RS")`
+    pub const COUNTRY_POST: Option<&str> = Some("RS");
+    /// `Some("sr")`
+    pub const LANG_AB: Option<&str> = Some("sr");
+    /// `Some("srp")`
+    pub const LANG_LIB: Option<&str> = Some("srp");
+    /// `Some("српски")`
+    pub const LANG_NAME: Option<&str> = Some("српски");
+    /// `Some("srp")`
+    pub const LANG_TERM: Option<&str> = Some("srp");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("Danilo Segan")`
+    pub const CONTACT: Option<&str> = Some("Danilo Segan");
+    /// `Some("2006-10-09")`
+    pub const DATE: Option<&str> = Some("2006-10-09");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Serbian")`
+    pub const LANGUAGE: Option<&str> = Some("Serbian");
+    /// `Some("1.3")`
+    pub const REVISION: Option<&str> = Some("1.3");
+    /// `Some("sr_YU, sr_CS locale")`
+    pub const SOURCE: Option<&str> = Some("sr_YU, sr_CS locale");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Serbia")`
+    pub const TERRITORY: Option<&str> = Some("Serbia");
+    /// `Some("Serbian locale for Serbia")`
+    pub const TITLE: Option<&str> = Some("Serbian locale for Serbia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNНн]"`
+    pub const NOEXPR: &str = "^[-0nNНн]";
+    /// `Some("не")`
+    pub const NOSTR: Option<&str> = Some("не");
+    /// `"^[+1yYdDДд]"`
+    pub const YESEXPR: &str = "^[+1yYdDДд]";
+    /// `Some("да")`
+    pub const YESSTR: Option<&str> = Some("да");
+}
+pub mod LC_MONETARY {
+    /// `"дин"`
+    pub const CURRENCY_SYMBOL: &str = "дин";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"RSD "`
+    pub const INT_CURR_SYMBOL: &str = "RSD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("381")`
+    pub const INT_PREFIX: Option<&str> = Some("381");
+    /// `Some("99")`
+    pub const INT_SELECT: Option<&str> = Some("99");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["нед", "пон", "уто", "сре", "чет", "пет", "суб"]`
+    pub const ABDAY: &[&str] = &["нед", "пон", "уто", "сре", "чет", "пет", "суб"];
+    /// `&["јан", "феб", "мар", "апр", "мај", "јун", "јул", "авг", "сеп", "окт", "нов", "дец"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_23;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %e. %b %Y.  %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %e. %b %Y.  %H:%M:%S %Z");
+    /// `&["недеља", "понедељак", "уторак", "среда", "четвртак", "петак", "субота"]`
+    pub const DAY: &[&str] = &["недеља", "понедељак", "уторак", "среда", "четвртак", "петак", "субота"];
+    /// `"%d.%m.%Y."`
+    pub const D_FMT: &str = "%d.%m.%Y.";
+    /// `"%A, %d. %B %Y. %T %Z"`
+    pub const D_T_FMT: &str = "%A, %d. %B %Y. %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["јануар", "фебруар", "март", "април", "мај", "јун", "јул", "август", "септембар", "октобар", "новембар", "децембар"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_19;
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%T"`
+    pub const T_FMT_AMPM: &str = "%T";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%T")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%T");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
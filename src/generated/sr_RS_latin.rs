@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("SRB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SRB");
+    /// `Some("RS")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RS");
+    /// `Some("SRB")`
+    pub const COUNTRY_CAR: Option<&str> = Some("SRB");
+    /// `Some("86")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("86");
+    /// `Some("Srbija")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Srbija");
+    /// `Some(688)`
+    pub const COUNTRY_NUM: Option<i64> = Some(688);
+    /// `Some("RS")`
+    pub const COUNTRY_POST: Option<&str> = Some("RS");
+    /// `Some("sr")`
+    pub const LANG_AB: Option<&str> = Some("sr");
+    /// `Some("srp")`
+    pub const LANG_LIB: Option<&str> = Some("srp");
+    /// `Some("srpski")`
+    pub const LANG_NAME: Option<&str> = Some("srpski");
+    /// `Some("srp")`
+    pub const LANG_TERM: Option<&str> = Some("srp");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("Danilo Segan")`
+    pub const CONTACT: Option<&str> = Some("Danilo Segan");
+    /// `Some("2006-10-09")`
+    pub const DATE: Option<&str> = Some("2006-10-09");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Serbian")`
+    pub const LANGUAGE: Option<&str> = Some("Serbian");
+    /// `Some("1.3")`
+    pub const REVISION: Option<&str> = Some("1.3");
+    /// `Some("sr_YU, sr_CS locale")`
+    pub const SOURCE: Option<&str> = Some("sr_YU, sr_CS locale");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Serbia")`
+    pub const TERRITORY: Option<&str> = Some("Serbia");
+    /// `Some("Serbian Latin locale for Serbia")`
+    pub const TITLE: Option<&str> = Some("Serbian Latin locale for Serbia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ne")`
+    pub const NOSTR: Option<&str> = Some("ne");
+    /// `"^[+1dDyY]"`
+    pub const YESEXPR: &str = "^[+1dDyY]";
+    /// `Some("da")`
+    pub const YESSTR: Option<&str> = Some("da");
+}
+pub mod LC_MONETARY {
+    /// `"din"`
+    pub const CURRENCY_SYMBOL: &str = "din";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"RSD "`
+    pub const INT_CURR_SYMBOL: &str = "RSD ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::sr_RS::LC_NUMERIC;
+pub use super::sr_RS::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ned", "pon", "uto", "sre", "čet", "pet", "sub"]`
+    pub const ABDAY: &[&str] = &["ned", "pon", "uto", "sre", "čet", "pet", "sub"];
+    /// `&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "avg", "sep", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_15;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %e. %b %Y.  %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %e. %b %Y.  %H:%M:%S %Z");
+    /// `&["nedelja", "ponedeljak", "utorak", "sreda", "četvrtak", "petak", "subota"]`
+    pub const DAY: &[&str] = &["nedelja", "ponedeljak", "utorak", "sreda", "četvrtak", "petak", "subota"];
+    /// `"%d.%m.%Y."`
+    pub const D_FMT: &str = "%d.%m.%Y.";
+    /// `"%A, %d. %B %Y. %T %Z"`
+    pub const D_T_FMT: &str = "%A, %d. %B %Y. %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"]`
+    pub const MON: &[&str] = &["januar", "februar", "mart", "april", "maj", "jun", "jul", "avgust", "septembar", "oktobar", "novembar", "decembar"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%T"`
+    pub const T_FMT_AMPM: &str = "%T";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%T")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%T");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
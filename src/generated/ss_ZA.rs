@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("ZAF")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ZAF");
+    /// `Some("ZA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ZA");
+    /// `Some("ZA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ZA");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("iNingizimu Afrika")`
+    pub const COUNTRY_NAME: Option<&str> = Some("iNingizimu Afrika");
+    /// `Some(710)`
+    pub const COUNTRY_NUM: Option<i64> = Some(710);
+    /// `Some("ZA")`
+    pub const COUNTRY_POST: Option<&str> = Some("ZA");
+    /// `Some("ss")`
+    pub const LANG_AB: Option<&str> = Some("ss");
+    /// `Some("ssw")`
+    pub const LANG_LIB: Option<&str> = Some("ssw");
+    /// `Some("siSwati")`
+    pub const LANG_NAME: Option<&str> = Some("siSwati");
+    /// `Some("ssw")`
+    pub const LANG_TERM: Option<&str> = Some("ssw");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("PO Box 28364, Sunnyside, 0132, South Africa")`
+    pub const ADDRESS: Option<&str> = Some("PO Box 28364, Sunnyside, 0132, South Africa");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Dwayne Bailey")`
+    pub const CONTACT: Option<&str> = Some("Dwayne Bailey");
+    /// `Some("2005-10-13")`
+    pub const DATE: Option<&str> = Some("2005-10-13");
+    /// `Some("dwayne@translate.org.za")`
+    pub const EMAIL: Option<&str> = Some("dwayne@translate.org.za");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Swati")`
+    pub const LANGUAGE: Option<&str> = Some("Swati");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("Zuza Software Foundation (Translate.org.za)")`
+    pub const SOURCE: Option<&str> = Some("Zuza Software Foundation (Translate.org.za)");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("South Africa")`
+    pub const TERRITORY: Option<&str> = Some("South Africa");
+    /// `Some("Swati locale for South Africa")`
+    pub const TITLE: Option<&str> = Some("Swati locale for South Africa");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNaA]"`
+    pub const NOEXPR: &str = "^[-0nNaA]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::en_ZA::LC_MONETARY;
+pub use super::en_ZA::LC_NUMERIC;
+pub use super::en_ZA::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Son", "Mso", "Bil", "Tsa", "Ne", "Hla", "Mgc"]`
+    pub const ABDAY: &[&str] = &["Son", "Mso", "Bil", "Tsa", "Ne", "Hla", "Mgc"];
+    /// `&["Bhi", "Van", "Vul", "Mab", "Khk", "Nhl", "Kho", "Ngc", "Nyo", "Imp", "Lwe", "Ngo"]`
+    pub const ABMON: &[&str] = &["Bhi", "Van", "Vul", "Mab", "Khk", "Nhl", "Kho", "Ngc", "Nyo", "Imp", "Lwe", "Ngo"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %b %-e %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %b %-e %H:%M:%S %Z %Y");
+    /// `&["Lisontfo", "uMsombuluko", "Lesibili", "Lesitsatfu", "Lesine", "Lesihlanu", "uMgcibelo"]`
+    pub const DAY: &[&str] = &["Lisontfo", "uMsombuluko", "Lesibili", "Lesitsatfu", "Lesine", "Lesihlanu", "uMgcibelo"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%a %-e %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %-e %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Bhimbidvwane", "iNdlovane", "iNdlovulenkhulu", "Mabasa", "Inkhwenkhweti", "iNhlaba", "Kholwane", "iNgci", "iNyoni", "Impala", "Lweti", "iNgongoni"]`
+    pub const MON: &[&str] = &["Bhimbidvwane", "iNdlovane", "iNdlovulenkhulu", "Mabasa", "Inkhwenkhweti", "iNhlaba", "Kholwane", "iNgci", "iNyoni", "Impala", "Lweti", "iNgongoni"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
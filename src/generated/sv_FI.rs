@@ -0,0 +1,129 @@
+pub mod LC_ADDRESS {
+    /// `Some("FIN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("FIN");
+    /// `Some("FI")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("FI");
+    /// `Some("FIN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("FIN");
+    /// `Some("952")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("952");
+    /// `Some("Finland")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Finland");
+    /// `Some(246)`
+    pub const COUNTRY_NUM: Option<i64> = Some(246);
+    /// `Some("FI")`
+    pub const COUNTRY_POST: Option<&str> = Some("FI");
+    /// `Some("sv")`
+    pub const LANG_AB: Option<&str> = Some("sv");
+    /// `Some("swe")`
+    pub const LANG_LIB: Option<&str> = Some("swe");
+    /// `Some("svenska")`
+    pub const LANG_NAME: Option<&str> = Some("svenska");
+    /// `Some("swe")`
+    pub const LANG_TERM: Option<&str> = Some("swe");
+    /// `Some("%f%N%d%N%b%N%a%N%s %h%t%e%t%r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%d%N%b%N%a%N%s %h%t%e%t%r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark")`
+    pub const ADDRESS: Option<&str> = Some("Sankt Jørgens Alle 8, DK-1615 København V, Danmark");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_10);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Swedish")`
+    pub const LANGUAGE: Option<&str> = Some("Swedish");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("RAP")`
+    pub const SOURCE: Option<&str> = Some("RAP");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Finland")`
+    pub const TERRITORY: Option<&str> = Some("Finland");
+    /// `Some("Swedish locale for Finland")`
+    pub const TITLE: Option<&str> = Some("Swedish locale for Finland");
+}
+pub use super::sv_SE::LC_MESSAGES;
+pub use super::fi_FI::LC_MONETARY;
+pub use super::fi_FI::LC_NUMERIC;
+pub use super::fi_FI::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["sön", "mån", "tis", "ons", "tor", "fre", "lör"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_13;
+    /// `&["jan", "feb", "mar", "apr", "maj", "jun", "jul", "aug", "sep", "okt", "nov", "dec"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_14;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d.%-m.%Y %H.%M.%S %z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d.%-m.%Y %H.%M.%S %z");
+    /// `&["söndag", "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_13;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %e. %B %Y %H.%M.%S"`
+    pub const D_T_FMT: &str = "%a %e. %B %Y %H.%M.%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["januari", "februari", "mars", "april", "maj", "juni", "juli", "augusti", "september", "oktober", "november", "december"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_15;
+    /// `"%H.%M.%S"`
+    pub const T_FMT: &str = "%H.%M.%S";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,181 @@
+pub mod LC_ADDRESS {
+    /// `Some("TZA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TZA");
+    /// `Some("TZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TZ");
+    /// `Some("EAT")`
+    pub const COUNTRY_CAR: Option<&str> = Some("EAT");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Tanzania")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Tanzania");
+    /// `Some(834)`
+    pub const COUNTRY_NUM: Option<i64> = Some(834);
+    /// `Some("TZ")`
+    pub const COUNTRY_POST: Option<&str> = Some("TZ");
+    /// `Some("sw")`
+    pub const LANG_AB: Option<&str> = Some("sw");
+    /// `Some("swa")`
+    pub const LANG_LIB: Option<&str> = Some("swa");
+    /// `Some("Kiswahili")`
+    pub const LANG_NAME: Option<&str> = Some("Kiswahili");
+    /// `Some("swa")`
+    pub const LANG_TERM: Option<&str> = Some("swa");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `None`
+    pub const ADDRESS: Option<&str> = None;
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("Martin Benjamin")`
+    pub const CONTACT: Option<&str> = Some("Martin Benjamin");
+    /// `Some("2011-03-07")`
+    pub const DATE: Option<&str> = Some("2011-03-07");
+    /// `Some("locales@kamusi.org")`
+    pub const EMAIL: Option<&str> = Some("locales@kamusi.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Swahili")`
+    pub const LANGUAGE: Option<&str> = Some("Swahili");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Kamusi Project")`
+    pub const SOURCE: Option<&str> = Some("Kamusi Project");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Tanzania")`
+    pub const TERRITORY: Option<&str> = Some("Tanzania");
+    /// `Some("Swahili locale for Tanzania")`
+    pub const TITLE: Option<&str> = Some("Swahili locale for Tanzania");
+}
+pub use super::sw_KE::LC_MESSAGES;
+pub mod LC_MONETARY {
+    /// `"TSh"`
+    pub const CURRENCY_SYMBOL: &str = "TSh";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TZS "`
+    pub const INT_CURR_SYMBOL: &str = "TZS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::sw_KE::LC_NUMERIC;
+pub mod LC_TELEPHONE {
+    /// `Some("255")`
+    pub const INT_PREFIX: Option<&str> = Some("255");
+    /// `Some("000")`
+    pub const INT_SELECT: Option<&str> = Some("000");
+    /// `Some("%A %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%A %l");
+    /// `Some("%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["J2", "J3", "J4", "J5", "Alh", "Ij", "J1"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_2;
+    /// `&["Jan", "Feb", "Mac", "Apr", "Mei", "Jun", "Jul", "Ago", "Sep", "Okt", "Nov", "Des"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_2;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["asubuhi", "alasiri"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_4;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Jumapili", "Jumatatu", "Jumanne", "Jumatano", "Alhamisi", "Ijumaa", "Jumamosi"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_3;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%e %B %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%e %B %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Januari", "Februari", "Machi", "Aprili", "Mei", "Juni", "Julai", "Agosti", "Septemba", "Oktoba", "Novemba", "Desemba"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_3;
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("POL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("POL");
+    /// `Some("PL")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PL");
+    /// `Some("PL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Polska")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Polska");
+    /// `Some(616)`
+    pub const COUNTRY_NUM: Option<i64> = Some(616);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("szl")`
+    pub const LANG_LIB: Option<&str> = Some("szl");
+    /// `Some("ślōnskŏ gŏdka")`
+    pub const LANG_NAME: Option<&str> = Some("ślōnskŏ gŏdka");
+    /// `Some("szl")`
+    pub const LANG_TERM: Option<&str> = Some("szl");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_8);
+    /// `Some("Przemyslaw Buczkowski")`
+    pub const CONTACT: Option<&str> = Some("Przemyslaw Buczkowski");
+    /// `Some("2012-07-17")`
+    pub const DATE: Option<&str> = Some("2012-07-17");
+    /// `Some("libc-alpha@sourceware.org")`
+    pub const EMAIL: Option<&str> = Some("libc-alpha@sourceware.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Silesian")`
+    pub const LANGUAGE: Option<&str> = Some("Silesian");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("szl_PL locale")`
+    pub const SOURCE: Option<&str> = Some("szl_PL locale");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Poland")`
+    pub const TERRITORY: Option<&str> = Some("Poland");
+    /// `Some("Silesian locale for Poland")`
+    pub const TITLE: Option<&str> = Some("Silesian locale for Poland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1JjTtYy]"`
+    pub const YESEXPR: &str = "^[+1JjTtYy]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::pl_PL::LC_MONETARY;
+pub use super::pl_PL::LC_NUMERIC;
+pub use super::pl_PL::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["niy", "pyń", "wto", "str", "szt", "piō", "sob"]`
+    pub const ABDAY: &[&str] = &["niy", "pyń", "wto", "str", "szt", "piō", "sob"];
+    /// `&["sty", "lut", "mar", "kwi", "moj", "czy", "lip", "sie", "wrz", "paź", "lis", "gru"]`
+    pub const ABMON: &[&str] = &["sty", "lut", "mar", "kwi", "moj", "czy", "lip", "sie", "wrz", "paź", "lis", "gru"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["styczyń", "luty", "marzec", "kwieciyń", "moj", "czyrwiec", "lipiec", "sierpiyń", "wrzesiyń", "październik", "listopad", "grudziyń"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["styczyń", "luty", "marzec", "kwieciyń", "moj", "czyrwiec", "lipiec", "sierpiyń", "wrzesiyń", "październik", "listopad", "grudziyń"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a, %-d %b %Y, %T %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a, %-d %b %Y, %T %Z");
+    /// `&["niydziela", "pyńdziałek", "wtorek", "strzoda", "sztwortek", "piōntek", "sobota"]`
+    pub const DAY: &[&str] = &["niydziela", "pyńdziałek", "wtorek", "strzoda", "sztwortek", "piōntek", "sobota"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a, %-d %b %Y, %T"`
+    pub const D_T_FMT: &str = "%a, %-d %b %Y, %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "sierpnia", "września", "października", "listopada", "grudnia"]`
+    pub const MON: &[&str] = &["stycznia", "lutego", "marca", "kwietnia", "moja", "czyrwca", "lipca", "sierpnia", "września", "października", "listopada", "grudnia"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,145 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("இந\u{bcd}திய\u{bbe}")`
+    pub const COUNTRY_NAME: Option<&str> = Some("இந\u{bcd}திய\u{bbe}");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ta")`
+    pub const LANG_AB: Option<&str> = Some("ta");
+    /// `Some("tam")`
+    pub const LANG_LIB: Option<&str> = Some("tam");
+    /// `Some("தமிழ\u{bcd}")`
+    pub const LANG_NAME: Option<&str> = Some("தமிழ\u{bcd}");
+    /// `Some("tam")`
+    pub const LANG_TERM: Option<&str> = Some("tam");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000,October,27 (XML source:2000,July,20)")`
+    pub const DATE: Option<&str> = Some("2000,October,27 (XML source:2000,July,20)");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tamil")`
+    pub const LANGUAGE: Option<&str> = Some("Tamil");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Tamil language locale for India")`
+    pub const TITLE: Option<&str> = Some("Tamil language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNஇ]"`
+    pub const NOEXPR: &str = "^[-0nNஇ]";
+    /// `Some("இல\u{bcd}லை")`
+    pub const NOSTR: Option<&str> = Some("இல\u{bcd}லை");
+    /// `"^[+1yYஆ]"`
+    pub const YESEXPR: &str = "^[+1yYஆ]";
+    /// `Some("ஆம\u{bcd}")`
+    pub const YESSTR: Option<&str> = Some("ஆம\u{bcd}");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ஞ\u{bbe}", "தி", "செ", "பு", "வி", "வெ", "ச"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_21;
+    /// `&["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."]`
+    pub const ABMON: &[&str] = &["ஜன.", "பிப\u{bcd}.", "ம\u{bbe}ர\u{bcd}.", "ஏப\u{bcd}.", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக.", "செப\u{bcd}.", "அக\u{bcd}.", "நவ.", "டிச."];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["க\u{bbe}லை", "ம\u{bbe}லை"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_13;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ஞ\u{bbe}யிறு", "திங\u{bcd}கள\u{bcd}", "செவ\u{bcd}வ\u{bbe}ய\u{bcd}", "புதன\u{bcd}", "விய\u{bbe}ழன\u{bcd}", "வெள\u{bcd}ளி", "சனி"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_22;
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %B %Y %p %I:%M:%S %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %p %I:%M:%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_27;
+    /// `"%p %I:%M:%S %Z"`
+    pub const T_FMT: &str = "%p %I:%M:%S %Z";
+    /// `"%p %I:%M:%S %Z"`
+    pub const T_FMT_AMPM: &str = "%p %I:%M:%S %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p %I:%M:%S %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %I:%M:%S %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,130 @@
+pub mod LC_ADDRESS {
+    /// `Some("LKA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("LKA");
+    /// `Some("LK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("LK");
+    /// `Some("CL")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CL");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("இலங\u{bcd}கை")`
+    pub const COUNTRY_NAME: Option<&str> = Some("இலங\u{bcd}கை");
+    /// `Some(144)`
+    pub const COUNTRY_NUM: Option<i64> = Some(144);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ta")`
+    pub const LANG_AB: Option<&str> = Some("ta");
+    /// `Some("tam")`
+    pub const LANG_LIB: Option<&str> = Some("tam");
+    /// `Some("தமிழ\u{bcd}")`
+    pub const LANG_NAME: Option<&str> = Some("தமிழ\u{bcd}");
+    /// `Some("tam")`
+    pub const LANG_TERM: Option<&str> = Some("tam");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("30/36Q -2/1,Charles Apartments, De Silva Cross Rd, Kalubowila, Dehiwela, SriLanka.")`
+    pub const ADDRESS: Option<&str> = Some("30/36Q -2/1,Charles Apartments, De Silva Cross Rd, Kalubowila, Dehiwela, SriLanka.");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("94-777-315206")`
+    pub const CONTACT: Option<&str> = Some("94-777-315206");
+    /// `Some("2011,August,11")`
+    pub const DATE: Option<&str> = Some("2011,August,11");
+    /// `Some("yogaraj.ubuntu@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("yogaraj.ubuntu@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tamil")`
+    pub const LANGUAGE: Option<&str> = Some("Tamil");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("J.Yogaraj")`
+    pub const SOURCE: Option<&str> = Some("J.Yogaraj");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Sri Lanka")`
+    pub const TERRITORY: Option<&str> = Some("Sri Lanka");
+    /// `Some("Tamil language locale for Sri Lanka")`
+    pub const TITLE: Option<&str> = Some("Tamil language locale for Sri Lanka");
+}
+pub use super::ta_IN::LC_MESSAGES;
+pub use super::si_LK::LC_MONETARY;
+pub use super::ta_IN::LC_NUMERIC;
+pub use super::si_LK::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ஞ\u{bbe}", "தி", "செ", "பு", "வி", "வெ", "ச"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_21;
+    /// `&["ஜன", "பிப\u{bcd}", "ம\u{bbe}ர\u{bcd}", "ஏப\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக", "செப\u{bcd}", "அக\u{bcd}", "நவ", "டிச"]`
+    pub const ABMON: &[&str] = &["ஜன", "பிப\u{bcd}", "ம\u{bbe}ர\u{bcd}", "ஏப\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆக", "செப\u{bcd}", "அக\u{bcd}", "நவ", "டிச"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["க\u{bbe}லை", "ம\u{bbe}லை"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_13;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ஞ\u{bbe}யிறு", "திங\u{bcd}கள\u{bcd}", "செவ\u{bcd}வ\u{bbe}ய\u{bcd}", "புதன\u{bcd}", "விய\u{bbe}ழன\u{bcd}", "வெள\u{bcd}ளி", "சனி"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_22;
+    /// `"%-d/%-m/%y"`
+    pub const D_FMT: &str = "%-d/%-m/%y";
+    /// `"%A %d %B %Y %H:%M:%S %Z"`
+    pub const D_T_FMT: &str = "%A %d %B %Y %H:%M:%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ஜனவரி", "பிப\u{bcd}ரவரி", "ம\u{bbe}ர\u{bcd}ச\u{bcd}", "ஏப\u{bcd}ரல\u{bcd}", "மே", "ஜூன\u{bcd}", "ஜூலை", "ஆகஸ\u{bcd}ட\u{bcd}", "செப\u{bcd}டம\u{bcd}பர\u{bcd}", "அக\u{bcd}டோபர\u{bcd}", "நவம\u{bcd}பர\u{bcd}", "டிசம\u{bcd}பர\u{bcd}"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_27;
+    /// `"%H:%M:%S %Z"`
+    pub const T_FMT: &str = "%H:%M:%S %Z";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,145 @@
+pub mod LC_ADDRESS {
+    /// `Some("IND")`
+    pub const COUNTRY_AB3: Option<&str> = Some("IND");
+    /// `Some("IN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("IN");
+    /// `Some("IND")`
+    pub const COUNTRY_CAR: Option<&str> = Some("IND");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("భ\u{c3e}రతద\u{c47}శం")`
+    pub const COUNTRY_NAME: Option<&str> = Some("భ\u{c3e}రతద\u{c47}శం");
+    /// `Some(356)`
+    pub const COUNTRY_NUM: Option<i64> = Some(356);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("te")`
+    pub const LANG_AB: Option<&str> = Some("te");
+    /// `Some("tel")`
+    pub const LANG_LIB: Option<&str> = Some("tel");
+    /// `Some("త\u{c46}లుగు")`
+    pub const LANG_NAME: Option<&str> = Some("త\u{c46}లుగు");
+    /// `Some("tel")`
+    pub const LANG_TERM: Option<&str> = Some("tel");
+    /// `Some("%a%N%d%N%f%N%r%t%e%N%h%t%b%N%s%t%N%T%t%z%N%S%N%c")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%d%N%f%N%r%t%e%N%h%t%b%N%s%t%N%T%t%z%N%S%N%c");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-10-05")`
+    pub const DATE: Option<&str> = Some("2004-10-05");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Telugu")`
+    pub const LANGUAGE: Option<&str> = Some("Telugu");
+    /// `Some("0.95")`
+    pub const REVISION: Option<&str> = Some("0.95");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("India")`
+    pub const TERRITORY: Option<&str> = Some("India");
+    /// `Some("Telugu language locale for India")`
+    pub const TITLE: Option<&str> = Some("Telugu language locale for India");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNవ]"`
+    pub const NOEXPR: &str = "^[-0nNవ]";
+    /// `Some("వద\u{c4d}దు")`
+    pub const NOSTR: Option<&str> = Some("వద\u{c4d}దు");
+    /// `"^[+1yYఅ]"`
+    pub const YESEXPR: &str = "^[+1yYఅ]";
+    /// `Some("అవును")`
+    pub const YESSTR: Option<&str> = Some("అవును");
+}
+pub use super::hi_IN::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 2]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_2;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::hi_IN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ఆద\u{c3f}", "స\u{c4b}మ", "మంగళ", "బుధ", "గురు", "శుక\u{c4d}ర", "శన\u{c3f}"]`
+    pub const ABDAY: &[&str] = &["ఆద\u{c3f}", "స\u{c4b}మ", "మంగళ", "బుధ", "గురు", "శుక\u{c4d}ర", "శన\u{c3f}"];
+    /// `&["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"]`
+    pub const ABMON: &[&str] = &["జన", "ఫ\u{c3f}బ\u{c4d}ర", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగ", "స\u{c46}ప\u{c4d}ట\u{c46}ం", "అక\u{c4d}ట\u{c4b}", "నవం", "డ\u{c3f}స\u{c46}ం"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ఉ.", "స\u{c3e}."]`
+    pub const AM_PM: &[&str] = &["ఉ.", "స\u{c3e}."];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["ఆద\u{c3f}వ\u{c3e}రం", "స\u{c4b}మవ\u{c3e}రం", "మంగళవ\u{c3e}రం", "బుధవ\u{c3e}రం", "గురువ\u{c3e}రం", "శుక\u{c4d}రవ\u{c3e}రం", "శన\u{c3f}వ\u{c3e}రం"]`
+    pub const DAY: &[&str] = &["ఆద\u{c3f}వ\u{c3e}రం", "స\u{c4b}మవ\u{c3e}రం", "మంగళవ\u{c3e}రం", "బుధవ\u{c3e}రం", "గురువ\u{c3e}రం", "శుక\u{c4d}రవ\u{c3e}రం", "శన\u{c3f}వ\u{c3e}రం"];
+    /// `"%d-%m-%y"`
+    pub const D_FMT: &str = "%d-%m-%y";
+    /// `"%B %d %A %Y %p%I.%M.%S %Z"`
+    pub const D_T_FMT: &str = "%B %d %A %Y %p%I.%M.%S %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"]`
+    pub const MON: &[&str] = &["జనవర\u{c3f}", "ఫ\u{c3f}బ\u{c4d}రవర\u{c3f}", "మ\u{c3e}ర\u{c4d}చ\u{c3f}", "ఏప\u{c4d}ర\u{c3f}ల\u{c4d}", "మ\u{c47}", "జూన\u{c4d}", "జుల\u{c48}", "ఆగస\u{c4d}టు", "స\u{c46}ప\u{c4d}ట\u{c46}ంబర\u{c4d}", "అక\u{c4d}ట\u{c4b}బర\u{c4d}", "నవంబర\u{c4d}", "డ\u{c3f}స\u{c46}ంబర\u{c4d}"];
+    /// `"%p%I.%M.%S %Z"`
+    pub const T_FMT: &str = "%p%I.%M.%S %Z";
+    /// `"%p%I.%M.%S %Z"`
+    pub const T_FMT_AMPM: &str = "%p%I.%M.%S %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p%I.%M.%S %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p%I.%M.%S %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,196 @@
+pub mod LC_ADDRESS {
+    /// `Some("TJK")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TJK");
+    /// `Some("TJ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TJ");
+    /// `Some("TJ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("TJ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Тоҷикистон")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Тоҷикистон");
+    /// `Some(762)`
+    pub const COUNTRY_NUM: Option<i64> = Some(762);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("tg")`
+    pub const LANG_AB: Option<&str> = Some("tg");
+    /// `Some("tgk")`
+    pub const LANG_LIB: Option<&str> = Some("tgk");
+    /// `Some("тоҷикӣ")`
+    pub const LANG_NAME: Option<&str> = Some("тоҷикӣ");
+    /// `Some("tgk")`
+    pub const LANG_TERM: Option<&str> = Some("tgk");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Pablo Saratxaga, Roger Kovacs")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga, Roger Kovacs");
+    /// `Some("2004-01-09")`
+    pub const DATE: Option<&str> = Some("2004-01-09");
+    /// `Some("pablo@mandrakesoft.com, ROGERKO@micromotion.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com, ROGERKO@micromotion.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tajik")`
+    pub const LANGUAGE: Option<&str> = Some("Tajik");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("Roger Kovacs")`
+    pub const SOURCE: Option<&str> = Some("Roger Kovacs");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Tajikistan")`
+    pub const TERRITORY: Option<&str> = Some("Tajikistan");
+    /// `Some("Tajik language locale for Tajikistan")`
+    pub const TITLE: Option<&str> = Some("Tajik language locale for Tajikistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNНн]"`
+    pub const NOEXPR: &str = "^[-0nNНн]";
+    /// `Some("не")`
+    pub const NOSTR: Option<&str> = Some("не");
+    /// `"^[+1yYҲҳХхДд]"`
+    pub const YESEXPR: &str = "^[+1yYҲҳХхДд]";
+    /// `Some("ҳа")`
+    pub const YESSTR: Option<&str> = Some("ҳа");
+}
+pub mod LC_MONETARY {
+    /// `"руб"`
+    pub const CURRENCY_SYMBOL: &str = "руб";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TJS "`
+    pub const INT_CURR_SYMBOL: &str = "TJS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("992")`
+    pub const INT_PREFIX: Option<&str> = Some("992");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a%t%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a%t%l");
+}
+pub mod LC_TIME {
+    /// `&["Вск", "Пнд", "Втр", "Срд", "Чтв", "Птн", "Сбт"]`
+    pub const ABDAY: &[&str] = &["Вск", "Пнд", "Втр", "Срд", "Чтв", "Птн", "Сбт"];
+    /// `&["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_21;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Воскресенье", "Понедельник", "Вторник", "Среда", "Четверг", "Пятница", "Суббота"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_17;
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Января", "Февраля", "Марта", "Апреля", "Мая", "Июня", "Июля", "Августа", "Сентября", "Октября", "Ноября", "Декабря"]`
+    pub const MON: &[&str] = &["Января", "Февраля", "Марта", "Апреля", "Мая", "Июня", "Июля", "Августа", "Сентября", "Октября", "Ноября", "Декабря"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
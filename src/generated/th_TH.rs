@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("THA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("THA");
+    /// `Some("TH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TH");
+    /// `Some("T")`
+    pub const COUNTRY_CAR: Option<&str> = Some("T");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ไทย")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ไทย");
+    /// `Some(764)`
+    pub const COUNTRY_NUM: Option<i64> = Some(764);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("th")`
+    pub const LANG_AB: Option<&str> = Some("th");
+    /// `Some("tha")`
+    pub const LANG_LIB: Option<&str> = Some("tha");
+    /// `Some("ไทย")`
+    pub const LANG_NAME: Option<&str> = Some("ไทย");
+    /// `Some("tha")`
+    pub const LANG_TERM: Option<&str> = Some("tha");
+    /// `Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%r%t%e%t%b%N%h%t%s%N%T%N%S%N%z%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-06-29")`
+    pub const DATE: Option<&str> = Some("2000-06-29");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Thai")`
+    pub const LANGUAGE: Option<&str> = Some("Thai");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Thailand")`
+    pub const TERRITORY: Option<&str> = Some("Thailand");
+    /// `Some("Thai locale for Thailand")`
+    pub const TITLE: Option<&str> = Some("Thai locale for Thailand");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNม]"`
+    pub const NOEXPR: &str = "^[-0nNม]";
+    /// `Some("ไม\u{e48}ใช\u{e48}")`
+    pub const NOSTR: Option<&str> = Some("ไม\u{e48}ใช\u{e48}");
+    /// `"^[+1yYช]"`
+    pub const YESEXPR: &str = "^[+1yYช]";
+    /// `Some("ใช\u{e48}")`
+    pub const YESSTR: Option<&str> = Some("ใช\u{e48}");
+}
+pub mod LC_MONETARY {
+    /// `"฿"`
+    pub const CURRENCY_SYMBOL: &str = "฿";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"THB "`
+    pub const INT_CURR_SYMBOL: &str = "THB ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `2`
+    pub const N_SEP_BY_SPACE: i64 = 2;
+    /// `4`
+    pub const N_SIGN_POSN: i64 = 4;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `2`
+    pub const P_SEP_BY_SPACE: i64 = 2;
+    /// `4`
+    pub const P_SIGN_POSN: i64 = 4;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("66")`
+    pub const INT_PREFIX: Option<&str> = Some("66");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `Some("0-%a%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("0-%a%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["อา.", "จ.", "อ.", "พ.", "พฤ.", "ศ.", "ส."]`
+    pub const ABDAY: &[&str] = &["อา.", "จ.", "อ.", "พ.", "พฤ.", "ศ.", "ส."];
+    /// `&["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."]`
+    pub const ABMON: &[&str] = &["ม.ค.", "ก.พ.", "ม\u{e35}.ค.", "เม.ย.", "พ.ค.", "ม\u{e34}.ย.", "ก.ค.", "ส.ค.", "ก.ย.", "ต.ค.", "พ.ย.", "ธ.ค."];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %e %b %Ey %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%a %e %b %Ey %H:%M:%S %Z");
+    /// `&["อาท\u{e34}ตย\u{e4c}", "จ\u{e31}นทร\u{e4c}", "อ\u{e31}งคาร", "พ\u{e38}ธ", "พฤห\u{e31}สบด\u{e35}", "ศ\u{e38}กร\u{e4c}", "เสาร\u{e4c}"]`
+    pub const DAY: &[&str] = &["อาท\u{e34}ตย\u{e4c}", "จ\u{e31}นทร\u{e4c}", "อ\u{e31}งคาร", "พ\u{e38}ธ", "พฤห\u{e31}สบด\u{e35}", "ศ\u{e38}กร\u{e4c}", "เสาร\u{e4c}"];
+    /// `"%d/%m/%Ey"`
+    pub const D_FMT: &str = "%d/%m/%Ey";
+    /// `"%a %e %b %Ey, %H:%M:%S"`
+    pub const D_T_FMT: &str = "%a %e %b %Ey, %H:%M:%S";
+    /// `Some(&["+:1:-543/01/01:+*:พ.ศ.:%EC %Ey"])`
+    pub const ERA: Option<&[&str]> = Some(&["+:1:-543/01/01:+*:พ.ศ.:%EC %Ey"]);
+    /// `Some("%e %b %Ey")`
+    pub const ERA_D_FMT: Option<&str> = Some("%e %b %Ey");
+    /// `Some("ว\u{e31}น%Aท\u{e35}\u{e48} %e %B %EC %Ey, %H.%M.%S น.")`
+    pub const ERA_D_T_FMT: Option<&str> = Some("ว\u{e31}น%Aท\u{e35}\u{e48} %e %B %EC %Ey, %H.%M.%S น.");
+    /// `Some("%H.%M.%S น.")`
+    pub const ERA_T_FMT: Option<&str> = Some("%H.%M.%S น.");
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"]`
+    pub const MON: &[&str] = &["มกราคม", "ก\u{e38}มภาพ\u{e31}นธ\u{e4c}", "ม\u{e35}นาคม", "เมษายน", "พฤษภาคม", "ม\u{e34}ถ\u{e38}นายน", "กรกฎาคม", "ส\u{e34}งหาคม", "ก\u{e31}นยายน", "ต\u{e38}ลาคม", "พฤศจ\u{e34}กายน", "ธ\u{e31}นวาคม"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
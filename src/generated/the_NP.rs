@@ -0,0 +1,145 @@
+pub mod LC_ADDRESS {
+    /// `Some("NPL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NPL");
+    /// `Some("NP")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NP");
+    /// `Some("NEP")`
+    pub const COUNTRY_CAR: Option<&str> = Some("NEP");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("न\u{947}पाल")`
+    pub const COUNTRY_NAME: Option<&str> = Some("न\u{947}पाल");
+    /// `Some(524)`
+    pub const COUNTRY_NUM: Option<i64> = Some(524);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("the")`
+    pub const LANG_LIB: Option<&str> = Some("the");
+    /// `Some("थार\u{941}")`
+    pub const LANG_NAME: Option<&str> = Some("थार\u{941}");
+    /// `Some("the")`
+    pub const LANG_TERM: Option<&str> = Some("the");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Sangam Market, narayanghat, Nepal")`
+    pub const ADDRESS: Option<&str> = Some("Sangam Market, narayanghat, Nepal");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2013-11-15")`
+    pub const DATE: Option<&str> = Some("2013-11-15");
+    /// `Some("info@chitwanix.com")`
+    pub const EMAIL: Option<&str> = Some("info@chitwanix.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Chitwania Tharu")`
+    pub const LANGUAGE: Option<&str> = Some("Chitwania Tharu");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("Chitwanix OS Development")`
+    pub const SOURCE: Option<&str> = Some("Chitwanix OS Development");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nepal")`
+    pub const TERRITORY: Option<&str> = Some("Nepal");
+    /// `Some("Tharu language locale for Nepal")`
+    pub const TITLE: Option<&str> = Some("Tharu language locale for Nepal");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub use super::ne_NP::LC_MONETARY;
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::ne_NP::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["आइत", "सोम", "म\u{902}गल", "ब\u{941}ध", "बिहि", "श\u{941}क\u{94d}र", "शनि"]`
+    pub const ABDAY: &[&str] = &["आइत", "सोम", "म\u{902}गल", "ब\u{941}ध", "बिहि", "श\u{941}क\u{94d}र", "शनि"];
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_28;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["प\u{942}र\u{94d}वाह\u{94d}न", "अपराह\u{94d}न"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_10;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["आइतबार", "सोमबार", "म\u{902}गलबार", "ब\u{941}धबार", "बिहिबार", "श\u{941}क\u{94d}रबार", "शनिबार"]`
+    pub const DAY: &[&str] = &["आइतबार", "सोमबार", "म\u{902}गलबार", "ब\u{941}धबार", "बिहिबार", "श\u{941}क\u{94d}रबार", "शनिबार"];
+    /// `"%A %d %b %Y"`
+    pub const D_FMT: &str = "%A %d %b %Y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["जनवरी", "फ\u{93c}रवरी", "मार\u{94d}च", "अप\u{94d}र\u{947}ल", "मई", "ज\u{942}न", "ज\u{941}लाई", "अगस\u{94d}त", "सितम\u{94d}बर", "अक\u{94d}ट\u{942}बर", "नवम\u{94d}बर", "दिसम\u{94d}बर"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_24;
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
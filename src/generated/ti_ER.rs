@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("ERI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ERI");
+    /// `Some("ER")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ER");
+    /// `Some("ER")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ER");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ኤርትራ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ኤርትራ");
+    /// `Some(232)`
+    pub const COUNTRY_NUM: Option<i64> = Some(232);
+    /// `Some("ERI")`
+    pub const COUNTRY_POST: Option<&str> = Some("ERI");
+    /// `Some("ti")`
+    pub const LANG_AB: Option<&str> = Some("ti");
+    /// `Some("tir")`
+    pub const LANG_LIB: Option<&str> = Some("tir");
+    /// `Some("ትግርኛ")`
+    pub const LANG_NAME: Option<&str> = Some("ትግርኛ");
+    /// `Some("tir")`
+    pub const LANG_TERM: Option<&str> = Some("tir");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_0);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tigrinya")`
+    pub const LANGUAGE: Option<&str> = Some("Tigrinya");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Eritrea")`
+    pub const TERRITORY: Option<&str> = Some("Eritrea");
+    /// `Some("Tigrigna language locale for Eritrea.")`
+    pub const TITLE: Option<&str> = Some("Tigrigna language locale for Eritrea.");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNነ]"`
+    pub const NOEXPR: &str = "^[-0nNነ]";
+    /// `Some("ነኖእ")`
+    pub const NOSTR: Option<&str> = Some("ነኖእ");
+    /// `"^[+1yYእ]"`
+    pub const YESEXPR: &str = "^[+1yYእ]";
+    /// `Some("እወ")`
+    pub const YESSTR: Option<&str> = Some("እወ");
+}
+pub mod LC_MONETARY {
+    /// `"Nfk"`
+    pub const CURRENCY_SYMBOL: &str = "Nfk";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"ERN "`
+    pub const INT_CURR_SYMBOL: &str = "ERN ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("291")`
+    pub const INT_PREFIX: Option<&str> = Some("291");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a-%l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a-%l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["ሰንበ", "ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_22;
+    /// `&["ጥሪ ", "ለካቲ", "መጋቢ", "ሚያዝ", "ግንቦ", "ሰነ ", "ሓምለ", "ነሓሰ", "መስከ", "ጥቅም", "ሕዳር", "ታሕሳ"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_32;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ንጉሆ ሰዓተ", "ድሕር ሰዓት"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_15;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A፡ %B %e መዓልቲ %r %Z %Y ዓ/ም")`
+    pub const DATE_FMT: Option<&str> = Some("%A፡ %B %e መዓልቲ %r %Z %Y ዓ/ም");
+    /// `&["ሰንበት", "ሰኑይ", "ሰሉስ", "ረቡዕ", "ሓሙስ", "ዓርቢ", "ቀዳም"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_23;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A፡ %B %e መዓልቲ %Y %l:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A፡ %B %e መዓልቲ %Y %l:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_30;
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%A፡ %B %e መዓልቲ %Y %r %Z"`
+        pub const D_T_FMT: &str = "%A፡ %B %e መዓልቲ %Y %r %Z";
+        /// `Some("%l:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
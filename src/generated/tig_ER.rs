@@ -0,0 +1,132 @@
+pub mod LC_ADDRESS {
+    /// `Some("ERI")`
+    pub const COUNTRY_AB3: Option<&str> = Some("ERI");
+    /// `Some("ER")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("ER");
+    /// `Some("ER")`
+    pub const COUNTRY_CAR: Option<&str> = Some("ER");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("ኤርትራ")`
+    pub const COUNTRY_NAME: Option<&str> = Some("ኤርትራ");
+    /// `Some(232)`
+    pub const COUNTRY_NUM: Option<i64> = Some(232);
+    /// `Some("ERI")`
+    pub const COUNTRY_POST: Option<&str> = Some("ERI");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("tig")`
+    pub const LANG_LIB: Option<&str> = Some("tig");
+    /// `Some("ትግረ")`
+    pub const LANG_NAME: Option<&str> = Some("ትግረ");
+    /// `Some("tig")`
+    pub const LANG_TERM: Option<&str> = Some("tig");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA")`
+    pub const ADDRESS: Option<&str> = Some("7802 Solomon Seal Dr., Springfield, VA 22152, USA");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_TIME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_0);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2003-07-05")`
+    pub const DATE: Option<&str> = Some("2003-07-05");
+    /// `Some("locales@geez.org")`
+    pub const EMAIL: Option<&str> = Some("locales@geez.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tigre")`
+    pub const LANGUAGE: Option<&str> = Some("Tigre");
+    /// `Some("0.20")`
+    pub const REVISION: Option<&str> = Some("0.20");
+    /// `Some("Ge'ez Frontier Foundation")`
+    pub const SOURCE: Option<&str> = Some("Ge'ez Frontier Foundation");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Eritrea")`
+    pub const TERRITORY: Option<&str> = Some("Eritrea");
+    /// `Some("Tigre language locale for Eritrea")`
+    pub const TITLE: Option<&str> = Some("Tigre language locale for Eritrea");
+}
+pub use super::ti_ER::LC_MESSAGES;
+pub use super::ti_ER::LC_MONETARY;
+pub use super::ti_ER::LC_NUMERIC;
+pub use super::ti_ER::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ሰ/ዓ", "ሰኖ ", "ታላሸ", "ኣረር", "ከሚሽ", "ጅምዓ", "ሰ/ን"]`
+    pub const ABDAY: &[&str] = &["ሰ/ዓ", "ሰኖ ", "ታላሸ", "ኣረር", "ከሚሽ", "ጅምዓ", "ሰ/ን"];
+    /// `&["ጥሪ ", "ለካቲ", "መጋቢ", "ሚያዝ", "ግንቦ", "ሰነ ", "ሓምለ", "ነሓሰ", "መስከ", "ጥቅም", "ሕዳር", "ታሕሳ"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_32;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ቀደም ሰር ምዕል", "ሓቆ ሰር ምዕል"]`
+    pub const AM_PM: &[&str] = &["ቀደም ሰር ምዕል", "ሓቆ ሰር ምዕል"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A፡ %B %e ዮም %r %Z %Y ዓ/ም")`
+    pub const DATE_FMT: Option<&str> = Some("%A፡ %B %e ዮም %r %Z %Y ዓ/ም");
+    /// `&["ሰንበት ዓባይ", "ሰኖ", "ታላሸኖ", "ኣረርባዓ", "ከሚሽ", "ጅምዓት", "ሰንበት ንኢሽ"]`
+    pub const DAY: &[&str] = &["ሰንበት ዓባይ", "ሰኖ", "ታላሸኖ", "ኣረርባዓ", "ከሚሽ", "ጅምዓት", "ሰንበት ንኢሽ"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A፡ %B %e ዮም %Y %l:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A፡ %B %e ዮም %Y %l:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["ጥሪ", "ለካቲት", "መጋቢት", "ሚያዝያ", "ግንቦት", "ሰነ", "ሓምለ", "ነሓሰ", "መስከረም", "ጥቅምቲ", "ሕዳር", "ታሕሳስ"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_30;
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT: &str = "%l:%M:%S %p";
+    /// `"%l:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%l:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%A፡ %B %e ዮም %Y %r %Z"`
+        pub const D_T_FMT: &str = "%A፡ %B %e ዮም %Y %r %Z";
+        /// `Some("%l:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%l:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("TKM")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TKM");
+    /// `Some("TM")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TM");
+    /// `Some("TM")`
+    pub const COUNTRY_CAR: Option<&str> = Some("TM");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Türkmenistan")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Türkmenistan");
+    /// `Some(795)`
+    pub const COUNTRY_NUM: Option<i64> = Some(795);
+    /// `Some("TM")`
+    pub const COUNTRY_POST: Option<&str> = Some("TM");
+    /// `Some("tk")`
+    pub const LANG_AB: Option<&str> = Some("tk");
+    /// `Some("tuk")`
+    pub const LANG_LIB: Option<&str> = Some("tuk");
+    /// `Some("türkmen dili")`
+    pub const LANG_NAME: Option<&str> = Some("türkmen dili");
+    /// `Some("tuk")`
+    pub const LANG_TERM: Option<&str> = Some("tuk");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_8);
+    /// `Some("Pablo Saratxaga & Ghorban M. Tavakoly")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga & Ghorban M. Tavakoly");
+    /// `Some("2011-04-09")`
+    pub const DATE: Option<&str> = Some("2011-04-09");
+    /// `Some("pablo@walon.org & gmt314@yahoo.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@walon.org & gmt314@yahoo.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Turkmen")`
+    pub const LANGUAGE: Option<&str> = Some("Turkmen");
+    /// `Some("0.5")`
+    pub const REVISION: Option<&str> = Some("0.5");
+    /// `Some("Ghorban M. Tavakoly")`
+    pub const SOURCE: Option<&str> = Some("Ghorban M. Tavakoly");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Turkmenistan")`
+    pub const TERRITORY: Option<&str> = Some("Turkmenistan");
+    /// `Some("Turkmen locale for Turkmenistan")`
+    pub const TITLE: Option<&str> = Some("Turkmen locale for Turkmenistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNýÝ]"`
+    pub const NOEXPR: &str = "^[-0nNýÝ]";
+    /// `Some("ýok")`
+    pub const NOSTR: Option<&str> = Some("ýok");
+    /// `"^[+1hH]"`
+    pub const YESEXPR: &str = "^[+1hH]";
+    /// `Some("hawa")`
+    pub const YESSTR: Option<&str> = Some("hawa");
+}
+pub mod LC_MONETARY {
+    /// `"MANAT"`
+    pub const CURRENCY_SYMBOL: &str = "MANAT";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TMM "`
+    pub const INT_CURR_SYMBOL: &str = "TMM ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("993")`
+    pub const INT_PREFIX: Option<&str> = Some("993");
+    /// `Some("8\u{a0}10")`
+    pub const INT_SELECT: Option<&str> = Some("8\u{a0}10");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Duş", "Siş", "Çar", "Pen", "Ann", "Şen", "Ýek"]`
+    pub const ABDAY: &[&str] = &["Duş", "Siş", "Çar", "Pen", "Ann", "Şen", "Ýek"];
+    /// `&["Ýan", "Few", "Mar", "Apr", "Maý", "Iýn", "Iýl", "Awg", "Sen", "Okt", "Noý", "Dek"]`
+    pub const ABMON: &[&str] = &["Ýan", "Few", "Mar", "Apr", "Maý", "Iýn", "Iýl", "Awg", "Sen", "Okt", "Noý", "Dek"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Duşenbe", "Sişenbe", "Çarşenbe", "Penşenbe", "Anna", "Şenbe", "Ýekşenbe"]`
+    pub const DAY: &[&str] = &["Duşenbe", "Sişenbe", "Çarşenbe", "Penşenbe", "Anna", "Şenbe", "Ýekşenbe"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%d.%m.%Y %T"`
+    pub const D_T_FMT: &str = "%d.%m.%Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ýanwar", "Fewral", "Mart", "Aprel", "Maý", "Iýun", "Iýul", "Awgust", "Sentýabr", "Oktýabr", "Noýabr", "Dekabr"]`
+    pub const MON: &[&str] = &["Ýanwar", "Fewral", "Mart", "Aprel", "Maý", "Iýun", "Iýul", "Awgust", "Sentýabr", "Oktýabr", "Noýabr", "Dekabr"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
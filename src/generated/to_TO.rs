@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("TON")`
+    pub const COUNTRY_AB3: Option<&str> = Some("TON");
+    /// `Some("TO")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("TO");
+    /// `Some("TON")`
+    pub const COUNTRY_CAR: Option<&str> = Some("TON");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Tonga")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Tonga");
+    /// `Some(776)`
+    pub const COUNTRY_NUM: Option<i64> = Some(776);
+    /// `Some("")`
+    pub const COUNTRY_POST: Option<&str> = Some("");
+    /// `Some("to")`
+    pub const LANG_AB: Option<&str> = Some("to");
+    /// `Some("ton")`
+    pub const LANG_LIB: Option<&str> = Some("ton");
+    /// `Some("lea fakatonga")`
+    pub const LANG_NAME: Option<&str> = Some("lea fakatonga");
+    /// `Some("ton")`
+    pub const LANG_TERM: Option<&str> = Some("ton");
+    /// `Some("%a%b%s%c")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%b%s%c");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("Samsung R & D Institute India - Delhi 2-A Sectort 126 Noida 201303")`
+    pub const ADDRESS: Option<&str> = Some("Samsung R & D Institute India - Delhi 2-A Sectort 126 Noida 201303");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_13);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2017-03-23")`
+    pub const DATE: Option<&str> = Some("2017-03-23");
+    /// `Some("akhilesh.k@samsung.com")`
+    pub const EMAIL: Option<&str> = Some("akhilesh.k@samsung.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tongan")`
+    pub const LANGUAGE: Option<&str> = Some("Tongan");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("International Components for Unicode")`
+    pub const SOURCE: Option<&str> = Some("International Components for Unicode");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Tonga")`
+    pub const TERRITORY: Option<&str> = Some("Tonga");
+    /// `Some("Tongan language locale for Tonga")`
+    pub const TITLE: Option<&str> = Some("Tongan language locale for Tonga");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("ʻIkai")`
+    pub const NOSTR: Option<&str> = Some("ʻIkai");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("ʻIo")`
+    pub const YESSTR: Option<&str> = Some("ʻIo");
+}
+pub mod LC_MONETARY {
+    /// `"T$"`
+    pub const CURRENCY_SYMBOL: &str = "T$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"TOP "`
+    pub const INT_CURR_SYMBOL: &str = "TOP ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("676")`
+    pub const INT_PREFIX: Option<&str> = Some("676");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %l");
+}
+pub mod LC_TIME {
+    /// `&["Sāp", "Mōn", "Tūs", "Pul", "Tuʻa", "Fal", "Tok"]`
+    pub const ABDAY: &[&str] = &["Sāp", "Mōn", "Tūs", "Pul", "Tuʻa", "Fal", "Tok"];
+    /// `&["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sep", "ʻOka", "Nōv", "Tīs"]`
+    pub const ABMON: &[&str] = &["Sān", "Fēp", "Maʻa", "ʻEpe", "Mē", "Sun", "Siu", "ʻAok", "Sep", "ʻOka", "Nōv", "Tīs"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["hengihengi", "efiafi"]`
+    pub const AM_PM: &[&str] = &["hengihengi", "efiafi"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Sāpate", "Mōnite", "Tūsite", "Pulelulu", "Tuʻapulelulu", "Falaite", "Tokonaki"]`
+    pub const DAY: &[&str] = &["Sāpate", "Mōnite", "Tūsite", "Pulelulu", "Tuʻapulelulu", "Falaite", "Tokonaki"];
+    /// `"%A %d %b %Y"`
+    pub const D_FMT: &str = "%A %d %b %Y";
+    /// `"%A %d %b %Y %I:%M:%S %p %Z"`
+    pub const D_T_FMT: &str = "%A %d %b %Y %I:%M:%S %p %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sepitema", "ʻOkatopa", "Nōvema", "Tīsema"]`
+    pub const MON: &[&str] = &["Sānuali", "Fēpueli", "Maʻasi", "ʻEpeleli", "Mē", "Sune", "Siulai", "ʻAokosi", "Sepitema", "ʻOkatopa", "Nōvema", "Tīsema"];
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT: &str = "%I:%M:%S %p %Z";
+    /// `"%I:%M:%S %p %Z"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p %Z";
+    /// `None`
+    pub const WEEK: Option<&[i64]> = None;
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p %Z")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p %Z");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
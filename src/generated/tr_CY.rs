@@ -0,0 +1,74 @@
+pub mod LC_ADDRESS {
+    /// `Some("CYP")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CYP");
+    /// `Some("CY")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CY");
+    /// `Some("CY")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CY");
+    /// `Some("975")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("975");
+    /// `Some("Kıbrıs")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Kıbrıs");
+    /// `Some(196)`
+    pub const COUNTRY_NUM: Option<i64> = Some(196);
+    /// `Some("TR")`
+    pub const COUNTRY_POST: Option<&str> = Some("TR");
+    /// `Some("tr")`
+    pub const LANG_AB: Option<&str> = Some("tr");
+    /// `Some("tur")`
+    pub const LANG_LIB: Option<&str> = Some("tur");
+    /// `Some("Türkçe")`
+    pub const LANG_NAME: Option<&str> = Some("Türkçe");
+    /// `Some("tur")`
+    pub const LANG_TERM: Option<&str> = Some("tur");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("https://www.gnu.org/software/libc/")`
+    pub const ADDRESS: Option<&str> = Some("https://www.gnu.org/software/libc/");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2004-10-23")`
+    pub const DATE: Option<&str> = Some("2004-10-23");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Turkish")`
+    pub const LANGUAGE: Option<&str> = Some("Turkish");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Free Software Foundation, Inc.")`
+    pub const SOURCE: Option<&str> = Some("Free Software Foundation, Inc.");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Cyprus")`
+    pub const TERRITORY: Option<&str> = Some("Cyprus");
+    /// `Some("Turkish language locale for Cyprus")`
+    pub const TITLE: Option<&str> = Some("Turkish language locale for Cyprus");
+}
+pub use super::tr_TR::LC_MESSAGES;
+pub use super::tr_TR::LC_MONETARY;
+pub use super::tr_TR::LC_NUMERIC;
+pub use super::el_CY::LC_TELEPHONE;
+pub use super::tr_TR::LC_TIME;
@@ -0,0 +1,180 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Русия")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Русия");
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("tt")`
+    pub const LANG_AB: Option<&str> = Some("tt");
+    /// `Some("tat")`
+    pub const LANG_LIB: Option<&str> = Some("tat");
+    /// `Some("татар")`
+    pub const LANG_NAME: Option<&str> = Some("татар");
+    /// `Some("tat")`
+    pub const LANG_TERM: Option<&str> = Some("tat");
+    /// `Some("???")`
+    pub const POSTAL_FMT: Option<&str> = Some("???");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_16);
+    /// `Some("Pablo Saratxaga, Rinat Norkin")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga, Rinat Norkin");
+    /// `Some("2001-01-28")`
+    pub const DATE: Option<&str> = Some("2001-01-28");
+    /// `Some("pablo@mandrakesoft.com, rinat@taif.ru")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com, rinat@taif.ru");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tatar")`
+    pub const LANGUAGE: Option<&str> = Some("Tatar");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("Rinat Norkin")`
+    pub const SOURCE: Option<&str> = Some("Rinat Norkin");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Russia")`
+    pub const TERRITORY: Option<&str> = Some("Russia");
+    /// `Some("Tatar language locale for Russia")`
+    pub const TITLE: Option<&str> = Some("Tatar language locale for Russia");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNюЮ]"`
+    pub const NOEXPR: &str = "^[-0nNюЮ]";
+    /// `Some("юк")`
+    pub const NOSTR: Option<&str> = Some("юк");
+    /// `"^[+1yYәӘ]"`
+    pub const YESEXPR: &str = "^[+1yYәӘ]";
+    /// `Some("әйе")`
+    pub const YESSTR: Option<&str> = Some("әйе");
+}
+pub mod LC_MONETARY {
+    /// `"₽"`
+    pub const CURRENCY_SYMBOL: &str = "₽";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"RUB "`
+    pub const INT_CURR_SYMBOL: &str = "RUB ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub use super::ru_RU::LC_NUMERIC;
+pub use super::ru_RU::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["якш", "дүш", "сиш", "чәр", "пәнҗ", "җом", "шим"]`
+    pub const ABDAY: &[&str] = &["якш", "дүш", "сиш", "чәр", "пәнҗ", "җом", "шим"];
+    /// `&["гыйн", "фев", "мар", "апр", "май", "июнь", "июль", "авг", "сент", "окт", "нояб", "дек"]`
+    pub const ABMON: &[&str] = &["гыйн", "фев", "мар", "апр", "май", "июнь", "июль", "авг", "сент", "окт", "нояб", "дек"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["якшәмбе", "дүшәмбе", "сишәмбе", "чәршәмбе", "пәнҗешәмбе", "җомга", "шимбә"]`
+    pub const DAY: &[&str] = &["якшәмбе", "дүшәмбе", "сишәмбе", "чәршәмбе", "пәнҗешәмбе", "җомга", "шимбә"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"]`
+    pub const MON: &[&str] = &["гыйнвар", "февраль", "март", "апрель", "май", "июнь", "июль", "август", "сентябрь", "октябрь", "ноябрь", "декабрь"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
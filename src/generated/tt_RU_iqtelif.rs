@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("RUS")`
+    pub const COUNTRY_AB3: Option<&str> = Some("RUS");
+    /// `Some("RU")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("RU");
+    /// `Some("RUS")`
+    pub const COUNTRY_CAR: Option<&str> = Some("RUS");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Urıs Patşahlıq")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Urıs Patşahlıq");
+    /// `Some(643)`
+    pub const COUNTRY_NUM: Option<i64> = Some(643);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("tt")`
+    pub const LANG_AB: Option<&str> = Some("tt");
+    /// `Some("tat")`
+    pub const LANG_LIB: Option<&str> = Some("tat");
+    /// `Some("tatar tele")`
+    pub const LANG_NAME: Option<&str> = Some("tatar tele");
+    /// `Some("tat")`
+    pub const LANG_TERM: Option<&str> = Some("tat");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_NAME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_15);
+    /// `Some("Reshat Sabiq")`
+    pub const CONTACT: Option<&str> = Some("Reshat Sabiq");
+    /// `Some("2006-10-12")`
+    pub const DATE: Option<&str> = Some("2006-10-12");
+    /// `Some("tatar.iqtelif.i18n@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("tatar.iqtelif.i18n@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Tatar")`
+    pub const LANGUAGE: Option<&str> = Some("Tatar");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Russia")`
+    pub const TERRITORY: Option<&str> = Some("Russia");
+    /// `Some("Tatar language locale using IQTElif alphabet; for Tatarstan, Russian Federation")`
+    pub const TITLE: Option<&str> = Some("Tatar language locale using IQTElif alphabet; for Tatarstan, Russian Federation");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0YyNn]"`
+    pub const NOEXPR: &str = "^[-0YyNn]";
+    /// `Some("yuq")`
+    pub const NOSTR: Option<&str> = Some("yuq");
+    /// `"^[+1Ee]"`
+    pub const YESEXPR: &str = "^[+1Ee]";
+    /// `Some("eyí")`
+    pub const YESSTR: Option<&str> = Some("eyí");
+}
+pub mod LC_MONETARY {
+    /// `"₽"`
+    pub const CURRENCY_SYMBOL: &str = "₽";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"RUB "`
+    pub const INT_CURR_SYMBOL: &str = "RUB ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("7")`
+    pub const INT_PREFIX: Option<&str> = Some("7");
+    /// `Some("0~10")`
+    pub const INT_SELECT: Option<&str> = Some("0~10");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["Yek", "Düş", "Siş", "Çer", "Pen", "Com", "Şim"]`
+    pub const ABDAY: &[&str] = &["Yek", "Düş", "Siş", "Çer", "Pen", "Com", "Şim"];
+    /// `&["Ğın", "Fiw", "Mar", "Apr", "May", "Yün", "Yül", "Awg", "Sin", "Ükt", "Noy", "Dik"]`
+    pub const ABMON: &[&str] = &["Ğın", "Fiw", "Mar", "Apr", "May", "Yün", "Yül", "Awg", "Sin", "Ükt", "Noy", "Dik"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ÖA", "ÖS"]`
+    pub const AM_PM: &[&str] = &["ÖA", "ÖS"];
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Yekşembí", "Düşembí", "Sişembí", "Çerşembí", "Pencíşembí", "Comğa", "Şimbe"]`
+    pub const DAY: &[&str] = &["Yekşembí", "Düşembí", "Sişembí", "Çerşembí", "Pencíşembí", "Comğa", "Şimbe"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Ğınwar", "Fiwral'", "Mart", "April", "May", "Yün", "Yül", "Awgust", "Sintebír", "Üktebír", "Noyebír", "Dikebír"]`
+    pub const MON: &[&str] = &["Ğınwar", "Fiwral'", "Mart", "April", "May", "Yün", "Yül", "Awgust", "Sintebír", "Üktebír", "Noyebír", "Dikebír"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,139 @@
+pub mod LC_ADDRESS {
+    /// `Some("CHN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CHN");
+    /// `Some("CN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CN");
+    /// `Some("CHN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CHN");
+    /// `Some("7")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("7");
+    /// `Some("جۇڭگو")`
+    pub const COUNTRY_NAME: Option<&str> = Some("جۇڭگو");
+    /// `Some(156)`
+    pub const COUNTRY_NUM: Option<i64> = Some(156);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ug")`
+    pub const LANG_AB: Option<&str> = Some("ug");
+    /// `Some("uig")`
+    pub const LANG_LIB: Option<&str> = Some("uig");
+    /// `Some("ئۇيغۇرچە")`
+    pub const LANG_NAME: Option<&str> = Some("ئۇيغۇرچە");
+    /// `Some("uig")`
+    pub const LANG_TERM: Option<&str> = Some("uig");
+    /// `Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%c%N%T%N%s %h %e %r%N%b%N%d%N%f%N%a%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_8);
+    /// `Some("Eagle Burkut")`
+    pub const CONTACT: Option<&str> = Some("Eagle Burkut");
+    /// `Some("2011-02-26")`
+    pub const DATE: Option<&str> = Some("2011-02-26");
+    /// `Some("eagle.burkut@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("eagle.burkut@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Uyghur")`
+    pub const LANGUAGE: Option<&str> = Some("Uyghur");
+    /// `Some("2.00")`
+    pub const REVISION: Option<&str> = Some("2.00");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("China")`
+    pub const TERRITORY: Option<&str> = Some("China");
+    /// `Some("Uyghur locale for China")`
+    pub const TITLE: Option<&str> = Some("Uyghur locale for China");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNي]"`
+    pub const NOEXPR: &str = "^[-0nNي]";
+    /// `Some("ياق")`
+    pub const NOSTR: Option<&str> = Some("ياق");
+    /// `"^[+1yYھ]"`
+    pub const YESEXPR: &str = "^[+1yYھ]";
+    /// `Some("ھەئە")`
+    pub const YESSTR: Option<&str> = Some("ھەئە");
+}
+pub use super::zh_CN::LC_MONETARY;
+pub use super::zh_CN::LC_NUMERIC;
+pub use super::zh_CN::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ي", "د", "س", "چ", "پ", "ج", "ش"]`
+    pub const ABDAY: &[&str] = &["ي", "د", "س", "چ", "پ", "ج", "ش"];
+    /// `&["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"]`
+    pub const ABMON: &[&str] = &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a، %d-%m-%Y، %T")`
+    pub const DATE_FMT: Option<&str> = Some("%a، %d-%m-%Y، %T");
+    /// `&["يەكشەنبە", "دۈشەنبە", "سەيشەنبە", "چارشەنبە", "پەيشەنبە", "جۈمە", "شەنبە"]`
+    pub const DAY: &[&str] = &["يەكشەنبە", "دۈشەنبە", "سەيشەنبە", "چارشەنبە", "پەيشەنبە", "جۈمە", "شەنبە"];
+    /// `"%a، %d-%m-%Y"`
+    pub const D_FMT: &str = "%a، %d-%m-%Y";
+    /// `"%a، %d-%m-%Y، %T"`
+    pub const D_T_FMT: &str = "%a، %d-%m-%Y، %T";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"]`
+    pub const MON: &[&str] = &["يانۋار", "فېۋرال", "مارت", "ئاپرېل", "ماي", "ئىيۇن", "ئىيۇل", "ئاۋغۇست", "سېنتەبىر", "ئۆكتەبىر", "نويابىر", "دېكابىر"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `None`
+        pub const T_FMT_AMPM: Option<&str> = None;
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT_AMPM: crate::Provenance = crate::Provenance::Synthesized;
+    }
+}
@@ -0,0 +1,200 @@
+pub mod LC_ADDRESS {
+    /// `Some("UKR")`
+    pub const COUNTRY_AB3: Option<&str> = Some("UKR");
+    /// `Some("UA")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("UA");
+    /// `Some("UA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("UA");
+    /// `Some("966")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("966");
+    /// `Some("Україна")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Україна");
+    /// `Some(804)`
+    pub const COUNTRY_NUM: Option<i64> = Some(804);
+    /// `Some("UA")`
+    pub const COUNTRY_POST: Option<&str> = Some("UA");
+    /// `Some("uk")`
+    pub const LANG_AB: Option<&str> = Some("uk");
+    /// `Some("ukr")`
+    pub const LANG_LIB: Option<&str> = Some("ukr");
+    /// `Some("українська")`
+    pub const LANG_NAME: Option<&str> = Some("українська");
+    /// `Some("ukr")`
+    pub const LANG_TERM: Option<&str> = Some("ukr");
+    /// `Some("%a%t%f%t%d%N%s%t%h%t, %b%t%e%t%r%N%T%t%S%N%z%N%c")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%t%f%t%d%N%s%t%h%t, %b%t%e%t%r%N%T%t%S%N%z%N%c");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("GNU libc maintainers")`
+    pub const CONTACT: Option<&str> = Some("GNU libc maintainers");
+    /// `Some("2006-05-20")`
+    pub const DATE: Option<&str> = Some("2006-05-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Ukrainian")`
+    pub const LANGUAGE: Option<&str> = Some("Ukrainian");
+    /// `Some("2.1.12")`
+    pub const REVISION: Option<&str> = Some("2.1.12");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Ukraine")`
+    pub const TERRITORY: Option<&str> = Some("Ukraine");
+    /// `Some("Ukrainian Language Locale for Ukraine")`
+    pub const TITLE: Option<&str> = Some("Ukrainian Language Locale for Ukraine");
+}
+pub mod LC_MESSAGES {
+    /// `"^([-0Nn]|[Нн][Іі])$"`
+    pub const NOEXPR: &str = "^([-0Nn]|[Нн][Іі])$";
+    /// `Some("ні")`
+    pub const NOSTR: Option<&str> = Some("ні");
+    /// `"^([+1Yy]|[Тт][Аа][Кк]?)$"`
+    pub const YESEXPR: &str = "^([+1Yy]|[Тт][Аа][Кк]?)$";
+    /// `Some("так")`
+    pub const YESSTR: Option<&str> = Some("так");
+}
+pub mod LC_MONETARY {
+    /// `"грн."`
+    pub const CURRENCY_SYMBOL: &str = "грн.";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"UAH "`
+    pub const INT_CURR_SYMBOL: &str = "UAH ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `Some(1)`
+    pub const INT_N_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(1)`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = Some(1);
+    /// `Some(4)`
+    pub const INT_N_SIGN_POSN: Option<i64> = Some(4);
+    /// `Some(1)`
+    pub const INT_P_CS_PRECEDES: Option<i64> = Some(1);
+    /// `Some(2)`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = Some(2);
+    /// `Some(4)`
+    pub const INT_P_SIGN_POSN: Option<i64> = Some(4);
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `2`
+    pub const P_SEP_BY_SPACE: i64 = 2;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("380")`
+    pub const INT_PREFIX: Option<&str> = Some("380");
+    /// `Some("8~10")`
+    pub const INT_SELECT: Option<&str> = Some("8~10");
+    /// `Some("(%A) %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("(%A) %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["нд", "пн", "вт", "ср", "чт", "пт", "сб"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_16;
+    /// `&["січ", "лют", "бер", "кві", "тра", "чер", "лип", "сер", "вер", "жов", "лис", "гру"]`
+    pub const ABMON: &[&str] = &["січ", "лют", "бер", "кві", "тра", "чер", "лип", "сер", "вер", "жов", "лис", "гру"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["січень", "лютий", "березень", "квітень", "травень", "червень", "липень", "серпень", "вересень", "жовтень", "листопад", "грудень"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["січень", "лютий", "березень", "квітень", "травень", "червень", "липень", "серпень", "вересень", "жовтень", "листопад", "грудень"]);
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `Some(2)`
+    pub const CAL_DIRECTION: Option<i64> = Some(2);
+    /// `Some("%A, %-d %B %Y %X %z")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %-d %B %Y %X %z");
+    /// `&["неділя", "понеділок", "вівторок", "середа", "четвер", "п'ятниця", "субота"]`
+    pub const DAY: &[&str] = &["неділя", "понеділок", "вівторок", "середа", "четвер", "п'ятниця", "субота"];
+    /// `"%d.%m.%y"`
+    pub const D_FMT: &str = "%d.%m.%y";
+    /// `"%a, %d-%b-%Y %T %z"`
+    pub const D_T_FMT: &str = "%a, %d-%b-%Y %T %z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["січня", "лютого", "березня", "квітня", "травня", "червня", "липня", "серпня", "вересня", "жовтня", "листопада", "грудня"]`
+    pub const MON: &[&str] = &["січня", "лютого", "березня", "квітня", "травня", "червня", "липня", "серпня", "вересня", "жовтня", "листопада", "грудня"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%a, %d-%b-%Y %X %z"`
+        pub const D_T_FMT: &str = "%a, %d-%b-%Y %X %z";
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const D_T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("USA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("USA");
+    /// `Some("US")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("US");
+    /// `Some("USA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("USA");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("USA")`
+    pub const COUNTRY_NAME: Option<&str> = Some("USA");
+    /// `Some(840)`
+    pub const COUNTRY_NUM: Option<i64> = Some(840);
+    /// `Some("USA")`
+    pub const COUNTRY_POST: Option<&str> = Some("USA");
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("unm")`
+    pub const LANG_LIB: Option<&str> = Some("unm");
+    /// `Some("Lenape")`
+    pub const LANG_NAME: Option<&str> = Some("Lenape");
+    /// `Some("unm")`
+    pub const LANG_TERM: Option<&str> = Some("unm");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T, %S %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2011-10-27")`
+    pub const DATE: Option<&str> = Some("2011-10-27");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Unami Delaware")`
+    pub const LANGUAGE: Option<&str> = Some("Unami Delaware");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("United States")`
+    pub const TERRITORY: Option<&str> = Some("United States");
+    /// `Some("Unami Delaware locale for the USA")`
+    pub const TITLE: Option<&str> = Some("Unami Delaware locale for the USA");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0kKmM]"`
+    pub const NOEXPR: &str = "^[-0kKmM]";
+    /// `None`
+    pub const NOSTR: Option<&str> = None;
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `None`
+    pub const YESSTR: Option<&str> = None;
+}
+pub mod LC_MONETARY {
+    /// `"$"`
+    pub const CURRENCY_SYMBOL: &str = "$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"USD "`
+    pub const INT_CURR_SYMBOL: &str = "USD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[2, 2, 2, 3]`
+    pub const GROUPING: &[i64] = &[2, 2, 2, 3];
+    /// `"\u{202f}"`
+    pub const THOUSANDS_SEP: &str = "\u{202f}";
+}
+pub use super::en_US::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["ken", "man", "tus", "lel", "tas", "pel", "sat"]`
+    pub const ABDAY: &[&str] = &["ken", "man", "tus", "lel", "tas", "pel", "sat"];
+    /// `&["eni", "chk", "xam", "kwe", "tai", "nip", "lai", "win", "tah", "puk", "kun", "mux"]`
+    pub const ABMON: &[&str] = &["eni", "chk", "xam", "kwe", "tai", "nip", "lai", "win", "tah", "puk", "kun", "mux"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["kentuwei", "manteke", "tusteke", "lelai", "tasteke", "pelaiteke", "sateteke"]`
+    pub const DAY: &[&str] = &["kentuwei", "manteke", "tusteke", "lelai", "tasteke", "pelaiteke", "sateteke"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["enikwsi", "chkwali", "xamokhwite", "kwetayoxe", "tainipen", "kichinipen", "lainipen", "winaminke", "kichitahkok", "puksit", "wini", "muxkotae"]`
+    pub const MON: &[&str] = &["enikwsi", "chkwali", "xamokhwite", "kwetayoxe", "tainipen", "kichinipen", "lainipen", "winaminke", "kichitahkok", "puksit", "wini", "muxkotae"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("PAK")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PAK");
+    /// `Some("PK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PK");
+    /// `Some("PK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PK");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("پاکستان")`
+    pub const COUNTRY_NAME: Option<&str> = Some("پاکستان");
+    /// `Some(586)`
+    pub const COUNTRY_NUM: Option<i64> = Some(586);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("ur")`
+    pub const LANG_AB: Option<&str> = Some("ur");
+    /// `Some("urd")`
+    pub const LANG_LIB: Option<&str> = Some("urd");
+    /// `Some("اردو")`
+    pub const LANG_NAME: Option<&str> = Some("اردو");
+    /// `Some("urd")`
+    pub const LANG_TERM: Option<&str> = Some("urd");
+    /// `Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%a%N%f%N%d%N%b%N%h %s %e %r%N%T %z%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-11")`
+    pub const DATE: Option<&str> = Some("2000-07-11");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Urdu")`
+    pub const LANGUAGE: Option<&str> = Some("Urdu");
+    /// `Some("0.3")`
+    pub const REVISION: Option<&str> = Some("0.3");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Pakistan")`
+    pub const TERRITORY: Option<&str> = Some("Pakistan");
+    /// `Some("Urdu Language Locale for Pakistan")`
+    pub const TITLE: Option<&str> = Some("Urdu Language Locale for Pakistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNنo]"`
+    pub const NOEXPR: &str = "^[-0nNنo]";
+    /// `Some("نہیں")`
+    pub const NOSTR: Option<&str> = Some("نہیں");
+    /// `"^[+1yYهبf]"`
+    pub const YESEXPR: &str = "^[+1yYهبf]";
+    /// `Some("ہاں")`
+    pub const YESSTR: Option<&str> = Some("ہاں");
+}
+pub mod LC_MONETARY {
+    /// `"Rs"`
+    pub const CURRENCY_SYMBOL: &str = "Rs";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"PKR "`
+    pub const INT_CURR_SYMBOL: &str = "PKR ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const P_SIGN_POSN: i64 = 2;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("92")`
+    pub const INT_PREFIX: Option<&str> = Some("92");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_17;
+    /// `&["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_25;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["ص", "ش"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_8;
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["اتوار", "پير", "منگل", "بدھ", "جمعرات", "جمعه", "هفته"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_18;
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"و %H:%M:%S %Z ت %d %B %Y"`
+    pub const D_T_FMT: &str = "و %H:%M:%S %Z ت %d %B %Y";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["جنوری", "فروری", "مارچ", "اپریل", "مئی", "جون", "جولائی", "اگست", "ستمبر", "اکتوبر", "نومبر", "دسمبر"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_21;
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%P %I:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%P %I:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%P %I:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%P %I:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
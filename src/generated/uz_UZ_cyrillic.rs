@@ -0,0 +1,188 @@
+pub mod LC_ADDRESS {
+    /// `Some("UZB")`
+    pub const COUNTRY_AB3: Option<&str> = Some("UZB");
+    /// `Some("UZ")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("UZ");
+    /// `Some("UZ")`
+    pub const COUNTRY_CAR: Option<&str> = Some("UZ");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Ўзбекистон")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Ўзбекистон");
+    /// `Some(860)`
+    pub const COUNTRY_NUM: Option<i64> = Some(860);
+    /// `Some("UZ")`
+    pub const COUNTRY_POST: Option<&str> = Some("UZ");
+    /// `Some("uz")`
+    pub const LANG_AB: Option<&str> = Some("uz");
+    /// `Some("uzb")`
+    pub const LANG_LIB: Option<&str> = Some("uzb");
+    /// `Some("Ўзбекча")`
+    pub const LANG_NAME: Option<&str> = Some("Ўзбекча");
+    /// `Some("uzb")`
+    pub const LANG_TERM: Option<&str> = Some("uzb");
+    /// `Some("%C%N%T%N%s %h%N%f%N%b%N%d%N%e %r%N%a%N%z")`
+    pub const POSTAL_FMT: Option<&str> = Some("%C%N%T%N%s %h%N%f%N%b%N%d%N%e %r%N%a%N%z");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Mashrab Kuvatov, Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Mashrab Kuvatov, Pablo Saratxaga");
+    /// `Some("2003-05-30")`
+    pub const DATE: Option<&str> = Some("2003-05-30");
+    /// `Some("kmashrab@uni-bremen.de, pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("kmashrab@uni-bremen.de, pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Uzbek")`
+    pub const LANGUAGE: Option<&str> = Some("Uzbek");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("Mashrab Kuvatov")`
+    pub const SOURCE: Option<&str> = Some("Mashrab Kuvatov");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Uzbekistan")`
+    pub const TERRITORY: Option<&str> = Some("Uzbekistan");
+    /// `Some("Uzbek (cyrillic) locale for Uzbekistan")`
+    pub const TITLE: Option<&str> = Some("Uzbek (cyrillic) locale for Uzbekistan");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNyYjJЙй]"`
+    pub const NOEXPR: &str = "^[-0nNyYjJЙй]";
+    /// `Some("йўқ")`
+    pub const NOSTR: Option<&str> = Some("йўқ");
+    /// `"^[+1hHҲҳ]"`
+    pub const YESEXPR: &str = "^[+1hHҲҳ]";
+    /// `Some("ҳа")`
+    pub const YESSTR: Option<&str> = Some("ҳа");
+}
+pub mod LC_MONETARY {
+    /// `"сўм"`
+    pub const CURRENCY_SYMBOL: &str = "сўм";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"UZS "`
+    pub const INT_CURR_SYMBOL: &str = "UZS ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub use super::uz_UZ::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Якш", "Душ", "Сеш", "Чор", "Пай", "Жум", "Шан"]`
+    pub const ABDAY: &[&str] = &["Якш", "Душ", "Сеш", "Чор", "Пай", "Жум", "Шан"];
+    /// `&["Янв", "Фев", "Мар", "Апр", "Май", "Июн", "Июл", "Авг", "Сен", "Окт", "Ноя", "Дек"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_21;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%d %B, %Y йил, %A")`
+    pub const DATE_FMT: Option<&str> = Some("%d %B, %Y йил, %A");
+    /// `&["Якшанба", "Душанба", "Сешанба", "Чоршанба", "Пайшанба", "Жума", "Шанба"]`
+    pub const DAY: &[&str] = &["Якшанба", "Душанба", "Сешанба", "Чоршанба", "Пайшанба", "Жума", "Шанба"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%T, %d %B, %Y йил, %A"`
+    pub const D_T_FMT: &str = "%T, %d %B, %Y йил, %A";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"]`
+    pub const MON: &[&str] = &["Январ", "Феврал", "Март", "Апрел", "Май", "Июн", "Июл", "Август", "Сентябр", "Октябр", "Ноябр", "Декабр"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("VNM")`
+    pub const COUNTRY_AB3: Option<&str> = Some("VNM");
+    /// `Some("VN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("VN");
+    /// `Some("VN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("VN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Việt Nam")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Việt Nam");
+    /// `Some(704)`
+    pub const COUNTRY_NUM: Option<i64> = Some(704);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("vi")`
+    pub const LANG_AB: Option<&str> = Some("vi");
+    /// `Some("vie")`
+    pub const LANG_LIB: Option<&str> = Some("vie");
+    /// `Some("Tiếng Việt")`
+    pub const LANG_NAME: Option<&str> = Some("Tiếng Việt");
+    /// `Some("vie")`
+    pub const LANG_TERM: Option<&str> = Some("vie");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2004-01-09")`
+    pub const DATE: Option<&str> = Some("2004-01-09");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Vietnamese")`
+    pub const LANGUAGE: Option<&str> = Some("Vietnamese");
+    /// `Some("1.1")`
+    pub const REVISION: Option<&str> = Some("1.1");
+    /// `Some("glibc locale and info from vietnamese native speakers")`
+    pub const SOURCE: Option<&str> = Some("glibc locale and info from vietnamese native speakers");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Vietnam")`
+    pub const TERRITORY: Option<&str> = Some("Vietnam");
+    /// `Some("Vietnamese language locale for Vietnam")`
+    pub const TITLE: Option<&str> = Some("Vietnamese language locale for Vietnam");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNkK]"`
+    pub const NOEXPR: &str = "^[-0nNkK]";
+    /// `Some("Không")`
+    pub const NOSTR: Option<&str> = Some("Không");
+    /// `"^[+1yYcC]"`
+    pub const YESEXPR: &str = "^[+1yYcC]";
+    /// `Some("Có")`
+    pub const YESSTR: Option<&str> = Some("Có");
+}
+pub mod LC_MONETARY {
+    /// `"₫"`
+    pub const CURRENCY_SYMBOL: &str = "₫";
+    /// `0`
+    pub const FRAC_DIGITS: i64 = 0;
+    /// `"VND "`
+    pub const INT_CURR_SYMBOL: &str = "VND ";
+    /// `0`
+    pub const INT_FRAC_DIGITS: i64 = 0;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"."`
+    pub const MON_THOUSANDS_SEP: &str = ".";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `"."`
+    pub const THOUSANDS_SEP: &str = ".";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("84")`
+    pub const INT_PREFIX: Option<&str> = Some("84");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["CN", "T2", "T3", "T4", "T5", "T6", "T7"]`
+    pub const ABDAY: &[&str] = &["CN", "T2", "T3", "T4", "T5", "T6", "T7"];
+    /// `&["Thg 1", "Thg 2", "Thg 3", "Thg 4", "Thg 5", "Thg 6", "Thg 7", "Thg 8", "Thg 9", "Thg 10", "Thg 11", "Thg 12"]`
+    pub const ABMON: &[&str] = &["Thg 1", "Thg 2", "Thg 3", "Thg 4", "Thg 5", "Thg 6", "Thg 7", "Thg 8", "Thg 9", "Thg 10", "Thg 11", "Thg 12"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%A, %d %B năm %Y %T %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%A, %d %B năm %Y %T %Z");
+    /// `&["Chủ nhật", "Thứ hai", "Thứ ba", "Thứ tư", "Thứ năm", "Thứ sáu", "Thứ bảy"]`
+    pub const DAY: &[&str] = &["Chủ nhật", "Thứ hai", "Thứ ba", "Thứ tư", "Thứ năm", "Thứ sáu", "Thứ bảy"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"%A, %d %B Năm %Y %T %Z"`
+    pub const D_T_FMT: &str = "%A, %d %B Năm %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"]`
+    pub const MON: &[&str] = &["Tháng 1", "Tháng 2", "Tháng 3", "Tháng 4", "Tháng 5", "Tháng 6", "Tháng 7", "Tháng 8", "Tháng 9", "Tháng 10", "Tháng 11", "Tháng 12"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
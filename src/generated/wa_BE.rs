@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("BEL")`
+    pub const COUNTRY_AB3: Option<&str> = Some("BEL");
+    /// `Some("BE")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("BE");
+    /// `Some("B")`
+    pub const COUNTRY_CAR: Option<&str> = Some("B");
+    /// `Some("2")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("2");
+    /// `Some("Beldjike")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Beldjike");
+    /// `Some(56)`
+    pub const COUNTRY_NUM: Option<i64> = Some(56);
+    /// `Some("B")`
+    pub const COUNTRY_POST: Option<&str> = Some("B");
+    /// `Some("wa")`
+    pub const LANG_AB: Option<&str> = Some("wa");
+    /// `Some("wln")`
+    pub const LANG_LIB: Option<&str> = Some("wln");
+    /// `Some("walon")`
+    pub const LANG_NAME: Option<&str> = Some("walon");
+    /// `Some("wln")`
+    pub const LANG_TERM: Option<&str> = Some("wln");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_NAME"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_3);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2003-08-25")`
+    pub const DATE: Option<&str> = Some("2003-08-25");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Walloon")`
+    pub const LANGUAGE: Option<&str> = Some("Walloon");
+    /// `Some("0.9")`
+    pub const REVISION: Option<&str> = Some("0.9");
+    /// `Some("Djan SACRE")`
+    pub const SOURCE: Option<&str> = Some("Djan SACRE");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Belgium")`
+    pub const TERRITORY: Option<&str> = Some("Belgium");
+    /// `Some("Walloon Language Locale for Belgium")`
+    pub const TITLE: Option<&str> = Some("Walloon Language Locale for Belgium");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("Neni")`
+    pub const NOSTR: Option<&str> = Some("Neni");
+    /// `"^[+1oOyYaAwW]"`
+    pub const YESEXPR: &str = "^[+1oOyYaAwW]";
+    /// `Some("Oyi")`
+    pub const YESSTR: Option<&str> = Some("Oyi");
+}
+pub use super::fr_BE::LC_MONETARY;
+pub use super::fr_BE::LC_NUMERIC;
+pub use super::fr_BE::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["dim", "lon", "mår", "mie", "dju", "vén", "sem"]`
+    pub const ABDAY: &[&str] = &["dim", "lon", "mår", "mie", "dju", "vén", "sem"];
+    /// `&["dja", "fev", "mås", "avr", "may", "djn", "djl", "awo", "set", "oct", "nôv", "dec"]`
+    pub const ABMON: &[&str] = &["dja", "fev", "mås", "avr", "may", "djn", "djl", "awo", "set", "oct", "nôv", "dec"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `Some(&["djanvî", "fevrî", "måss", "avri", "may", "djun", "djulete", "awousse", "setimbe", "octôbe", "nôvimbe", "decimbe"])`
+    pub const ALT_MON: Option<&[&str]> = Some(&["djanvî", "fevrî", "måss", "avri", "may", "djun", "djulete", "awousse", "setimbe", "octôbe", "nôvimbe", "decimbe"]);
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dimegne", "londi", "mårdi", "mierkidi", "djudi", "vénrdi", "semdi"]`
+    pub const DAY: &[&str] = &["dimegne", "londi", "mårdi", "mierkidi", "djudi", "vénrdi", "semdi"];
+    /// `"%d/%m/%Y"`
+    pub const D_FMT: &str = "%d/%m/%Y";
+    /// `"Li %A %d %B %Y %T %Z"`
+    pub const D_T_FMT: &str = "Li %A %d %B %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["di djanvî", "di fevrî", "di måss", "d’\u{a0}avri", "di may", "di djun", "di djulete", "d’\u{a0}awousse", "di setimbe", "d’\u{a0}octôbe", "di nôvimbe", "di decimbe"]`
+    pub const MON: &[&str] = &["di djanvî", "di fevrî", "di måss", "d’\u{a0}avri", "di may", "di djun", "di djulete", "d’\u{a0}awousse", "di setimbe", "d’\u{a0}octôbe", "di nôvimbe", "di decimbe"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("CHE")`
+    pub const COUNTRY_AB3: Option<&str> = Some("CHE");
+    /// `Some("CH")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("CH");
+    /// `Some("CH")`
+    pub const COUNTRY_CAR: Option<&str> = Some("CH");
+    /// `Some("3")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("3");
+    /// `Some("Schwiz")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Schwiz");
+    /// `Some(756)`
+    pub const COUNTRY_NUM: Option<i64> = Some(756);
+    /// `Some("DH")`
+    pub const COUNTRY_POST: Option<&str> = Some("DH");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("wae")`
+    pub const LANG_LIB: Option<&str> = Some("wae");
+    /// `Some("Walser")`
+    pub const LANG_NAME: Option<&str> = Some("Walser");
+    /// `Some("wae")`
+    pub const LANG_TERM: Option<&str> = Some("wae");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_9);
+    /// `Some("Walser Translation Team")`
+    pub const CONTACT: Option<&str> = Some("Walser Translation Team");
+    /// `Some("2010-10-25")`
+    pub const DATE: Option<&str> = Some("2010-10-25");
+    /// `Some("ml@translate-wae.ch")`
+    pub const EMAIL: Option<&str> = Some("ml@translate-wae.ch");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Walser")`
+    pub const LANGUAGE: Option<&str> = Some("Walser");
+    /// `Some("0.1")`
+    pub const REVISION: Option<&str> = Some("0.1");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Switzerland")`
+    pub const TERRITORY: Option<&str> = Some("Switzerland");
+    /// `Some("Walser locale for Switzerland")`
+    pub const TITLE: Option<&str> = Some("Walser locale for Switzerland");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("nei")`
+    pub const NOSTR: Option<&str> = Some("nei");
+    /// `"^[+1jJyY]"`
+    pub const YESEXPR: &str = "^[+1jJyY]";
+    /// `Some("ja")`
+    pub const YESSTR: Option<&str> = Some("ja");
+}
+pub use super::de_CH::LC_MONETARY;
+pub use super::de_CH::LC_NUMERIC;
+pub use super::de_CH::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Sun", "Män", "Zis", "Mit", "Fro", "Fri", "Sam"]`
+    pub const ABDAY: &[&str] = &["Sun", "Män", "Zis", "Mit", "Fro", "Fri", "Sam"];
+    /// `&["Jen", "Hor", "Mär", "Abr", "Mei", "Brá", "Hei", "Öig", "Her", "Wím", "Win", "Chr"]`
+    pub const ABMON: &[&str] = &["Jen", "Hor", "Mär", "Abr", "Mei", "Brá", "Hei", "Öig", "Her", "Wím", "Win", "Chr"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%a %-d %b %H:%M:%S %Z %Y")`
+    pub const DATE_FMT: Option<&str> = Some("%a %-d %b %H:%M:%S %Z %Y");
+    /// `&["Suntag", "Mäntag", "Zischtag", "Mittwuch", "Frontag", "Fritag", "Samschtag"]`
+    pub const DAY: &[&str] = &["Suntag", "Mäntag", "Zischtag", "Mittwuch", "Frontag", "Fritag", "Samschtag"];
+    /// `"%Y-%m-%d"`
+    pub const D_FMT: &str = "%Y-%m-%d";
+    /// `"%a %d. %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d. %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Jenner", "Hornig", "Märze", "Abrille", "Meije", "Bráčet", "Heiwet", "Öigšte", "Herbštmánet", "Wímánet", "Wintermánet", "Chrištmánet"]`
+    pub const MON: &[&str] = &["Jenner", "Hornig", "Märze", "Abrille", "Meije", "Bráčet", "Heiwet", "Öigšte", "Herbštmánet", "Wímánet", "Wintermánet", "Chrištmánet"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 4])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_1);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
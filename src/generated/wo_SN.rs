@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("SEN")`
+    pub const COUNTRY_AB3: Option<&str> = Some("SEN");
+    /// `Some("SN")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("SN");
+    /// `Some("SN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("SN");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Senegaal")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Senegaal");
+    /// `Some(686)`
+    pub const COUNTRY_NUM: Option<i64> = Some(686);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("wo")`
+    pub const LANG_AB: Option<&str> = Some("wo");
+    /// `Some("wol")`
+    pub const LANG_LIB: Option<&str> = Some("wol");
+    /// `Some("Wolof")`
+    pub const LANG_NAME: Option<&str> = Some("Wolof");
+    /// `Some("wol")`
+    pub const LANG_TERM: Option<&str> = Some("wol");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("Christian Perrier")`
+    pub const CONTACT: Option<&str> = Some("Christian Perrier");
+    /// `Some("2004-09-08")`
+    pub const DATE: Option<&str> = Some("2004-09-08");
+    /// `Some("bubulle@debian.org")`
+    pub const EMAIL: Option<&str> = Some("bubulle@debian.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Wolof")`
+    pub const LANGUAGE: Option<&str> = Some("Wolof");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("The Debian Project")`
+    pub const SOURCE: Option<&str> = Some("The Debian Project");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Senegal")`
+    pub const TERRITORY: Option<&str> = Some("Senegal");
+    /// `Some("Wolof locale for Senegal")`
+    pub const TITLE: Option<&str> = Some("Wolof locale for Senegal");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0dDnN]"`
+    pub const NOEXPR: &str = "^[-0dDnN]";
+    /// `Some("déedet")`
+    pub const NOSTR: Option<&str> = Some("déedet");
+    /// `"^[+1wWyY]"`
+    pub const YESEXPR: &str = "^[+1wWyY]";
+    /// `Some("waaw")`
+    pub const YESSTR: Option<&str> = Some("waaw");
+}
+pub mod LC_MONETARY {
+    /// `"CFA"`
+    pub const CURRENCY_SYMBOL: &str = "CFA";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"XOF "`
+    pub const INT_CURR_SYMBOL: &str = "XOF ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `","`
+    pub const MON_DECIMAL_POINT: &str = ",";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `"\u{202f}"`
+    pub const MON_THOUSANDS_SEP: &str = "\u{202f}";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `0`
+    pub const N_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `0`
+    pub const P_CS_PRECEDES: i64 = 0;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `","`
+    pub const DECIMAL_POINT: &str = ",";
+    /// `&[0, 0]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_1;
+    /// `""`
+    pub const THOUSANDS_SEP: &str = "";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("221")`
+    pub const INT_PREFIX: Option<&str> = Some("221");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `Some("%a %l")`
+    pub const TEL_DOM_FMT: Option<&str> = Some("%a %l");
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["dib", "alt", "tal", "all", "alx", "ajj", "gaa"]`
+    pub const ABDAY: &[&str] = &["dib", "alt", "tal", "all", "alx", "ajj", "gaa"];
+    /// `&["san", "fee", "mar", "awr", "me ", "suw", "sul", "uut", "sep", "okt", "now", "des"]`
+    pub const ABMON: &[&str] = &["san", "fee", "mar", "awr", "me ", "suw", "sul", "uut", "sep", "okt", "now", "des"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["", ""]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_0;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["dib'eer", "altine", "talaata", "allarba", "alxames", "ajjuma", "gaawu"]`
+    pub const DAY: &[&str] = &["dib'eer", "altine", "talaata", "allarba", "alxames", "ajjuma", "gaawu"];
+    /// `"%d.%m.%Y"`
+    pub const D_FMT: &str = "%d.%m.%Y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["sanwiy'e", "feebriy'e", "mars", "awril", "me", "suwen", "sulet", "uut", "septaambar", "oktoobar", "nowaambar", "desaambar"]`
+    pub const MON: &[&str] = &["sanwiy'e", "feebriy'e", "mars", "awril", "me", "suwen", "sulet", "uut", "septaambar", "oktoobar", "nowaambar", "desaambar"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `""`
+    pub const T_FMT_AMPM: &str = "";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("")`
+        pub const T_FMT_AMPM: Option<&str> = Some("");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,180 @@
+pub mod LC_ADDRESS {
+    /// `Some("USA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("USA");
+    /// `Some("US")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("US");
+    /// `Some("USA")`
+    pub const COUNTRY_CAR: Option<&str> = Some("USA");
+    /// `Some("0")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("0");
+    /// `Some("פ\u{5bf}א\u{5b7}ראייניגטע שטא\u{5b7}טן")`
+    pub const COUNTRY_NAME: Option<&str> = Some("פ\u{5bf}א\u{5b7}ראייניגטע שטא\u{5b7}טן");
+    /// `Some(840)`
+    pub const COUNTRY_NUM: Option<i64> = Some(840);
+    /// `Some("USA")`
+    pub const COUNTRY_POST: Option<&str> = Some("USA");
+    /// `Some("yi")`
+    pub const LANG_AB: Option<&str> = Some("yi");
+    /// `Some("yid")`
+    pub const LANG_LIB: Option<&str> = Some("yid");
+    /// `Some("יי\u{5b4}דיש")`
+    pub const LANG_NAME: Option<&str> = Some("יי\u{5b4}דיש");
+    /// `Some("yid")`
+    pub const LANG_TERM: Option<&str> = Some("yid");
+    /// `Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%d%N%f%N%d%N%b%N%s %h 5e %r%N%C%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_4);
+    /// `Some("Pablo Saratxaga")`
+    pub const CONTACT: Option<&str> = Some("Pablo Saratxaga");
+    /// `Some("2003-08-16")`
+    pub const DATE: Option<&str> = Some("2003-08-16");
+    /// `Some("pablo@mandrakesoft.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandrakesoft.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Yiddish")`
+    pub const LANGUAGE: Option<&str> = Some("Yiddish");
+    /// `Some("0.4")`
+    pub const REVISION: Option<&str> = Some("0.4");
+    /// `Some("http://www.uyip.org/")`
+    pub const SOURCE: Option<&str> = Some("http://www.uyip.org/");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("United States")`
+    pub const TERRITORY: Option<&str> = Some("United States");
+    /// `Some("Yiddish Language locale for the USA")`
+    pub const TITLE: Option<&str> = Some("Yiddish Language locale for the USA");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNנק]"`
+    pub const NOEXPR: &str = "^[-0nNנק]";
+    /// `Some("קײן")`
+    pub const NOSTR: Option<&str> = Some("קײן");
+    /// `"^[+1yYי]"`
+    pub const YESEXPR: &str = "^[+1yYי]";
+    /// `Some("יא\u{5b8}")`
+    pub const YESSTR: Option<&str> = Some("יא\u{5b8}");
+}
+pub mod LC_MONETARY {
+    /// `"$"`
+    pub const CURRENCY_SYMBOL: &str = "$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"USD "`
+    pub const INT_CURR_SYMBOL: &str = "USD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const N_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const N_SIGN_POSN: i64 = 2;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `1`
+    pub const P_SEP_BY_SPACE: i64 = 1;
+    /// `2`
+    pub const P_SIGN_POSN: i64 = 2;
+}
+pub use super::en_US::LC_NUMERIC;
+pub use super::en_US::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["זונ'", "מא\u{5b8}נ'", "דינ'", "מיט'", "דא\u{5b8}נ'", "פ\u{5bf}רײ\u{5b7}'", "שבת"]`
+    pub const ABDAY: &[&str] = &["זונ'", "מא\u{5b8}נ'", "דינ'", "מיט'", "דא\u{5b8}נ'", "פ\u{5bf}רײ\u{5b7}'", "שבת"];
+    /// `&["יא\u{5b7}נ", "פ\u{5bf}עב", "מא\u{5b7}ר", "א\u{5b7}פ\u{5bc}ר", "מײ\u{5b7} ", "יונ", "יול", "אױג", "סעפ\u{5bc}", "א\u{5b8}קט", "נא\u{5b8}װ", "דעצ"]`
+    pub const ABMON: &[&str] = &["יא\u{5b7}נ", "פ\u{5bf}עב", "מא\u{5b7}ר", "א\u{5b7}פ\u{5bc}ר", "מײ\u{5b7} ", "יונ", "יול", "אױג", "סעפ\u{5bc}", "א\u{5b8}קט", "נא\u{5b8}װ", "דעצ"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `Some(3)`
+    pub const CAL_DIRECTION: Option<i64> = Some(3);
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["זונטיק", "מא\u{5b8}נטיק", "דינסטיק", "מיטװא\u{5b8}ך", "דא\u{5b8}נערשטיק", "פ\u{5bf}רײ\u{5b7}טיק", "שבת"]`
+    pub const DAY: &[&str] = &["זונטיק", "מא\u{5b8}נטיק", "דינסטיק", "מיטװא\u{5b8}ך", "דא\u{5b8}נערשטיק", "פ\u{5bf}רײ\u{5b7}טיק", "שבת"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%Z %H:%M:%S %Y %b %d %a"`
+    pub const D_T_FMT: &str = "%Z %H:%M:%S %Y %b %d %a";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["יא\u{5b7}נוא\u{5b7}ר", "פ\u{5bf}עברוא\u{5b7}ר", "מערץ", "א\u{5b7}פ\u{5bc}ריל", "מיי", "יוני", "יולי", "אויגוסט", "סעפ\u{5bc}טעמבער", "אקטאבער", "נאוועמבער", "דעצעמבער"]`
+    pub const MON: &[&str] = &["יא\u{5b7}נוא\u{5b7}ר", "פ\u{5bf}עברוא\u{5b7}ר", "מערץ", "א\u{5b7}פ\u{5bc}ריל", "מיי", "יוני", "יולי", "אויגוסט", "סעפ\u{5bc}טעמבער", "אקטאבער", "נאוועמבער", "דעצעמבער"];
+    /// `"%H:%M:%S"`
+    pub const T_FMT: &str = "%H:%M:%S";
+    /// `"%I:%M:%S %P"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %P";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %P")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %P");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,141 @@
+pub mod LC_ADDRESS {
+    /// `Some("NGA")`
+    pub const COUNTRY_AB3: Option<&str> = Some("NGA");
+    /// `Some("NG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("NG");
+    /// `Some("WAN")`
+    pub const COUNTRY_CAR: Option<&str> = Some("WAN");
+    /// `Some("978")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("978");
+    /// `Some("Orilẹ\u{300}-èdè Nàìjíríà")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Orilẹ\u{300}-èdè Nàìjíríà");
+    /// `Some(566)`
+    pub const COUNTRY_NUM: Option<i64> = Some(566);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("yo")`
+    pub const LANG_AB: Option<&str> = Some("yo");
+    /// `Some("yor")`
+    pub const LANG_LIB: Option<&str> = Some("yor");
+    /// `Some("Èdè Yorùbá")`
+    pub const LANG_NAME: Option<&str> = Some("Èdè Yorùbá");
+    /// `Some("yor")`
+    pub const LANG_TERM: Option<&str> = Some("yor");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2005-11-20")`
+    pub const DATE: Option<&str> = Some("2005-11-20");
+    /// `Some("pablo@mandriva.com")`
+    pub const EMAIL: Option<&str> = Some("pablo@mandriva.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Yoruba")`
+    pub const LANGUAGE: Option<&str> = Some("Yoruba");
+    /// `Some("0.2")`
+    pub const REVISION: Option<&str> = Some("0.2");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Nigeria")`
+    pub const TERRITORY: Option<&str> = Some("Nigeria");
+    /// `Some("Yoruba locale for Nigeria")`
+    pub const TITLE: Option<&str> = Some("Yoruba locale for Nigeria");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0rROoKk]"`
+    pub const NOEXPR: &str = "^[-0rROoKk]";
+    /// `Some("Bẹ\u{301}ẹ\u{300}kọ\u{301}")`
+    pub const NOSTR: Option<&str> = Some("Bẹ\u{301}ẹ\u{300}kọ\u{301}");
+    /// `"^[+1EeyYNn]"`
+    pub const YESEXPR: &str = "^[+1EeyYNn]";
+    /// `Some("Bẹ\u{301}ẹ\u{300}ni")`
+    pub const YESSTR: Option<&str> = Some("Bẹ\u{301}ẹ\u{300}ni");
+}
+pub use super::en_NG::LC_MONETARY;
+pub use super::en_NG::LC_NUMERIC;
+pub use super::en_NG::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["Àìkú", "Ajé", "Ìsẹ\u{301}gun", "Ọjọ\u{301}rú", "Ọjọ\u{301}bọ", "Ẹtì", "Àbámẹ\u{301}ta"]`
+    pub const ABDAY: &[&str] = &["Àìkú", "Ajé", "Ìsẹ\u{301}gun", "Ọjọ\u{301}rú", "Ọjọ\u{301}bọ", "Ẹtì", "Àbámẹ\u{301}ta"];
+    /// `&["Ṣẹ\u{301}rẹ\u{301}", "Èrèlè", "Ẹrẹ\u{300}nà", "Ìgbé", "Ẹ\u{300}bibi", "Òkúdu", "Agẹmọ", "Ògún", "Owewe", "Ọ\u{300}wàrà", "Bélú", "Ọ\u{300}pẹ\u{300}"]`
+    pub const ABMON: &[&str] = &["Ṣẹ\u{301}rẹ\u{301}", "Èrèlè", "Ẹrẹ\u{300}nà", "Ìgbé", "Ẹ\u{300}bibi", "Òkúdu", "Agẹmọ", "Ògún", "Owewe", "Ọ\u{300}wàrà", "Bélú", "Ọ\u{300}pẹ\u{300}"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["Ọjọ\u{301} Àìkú", "Ọjọ\u{301} Ajé", "Ọjọ\u{301} Ìsẹ\u{301}gun", "Ọjọ\u{301}rú", "Ọjọ\u{301}bọ", "Ọjọ\u{301} Ẹtì", "Ọjọ\u{301} Àbámẹ\u{301}ta"]`
+    pub const DAY: &[&str] = &["Ọjọ\u{301} Àìkú", "Ọjọ\u{301} Ajé", "Ọjọ\u{301} Ìsẹ\u{301}gun", "Ọjọ\u{301}rú", "Ọjọ\u{301}bọ", "Ọjọ\u{301} Ẹtì", "Ọjọ\u{301} Àbámẹ\u{301}ta"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"ọjọ\u{301} %a, %d oṣù %b ọdún %Y %T %Z"`
+    pub const D_T_FMT: &str = "ọjọ\u{301} %a, %d oṣù %b ọdún %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `Some(2)`
+    pub const FIRST_WEEKDAY: Option<i64> = Some(2);
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"]`
+    pub const MON: &[&str] = &["Oṣù Ṣẹ\u{301}rẹ\u{301}", "Oṣù Èrèlè", "Oṣù Ẹrẹ\u{300}nà", "Oṣù Ìgbé", "Oṣù Ẹ\u{300}bibi", "Oṣù Òkúdu", "Oṣù Agẹmọ", "Oṣù Ògún", "Oṣù Owewe", "Oṣù Ọ\u{300}wàrà", "Oṣù Bélú", "Oṣù Ọ\u{300}pẹ\u{300}"];
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT: &str = "%I:%M:%S %p";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `"%r"`
+        pub const T_FMT: &str = "%r";
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+        pub const T_FMT: crate::Provenance = crate::Provenance::Inlined;
+    }
+}
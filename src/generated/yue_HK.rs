@@ -0,0 +1,138 @@
+pub mod LC_ADDRESS {
+    /// `Some("HKG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("HKG");
+    /// `Some("HK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("HK");
+    /// `Some("HK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("HK");
+    /// `Some("962")`
+    pub const COUNTRY_ISBN: Option<&str> = Some("962");
+    /// `Some("中華人民共和國香港特別行政區")`
+    pub const COUNTRY_NAME: Option<&str> = Some("中華人民共和國香港特別行政區");
+    /// `Some(344)`
+    pub const COUNTRY_NUM: Option<i64> = Some(344);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `None`
+    pub const LANG_AB: Option<&str> = None;
+    /// `Some("yue")`
+    pub const LANG_LIB: Option<&str> = Some("yue");
+    /// `Some("粵語")`
+    pub const LANG_NAME: Option<&str> = Some("粵語");
+    /// `Some("yue")`
+    pub const LANG_TERM: Option<&str> = Some("yue");
+    /// `Some("%c%T%s%h%N%b%e%r%N%f%N%d%N%a")`
+    pub const POSTAL_FMT: Option<&str> = Some("%c%T%s%h%N%b%e%r%N%f%N%d%N%a");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2010-11-07")`
+    pub const DATE: Option<&str> = Some("2010-11-07");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Cantonese")`
+    pub const LANGUAGE: Option<&str> = Some("Cantonese");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("")`
+    pub const SOURCE: Option<&str> = Some("");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Hong Kong SAR China")`
+    pub const TERRITORY: Option<&str> = Some("Hong Kong SAR China");
+    /// `Some("Yue Chinese (Cantonese) language locale for Hong Kong")`
+    pub const TITLE: Option<&str> = Some("Yue Chinese (Cantonese) language locale for Hong Kong");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nN]"`
+    pub const NOEXPR: &str = "^[-0nN]";
+    /// `Some("唔係")`
+    pub const NOSTR: Option<&str> = Some("唔係");
+    /// `"^[+1yY]"`
+    pub const YESEXPR: &str = "^[+1yY]";
+    /// `Some("係")`
+    pub const YESSTR: Option<&str> = Some("係");
+}
+pub use super::zh_HK::LC_MONETARY;
+pub use super::zh_HK::LC_NUMERIC;
+pub use super::zh_HK::LC_TELEPHONE;
+pub mod LC_TIME {
+    /// `&["日", "一", "二", "三", "四", "五", "六"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_24;
+    /// `&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_1;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["上午", "下午"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_17;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `Some("%Y年 %b %e日 %A %H:%M:%S %Z")`
+    pub const DATE_FMT: Option<&str> = Some("%Y年 %b %e日 %A %H:%M:%S %Z");
+    /// `&["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_25;
+    /// `"%Y年%m月%d日 %A"`
+    pub const D_FMT: &str = "%Y年%m月%d日 %A";
+    /// `"%Y年%m月%d日 %A %H點%M分%S秒"`
+    pub const D_T_FMT: &str = "%Y年%m月%d日 %A %H點%M分%S秒";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_0;
+    /// `"%H點%M分%S秒"`
+    pub const T_FMT: &str = "%H點%M分%S秒";
+    /// `"%p%I點%M分%S秒"`
+    pub const T_FMT_AMPM: &str = "%p%I點%M分%S秒";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p%I點%M分%S秒")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p%I點%M分%S秒");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("PNG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("PNG");
+    /// `Some("PG")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("PG");
+    /// `Some("PNG")`
+    pub const COUNTRY_CAR: Option<&str> = Some("PNG");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `Some("Papua New Guinea")`
+    pub const COUNTRY_NAME: Option<&str> = Some("Papua New Guinea");
+    /// `Some(598)`
+    pub const COUNTRY_NUM: Option<i64> = Some(598);
+    /// `Some("")`
+    pub const COUNTRY_POST: Option<&str> = Some("");
+    /// `Some("")`
+    pub const LANG_AB: Option<&str> = Some("");
+    /// `Some("yuw")`
+    pub const LANG_LIB: Option<&str> = Some("yuw");
+    /// `Some("Uruwa")`
+    pub const LANG_NAME: Option<&str> = Some("Uruwa");
+    /// `Some("yuw")`
+    pub const LANG_TERM: Option<&str> = Some("yuw");
+    /// `Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N")`
+    pub const POSTAL_FMT: Option<&str> = Some("%f%N%a%N%d%N%b%N%s %h %e %r%N%z %T%N%c%N");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("")`
+    pub const ADDRESS: Option<&str> = Some("");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_6);
+    /// `Some("Hannah Sarvasy")`
+    pub const CONTACT: Option<&str> = Some("Hannah Sarvasy");
+    /// `Some("2016-12-07")`
+    pub const DATE: Option<&str> = Some("2016-12-07");
+    /// `Some("nungon.localization@gmail.com")`
+    pub const EMAIL: Option<&str> = Some("nungon.localization@gmail.com");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Yau")`
+    pub const LANGUAGE: Option<&str> = Some("Yau");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("Information from native speakers")`
+    pub const SOURCE: Option<&str> = Some("Information from native speakers");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Papua New Guinea")`
+    pub const TERRITORY: Option<&str> = Some("Papua New Guinea");
+    /// `Some("YauNungon locale for Papua New Guinea")`
+    pub const TITLE: Option<&str> = Some("YauNungon locale for Papua New Guinea");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNmM]"`
+    pub const NOEXPR: &str = "^[-0nNmM]";
+    /// `Some("muuno")`
+    pub const NOSTR: Option<&str> = Some("muuno");
+    /// `"^[+1yYöÖ]"`
+    pub const YESEXPR: &str = "^[+1yYöÖ]";
+    /// `Some("öö")`
+    pub const YESSTR: Option<&str> = Some("öö");
+}
+pub mod LC_MONETARY {
+    /// `"K"`
+    pub const CURRENCY_SYMBOL: &str = "K";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"PGK "`
+    pub const INT_CURR_SYMBOL: &str = "PGK ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_2;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const N_SIGN_POSN: i64 = 1;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3, 3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_3;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("675")`
+    pub const INT_PREFIX: Option<&str> = Some("675");
+    /// `Some("00")`
+    pub const INT_SELECT: Option<&str> = Some("00");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c %a %l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c %a %l");
+}
+pub mod LC_TIME {
+    /// `&["sön", "mön", "sin", "mit", "soi", "nen", "sab"]`
+    pub const ABDAY: &[&str] = &["sön", "mön", "sin", "mit", "soi", "nen", "sab"];
+    /// `&["jen", "feb", "mas", "epr", "mei", "jun", "jul", "ögu", "sep", "ökt", "nöw", "dis"]`
+    pub const ABMON: &[&str] = &["jen", "feb", "mas", "epr", "mei", "jun", "jul", "ögu", "sep", "ökt", "nöw", "dis"];
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["AM", "PM"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_1;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["sönda", "mönda", "sinda", "mitiwö", "sogipbono", "nenggo", "söndanggie"]`
+    pub const DAY: &[&str] = &["sönda", "mönda", "sinda", "mitiwö", "sogipbono", "nenggo", "söndanggie"];
+    /// `"%d/%m/%y"`
+    pub const D_FMT: &str = "%d/%m/%y";
+    /// `"%a %d %b %Y %T %Z"`
+    pub const D_T_FMT: &str = "%a %d %b %Y %T %Z";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["jenuari", "febuari", "mas", "epril", "mei", "jun", "julai", "ögus", "septemba", "öktoba", "nöwemba", "diksemba"]`
+    pub const MON: &[&str] = &["jenuari", "febuari", "mas", "epril", "mei", "jun", "julai", "ögus", "septemba", "öktoba", "nöwemba", "diksemba"];
+    /// `"%T"`
+    pub const T_FMT: &str = "%T";
+    /// `"%I:%M:%S %p"`
+    pub const T_FMT_AMPM: &str = "%I:%M:%S %p";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%I:%M:%S %p")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%I:%M:%S %p");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
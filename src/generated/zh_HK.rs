@@ -0,0 +1,197 @@
+pub mod LC_ADDRESS {
+    /// `Some("HKG")`
+    pub const COUNTRY_AB3: Option<&str> = Some("HKG");
+    /// `Some("HK")`
+    pub const COUNTRY_ABTWO: Option<&str> = Some("HK");
+    /// `Some("HK")`
+    pub const COUNTRY_CAR: Option<&str> = Some("HK");
+    /// `None`
+    pub const COUNTRY_ISBN: Option<&str> = None;
+    /// `None`
+    pub const COUNTRY_NAME: Option<&str> = None;
+    /// `Some(344)`
+    pub const COUNTRY_NUM: Option<i64> = Some(344);
+    /// `None`
+    pub const COUNTRY_POST: Option<&str> = None;
+    /// `Some("zh")`
+    pub const LANG_AB: Option<&str> = Some("zh");
+    /// `Some("chi")`
+    pub const LANG_LIB: Option<&str> = Some("chi");
+    /// `Some("繁體中文")`
+    pub const LANG_NAME: Option<&str> = Some("繁體中文");
+    /// `Some("zho")`
+    pub const LANG_TERM: Option<&str> = Some("zho");
+    /// `Some("%z%c%T%s%b%e%r")`
+    pub const POSTAL_FMT: Option<&str> = Some("%z%c%T%s%b%e%r");
+}
+pub mod LC_IDENTIFICATION {
+    /// `None`
+    pub const ABBREVIATION: Option<&str> = None;
+    /// `Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan")`
+    pub const ADDRESS: Option<&str> = Some("1623-14, Shimotsuruma, Yamato-shi, Kanagawa-ken, 242-8502, Japan");
+    /// ```ignore
+    /// &[
+    ///     &["i18n:2012", "LC_IDENTIFICATION"],
+    ///     &["i18n:2012", "LC_CTYPE"],
+    ///     &["i18n:2012", "LC_COLLATE"],
+    ///     &["i18n:2012", "LC_TIME"],
+    ///     &["i18n:2012", "LC_NUMERIC"],
+    ///     &["i18n:2012", "LC_MONETARY"],
+    ///     &["i18n:2012", "LC_MESSAGES"],
+    ///     &["i18n:2012", "LC_PAPER"],
+    ///     &["i18n:2012", "LC_NAME"],
+    ///     &["i18n:2012", "LC_ADDRESS"],
+    ///     &["i18n:2012", "LC_TELEPHONE"],
+    ///     &["i18n:2012", "LC_MEASUREMENT"],
+    /// ]
+    /// ```
+    pub const CATEGORY: Option<&[&[&str]]> = Some(crate::__data::LC_IDENTIFICATION::CATEGORY_11);
+    /// `Some("")`
+    pub const CONTACT: Option<&str> = Some("");
+    /// `Some("2000-07-20")`
+    pub const DATE: Option<&str> = Some("2000-07-20");
+    /// `Some("bug-glibc-locales@gnu.org")`
+    pub const EMAIL: Option<&str> = Some("bug-glibc-locales@gnu.org");
+    /// `Some("")`
+    pub const FAX: Option<&str> = Some("");
+    /// `Some("Chinese")`
+    pub const LANGUAGE: Option<&str> = Some("Chinese");
+    /// `Some("1.0")`
+    pub const REVISION: Option<&str> = Some("1.0");
+    /// `Some("IBM Globalization Center of Competency, Yamato Software Laboratory")`
+    pub const SOURCE: Option<&str> = Some("IBM Globalization Center of Competency, Yamato Software Laboratory");
+    /// `Some("")`
+    pub const TEL: Option<&str> = Some("");
+    /// `Some("Hong Kong SAR China")`
+    pub const TERRITORY: Option<&str> = Some("Hong Kong SAR China");
+    /// `Some("Chinese language locale for Hong Kong")`
+    pub const TITLE: Option<&str> = Some("Chinese language locale for Hong Kong");
+}
+pub mod LC_MESSAGES {
+    /// `"^[-0nNｎＮ不否]"`
+    pub const NOEXPR: &str = "^[-0nNｎＮ不否]";
+    /// `Some("否")`
+    pub const NOSTR: Option<&str> = Some("否");
+    /// `"^[+1yYｙＹ是]"`
+    pub const YESEXPR: &str = "^[+1yYｙＹ是]";
+    /// `Some("是")`
+    pub const YESSTR: Option<&str> = Some("是");
+}
+pub mod LC_MONETARY {
+    /// `"HK$"`
+    pub const CURRENCY_SYMBOL: &str = "HK$";
+    /// `2`
+    pub const FRAC_DIGITS: i64 = 2;
+    /// `"HKD "`
+    pub const INT_CURR_SYMBOL: &str = "HKD ";
+    /// `2`
+    pub const INT_FRAC_DIGITS: i64 = 2;
+    /// `None`
+    pub const INT_N_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_N_SIGN_POSN: Option<i64> = None;
+    /// `None`
+    pub const INT_P_CS_PRECEDES: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SEP_BY_SPACE: Option<i64> = None;
+    /// `None`
+    pub const INT_P_SIGN_POSN: Option<i64> = None;
+    /// `"."`
+    pub const MON_DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const MON_GROUPING: &[i64] = crate::__data::LC_MONETARY::MON_GROUPING_3;
+    /// `","`
+    pub const MON_THOUSANDS_SEP: &str = ",";
+    /// `"-"`
+    pub const NEGATIVE_SIGN: &str = "-";
+    /// `1`
+    pub const N_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const N_SEP_BY_SPACE: i64 = 0;
+    /// `0`
+    pub const N_SIGN_POSN: i64 = 0;
+    /// `""`
+    pub const POSITIVE_SIGN: &str = "";
+    /// `1`
+    pub const P_CS_PRECEDES: i64 = 1;
+    /// `0`
+    pub const P_SEP_BY_SPACE: i64 = 0;
+    /// `1`
+    pub const P_SIGN_POSN: i64 = 1;
+}
+pub mod LC_NUMERIC {
+    /// `"."`
+    pub const DECIMAL_POINT: &str = ".";
+    /// `&[3]`
+    pub const GROUPING: &[i64] = crate::__data::LC_NUMERIC::GROUPING_4;
+    /// `","`
+    pub const THOUSANDS_SEP: &str = ",";
+}
+pub mod LC_TELEPHONE {
+    /// `Some("852")`
+    pub const INT_PREFIX: Option<&str> = Some("852");
+    /// `Some("001")`
+    pub const INT_SELECT: Option<&str> = Some("001");
+    /// `None`
+    pub const TEL_DOM_FMT: Option<&str> = None;
+    /// `Some("+%c ;%a ;%l")`
+    pub const TEL_INT_FMT: Option<&str> = Some("+%c ;%a ;%l");
+}
+pub mod LC_TIME {
+    /// `&["日", "一", "二", "三", "四", "五", "六"]`
+    pub const ABDAY: &[&str] = crate::__data::LC_TIME::ABDAY_24;
+    /// `&["1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月"]`
+    pub const ABMON: &[&str] = crate::__data::LC_TIME::ABMON_1;
+    /// `None`
+    pub const AB_ALT_MON: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_DIGITS: Option<&[&str]> = None;
+    /// `None`
+    pub const ALT_MON: Option<&[&str]> = None;
+    /// `&["上午", "下午"]`
+    pub const AM_PM: &[&str] = crate::__data::LC_TIME::AM_PM_17;
+    /// `None`
+    pub const CAL_DIRECTION: Option<i64> = None;
+    /// `None`
+    pub const DATE_FMT: Option<&str> = None;
+    /// `&["星期日", "星期一", "星期二", "星期三", "星期四", "星期五", "星期六"]`
+    pub const DAY: &[&str] = crate::__data::LC_TIME::DAY_25;
+    /// `"%Y年%m月%d日 %A"`
+    pub const D_FMT: &str = "%Y年%m月%d日 %A";
+    /// `"%Y年%m月%d日 %A %H:%M:%S"`
+    pub const D_T_FMT: &str = "%Y年%m月%d日 %A %H:%M:%S";
+    /// `None`
+    pub const ERA: Option<&[&str]> = None;
+    /// `None`
+    pub const ERA_D_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_D_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const ERA_T_FMT: Option<&str> = None;
+    /// `None`
+    pub const FIRST_WEEKDAY: Option<i64> = None;
+    /// `None`
+    pub const FIRST_WORKDAY: Option<i64> = None;
+    /// `&["一月", "二月", "三月", "四月", "五月", "六月", "七月", "八月", "九月", "十月", "十一月", "十二月"]`
+    pub const MON: &[&str] = crate::__data::LC_TIME::MON_31;
+    /// `"%p %I時%M分%S秒 %Z"`
+    pub const T_FMT: &str = "%p %I時%M分%S秒 %Z";
+    /// `"%p %I:%M:%S"`
+    pub const T_FMT_AMPM: &str = "%p %I:%M:%S";
+    /// `Some(&[7, 19971130, 1])`
+    pub const WEEK: Option<&[i64]> = Some(crate::__data::LC_TIME::WEEK_0);
+
+    /// The values of `glibc`, without the fixups applied by this crate.
+    pub mod raw {
+        pub use super::*;
+        /// `Some("%p %I:%M:%S")`
+        pub const T_FMT_AMPM: Option<&str> = Some("%p %I:%M:%S");
+    }
+
+    /// Where the values of this category come from.
+    pub mod provenance {
+        pub use crate::__provenance::LC_TIME::*;
+    }
+}
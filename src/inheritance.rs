@@ -0,0 +1,122 @@
+use crate::__inheritance::{CATEGORIES, CHAINS, LOCALES, MAIN_TERRITORIES};
+use crate::{Category, Locale};
+
+/// A file a category is copied from, with `copy "..."` in the locale source files.
+///
+/// Locales are referred to by name because they are not necessarily compiled in: see
+/// [`Source::locale`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Source {
+    /// Another locale, like `de_DE`.
+    Locale(&'static str),
+    /// A file that is not a locale, like `i18n`.
+    File(&'static str),
+}
+
+impl Source {
+    /// The name of the file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Source::Locale(name) | Source::File(name) => name,
+        }
+    }
+
+    /// The locale, if the source is a locale and it is compiled in.
+    pub fn locale(self) -> Option<Locale> {
+        match self {
+            Source::Locale(name) => core::convert::TryFrom::<&str>::try_from(name).ok(),
+            Source::File(_) => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Source {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Locale {
+    /// The files `category` is copied from, the nearest first.
+    ///
+    /// ```
+    /// use pure_rust_locales::{Category, Locale, Source};
+    ///
+    /// assert_eq!(
+    ///     Locale::de_AT.inheritance(Category::LC_CTYPE),
+    ///     &[Source::Locale("de_DE"), Source::File("i18n"), Source::File("i18n_ctype")],
+    /// );
+    /// ```
+    pub fn inheritance(self, category: Category) -> &'static [Source] {
+        CHAINS[CATEGORIES[self.table_index()][category.index()].0 as usize]
+    }
+
+    /// The locale whose data is used for `category`: the locale itself, unless the category
+    /// consists only of a copy of another locale. Copies are followed transitively.
+    ///
+    /// If the source is not compiled in, the locale itself is returned.
+    ///
+    /// ```
+    /// use pure_rust_locales::{Category, Locale};
+    ///
+    /// assert_eq!(Locale::de_DE_euro.source_of(Category::LC_TIME), Locale::de_DE);
+    /// assert_eq!(Locale::de_DE.source_of(Category::LC_TIME), Locale::de_DE);
+    /// ```
+    pub fn source_of(self, category: Category) -> Locale {
+        let source = LOCALES[CATEGORIES[self.table_index()][category.index()].1 as usize];
+        core::convert::TryFrom::<&str>::try_from(source).unwrap_or(self)
+    }
+
+    /// The locales that share the data of `category` with this one, itself included: the
+    /// locales with the same [`Locale::source_of`].
+    ///
+    /// ```
+    /// use pure_rust_locales::{Category, Locale};
+    ///
+    /// assert!(Locale::de_DE
+    ///     .shared_with(Category::LC_TIME)
+    ///     .any(|locale| locale == Locale::de_DE_euro));
+    /// ```
+    pub fn shared_with(self, category: Category) -> impl Iterator<Item = Locale> {
+        let source = CATEGORIES[self.table_index()][category.index()].1;
+
+        LOCALES
+            .iter()
+            .zip(CATEGORIES.iter())
+            .filter(move |(_, categories)| categories[category.index()].1 == source)
+            .filter_map(|(name, _)| core::convert::TryFrom::<&str>::try_from(*name).ok())
+    }
+
+    /// The locale of the main territory of the language of this locale, like `de_DE` for
+    /// `de_AT`, if there is one and it is compiled in.
+    ///
+    /// ```
+    /// use pure_rust_locales::Locale;
+    ///
+    /// assert_eq!(Locale::de_AT.main_territory(), Some(Locale::de_DE));
+    /// assert_eq!(Locale::en_GB.main_territory(), Some(Locale::en_US));
+    /// ```
+    pub fn main_territory(self) -> Option<Locale> {
+        let index = MAIN_TERRITORIES[self.table_index()]?;
+        core::convert::TryFrom::<&str>::try_from(LOCALES[index as usize]).ok()
+    }
+
+    /// The index of the locale in the inheritance tables.
+    fn table_index(self) -> usize {
+        LOCALES
+            .binary_search(&self.name())
+            .expect("every locale is in the inheritance tables")
+    }
+}
+
+/// The name of the locale of the main territory of the language of a name, whether the locale
+/// of the name and the one of the main territory are compiled in or not.
+pub(crate) fn main_territory_of(name: &str) -> Option<&'static str> {
+    let language = name.split(|x| x == '_' || x == '@').next()?;
+    let index = LOCALES.iter().position(|x| {
+        x.strip_prefix(language)
+            .map_or(false, |x| x.starts_with('_') || x.starts_with('@'))
+    })?;
+    MAIN_TERRITORIES[index].map(|x| LOCALES[x as usize])
+}
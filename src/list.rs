@@ -0,0 +1,284 @@
+//! The lists of the items of a locale returned by the `Dyn*Info` traits, like
+//! [`DynTimeInfo::day`](crate::DynTimeInfo::day). They borrow the arrays of the modules of the
+//! locales, the vectors of a [`LocaleData`](crate::LocaleData) or a [`Blob`].
+//!
+//! ```
+//! use pure_rust_locales::Locale;
+//!
+//! let day = Locale::fr_BE.info().time().day();
+//! assert_eq!(day.len(), 7);
+//! assert_eq!(day.get(1), Some("lundi"));
+//! assert_eq!(day.iter().last(), Some("samedi"));
+//! ```
+
+use crate::blob::Blob;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+/// A list of strings.
+#[derive(Copy, Clone)]
+pub struct Strs<'a>(StrsRepr<'a>);
+
+#[derive(Copy, Clone)]
+enum StrsRepr<'a> {
+    Static(&'a [&'a str]),
+    #[cfg(feature = "alloc")]
+    Owned(&'a [String]),
+    /// The index of the length of the list in the blob.
+    Blob(Blob<'a>, usize),
+}
+
+impl<'a> Strs<'a> {
+    pub(crate) fn blob(blob: Blob<'a>, at: usize) -> Self {
+        Self(StrsRepr::Blob(blob, at))
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            StrsRepr::Static(x) => x.len(),
+            #[cfg(feature = "alloc")]
+            StrsRepr::Owned(x) => x.len(),
+            StrsRepr::Blob(blob, at) => blob.list_len(at, 2),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The string at `index`, `None` if it is out of bounds. The strings that can't be read
+    /// from a corrupted blob are empty.
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        match self.0 {
+            StrsRepr::Static(x) => x.get(index).copied(),
+            #[cfg(feature = "alloc")]
+            StrsRepr::Owned(x) => x.get(index).map(String::as_str),
+            StrsRepr::Blob(blob, at) => {
+                (index < self.len()).then(|| blob.str_at(at + 1 + 2 * index).unwrap_or_default())
+            }
+        }
+    }
+
+    pub fn iter(self) -> impl ExactSizeIterator<Item = &'a str> {
+        (0..self.len()).map(move |i| self.get(i).unwrap_or_default())
+    }
+}
+
+/// An empty list.
+impl<'a> Default for Strs<'a> {
+    fn default() -> Self {
+        Self(StrsRepr::Static(&[]))
+    }
+}
+
+impl<'a> From<&'a [&'a str]> for Strs<'a> {
+    fn from(x: &'a [&'a str]) -> Self {
+        Self(StrsRepr::Static(x))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a [String]> for Strs<'a> {
+    fn from(x: &'a [String]) -> Self {
+        Self(StrsRepr::Owned(x))
+    }
+}
+
+/// A list of integers.
+#[derive(Copy, Clone)]
+pub struct Ints<'a>(IntsRepr<'a>);
+
+#[derive(Copy, Clone)]
+enum IntsRepr<'a> {
+    Slice(&'a [i64]),
+    /// The index of the length of the list in the blob.
+    Blob(Blob<'a>, usize),
+}
+
+impl<'a> Ints<'a> {
+    pub(crate) fn blob(blob: Blob<'a>, at: usize) -> Self {
+        Self(IntsRepr::Blob(blob, at))
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            IntsRepr::Slice(x) => x.len(),
+            IntsRepr::Blob(blob, at) => blob.list_len(at, 2),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The integer at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<i64> {
+        match self.0 {
+            IntsRepr::Slice(x) => x.get(index).copied(),
+            IntsRepr::Blob(blob, at) => {
+                (index < self.len()).then(|| blob.int_at(at + 1 + 2 * index).unwrap_or_default())
+            }
+        }
+    }
+
+    pub fn iter(self) -> impl ExactSizeIterator<Item = i64> + 'a {
+        (0..self.len()).map(move |i| self.get(i).unwrap_or_default())
+    }
+}
+
+/// An empty list.
+impl<'a> Default for Ints<'a> {
+    fn default() -> Self {
+        Self(IntsRepr::Slice(&[]))
+    }
+}
+
+impl<'a> From<&'a [i64]> for Ints<'a> {
+    fn from(x: &'a [i64]) -> Self {
+        Self(IntsRepr::Slice(x))
+    }
+}
+
+/// A list of lists of strings.
+#[derive(Copy, Clone)]
+pub struct Table<'a>(TableRepr<'a>);
+
+#[derive(Copy, Clone)]
+enum TableRepr<'a> {
+    Static(&'a [&'a [&'a str]]),
+    #[cfg(feature = "alloc")]
+    Owned(&'a [Vec<String>]),
+    /// The index of the length of the list in the blob.
+    Blob(Blob<'a>, usize),
+}
+
+impl<'a> Table<'a> {
+    pub(crate) fn blob(blob: Blob<'a>, at: usize) -> Self {
+        Self(TableRepr::Blob(blob, at))
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            TableRepr::Static(x) => x.len(),
+            #[cfg(feature = "alloc")]
+            TableRepr::Owned(x) => x.len(),
+            TableRepr::Blob(blob, at) => blob.list_len(at, 1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The list at `index`, `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Strs<'a>> {
+        match self.0 {
+            TableRepr::Static(x) => x.get(index).map(|x| Strs::from(*x)),
+            #[cfg(feature = "alloc")]
+            TableRepr::Owned(x) => x.get(index).map(|x| Strs::from(x.as_slice())),
+            TableRepr::Blob(blob, at) => {
+                (index < self.len()).then(|| match blob.word(at + 1 + index) {
+                    Some(x) => Strs::blob(blob, x as usize),
+                    None => Strs::default(),
+                })
+            }
+        }
+    }
+
+    pub fn iter(self) -> impl ExactSizeIterator<Item = Strs<'a>> {
+        (0..self.len()).map(move |i| self.get(i).unwrap_or_default())
+    }
+}
+
+/// An empty list.
+impl<'a> Default for Table<'a> {
+    fn default() -> Self {
+        Self(TableRepr::Static(&[]))
+    }
+}
+
+impl<'a> From<&'a [&'a [&'a str]]> for Table<'a> {
+    fn from(x: &'a [&'a [&'a str]]) -> Self {
+        Self(TableRepr::Static(x))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> From<&'a [Vec<String>]> for Table<'a> {
+    fn from(x: &'a [Vec<String>]) -> Self {
+        Self(TableRepr::Owned(x))
+    }
+}
+
+impl<'a> fmt::Debug for Strs<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> fmt::Debug for Ints<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> fmt::Debug for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, 'b> PartialEq<Strs<'b>> for Strs<'a> {
+    fn eq(&self, other: &Strs<'b>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b> PartialEq<Ints<'b>> for Ints<'a> {
+    fn eq(&self, other: &Ints<'b>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b> PartialEq<Table<'b>> for Table<'a> {
+    fn eq(&self, other: &Table<'b>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a> Eq for Strs<'a> {}
+
+impl<'a> Eq for Ints<'a> {}
+
+impl<'a> Eq for Table<'a> {}
+
+impl<'a, 'b> PartialEq<[&'b str]> for Strs<'a> {
+    fn eq(&self, other: &[&'b str]) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+impl<'a, 'b, const N: usize> PartialEq<[&'b str; N]> for Strs<'a> {
+    fn eq(&self, other: &[&'b str; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a> PartialEq<[i64]> for Ints<'a> {
+    fn eq(&self, other: &[i64]) -> bool {
+        self.iter().eq(other.iter().copied())
+    }
+}
+
+impl<'a, const N: usize> PartialEq<[i64; N]> for Ints<'a> {
+    fn eq(&self, other: &[i64; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, 'b> PartialEq<[&'b [&'b str]]> for Table<'a> {
+    fn eq(&self, other: &[&'b [&'b str]]) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(x, y)| x == **y)
+    }
+}
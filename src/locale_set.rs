@@ -0,0 +1,281 @@
+use crate::{DynLocaleInfo, Locale, LocaleProvider, UnknownLocale, UnknownLocaleKind};
+
+/// Locale categories as defined by `glibc`.
+///
+/// The variants are listed in the order used by the composite string returned by
+/// `setlocale(LC_ALL, NULL)`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Category {
+    LC_CTYPE,
+    LC_NUMERIC,
+    LC_TIME,
+    LC_COLLATE,
+    LC_MONETARY,
+    LC_MESSAGES,
+    LC_PAPER,
+    LC_NAME,
+    LC_ADDRESS,
+    LC_TELEPHONE,
+    LC_MEASUREMENT,
+    LC_IDENTIFICATION,
+}
+
+impl Category {
+    /// All the categories, in the order used by the composite string form.
+    pub const ALL: [Category; 12] = [
+        Category::LC_CTYPE,
+        Category::LC_NUMERIC,
+        Category::LC_TIME,
+        Category::LC_COLLATE,
+        Category::LC_MONETARY,
+        Category::LC_MESSAGES,
+        Category::LC_PAPER,
+        Category::LC_NAME,
+        Category::LC_ADDRESS,
+        Category::LC_TELEPHONE,
+        Category::LC_MEASUREMENT,
+        Category::LC_IDENTIFICATION,
+    ];
+
+    /// The name of the category, which is also the name of its environment variable.
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::LC_CTYPE => "LC_CTYPE",
+            Category::LC_NUMERIC => "LC_NUMERIC",
+            Category::LC_TIME => "LC_TIME",
+            Category::LC_COLLATE => "LC_COLLATE",
+            Category::LC_MONETARY => "LC_MONETARY",
+            Category::LC_MESSAGES => "LC_MESSAGES",
+            Category::LC_PAPER => "LC_PAPER",
+            Category::LC_NAME => "LC_NAME",
+            Category::LC_ADDRESS => "LC_ADDRESS",
+            Category::LC_TELEPHONE => "LC_TELEPHONE",
+            Category::LC_MEASUREMENT => "LC_MEASUREMENT",
+            Category::LC_IDENTIFICATION => "LC_IDENTIFICATION",
+        }
+    }
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl core::fmt::Display for Category {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl core::str::FromStr for Category {
+    type Err = UnknownLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Category::ALL
+            .iter()
+            .copied()
+            .find(|category| category.name() == s)
+            .ok_or_else(|| UnknownLocale::with_kind(s, UnknownLocaleKind::Category))
+    }
+}
+
+/// A [`Locale`] per [`Category`], like a process running with `LANG=en_US` and
+/// `LC_TIME=en_GB`.
+///
+/// It parses and renders the composite form returned by `setlocale(LC_ALL, NULL)`:
+/// `LC_CTYPE=...;LC_NUMERIC=...;...`. When every category uses the same locale, the plain
+/// locale name is accepted and rendered instead.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LocaleSet {
+    locales: [Locale; 12],
+}
+
+impl LocaleSet {
+    /// Use the same locale for every category.
+    pub fn uniform(locale: Locale) -> Self {
+        Self {
+            locales: [locale; 12],
+        }
+    }
+
+    /// Build a set from environment variables, following the POSIX precedence: `LC_ALL`, then
+    /// the variable named after the category, then `LANG`, then `POSIX`.
+    ///
+    /// `var` is called with the name of the variable and returns its value, if set. Empty values
+    /// are treated as unset.
+    pub fn from_vars<'a, F>(mut var: F) -> Result<Self, UnknownLocale>
+    where
+        F: FnMut(&str) -> Option<&'a str>,
+    {
+        let mut var = |name: &str| var(name).filter(|value| !value.is_empty());
+        let all = var("LC_ALL");
+        let lang = var("LANG");
+        let mut set = Self::default();
+
+        for category in Category::ALL.iter().copied() {
+            if let Some(name) = all.or_else(|| var(category.name())).or(lang) {
+                set.set(category, parse_locale_name(name)?);
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// The locale used for `category`.
+    pub fn get(&self, category: Category) -> Locale {
+        self.locales[category.index()]
+    }
+
+    /// Change the locale used for `category`.
+    pub fn set(&mut self, category: Category, locale: Locale) {
+        self.locales[category.index()] = locale;
+    }
+
+    /// Builder-style version of [`LocaleSet::set`].
+    pub fn with(mut self, category: Category, locale: Locale) -> Self {
+        self.set(category, locale);
+        self
+    }
+
+    /// Returns the locale if every category uses the same one.
+    pub fn as_uniform(&self) -> Option<Locale> {
+        let first = self.locales[0];
+        self.locales
+            .iter()
+            .all(|locale| *locale == first)
+            .then(|| first)
+    }
+
+    /// The data of the locale used for `category`, taken from `provider`.
+    ///
+    /// ```
+    /// use pure_rust_locales::{Builtin, Category, Locale, LocaleSet};
+    ///
+    /// let set = LocaleSet::uniform(Locale::en_US).with(Category::LC_TIME, Locale::en_GB);
+    /// let time = set.info(&Builtin, Category::LC_TIME).unwrap().time();
+    /// assert_eq!(time.d_fmt(), "%d/%m/%y");
+    /// ```
+    pub fn info<'a, P>(&self, provider: &'a P, category: Category) -> Option<&'a dyn DynLocaleInfo>
+    where
+        P: LocaleProvider + ?Sized,
+    {
+        provider.locale(self.get(category).name())
+    }
+
+    /// Iterate over every category with its locale.
+    pub fn iter(&self) -> impl Iterator<Item = (Category, Locale)> + '_ {
+        Category::ALL
+            .iter()
+            .copied()
+            .map(move |category| (category, self.get(category)))
+    }
+}
+
+impl Default for LocaleSet {
+    fn default() -> Self {
+        Self::uniform(Locale::default())
+    }
+}
+
+impl From<Locale> for LocaleSet {
+    fn from(locale: Locale) -> Self {
+        Self::uniform(locale)
+    }
+}
+
+impl core::fmt::Display for LocaleSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(locale) = self.as_uniform() {
+            return core::fmt::Display::fmt(&locale, f);
+        }
+
+        for (i, (category, locale)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(";")?;
+            }
+            write!(f, "{}={}", category, locale)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for LocaleSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(self, f)
+    }
+}
+
+impl core::str::FromStr for LocaleSet {
+    type Err = UnknownLocale;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        core::convert::TryFrom::<&str>::try_from(s)
+    }
+}
+
+/// Parses either a single locale name or the composite form
+/// `LC_CTYPE=...;LC_NUMERIC=...;...`.
+///
+/// Categories missing from the composite form use `POSIX`; a category can't be given twice.
+impl core::convert::TryFrom<&str> for LocaleSet {
+    type Error = UnknownLocale;
+
+    fn try_from(i: &str) -> Result<Self, Self::Error> {
+        if !i.contains('=') {
+            return parse_locale_name(i).map(Self::uniform);
+        }
+
+        let mut set = Self::default();
+        let mut seen = [false; 12];
+
+        for part in i.split(';') {
+            let (name, locale) = match part.find('=') {
+                Some(pos) => (&part[..pos], &part[pos + 1..]),
+                None => return Err(UnknownLocale::with_kind(part, UnknownLocaleKind::Syntax)),
+            };
+            let category: Category = name.parse()?;
+            if core::mem::replace(&mut seen[category.index()], true) {
+                return Err(UnknownLocale::with_kind(
+                    name,
+                    UnknownLocaleKind::RepeatedCategory,
+                ));
+            }
+            set.set(category, parse_locale_name(locale)?);
+        }
+
+        Ok(set)
+    }
+}
+
+/// Parse a locale name as found in the environment or returned by `setlocale`: the codeset is
+/// ignored (`en_US.UTF-8@euro` is read as `en_US@euro`) and `C` is an alias of `POSIX`.
+fn parse_locale_name(name: &str) -> Result<Locale, UnknownLocale> {
+    let (base, modifier) = match name.find('@') {
+        Some(pos) => name.split_at(pos),
+        None => (name, ""),
+    };
+    let base = match base.find('.') {
+        Some(pos) => &base[..pos],
+        None => base,
+    };
+
+    if base == "C" && modifier.is_empty() {
+        return Ok(Locale::POSIX);
+    }
+
+    let mut buf = [0_u8; 64];
+    let len = base.len() + modifier.len();
+    if len > buf.len() {
+        return Err(UnknownLocale::with_kind(name, UnknownLocaleKind::Syntax));
+    }
+    buf[..base.len()].copy_from_slice(base.as_bytes());
+    buf[base.len()..len].copy_from_slice(modifier.as_bytes());
+
+    match core::str::from_utf8(&buf[..len]) {
+        Ok(name) => core::convert::TryFrom::<&str>::try_from(name),
+        Err(_) => Err(UnknownLocale::with_kind(name, UnknownLocaleKind::Syntax)),
+    }
+}
@@ -0,0 +1,662 @@
+//! Parser of the locale source files of `glibc`, the input of `localedef`, to load locales at
+//! runtime.
+//!
+//! [`SourceFile::parse`] reads the categories of a file and their keywords as they are written.
+//! [`LocaleData::parse`](crate::LocaleData::parse) and, with the feature `std`,
+//! [`LocaleData::load`](crate::LocaleData::load) resolve the `copy` and `include` of the
+//! categories and convert the keywords to the typed items of the generated modules, with the
+//! fixups of the generated data applied to `LC_TIME`.
+
+use crate::owned::LocaleData;
+use crate::Category;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A value of a keyword.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// `"..."`, with the escaped characters and the `<Uxxxx>` symbols replaced.
+    String(String),
+    Integer(i64),
+    /// Any other token as written, like `<U0041>` or `LC_IDENTIFICATION`.
+    Raw(String),
+}
+
+impl Value {
+    fn from_token(token: String) -> Self {
+        let integer = !token.is_empty()
+            && token
+                .char_indices()
+                .all(|(i, c)| c.is_ascii_digit() || (i == 0 && c == '-'));
+
+        match token.parse() {
+            Ok(x) if integer => Value::Integer(x),
+            _ => Value::Raw(token),
+        }
+    }
+
+    /// The value as a string: the content of a string, an integer in decimal or a raw token.
+    pub fn to_text(&self) -> String {
+        match self {
+            Value::String(x) | Value::Raw(x) => x.clone(),
+            Value::Integer(x) => x.to_string(),
+        }
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+/// A keyword of a category with its values, like `d_fmt "%d/%m/%y"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Keyword {
+    pub name: String,
+    pub values: Vec<Value>,
+    /// The line the keyword starts at, from 1.
+    pub line: usize,
+}
+
+/// A category of a source file: `LC_TIME` ... `END LC_TIME`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Definition {
+    pub category: Category,
+    /// The keywords in order, including `copy` and `include`.
+    pub keywords: Vec<Keyword>,
+}
+
+/// A locale source file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceFile {
+    pub definitions: Vec<Definition>,
+}
+
+impl SourceFile {
+    /// Parse a locale source file.
+    ///
+    /// ```
+    /// use pure_rust_locales::localedef::{SourceFile, Value};
+    /// use pure_rust_locales::Category;
+    ///
+    /// let file = SourceFile::parse("LC_TIME\nd_fmt \"%d.%m.%Y\"\nEND LC_TIME\n").unwrap();
+    /// let time = file.definition(Category::LC_TIME).unwrap();
+    /// assert_eq!(time.keywords[0].name, "d_fmt");
+    /// assert_eq!(time.keywords[0].values, [Value::String("%d.%m.%Y".to_string())]);
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let mut lexer = Lexer::new(input);
+        let mut definitions = Vec::new();
+        let mut current: Option<Definition> = None;
+
+        while let Some((line, tokens)) = lexer.next_line()? {
+            let (name, values) = split_keyword(line, tokens)?;
+
+            match current.as_mut() {
+                None => match name.as_str() {
+                    "comment_char" | "escape_char" => {
+                        let c = match values.as_slice() {
+                            [value] if value.to_text().chars().count() == 1 => {
+                                value.to_text().chars().next().unwrap()
+                            }
+                            _ => return Err(Error::new(line, ErrorKind::InvalidArguments(name))),
+                        };
+                        if name == "comment_char" {
+                            lexer.comment_char = c;
+                        } else {
+                            lexer.escape_char = c;
+                        }
+                    }
+                    _ => match name.parse() {
+                        Ok(category) if values.is_empty() => {
+                            current = Some(Definition {
+                                category,
+                                keywords: Vec::new(),
+                            })
+                        }
+                        _ => return Err(Error::new(line, ErrorKind::UnknownCategory(name))),
+                    },
+                },
+                Some(definition) if name == "END" => {
+                    let found = values.first().map(Value::to_text).unwrap_or_default();
+                    if found != definition.category.name() {
+                        return Err(Error::new(
+                            line,
+                            ErrorKind::MismatchedEnd {
+                                expected: definition.category.name().to_string(),
+                                found,
+                            },
+                        ));
+                    }
+                    definitions.extend(current.take());
+                }
+                Some(definition) => definition.keywords.push(Keyword { name, values, line }),
+            }
+        }
+
+        match current {
+            Some(definition) => Err(Error::new(
+                lexer.line,
+                ErrorKind::MissingEnd(definition.category.name().to_string()),
+            )),
+            None => Ok(Self { definitions }),
+        }
+    }
+
+    /// The definition of a category, if the file has one.
+    pub fn definition(&self, category: Category) -> Option<&Definition> {
+        self.definitions.iter().find(|x| x.category == category)
+    }
+}
+
+/// Split the tokens of a line into the keyword and its values. Empty values, between two `;`,
+/// are skipped.
+fn split_keyword(line: usize, tokens: Vec<Token>) -> Result<(String, Vec<Value>), Error> {
+    let mut tokens = tokens.into_iter();
+    let name = match tokens.next() {
+        Some(Token::Word(name)) => name,
+        _ => return Err(Error::new(line, ErrorKind::Syntax)),
+    };
+    let values = tokens
+        .filter_map(|token| match token {
+            Token::Word(x) => Some(Value::from_token(x)),
+            Token::String(x) => Some(Value::String(x)),
+            Token::Semicolon => None,
+        })
+        .collect();
+
+    Ok((name, values))
+}
+
+#[derive(Debug)]
+enum Token {
+    Word(String),
+    String(String),
+    Semicolon,
+}
+
+/// Splits the input in logical lines of tokens, following the continuations and skipping the
+/// comments.
+struct Lexer<'a> {
+    input: core::iter::Peekable<core::str::Chars<'a>>,
+    line: usize,
+    comment_char: char,
+    escape_char: char,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input: input.chars().peekable(),
+            line: 1,
+            comment_char: '#',
+            escape_char: '\\',
+        }
+    }
+
+    /// Consume a newline, if there is one: `\n`, `\r\n` or a lone `\r`.
+    fn newline(&mut self) -> bool {
+        match self.input.peek() {
+            Some('\r') => {
+                self.input.next();
+                if self.input.peek() == Some(&'\n') {
+                    self.input.next();
+                }
+            }
+            Some('\n') => {
+                self.input.next();
+            }
+            _ => return false,
+        }
+        self.line += 1;
+        true
+    }
+
+    fn next_line(&mut self) -> Result<Option<(usize, Vec<Token>)>, Error> {
+        let mut tokens = Vec::new();
+        let mut start = self.line;
+
+        while let Some(&c) = self.input.peek() {
+            if tokens.is_empty() {
+                start = self.line;
+            }
+            match c {
+                '\n' | '\r' => {
+                    self.newline();
+                    if !tokens.is_empty() {
+                        return Ok(Some((start, tokens)));
+                    }
+                }
+                ' ' | '\t' => {
+                    self.input.next();
+                }
+                ';' => {
+                    self.input.next();
+                    tokens.push(Token::Semicolon);
+                }
+                '"' => {
+                    self.input.next();
+                    tokens.push(Token::String(self.string()?));
+                }
+                c if c == self.comment_char => self.comment(),
+                c if c == self.escape_char => {
+                    self.input.next();
+                    if !self.newline() {
+                        tokens.push(Token::Word(self.word()));
+                    }
+                }
+                _ => tokens.push(Token::Word(self.word())),
+            }
+        }
+
+        Ok(Some((start, tokens)).filter(|(_, tokens)| !tokens.is_empty()))
+    }
+
+    /// Skip a comment up to the end of the line. Like the other lines, it continues on the next
+    /// line if it ends with the escape character.
+    fn comment(&mut self) {
+        while let Some(&c) = self.input.peek() {
+            if c == '\n' || c == '\r' {
+                return;
+            }
+            self.input.next();
+            if c == self.escape_char && self.newline() {
+                return;
+            }
+        }
+    }
+
+    /// A token up to the next space or `;`. The escape character keeps the following character
+    /// as is. The first character is always kept: it is either a regular character or the one
+    /// following an escape character, already consumed.
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        let mut escaped = true;
+
+        while let Some(&c) = self.input.peek() {
+            if escaped {
+                escaped = false;
+            } else if c == self.escape_char {
+                self.input.next();
+                escaped = true;
+                if self.newline() {
+                    break;
+                }
+                continue;
+            } else if matches!(c, ' ' | '\t' | '\r' | '\n' | ';' | '"') {
+                break;
+            }
+            word.push(c);
+            self.input.next();
+        }
+
+        word
+    }
+
+    /// The content of a string, after its opening quote.
+    fn string(&mut self) -> Result<String, Error> {
+        let start = self.line;
+        let mut string = String::new();
+
+        loop {
+            match self.input.next() {
+                None | Some('\n') => return Err(Error::new(start, ErrorKind::UnterminatedString)),
+                Some('"') => break,
+                Some(c) if c == self.escape_char => {
+                    if !self.newline() {
+                        match self.input.next() {
+                            Some(c) => string.push(c),
+                            None => return Err(Error::new(start, ErrorKind::UnterminatedString)),
+                        }
+                    }
+                }
+                Some('<') if self.input.peek() == Some(&'U') => {
+                    let mut escape = String::from("<");
+                    for c in self.input.by_ref() {
+                        escape.push(c);
+                        if c == '>' || c == '"' || c == '\n' {
+                            break;
+                        }
+                    }
+                    let c = escape
+                        .strip_prefix("<U")
+                        .and_then(|x| x.strip_suffix('>'))
+                        .and_then(|x| u32::from_str_radix(x, 16).ok())
+                        .and_then(core::char::from_u32);
+                    match c {
+                        Some(c) => string.push(c),
+                        None => {
+                            return Err(Error::new(self.line, ErrorKind::BadUnicodeEscape(escape)))
+                        }
+                    }
+                }
+                Some(c) => string.push(c),
+            }
+        }
+
+        Ok(string)
+    }
+}
+
+/// Why a locale could not be parsed or loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    Syntax,
+    UnterminatedString,
+    BadUnicodeEscape(String),
+    UnknownCategory(String),
+    MismatchedEnd {
+        expected: String,
+        found: String,
+    },
+    MissingEnd(String),
+    InvalidArguments(String),
+    /// A file copied or included could not be found.
+    NotFound(String),
+    /// A category copies itself through other files.
+    CircularCopy(String),
+    /// A file could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+/// An error of [`SourceFile::parse`] or of the loading of a [`LocaleData`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// The file the error is in, if it is not the file parsed directly.
+    pub file: Option<String>,
+    /// The line the error is at, from 1. It is 0 for the errors that are not in a file.
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+impl Error {
+    fn new(line: usize, kind: ErrorKind) -> Self {
+        Self {
+            file: None,
+            line,
+            kind,
+        }
+    }
+
+    fn in_file(mut self, file: &str) -> Self {
+        self.file.get_or_insert_with(|| file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Syntax => write!(f, "syntax error"),
+            ErrorKind::UnterminatedString => write!(f, "unterminated string"),
+            ErrorKind::BadUnicodeEscape(escape) => write!(f, "invalid unicode escape `{}`", escape),
+            ErrorKind::UnknownCategory(name) => write!(f, "unknown category `{}`", name),
+            ErrorKind::MismatchedEnd { expected, found } => {
+                write!(f, "expected `END {}`, found `END {}`", expected, found)
+            }
+            ErrorKind::MissingEnd(name) => write!(f, "expected `END {}`", name),
+            ErrorKind::InvalidArguments(keyword) => {
+                write!(f, "invalid arguments for `{}`", keyword)
+            }
+            ErrorKind::NotFound(name) => write!(f, "file `{}` not found", name),
+            ErrorKind::CircularCopy(name) => write!(f, "circular copy of `{}`", name),
+            #[cfg(feature = "std")]
+            ErrorKind::Io(kind) => write!(f, "could not read the file ({:?})", kind),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        if self.line > 0 {
+            write!(f, "{}: ", self.line)?;
+        } else if self.file.is_some() {
+            f.write_str(" ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Resolve the `copy` and `include` of a category: the keywords of the files they refer to, then
+/// the keywords of the category that replace them.
+pub(crate) fn resolve(
+    file: &SourceFile,
+    category: Category,
+    load: &mut dyn FnMut(&str) -> Result<SourceFile, Error>,
+    chain: &mut Vec<String>,
+) -> Result<Vec<Keyword>, Error> {
+    let definition = match file.definition(category) {
+        Some(definition) => definition,
+        None => return Ok(Vec::new()),
+    };
+    let mut inherited = Vec::new();
+    let mut own: Vec<Keyword> = Vec::new();
+
+    for keyword in definition.keywords.iter() {
+        if keyword.name != "copy" && keyword.name != "include" {
+            own.push(keyword.clone());
+            continue;
+        }
+
+        let name = match keyword.values.first() {
+            Some(Value::String(name)) => name,
+            _ => {
+                return Err(Error::new(
+                    keyword.line,
+                    ErrorKind::InvalidArguments(keyword.name.clone()),
+                ))
+            }
+        };
+        if chain.contains(name) {
+            return Err(Error::new(
+                keyword.line,
+                ErrorKind::CircularCopy(category.name().to_string()),
+            ));
+        }
+        let source = load(name).map_err(|err| match err.kind {
+            ErrorKind::NotFound(_) => Error::new(keyword.line, err.kind),
+            _ => err.in_file(name),
+        })?;
+
+        chain.push(name.clone());
+        inherited.extend(resolve(&source, category, load, chain).map_err(|x| x.in_file(name))?);
+        chain.pop();
+    }
+
+    inherited.retain(|x| own.iter().all(|y| y.name != x.name));
+    inherited.extend(own);
+
+    Ok(inherited)
+}
+
+impl LocaleData {
+    /// Parse a locale source file named `name`. `load` is called with the name of the files
+    /// copied or included, and returns their content.
+    ///
+    /// The items that are not defined use the values of `POSIX`. `t_fmt_ampm`, `d_t_fmt` and
+    /// `t_fmt` are fixed up like in the generated data.
+    ///
+    /// ```
+    /// use pure_rust_locales::LocaleData;
+    ///
+    /// let input = "LC_TIME\ncopy \"base\"\nd_fmt \"%d.%m.%Y\"\nEND LC_TIME\n";
+    /// let data = LocaleData::parse("xx_XX", input, |name| match name {
+    ///     "base" => Some("LC_TIME\nd_fmt \"%d/%m/%Y\"\nt_fmt \"%H:%M\"\nEND LC_TIME\n".into()),
+    ///     _ => None,
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(data.time.d_fmt, "%d.%m.%Y");
+    /// assert_eq!(data.time.t_fmt, "%H:%M");
+    /// assert_eq!(data.numeric.decimal_point, ".");
+    /// ```
+    pub fn parse(
+        name: &str,
+        input: &str,
+        mut load: impl FnMut(&str) -> Option<String>,
+    ) -> Result<Self, Error> {
+        let file = SourceFile::parse(input)?;
+        Self::resolve(name, &file, &mut |name| match load(name) {
+            Some(input) => SourceFile::parse(&input),
+            None => Err(Error::new(0, ErrorKind::NotFound(name.to_string()))),
+        })
+    }
+
+    /// Load the locale named `name` from the first directory of `search_path` that has it,
+    /// looking the files it copies or includes up in the same way.
+    ///
+    /// ```no_run
+    /// use pure_rust_locales::LocaleData;
+    ///
+    /// let data = LocaleData::load("fr_BE", &["/usr/share/i18n/locales"]).unwrap();
+    /// println!("{}", data.time.d_fmt);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load<P: AsRef<std::path::Path>>(name: &str, search_path: &[P]) -> Result<Self, Error> {
+        let mut load = |name: &str| {
+            let path = search_path
+                .iter()
+                .map(|dir| dir.as_ref().join(name))
+                .find(|path| path.is_file());
+            let input = match path {
+                Some(path) => std::fs::read_to_string(path),
+                None => return Err(Error::new(0, ErrorKind::NotFound(name.to_string()))),
+            };
+            let input = input.map_err(|err| Error::new(0, ErrorKind::Io(err.kind())))?;
+            SourceFile::parse(&input).map_err(|err| err.in_file(name))
+        };
+
+        let file = load(name)?;
+        Self::resolve(name, &file, &mut load)
+    }
+
+    fn resolve(
+        name: &str,
+        file: &SourceFile,
+        load: &mut dyn FnMut(&str) -> Result<SourceFile, Error>,
+    ) -> Result<Self, Error> {
+        let mut chain = alloc::vec![name.to_string()];
+        Self::from_keywords(name, &mut |category| {
+            let mut keywords = resolve(file, category, load, &mut chain)?;
+            if category == Category::LC_TIME && file.definition(category).is_some() {
+                fix_time(&mut keywords);
+            }
+            Ok(keywords)
+        })
+    }
+}
+
+/// The `t_fmt_ampm` of `POSIX`.
+const T_FMT_AMPM: &str = "%l:%M:%S %p";
+
+/// Apply the fixups of `generate-api` to the keywords of `LC_TIME`: add `t_fmt_ampm` if it is
+/// missing or empty, with the value of `POSIX` unless `am_pm` is empty, then inline in `d_t_fmt`
+/// and `t_fmt` the formats they refer to with `%x`, `%X` and `%r`.
+fn fix_time(keywords: &mut Vec<Keyword>) {
+    let string = |keywords: &[Keyword], name: &str| {
+        keywords
+            .iter()
+            .rev()
+            .find_map(|x| match x.values.as_slice() {
+                [Value::String(value)] if x.name == name => Some(value.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    };
+    let am_pm_empty = keywords
+        .iter()
+        .rev()
+        .find_map(|x| match x.values.as_slice() {
+            [Value::String(am), Value::String(pm)] if x.name == "am_pm" => {
+                Some(am.is_empty() && pm.is_empty())
+            }
+            _ => None,
+        })
+        .unwrap_or(false);
+
+    if keywords.iter().all(|x| x.name != "t_fmt_ampm") {
+        let value = if am_pm_empty { "" } else { T_FMT_AMPM };
+        keywords.push(Keyword {
+            name: "t_fmt_ampm".to_string(),
+            values: alloc::vec![Value::String(value.to_string())],
+            line: 0,
+        });
+    } else if !am_pm_empty {
+        for keyword in keywords.iter_mut().filter(|x| x.name == "t_fmt_ampm") {
+            if matches!(keyword.values.as_slice(), [Value::String(x)] if x.is_empty()) {
+                keyword.values = alloc::vec![Value::String(T_FMT_AMPM.to_string())];
+            }
+        }
+    }
+
+    let d_fmt = string(keywords, "d_fmt");
+    let t_fmt = string(keywords, "t_fmt");
+    let t_fmt_ampm = Some(string(keywords, "t_fmt_ampm"))
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| "%T".to_string());
+
+    for keyword in keywords
+        .iter_mut()
+        .filter(|x| x.name == "d_t_fmt" || x.name == "t_fmt")
+    {
+        if let Some(Value::String(value)) = keyword.values.first_mut() {
+            *value = value
+                .replace("%x", &d_fmt)
+                .replace("%X", &t_fmt)
+                .replace("%r", &t_fmt_ampm);
+        }
+    }
+}
+
+/// Conversion of the keywords of a category to the typed items, used by the generated code.
+#[doc(hidden)]
+pub mod __private {
+    use super::{Keyword, Value};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    fn values<'a>(keywords: &'a [Keyword], name: &str) -> Option<&'a [Value]> {
+        keywords
+            .iter()
+            .rev()
+            .find(|x| x.name == name && !x.values.is_empty())
+            .map(|x| x.values.as_slice())
+    }
+
+    pub fn string(keywords: &[Keyword], name: &str) -> Option<String> {
+        values(keywords, name).map(|x| x[0].to_text())
+    }
+
+    pub fn integer(keywords: &[Keyword], name: &str) -> Option<i64> {
+        values(keywords, name).and_then(|x| x[0].as_integer())
+    }
+
+    pub fn strings(keywords: &[Keyword], name: &str) -> Option<Vec<String>> {
+        values(keywords, name).map(|x| x.iter().map(Value::to_text).collect())
+    }
+
+    pub fn integers(keywords: &[Keyword], name: &str) -> Option<Vec<i64>> {
+        values(keywords, name).and_then(|x| x.iter().map(Value::as_integer).collect())
+    }
+
+    /// The values of every line of a keyword that can be repeated.
+    pub fn table(keywords: &[Keyword], name: &str) -> Option<Vec<Vec<String>>> {
+        let table = keywords
+            .iter()
+            .filter(|x| x.name == name && !x.values.is_empty())
+            .map(|x| x.values.iter().map(Value::to_text).collect())
+            .collect::<Vec<_>>();
+        Some(table).filter(|x| !x.is_empty())
+    }
+}
@@ -0,0 +1,15 @@
+/// Where the value of an item comes from.
+///
+/// Some items of `glibc` are changed to make them easier to use: see the `provenance` module of
+/// the categories that have such items, like `fr_BE::LC_TIME::provenance`. The upstream values
+/// are available in the `raw` module next to it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Provenance {
+    /// The value of `glibc`.
+    Upstream,
+    /// The value of `glibc` with the items it refers to inlined, like `%x` in `D_T_FMT`.
+    Inlined,
+    /// The item is missing or empty in `glibc` and its value has been made up.
+    Synthesized,
+}
@@ -0,0 +1,131 @@
+use crate::{
+    Category, DynAddressInfo, DynIdentificationInfo, DynLocaleInfo, DynMessagesInfo,
+    DynMonetaryInfo, DynNumericInfo, DynTelephoneInfo, DynTimeInfo, Locale, Resolved,
+};
+
+/// A source of locale data, looked up by locale name.
+///
+/// [`Builtin`] provides the data compiled in the crate. Other implementations can provide data
+/// loaded at runtime or fake locales for tests. A slice of `(name, data)` pairs is a provider and
+/// a pair of providers `(first, second)` looks up `first` before `second`. With the `alloc`
+/// feature, a slice of `LocaleData` provides each of them by its name:
+///
+/// ```
+/// use pure_rust_locales::{locales, Builtin, DynLocaleInfo, LocaleProvider};
+///
+/// let fake: &[(&str, &dyn DynLocaleInfo)] = &[("xx_XX", &locales::fr_BE)];
+/// let provider = (fake, Builtin);
+///
+/// assert_eq!(provider.time("xx_XX").unwrap().d_fmt(), "%d/%m/%y");
+/// assert_eq!(provider.time("en_US").unwrap().d_fmt(), "%m/%d/%Y");
+/// assert!(provider.locale("yy_YY").is_none());
+/// ```
+pub trait LocaleProvider {
+    /// The data of the locale named `name`, if the provider has it.
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo>;
+
+    /// `LC_TIME` of the locale named `name`.
+    fn time(&self, name: &str) -> Option<&dyn DynTimeInfo> {
+        self.locale(name).map(|x| x.time())
+    }
+
+    /// `LC_NUMERIC` of the locale named `name`.
+    fn numeric(&self, name: &str) -> Option<&dyn DynNumericInfo> {
+        self.locale(name).map(|x| x.numeric())
+    }
+
+    /// `LC_MONETARY` of the locale named `name`.
+    fn monetary(&self, name: &str) -> Option<&dyn DynMonetaryInfo> {
+        self.locale(name).map(|x| x.monetary())
+    }
+
+    /// `LC_MESSAGES` of the locale named `name`.
+    fn messages(&self, name: &str) -> Option<&dyn DynMessagesInfo> {
+        self.locale(name).map(|x| x.messages())
+    }
+
+    /// `LC_ADDRESS` of the locale named `name`.
+    fn address(&self, name: &str) -> Option<&dyn DynAddressInfo> {
+        self.locale(name).map(|x| x.address())
+    }
+
+    /// `LC_TELEPHONE` of the locale named `name`.
+    fn telephone(&self, name: &str) -> Option<&dyn DynTelephoneInfo> {
+        self.locale(name).map(|x| x.telephone())
+    }
+
+    /// `LC_IDENTIFICATION` of the locale named `name`.
+    fn identification(&self, name: &str) -> Option<&dyn DynIdentificationInfo> {
+        self.locale(name).map(|x| x.identification())
+    }
+
+    /// Look up an item of a category in the same way as
+    /// [`locale_resolve!`](crate::locale_resolve), taking the data from this provider: the item
+    /// of the locale, then of the locales the category is copied from, of the main territory of
+    /// the language and of `POSIX`. Locales the provider doesn't have are skipped.
+    ///
+    /// The name doesn't have to be the name of a [`Locale`]: the item of a locale that is not
+    /// compiled in is looked up in the locale itself, then in the main territory of its
+    /// language, if any, and in `POSIX`.
+    ///
+    /// ```
+    /// use pure_rust_locales::{Builtin, Category, LocaleProvider, Origin};
+    ///
+    /// let resolved = Builtin
+    ///     .resolve("de_AT", Category::LC_TIME, |x| x.time().date_fmt())
+    ///     .unwrap();
+    /// assert_eq!(resolved.locale, "de_DE");
+    /// assert_eq!(resolved.origin, Origin::MainTerritory);
+    /// ```
+    fn resolve<'a, 'n, T>(
+        &'a self,
+        name: &'n str,
+        category: Category,
+        item: impl Fn(&'a dyn DynLocaleInfo) -> Option<T>,
+    ) -> Option<Resolved<T, &'n str>>
+    where
+        Self: Sized,
+    {
+        crate::__resolve::resolve_in(self, name, category, item, |_| None)
+    }
+}
+
+/// The data compiled in the crate: the locales of [`Locale`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Builtin;
+
+impl LocaleProvider for Builtin {
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo> {
+        core::convert::TryFrom::<&str>::try_from(name)
+            .ok()
+            .map(Locale::info)
+    }
+}
+
+impl<P: LocaleProvider + ?Sized> LocaleProvider for &P {
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo> {
+        (**self).locale(name)
+    }
+}
+
+impl<'a> LocaleProvider for &'a [(&'a str, &'a dyn DynLocaleInfo)] {
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo> {
+        self.iter().find(|(x, _)| *x == name).map(|(_, data)| *data)
+    }
+}
+
+impl<A: LocaleProvider, B: LocaleProvider> LocaleProvider for (A, B) {
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo> {
+        self.0.locale(name).or_else(|| self.1.locale(name))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl LocaleProvider for [crate::LocaleData] {
+    fn locale(&self, name: &str) -> Option<&dyn DynLocaleInfo> {
+        self.iter()
+            .find(|x| x.name == name)
+            .map(|x| x as &dyn DynLocaleInfo)
+    }
+}
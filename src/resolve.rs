@@ -0,0 +1,292 @@
+use crate::{Category, Locale};
+
+/// A value found by [`locale_resolve!`](crate::locale_resolve) with where it comes from. The
+/// locale is a [`Locale`], or its name when the value is looked up in a
+/// [`LocaleProvider`](crate::LocaleProvider).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Resolved<T, L = Locale> {
+    pub value: T,
+    /// The locale the value was taken from.
+    pub locale: L,
+    pub origin: Origin,
+}
+
+/// Where a [`Resolved`] value comes from, in the order they are tried.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Origin {
+    /// The item of the locale.
+    Own,
+    /// The item without the `INT_` prefix, like `glibc` does for the `INT_*_CS_PRECEDES`,
+    /// `INT_*_SEP_BY_SPACE` and `INT_*_SIGN_POSN` items of `LC_MONETARY`. It is taken from the
+    /// first locale that has either item, which may be another locale than the one asked for.
+    Counterpart,
+    /// The item of a locale the category is copied from: see [`Locale::inheritance`].
+    Inherited,
+    /// The item of the main territory of the language: see [`Locale::main_territory`].
+    MainTerritory,
+    /// The item of `POSIX`.
+    Posix,
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use super::{Origin, Resolved};
+    use crate::{Category, DynLocaleInfo, Ints, Locale, LocaleProvider, Strs, Table};
+
+    /// Turns the value of an item into an `Option`, whether the item is optional or not.
+    pub trait Item {
+        type Value;
+
+        fn into_option(self) -> Option<Self::Value>;
+    }
+
+    impl<T> Item for Option<T> {
+        type Value = T;
+
+        fn into_option(self) -> Option<T> {
+            self
+        }
+    }
+
+    impl Item for &str {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    impl Item for i64 {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    impl<T> Item for &[T] {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    impl<'a> Item for Strs<'a> {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    impl<'a> Item for Ints<'a> {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    impl<'a> Item for Table<'a> {
+        type Value = Self;
+
+        fn into_option(self) -> Option<Self> {
+            Some(self)
+        }
+    }
+
+    pub fn resolve<T>(
+        locale: Locale,
+        category: Category,
+        item: impl Fn(Locale) -> Option<T>,
+        counterpart: impl Fn(Locale) -> Option<T>,
+    ) -> Option<Resolved<T>> {
+        super::candidates(locale, category).find_map(|(candidate, origin)| {
+            let (value, origin) = match (item(candidate), origin) {
+                (Some(value), origin) => (value, origin),
+                (None, _) => (counterpart(candidate)?, Origin::Counterpart),
+            };
+            Some(Resolved {
+                value,
+                locale: candidate,
+                origin,
+            })
+        })
+    }
+
+    /// Like [`resolve`] with the data of a provider, by name.
+    pub fn resolve_in<'a, 'n, P, T>(
+        provider: &'a P,
+        name: &'n str,
+        category: Category,
+        item: impl Fn(&'a dyn DynLocaleInfo) -> Option<T>,
+        counterpart: impl Fn(&'a dyn DynLocaleInfo) -> Option<T>,
+    ) -> Option<Resolved<T, &'n str>>
+    where
+        P: LocaleProvider + ?Sized,
+    {
+        super::candidates_by_name(name, category).find_map(|(candidate, origin)| {
+            let info = provider.locale(candidate)?;
+            let (value, origin) = match (item(info), origin) {
+                (Some(value), origin) => (value, origin),
+                (None, _) => (counterpart(info)?, Origin::Counterpart),
+            };
+            Some(Resolved {
+                value,
+                locale: candidate,
+                origin,
+            })
+        })
+    }
+}
+
+/// The locales to look an item up in: the locale, the locales it copies the category from, the
+/// main territory of its language and `POSIX`.
+pub(crate) fn candidates(
+    locale: Locale,
+    category: Category,
+) -> impl Iterator<Item = (Locale, Origin)> {
+    let inherited = locale
+        .inheritance(category)
+        .iter()
+        .filter_map(|source| source.locale())
+        .map(|x| (x, Origin::Inherited));
+    let main_territory = locale.main_territory().map(|x| (x, Origin::MainTerritory));
+
+    core::iter::once((locale, Origin::Own))
+        .chain(inherited)
+        .chain(main_territory)
+        .chain(core::iter::once((Locale::POSIX, Origin::Posix)))
+}
+
+/// The names of the locales to look an item up in for a locale that may not be compiled in:
+/// the [`candidates`] of the locale if it is, otherwise the name, the main territory of its
+/// language and `POSIX`.
+pub(crate) fn candidates_by_name(
+    name: &str,
+    category: Category,
+) -> impl Iterator<Item = (&str, Origin)> {
+    let locale = core::convert::TryFrom::<&str>::try_from(name).ok();
+    let builtin = locale
+        .into_iter()
+        .flat_map(move |x| candidates(x, category))
+        .map(|(x, origin)| (x.name(), origin));
+    let other = match locale {
+        Some(_) => None,
+        None => Some(
+            core::iter::once((name, Origin::Own))
+                .chain(
+                    crate::inheritance::main_territory_of(name).map(|x| (x, Origin::MainTerritory)),
+                )
+                .chain(core::iter::once((Locale::POSIX.name(), Origin::Posix))),
+        ),
+    };
+
+    builtin.chain(other.into_iter().flatten())
+}
+
+/// Look up an item like [`locale_match!`](crate::locale_match), filling it in when the locale
+/// doesn't define it.
+///
+/// The item is looked up in the locale, then in the locales the category is copied from, then
+/// in the main territory of the language and finally in `POSIX`. For the `INT_*` items of
+/// `LC_MONETARY` that `glibc` defaults to their counterpart without the prefix, the counterpart
+/// is used before moving to the next locale.
+///
+/// Returns `Option<Resolved<T>>`, `None` if even `POSIX` doesn't define the item.
+///
+/// With `in provider, name`, the items are looked up in the data of a
+/// [`LocaleProvider`](crate::LocaleProvider) instead, like with
+/// [`LocaleProvider::resolve`](crate::LocaleProvider::resolve), and the locales of the
+/// `Resolved` are names.
+///
+/// ```
+/// use pure_rust_locales::{locale_resolve, Builtin, Locale, Origin};
+///
+/// let resolved = locale_resolve!(Locale::POSIX => LC_MONETARY::INT_P_CS_PRECEDES).unwrap();
+/// assert_eq!(resolved.value, -1);
+/// assert_eq!(resolved.origin, Origin::Counterpart);
+///
+/// let resolved = locale_resolve!(Locale::POSIX => LC_ADDRESS::POSTAL_FMT);
+/// assert_eq!(resolved, None);
+///
+/// let resolved = locale_resolve!(in Builtin, "de_AT" => LC_TIME::DATE_FMT).unwrap();
+/// assert_eq!(resolved.locale, "de_DE");
+/// assert_eq!(resolved.origin, Origin::MainTerritory);
+/// ```
+#[macro_export]
+macro_rules! locale_resolve {
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_P_CS_PRECEDES) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_P_CS_PRECEDES, P_CS_PRECEDES)
+    };
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_P_SEP_BY_SPACE) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_P_SEP_BY_SPACE, P_SEP_BY_SPACE)
+    };
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_N_CS_PRECEDES) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_N_CS_PRECEDES, N_CS_PRECEDES)
+    };
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_N_SEP_BY_SPACE) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_N_SEP_BY_SPACE, N_SEP_BY_SPACE)
+    };
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_P_SIGN_POSN) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_P_SIGN_POSN, P_SIGN_POSN)
+    };
+    (in $provider:expr, $name:expr => LC_MONETARY::INT_N_SIGN_POSN) => {
+        $crate::locale_resolve!(@counterpart in $provider, $name => LC_MONETARY::INT_N_SIGN_POSN, N_SIGN_POSN)
+    };
+    (@counterpart in $provider:expr, $name:expr => $category:ident :: $item:ident, $counterpart:ident) => {
+        $crate::__resolve::resolve_in(
+            &$provider,
+            $name,
+            $crate::Category::$category,
+            |info| $crate::__resolve::Item::into_option($crate::__dyn::$category::$item(info)),
+            |info| $crate::__resolve::Item::into_option($crate::__dyn::$category::$counterpart(info)),
+        )
+    };
+    (in $provider:expr, $name:expr => $category:ident :: $item:ident) => {
+        $crate::__resolve::resolve_in(
+            &$provider,
+            $name,
+            $crate::Category::$category,
+            |info| $crate::__resolve::Item::into_option($crate::__dyn::$category::$item(info)),
+            |_| None,
+        )
+    };
+    ($locale:expr => LC_MONETARY::INT_P_CS_PRECEDES) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_P_CS_PRECEDES, P_CS_PRECEDES)
+    };
+    ($locale:expr => LC_MONETARY::INT_P_SEP_BY_SPACE) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_P_SEP_BY_SPACE, P_SEP_BY_SPACE)
+    };
+    ($locale:expr => LC_MONETARY::INT_N_CS_PRECEDES) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_N_CS_PRECEDES, N_CS_PRECEDES)
+    };
+    ($locale:expr => LC_MONETARY::INT_N_SEP_BY_SPACE) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_N_SEP_BY_SPACE, N_SEP_BY_SPACE)
+    };
+    ($locale:expr => LC_MONETARY::INT_P_SIGN_POSN) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_P_SIGN_POSN, P_SIGN_POSN)
+    };
+    ($locale:expr => LC_MONETARY::INT_N_SIGN_POSN) => {
+        $crate::locale_resolve!(@counterpart $locale => LC_MONETARY::INT_N_SIGN_POSN, N_SIGN_POSN)
+    };
+    (@counterpart $locale:expr => $category:ident :: $item:ident, $counterpart:ident) => {
+        $crate::__resolve::resolve(
+            $locale,
+            $crate::Category::$category,
+            |locale| $crate::__resolve::Item::into_option($crate::locale_match!(locale => $category::$item)),
+            |locale| $crate::__resolve::Item::into_option($crate::locale_match!(locale => $category::$counterpart)),
+        )
+    };
+    ($locale:expr => $category:ident :: $item:ident) => {
+        $crate::__resolve::resolve(
+            $locale,
+            $crate::Category::$category,
+            |locale| $crate::__resolve::Item::into_option($crate::locale_match!(locale => $category::$item)),
+            |_| None,
+        )
+    };
+}
@@ -0,0 +1,104 @@
+use pure_rust_locales::blob::{Blob, BlobError, Value};
+use pure_rust_locales::{Category, Locale};
+use std::convert::TryFrom;
+
+#[test]
+fn same_values_as_the_constants() {
+    let blob = Blob::builtin();
+    assert!(blob.len() > 300);
+
+    for locale in blob.locales() {
+        let name = locale.name().unwrap();
+        // The blob has all the locales, whatever the features.
+        let info = match Locale::try_from(name) {
+            Ok(locale) => locale.info(),
+            Err(_) => continue,
+        };
+        let time = locale.category(Category::LC_TIME).unwrap();
+        let monetary = locale.category(Category::LC_MONETARY).unwrap();
+        let address = locale.category(Category::LC_ADDRESS).unwrap();
+        let identification = locale.category(Category::LC_IDENTIFICATION).unwrap();
+
+        assert_eq!(time.str("D_FMT"), Some(info.time().d_fmt()), "{}", name);
+        assert_eq!(time.strs("DAY"), Some(info.time().day()), "{}", name);
+        assert_eq!(
+            time.int("FIRST_WEEKDAY"),
+            info.time().first_weekday(),
+            "{}",
+            name
+        );
+        assert_eq!(time.strs("ERA"), info.time().era(), "{}", name,);
+        assert_eq!(time.ints("WEEK"), info.time().week(), "{}", name,);
+        assert_eq!(
+            monetary.ints("MON_GROUPING"),
+            Some(info.monetary().mon_grouping()),
+            "{}",
+            name,
+        );
+        assert_eq!(
+            address.int("COUNTRY_NUM"),
+            info.address().country_num(),
+            "{}",
+            name
+        );
+        assert_eq!(
+            identification.table("CATEGORY"),
+            info.identification().category(),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn items() {
+    let locale = Blob::builtin().locale("en_US").unwrap();
+    let messages = locale.category(Category::LC_MESSAGES).unwrap();
+
+    assert_eq!(
+        messages.items().collect::<Vec<_>>(),
+        [
+            ("NOEXPR", Value::Str("^[-0nN]")),
+            ("NOSTR", Value::Str("no")),
+            ("YESEXPR", Value::Str("^[+1yY]")),
+            ("YESSTR", Value::Str("yes")),
+        ],
+    );
+    assert!(locale.category(Category::LC_CTYPE).is_none());
+    assert!(Blob::builtin().locale("xx_XX").is_none());
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(Blob::new(b"").unwrap_err(), BlobError::BadMagic);
+    assert_eq!(
+        Blob::new(b"PRLB\x02\0\0\0\0\0\0\0").unwrap_err(),
+        BlobError::UnsupportedVersion(2),
+    );
+    assert_eq!(
+        Blob::new(b"PRLB\x01\0\0\0\x01\0\0\0").unwrap_err(),
+        BlobError::Truncated,
+    );
+
+    // The values past the end of a blob are missing.
+    let blob = Blob::new(b"PRLB\x01\0\0\0\x02\0\0\0\x01\0\0\0\x01\0\0\0").unwrap();
+    assert_eq!(blob.len(), 1);
+    assert!(blob.locales().next().unwrap().name().is_none());
+    assert!(blob.locale("en_US").is_none());
+
+    // A blob of garbage: the counts are bounded by its words and the indexes are checked.
+    let mut garbage = b"PRLB\x01\0\0\0\x40\0\0\0".to_vec();
+    garbage.extend_from_slice(&[0xff; 4 * 0x40 + 16]);
+    let blob = Blob::new(&garbage).unwrap();
+    assert_eq!(blob.len(), u32::MAX as usize);
+    assert!(blob.locale("en_US").is_none());
+    for locale in blob.locales().take(3) {
+        assert!(locale.name().is_none());
+        for category in Category::ALL {
+            if let Some(category) = locale.category(category) {
+                assert!(category.get("D_FMT").is_none());
+                assert_eq!(category.items().count(), 0);
+            }
+        }
+    }
+}
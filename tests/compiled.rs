@@ -0,0 +1,361 @@
+#![cfg(feature = "std")]
+
+use pure_rust_locales::compiled::{Archive, Error, ErrorKind};
+use pure_rust_locales::owned::{
+    AddressData, IdentificationData, MessagesData, MonetaryData, NumericData, TelephoneData,
+    TimeData,
+};
+use pure_rust_locales::{Locale, LocaleData};
+use std::convert::{TryFrom, TryInto};
+use std::path::PathBuf;
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/compiled")
+}
+
+/// The data of the crate as it is compiled: the items the locale doesn't define are filled in
+/// with the defaults of `localedef` and the items `localedef` doesn't write are removed.
+fn builtin(locale: Locale) -> LocaleData {
+    let mut data = LocaleData::from(locale);
+    let time = &mut data.time;
+    let week = time.week.get_or_insert_with(Vec::new);
+    for (i, default) in [7, 19971130, 7].iter().enumerate() {
+        if week.len() == i {
+            week.push(*default);
+        }
+    }
+    time.first_weekday.get_or_insert(1);
+    time.first_workday.get_or_insert(2);
+    time.cal_direction.get_or_insert(1);
+    time.date_fmt
+        .get_or_insert_with(|| "%a %b %e %H:%M:%S %Z %Y".to_string());
+    // The international items default to the items without the `INT_` prefix.
+    let monetary = &mut data.monetary;
+    for (item, default) in [
+        (&mut monetary.int_p_cs_precedes, monetary.p_cs_precedes),
+        (&mut monetary.int_p_sep_by_space, monetary.p_sep_by_space),
+        (&mut monetary.int_n_cs_precedes, monetary.n_cs_precedes),
+        (&mut monetary.int_n_sep_by_space, monetary.n_sep_by_space),
+        (&mut monetary.int_p_sign_posn, monetary.p_sign_posn),
+        (&mut monetary.int_n_sign_posn, monetary.n_sign_posn),
+    ] {
+        item.get_or_insert(default);
+    }
+    // A grouping of 0 stops the grouping, in the same way as `-1`, and is written as such.
+    for grouping in [&mut data.numeric.grouping, &mut data.monetary.mon_grouping] {
+        if grouping.first() == Some(&0) {
+            *grouping = vec![-1];
+        }
+    }
+    // Empty strings are not defined.
+    let (address, identification) = (&mut data.address, &mut data.identification);
+    let (telephone, time) = (&mut data.telephone, &mut data.time);
+    for item in [
+        &mut address.country_ab3,
+        &mut address.country_abtwo,
+        &mut address.country_car,
+        &mut address.country_isbn,
+        &mut address.country_name,
+        &mut address.country_post,
+        &mut address.lang_ab,
+        &mut address.lang_lib,
+        &mut address.lang_name,
+        &mut address.lang_term,
+        &mut address.postal_fmt,
+        &mut identification.abbreviation,
+        &mut identification.address,
+        &mut identification.contact,
+        &mut identification.date,
+        &mut identification.email,
+        &mut identification.fax,
+        &mut identification.language,
+        &mut identification.revision,
+        &mut identification.source,
+        &mut identification.tel,
+        &mut identification.territory,
+        &mut identification.title,
+        &mut data.messages.nostr,
+        &mut data.messages.yesstr,
+        &mut telephone.int_prefix,
+        &mut telephone.int_select,
+        &mut telephone.tel_dom_fmt,
+        &mut telephone.tel_int_fmt,
+        &mut time.era_d_fmt,
+        &mut time.era_d_t_fmt,
+        &mut time.era_t_fmt,
+    ] {
+        if item.as_deref() == Some("") {
+            *item = None;
+        }
+    }
+    // The versions of the categories are in the order of `glibc` rather than of the file.
+    if let Some(rows) = data.identification.category.as_mut() {
+        rows.sort_by_key(|row| compiled_order(&row[1]));
+    }
+    data
+}
+
+/// The data of the crate as compiled by `localedef`, which has the values of `glibc` for the
+/// items the crate fixes up: `localedef!(fr_BE, "fr_BE.UTF-8")`.
+#[cfg(feature = "all-locales")]
+macro_rules! localedef {
+    ($locale:ident, $name:expr) => {{
+        use pure_rust_locales::$locale::LC_TIME::raw;
+
+        let mut data = builtin(Locale::$locale);
+        data.name = $name.to_string();
+        data.time.d_t_fmt = raw::D_T_FMT.to_string();
+        data.time.t_fmt = raw::T_FMT.to_string();
+        data.time.t_fmt_ampm = raw::T_FMT_AMPM.unwrap_or_default().to_string();
+        data
+    }};
+}
+
+fn compiled_order(category: &str) -> usize {
+    pure_rust_locales::Category::ALL
+        .iter()
+        .position(|x| x.name() == category)
+        .unwrap()
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn load_compiled() {
+    let data = LocaleData::load_compiled(fixtures().join("fr_BE.UTF-8")).unwrap();
+
+    assert_eq!(data, localedef!(fr_BE, "fr_BE.UTF-8"));
+    assert_eq!(data.time.d_t_fmt, "%a %d %b %Y %T %Z");
+    // The defaults of `localedef` for the items `fr_BE` doesn't define.
+    assert_eq!(data.time.week, Some(vec![7, 19971130, 4]));
+    assert_eq!(data.time.first_weekday, Some(2));
+    assert_eq!(data.time.first_workday, Some(2));
+    assert_eq!(data.time.cal_direction, Some(1));
+    assert_eq!(
+        data.time.date_fmt.as_deref(),
+        Some("%a %b %e %H:%M:%S %Z %Y")
+    );
+    assert_eq!(data.monetary.int_p_sep_by_space, Some(1));
+    assert_eq!(data.monetary.int_p_sign_posn, Some(1));
+    assert_eq!(data.time.alt_mon, None);
+    assert_eq!(data.numeric.grouping, [3, 3]);
+    assert_eq!(data.monetary.currency_symbol, "€");
+    assert_eq!(data.monetary.int_p_cs_precedes, Some(0));
+    assert_eq!(data.address.country_num, Some(56));
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn era_and_alt_digits() {
+    let data = LocaleData::load_compiled(fixtures().join("ja_JP.UTF-8")).unwrap();
+    assert_eq!(data, localedef!(ja_JP, "ja_JP.UTF-8"));
+    assert_eq!(data.time.first_weekday, Some(1));
+    assert_eq!(data.monetary.int_p_cs_precedes, Some(1));
+    assert_eq!(data.monetary.int_p_sep_by_space, Some(2));
+    assert_eq!(data.monetary.int_n_sign_posn, Some(4));
+    assert_eq!(data.time.era.as_ref().map(Vec::len), Some(11));
+    assert_eq!(data.time.alt_digits.as_ref().map(Vec::len), Some(100));
+}
+
+#[test]
+fn archive() {
+    let archive = Archive::open(fixtures().join("locale-archive")).unwrap();
+
+    assert_eq!(
+        archive.names().collect::<Vec<_>>(),
+        ["de_DE.utf8", "fr_BE.utf8"]
+    );
+    let mut expected = LocaleData::load_compiled(fixtures().join("fr_BE.UTF-8")).unwrap();
+    expected.name = "fr_BE.utf8".to_string();
+    for name in ["fr_BE.utf8", "fr_BE.UTF-8", "fr_BE.utf-8"] {
+        assert_eq!(archive.locale(name).unwrap(), expected, "{}", name);
+    }
+    #[cfg(feature = "all-locales")]
+    {
+        let data = archive.locale("de_DE.UTF-8").unwrap();
+        assert_eq!(data, localedef!(de_DE, "de_DE.utf8"));
+        assert_eq!(data.time.week, Some(vec![7, 19971130, 4]));
+        assert_eq!(data.time.first_workday, Some(2));
+        assert_eq!(data.time.cal_direction, Some(1));
+        let monetary = &data.monetary;
+        assert_eq!(
+            [
+                monetary.int_p_cs_precedes,
+                monetary.int_p_sep_by_space,
+                monetary.int_n_cs_precedes,
+                monetary.int_n_sep_by_space,
+                monetary.int_p_sign_posn,
+                monetary.int_n_sign_posn,
+            ],
+            [Some(0), Some(1), Some(0), Some(1), Some(1), Some(1)],
+        );
+    }
+    for name in ["ja_JP.UTF-8", "fr_BE", "fr_BE.utf8@euro"] {
+        assert_eq!(
+            archive.locale(name).unwrap_err().kind,
+            ErrorKind::NotFound(name.to_string()),
+        );
+    }
+}
+
+#[test]
+fn archive_hash_table() {
+    let data = std::fs::read(fixtures().join("locale-archive")).unwrap();
+    let word = |offset: usize| u32::from_ne_bytes(data[offset..offset + 4].try_into().unwrap());
+
+    // The header: the magic number, then the offset, the number of used entries and the size of
+    // the hash table of the names.
+    assert_eq!(word(0), 0xde02_0109);
+    let (namehash_offset, used, size) = (word(8) as usize, word(12), word(16) as usize);
+    assert_eq!(used, 2);
+    let entries = (0..size)
+        .map(|i| namehash_offset + 12 * i)
+        .filter(|&entry| word(entry + 4) != 0)
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 2);
+
+    // The names are found by their hash: with another hash value, the locale is still listed but
+    // can't be found anymore.
+    for &entry in &entries {
+        let mut corrupted = data.clone();
+        corrupted[entry..entry + 4].copy_from_slice(&(word(entry) ^ 1).to_ne_bytes());
+        let archive = Archive::from_bytes(corrupted).unwrap();
+        let name_offset = word(entry + 4) as usize;
+        let len = data[name_offset..].iter().position(|&x| x == 0).unwrap();
+        let name = std::str::from_utf8(&data[name_offset..name_offset + len]).unwrap();
+
+        assert_eq!(archive.names().count(), 2);
+        assert_eq!(
+            archive.locale(name).unwrap_err().kind,
+            ErrorKind::NotFound(name.to_string()),
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let time = std::fs::read(fixtures().join("fr_BE.UTF-8/LC_TIME")).unwrap();
+
+    // A file of another category.
+    assert_eq!(
+        TimeData::from_compiled(&std::fs::read(fixtures().join("fr_BE.UTF-8/LC_NUMERIC")).unwrap()),
+        Err(Error::from(ErrorKind::BadMagic {
+            expected: 0x2003_1117,
+            found: 0x2003_1114,
+        })),
+    );
+    assert_eq!(
+        NumericData::from_compiled(&time).unwrap_err().kind,
+        ErrorKind::BadMagic {
+            expected: 0x2003_1114,
+            found: 0x2003_1117,
+        },
+    );
+
+    // A file of an older version of `glibc`, before `ALTMON_1`.
+    let mut old = time.clone();
+    old[4..8].copy_from_slice(&111u32.to_ne_bytes());
+    assert_eq!(
+        TimeData::from_compiled(&old).unwrap_err().kind,
+        ErrorKind::MissingItems {
+            expected: 159,
+            found: 111,
+        },
+    );
+
+    assert_eq!(
+        TimeData::from_compiled(&time[..1000]).unwrap_err().kind,
+        ErrorKind::Truncated,
+    );
+    assert_eq!(
+        Archive::from_bytes(time).unwrap_err().kind,
+        ErrorKind::BadMagic {
+            expected: 0xde02_0109,
+            found: 0x2003_1117,
+        },
+    );
+
+    let error = LocaleData::load_compiled(fixtures().join("xx_XX")).unwrap_err();
+    assert_eq!(error.file.as_deref(), Some("LC_ADDRESS"));
+    assert_eq!(error.kind, ErrorKind::Io(std::io::ErrorKind::NotFound));
+    assert_eq!(
+        error.to_string(),
+        "LC_ADDRESS: could not access the file (NotFound)"
+    );
+}
+
+/// The files of the categories of a compiled locale.
+#[cfg(feature = "all-locales")]
+fn files(data: &LocaleData) -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("LC_ADDRESS", data.address.to_compiled()),
+        ("LC_IDENTIFICATION", data.identification.to_compiled()),
+        ("LC_MESSAGES/SYS_LC_MESSAGES", data.messages.to_compiled()),
+        ("LC_MONETARY", data.monetary.to_compiled()),
+        ("LC_NUMERIC", data.numeric.to_compiled()),
+        ("LC_TELEPHONE", data.telephone.to_compiled()),
+        ("LC_TIME", data.time.to_compiled().unwrap()),
+    ]
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn write_like_localedef() {
+    for (data, dir) in [
+        (localedef!(fr_BE, "fr_BE.UTF-8"), "fr_BE.UTF-8"),
+        (localedef!(ja_JP, "ja_JP.UTF-8"), "ja_JP.UTF-8"),
+    ] {
+        for (file, data) in files(&data) {
+            let expected = std::fs::read(fixtures().join(dir).join(file)).unwrap();
+            assert!(data == expected, "{}/{} differs", dir, file);
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    let localedata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("localedata/locales");
+
+    for entry in std::fs::read_dir(localedata).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        let locale = match Locale::try_from(name.as_str()) {
+            Ok(locale) => locale,
+            Err(_) => continue,
+        };
+        let data = LocaleData::from(locale);
+        let compiled = LocaleData {
+            name: data.name.clone(),
+            address: AddressData::from_compiled(&data.address.to_compiled()).unwrap(),
+            identification: IdentificationData::from_compiled(&data.identification.to_compiled())
+                .unwrap(),
+            messages: MessagesData::from_compiled(&data.messages.to_compiled()).unwrap(),
+            monetary: MonetaryData::from_compiled(&data.monetary.to_compiled()).unwrap(),
+            numeric: NumericData::from_compiled(&data.numeric.to_compiled()).unwrap(),
+            telephone: TelephoneData::from_compiled(&data.telephone.to_compiled()).unwrap(),
+            time: TimeData::from_compiled(&data.time.to_compiled().unwrap()).unwrap(),
+        };
+
+        assert_eq!(compiled, builtin(locale), "{}", name);
+    }
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn write_compiled() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    let data = LocaleData::from(Locale::de_DE);
+
+    data.write_compiled(dir.join("de_DE.utf8")).unwrap();
+    let compiled = LocaleData::load_compiled(dir.join("de_DE.utf8")).unwrap();
+
+    let mut expected = builtin(Locale::de_DE);
+    expected.name = "de_DE.utf8".to_string();
+    assert_eq!(compiled, expected);
+
+    let mut time = data.time;
+    time.era = Some(vec!["+:1:2000/01/01".to_string()]);
+    assert_eq!(
+        time.to_compiled().unwrap_err().kind,
+        ErrorKind::InvalidEra("+:1:2000/01/01".to_string()),
+    );
+}
@@ -0,0 +1,25 @@
+use std::convert::TryFrom;
+
+use pure_rust_locales::Locale;
+
+#[test]
+fn posix_is_always_available() {
+    assert_eq!(Locale::try_from("POSIX").unwrap(), Locale::POSIX);
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn all_locales() {
+    assert_eq!(Locale::try_from("de_DE").unwrap(), Locale::de_DE);
+    assert_eq!(Locale::try_from("fr_BE@euro").unwrap(), Locale::fr_BE_euro);
+}
+
+#[cfg(all(feature = "lang-fr", not(feature = "all-locales")))]
+#[test]
+fn reduced_set() {
+    use pure_rust_locales::locale_match;
+
+    let locale = Locale::try_from("fr_BE").unwrap();
+    assert_eq!(locale_match!(locale => LC_TIME::D_FMT), "%d/%m/%y");
+    assert!(Locale::try_from("de_DE").is_err());
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{Category, Locale, Source};
+
+#[test]
+fn source_of() {
+    assert_eq!(
+        Locale::de_DE_euro.source_of(Category::LC_TIME),
+        Locale::de_DE
+    );
+    assert_eq!(Locale::de_DE.source_of(Category::LC_TIME), Locale::de_DE);
+    assert_eq!(Locale::POSIX.source_of(Category::LC_TIME), Locale::POSIX);
+    // copied from a file that is not a locale
+    assert_eq!(Locale::de_DE.source_of(Category::LC_CTYPE), Locale::de_DE);
+}
+
+#[test]
+fn inheritance() {
+    assert_eq!(
+        Locale::de_DE_euro.inheritance(Category::LC_TIME),
+        &[Source::Locale("de_DE")],
+    );
+    assert_eq!(
+        Locale::de_DE.inheritance(Category::LC_CTYPE),
+        &[Source::File("i18n"), Source::File("i18n_ctype")],
+    );
+    assert_eq!(Locale::de_DE.inheritance(Category::LC_TIME), &[]);
+    assert_eq!(Source::Locale("de_DE").locale(), Some(Locale::de_DE));
+    assert_eq!(Source::File("i18n").locale(), None);
+}
+
+#[test]
+fn shared_with() {
+    let shared = Locale::de_DE
+        .shared_with(Category::LC_TIME)
+        .collect::<Vec<_>>();
+    assert!(shared.contains(&Locale::de_DE));
+    assert!(shared.contains(&Locale::de_DE_euro));
+    assert!(!shared.contains(&Locale::de_AT));
+
+    assert_eq!(
+        Locale::de_DE_euro
+            .shared_with(Category::LC_TIME)
+            .collect::<Vec<_>>(),
+        shared,
+    );
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{locales, DynLocaleInfo, Locale, LocaleInfo, NumericInfo, TimeInfo};
+
+fn date_time<L: LocaleInfo>() -> (&'static str, &'static str) {
+    (L::D_T_FMT, L::DECIMAL_POINT)
+}
+
+#[test]
+fn static_dispatch() {
+    assert_eq!(
+        date_time::<locales::fr_BE>(),
+        (
+            pure_rust_locales::fr_BE::LC_TIME::D_T_FMT,
+            pure_rust_locales::fr_BE::LC_NUMERIC::DECIMAL_POINT,
+        ),
+    );
+    assert_eq!(<locales::en_US as LocaleInfo>::LOCALE, Locale::en_US);
+    assert_eq!(
+        <locales::en_US as NumericInfo>::GROUPING,
+        pure_rust_locales::en_US::LC_NUMERIC::GROUPING,
+    );
+    assert_eq!(<locales::POSIX as TimeInfo>::D_FMT, "%m/%d/%y");
+}
+
+#[test]
+fn dynamic_dispatch() {
+    let info: &dyn DynLocaleInfo = Locale::fr_BE.info();
+    assert_eq!(info.name(), "fr_BE");
+    assert_eq!(
+        info.time().d_fmt(),
+        pure_rust_locales::fr_BE::LC_TIME::D_FMT
+    );
+    assert_eq!(
+        info.monetary().int_curr_symbol(),
+        pure_rust_locales::fr_BE::LC_MONETARY::INT_CURR_SYMBOL,
+    );
+    assert_eq!(
+        info.identification().language(),
+        pure_rust_locales::fr_BE::LC_IDENTIFICATION::LANGUAGE,
+    );
+}
+
+#[test]
+fn same_as_markers() {
+    for locale in [Locale::POSIX, Locale::en_US, Locale::fr_BE] {
+        assert_eq!(locale.info().name(), locale.name());
+    }
+    assert_eq!(
+        Locale::en_US.info().time().day(),
+        *<locales::en_US as TimeInfo>::DAY,
+    );
+}
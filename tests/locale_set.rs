@@ -0,0 +1,68 @@
+#![cfg(feature = "all-locales")]
+
+use std::convert::TryInto;
+
+use pure_rust_locales::{locale_set_match, Category, Locale, LocaleSet};
+
+const COMPOSITE: &str = "LC_CTYPE=en_US;LC_NUMERIC=en_US;LC_TIME=en_GB;LC_COLLATE=en_US;\
+LC_MONETARY=en_US;LC_MESSAGES=en_US;LC_PAPER=en_US;LC_NAME=en_US;LC_ADDRESS=en_US;\
+LC_TELEPHONE=en_US;LC_MEASUREMENT=en_US;LC_IDENTIFICATION=en_US";
+
+#[test]
+fn composite_round_trip() {
+    let set: LocaleSet = COMPOSITE.try_into().unwrap();
+    assert_eq!(set.get(Category::LC_TIME), Locale::en_GB);
+    assert_eq!(set.get(Category::LC_NUMERIC), Locale::en_US);
+    assert_eq!(set.as_uniform(), None);
+    assert_eq!(set.to_string(), COMPOSITE);
+}
+
+#[test]
+fn uniform() {
+    let set: LocaleSet = "fr_BE".try_into().unwrap();
+    assert_eq!(set, LocaleSet::uniform(Locale::fr_BE));
+    assert_eq!(set.to_string(), "fr_BE");
+
+    let set: LocaleSet = "C.UTF-8".try_into().unwrap();
+    assert_eq!(set.as_uniform(), Some(Locale::POSIX));
+
+    let set: LocaleSet = "de_DE.UTF-8@euro".try_into().unwrap();
+    assert_eq!(set.as_uniform(), Some(Locale::de_DE_euro));
+}
+
+#[test]
+fn invalid() {
+    assert!("LC_TIME".parse::<LocaleSet>().is_err());
+    assert!("LC_FOO=fr_BE".parse::<LocaleSet>().is_err());
+    assert!("LC_TIME=xx_XX".parse::<LocaleSet>().is_err());
+    assert!("LC_TIME=fr_BE;LC_TIME=en_US".parse::<LocaleSet>().is_err());
+}
+
+#[test]
+fn from_vars() {
+    let vars = |name: &str| match name {
+        "LANG" => Some("en_US.UTF-8"),
+        "LC_TIME" => Some("en_GB.UTF-8"),
+        "LC_MONETARY" => Some(""),
+        _ => None,
+    };
+    let set = LocaleSet::from_vars(vars).unwrap();
+    assert_eq!(set.get(Category::LC_TIME), Locale::en_GB);
+    assert_eq!(set.get(Category::LC_MONETARY), Locale::en_US);
+
+    let set = LocaleSet::from_vars(|name| match name {
+        "LC_ALL" => Some("fr_BE"),
+        "LC_TIME" => Some("en_GB"),
+        _ => None,
+    })
+    .unwrap();
+    assert_eq!(set.as_uniform(), Some(Locale::fr_BE));
+}
+
+#[test]
+fn locale_match_per_category() {
+    let set = LocaleSet::uniform(Locale::en_US).with(Category::LC_TIME, Locale::fr_BE);
+
+    assert_eq!(locale_set_match!(set => LC_TIME::D_FMT), "%d/%m/%y");
+    assert_eq!(locale_set_match!(set => LC_NUMERIC::DECIMAL_POINT), ".");
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "std")]
+
+use pure_rust_locales::localedef::{Error, ErrorKind, SourceFile, Value};
+use pure_rust_locales::{Category, Locale, LocaleData};
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+fn localedata() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("localedata/locales")
+}
+
+#[test]
+fn parse_source_file() {
+    let input = "comment_char %\nescape_char /\n% comment\nLC_TIME\nabday \"Sun\";/\n      \"<U004D>on\"\nweek 7;19971130;1 % comment\nEND LC_TIME\n";
+    let file = SourceFile::parse(input).unwrap();
+    let time = file.definition(Category::LC_TIME).unwrap();
+
+    assert_eq!(time.keywords.len(), 2);
+    assert_eq!(time.keywords[0].name, "abday");
+    assert_eq!(
+        time.keywords[0].values,
+        [
+            Value::String("Sun".to_string()),
+            Value::String("Mon".to_string())
+        ],
+    );
+    assert_eq!(time.keywords[1].line, 7);
+    assert_eq!(
+        time.keywords[1].values,
+        [
+            Value::Integer(7),
+            Value::Integer(19971130),
+            Value::Integer(1)
+        ],
+    );
+}
+
+#[test]
+fn carriage_returns() {
+    let input = "LC_TIME\r\nd_fmt \"%d\"\rt_fmt \"%T\" # comment\rabday \"Sun\";\\\r\"Mon\"\r\nEND LC_TIME\r";
+    let file = SourceFile::parse(input).unwrap();
+    let time = file.definition(Category::LC_TIME).unwrap();
+
+    assert_eq!(time.keywords.len(), 3);
+    assert_eq!(time.keywords[0].values, [Value::String("%d".to_string())]);
+    assert_eq!(time.keywords[1].name, "t_fmt");
+    assert_eq!(time.keywords[1].values, [Value::String("%T".to_string())]);
+    assert_eq!(time.keywords[1].line, 3);
+    assert_eq!(
+        time.keywords[2].values,
+        [
+            Value::String("Sun".to_string()),
+            Value::String("Mon".to_string())
+        ],
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |input: &str| SourceFile::parse(input).unwrap_err();
+
+    assert_eq!(
+        error("LC_TIME\nd_fmt \"%d\nEND LC_TIME\n"),
+        Error {
+            file: None,
+            line: 2,
+            kind: ErrorKind::UnterminatedString,
+        },
+    );
+    assert_eq!(
+        error("LC_TIME\nEND LC_NUMERIC\n").kind,
+        ErrorKind::MismatchedEnd {
+            expected: "LC_TIME".to_string(),
+            found: "LC_NUMERIC".to_string(),
+        },
+    );
+    assert_eq!(
+        error("LC_TIME\n").kind,
+        ErrorKind::MissingEnd("LC_TIME".to_string())
+    );
+    assert_eq!(
+        error("LC_FOO\nEND LC_FOO\n").kind,
+        ErrorKind::UnknownCategory("LC_FOO".to_string())
+    );
+    assert_eq!(
+        error("LC_TIME\nd_fmt \"<U12G4>\"\nEND LC_TIME\n").kind,
+        ErrorKind::BadUnicodeEscape("<U12G4>".to_string())
+    );
+}
+
+#[test]
+fn all_localedata() {
+    for entry in std::fs::read_dir(localedata()).unwrap() {
+        let path = entry.unwrap().path();
+        let input = std::fs::read_to_string(&path).unwrap();
+        if let Err(err) = SourceFile::parse(&input) {
+            panic!("{}: {}", path.display(), err);
+        }
+    }
+}
+
+#[test]
+fn load_matches_builtin() {
+    for entry in std::fs::read_dir(localedata()).unwrap() {
+        let name = entry.unwrap().file_name().into_string().unwrap();
+        let locale = match Locale::try_from(name.as_str()) {
+            Ok(locale) => locale,
+            Err(_) => continue,
+        };
+        let loaded = LocaleData::load(&name, &[localedata()]).unwrap();
+        assert_eq!(loaded, LocaleData::from(locale), "{}", name);
+    }
+}
+
+#[cfg(any(feature = "all-locales", feature = "lang-fr"))]
+#[test]
+fn copy_and_overrides() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(
+        dir.join("fr_XX"),
+        "LC_TIME\ncopy \"fr_BE\"\nd_fmt \"%Y-%m-%d\"\nEND LC_TIME\n\
+         LC_NUMERIC\ncopy \"fr_FR\"\nEND LC_NUMERIC\n",
+    )
+    .unwrap();
+
+    let data = LocaleData::load("fr_XX", &[dir.to_path_buf(), localedata()]).unwrap();
+    let fr_be = LocaleData::from(Locale::fr_BE);
+    assert_eq!(data.name, "fr_XX");
+    assert_eq!(data.time.d_fmt, "%Y-%m-%d");
+    assert_eq!(data.time.day, fr_be.time.day);
+    assert_eq!(data.numeric, LocaleData::from(Locale::fr_FR).numeric);
+    assert_eq!(
+        data.messages.yesexpr,
+        LocaleData::from(Locale::POSIX).messages.yesexpr
+    );
+
+    std::fs::write(dir.join("xx_XX"), "LC_TIME\ncopy \"yy_YY\"\nEND LC_TIME\n").unwrap();
+    let err = LocaleData::load("xx_XX", &[&dir]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound("yy_YY".to_string()));
+    assert_eq!(err.line, 2);
+
+    std::fs::write(dir.join("yy_YY"), "LC_TIME\ncopy \"xx_XX\"\nEND LC_TIME\n").unwrap();
+    let err = LocaleData::load("xx_XX", &[&dir]).unwrap_err();
+    assert_eq!(err.kind, ErrorKind::CircularCopy("LC_TIME".to_string()));
+    assert_eq!(err.to_string(), "yy_YY:2: circular copy of `LC_TIME`");
+}
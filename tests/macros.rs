@@ -1,8 +1,8 @@
-use std::convert::TryInto;
-
+#[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
 #[test]
 fn locale_match() {
     use pure_rust_locales::locale_match;
+    use std::convert::TryInto;
 
     let locale = "fr_BE".try_into().unwrap();
 
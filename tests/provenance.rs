@@ -0,0 +1,34 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{ca_ES, en_US, fr_BE, locale_match, Locale, Provenance, POSIX};
+
+#[test]
+fn raw_values() {
+    assert_eq!(en_US::LC_TIME::T_FMT, "%I:%M:%S %p");
+    assert_eq!(en_US::LC_TIME::raw::T_FMT, "%r");
+    assert_eq!(en_US::LC_TIME::raw::D_FMT, en_US::LC_TIME::D_FMT);
+
+    assert_eq!(ca_ES::LC_TIME::T_FMT_AMPM, "%l:%M:%S %p");
+    assert_eq!(ca_ES::LC_TIME::raw::T_FMT_AMPM, Some(""));
+    assert_eq!(fr_BE::LC_TIME::raw::T_FMT_AMPM, Some(""));
+    assert_eq!(POSIX::LC_TIME::raw::T_FMT_AMPM, Some("%I:%M:%S %p"));
+}
+
+#[test]
+fn provenance() {
+    assert_eq!(en_US::LC_TIME::provenance::T_FMT, Provenance::Inlined);
+    assert_eq!(en_US::LC_TIME::provenance::D_FMT, Provenance::Upstream);
+    assert_eq!(
+        ca_ES::LC_TIME::provenance::T_FMT_AMPM,
+        Provenance::Synthesized
+    );
+
+    assert_eq!(
+        locale_match!(Locale::en_US => LC_TIME::provenance::D_T_FMT),
+        Provenance::Inlined,
+    );
+    assert_eq!(
+        locale_match!(Locale::en_US => LC_TIME::raw::D_T_FMT),
+        "%a %d %b %Y %r %Z",
+    );
+}
@@ -0,0 +1,227 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{
+    locale_match, locale_resolve, locale_set_match, locales, Builtin, Category, DynAddressInfo,
+    DynIdentificationInfo, DynLocaleInfo, DynMessagesInfo, DynMonetaryInfo, DynNumericInfo,
+    DynTelephoneInfo, DynTimeInfo, Ints, Locale, LocaleProvider, LocaleSet, Origin,
+};
+
+/// A fake locale using the comma as thousands separator and the data of `en_US` otherwise.
+struct Fake {
+    name: &'static str,
+}
+
+impl DynNumericInfo for Fake {
+    fn decimal_point(&self) -> &str {
+        "."
+    }
+
+    fn grouping(&self) -> Ints<'_> {
+        (&[3][..]).into()
+    }
+
+    fn thousands_sep(&self) -> &str {
+        ","
+    }
+}
+
+impl DynLocaleInfo for Fake {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn address(&self) -> &dyn DynAddressInfo {
+        &locales::en_US
+    }
+
+    fn identification(&self) -> &dyn DynIdentificationInfo {
+        &locales::en_US
+    }
+
+    fn messages(&self) -> &dyn DynMessagesInfo {
+        &locales::en_US
+    }
+
+    fn monetary(&self) -> &dyn DynMonetaryInfo {
+        &locales::en_US
+    }
+
+    fn numeric(&self) -> &dyn DynNumericInfo {
+        self
+    }
+
+    fn telephone(&self) -> &dyn DynTelephoneInfo {
+        &locales::en_US
+    }
+
+    fn time(&self) -> &dyn DynTimeInfo {
+        &locales::en_US
+    }
+}
+
+/// Some code that only knows about providers.
+fn thousands_sep(provider: &dyn LocaleProvider, name: &str) -> Option<String> {
+    provider
+        .numeric(name)
+        .map(|x| x.thousands_sep().to_string())
+}
+
+#[test]
+fn builtin() {
+    let info = Builtin.locale("fr_BE").unwrap();
+    assert_eq!(info.name(), "fr_BE");
+    assert_eq!(
+        Builtin.time("fr_BE").unwrap().d_fmt(),
+        pure_rust_locales::fr_BE::LC_TIME::D_FMT,
+    );
+    assert!(Builtin.locale("xx_XX").is_none());
+}
+
+#[test]
+fn fake_locales() {
+    let fake = Fake { name: "de_DE" };
+    let fakes: &[(&str, &dyn DynLocaleInfo)] = &[("de_DE", &fake)];
+
+    assert_eq!(
+        thousands_sep(&Builtin, "de_DE").as_deref(),
+        Some(pure_rust_locales::de_DE::LC_NUMERIC::THOUSANDS_SEP),
+    );
+    assert_eq!(
+        thousands_sep(&(fakes, Builtin), "de_DE").as_deref(),
+        Some(",")
+    );
+    assert_eq!(
+        thousands_sep(&(fakes, Builtin), "fr_BE").as_deref(),
+        Some(pure_rust_locales::fr_BE::LC_NUMERIC::THOUSANDS_SEP),
+    );
+    assert_eq!(thousands_sep(&fakes, "fr_BE"), None);
+}
+
+#[test]
+fn locale_set() {
+    let fake = Fake { name: "fr_BE" };
+    let fakes: &[(&str, &dyn DynLocaleInfo)] = &[("fr_BE", &fake)];
+    let set = LocaleSet::uniform(Locale::fr_BE).with(Category::LC_TIME, Locale::en_GB);
+
+    assert_eq!(
+        set.info(&Builtin, Category::LC_NUMERIC)
+            .unwrap()
+            .numeric()
+            .thousands_sep(),
+        pure_rust_locales::fr_BE::LC_NUMERIC::THOUSANDS_SEP,
+    );
+    assert_eq!(
+        set.info(&fakes, Category::LC_NUMERIC)
+            .unwrap()
+            .numeric()
+            .thousands_sep(),
+        ",",
+    );
+    assert!(set.info(&fakes, Category::LC_TIME).is_none());
+}
+
+#[test]
+fn resolve() {
+    let fake = Fake { name: "de_DE" };
+    let fakes: &[(&str, &dyn DynLocaleInfo)] = &[("de_AT", &fake)];
+
+    let resolved = Builtin
+        .resolve("de_AT", Category::LC_NUMERIC, |x| {
+            Some(x.numeric().thousands_sep())
+        })
+        .unwrap();
+    assert_eq!(resolved.origin, Origin::Own);
+    assert_eq!(
+        resolved.value,
+        pure_rust_locales::de_AT::LC_NUMERIC::THOUSANDS_SEP
+    );
+
+    let provider = (fakes, Builtin);
+    let resolved = provider
+        .resolve("de_AT", Category::LC_NUMERIC, |x| {
+            Some(x.numeric().thousands_sep())
+        })
+        .unwrap();
+    assert_eq!(resolved.locale, "de_AT");
+    assert_eq!(resolved.value, ",");
+
+    let resolved = fakes
+        .resolve("de_CH", Category::LC_TIME, |x| x.time().date_fmt())
+        .is_none();
+    assert!(resolved);
+}
+
+#[test]
+fn macros() {
+    let fake = Fake { name: "de_DE" };
+    let fakes: &[(&str, &dyn DynLocaleInfo)] = &[("de_DE", &fake)];
+    let provider = (fakes, Builtin);
+
+    assert_eq!(
+        locale_match!(in provider, "de_DE" => LC_NUMERIC::THOUSANDS_SEP),
+        Some(","),
+    );
+    assert_eq!(
+        locale_match!(in provider, "fr_BE" => LC_NUMERIC::THOUSANDS_SEP),
+        Some(pure_rust_locales::fr_BE::LC_NUMERIC::THOUSANDS_SEP),
+    );
+    assert_eq!(locale_match!(in fakes, "fr_BE" => LC_TIME::D_FMT), None);
+
+    let set = LocaleSet::uniform(Locale::fr_BE).with(Category::LC_NUMERIC, Locale::de_DE);
+    assert_eq!(
+        locale_set_match!(in provider, set => LC_NUMERIC::GROUPING),
+        Some(Ints::from(&[3][..])),
+    );
+
+    let resolved = locale_resolve!(in provider, "de_AT" => LC_TIME::DATE_FMT).unwrap();
+    assert_eq!(resolved.locale, "de_DE");
+    assert_eq!(resolved.origin, Origin::MainTerritory);
+    // The fake has the `LC_MONETARY` of `en_US`, without `INT_P_SIGN_POSN`.
+    let resolved = locale_resolve!(in provider, "de_DE" => LC_MONETARY::INT_P_SIGN_POSN).unwrap();
+    assert_eq!(resolved.origin, Origin::Counterpart);
+    assert_eq!(
+        resolved.value,
+        pure_rust_locales::en_US::LC_MONETARY::P_SIGN_POSN
+    );
+    // `de_AT` isn't in the provider: the counterpart comes from its main territory.
+    let resolved = locale_resolve!(in fakes, "de_AT" => LC_MONETARY::INT_P_SIGN_POSN).unwrap();
+    assert_eq!(resolved.locale, "de_DE");
+    assert_eq!(resolved.origin, Origin::Counterpart);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn locale_data() {
+    use pure_rust_locales::LocaleData;
+
+    let mut data = LocaleData::from(Locale::de_AT);
+    data.name = "de_XX".to_string();
+    data.time.date_fmt = None;
+    data.numeric.thousands_sep = ",".to_string();
+    let runtime = [data, LocaleData::from(Locale::fr_BE)];
+
+    assert_eq!(runtime[..].locale("de_XX").unwrap().name(), "de_XX");
+    assert!(runtime[..].locale("fr_FR").is_none());
+    assert_eq!(runtime[0].locale(), None);
+    assert_eq!(runtime[1].locale(), Some(Locale::fr_BE));
+    assert_eq!(thousands_sep(&&runtime[..], "de_XX").as_deref(), Some(","));
+    assert_eq!(runtime[1].time().day(), Locale::fr_BE.info().time().day());
+
+    // The locale isn't compiled in: the main territory of its language is.
+    let provider = (&runtime[..], Builtin);
+    let resolved = locale_resolve!(in provider, "de_XX" => LC_TIME::DATE_FMT).unwrap();
+    assert_eq!(resolved.locale, "de_DE");
+    assert_eq!(resolved.origin, Origin::MainTerritory);
+    assert_eq!(
+        resolved.value,
+        pure_rust_locales::de_DE::LC_TIME::DATE_FMT.unwrap()
+    );
+    let resolved = provider
+        .resolve("de_XX", Category::LC_NUMERIC, |x| {
+            Some(x.numeric().thousands_sep())
+        })
+        .unwrap();
+    assert_eq!((resolved.locale, resolved.origin), ("de_XX", Origin::Own));
+    let resolved = locale_resolve!(in provider, "xx_XX" => LC_TIME::D_FMT).unwrap();
+    assert_eq!((resolved.locale, resolved.origin), ("POSIX", Origin::Posix));
+}
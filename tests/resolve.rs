@@ -0,0 +1,52 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{locale_resolve, Locale, Origin, Resolved};
+
+#[test]
+fn own() {
+    assert_eq!(
+        locale_resolve!(Locale::de_AT => LC_MESSAGES::YESSTR),
+        Some(Resolved {
+            value: "ja",
+            locale: Locale::de_AT,
+            origin: Origin::Own,
+        }),
+    );
+}
+
+#[test]
+fn main_territory() {
+    let resolved = locale_resolve!(Locale::de_AT => LC_TIME::DATE_FMT).unwrap();
+    assert_eq!(resolved.locale, Locale::de_DE);
+    assert_eq!(resolved.origin, Origin::MainTerritory);
+    assert_eq!(
+        resolved.value,
+        pure_rust_locales::de_DE::LC_TIME::DATE_FMT.unwrap()
+    );
+}
+
+#[test]
+fn posix() {
+    let resolved = locale_resolve!(Locale::fr_BE => LC_TIME::DATE_FMT).unwrap();
+    assert_eq!(resolved.locale, Locale::POSIX);
+    assert_eq!(resolved.origin, Origin::Posix);
+
+    assert_eq!(
+        locale_resolve!(Locale::POSIX => LC_ADDRESS::POSTAL_FMT),
+        None
+    );
+}
+
+#[test]
+fn counterpart() {
+    let resolved = locale_resolve!(Locale::de_AT => LC_MONETARY::INT_P_CS_PRECEDES).unwrap();
+    assert_eq!(
+        resolved.value,
+        pure_rust_locales::de_AT::LC_MONETARY::P_CS_PRECEDES
+    );
+    assert_eq!(resolved.origin, Origin::Counterpart);
+
+    // INT_FRAC_DIGITS has no counterpart in glibc
+    let resolved = locale_resolve!(Locale::de_AT => LC_MONETARY::INT_FRAC_DIGITS).unwrap();
+    assert_eq!(resolved.origin, Origin::Own);
+}
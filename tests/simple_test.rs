@@ -1,5 +1,4 @@
-use std::convert::TryInto;
-
+#[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
 #[test]
 fn it_works() {
     use pure_rust_locales::fr_BE;
@@ -8,9 +7,11 @@ fn it_works() {
     assert_eq!(fr_BE::LC_TIME::FIRST_WEEKDAY, Some(2_i64));
 }
 
+#[cfg(any(feature = "all-locales", feature = "lang-fr", feature = "region-be"))]
 #[test]
 fn parsing_locales() {
     use pure_rust_locales::Locale;
+    use std::convert::TryInto;
 
     let locale: Locale = "fr_BE".try_into().unwrap();
     assert_eq!(locale, Locale::fr_BE);
@@ -18,3 +19,57 @@ fn parsing_locales() {
     let locale: Locale = locale_string.as_str().try_into().unwrap();
     assert_eq!(locale, Locale::fr_BE);
 }
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn parsing_ignores_case_and_separators() {
+    use pure_rust_locales::Locale;
+
+    for name in ["fr_BE", "fr-BE", "fr_be", "FR-be", "Fr_Be"] {
+        assert_eq!(name.parse::<Locale>().unwrap(), Locale::fr_BE, "{}", name);
+    }
+    assert_eq!("posix".parse::<Locale>().unwrap(), Locale::POSIX);
+    assert_eq!(
+        "sr-rs@LATIN".parse::<Locale>().unwrap(),
+        Locale::sr_RS_latin
+    );
+    assert!("fr_BE ".parse::<Locale>().is_err());
+    assert!("fr".parse::<Locale>().is_err());
+    assert!("fr_BE_euro".parse::<Locale>().is_err());
+    assert!("".parse::<Locale>().is_err());
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn name_round_trip() {
+    use pure_rust_locales::blob::Blob;
+    use pure_rust_locales::Locale;
+
+    for name in Blob::builtin().locales().filter_map(|x| x.name()) {
+        let locale = name.parse::<Locale>().unwrap();
+        assert_eq!(locale.name(), name);
+        assert_eq!(locale.to_string(), name);
+        assert_eq!(name.to_lowercase().parse::<Locale>().unwrap(), locale);
+    }
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn id_round_trip() {
+    use pure_rust_locales::blob::Blob;
+    use pure_rust_locales::Locale;
+    use std::collections::HashSet;
+
+    let mut ids = HashSet::new();
+    for name in Blob::builtin().locales().filter_map(|x| x.name()) {
+        let locale = name.parse::<Locale>().unwrap();
+        assert!(ids.insert(locale.to_u16()), "{}", name);
+        assert_eq!(Locale::from_u16(locale.to_u16()), Some(locale));
+    }
+    assert_eq!(Locale::from_u16(u16::MAX), None);
+
+    // The IDs must never change: they are stored by the users of the crate.
+    assert_eq!(Locale::POSIX.to_u16(), 0);
+    assert_eq!(Locale::en_US.to_u16(), 103);
+    assert_eq!(Locale::fr_BE.to_u16(), 139);
+}
@@ -0,0 +1,174 @@
+//! Guards against regressions in the size of the generated data and of the programs using it.
+
+use std::fs;
+use std::path::Path;
+
+/// Upper bound for the size of `src/generated/locales.blob`, in bytes.
+const MAX_BLOB_SIZE: usize = 400_000;
+
+/// Upper bound for the read-only data of a program reading a locale chosen at runtime with
+/// `Locale::info`, in bytes: `.rodata`, with `.data.rel.ro` and `.rela.dyn` for the pointers to
+/// it.
+const MAX_INFO_RODATA_SIZE: u64 = 460_000;
+
+/// The program measured by `info_rodata_size`.
+const INFO_PROGRAM: &str = r#"
+fn main() {
+    let name = std::env::args().nth(1).unwrap_or_default();
+    if let Ok(locale) = name.parse::<pure_rust_locales::Locale>() {
+        println!("{}", locale.info().time().d_fmt());
+    }
+}
+"#;
+
+/// The generated source files, by name: `lib.rs` and the modules of `src/generated`.
+fn generated_files() -> Vec<(String, String)> {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut files = vec![(
+        "lib.rs".to_string(),
+        fs::read_to_string(src.join("lib.rs")).unwrap(),
+    )];
+
+    for entry in fs::read_dir(src.join("generated")).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(true, |x| x != "rs") {
+            continue;
+        }
+        let name = path.file_name().unwrap().to_str().unwrap().to_string();
+        files.push((name, fs::read_to_string(&path).unwrap()));
+    }
+
+    files
+}
+
+/// The name and value of every constant defined outside of the shared `__data` module.
+fn locale_constants() -> Vec<(String, String)> {
+    let mut constants = Vec::new();
+
+    for (name, code) in generated_files() {
+        if name == "__data.rs" {
+            continue;
+        }
+        let mut rest = code.as_str();
+        while let Some(start) = rest.find("pub const ") {
+            rest = &rest[start..];
+            let end = rest.find(";\n").unwrap();
+            let (name, value) = rest[..end].split_once(" = ").unwrap();
+            constants.push((name.to_string(), value.to_string()));
+            rest = &rest[end..];
+        }
+    }
+
+    constants
+}
+
+#[test]
+fn arrays_are_shared() {
+    let mut arrays = locale_constants()
+        .into_iter()
+        .filter(|(_, value)| value.starts_with("&[") || value.starts_with("Some(&["))
+        .collect::<Vec<_>>();
+    let len = arrays.len();
+
+    arrays.sort();
+    arrays.dedup();
+
+    assert_eq!(
+        arrays.len(),
+        len,
+        "identical arrays must use the __data module"
+    );
+}
+
+#[test]
+fn blob_size() {
+    let size =
+        fs::metadata(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/generated/locales.blob"))
+            .unwrap()
+            .len() as usize;
+
+    assert!(
+        size <= MAX_BLOB_SIZE,
+        "the blob is {} bytes, more than {}",
+        size,
+        MAX_BLOB_SIZE,
+    );
+}
+
+#[cfg(all(
+    target_os = "linux",
+    target_pointer_width = "64",
+    target_endian = "little"
+))]
+#[test]
+fn info_rodata_size() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir = temp_dir.path();
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"info\"\nversion = \"0.0.0\"\nedition = \"2018\"\n\n\
+             [dependencies]\npure-rust-locales = {{ path = {:?} }}\n\n[workspace]\n",
+            manifest_dir,
+        ),
+    )
+    .unwrap();
+    fs::create_dir(dir.join("src")).unwrap();
+    fs::write(dir.join("src/main.rs"), INFO_PROGRAM).unwrap();
+
+    let target_dir = manifest_dir.join("target/size");
+    let output = std::process::Command::new("cargo")
+        .args(&["build", "--release"])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let binary = fs::read(target_dir.join("release/info")).unwrap();
+    let sizes = [".rodata", ".data.rel.ro", ".rela.dyn"]
+        .iter()
+        .map(|name| (*name, section_size(&binary, name)))
+        .collect::<Vec<_>>();
+    let size = sizes.iter().map(|(_, size)| size).sum::<u64>();
+
+    assert!(
+        size <= MAX_INFO_RODATA_SIZE,
+        "the read-only data is {} bytes, more than {}: {:?}",
+        size,
+        MAX_INFO_RODATA_SIZE,
+        sizes,
+    );
+}
+
+/// The size of a section of an ELF64 little-endian binary, 0 if it doesn't have the section.
+#[cfg(all(
+    target_os = "linux",
+    target_pointer_width = "64",
+    target_endian = "little"
+))]
+fn section_size(elf: &[u8], name: &str) -> u64 {
+    use std::convert::TryInto;
+
+    let half = |at: usize| u16::from_le_bytes(elf[at..at + 2].try_into().unwrap()) as usize;
+    let word = |at: usize| u32::from_le_bytes(elf[at..at + 4].try_into().unwrap()) as usize;
+    let xword = |at: usize| u64::from_le_bytes(elf[at..at + 8].try_into().unwrap());
+
+    // The section headers: their offset, size and number, and the index of the names.
+    let (offset, header_size, len) = (xword(0x28) as usize, half(0x3a), half(0x3c));
+    let header = |index: usize| offset + header_size * index;
+    let names = xword(header(half(0x3e)) + 0x18) as usize;
+
+    (0..len)
+        .map(header)
+        .find(|&header| {
+            let at = names + word(header);
+            elf[at..].starts_with(name.as_bytes()) && elf[at + name.len()] == 0
+        })
+        .map_or(0, |header| xword(header + 0x20))
+}
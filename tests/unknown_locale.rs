@@ -0,0 +1,89 @@
+#![cfg(feature = "all-locales")]
+
+use pure_rust_locales::{Category, Locale, LocaleSet, UnknownLocale, UnknownLocaleKind};
+
+fn parse(name: &str) -> UnknownLocale {
+    name.parse::<Locale>().unwrap_err()
+}
+
+#[test]
+fn kind() {
+    for name in ["", "frBE", "fr_BE ", "fr_BEL", "fr_BE@", "fr_BE.UTF-8", "C"] {
+        assert_eq!(parse(name).kind(), UnknownLocaleKind::Syntax, "{}", name);
+    }
+    assert_eq!(parse("xx_XX").kind(), UnknownLocaleKind::Language);
+    assert_eq!(parse("fr_XX").kind(), UnknownLocaleKind::Territory);
+    assert_eq!(parse("fr").kind(), UnknownLocaleKind::Territory);
+    assert_eq!(parse("de_DE@foo").kind(), UnknownLocaleKind::Modifier);
+    assert_eq!(parse("SR-rs@Cyrillic").kind(), UnknownLocaleKind::Modifier);
+}
+
+#[test]
+fn nul_bytes() {
+    // The keys of the names are padded with zeros, which must not match a shorter name.
+    for name in ["\0", "fr_BE\0", "fr_BE\0\0", "fr\0"] {
+        assert_eq!(parse(name).kind(), UnknownLocaleKind::Syntax, "{:?}", name);
+    }
+}
+
+#[test]
+fn input() {
+    assert_eq!(parse("fr_XX").input(), "fr_XX");
+    assert_eq!(parse(&"a".repeat(40)).input(), "a".repeat(32));
+    assert_eq!(parse(&"é".repeat(20)).input(), "é".repeat(16));
+}
+
+#[test]
+fn suggestions() {
+    let suggestions = |name: &str| parse(name).suggestions().collect::<Vec<_>>();
+
+    assert_eq!(suggestions("frBE"), [Locale::fr_BE]);
+    assert_eq!(suggestions("fr_BE "), [Locale::fr_BE]);
+    assert_eq!(
+        suggestions("fr"),
+        [Locale::fr_BE, Locale::fr_CA, Locale::fr_CH]
+    );
+    assert_eq!(suggestions("en_UK").len(), UnknownLocale::MAX_SUGGESTIONS);
+    assert!(suggestions("en_UK").contains(&Locale::en_US));
+    assert_eq!(suggestions("sr_RS@cyrillic")[0], Locale::sr_RS_latin);
+    assert_eq!(suggestions("xx_XX"), []);
+    assert_eq!(suggestions(""), []);
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        parse("fr_XX").to_string(),
+        "unknown locale `fr_XX`: unknown territory for the language, did you mean `fr_BE`, \
+         `fr_CA` or `fr_CH`?",
+    );
+    assert_eq!(
+        parse("xx_XX").to_string(),
+        "unknown locale `xx_XX`: unknown language"
+    );
+}
+
+#[test]
+fn locale_set() {
+    let error = "LC_TIME=fr_XX".parse::<LocaleSet>().unwrap_err();
+    assert_eq!(
+        (error.input(), error.kind()),
+        ("fr_XX", UnknownLocaleKind::Territory)
+    );
+    let error = "LC_FOO=fr_BE".parse::<LocaleSet>().unwrap_err();
+    assert_eq!(
+        (error.input(), error.kind()),
+        ("LC_FOO", UnknownLocaleKind::Category)
+    );
+    assert_eq!(error.suggestions().count(), 0);
+    assert_eq!(error.to_string(), "unknown category `LC_FOO`");
+
+    let error = "LC_TIME=fr_BE;LC_NUMERIC=fr_BE;LC_TIME=en_US"
+        .parse::<LocaleSet>()
+        .unwrap_err();
+    assert_eq!(error.kind(), UnknownLocaleKind::RepeatedCategory);
+    assert_eq!(error.to_string(), "category `LC_TIME` given twice");
+
+    let error = "LC_MESSAGE".parse::<Category>().unwrap_err();
+    assert_eq!(error.kind(), UnknownLocaleKind::Category);
+}